@@ -1,6 +1,12 @@
 use std::{any::Any, fmt::Debug};
 
+use crate::{
+    language::diagnostic::{Diagnostic, Severity},
+    node::{encoding::LengaFile, matching},
+};
+
 pub mod c;
+pub mod diagnostic;
 
 pub trait LanguageObject: Debug + Any + PartialEqAny {
     fn as_any(&self) -> &dyn Any;
@@ -36,8 +42,41 @@ pub trait Language {
 
     fn file_extension(&self) -> String;
     fn name(&self) -> String;
-    fn parse_text(&self, content: &str) -> Result<Self::SourceFile, String>;
+    /// Hash of the node types the language writes, which changes when one is added, removed
+    /// or renumbered, so files written with other node types are refused
+    fn schema_hash(&self) -> u64;
+    /// Parses `content`, with a diagnostic for every problem found in it. When it cannot be
+    /// parsed, the diagnostics hold the error that stopped the parser.
+    fn parse_text_with_diagnostics(
+        &self,
+        content: &str,
+    ) -> Result<(Self::SourceFile, Vec<Diagnostic>), Vec<Diagnostic>>;
+    fn parse_text(&self, content: &str) -> Result<Self::SourceFile, String> {
+        self.parse_text_with_diagnostics(content)
+            .map(|(src_file, _)| src_file)
+            .map_err(|diagnostics| {
+                diagnostics
+                    .into_iter()
+                    .find(|diagnostic| diagnostic.severity == Severity::Error)
+                    .map(|diagnostic| diagnostic.message)
+                    .unwrap_or_default()
+            })
+    }
     fn write_to_text(&self, src_file: Self::SourceFile) -> Result<String, String>;
     fn parse_nodes(&self, nodes: Vec<u8>) -> Result<Self::SourceFile, String>;
     fn write_to_nodes(&self, src_file: Self::SourceFile) -> Result<Vec<u8>, String>;
+    /// Parses `content` as a new version of `src_file`, like the same file edited as text.
+    /// Objects matched to one of `src_file` keep its id, and references to them follow, so only
+    /// new code gets new ids
+    fn update_from_text(
+        &self,
+        src_file: Self::SourceFile,
+        content: &str,
+    ) -> Result<Self::SourceFile, String> {
+        let old = LengaFile::from_bincode(&self.write_to_nodes(src_file)?)?;
+        let new_src_file = self.parse_text(content)?;
+        let mut new = LengaFile::from_bincode(&self.write_to_nodes(new_src_file)?)?;
+        matching::keep_matched_ids(&old.nodes, &mut new.nodes);
+        self.parse_nodes(new.to_bincode())
+    }
 }
@@ -8,23 +8,30 @@ use crate::language::{
     Language,
     c::{
         language_object::{
-            LanguageObject as CLanguageObject, declaration_object::DeclarationObject,
-            special_object::source_file::SourceFile as CSourceFile,
+            LanguageObject as CLanguageObject,
+            declaration_object::DeclarationObject,
+            special_object::{source_file::SourceFile as CSourceFile, trivia::Trivia},
         },
-        parsers::{nodes::NodeParser, text::TreeSitterParser},
+        parsers::{ids::IdMode, nodes::NodeParser, text::TreeSitterParser},
         writers::{
             Writer,
-            node_writer::NodeWriter,
+            node_writer::{NodeWriter, node_type::NodeType},
             text_writer::{TextWriter, style::Style},
         },
     },
+    diagnostic::{Diagnostic, Severity, Span},
 };
 use tree_sitter::Parser;
-use uuid::Uuid;
 
 pub mod c_type;
+pub mod literal;
+pub mod specifiers;
 
-pub struct C {}
+pub struct C {
+    /// Whether parsed text keeps its layout, so its objects are written back laid out as they were
+    keep_trivia: bool,
+    id_mode: IdMode,
+}
 
 impl Default for C {
     fn default() -> Self {
@@ -34,7 +41,29 @@ impl Default for C {
 
 impl C {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            keep_trivia: false,
+            id_mode: IdMode::Random,
+        }
+    }
+
+    /// Language that keeps the blank lines, function line breaks and indentation of the objects
+    /// of the text it parses, so they are written back laid out as they were
+    pub fn with_trivia() -> Self {
+        Self {
+            keep_trivia: true,
+            ..Self::new()
+        }
+    }
+
+    /// Gives the objects parsed from text ids derived from where they are and what they hold
+    /// rather than random ones, so the same text always gives the same objects and nodes.
+    /// `seed` tells files apart, like their path, so each file gets its own ids
+    pub fn with_deterministic_ids(self, seed: &str) -> Self {
+        Self {
+            id_mode: IdMode::deterministic(seed),
+            ..self
+        }
     }
 }
 
@@ -50,43 +79,65 @@ impl Language for C {
         "C".to_string()
     }
 
-    fn parse_text(&self, source_code: &str) -> Result<Self::SourceFile, String> {
+    fn schema_hash(&self) -> u64 {
+        NodeType::schema_hash()
+    }
+
+    fn parse_text_with_diagnostics(
+        &self,
+        source_code: &str,
+    ) -> Result<(Self::SourceFile, Vec<Diagnostic>), Vec<Diagnostic>> {
         let mut parser = Parser::new();
 
         parser
             .set_language(&tree_sitter_c::LANGUAGE.into())
-            .expect("Error loading C grammar");
+            .map_err(|e| internal_error(format!("Error loading C grammar: {e}")))?;
 
-        let tree = parser.parse(source_code, None).unwrap();
+        let tree = parser
+            .parse(source_code, None)
+            .ok_or_else(|| internal_error("The C parser did not return a tree".to_string()))?;
         let root_node = tree.root_node();
 
-        assert_eq!(
-            root_node.kind(),
-            "translation_unit",
-            "Root node should be a translation unit"
-        );
-
-        let objects: Vec<DeclarationObject> = match root_node.child(0) {
-            Some(node) => TreeSitterParser::parse_with_tree(node, source_code)?
-                .into_iter()
-                .map(std::convert::TryInto::try_into)
-                .collect::<Result<Vec<DeclarationObject>, language_object::ConversionError>>()
-                .map_err(|e: language_object::ConversionError| format!("{e:?}"))?,
-            None => {
-                vec![]
+        // Text the grammar cannot recover from at all is a single error node, kept whole as
+        // an unknown part
+        let first_node = if root_node.is_error() {
+            Some(root_node)
+        } else if root_node.kind() == "translation_unit" {
+            root_node.child(0)
+        } else {
+            return Err(internal_error(format!(
+                "Root node should be a translation unit, found {}",
+                root_node.kind()
+            )));
+        };
+
+        let mut trivia = self.keep_trivia.then(Trivia::default);
+        let (objects, diagnostics) = match first_node {
+            Some(node) => {
+                TreeSitterParser::parse_with_tree(node, source_code, trivia.as_mut(), self.id_mode)?
             }
+            None => (vec![], vec![]),
         };
+        let objects = objects
+            .into_iter()
+            .map(std::convert::TryInto::try_into)
+            .collect::<Result<Vec<DeclarationObject>, language_object::ConversionError>>()
+            .map_err(|e: language_object::ConversionError| internal_error(format!("{e:?}")))?;
 
-        Ok(CSourceFile {
-            id: Uuid::new_v4(),
-            code: objects,
-        })
+        Ok((
+            CSourceFile {
+                id: self.id_mode.file_id(),
+                span: Some(Span::from_node(root_node)),
+                code: objects,
+                trivia,
+            },
+            diagnostics,
+        ))
     }
 
     fn parse_nodes(&self, nodes: Vec<u8>) -> Result<Self::SourceFile, String> {
         let mut node_reader = NodeParser::new();
-        let src_file = node_reader.read_file(nodes).unwrap();
-        Ok(src_file)
+        node_reader.read_file(nodes)
     }
 
     fn write_to_text(&self, src_file: Self::SourceFile) -> Result<String, String> {
@@ -109,6 +160,16 @@ impl Language for C {
     }
 }
 
+/// Failure of the parser itself rather than of the parsed source
+fn internal_error(message: String) -> Vec<Diagnostic> {
+    vec![Diagnostic {
+        severity: Severity::Error,
+        code: "internal-error",
+        message,
+        span: Span::default(),
+    }]
+}
+
 fn dfs(walker: &mut tree_sitter::TreeCursor, source_code: &str) -> Vec<String> {
     let node = walker.node();
     let mut lines = Vec::new();
@@ -136,6 +197,14 @@ fn dfs(walker: &mut tree_sitter::TreeCursor, source_code: &str) -> Vec<String> {
 pub trait TreeSitterNodeExt {
     fn content(&self, source_code: &str) -> String;
     fn dump(&self, source_code: &str) -> String;
+    /// Child at `index` among the children that are not comments
+    fn code_child(&self, index: usize) -> Option<Self>
+    where
+        Self: Sized;
+    /// Next sibling that is not a comment
+    fn next_code_sibling(&self) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 impl TreeSitterNodeExt for tree_sitter::Node<'_> {
@@ -146,6 +215,23 @@ impl TreeSitterNodeExt for tree_sitter::Node<'_> {
     fn dump(&self, source_code: &str) -> String {
         dfs(&mut self.walk(), source_code).join("\n").to_string()
     }
+
+    fn code_child(&self, index: usize) -> Option<Self> {
+        let mut cursor = self.walk();
+        self.children(&mut cursor)
+            .filter(|child| !child.is_extra())
+            .nth(index)
+    }
+
+    fn next_code_sibling(&self) -> Option<Self> {
+        let mut sibling = self.next_sibling();
+        while let Some(node) = sibling
+            && node.is_extra()
+        {
+            sibling = node.next_sibling();
+        }
+        sibling
+    }
 }
 
 #[cfg(test)]
@@ -154,35 +240,79 @@ mod tests {
     use std::vec;
 
     use crate::language::c::{
-        c_type::CType,
+        c_type::{
+            ArrayType, CType, FnParameter, FnType, Length, Qualifiers, Signedness, SizedType,
+            TypeReference,
+        },
         language_object::{
             declaration_object::{
                 declaration::Declaration,
+                declaration_list::DeclarationList,
+                enum_definition::{EnumDefinition, enumerator::Enumerator},
                 function_declaration::{
                     FunctionDeclaration, function_parameter::FunctionParameter,
                 },
                 function_definition::FunctionDefinition,
+                preproc_if::PreprocIf,
                 preproc_include::PreprocInclude,
+                struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+                union_definition::UnionDefinition,
             },
             expression_object::{
-                ExpressionObject, assignment_expression::AssignmentExpression,
-                binary_expression::BinaryExpression, call_expression::CallExpression,
-                number_literal::NumberLiteral, reference::Reference, string_literal::StringLiteral,
+                ExpressionObject,
+                assignment_expression::AssignmentExpression,
+                binary_expression::BinaryExpression,
+                call_expression::CallExpression,
+                cast_expression::CastExpression,
+                char_literal::CharLiteral,
+                conditional_expression::ConditionalExpression,
+                initializer_list::InitializerList,
+                initializer_pair::{Designator, InitializerPair},
+                number_literal::{NumberLiteral, NumberValue, Radix},
+                parenthesized_expression::ParenthesizedExpression,
+                reference::Reference,
+                sizeof_expression::SizeofExpression,
+                string_literal::StringLiteral,
+                subscript_expression::SubscriptExpression,
+                unary_expression::UnaryExpression,
+                update_expression::UpdateExpression,
             },
-            special_object::comment::Comment,
+            special_object::comment::{Comment, Comments},
             statement_object::{
+                break_statement::BreakStatement,
+                case_statement::CaseStatement,
                 compound_statement::{
                     CompoundStatement, compound_statement_object::CompoundStatementObject,
                 },
+                continue_statement::ContinueStatement,
+                do_statement::DoStatement,
+                for_statement::ForStatement,
                 if_statement::{ElseStatement, IfStatement},
+                preproc_if_statement::PreprocIfStatement,
                 return_statement::ReturnStatement,
+                switch_statement::SwitchStatement,
+                while_statement::WhileStatement,
             },
         },
+        literal::EncodingPrefix,
         parsers::{context::SymbolAlreadyExists, text::TreeSitterParserError},
     };
+    use uuid::Uuid;
 
     use super::*;
 
+    /// Declaration id and name of the plain name an assignment is made to
+    fn assigned_name(left: &ExpressionObject) -> (&Uuid, &String) {
+        match left {
+            ExpressionObject::Reference(Reference {
+                declaration_id,
+                identifier,
+                ..
+            }) => (declaration_id, identifier),
+            other => panic!("expected an assignment to a name, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_empty_function_definition() {
         let c_code = "
@@ -390,16 +520,15 @@ int main() {
 
         match src_file.code.as_slice() {
             [
-                DeclarationObject::Comment(Comment {
-                    content: line_comment,
-                    ..
-                }),
                 DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    comments,
                     compound_statement: CompoundStatement { code_block, .. },
                     ..
                 }),
             ] => {
-                assert_eq!(line_comment, "// This is a line comment");
+                // The line comment documents the function, the block comment documents nothing
+                assert_eq!(comments.leading.len(), 1);
+                assert_eq!(comments.leading[0].content, "// This is a line comment");
                 match code_block.as_slice() {
                     [
                         CompoundStatementObject::Comment(Comment {
@@ -412,11 +541,140 @@ int main() {
                     _ => panic!("AST did not match expected block comment"),
                 }
             }
-            _ => panic!("AST did not match expected comments and function definition"),
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_attach_comments() {
+        let c_code = r#"#include <stdio.h> // io
+
+/* unrelated */
+
+// doc for f
+int f(int a /* first */, int b)
+{
+    int x = a + /* two */ 2; // trailing x
+    f(a, // arg
+      b);
+    /* lead */ return x;
+    // dangling
+}
+"#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::PreprocInclude(include),
+            DeclarationObject::Comment(unrelated),
+            DeclarationObject::FunctionDefinition(function),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        let contents = |comments: &[Comment]| {
+            comments
+                .iter()
+                .map(|comment| comment.content.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(contents(&include.comments.trailing), ["// io"]);
+        assert_eq!(unrelated.content, "/* unrelated */");
+        assert_eq!(contents(&function.comments.leading), ["// doc for f"]);
+        assert_eq!(
+            contents(&function.parameter_list[0].comments.trailing),
+            ["/* first */"]
+        );
+        let [
+            CompoundStatementObject::Declaration(x),
+            CompoundStatementObject::CallExpression(call),
+            CompoundStatementObject::ReturnStatement(return_statement),
+            CompoundStatementObject::Comment(dangling),
+        ] = function.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected statements: {:?}", function.compound_statement);
+        };
+        assert_eq!(contents(&x.comments.trailing), ["// trailing x"]);
+        let Some(value) = &x.value else {
+            panic!("expected a value");
+        };
+        let ExpressionObject::BinaryExpression(sum) = value.as_ref() else {
+            panic!("expected a binary expression: {value:?}");
+        };
+        assert_eq!(
+            contents(&sum.right.as_language_object().comments().unwrap().leading),
+            ["/* two */"]
+        );
+        assert_eq!(
+            contents(
+                &call.argument_list[0]
+                    .as_language_object()
+                    .comments()
+                    .unwrap()
+                    .trailing
+            ),
+            ["// arg"]
+        );
+        assert_eq!(contents(&return_statement.comments.leading), ["/* lead */"]);
+        assert_eq!(dangling.content, "// dangling");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert_eq!(
+            text,
+            "#include <stdio.h> // io
+
+/* unrelated */
+// doc for f
+int
+f(int a /* first */, int b)
+{
+    int x = a + /* two */ 2; // trailing x
+    f(a, // arg
+        b);
+    /* lead */
+    return x;
+    // dangling
+}
+
+"
+        );
+    }
+
+    #[test]
+    fn test_attach_comments_after_function_parameters() {
+        let c_code = "int\nadd(int a, int b) // t\n{\n    return a + b;\n}\n\nint\nsub(int a, int b) /* s */\n{\n    return a - b;\n}\n\n";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+        let [
+            DeclarationObject::FunctionDefinition(add),
+            DeclarationObject::FunctionDefinition(sub),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        // They lead the body, not trail the last parameter
+        for (function, comment) in [(add, "// t"), (sub, "/* s */")] {
+            assert_eq!(function.parameter_list[1].comments, Comments::default());
+            let [leading] = function.compound_statement.comments.leading.as_slice() else {
+                panic!(
+                    "unexpected body comments: {:?}",
+                    function.compound_statement
+                );
+            };
+            assert_eq!(leading.content, comment);
         }
+
         let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
         let parsed_objects = c_language.parse_nodes(nodes).unwrap();
         assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
     }
 
     #[test]
@@ -463,14 +721,18 @@ int main() {
                             a_value,
                             &Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                                 id: Uuid::new_v4(),
-                                value: "5".to_string(),
+                                ..NumberLiteral::from_spelling("5").unwrap()
                             }))
                         );
                         assert_eq!(
                             b_value,
                             &Box::new(ExpressionObject::StringLiteral(StringLiteral {
                                 id: Uuid::new_v4(),
-                                value: "Hello, World!\\n".to_string()
+                                span: None,
+                                comments: Comments::default(),
+                                prefix: EncodingPrefix::None,
+                                value: "Hello, World!\n".to_string(),
+                                segments: vec![],
                             }))
                         );
                     }
@@ -557,14 +819,16 @@ int main() {
                             primitive_type: CType::Int,
                             identifier: a_identifier,
                             value: None,
+                            ..
                         }),
                         CompoundStatementObject::AssignmentExpression(AssignmentExpression {
-                            id_declaration: assignment_id,
-                            identifier: a_assignment_identifier,
+                            left: a_assignment_left,
                             value: a_assignment_value,
                             ..
                         }),
                     ] => {
+                        let (assignment_id, a_assignment_identifier) =
+                            assigned_name(a_assignment_left);
                         assert_eq!(a_identifier, "a");
                         assert_eq!(a_assignment_identifier, "a");
                         assert_eq!(assignment_id, declaration_id);
@@ -572,7 +836,7 @@ int main() {
                             a_assignment_value,
                             &Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                                 id: Uuid::new_v4(),
-                                value: "5".to_string()
+                                ..NumberLiteral::from_spelling("5").unwrap()
                             }))
                         );
                     }
@@ -674,18 +938,21 @@ int main() {
                         primitive_type: CType::Int,
                         identifier: a_declaration_identifier,
                         value: Some(a_declaration_value),
+                        ..
                     }),
                     CompoundStatementObject::Declaration(Declaration {
                         id: b_declaration_id,
                         primitive_type: CType::Int,
                         identifier: b_declaration_identifier,
                         value: Some(b_declaration_value),
+                        ..
                     }),
                     CompoundStatementObject::Declaration(Declaration {
                         id: c_declaration_id,
                         primitive_type: CType::Int,
                         identifier: c_declaration_identifier,
                         value: None,
+                        ..
                     }),
                     CompoundStatementObject::CompoundStatement(CompoundStatement {
                         code_block: inner_scope,
@@ -696,20 +963,21 @@ int main() {
                     && c_declaration_identifier == "c" =>
                 {
                     match a_declaration_value.as_ref() {
-                        ExpressionObject::NumberLiteral(NumberLiteral { value, .. })
-                            if value == "1" => {}
+                        ExpressionObject::NumberLiteral(NumberLiteral {
+                            spelling: value, ..
+                        }) if value == "1" => {}
                         _ => panic!(),
                     }
                     match b_declaration_value.as_ref() {
-                        ExpressionObject::NumberLiteral(NumberLiteral { value, .. })
-                            if value == "2" => {}
+                        ExpressionObject::NumberLiteral(NumberLiteral {
+                            spelling: value, ..
+                        }) if value == "2" => {}
                         _ => panic!(),
                     }
                     match inner_scope.as_slice() {
                         [
                             CompoundStatementObject::AssignmentExpression(AssignmentExpression {
-                                id_declaration: b_assignment_id,
-                                identifier: b_assignment_identifier,
+                                left: b_assignment_left,
                                 value: b_assignment_value,
                                 ..
                             }),
@@ -718,14 +986,18 @@ int main() {
                                 primitive_type: CType::Int,
                                 identifier: inner_a_declaration_identifier,
                                 value: Some(inner_a_declaration_value),
+                                ..
                             }),
                             CompoundStatementObject::AssignmentExpression(AssignmentExpression {
-                                id_declaration: c_assignment_id,
-                                identifier: c_assignment_identifier,
+                                left: c_assignment_left,
                                 value: c_assignment_value,
                                 ..
                             }),
                         ] => {
+                            let (b_assignment_id, b_assignment_identifier) =
+                                assigned_name(b_assignment_left);
+                            let (c_assignment_id, c_assignment_identifier) =
+                                assigned_name(c_assignment_left);
                             assert_eq!(b_assignment_identifier, "b");
                             assert_eq!(b_assignment_id, b_declaration_id);
                             match b_assignment_value.as_ref() {
@@ -743,7 +1015,8 @@ int main() {
                             assert_ne!(a_declaration_id, inner_a_declaration_id);
                             match inner_a_declaration_value.as_ref() {
                                 ExpressionObject::NumberLiteral(NumberLiteral {
-                                    value, ..
+                                    spelling: value,
+                                    ..
                                 }) if value == "3" => {}
                                 _ => panic!(),
                             }
@@ -800,6 +1073,8 @@ int main() {
         let expected: Vec<DeclarationObject> =
             vec![DeclarationObject::PreprocInclude(PreprocInclude {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 content: "<stdio.h>".to_string(),
             })];
         assert_eq!(src_file.code, expected);
@@ -939,11 +1214,11 @@ int main() {
                                 match argument_list.as_slice() {
                                     [
                                         ExpressionObject::NumberLiteral(NumberLiteral {
-                                            value: a,
+                                            spelling: a,
                                             ..
                                         }),
                                         ExpressionObject::NumberLiteral(NumberLiteral {
-                                            value: b,
+                                            spelling: b,
                                             ..
                                         }),
                                     ] => {
@@ -996,6 +1271,8 @@ int main() {
                     [
                         CompoundStatementObject::CallExpression(CallExpression {
                             id: _,
+                            span: _,
+                            comments: _,
                             id_declaration: _, // TODO verify id with id assigned to stdlib function
                             identifier: call_identifier,
                             argument_list,
@@ -1004,7 +1281,7 @@ int main() {
                         assert_eq!(call_identifier, "printf");
                         match argument_list.as_slice() {
                             [ExpressionObject::StringLiteral(StringLiteral { value, .. })]
-                                if value == "Hello, World!\\n" => {}
+                                if value == "Hello, World!\n" => {}
                             _ => {
                                 panic!("AST did not match expected function call argument list")
                             }
@@ -1041,74 +1318,86 @@ int main() {
         let code: Vec<CompoundStatementObject> = vec![
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: "==".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: "<".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: ">".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: "!=".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: "<=".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
             CompoundStatementObject::BinaryExpression(BinaryExpression {
                 id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
                 left: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "1".to_string(),
+                    ..NumberLiteral::from_spelling("1").unwrap()
                 })),
                 operator: ">=".to_string(),
                 right: Box::new(ExpressionObject::NumberLiteral(NumberLiteral {
                     id: Uuid::new_v4(),
-                    value: "2".to_string(),
+                    ..NumberLiteral::from_spelling("2").unwrap()
                 })),
             }),
         ];
@@ -1175,17 +1464,19 @@ int main() {
                                 condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: ">".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1265,17 +1556,19 @@ int main() {
                                 condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: ">".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1369,17 +1662,19 @@ int main() {
                                 condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: ">".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1401,17 +1696,19 @@ int main() {
                                 else_if.condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: "<".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1493,17 +1790,19 @@ int main() {
                                 condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: ">".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1525,17 +1824,19 @@ int main() {
                                 else_if.condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: "<".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1632,22 +1933,27 @@ int main() {
                             ..
                         }),
                     ] => match *(*body).clone() {
-                        CompoundStatementObject::NumberLiteral(NumberLiteral { value, .. }) => {
+                        CompoundStatementObject::NumberLiteral(NumberLiteral {
+                            spelling: value,
+                            ..
+                        }) => {
                             assert_eq!(
                                 condition.as_ref(),
                                 &ExpressionObject::BinaryExpression(BinaryExpression {
                                     id: Uuid::new_v4(),
+                                    span: None,
+                                    comments: Comments::default(),
                                     left: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "5".to_string(),
+                                            ..NumberLiteral::from_spelling("5").unwrap()
                                         }
                                     )),
                                     operator: ">".to_string(),
                                     right: Box::new(ExpressionObject::NumberLiteral(
                                         NumberLiteral {
                                             id: Uuid::new_v4(),
-                                            value: "0".to_string(),
+                                            ..NumberLiteral::from_spelling("0").unwrap()
                                         }
                                     )),
                                 })
@@ -1655,7 +1961,7 @@ int main() {
                             assert_eq!(value, "1".to_string());
                             match *else_if.body.clone() {
                                 CompoundStatementObject::NumberLiteral(NumberLiteral {
-                                    value: else_value,
+                                    spelling: else_value,
                                     ..
                                 }) => {
                                     assert_eq!(else_value, "2".to_string());
@@ -1666,7 +1972,7 @@ int main() {
                                 Some(ElseStatement::ElseClause(else_clause)) => {
                                     match *else_clause.body.clone() {
                                         CompoundStatementObject::NumberLiteral(NumberLiteral {
-                                            value: else_else_value,
+                                            spelling: else_else_value,
                                             ..
                                         }) => {
                                             assert_eq!(else_else_value, "3".to_string());
@@ -1794,4 +2100,3372 @@ int main() {
         let parsed_objects = c_language.parse_nodes(nodes).unwrap();
         assert_eq!(src_file, parsed_objects);
     }
+
+    #[test]
+    fn test_parse_while_statement() {
+        let c_code = r#"
+        int main() {
+            int a = 0;
+            while (a < 10) {
+                a = a + 1;
+            }
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => match code_block.as_slice() {
+                [
+                    CompoundStatementObject::Declaration(Declaration { id: a_id, .. }),
+                    CompoundStatementObject::WhileStatement(WhileStatement {
+                        condition, body, ..
+                    }),
+                ] => {
+                    match condition.as_ref() {
+                        ExpressionObject::BinaryExpression(BinaryExpression {
+                            left,
+                            operator,
+                            ..
+                        }) => {
+                            assert_eq!(operator, "<");
+                            match left.as_ref() {
+                                ExpressionObject::Reference(Reference {
+                                    declaration_id, ..
+                                }) => assert_eq!(declaration_id, a_id),
+                                _ => panic!("AST did not match expected while condition"),
+                            }
+                        }
+                        _ => panic!("AST did not match expected while condition"),
+                    }
+                    match body.as_ref() {
+                        CompoundStatementObject::CompoundStatement(CompoundStatement {
+                            code_block: while_code_block,
+                            ..
+                        }) => match while_code_block.as_slice() {
+                            [
+                                CompoundStatementObject::AssignmentExpression(
+                                    AssignmentExpression { left, .. },
+                                ),
+                            ] => assert_eq!(assigned_name(left).0, a_id),
+                            _ => panic!("AST did not match expected while body"),
+                        },
+                        _ => panic!("AST did not match expected while body"),
+                    }
+                }
+                _ => panic!("AST did not match expected function body"),
+            },
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_parse_do_statement() {
+        let c_code = r#"
+        int main() {
+            int a = 0;
+            do {
+                a = a + 1;
+            } while (a < 10);
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => match code_block.as_slice() {
+                [
+                    CompoundStatementObject::Declaration(_),
+                    CompoundStatementObject::DoStatement(DoStatement {
+                        body, condition, ..
+                    }),
+                ] => {
+                    assert!(matches!(
+                        body.as_ref(),
+                        CompoundStatementObject::CompoundStatement(_)
+                    ));
+                    assert!(matches!(
+                        condition.as_ref(),
+                        ExpressionObject::BinaryExpression(_)
+                    ));
+                }
+                _ => panic!("AST did not match expected function body"),
+            },
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_parse_for_statement() {
+        let c_code = r#"
+        int main() {
+            int a = 0;
+            for (int i = 0; i < 10; i = i + 1) {
+                a = a + i;
+            }
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => match code_block.as_slice() {
+                [
+                    CompoundStatementObject::Declaration(_),
+                    CompoundStatementObject::ForStatement(ForStatement {
+                        initializer: Some(initializer),
+                        condition: Some(condition),
+                        update: Some(update),
+                        ..
+                    }),
+                ] => {
+                    let i_id = match initializer.as_ref() {
+                        CompoundStatementObject::Declaration(Declaration {
+                            id,
+                            identifier,
+                            ..
+                        }) => {
+                            assert_eq!(identifier, "i");
+                            id
+                        }
+                        _ => panic!("AST did not match expected for initializer"),
+                    };
+                    match condition.as_ref() {
+                        ExpressionObject::BinaryExpression(BinaryExpression { left, .. }) => {
+                            match left.as_ref() {
+                                ExpressionObject::Reference(Reference {
+                                    declaration_id, ..
+                                }) => assert_eq!(declaration_id, i_id),
+                                _ => panic!("AST did not match expected for condition"),
+                            }
+                        }
+                        _ => panic!("AST did not match expected for condition"),
+                    }
+                    match update.as_ref() {
+                        ExpressionObject::AssignmentExpression(AssignmentExpression {
+                            left,
+                            ..
+                        }) => assert_eq!(assigned_name(left).0, i_id),
+                        _ => panic!("AST did not match expected for update"),
+                    }
+                }
+                _ => panic!("AST did not match expected function body"),
+            },
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_parse_for_statement_empty_header() {
+        let c_code = r#"
+        int main() {
+            for (;;) {
+                return 0;
+            }
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => match code_block.as_slice() {
+                [
+                    CompoundStatementObject::ForStatement(ForStatement {
+                        initializer: None,
+                        condition: None,
+                        update: None,
+                        body,
+                        ..
+                    }),
+                ] => assert!(matches!(
+                    body.as_ref(),
+                    CompoundStatementObject::CompoundStatement(_)
+                )),
+                _ => panic!("AST did not match expected function body"),
+            },
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(text.contains("for (;;) {"));
+    }
+
+    #[test]
+    fn test_parse_switch_statement() {
+        let c_code = r#"
+        int main() {
+            int state = 0;
+            while (state < 3) {
+                switch (state) {
+                    case 0:
+                        state = 1;
+                        break;
+                    case 1:
+                        int next = 2;
+                        state = next;
+                        continue;
+                    default:
+                        state = 3;
+                }
+            }
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => match code_block.as_slice() {
+                [
+                    CompoundStatementObject::Declaration(Declaration { id: state_id, .. }),
+                    CompoundStatementObject::WhileStatement(WhileStatement { body, .. }),
+                ] => match body.as_ref() {
+                    CompoundStatementObject::CompoundStatement(CompoundStatement {
+                        code_block: while_code_block,
+                        ..
+                    }) => match while_code_block.as_slice() {
+                        [
+                            CompoundStatementObject::SwitchStatement(SwitchStatement {
+                                condition,
+                                body,
+                                ..
+                            }),
+                        ] => {
+                            match condition.as_ref() {
+                                ExpressionObject::Reference(Reference {
+                                    declaration_id, ..
+                                }) => assert_eq!(declaration_id, state_id),
+                                _ => panic!("AST did not match expected switch condition"),
+                            }
+                            match body.as_ref() {
+                                CompoundStatementObject::CompoundStatement(CompoundStatement {
+                                    code_block: switch_code_block,
+                                    ..
+                                }) => match switch_code_block.as_slice() {
+                                    [
+                                        CompoundStatementObject::CaseStatement(CaseStatement {
+                                            value: Some(first_value),
+                                            body: first_body,
+                                            ..
+                                        }),
+                                        CompoundStatementObject::CaseStatement(CaseStatement {
+                                            value: Some(_),
+                                            body: second_body,
+                                            ..
+                                        }),
+                                        CompoundStatementObject::CaseStatement(CaseStatement {
+                                            value: None,
+                                            body: default_body,
+                                            ..
+                                        }),
+                                    ] => {
+                                        assert!(matches!(
+                                            first_value.as_ref(),
+                                            ExpressionObject::NumberLiteral(_)
+                                        ));
+                                        assert!(matches!(
+                                            first_body.as_slice(),
+                                            [
+                                                CompoundStatementObject::AssignmentExpression(_),
+                                                CompoundStatementObject::BreakStatement(
+                                                    BreakStatement { .. }
+                                                ),
+                                            ]
+                                        ));
+                                        match second_body.as_slice() {
+                                            [
+                                                CompoundStatementObject::Declaration(Declaration {
+                                                    id: next_id,
+                                                    ..
+                                                }),
+                                                CompoundStatementObject::AssignmentExpression(
+                                                    AssignmentExpression { value, .. },
+                                                ),
+                                                CompoundStatementObject::ContinueStatement(
+                                                    ContinueStatement { .. },
+                                                ),
+                                            ] => match value.as_ref() {
+                                                ExpressionObject::Reference(Reference {
+                                                    declaration_id,
+                                                    ..
+                                                }) => assert_eq!(declaration_id, next_id),
+                                                _ => panic!("AST did not match expected case body"),
+                                            },
+                                            _ => panic!("AST did not match expected case body"),
+                                        }
+                                        assert!(matches!(
+                                            default_body.as_slice(),
+                                            [CompoundStatementObject::AssignmentExpression(_)]
+                                        ));
+                                    }
+                                    _ => panic!("AST did not match expected switch body"),
+                                },
+                                _ => panic!("AST did not match expected switch body"),
+                            }
+                        }
+                        _ => panic!("AST did not match expected while body"),
+                    },
+                    _ => panic!("AST did not match expected while body"),
+                },
+                _ => panic!("AST did not match expected function body"),
+            },
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_parse_struct_and_union_definitions() {
+        let c_code = r#"
+        struct Point {
+            int x;
+            int y;
+        };
+        union Value {
+            int i;
+            float f;
+        };
+        struct Point origin;
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::StructDefinition(StructDefinition {
+                    id: point_id,
+                    span: _,
+                    comments: _,
+                    identifier: point_identifier,
+                    fields: point_fields,
+                    is_forward: false,
+                }),
+                DeclarationObject::UnionDefinition(UnionDefinition {
+                    identifier: value_identifier,
+                    fields: value_fields,
+                    ..
+                }),
+                DeclarationObject::Declaration(Declaration {
+                    primitive_type:
+                        CType::Struct(TypeReference {
+                            declaration_id,
+                            identifier,
+                        }),
+                    identifier: origin_identifier,
+                    value: None,
+                    ..
+                }),
+            ] => {
+                assert_eq!(point_identifier, "Point");
+                assert!(matches!(
+                    point_fields.as_slice(),
+                    [
+                        FieldDeclaration { field_type: CType::Int, identifier: x, .. },
+                        FieldDeclaration { field_type: CType::Int, identifier: y, .. },
+                    ] if x == "x" && y == "y"
+                ));
+                assert_eq!(value_identifier, "Value");
+                assert!(matches!(
+                    value_fields.as_slice(),
+                    [
+                        FieldDeclaration {
+                            field_type: CType::Int,
+                            ..
+                        },
+                        FieldDeclaration {
+                            field_type: CType::Float,
+                            ..
+                        },
+                    ]
+                ));
+                assert_eq!(declaration_id, point_id);
+                assert_eq!(identifier, "Point");
+                assert_eq!(origin_identifier, "origin");
+            }
+            _ => panic!("AST did not match expected struct and union definitions"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(text.contains("struct Point origin;"));
+    }
+
+    #[test]
+    fn test_parse_enum_definition() {
+        let c_code = r#"
+        enum Color {
+            RED,
+            GREEN = 4,
+            BLUE
+        };
+        int main() {
+            enum Color c = GREEN;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::EnumDefinition(EnumDefinition {
+                    id: color_id,
+                    span: _,
+                    comments: _,
+                    identifier: color_identifier,
+                    enumerators,
+                    is_forward: false,
+                }),
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => {
+                assert_eq!(color_identifier, "Color");
+                match enumerators.as_slice() {
+                    [
+                        Enumerator { value: None, .. },
+                        Enumerator {
+                            id: green_id,
+                            span: _,
+                            comments: _,
+                            identifier: green_identifier,
+                            value: Some(_),
+                        },
+                        Enumerator { value: None, .. },
+                    ] => {
+                        assert_eq!(green_identifier, "GREEN");
+                        match code_block.as_slice() {
+                            [
+                                CompoundStatementObject::Declaration(Declaration {
+                                    primitive_type:
+                                        CType::Enum(TypeReference { declaration_id, .. }),
+                                    value: Some(value),
+                                    ..
+                                }),
+                            ] => {
+                                assert_eq!(declaration_id, color_id);
+                                match value.as_ref() {
+                                    ExpressionObject::Reference(Reference {
+                                        declaration_id,
+                                        ..
+                                    }) => assert_eq!(declaration_id, green_id),
+                                    _ => panic!("AST did not match expected enumerator reference"),
+                                }
+                            }
+                            _ => panic!("AST did not match expected enum declaration"),
+                        }
+                    }
+                    _ => panic!("AST did not match expected enumerators"),
+                }
+            }
+            _ => panic!("AST did not match expected enum definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+    }
+
+    #[test]
+    fn test_undeclared_struct_type() {
+        // A struct never defined in the file is kept by name, like a type name without a typedef
+        let c_code = "struct Missing *value;";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+        let [DeclarationObject::Declaration(value)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        let CType::Pointer(pointee) = &value.primitive_type else {
+            panic!("expected a pointer, got {:?}", value.primitive_type);
+        };
+        let CType::Struct(missing) = pointee.as_ref() else {
+            panic!("expected a struct, got {pointee:?}");
+        };
+        assert_eq!(missing.identifier, "Missing");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(
+            c_language.write_to_text(parsed_objects).unwrap(),
+            "struct Missing *value;\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_tags_used_before_their_definition() {
+        let c_code = r#"
+struct s *p;
+struct a {
+    struct b *next;
+};
+typedef struct node node_t;
+struct node {
+    node_t *next;
+};
+struct b {
+    int value;
+};
+struct s {
+    int a;
+} g = {1};
+int
+main()
+{
+    struct local {
+        int x;
+    } v;
+    return p->a + v.x;
+}
+"#;
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        // Definitions that declare something too are split in the definition and the declaration
+        let [
+            DeclarationObject::Declaration(p),
+            DeclarationObject::StructDefinition(a),
+            DeclarationObject::TypeDefinition(node_t),
+            DeclarationObject::StructDefinition(node),
+            DeclarationObject::StructDefinition(b),
+            DeclarationObject::StructDefinition(s),
+            DeclarationObject::Declaration(g),
+            DeclarationObject::FunctionDefinition(main),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        let reference = |definition_id: Uuid, identifier: &str| TypeReference {
+            declaration_id: definition_id,
+            identifier: identifier.to_string(),
+        };
+        // Uses before the definition are bound to it
+        assert_eq!(
+            p.primitive_type,
+            CType::Pointer(Box::new(CType::Struct(reference(s.id, "s"))))
+        );
+        assert_eq!(
+            a.fields[0].field_type,
+            CType::Pointer(Box::new(CType::Struct(reference(b.id, "b"))))
+        );
+        assert_eq!(
+            node_t.aliased_type,
+            CType::Struct(reference(node.id, "node"))
+        );
+        assert_eq!(g.primitive_type, CType::Struct(reference(s.id, "s")));
+        let [
+            CompoundStatementObject::StructDefinition(local),
+            CompoundStatementObject::Declaration(v),
+            ..,
+        ] = main.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", main.compound_statement);
+        };
+        assert_eq!(
+            v.primitive_type,
+            CType::Struct(reference(local.id, "local"))
+        );
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        let text = c_language.write_to_text(parsed_objects).unwrap();
+        for line in [
+            "struct s *p;",
+            "typedef struct node node_t;",
+            "struct s g = {1};",
+            "    struct local v;",
+        ] {
+            assert!(text.contains(line), "{text}");
+        }
+        // The written text parses to the same objects
+        let reparsed = c_language.parse_text(&text).unwrap();
+        assert_eq!(c_language.write_to_text(reparsed).unwrap(), text);
+    }
+
+    #[test]
+    fn test_parse_grouped_fields() {
+        let c_code = "struct p {\n    int x, y;\n    char *name, tag[4];\n    int z;\n};\n";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+        let [DeclarationObject::StructDefinition(p)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        let shared = p
+            .fields
+            .iter()
+            .map(|field| (field.identifier.as_str(), field.shares_declaration))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shared,
+            [
+                ("x", false),
+                ("y", true),
+                ("name", false),
+                ("tag", true),
+                ("z", false),
+            ]
+        );
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+
+        // A field whose type no longer shares the specifier is declared apart
+        let mut edited = src_file;
+        let DeclarationObject::StructDefinition(p) = &mut edited.code[0] else {
+            panic!("expected a struct definition");
+        };
+        p.fields[1].field_type = CType::Float;
+        let text = c_language.write_to_text(edited).unwrap();
+        assert!(text.contains("    int x;\n    float y;\n"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_forward_declarations_of_tags() {
+        let c_code = "struct s;\nunion u;\nenum e;\nstruct s *p;\nstruct s {\n    int a;\n};\nstruct s;\nstruct opaque;\n";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+
+        let [
+            DeclarationObject::StructDefinition(forward),
+            DeclarationObject::UnionDefinition(u),
+            DeclarationObject::EnumDefinition(e),
+            DeclarationObject::Declaration(p),
+            DeclarationObject::StructDefinition(s),
+            DeclarationObject::StructDefinition(redeclared),
+            DeclarationObject::StructDefinition(opaque),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert!(forward.is_forward && u.is_forward && e.is_forward);
+        assert!(redeclared.is_forward && opaque.is_forward);
+        assert!(!s.is_forward);
+        // The use after the forward declaration is bound to the definition
+        assert_eq!(
+            p.primitive_type,
+            CType::Pointer(Box::new(CType::Struct(TypeReference {
+                declaration_id: s.id,
+                identifier: "s".to_string(),
+            })))
+        );
+
+        assert_eq!(c_language.write_to_text(src_file.clone()).unwrap(), c_code);
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(parsed_nodes, src_file);
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_pointer_array_and_qualified_types() {
+        let c_code = r#"
+        int main(int argc, char *argv[]) {
+            const int limit = 10;
+            unsigned long total;
+            char *const name;
+            const char *message;
+            int *values[4];
+            int (*matrix)[3];
+            size_t length;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let const_qualifier = Qualifiers {
+            is_const: true,
+            ..Default::default()
+        };
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    parameter_list,
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => {
+                assert_eq!(
+                    parameter_list[1].param_type,
+                    CType::Array(ArrayType {
+                        element: Box::new(CType::Pointer(Box::new(CType::Char))),
+                        size: None,
+                        qualifiers: Qualifiers::default(),
+                        is_static: false,
+                    })
+                );
+                let types = code_block
+                    .iter()
+                    .map(|object| match object {
+                        CompoundStatementObject::Declaration(Declaration {
+                            primitive_type,
+                            ..
+                        }) => primitive_type.clone(),
+                        _ => panic!("AST did not match expected declarations"),
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(
+                    types,
+                    vec![
+                        CType::Qualified(const_qualifier, Box::new(CType::Int)),
+                        CType::Sized(SizedType {
+                            signedness: Some(Signedness::Unsigned),
+                            length: Some(Length::Long),
+                            base: None,
+                        }),
+                        CType::Qualified(
+                            const_qualifier,
+                            Box::new(CType::Pointer(Box::new(CType::Char)))
+                        ),
+                        CType::Pointer(Box::new(CType::Qualified(
+                            const_qualifier,
+                            Box::new(CType::Char)
+                        ))),
+                        CType::Array(ArrayType {
+                            element: Box::new(CType::Pointer(Box::new(CType::Int))),
+                            size: Some("4".to_string()),
+                            qualifiers: Qualifiers::default(),
+                            is_static: false,
+                        }),
+                        CType::Pointer(Box::new(CType::Array(ArrayType {
+                            element: Box::new(CType::Int),
+                            size: Some("3".to_string()),
+                            qualifiers: Qualifiers::default(),
+                            is_static: false,
+                        }))),
+                        CType::Typedef("size_t".to_string()),
+                    ]
+                );
+            }
+            _ => panic!("AST did not match expected function definition"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for declaration in [
+            "char *argv[]",
+            "const int limit = 10;",
+            "unsigned long total;",
+            "char *const name;",
+            "const char *message;",
+            "int *values[4];",
+            "int (*matrix)[3];",
+            "size_t length;",
+        ] {
+            assert!(text.contains(declaration), "missing `{declaration}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_array_sizes_as_written() {
+        let c_code = r#"
+        #define N 8
+        #define BUF_LEN 16
+        int x;
+        char buf[N];
+        char hex[0x10];
+        char line[BUF_LEN + 1];
+        char copy[sizeof x];
+        int sum(int values[static 4], int rows[const 2]) {
+            return 0;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let char_array = |size: &str| {
+            CType::Array(ArrayType {
+                element: Box::new(CType::Char),
+                size: Some(size.to_string()),
+                qualifiers: Qualifiers::default(),
+                is_static: false,
+            })
+        };
+        let declarations = src_file
+            .code
+            .iter()
+            .filter_map(|object| match object {
+                DeclarationObject::Declaration(Declaration {
+                    identifier,
+                    primitive_type,
+                    ..
+                }) => Some((identifier.as_str(), primitive_type.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            declarations,
+            vec![
+                ("x", CType::Int),
+                ("buf", char_array("N")),
+                ("hex", char_array("0x10")),
+                ("line", char_array("BUF_LEN + 1")),
+                ("copy", char_array("sizeof x")),
+            ]
+        );
+        let Some(DeclarationObject::FunctionDefinition(FunctionDefinition {
+            parameter_list, ..
+        })) = src_file.code.last()
+        else {
+            panic!("AST did not match expected function definition");
+        };
+        assert_eq!(
+            parameter_list
+                .iter()
+                .map(|parameter| parameter.param_type.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                CType::Array(ArrayType {
+                    element: Box::new(CType::Int),
+                    size: Some("4".to_string()),
+                    qualifiers: Qualifiers::default(),
+                    is_static: true,
+                }),
+                CType::Array(ArrayType {
+                    element: Box::new(CType::Int),
+                    size: Some("2".to_string()),
+                    qualifiers: Qualifiers {
+                        is_const: true,
+                        ..Default::default()
+                    },
+                    is_static: false,
+                }),
+            ]
+        );
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for declaration in [
+            "char buf[N];",
+            "char hex[0x10];",
+            "char line[BUF_LEN + 1];",
+            "char copy[sizeof x];",
+            "int values[static 4]",
+            "int rows[const 2]",
+        ] {
+            assert!(text.contains(declaration), "missing `{declaration}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_function_pointer_types() {
+        let c_code = r#"
+        struct Handler {
+            int (*callback)(int, char);
+            void (*handlers[2])(void);
+            int (*log_fn)(const char *, ...);
+        };
+        int (*get(int k))(void);
+        int apply(int (*operation)(int value), int value) {
+            int (*copy)(int) = operation;
+            return value;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let unnamed = |param_type| FnParameter {
+            param_type,
+            identifier: None,
+        };
+        let fn_type = |return_type, parameters| FnType {
+            return_type: Box::new(return_type),
+            parameters,
+            is_variadic: false,
+            explicit_void: false,
+        };
+        let callback_type = CType::Pointer(Box::new(CType::Fn(fn_type(
+            CType::Int,
+            vec![unnamed(CType::Int), unnamed(CType::Char)],
+        ))));
+        let operation_type = CType::Pointer(Box::new(CType::Fn(fn_type(
+            CType::Int,
+            vec![FnParameter {
+                param_type: CType::Int,
+                identifier: Some("value".to_string()),
+            }],
+        ))));
+        let no_parameters = |return_type| FnType {
+            explicit_void: true,
+            ..fn_type(return_type, vec![])
+        };
+        match src_file.code.as_slice() {
+            [
+                DeclarationObject::StructDefinition(StructDefinition { fields, .. }),
+                DeclarationObject::FunctionDeclaration(get),
+                DeclarationObject::FunctionDefinition(FunctionDefinition {
+                    parameter_list,
+                    compound_statement: CompoundStatement { code_block, .. },
+                    ..
+                }),
+            ] => {
+                assert_eq!(fields[0].field_type, callback_type);
+                assert_eq!(
+                    fields[1].field_type,
+                    CType::Array(ArrayType {
+                        element: Box::new(CType::Pointer(Box::new(CType::Fn(no_parameters(
+                            CType::Void
+                        ))))),
+                        size: Some("2".to_string()),
+                        qualifiers: Qualifiers::default(),
+                        is_static: false,
+                    })
+                );
+                assert_eq!(
+                    fields[2].field_type,
+                    CType::Pointer(Box::new(CType::Fn(FnType {
+                        is_variadic: true,
+                        ..fn_type(
+                            CType::Int,
+                            vec![unnamed(CType::Pointer(Box::new(CType::Qualified(
+                                Qualifiers {
+                                    is_const: true,
+                                    ..Default::default()
+                                },
+                                Box::new(CType::Char)
+                            ))))]
+                        )
+                    })))
+                );
+                assert_eq!(get.identifier, "get");
+                assert_eq!(
+                    get.return_type,
+                    CType::Pointer(Box::new(CType::Fn(no_parameters(CType::Int))))
+                );
+                assert_eq!(get.parameter_list[0].identifier, "k");
+                assert_eq!(parameter_list[0].identifier, "operation");
+                assert_eq!(parameter_list[0].param_type, operation_type);
+                match code_block.as_slice() {
+                    [
+                        CompoundStatementObject::Declaration(Declaration {
+                            primitive_type,
+                            identifier,
+                            ..
+                        }),
+                        CompoundStatementObject::ReturnStatement(_),
+                    ] => {
+                        assert_eq!(identifier, "copy");
+                        assert_eq!(
+                            *primitive_type,
+                            CType::Pointer(Box::new(CType::Fn(fn_type(
+                                CType::Int,
+                                vec![unnamed(CType::Int)]
+                            ))))
+                        );
+                    }
+                    _ => panic!("AST did not match expected function body"),
+                }
+            }
+            _ => panic!("AST did not match expected definitions"),
+        }
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for declaration in [
+            "int (*callback)(int, char);",
+            "void (*handlers[2])(void);",
+            "int (*log_fn)(const char *, ...);",
+            "int\n(*get(int k))(void);",
+            "int (*operation)(int value)",
+            "int (*copy)(int) = operation;",
+        ] {
+            assert!(text.contains(declaration), "missing `{declaration}`");
+        }
+    }
+
+    #[test]
+    fn test_typedef_callback_round_trip() {
+        let c_code = "typedef void (*handler_t)(int);
+struct widget {
+    handler_t on_click;
+};
+void
+attach(struct widget *w, handler_t handler);
+
+";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::TypeDefinition(handler_t),
+            DeclarationObject::StructDefinition(widget),
+            DeclarationObject::FunctionDeclaration(attach),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        let handler_type = CType::Named {
+            declaration_id: handler_t.id,
+            name: "handler_t".to_string(),
+        };
+        assert_eq!(widget.fields[0].field_type, handler_type);
+        assert_eq!(attach.parameter_list[1].param_type, handler_type);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_unary_operators_keep_apart_from_their_argument() {
+        let c_code = "int\nf(int x)\n{\n    int a = - -x;\n    int b = + +x;\n    int c = - --x;\n    int d = + ++x;\n    int e = -x--;\n    int *p = &x;\n    return !-a;\n}\n\n";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        // Written together, `- -x` would be `--x` and `- --x` would not compile
+        assert_eq!(c_language.write_to_text(src_file.clone()).unwrap(), c_code);
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_unary_update_cast_sizeof_and_conditional_expressions() {
+        let c_code = r#"
+        int clamp(int value, int limit) {
+            int *pointer = &value;
+            int negative = -*pointer;
+            value++;
+            --limit;
+            long wide = (long)value;
+            unsigned int size = sizeof(int);
+            unsigned int width = sizeof value;
+            return value > limit ? limit : value;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let DeclarationObject::FunctionDefinition(FunctionDefinition {
+            parameter_list,
+            compound_statement: CompoundStatement { code_block, .. },
+            ..
+        }) = &src_file.code[0]
+        else {
+            panic!("AST did not match expected function definition");
+        };
+        let CompoundStatementObject::Declaration(pointer) = &code_block[0] else {
+            panic!("AST did not match expected pointer declaration");
+        };
+        let reference = |identifier: &str| {
+            let declaration_id = match identifier {
+                "pointer" => pointer.id,
+                _ => {
+                    parameter_list
+                        .iter()
+                        .find(|parameter| parameter.identifier == identifier)
+                        .unwrap()
+                        .id
+                }
+            };
+            Box::new(ExpressionObject::Reference(Reference {
+                id: Uuid::new_v4(),
+                span: None,
+                comments: Comments::default(),
+                declaration_id,
+                identifier: identifier.to_string(),
+            }))
+        };
+        let values = code_block
+            .iter()
+            .filter_map(|object| match object {
+                CompoundStatementObject::Declaration(Declaration { value, .. }) => {
+                    value.as_deref().cloned()
+                }
+                CompoundStatementObject::ReturnStatement(ReturnStatement { value, .. }) => {
+                    value.clone()
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                ExpressionObject::UnaryExpression(UnaryExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    operator: "&".to_string(),
+                    argument: reference("value"),
+                }),
+                ExpressionObject::UnaryExpression(UnaryExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    operator: "-".to_string(),
+                    argument: Box::new(ExpressionObject::UnaryExpression(UnaryExpression {
+                        id: Uuid::new_v4(),
+                        span: None,
+                        comments: Comments::default(),
+                        operator: "*".to_string(),
+                        argument: reference("pointer"),
+                    })),
+                }),
+                ExpressionObject::CastExpression(CastExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    cast_type: CType::Sized(SizedType {
+                        signedness: None,
+                        length: Some(Length::Long),
+                        base: None,
+                    }),
+                    value: reference("value"),
+                }),
+                ExpressionObject::SizeofExpression(SizeofExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    argument_type: Some(CType::Int),
+                    value: None,
+                }),
+                ExpressionObject::SizeofExpression(SizeofExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    argument_type: None,
+                    value: Some(reference("value")),
+                }),
+                ExpressionObject::ConditionalExpression(ConditionalExpression {
+                    id: Uuid::new_v4(),
+                    span: None,
+                    comments: Comments::default(),
+                    condition: Box::new(ExpressionObject::BinaryExpression(BinaryExpression {
+                        id: Uuid::new_v4(),
+                        span: None,
+                        comments: Comments::default(),
+                        left: reference("value"),
+                        operator: ">".to_string(),
+                        right: reference("limit"),
+                    })),
+                    consequence: reference("limit"),
+                    alternative: reference("value"),
+                }),
+            ]
+        );
+        match &code_block[2..4] {
+            [
+                CompoundStatementObject::UpdateExpression(UpdateExpression {
+                    operator: increment,
+                    is_prefix: false,
+                    ..
+                }),
+                CompoundStatementObject::UpdateExpression(UpdateExpression {
+                    operator: decrement,
+                    is_prefix: true,
+                    ..
+                }),
+            ] => {
+                assert_eq!(increment, "++");
+                assert_eq!(decrement, "--");
+            }
+            _ => panic!("AST did not match expected update expressions"),
+        }
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "int *pointer = &value;",
+            "int negative = -*pointer;",
+            "value++;",
+            "--limit;",
+            "long wide = (long)value;",
+            "unsigned int size = sizeof(int);",
+            "unsigned int width = sizeof(value);",
+            "return value > limit ? limit : value;",
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_field_subscript_and_parenthesized_expressions() {
+        let c_code = r#"
+        struct Node {
+            int value;
+            struct Node *next;
+        };
+        int sum(struct Node node, struct Node *head, int values[], Wrapper wrapper, int i) {
+            int first = node.value;
+            int second = head->next->value;
+            int third = values[i];
+            int fourth = (first + second) * third;
+            return wrapper.count;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::StructDefinition(StructDefinition { fields, .. }),
+            DeclarationObject::FunctionDefinition(FunctionDefinition {
+                compound_statement: CompoundStatement { code_block, .. },
+                ..
+            }),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected struct and function definitions");
+        };
+        let [value_field, next_field] = fields.as_slice() else {
+            panic!("AST did not match expected struct fields");
+        };
+        let values = code_block
+            .iter()
+            .filter_map(|object| match object {
+                CompoundStatementObject::Declaration(Declaration { value, .. }) => {
+                    value.as_deref().cloned()
+                }
+                CompoundStatementObject::ReturnStatement(ReturnStatement { value, .. }) => {
+                    value.clone()
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        match values.as_slice() {
+            [
+                ExpressionObject::FieldExpression(first),
+                ExpressionObject::FieldExpression(second),
+                ExpressionObject::SubscriptExpression(SubscriptExpression {
+                    argument: subscript_argument,
+                    index,
+                    ..
+                }),
+                ExpressionObject::BinaryExpression(BinaryExpression { left, operator, .. }),
+                ExpressionObject::FieldExpression(unknown),
+            ] => {
+                assert_eq!(first.operator, ".");
+                assert_eq!(first.declaration_id, value_field.id);
+                assert_eq!(first.identifier, "value");
+
+                assert_eq!(second.operator, "->");
+                assert_eq!(second.declaration_id, value_field.id);
+                let ExpressionObject::FieldExpression(inner) = second.argument.as_ref() else {
+                    panic!("AST did not match expected nested field expression");
+                };
+                assert_eq!(inner.operator, "->");
+                assert_eq!(inner.declaration_id, next_field.id);
+                assert_eq!(inner.identifier, "next");
+
+                assert!(matches!(
+                    (subscript_argument.as_ref(), index.as_ref()),
+                    (ExpressionObject::Reference(values), ExpressionObject::Reference(i))
+                        if values.identifier == "values" && i.identifier == "i"
+                ));
+
+                assert_eq!(operator, "*");
+                assert!(matches!(
+                    left.as_ref(),
+                    ExpressionObject::ParenthesizedExpression(ParenthesizedExpression { value, .. })
+                        if matches!(value.as_ref(), ExpressionObject::BinaryExpression(_))
+                ));
+
+                assert_eq!(unknown.declaration_id, Uuid::nil());
+                assert_eq!(unknown.identifier, "count");
+            }
+            _ => panic!("AST did not match expected expressions"),
+        }
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "int first = node.value;",
+            "int second = head->next->value;",
+            "int third = values[i];",
+            "int fourth = (first + second) * third;",
+            "return wrapper.count;",
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_assignments_to_fields_subscripts_and_pointers() {
+        let c_code = r#"
+        struct pair {
+            int a;
+            int b;
+        };
+        void reset(struct pair v, struct pair *p, int a[], int *q) {
+            v.a = 1;
+            p->a = 2;
+            a[0] = 1;
+            *q = 1;
+        }
+        "#;
+        let c_language = C::new();
+        let (src_file, diagnostics) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        let [
+            DeclarationObject::StructDefinition(StructDefinition { fields, .. }),
+            DeclarationObject::FunctionDefinition(FunctionDefinition {
+                compound_statement: CompoundStatement { code_block, .. },
+                ..
+            }),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected struct and function definitions");
+        };
+        let lefts = code_block
+            .iter()
+            .map(|object| match object {
+                CompoundStatementObject::AssignmentExpression(AssignmentExpression {
+                    left,
+                    ..
+                }) => left.as_ref(),
+                _ => panic!("AST did not match expected assignments"),
+            })
+            .collect::<Vec<_>>();
+        match lefts.as_slice() {
+            [
+                ExpressionObject::FieldExpression(dot),
+                ExpressionObject::FieldExpression(arrow),
+                ExpressionObject::SubscriptExpression(SubscriptExpression { argument, .. }),
+                ExpressionObject::UnaryExpression(UnaryExpression {
+                    operator,
+                    argument: dereferenced,
+                    ..
+                }),
+            ] => {
+                assert_eq!(dot.operator, ".");
+                assert_eq!(dot.declaration_id, fields[0].id);
+                assert_eq!(arrow.operator, "->");
+                assert_eq!(arrow.declaration_id, fields[0].id);
+                assert_eq!(assigned_name(argument).1, "a");
+                assert_eq!(operator, "*");
+                assert_eq!(assigned_name(dereferenced).1, "q");
+            }
+            _ => panic!("AST did not match expected assignment targets"),
+        }
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in ["v.a = 1;", "p->a = 2;", "a[0] = 1;", "*q = 1;"] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_assignments_to_undeclared_names() {
+        let c_code = "void\ns(void)\n{\n    cur = 1;\n}\n\n";
+        let c_language = C::new();
+        let (src_file, diagnostics) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        // Names declared outside the file are kept by name, as calls to undeclared functions
+        let DeclarationObject::FunctionDefinition(function) = &src_file.code[0] else {
+            panic!("expected a function definition");
+        };
+        let [CompoundStatementObject::AssignmentExpression(assignment)] =
+            function.compound_statement.code_block.as_slice()
+        else {
+            panic!(
+                "unexpected function body: {:?}",
+                function.compound_statement
+            );
+        };
+        assert_eq!(
+            assigned_name(&assignment.left),
+            (&Uuid::nil(), &"cur".to_string())
+        );
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+
+        // A reference to a declaration the nodes don't have is an error, not a panic
+        let mut dangling = src_file;
+        let DeclarationObject::FunctionDefinition(function) = &mut dangling.code[0] else {
+            panic!("expected a function definition");
+        };
+        let CompoundStatementObject::AssignmentExpression(assignment) =
+            &mut function.compound_statement.code_block[0]
+        else {
+            panic!("expected an assignment");
+        };
+        let ExpressionObject::Reference(reference) = assignment.left.as_mut() else {
+            panic!("expected a reference");
+        };
+        reference.declaration_id = Uuid::new_v4();
+        let nodes = c_language.write_to_nodes(dangling).unwrap();
+        assert!(c_language.parse_nodes(nodes).is_err());
+    }
+
+    #[test]
+    fn test_parse_redeclared_variables() {
+        let c_code = "extern int x;\nint x = 1;\nint y;\nchar y;\nint\nget(void)\n{\n    return x + y;\n}\n\n";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        let [
+            DeclarationObject::Declaration(declared),
+            DeclarationObject::Declaration(defined),
+            DeclarationObject::Declaration(y),
+            DeclarationObject::Unknown(char_y),
+            DeclarationObject::FunctionDefinition(get),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        // Both declarations of `x` are the same variable
+        assert_eq!(declared.id, defined.id);
+        let [
+            CompoundStatementObject::ReturnStatement(ReturnStatement {
+                value: Some(ExpressionObject::BinaryExpression(sum)),
+                ..
+            }),
+        ] = get.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", get.compound_statement);
+        };
+        let (ExpressionObject::Reference(left), ExpressionObject::Reference(right)) =
+            (sum.left.as_ref(), sum.right.as_ref())
+        else {
+            panic!("expected references: {sum:?}");
+        };
+        assert_eq!(left.declaration_id, defined.id);
+        assert_eq!(right.declaration_id, y.id);
+
+        // Declared again with another type, it is kept as written
+        assert_eq!(char_y.content, "char y;");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "wrong-type");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_unknown_struct_member() {
+        let c_code = r#"
+        struct Point {
+            int x;
+        };
+        int get(struct Point point) {
+            return point.y;
+        }
+        "#;
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        let [_, DeclarationObject::FunctionDefinition(get)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        // Only the member access is kept as written
+        let [
+            CompoundStatementObject::ReturnStatement(ReturnStatement {
+                value: Some(ExpressionObject::Unknown(member)),
+                ..
+            }),
+        ] = get.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", get.compound_statement);
+        };
+        assert_eq!(member.content, "point.y");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "missing-symbol");
+        assert_eq!(warnings[0].message, "Missing symbol: y");
+    }
+
+    #[test]
+    fn test_parse_preprocessor_directives() {
+        let c_code = r#"
+        #ifndef CONFIG_H
+        #define CONFIG_H
+        #define LIMIT 10
+        #define SQUARE(x) ((x) * (x))
+        #pragma once
+        #if LIMIT > 5
+        int level = LIMIT;
+        #elif defined(TRACE)
+        int level = 1;
+        #else
+        int level = 0;
+        #endif
+        int area(int side) {
+        #ifdef DEBUG
+            side = side + 1;
+        #endif
+            return SQUARE(side);
+        }
+        #endif
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::PreprocIf(PreprocIf {
+                directive,
+                condition,
+                body,
+                alternative: None,
+                ..
+            }),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected include guard");
+        };
+        assert_eq!(directive, "#ifndef");
+        assert_eq!(condition, "CONFIG_H");
+
+        let [
+            DeclarationObject::PreprocDefine(guard),
+            DeclarationObject::PreprocDefine(limit),
+            DeclarationObject::PreprocDefine(square),
+            DeclarationObject::PreprocCall(pragma),
+            DeclarationObject::PreprocIf(level),
+            DeclarationObject::FunctionDefinition(FunctionDefinition {
+                compound_statement: CompoundStatement { code_block, .. },
+                ..
+            }),
+        ] = body.as_slice()
+        else {
+            panic!("AST did not match expected guarded declarations");
+        };
+        assert_eq!(guard.identifier, "CONFIG_H");
+        assert_eq!(guard.parameters, None);
+        assert_eq!(guard.value, "");
+        assert_eq!(limit.value, "10");
+        assert_eq!(square.parameters, Some(vec!["x".to_string()]));
+        assert_eq!(square.value, "((x) * (x))");
+        assert_eq!(pragma.directive, "#pragma");
+        assert_eq!(pragma.argument, "once");
+
+        assert_eq!(level.directive, "#if");
+        assert_eq!(level.condition, "LIMIT > 5");
+        let [DeclarationObject::Declaration(Declaration { value, .. })] = level.body.as_slice()
+        else {
+            panic!("AST did not match expected #if branch");
+        };
+        assert!(matches!(
+            value.as_deref(),
+            Some(ExpressionObject::Reference(Reference { declaration_id, .. }))
+                if *declaration_id == limit.id
+        ));
+        let elif = level.alternative.as_ref().unwrap();
+        assert_eq!(elif.directive, "#elif");
+        assert_eq!(elif.condition, "defined(TRACE)");
+        let otherwise = elif.alternative.as_ref().unwrap();
+        assert_eq!(otherwise.directive, "#else");
+        assert_eq!(otherwise.condition, "");
+        assert!(otherwise.alternative.is_none());
+
+        match code_block.as_slice() {
+            [
+                CompoundStatementObject::PreprocIfStatement(PreprocIfStatement {
+                    directive,
+                    condition,
+                    body,
+                    alternative: None,
+                    ..
+                }),
+                CompoundStatementObject::ReturnStatement(ReturnStatement {
+                    value: Some(ExpressionObject::CallExpression(call)),
+                    ..
+                }),
+            ] => {
+                assert_eq!(directive, "#ifdef");
+                assert_eq!(condition, "DEBUG");
+                assert!(matches!(
+                    body.as_slice(),
+                    [CompoundStatementObject::AssignmentExpression(_)]
+                ));
+                assert_eq!(call.id_declaration, square.id);
+            }
+            _ => panic!("AST did not match expected function body"),
+        }
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        for line in [
+            "#ifndef CONFIG_H\n",
+            "#define CONFIG_H\n",
+            "#define LIMIT 10\n",
+            "#define SQUARE(x) ((x) * (x))\n",
+            "#pragma once\n",
+            "#if LIMIT > 5\n",
+            "#elif defined(TRACE)\n",
+            "#else\n",
+            "#ifdef DEBUG\n",
+            "#endif\n",
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+        assert!(c_language.parse_text(&text).is_ok());
+    }
+
+    #[test]
+    fn test_parse_storage_class_specifiers() {
+        let c_code = r#"
+        static const int limit = 10;
+        extern int counter;
+        thread_local int depth;
+        static inline int twice(int value);
+        inline static int square(int value) {
+            register int result = value * value;
+            return result;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::Declaration(limit),
+            DeclarationObject::Declaration(counter),
+            DeclarationObject::Declaration(depth),
+            DeclarationObject::FunctionDeclaration(twice),
+            DeclarationObject::FunctionDefinition(square),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected declarations");
+        };
+        assert_eq!(limit.specifiers.keywords(), ["static"]);
+        assert_eq!(
+            limit.primitive_type,
+            CType::Int.qualified(Qualifiers {
+                is_const: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(counter.specifiers.keywords(), ["extern"]);
+        assert_eq!(depth.specifiers.keywords(), ["thread_local"]);
+        assert_eq!(twice.specifiers.keywords(), ["static", "inline"]);
+        assert_eq!(square.specifiers.keywords(), ["static", "inline"]);
+        let [CompoundStatementObject::Declaration(result), _] =
+            square.compound_statement.code_block.as_slice()
+        else {
+            panic!("AST did not match expected function body");
+        };
+        assert_eq!(result.specifiers.keywords(), ["register"]);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "static const int limit = 10;",
+            "extern int counter;",
+            "thread_local int depth;",
+            "static inline int\ntwice(int value);",
+            "static inline int\nsquare(int value)",
+            "register int result = value * value;",
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_declaration_lists_and_initializer_lists() {
+        let c_code = r#"
+        struct Point { int x; int y[3]; };
+        int a = 1, *b, c[3] = {1, 2, 3,};
+        struct Point origin = {.x = 1, .y[2] = 5};
+        int squares[4] = {[3] = 9, 1};
+        int main() {
+            for (int i = 0, j = 10; i < j; i++) {
+            }
+            return a;
+        }
+        "#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::StructDefinition(_),
+            DeclarationObject::DeclarationList(DeclarationList { declarations, .. }),
+            DeclarationObject::Declaration(origin),
+            DeclarationObject::Declaration(squares),
+            DeclarationObject::FunctionDefinition(main),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected declarations");
+        };
+        let [a, b, c] = declarations.as_slice() else {
+            panic!("expected three declarators");
+        };
+        assert_eq!(a.identifier, "a");
+        assert_eq!(a.primitive_type, CType::Int);
+        assert_eq!(b.identifier, "b");
+        assert_eq!(b.primitive_type, CType::Pointer(Box::new(CType::Int)));
+        assert_eq!(b.value, None);
+        assert_eq!(
+            c.primitive_type,
+            CType::Array(ArrayType {
+                element: Box::new(CType::Int),
+                size: Some("3".to_string()),
+                qualifiers: Qualifiers::default(),
+                is_static: false,
+            })
+        );
+        let Some(ExpressionObject::InitializerList(InitializerList { elements, .. })) =
+            c.value.as_deref()
+        else {
+            panic!("expected an initializer list");
+        };
+        assert_eq!(elements.len(), 3);
+
+        let Some(ExpressionObject::InitializerList(InitializerList { elements, .. })) =
+            origin.value.as_deref()
+        else {
+            panic!("expected an initializer list");
+        };
+        let [
+            ExpressionObject::InitializerPair(InitializerPair {
+                designators: x_designators,
+                ..
+            }),
+            ExpressionObject::InitializerPair(InitializerPair {
+                designators: y_designators,
+                ..
+            }),
+        ] = elements.as_slice()
+        else {
+            panic!("expected two designated initializers");
+        };
+        assert_eq!(x_designators, &[Designator::Field("x".to_string())]);
+        assert!(matches!(
+            y_designators.as_slice(),
+            [
+                Designator::Field(field),
+                Designator::Subscript(ExpressionObject::NumberLiteral(NumberLiteral { spelling: value, .. })),
+            ] if field == "y" && value == "2"
+        ));
+
+        let Some(ExpressionObject::InitializerList(InitializerList { elements, .. })) =
+            squares.value.as_deref()
+        else {
+            panic!("expected an initializer list");
+        };
+        assert!(matches!(
+            elements.as_slice(),
+            [
+                ExpressionObject::InitializerPair(_),
+                ExpressionObject::NumberLiteral(_)
+            ]
+        ));
+
+        let [
+            CompoundStatementObject::ForStatement(for_statement),
+            CompoundStatementObject::ReturnStatement(return_statement),
+        ] = main.compound_statement.code_block.as_slice()
+        else {
+            panic!("AST did not match expected function body");
+        };
+        let Some(CompoundStatementObject::DeclarationList(initializer)) =
+            for_statement.initializer.as_deref()
+        else {
+            panic!("expected the loop to declare two variables");
+        };
+        assert_eq!(initializer.declarations.len(), 2);
+        // Every declarator is its own symbol
+        let Some(ExpressionObject::Reference(reference)) = &return_statement.value else {
+            panic!("expected a reference to a");
+        };
+        assert_eq!(reference.declaration_id, a.id);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "int a = 1, *b, c[3] = {1, 2, 3};",
+            "struct Point origin = {.x = 1, .y[2] = 5};",
+            "int squares[4] = {[3] = 9, 1};",
+            "for (int i = 0, j = 10; i < j; i++)",
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+    }
+
+    #[test]
+    fn test_parse_char_and_string_literals() {
+        let c_code = r#"
+        char c = 'a', nl = '\n', quote = '\'';
+        int wide = L'x';
+        char *s = L"tab\there" " and \x41" u8"!";
+        char *octal = "\101\"q\"";
+        "#;
+        let c_language = C::new();
+        let mut src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::DeclarationList(DeclarationList { declarations, .. }),
+            DeclarationObject::Declaration(wide),
+            DeclarationObject::Declaration(s),
+            DeclarationObject::Declaration(octal),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("AST did not match expected declarations");
+        };
+        let chars: Vec<&CharLiteral> = declarations
+            .iter()
+            .chain([wide])
+            .map(|declaration| match declaration.value.as_deref() {
+                Some(ExpressionObject::CharLiteral(char_literal)) => char_literal,
+                other => panic!("expected a char literal, got {other:?}"),
+            })
+            .collect();
+        let [c, nl, quote, wide] = chars.as_slice() else {
+            panic!("expected four char literals");
+        };
+        assert_eq!(c.value, "a");
+        assert_eq!(nl.value, "\n");
+        assert_eq!(nl.spelling, r"'\n'");
+        assert_eq!(quote.value, "'");
+        assert_eq!(wide.prefix, EncodingPrefix::Wide);
+        assert_eq!(wide.value, "x");
+
+        let Some(ExpressionObject::StringLiteral(s)) = s.value.as_deref() else {
+            panic!("expected a string literal");
+        };
+        assert_eq!(s.prefix, EncodingPrefix::Wide);
+        assert_eq!(s.value, "tab\there and A!");
+        assert_eq!(
+            s.segments,
+            vec![r#"L"tab\there""#, r#"" and \x41""#, r#"u8"!""#]
+        );
+        let Some(ExpressionObject::StringLiteral(octal)) = octal.value.as_deref() else {
+            panic!("expected a string literal");
+        };
+        assert_eq!(octal.value, "A\"q\"");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        let DeclarationObject::Declaration(parsed_s) = &parsed_objects.code[2] else {
+            panic!("expected a declaration");
+        };
+        let Some(ExpressionObject::StringLiteral(parsed_s)) = parsed_s.value.as_deref() else {
+            panic!("expected a string literal");
+        };
+        assert_eq!(parsed_s.segments, s.segments);
+
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        for line in [
+            r#"char c = 'a', nl = '\n', quote = '\'';"#,
+            r#"int wide = L'x';"#,
+            r#"char *s = L"tab\there" " and \x41" u8"!";"#,
+            r#"char *octal = "\101\"q\"";"#,
+        ] {
+            assert!(text.contains(line), "missing `{line}`");
+        }
+
+        // Edited values no longer match their spelling and are written escaped
+        let DeclarationObject::Declaration(s) = &mut src_file.code[2] else {
+            panic!("expected a declaration");
+        };
+        let Some(ExpressionObject::StringLiteral(s)) = s.value.as_deref_mut() else {
+            panic!("expected a string literal");
+        };
+        s.value = "say \"hi\"\n".to_string();
+        let DeclarationObject::Declaration(wide) = &mut src_file.code[1] else {
+            panic!("expected a declaration");
+        };
+        let Some(ExpressionObject::CharLiteral(wide)) = wide.value.as_deref_mut() else {
+            panic!("expected a char literal");
+        };
+        wide.value = "\\".to_string();
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(text.contains(r#"char *s = L"say \"hi\"\n";"#), "{text}");
+        assert!(text.contains(r"int wide = L'\\';"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_bytes_above_0x7f_in_literals() {
+        let c_code = r#"
+        char *hex = "\xff" "a";
+        char *octal = "\377";
+        char *utf8 = "ÿ";
+        "#;
+        let c_language = C::new();
+        let mut src_file = c_language.parse_text(c_code).unwrap();
+        let mut values = src_file.code.iter_mut().map(|declaration| {
+            let DeclarationObject::Declaration(declaration) = declaration else {
+                panic!("expected a declaration");
+            };
+            let Some(ExpressionObject::StringLiteral(s)) = declaration.value.as_deref_mut() else {
+                panic!("expected a string literal");
+            };
+            &mut s.value
+        });
+        let (hex, octal, utf8) = (
+            values.next().unwrap(),
+            values.next().unwrap(),
+            values.next().unwrap(),
+        );
+        // A byte is not the character with the same code
+        assert_eq!(hex, r"\377a");
+        assert_eq!(octal, r"\377");
+        assert_eq!(utf8, "ÿ");
+
+        // Edited values keep the byte and still end it before the next character
+        hex.push('!');
+        octal.push('7');
+        utf8.push('!');
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            r#"char *hex = "\377a!";"#,
+            r#"char *octal = "\3777";"#,
+            r#"char *utf8 = "ÿ!";"#,
+        ] {
+            assert!(text.contains(line), "missing `{line}` in {text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_strings_of_nodes_written_before_decoding() {
+        // Written by the first release, where strings are the text between their quotes
+        let nodes = include_bytes!("c/fixtures/baseline.c.lenga").to_vec();
+        let c_language = C::new();
+        let src_file = c_language.parse_nodes(nodes).unwrap();
+        let DeclarationObject::FunctionDefinition(main) = &src_file.code[3] else {
+            panic!("expected the main function");
+        };
+        let CompoundStatementObject::CallExpression(printf) =
+            &main.compound_statement.code_block[1]
+        else {
+            panic!("expected the call to printf");
+        };
+        let ExpressionObject::StringLiteral(format) = &printf.argument_list[0] else {
+            panic!("expected a string literal");
+        };
+        assert_eq!(format.value, "hi\n");
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        assert!(
+            text.contains(r#"printf("hi\n", clamp(offset + 5));"#),
+            "{text}"
+        );
+
+        // Strings without segments, like the ones made through the RPC, keep their value
+        let mut made = src_file;
+        let DeclarationObject::FunctionDefinition(main) = &mut made.code[3] else {
+            panic!("expected the main function");
+        };
+        let CompoundStatementObject::CallExpression(printf) =
+            &mut main.compound_statement.code_block[1]
+        else {
+            panic!("expected the call to printf");
+        };
+        printf.argument_list[0] = ExpressionObject::StringLiteral(StringLiteral {
+            value: "bye\n".to_string(),
+            ..StringLiteral::default()
+        });
+        let nodes = c_language.write_to_nodes(made).unwrap();
+        let text = c_language
+            .write_to_text(c_language.parse_nodes(nodes).unwrap())
+            .unwrap();
+        assert!(
+            text.contains(r#"printf("bye\n", clamp(offset + 5));"#),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn test_parse_number_literals() {
+        let c_code = r#"
+        int a[] = {0x1F, 31u, 017, 0b101, 1'000'000, 10ULL, 0};
+        double b[] = {1.0e-3f, .5, 0x1.8p3, 1e10L, 0.25};
+        "#;
+        let c_language = C::new();
+        let mut src_file = c_language.parse_text(c_code).unwrap();
+
+        let literals = |declaration: &DeclarationObject| -> Vec<NumberLiteral> {
+            let DeclarationObject::Declaration(Declaration {
+                value: Some(value), ..
+            }) = declaration
+            else {
+                panic!("expected a declaration with a value");
+            };
+            let ExpressionObject::InitializerList(list) = value.as_ref() else {
+                panic!("expected an initializer list");
+            };
+            list.elements
+                .iter()
+                .map(|element| match element {
+                    ExpressionObject::NumberLiteral(number) => number.clone(),
+                    other => panic!("expected a number literal, got {other:?}"),
+                })
+                .collect()
+        };
+        let summary = |number: &NumberLiteral| (number.value, number.radix, number.suffix.clone());
+
+        let integers = literals(&src_file.code[0]);
+        assert_eq!(
+            integers.iter().map(summary).collect::<Vec<_>>(),
+            vec![
+                (NumberValue::Integer(31), Radix::Hexadecimal, "".to_string()),
+                (NumberValue::Integer(31), Radix::Decimal, "u".to_string()),
+                (NumberValue::Integer(15), Radix::Octal, "".to_string()),
+                (NumberValue::Integer(5), Radix::Binary, "".to_string()),
+                (
+                    NumberValue::Integer(1_000_000),
+                    Radix::Decimal,
+                    "".to_string()
+                ),
+                (NumberValue::Integer(10), Radix::Decimal, "ull".to_string()),
+                (NumberValue::Integer(0), Radix::Decimal, "".to_string()),
+            ]
+        );
+        let floats = literals(&src_file.code[1]);
+        assert_eq!(
+            floats.iter().map(summary).collect::<Vec<_>>(),
+            vec![
+                (NumberValue::Float(0.001), Radix::Decimal, "f".to_string()),
+                (NumberValue::Float(0.5), Radix::Decimal, "".to_string()),
+                (NumberValue::Float(12.0), Radix::Hexadecimal, "".to_string()),
+                (NumberValue::Float(1e10), Radix::Decimal, "l".to_string()),
+                (NumberValue::Float(0.25), Radix::Decimal, "".to_string()),
+            ]
+        );
+        assert_eq!(integers[5].spelling, "10ULL");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(literals(&parsed_objects.code[0])[5].spelling, "10ULL");
+
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        assert!(
+            text.contains("int a[] = {0x1F, 31u, 017, 0b101, 1'000'000, 10ULL, 0};"),
+            "{text}"
+        );
+        assert!(
+            text.contains("double b[] = {1.0e-3f, .5, 0x1.8p3, 1e10L, 0.25};"),
+            "{text}"
+        );
+
+        // Edited numbers no longer match their spelling and keep their radix and suffix
+        let DeclarationObject::Declaration(Declaration {
+            value: Some(value), ..
+        }) = &mut src_file.code[0]
+        else {
+            panic!("expected a declaration with a value");
+        };
+        let ExpressionObject::InitializerList(list) = value.as_mut() else {
+            panic!("expected an initializer list");
+        };
+        for (element, value) in list.elements.iter_mut().zip([255, 7, 8, 6]) {
+            if let ExpressionObject::NumberLiteral(number) = element {
+                number.value = NumberValue::Integer(value);
+            }
+        }
+        let DeclarationObject::Declaration(Declaration {
+            value: Some(value), ..
+        }) = &mut src_file.code[1]
+        else {
+            panic!("expected a declaration with a value");
+        };
+        let ExpressionObject::InitializerList(list) = value.as_mut() else {
+            panic!("expected an initializer list");
+        };
+        for (element, value) in list.elements.iter_mut().zip([2.5, 3.0, 0.75]) {
+            if let ExpressionObject::NumberLiteral(number) = element {
+                number.value = NumberValue::Float(value);
+            }
+        }
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(
+            text.contains("int a[] = {0xff, 7u, 010, 0b110, 1'000'000, 10ULL, 0};"),
+            "{text}"
+        );
+        assert!(
+            text.contains("double b[] = {2.5f, 3.0, 0x1.8p-1, 1e10L, 0.25};"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn test_parse_signed_number_literals() {
+        let c_code = r#"
+int x = -1;
+int y = +1;
+
+int f(int a)
+{
+    int b = a + -1;
+    return -1;
+}
+"#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let DeclarationObject::Declaration(Declaration {
+            value: Some(value), ..
+        }) = &src_file.code[0]
+        else {
+            panic!("expected a declaration with a value");
+        };
+        let ExpressionObject::UnaryExpression(UnaryExpression {
+            operator, argument, ..
+        }) = value.as_ref()
+        else {
+            panic!("expected the sign as a unary expression, got {value:?}");
+        };
+        assert_eq!(operator, "-");
+        assert!(matches!(
+            argument.as_ref(),
+            ExpressionObject::NumberLiteral(NumberLiteral {
+                value: NumberValue::Integer(1),
+                ..
+            })
+        ));
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "int x = -1;",
+            "int y = +1;",
+            "int b = a + -1;",
+            "return -1;",
+        ] {
+            assert!(text.contains(line), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_numbers_of_nodes_written_before_number_tags() {
+        // Written by the first release, where numbers are only their spelling, sign included
+        let nodes = include_bytes!("c/fixtures/baseline.c.lenga").to_vec();
+        let c_language = C::new();
+        let src_file = c_language.parse_nodes(nodes).unwrap();
+        let text = c_language.write_to_text(src_file).unwrap();
+        for line in [
+            "int limit = 0x1F;",
+            "return -1;",
+            "int offset = -2;",
+            "clamp(offset + 5)",
+            "return 0;",
+        ] {
+            assert!(text.contains(line), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_parse_labels_and_goto() {
+        let c_code = r#"
+int f(int a) {
+    int end = a;
+    goto end;
+    {
+    inner:
+        a++;
+    }
+again:
+    if (a) {
+        goto again;
+    }
+end: ;
+    return end;
+}
+"#;
+        let c_language = C::new();
+        let mut src_file = c_language.parse_text(c_code).unwrap();
+
+        let code_block = |src_file: &CSourceFile| -> Vec<CompoundStatementObject> {
+            let DeclarationObject::FunctionDefinition(function) = &src_file.code[0] else {
+                panic!("expected a function definition");
+            };
+            function.compound_statement.code_block.clone()
+        };
+        let code = code_block(&src_file);
+        let [
+            CompoundStatementObject::Declaration(variable),
+            CompoundStatementObject::GotoStatement(forward_goto),
+            CompoundStatementObject::CompoundStatement(block),
+            CompoundStatementObject::LabeledStatement(again),
+            CompoundStatementObject::LabeledStatement(end),
+            CompoundStatementObject::ReturnStatement(ReturnStatement {
+                value: Some(returned),
+                ..
+            }),
+        ] = code.as_slice()
+        else {
+            panic!("unexpected function body: {code:?}");
+        };
+
+        // A goto may jump forward, to a label defined later in the function
+        assert_eq!(forward_goto.label, "end");
+        assert_eq!(forward_goto.label_id, end.id);
+        assert!(end.statement.is_none());
+
+        // Labels do not shadow variables with the same name
+        let ExpressionObject::Reference(reference) = returned else {
+            panic!("expected a reference, got {returned:?}");
+        };
+        assert_eq!(reference.declaration_id, variable.id);
+        assert_ne!(reference.declaration_id, end.id);
+
+        let [CompoundStatementObject::LabeledStatement(inner)] = block.code_block.as_slice() else {
+            panic!("unexpected block: {block:?}");
+        };
+        assert_eq!(inner.label, "inner");
+        assert!(matches!(
+            inner.statement.as_deref(),
+            Some(CompoundStatementObject::UpdateExpression(_))
+        ));
+
+        let Some(CompoundStatementObject::IfStatement(if_statement)) = again.statement.as_deref()
+        else {
+            panic!("expected an if statement, got {again:?}");
+        };
+        let CompoundStatementObject::CompoundStatement(if_block) = if_statement.body.as_ref()
+        else {
+            panic!("expected a block, got {if_statement:?}");
+        };
+        let [CompoundStatementObject::GotoStatement(backward_goto)] =
+            if_block.code_block.as_slice()
+        else {
+            panic!("expected a goto, got {if_block:?}");
+        };
+        assert_eq!(backward_goto.label_id, again.id);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        assert!(text.contains("    goto end;\n"), "{text}");
+        assert!(text.contains("        inner:\n        a++;\n"), "{text}");
+        assert!(text.contains("    again:\n    if (a) {\n"), "{text}");
+        assert!(text.contains("    end:;\n"), "{text}");
+
+        // Gotos refer to the label by id, so renaming the label renames every goto
+        let DeclarationObject::FunctionDefinition(function) = &mut src_file.code[0] else {
+            panic!("expected a function definition");
+        };
+        let CompoundStatementObject::LabeledStatement(end) =
+            &mut function.compound_statement.code_block[4]
+        else {
+            panic!("expected a labeled statement");
+        };
+        end.label = "finish".to_string();
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let renamed = c_language.parse_nodes(nodes).unwrap();
+        let CompoundStatementObject::GotoStatement(forward_goto) = &code_block(&renamed)[1] else {
+            panic!("expected a goto");
+        };
+        assert_eq!(forward_goto.label, "finish");
+        let text = c_language.write_to_text(renamed).unwrap();
+        assert!(text.contains("    goto finish;\n"), "{text}");
+        assert!(text.contains("    finish:;\n"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_gotos_to_lost_labels_as_unknown() {
+        let c_code = "int
+f(int a)
+{
+    if (a) {
+        goto exit;
+    }
+    goto out;
+out:
+    a += 1;
+exit:
+}
+";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        // The label closing the block is a syntax error, so the goto to it is kept as text
+        let DeclarationObject::FunctionDefinition(function) = &src_file.code[0] else {
+            panic!("expected a function definition");
+        };
+        let [
+            CompoundStatementObject::IfStatement(if_statement),
+            CompoundStatementObject::GotoStatement(goto_out),
+            CompoundStatementObject::LabeledStatement(out),
+            CompoundStatementObject::Unknown(_),
+        ] = function.compound_statement.code_block.as_slice()
+        else {
+            panic!(
+                "unexpected function body: {:?}",
+                function.compound_statement
+            );
+        };
+        let CompoundStatementObject::CompoundStatement(if_block) = if_statement.body.as_ref()
+        else {
+            panic!("expected a block, got {if_statement:?}");
+        };
+        let [CompoundStatementObject::Unknown(goto_exit)] = if_block.code_block.as_slice() else {
+            panic!("unexpected block: {if_block:?}");
+        };
+        assert_eq!(goto_exit.content, "goto exit;");
+        assert_eq!(goto_out.label_id, out.id);
+        assert!(
+            warnings
+                .iter()
+                .any(|warning| warning.code == "missing-symbol"
+                    && &c_code[warning.span.range()] == "goto exit;"),
+            "{warnings:?}"
+        );
+
+        // The nodes read back, which they could not with a goto to a label they don't have
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(
+            c_language.write_to_text(parsed_objects).unwrap(),
+            c_language.write_to_text(src_file).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_single_statement_bodies_round_trip() {
+        let c_code = "int\nf(int x)\n{\n    while (x) {\n        if (x) break; else continue;\n    }\n    if (x) goto label;\n    if (x) return 1; else goto label;\n    if (x) {\n        x = 2;\n    } else x = 3;\n    label:\n    return 0;\n}\n";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        // A body that is not a block ends with its own semicolon, on the line of the `if`, and
+        // the style leaves a blank line after the function
+        let expected = format!("{c_code}\n");
+        assert_eq!(
+            c_language.write_to_text(src_file.clone()).unwrap(),
+            expected
+        );
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_variadic_and_void_parameter_lists() {
+        let c_code = r#"
+int printf(const char *fmt, ...);
+int g(void);
+int h();
+int k(int, char *);
+int main(void) {
+    return 0;
+}
+"#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::FunctionDeclaration(printf),
+            DeclarationObject::FunctionDeclaration(g),
+            DeclarationObject::FunctionDeclaration(h),
+            DeclarationObject::FunctionDeclaration(k),
+            DeclarationObject::FunctionDefinition(main),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert!(printf.is_variadic);
+        assert!(!printf.explicit_void);
+        assert_eq!(printf.parameter_list.len(), 1);
+        assert!(g.explicit_void);
+        assert!(g.parameter_list.is_empty());
+        assert!(!h.explicit_void);
+        assert!(h.parameter_list.is_empty());
+        assert!(!k.is_variadic);
+        assert_eq!(k.parameter_list.len(), 2);
+        assert!(k.parameter_list.iter().all(|p| p.identifier.is_empty()));
+        assert!(main.explicit_void);
+        assert!(!main.is_variadic);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(
+            text.contains("int\nprintf(const char *fmt, ...);"),
+            "{text}"
+        );
+        assert!(text.contains("int\ng(void);"), "{text}");
+        assert!(text.contains("int\nh();"), "{text}");
+        assert!(text.contains("int\nk(int, char *);"), "{text}");
+        assert!(text.contains("int\nmain(void)\n{"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_type_definitions() {
+        let c_code = r#"
+struct point {
+    int x;
+    int y;
+};
+typedef struct point point_t;
+typedef point_t *point_ptr;
+typedef int (*callback_t)(int, char);
+int apply(callback_t cb, point_ptr p, size_t n) {
+    point_t origin;
+    return p->y + origin.x;
+}
+"#;
+        let c_language = C::new();
+        let mut src_file = c_language.parse_text(c_code).unwrap();
+
+        let [
+            DeclarationObject::StructDefinition(point),
+            DeclarationObject::TypeDefinition(point_t),
+            DeclarationObject::TypeDefinition(point_ptr),
+            DeclarationObject::TypeDefinition(callback_t),
+            DeclarationObject::FunctionDefinition(apply),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(
+            point_t.aliased_type,
+            CType::Struct(TypeReference {
+                declaration_id: point.id,
+                identifier: "point".to_string(),
+            })
+        );
+        let point_t_type = CType::Named {
+            declaration_id: point_t.id,
+            name: "point_t".to_string(),
+        };
+        assert_eq!(
+            point_ptr.aliased_type,
+            CType::Pointer(Box::new(point_t_type.clone()))
+        );
+        assert_eq!(
+            callback_t.aliased_type,
+            CType::Pointer(Box::new(CType::Fn(FnType {
+                return_type: Box::new(CType::Int),
+                parameters: vec![
+                    FnParameter {
+                        param_type: CType::Int,
+                        identifier: None,
+                    },
+                    FnParameter {
+                        param_type: CType::Char,
+                        identifier: None,
+                    },
+                ],
+                is_variadic: false,
+                explicit_void: false,
+            })))
+        );
+
+        let [cb, p, n] = apply.parameter_list.as_slice() else {
+            panic!("unexpected parameters: {:?}", apply.parameter_list);
+        };
+        assert_eq!(
+            cb.param_type,
+            CType::Named {
+                declaration_id: callback_t.id,
+                name: "callback_t".to_string(),
+            }
+        );
+        assert!(
+            matches!(&p.param_type, CType::Named { declaration_id, .. } if *declaration_id == point_ptr.id)
+        );
+        // Type names without a typedef in the source stay external
+        assert_eq!(n.param_type, CType::Typedef("size_t".to_string()));
+
+        // Member accesses see through typedefs to the struct they alias
+        let [
+            CompoundStatementObject::Declaration(origin),
+            CompoundStatementObject::ReturnStatement(returned),
+        ] = apply.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", apply.compound_statement);
+        };
+        assert_eq!(origin.primitive_type, point_t_type);
+        let Some(ExpressionObject::BinaryExpression(sum)) = &returned.value else {
+            panic!("expected a binary expression, got {returned:?}");
+        };
+        let (ExpressionObject::FieldExpression(y), ExpressionObject::FieldExpression(x)) =
+            (sum.left.as_ref(), sum.right.as_ref())
+        else {
+            panic!("expected field expressions, got {sum:?}");
+        };
+        assert_eq!(y.declaration_id, point.fields[1].id);
+        assert_eq!(x.declaration_id, point.fields[0].id);
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+
+        let text = c_language.write_to_text(src_file.clone()).unwrap();
+        assert!(text.contains("typedef struct point point_t;\n"), "{text}");
+        assert!(text.contains("typedef point_t *point_ptr;\n"), "{text}");
+        assert!(
+            text.contains("typedef int (*callback_t)(int, char);\n"),
+            "{text}"
+        );
+        assert!(
+            text.contains("(callback_t cb, point_ptr p, size_t n)"),
+            "{text}"
+        );
+        assert!(text.contains("    point_t origin;\n"), "{text}");
+
+        // Uses refer to the typedef by id, so renaming it renames every use
+        let DeclarationObject::TypeDefinition(point_t) = &mut src_file.code[1] else {
+            panic!("expected a type definition");
+        };
+        point_t.identifier = "point_s".to_string();
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let renamed = c_language.parse_nodes(nodes).unwrap();
+        let text = c_language.write_to_text(renamed).unwrap();
+        assert!(text.contains("typedef struct point point_s;\n"), "{text}");
+        assert!(text.contains("typedef point_s *point_ptr;\n"), "{text}");
+        assert!(text.contains("    point_s origin;\n"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_type_definitions_of_primitive_names() {
+        // tree-sitter reads names like `size_t` as primitive types, in typedefs too
+        let c_code = r#"
+typedef unsigned long size_t;
+typedef unsigned int uint32_t;
+size_t count(uint32_t n) {
+    return n;
+}
+"#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+        let [
+            DeclarationObject::TypeDefinition(size_t),
+            DeclarationObject::TypeDefinition(uint32_t),
+            DeclarationObject::FunctionDefinition(count),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(size_t.identifier, "size_t");
+        assert_eq!(size_t.aliased_type, "unsigned long".parse().unwrap());
+        assert_eq!(uint32_t.identifier, "uint32_t");
+        // Uses of the names link to the typedefs of the source
+        assert_eq!(
+            count.return_type,
+            CType::Named {
+                declaration_id: size_t.id,
+                name: "size_t".to_string(),
+            }
+        );
+        assert_eq!(
+            count.parameter_list[0].param_type,
+            CType::Named {
+                declaration_id: uint32_t.id,
+                name: "uint32_t".to_string(),
+            }
+        );
+
+        let text = c_language.write_to_text(src_file).unwrap();
+        assert!(text.contains("typedef unsigned long size_t;"), "{text}");
+        assert!(text.contains("typedef unsigned int uint32_t;"), "{text}");
+    }
+
+    #[test]
+    fn test_parse_unsupported_syntax_as_unknown() {
+        let c_code = "struct point;
+int x __attribute__((aligned(8)));
+enum { A, B } e;
+int
+main()
+{
+    int total = 0;
+    total += 2;
+    int copy = total;
+    copy = copy *;
+    return total + sizeof(struct { int a; });
+}
+
+";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        // Consecutive unknown parts are kept as one, with the text between them
+        let [
+            DeclarationObject::StructDefinition(point),
+            DeclarationObject::Unknown(header),
+            DeclarationObject::FunctionDefinition(main),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert!(point.is_forward);
+        assert_eq!(
+            header.content,
+            "int x __attribute__((aligned(8)));\nenum { A, B } e;"
+        );
+        let [
+            CompoundStatementObject::Declaration(_),
+            CompoundStatementObject::Unknown(compound_assignment),
+            CompoundStatementObject::Declaration(_),
+            CompoundStatementObject::Unknown(syntax_error),
+            CompoundStatementObject::ReturnStatement(ReturnStatement {
+                value: Some(ExpressionObject::BinaryExpression(sum)),
+                ..
+            }),
+        ] = main.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", main.compound_statement);
+        };
+        assert_eq!(compound_assignment.content, "total += 2;");
+        assert_eq!(syntax_error.content, "copy = copy *;");
+        let ExpressionObject::Unknown(sizeof) = sum.right.as_ref() else {
+            panic!("expected an unknown expression, got {sum:?}");
+        };
+        assert_eq!(sizeof.content, "sizeof(struct { int a; })");
+
+        let ranges = warnings
+            .iter()
+            .map(|warning| &c_code[warning.span.range()])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            [
+                "int x __attribute__((aligned(8)));\nenum { A, B } e;",
+                "total += 2;",
+                "copy = copy *;",
+                "sizeof(struct { int a; })",
+            ]
+        );
+        assert_eq!(warnings[1].message, "unsupported `+=` assignment");
+        assert_eq!(warnings[2].message, "syntax error");
+        assert_eq!(
+            warnings[3].message,
+            "unsupported anonymous struct_specifier"
+        );
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_text_the_grammar_cannot_recover_from() {
+        // tree-sitter gives back a single error node instead of a translation unit
+        let c_code = "= 1 1 , ]\n";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+        let [DeclarationObject::Unknown(unknown)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(unknown.content, "= 1 1 , ]");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "syntax error");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert_eq!(c_language.write_to_text(parsed_objects).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_k_and_r_definitions_as_unknown() {
+        let c_code = "int f(a, b)
+int a;
+char *b;
+{
+    return a;
+}
+int
+g(void)
+{
+    return f(1, 0);
+}
+
+";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        // The old style definition is kept as it was, and calls to it by name
+        let [
+            DeclarationObject::Unknown(f),
+            DeclarationObject::FunctionDefinition(g),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(
+            f.content,
+            "int f(a, b)\nint a;\nchar *b;\n{\n    return a;\n}"
+        );
+        let [
+            CompoundStatementObject::ReturnStatement(ReturnStatement {
+                value: Some(ExpressionObject::CallExpression(call)),
+                ..
+            }),
+        ] = g.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", g.compound_statement);
+        };
+        assert_eq!(call.identifier, "f");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "unsupported-syntax");
+        assert_eq!(warnings[0].message, "unsupported K&R style parameter list");
+
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(src_file, parsed_objects);
+        assert!(
+            c_language
+                .write_to_text(parsed_objects)
+                .unwrap()
+                .starts_with("int f(a, b)\nint a;\nchar *b;\n{\n    return a;\n}\n")
+        );
+    }
+
+    #[test]
+    fn test_merge_unknowns_with_nested_warnings() {
+        // The missing symbol in the second part is reported before that part is merged into
+        // the first, so the merge goes by the spans of the parts rather than their warnings
+        let c_code = "int a;\nINIT(a, 0)\nreturn b;\nint c;\n";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        let [
+            DeclarationObject::Declaration(_),
+            DeclarationObject::Unknown(unknown),
+            DeclarationObject::Declaration(_),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(unknown.content, "INIT(a, 0)\nreturn b;");
+        assert!(
+            warnings
+                .iter()
+                .any(|warning| &c_code[warning.span.range()] == "INIT(a, 0)\nreturn b;"),
+            "{warnings:?}"
+        );
+
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_text_never_panics() {
+        let c_code = "int main() { return 0;\n";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        let [DeclarationObject::Unknown(unknown)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(unknown.content, "int main() { return 0;");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "missing `}`");
+        assert_eq!(warnings[0].span.range(), 0..22);
+        assert_eq!(
+            c_language.write_to_text(src_file).unwrap(),
+            "int main() { return 0;\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_unexpected_shapes_as_unknown() {
+        let c_code = "struct point {
+    int x;
+#ifdef HAS_Y
+    int y;
+#endif
+};
+int limit;
+enum color {
+    RED,
+#ifdef HAS_GREEN
+    GREEN,
+#endif
+};
+struct ops {
+    int (*fn)(int);
+};
+int
+call(struct ops v, int (*fp)(int))
+{
+    v.fn(1);
+    int a = 0;
+    (*fp)(1);
+    int b = 0;
+    ((int (*)(int))0)(1);
+    return a + b;
+}
+
+";
+        let c_language = C::new();
+        let (src_file, warnings) = c_language.parse_text_with_diagnostics(c_code).unwrap();
+
+        let [
+            DeclarationObject::Unknown(point),
+            DeclarationObject::Declaration(_),
+            DeclarationObject::Unknown(color),
+            DeclarationObject::StructDefinition(_),
+            DeclarationObject::FunctionDefinition(call),
+        ] = src_file.code.as_slice()
+        else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert!(point.content.starts_with("struct point {"));
+        assert!(color.content.starts_with("enum color {"));
+        let [
+            CompoundStatementObject::Unknown(field_call),
+            CompoundStatementObject::Declaration(_),
+            CompoundStatementObject::Unknown(pointer_call),
+            CompoundStatementObject::Declaration(_),
+            CompoundStatementObject::Unknown(cast_call),
+            CompoundStatementObject::ReturnStatement(_),
+        ] = call.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected function body: {:?}", call.compound_statement);
+        };
+        assert_eq!(field_call.content, "v.fn(1);");
+        assert_eq!(pointer_call.content, "(*fp)(1);");
+        assert_eq!(cast_call.content, "((int (*)(int))0)(1);");
+
+        let messages = warnings
+            .iter()
+            .map(|warning| warning.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "unsupported preproc_ifdef in field_declaration_list",
+                "unsupported preproc_ifdef in enumerator_list",
+                "unsupported field_expression in call_expression",
+                "unsupported parenthesized_expression in call_expression",
+                "unsupported parenthesized_expression in call_expression",
+            ]
+        );
+        assert_eq!(c_language.write_to_text(src_file).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_text_error_diagnostic_span() {
+        let c_code = "int\nmain()\n{\n    int total;\n    int total;\n}\n";
+        let c_language = C::new();
+        let diagnostics = c_language.parse_text_with_diagnostics(c_code).unwrap_err();
+
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.code, "symbol-already-exists");
+        assert_eq!(diagnostic.message, "Symbol already exists");
+        assert_eq!(diagnostic.span.start.line, 4);
+        assert_eq!(diagnostic.span.start.column, 4);
+        assert_eq!(diagnostic.span.end.line, 4);
+        assert_eq!(diagnostic.span.end.column, 14);
+        assert_eq!(
+            diagnostic.to_string(),
+            "5:5: error[symbol-already-exists]: Symbol already exists"
+        );
+        assert_eq!(
+            c_language.parse_text(c_code).unwrap_err(),
+            "Symbol already exists"
+        );
+    }
+
+    /// Start and end `(line, column)` of a span
+    fn lines_and_columns(span: Option<Span>) -> ((usize, usize), (usize, usize)) {
+        let span = span.expect("parsed objects have a span");
+        (
+            (span.start.line, span.start.column),
+            (span.end.line, span.end.column),
+        )
+    }
+
+    #[test]
+    fn test_parse_text_keeps_source_spans() {
+        let c_code = "int add(int a, int b)\n{\n    int x = a, y = b;\n    return x + y;\n}\n";
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        assert_eq!(src_file.span.unwrap().range(), 0..c_code.len());
+        let [DeclarationObject::FunctionDefinition(function)] = src_file.code.as_slice() else {
+            panic!("unexpected declarations: {:?}", src_file.code);
+        };
+        assert_eq!(lines_and_columns(function.span), ((0, 0), (4, 1)));
+        assert_eq!(
+            lines_and_columns(function.parameter_list[0].span),
+            ((0, 8), (0, 13))
+        );
+        assert_eq!(
+            lines_and_columns(function.parameter_list[1].span),
+            ((0, 15), (0, 20))
+        );
+        let [
+            CompoundStatementObject::DeclarationList(declaration_list),
+            CompoundStatementObject::ReturnStatement(return_statement),
+        ] = function.compound_statement.code_block.as_slice()
+        else {
+            panic!("unexpected statements: {:?}", function.compound_statement);
+        };
+        assert_eq!(lines_and_columns(declaration_list.span), ((2, 4), (2, 21)));
+        assert_eq!(
+            lines_and_columns(declaration_list.declarations[0].span),
+            ((2, 8), (2, 13))
+        );
+        assert_eq!(
+            lines_and_columns(declaration_list.declarations[1].span),
+            ((2, 15), (2, 20))
+        );
+        assert_eq!(lines_and_columns(return_statement.span), ((3, 4), (3, 17)));
+        let Some(value) = &return_statement.value else {
+            panic!("expected a returned value");
+        };
+        assert_eq!(
+            lines_and_columns(value.as_language_object().span()),
+            ((3, 11), (3, 16))
+        );
+
+        // Spans are kept in the nodes, and files written without them still load
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_objects = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(parsed_objects.span, src_file.span);
+        let DeclarationObject::FunctionDefinition(parsed_function) = &parsed_objects.code[0] else {
+            panic!("expected a function definition");
+        };
+        assert_eq!(parsed_function.span, function.span);
+        assert_eq!(
+            parsed_function.parameter_list[1].span,
+            function.parameter_list[1].span
+        );
+        assert_eq!(
+            parsed_function.compound_statement,
+            function.compound_statement
+        );
+        let CompoundStatementObject::DeclarationList(parsed_list) =
+            &parsed_function.compound_statement.code_block[0]
+        else {
+            panic!("expected a declaration list");
+        };
+        assert_eq!(
+            parsed_list.declarations[1].span,
+            declaration_list.declarations[1].span
+        );
+
+        let unspanned = c_language
+            .write_to_nodes(CSourceFile {
+                id: Uuid::new_v4(),
+                span: None,
+                code: vec![],
+                trivia: None,
+            })
+            .unwrap();
+        assert_eq!(c_language.parse_nodes(unspanned).unwrap().span, None);
+    }
+
+    #[test]
+    fn test_parse_text_with_trivia_keeps_the_layout() {
+        let c_code = "/* Header */\n\n#include <stdio.h>\n\n\nint spaced = 1;\nstruct point {\n    int x;\n    int y;\n};\n\n// Doc of main\nint main(void)\n{\n\tint x = 1;\n\n\tif (x) {\n\t\tx = 2;\n\t} else if (x > 1) {\n\t\tx = 3;\n\t}\n\treturn x; // done\n}\n";
+        let c_language = C::with_trivia();
+        let src_file = c_language.parse_text(c_code).unwrap();
+        assert!(src_file.trivia.is_some());
+
+        assert_eq!(c_language.write_to_text(src_file.clone()).unwrap(), c_code);
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(parsed_nodes, src_file);
+        assert_eq!(parsed_nodes.trivia, src_file.trivia);
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), c_code);
+
+        // Changed objects keep their layout too, and the spacing in a line is the style's
+        let mut edited = src_file.clone();
+        let DeclarationObject::Declaration(spaced) = &mut edited.code[2] else {
+            panic!("unexpected declaration: {:?}", edited.code[2]);
+        };
+        spaced.identifier = "renamed".to_string();
+        let DeclarationObject::FunctionDefinition(main) = &mut edited.code[4] else {
+            panic!("unexpected declaration: {:?}", edited.code[4]);
+        };
+        let CompoundStatementObject::IfStatement(if_statement) =
+            &mut main.compound_statement.code_block[1]
+        else {
+            panic!(
+                "unexpected statement: {:?}",
+                main.compound_statement.code_block[1]
+            );
+        };
+        *if_statement.condition =
+            ExpressionObject::NumberLiteral(NumberLiteral::from_spelling("0").unwrap());
+        assert_eq!(
+            c_language.write_to_text(edited).unwrap(),
+            c_code
+                .replace("spaced", "renamed")
+                .replace("if (x)", "if (0)")
+        );
+        let spaced = c_language
+            .parse_text("int    spaced   =   1 ;\nint f(int a,int b)   {  return a; }\n")
+            .unwrap();
+        assert_eq!(
+            c_language.write_to_text(spaced).unwrap(),
+            "int spaced = 1;\nint f(int a, int b) {\n    return a;\n}\n"
+        );
+
+        let styled = C::new().parse_text(c_code).unwrap();
+        assert!(styled.trivia.is_none());
+        assert_ne!(C::new().write_to_text(styled).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_parse_text_with_trivia_indents_directive_and_label_bodies() {
+        let c_code = "int f(int i)\n{\n#ifdef DEBUG\n    i = 1;\n#endif\n    if (i) {\n        goto out;\n    }\nout:\n    return i;\n}\n";
+        let c_language = C::with_trivia();
+        let src_file = c_language.parse_text(c_code).unwrap();
+
+        assert_eq!(c_language.write_to_text(src_file.clone()).unwrap(), c_code);
+        let nodes = c_language.write_to_nodes(src_file).unwrap();
+        let parsed_nodes = c_language.parse_nodes(nodes).unwrap();
+        assert_eq!(c_language.write_to_text(parsed_nodes).unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_nodes_header() {
+        use crate::node::{
+            Node,
+            header::{FORMAT_VERSION, Header, HeaderError, MAGIC},
+        };
+
+        let c_language = C::new();
+        let src_file = c_language.parse_text("int x = 1;\n").unwrap();
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+        assert!(nodes.starts_with(MAGIC));
+        let (header, body) = Header::read(&nodes).unwrap();
+        assert_eq!(
+            header,
+            Some(Header {
+                format_version: FORMAT_VERSION,
+                language: "C".to_string(),
+                schema_hash: c_language.schema_hash(),
+            })
+        );
+
+        // Files written before the header are only nodes
+        assert_eq!(c_language.parse_nodes(body.to_vec()).unwrap(), src_file);
+
+        let with_header = |header: Header| {
+            let mut bytes = Vec::new();
+            header.write(&mut bytes).unwrap();
+            bytes.extend_from_slice(body);
+            c_language.parse_nodes(bytes)
+        };
+        let header = header.unwrap();
+        assert!(
+            with_header(Header {
+                format_version: FORMAT_VERSION + 1,
+                ..header.clone()
+            })
+            .unwrap_err()
+            .contains("newer than the supported version")
+        );
+        assert_eq!(
+            with_header(Header {
+                language: "Rust".to_string(),
+                ..header.clone()
+            })
+            .unwrap_err(),
+            "Lenga file holds Rust code, not C"
+        );
+        assert!(
+            with_header(Header {
+                schema_hash: header.schema_hash ^ 1,
+                ..header.clone()
+            })
+            .unwrap_err()
+            .contains("node schema")
+        );
+
+        // Unknown node types and bytes that are not nodes are errors rather than panics
+        let mut file: Vec<Node> = bincode::deserialize(body).unwrap();
+        file[0].children[0].node_type = 9999;
+        let mut unknown_type = Vec::new();
+        header.write(&mut unknown_type).unwrap();
+        unknown_type.extend(bincode::serialize(&file).unwrap());
+        assert_eq!(
+            c_language.parse_nodes(unknown_type).unwrap_err(),
+            "Unknown node type: 9999"
+        );
+        assert!(c_language.parse_nodes(vec![1, 2, 3]).is_err());
+        assert!(c_language.parse_nodes(MAGIC.to_vec()).is_err());
+
+        // A language name longer than any header is corrupted, not allocated
+        let mut huge_language = MAGIC.to_vec();
+        huge_language.extend(FORMAT_VERSION.to_le_bytes());
+        huge_language.extend(u64::MAX.to_le_bytes());
+        huge_language.extend(b"C");
+        assert!(matches!(
+            Header::read(&huge_language),
+            Err(HeaderError::Corrupted(_))
+        ));
+    }
+
+    #[test]
+    fn test_deterministic_ids() {
+        let c_code = r#"
+// Adds two numbers
+int add(int a, int b)
+{
+    int sum = a + b;
+    if (sum > 10) {
+        return sum;
+    } else {
+        return 0;
+    }
+}
+
+int main()
+{
+    return add(1, 2);
+}
+"#;
+        let c_language = C::new().with_deterministic_ids("main.c");
+        let first = c_language.parse_text(c_code).unwrap();
+        let second = c_language.parse_text(c_code).unwrap();
+        let nodes = c_language.write_to_nodes(first.clone()).unwrap();
+        assert_eq!(nodes, c_language.write_to_nodes(second).unwrap());
+
+        // Ids survive a round trip through nodes, the one of the file included
+        let read_back = c_language.parse_nodes(nodes.clone()).unwrap();
+        assert_eq!(read_back.id, first.id);
+        assert_eq!(c_language.write_to_nodes(read_back).unwrap(), nodes);
+
+        // Editing a function leaves the file and the other functions with the same ids
+        let edited = c_language
+            .parse_text(&c_code.replace("add(1, 2)", "add(3, 4)"))
+            .unwrap();
+        assert_eq!(edited.id, first.id);
+        assert_eq!(edited.code[0], first.code[0]);
+        assert_eq!(edited.code[0].id(), first.code[0].id());
+        assert_ne!(
+            c_language.write_to_nodes(edited).unwrap(),
+            c_language.write_to_nodes(first.clone()).unwrap()
+        );
+
+        // Functions are placed by their name, so adding one before them keeps their ids and the
+        // ids of what they hold. Statements without a name are placed among the ones of their
+        // kind, so a call added before a return leaves the return with its id
+        let statement_ids = |object: &DeclarationObject| match object {
+            DeclarationObject::FunctionDefinition(function) => function
+                .compound_statement
+                .code_block
+                .iter()
+                .map(|statement| CLanguageObject::from(statement.clone()).id())
+                .collect::<Vec<_>>(),
+            _ => panic!("expected a function definition"),
+        };
+        let inserted = c_language
+            .parse_text(
+                &c_code
+                    .replace(
+                        "// Adds two numbers",
+                        "int zero()\n{\n    return 0;\n}\n\n// Adds two numbers",
+                    )
+                    .replace(
+                        "    return add(1, 2);",
+                        "    zero();\n    return add(1, 2);",
+                    ),
+            )
+            .unwrap();
+        assert_eq!(inserted.id, first.id);
+        assert_eq!(inserted.code[1].id(), first.code[0].id());
+        assert_eq!(
+            statement_ids(&inserted.code[1]),
+            statement_ids(&first.code[0])
+        );
+        assert_eq!(inserted.code[2].id(), first.code[1].id());
+        assert_eq!(
+            statement_ids(&inserted.code[2])[1],
+            statement_ids(&first.code[1])[0]
+        );
+
+        // The seed tells files apart, so the same code in another file gets other ids
+        let other = C::new()
+            .with_deterministic_ids("other.c")
+            .parse_text(c_code)
+            .unwrap();
+        assert_ne!(other.id, first.id);
+        assert_ne!(other.code[0].id(), first.code[0].id());
+
+        // Random ids are new on every parse
+        let random = C::new().parse_text(c_code).unwrap();
+        assert_ne!(random.id, first.id);
+        assert_ne!(random.code[1].id(), first.code[1].id());
+    }
+
+    #[test]
+    fn test_update_from_text() {
+        let c_code = r#"
+// Adds two numbers
+int add(int a, int b)
+{
+    return a + b;
+}
+
+int main()
+{
+    int total = add(1, 2);
+    printf("%d", total);
+    return 0;
+}
+"#;
+        let edited_code = r#"
+// Adds two numbers
+int add(int a, int b)
+{
+    return a + b;
+}
+
+int square(int x)
+{
+    return x * x;
+}
+
+int main()
+{
+    int total = add(3, 4);
+    total = square(total);
+    printf("%d", total);
+    return 0;
+}
+"#;
+        let c_language = C::new();
+        let src_file = c_language.parse_text(c_code).unwrap();
+        let nodes = c_language.write_to_nodes(src_file.clone()).unwrap();
+
+        // Unchanged text keeps every id
+        let unchanged = c_language
+            .update_from_text(src_file.clone(), c_code)
+            .unwrap();
+        assert_eq!(c_language.write_to_nodes(unchanged).unwrap(), nodes);
+
+        let updated = c_language
+            .update_from_text(src_file.clone(), edited_code)
+            .unwrap();
+        assert_eq!(updated.id, src_file.id);
+        assert_eq!(
+            c_language.write_to_text(updated.clone()).unwrap(),
+            c_language
+                .write_to_text(c_language.parse_text(edited_code).unwrap())
+                .unwrap()
+        );
+
+        let [
+            DeclarationObject::FunctionDefinition(add),
+            DeclarationObject::FunctionDefinition(square),
+            DeclarationObject::FunctionDefinition(main),
+        ] = &updated.code[..]
+        else {
+            panic!("expected three function definitions");
+        };
+        let [
+            DeclarationObject::FunctionDefinition(old_add),
+            DeclarationObject::FunctionDefinition(old_main),
+        ] = &src_file.code[..]
+        else {
+            panic!("expected two function definitions");
+        };
+        assert_eq!(add, old_add);
+        assert_eq!(add.id, old_add.id);
+        assert_eq!(main.id, old_main.id);
+        assert_ne!(square.id, old_add.id);
+        assert_ne!(square.id, old_main.id);
+
+        // The edited declaration keeps its id, so does the call in it and the uses of the variable
+        let (
+            [
+                CompoundStatementObject::Declaration(total),
+                CompoundStatementObject::AssignmentExpression(assignment),
+                CompoundStatementObject::CallExpression(printf),
+                CompoundStatementObject::ReturnStatement(_),
+            ],
+            [
+                CompoundStatementObject::Declaration(old_total),
+                CompoundStatementObject::CallExpression(old_printf),
+                CompoundStatementObject::ReturnStatement(_),
+            ],
+        ) = (
+            &main.compound_statement.code_block[..],
+            &old_main.compound_statement.code_block[..],
+        )
+        else {
+            panic!("expected the statements of main");
+        };
+        assert_eq!(total.id, old_total.id);
+        assert_eq!(printf.id, old_printf.id);
+        assert_eq!(printf.argument_list, old_printf.argument_list);
+        let ExpressionObject::CallExpression(add_call) = total.value.as_deref().unwrap() else {
+            panic!("expected a call to add");
+        };
+        let ExpressionObject::CallExpression(old_add_call) = old_total.value.as_deref().unwrap()
+        else {
+            panic!("expected a call to add");
+        };
+        assert_eq!(add_call.id, old_add_call.id);
+        assert_eq!(add_call.id_declaration, old_add.id);
+        let ExpressionObject::CallExpression(square_call) = assignment.value.as_ref() else {
+            panic!("expected a call to square");
+        };
+        assert_eq!(square_call.id_declaration, square.id);
+        assert_eq!(*assigned_name(&assignment.left).0, total.id);
+    }
 }
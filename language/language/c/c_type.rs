@@ -1,6 +1,11 @@
 use crate::language::c::TreeSitterNodeExt;
+use crate::language::c::parsers::{
+    context::Context,
+    text::{ExpectShape, TreeSitterParserError},
+};
 use std::fmt;
 use std::str::FromStr;
+use uuid::Uuid;
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub enum CType {
@@ -10,14 +15,164 @@ pub enum CType {
     Char,
     #[default]
     Void,
+    /// Integer and floating types spelled with `signed`, `unsigned`, `short` or `long`
+    Sized(SizedType),
+    Struct(TypeReference),
+    Union(TypeReference),
+    Enum(TypeReference),
+    /// Type name declared outside the parsed source, like `size_t`
+    Typedef(String),
+    /// Type name declared by a `typedef` in the parsed source, referenced by id so renaming
+    /// the typedef carries through to every use
+    Named {
+        declaration_id: Uuid,
+        name: String,
+    },
+    Pointer(Box<CType>),
+    Array(ArrayType),
+    Qualified(Qualifiers, Box<CType>),
     Fn(FnType),
-    // TODO add more types as needed
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct FnType {
     pub return_type: Box<CType>,
-    pub parameters: Vec<CType>,
+    pub parameters: Vec<FnParameter>,
+    /// The parameter list ends in `...`
+    pub is_variadic: bool,
+    /// The parameter list is written `(void)` rather than `()`
+    pub explicit_void: bool,
+}
+
+/// Parameter of a function type, with the name it is declared with if it has one, like
+/// `value` in `int (*op)(int value)`
+#[derive(Debug, PartialEq, Clone)]
+pub struct FnParameter {
+    pub param_type: CType,
+    pub identifier: Option<String>,
+}
+
+impl FnType {
+    /// Parameter list as written between the parentheses, like `const char *, ...`
+    pub fn parameter_list(&self) -> String {
+        let mut parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| {
+                parameter
+                    .param_type
+                    .declaration(parameter.identifier.as_deref().unwrap_or_default())
+            })
+            .collect::<Vec<_>>();
+        if self.is_variadic {
+            parameters.push("...".to_string());
+        }
+        if self.explicit_void && parameters.is_empty() {
+            parameters.push("void".to_string());
+        }
+        parameters.join(", ")
+    }
+}
+
+/// Names a struct, union or enum definition by id, so renaming the definition
+/// carries through to every use of the type.
+#[derive(Debug, Clone)]
+pub struct TypeReference {
+    pub declaration_id: Uuid,
+    pub identifier: String,
+}
+
+impl PartialEq for TypeReference {
+    fn eq(&self, other: &Self) -> bool {
+        self.declaration_id == other.declaration_id && self.identifier == other.identifier
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Signedness {
+    Signed,
+    Unsigned,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Length {
+    Short,
+    Long,
+    LongLong,
+}
+
+/// Keeps the modifiers as written, so `unsigned` and `unsigned int` print back unchanged
+#[derive(Debug, PartialEq, Clone)]
+pub struct SizedType {
+    pub signedness: Option<Signedness>,
+    pub length: Option<Length>,
+    pub base: Option<Box<CType>>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ArrayType {
+    pub element: Box<CType>,
+    /// Size as written, like `4`, `N` or `BUF_LEN + 1`. `None` for arrays declared without a
+    /// size, like `char *argv[]`
+    pub size: Option<String>,
+    /// Qualifiers in the brackets of an array parameter, like `const` in `int a[const 4]`
+    pub qualifiers: Qualifiers,
+    /// `static` in the brackets of an array parameter, as in `int a[static 4]`
+    pub is_static: bool,
+}
+
+impl ArrayType {
+    /// What is written between the brackets, like `static const 4`
+    pub fn brackets(&self) -> String {
+        let mut words = Vec::new();
+        if self.is_static {
+            words.push("static");
+        }
+        words.extend(self.qualifiers.keywords());
+        words.extend(self.size.as_deref());
+        words.join(" ")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Qualifiers {
+    pub is_const: bool,
+    pub is_volatile: bool,
+    pub is_restrict: bool,
+}
+
+impl Qualifiers {
+    pub fn is_empty(&self) -> bool {
+        !(self.is_const || self.is_volatile || self.is_restrict)
+    }
+
+    /// Sets the qualifier named by `keyword`, returning false if it is not one
+    pub fn insert(&mut self, keyword: &str) -> bool {
+        match keyword {
+            "const" => self.is_const = true,
+            "volatile" => self.is_volatile = true,
+            "restrict" => self.is_restrict = true,
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn keywords(&self) -> Vec<&'static str> {
+        [
+            (self.is_const, "const"),
+            (self.is_volatile, "volatile"),
+            (self.is_restrict, "restrict"),
+        ]
+        .into_iter()
+        .filter_map(|(set, keyword)| set.then_some(keyword))
+        .collect()
+    }
+}
+
+impl fmt::Display for Qualifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.keywords().join(" "))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,33 +194,404 @@ impl FromStr for CType {
             "double" => Ok(CType::Double),
             "char" => Ok(CType::Char),
             "void" => Ok(CType::Void),
-            _ => Err(ParseCTypeError),
+            _ => s.parse::<SizedType>().map(CType::Sized),
         }
     }
 }
 
-impl CType {
-    pub fn as_str(&self) -> &str {
-        match self {
-            CType::Int => "int",
-            CType::Float => "float",
-            CType::Double => "double",
-            CType::Char => "char",
-            CType::Void => "void",
-            CType::Fn(_) => todo!(),
+impl FromStr for SizedType {
+    type Err = ParseCTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sized = SizedType {
+            signedness: None,
+            length: None,
+            base: None,
+        };
+        for word in s.split_whitespace() {
+            match (word, sized.length) {
+                ("signed" | "unsigned", _) if sized.signedness.is_some() => {
+                    return Err(ParseCTypeError);
+                }
+                ("signed", _) => sized.signedness = Some(Signedness::Signed),
+                ("unsigned", _) => sized.signedness = Some(Signedness::Unsigned),
+                ("short", None) => sized.length = Some(Length::Short),
+                ("long", None) => sized.length = Some(Length::Long),
+                ("long", Some(Length::Long)) => sized.length = Some(Length::LongLong),
+                ("int" | "char" | "double", _) if sized.base.is_none() => {
+                    sized.base = Some(Box::new(word.parse()?));
+                }
+                _ => return Err(ParseCTypeError),
+            }
+        }
+        if sized.signedness.is_none() && sized.length.is_none() {
+            return Err(ParseCTypeError);
         }
+        Ok(sized)
     }
+}
 
+impl fmt::Display for SizedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut words = vec![];
+        match self.signedness {
+            Some(Signedness::Signed) => words.push("signed".to_string()),
+            Some(Signedness::Unsigned) => words.push("unsigned".to_string()),
+            None => {}
+        }
+        match self.length {
+            Some(Length::Short) => words.push("short".to_string()),
+            Some(Length::Long) => words.push("long".to_string()),
+            Some(Length::LongLong) => words.push("long long".to_string()),
+            None => {}
+        }
+        if let Some(base) = &self.base {
+            words.push(base.to_string());
+        }
+        f.write_str(&words.join(" "))
+    }
+}
+
+impl fmt::Display for CType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.declaration(""))
+    }
+}
+
+impl CType {
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         s.parse::<CType>().ok()
     }
 
-    pub fn from_tree_sitter_node(node: tree_sitter::Node<'_>, source_code: &str) -> Self {
-        assert_eq!(node.kind(), "primitive_type");
-        let type_str = node.content(source_code);
-        type_str
-            .parse::<CType>()
-            .expect("invalid primitive_type text in AST")
+    /// Spells a declaration of `declarator` with this type, so `char *argv[]` comes back in
+    /// C declarator syntax instead of as a type followed by a name. An empty declarator gives
+    /// the type name on its own.
+    pub fn declaration(&self, declarator: &str) -> String {
+        match self {
+            CType::Pointer(pointee) => pointee.declaration(&format!("*{declarator}")),
+            CType::Qualified(qualifiers, base) => match base.as_ref() {
+                // Qualifiers of the pointer itself go after the star, as in `char *const p`
+                CType::Pointer(pointee) => {
+                    let declarator = format!("*{qualifiers} {declarator}");
+                    pointee.declaration(declarator.trim_end())
+                }
+                base => format!("{qualifiers} {}", base.declaration(declarator)),
+            },
+            CType::Fn(fn_type) => {
+                let parameters = fn_type.parameter_list();
+                if declarator.starts_with('*') {
+                    fn_type
+                        .return_type
+                        .declaration(&format!("({declarator})({parameters})"))
+                } else {
+                    fn_type
+                        .return_type
+                        .declaration(&format!("{declarator}({parameters})"))
+                }
+            }
+            CType::Array(array) => {
+                let size = array.brackets();
+                if declarator.starts_with('*') {
+                    array
+                        .element
+                        .declaration(&format!("({declarator})[{size}]"))
+                } else {
+                    array.element.declaration(&format!("{declarator}[{size}]"))
+                }
+            }
+            specifier => {
+                let name = match specifier {
+                    CType::Int => "int".to_string(),
+                    CType::Float => "float".to_string(),
+                    CType::Double => "double".to_string(),
+                    CType::Char => "char".to_string(),
+                    CType::Void => "void".to_string(),
+                    CType::Sized(sized) => sized.to_string(),
+                    CType::Struct(reference) => format!("struct {}", reference.identifier),
+                    CType::Union(reference) => format!("union {}", reference.identifier),
+                    CType::Enum(reference) => format!("enum {}", reference.identifier),
+                    CType::Typedef(name) | CType::Named { name, .. } => name.clone(),
+                    CType::Pointer(_) | CType::Qualified(..) | CType::Array(_) | CType::Fn(_) => {
+                        unreachable!()
+                    }
+                };
+                if declarator.is_empty() {
+                    name
+                } else {
+                    format!("{name} {declarator}")
+                }
+            }
+        }
+    }
+
+    /// Type specifier the declaration of this type starts with, together with its qualifiers:
+    /// `const char` for `const char *argv[]`. Declarators sharing it can be grouped.
+    pub fn specifier(&self) -> CType {
+        match self {
+            CType::Pointer(pointee) => pointee.specifier(),
+            CType::Qualified(qualifiers, base) => match base.as_ref() {
+                CType::Pointer(pointee) => pointee.specifier(),
+                base => base.specifier().qualified(*qualifiers),
+            },
+            CType::Fn(fn_type) => fn_type.return_type.specifier(),
+            CType::Array(array) => array.element.specifier(),
+            specifier => specifier.clone(),
+        }
+    }
+
+    /// Declaration of `declarator` without the leading type specifier, as written after the
+    /// comma in `int a, *b, c[3]`
+    pub fn declarator(&self, declarator: &str) -> String {
+        let declaration = self.declaration(declarator);
+        let specifier = self.specifier().declaration("");
+        match declaration.strip_prefix(&specifier) {
+            Some(declarator) => declarator.trim_start().to_string(),
+            None => declaration,
+        }
+    }
+
+    /// Keyword of a type that is declared elsewhere, as used in the `TypeDescription` node.
+    pub fn tag_keyword(&self) -> Option<&'static str> {
+        match self {
+            CType::Struct(_) => Some("struct"),
+            CType::Union(_) => Some("union"),
+            CType::Enum(_) => Some("enum"),
+            _ => None,
+        }
+    }
+
+    pub fn from_tag(keyword: &str, reference: TypeReference) -> Option<Self> {
+        match keyword {
+            "struct" => Some(CType::Struct(reference)),
+            "union" => Some(CType::Union(reference)),
+            "enum" => Some(CType::Enum(reference)),
+            _ => None,
+        }
+    }
+
+    /// Wraps the type in `qualifiers`, leaving it untouched when there are none
+    pub fn qualified(self, qualifiers: Qualifiers) -> Self {
+        if qualifiers.is_empty() {
+            self
+        } else {
+            CType::Qualified(qualifiers, Box::new(self))
+        }
+    }
+
+    /// The type without the qualifiers wrapped around it
+    pub fn unqualified(&self) -> &CType {
+        match self {
+            CType::Qualified(_, base) => base.unqualified(),
+            other => other,
+        }
+    }
+
+    /// Type reached by dereferencing or indexing a pointer or an array of this type
+    pub fn pointee(&self) -> Option<&CType> {
+        match self.unqualified() {
+            CType::Pointer(pointee) => Some(pointee),
+            CType::Array(array) => Some(&array.element),
+            _ => None,
+        }
+    }
+
+    /// Type of a declaration, parameter or field node: its type specifier together with the
+    /// qualifiers written next to it.
+    pub fn from_declaration_node(
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+        context: &Context,
+    ) -> Result<Self, TreeSitterParserError> {
+        let c_type =
+            CType::from_tree_sitter_node(node.expect_field("type")?, source_code, context)?;
+        let mut qualifiers = Qualifiers::default();
+        let mut cursor = node.walk();
+        for qualifier in node
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "type_qualifier")
+        {
+            qualifiers.insert(&qualifier.content(source_code));
+        }
+        Ok(c_type.qualified(qualifiers))
+    }
+
+    pub fn from_tree_sitter_node(
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+        context: &Context,
+    ) -> Result<Self, TreeSitterParserError> {
+        match node.kind() {
+            // tree-sitter also reads common typedefs like `size_t` or `uint8_t` as primitives
+            "primitive_type" => {
+                let type_str = node.content(source_code);
+                Ok(CType::from_str(&type_str).unwrap_or(CType::type_name(type_str, context)))
+            }
+            "sized_type_specifier" => {
+                let type_str = node.content(source_code);
+                type_str
+                    .parse::<CType>()
+                    .map_err(|_| TreeSitterParserError::WrongType(type_str))
+            }
+            "type_identifier" => Ok(CType::type_name(node.content(source_code), context)),
+            "struct_specifier" | "union_specifier" | "enum_specifier" => {
+                let identifier = node
+                    .child_by_field_name("name")
+                    .ok_or_else(|| {
+                        TreeSitterParserError::Unsupported(format!("anonymous {}", node.kind()))
+                    })?
+                    .content(source_code);
+                // Tags can be used before their definition, which binds them when found
+                let declaration_id = context
+                    .get_tag_id(&identifier)
+                    .unwrap_or_else(|| context.forward_tag_id(&identifier));
+                let keyword = node.expect_code_child(0)?.kind();
+                Ok(CType::from_tag(
+                    keyword,
+                    TypeReference {
+                        declaration_id,
+                        identifier,
+                    },
+                )
+                .ok_or_else(|| TreeSitterParserError::Unsupported(keyword.to_string()))?)
+            }
+            other => Err(TreeSitterParserError::Unsupported(other.to_string())),
+        }
+    }
+
+    /// Type named by a bare identifier: the typedef declared with that name in the parsed
+    /// source, or an external type name when there is none.
+    pub fn type_name(name: String, context: &Context) -> Self {
+        match context.get_type_name_id(&name) {
+            Some(declaration_id) => CType::Named {
+                declaration_id,
+                name,
+            },
+            None => CType::Typedef(name),
+        }
+    }
+
+    /// Type of a parameter, with the name it declares if it has one
+    pub fn from_parameter_node<'tree>(
+        node: tree_sitter::Node<'tree>,
+        source_code: &str,
+        context: &Context,
+    ) -> Result<(Self, Option<tree_sitter::Node<'tree>>), TreeSitterParserError> {
+        let base_type = CType::from_declaration_node(node, source_code, context)?;
+        match node.child_by_field_name("declarator") {
+            Some(declarator) => base_type.apply_declarator(declarator, source_code, context),
+            None => Ok((base_type, None)),
+        }
+    }
+
+    /// Applies the pointer, array, function and parenthesized declarators wrapped around a
+    /// name to this type, from the outside in. Returns the complete type with the node the
+    /// declarators wrap: the declared identifier, the declarator of a function being declared,
+    /// or `None` for abstract ones.
+    pub fn apply_declarator<'tree>(
+        self,
+        node: tree_sitter::Node<'tree>,
+        source_code: &str,
+        context: &Context,
+    ) -> Result<(Self, Option<tree_sitter::Node<'tree>>), TreeSitterParserError> {
+        match node.kind() {
+            "function_declarator" | "abstract_function_declarator" => {
+                let declarator = node.child_by_field_name("declarator");
+                // Around a plain name it declares the function itself rather than a type
+                if let Some(declarator) = declarator
+                    && declarator.kind() == "identifier"
+                {
+                    return Ok((self, Some(node)));
+                }
+                let parameters_node = node.expect_field("parameters")?;
+                let mut fn_type = FnType {
+                    return_type: Box::new(self),
+                    parameters: Vec::new(),
+                    is_variadic: false,
+                    explicit_void: false,
+                };
+                let mut cursor = parameters_node.walk();
+                for parameter in parameters_node.named_children(&mut cursor) {
+                    match parameter.kind() {
+                        "parameter_declaration" => {
+                            let (param_type, name_node) =
+                                CType::from_parameter_node(parameter, source_code, context)?;
+                            fn_type.parameters.push(FnParameter {
+                                param_type,
+                                identifier: name_node
+                                    .filter(|name_node| name_node.kind() == "identifier")
+                                    .map(|name_node| name_node.content(source_code)),
+                            });
+                        }
+                        "variadic_parameter" => fn_type.is_variadic = true,
+                        _ => {}
+                    }
+                }
+                // A lone unnamed `void` is not a parameter, it declares that there are none
+                if let [
+                    FnParameter {
+                        param_type: CType::Void,
+                        identifier: None,
+                    },
+                ] = fn_type.parameters.as_slice()
+                {
+                    fn_type.parameters.clear();
+                    fn_type.explicit_void = true;
+                }
+                let c_type = CType::Fn(fn_type);
+                match declarator {
+                    Some(declarator) => c_type.apply_declarator(declarator, source_code, context),
+                    None => Ok((c_type, None)),
+                }
+            }
+            "pointer_declarator" | "abstract_pointer_declarator" => {
+                let mut qualifiers = Qualifiers::default();
+                let mut cursor = node.walk();
+                for qualifier in node
+                    .children(&mut cursor)
+                    .filter(|child| child.kind() == "type_qualifier")
+                {
+                    qualifiers.insert(&qualifier.content(source_code));
+                }
+                let c_type = CType::Pointer(Box::new(self)).qualified(qualifiers);
+                match node.child_by_field_name("declarator") {
+                    Some(declarator) => c_type.apply_declarator(declarator, source_code, context),
+                    None => Ok((c_type, None)),
+                }
+            }
+            "array_declarator" | "abstract_array_declarator" => {
+                let mut qualifiers = Qualifiers::default();
+                let mut is_static = false;
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    match child.kind() {
+                        "type_qualifier" => {
+                            qualifiers.insert(&child.content(source_code));
+                        }
+                        "static" => is_static = true,
+                        _ => {}
+                    }
+                }
+                let c_type = CType::Array(ArrayType {
+                    element: Box::new(self),
+                    size: node
+                        .child_by_field_name("size")
+                        .map(|size| size.content(source_code)),
+                    qualifiers,
+                    is_static,
+                });
+                match node.child_by_field_name("declarator") {
+                    Some(declarator) => c_type.apply_declarator(declarator, source_code, context),
+                    None => Ok((c_type, None)),
+                }
+            }
+            "parenthesized_declarator" | "abstract_parenthesized_declarator" => {
+                match node.named_child(0) {
+                    Some(declarator) => self.apply_declarator(declarator, source_code, context),
+                    None => Ok((self, None)),
+                }
+            }
+            _ => Ok((self, Some(node))),
+        }
     }
 }
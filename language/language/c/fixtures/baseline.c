@@ -0,0 +1,19 @@
+#include <stdio.h>
+
+int limit = 0x1F;
+
+int clamp(int value)
+{
+    if (value > limit) {
+        return -1;
+    } else {
+        return value;
+    }
+}
+
+int main()
+{
+    int offset = -2;
+    printf("hi\n", clamp(offset + 5));
+    return 0;
+}
@@ -7,27 +7,54 @@ pub mod statement_object;
 mod field_inspect_test;
 
 use crate::language::c::{
-    language_object::declaration_object::function_declaration::function_parameter::FunctionParameter,
+    language_object::declaration_object::{
+        enum_definition::enumerator::Enumerator,
+        function_declaration::function_parameter::FunctionParameter,
+        struct_definition::field_declaration::FieldDeclaration,
+    },
     parsers::{nodes::NodeParserError, text::TreeSitterParserError},
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 use std::{any::Any, fmt::Debug};
 use uuid::Uuid;
 
 use declaration_object::{
-    declaration::Declaration, function_declaration::FunctionDeclaration,
-    function_definition::FunctionDefinition, preproc_include::PreprocInclude,
+    declaration::Declaration, declaration_list::DeclarationList, enum_definition::EnumDefinition,
+    function_declaration::FunctionDeclaration, function_definition::FunctionDefinition,
+    preproc_call::PreprocCall, preproc_define::PreprocDefine, preproc_if::PreprocIf,
+    preproc_include::PreprocInclude, struct_definition::StructDefinition,
+    type_definition::TypeDefinition, union_definition::UnionDefinition,
 };
 use expression_object::{
     assignment_expression::AssignmentExpression, binary_expression::BinaryExpression,
-    call_expression::CallExpression, number_literal::NumberLiteral, reference::Reference,
-    string_literal::StringLiteral,
+    call_expression::CallExpression, cast_expression::CastExpression, char_literal::CharLiteral,
+    conditional_expression::ConditionalExpression, field_expression::FieldExpression,
+    initializer_list::InitializerList, initializer_pair::InitializerPair,
+    number_literal::NumberLiteral, parenthesized_expression::ParenthesizedExpression,
+    reference::Reference, sizeof_expression::SizeofExpression, string_literal::StringLiteral,
+    subscript_expression::SubscriptExpression, unary_expression::UnaryExpression,
+    update_expression::UpdateExpression,
+};
+use special_object::{
+    comment::{Comment, Comments},
+    source_file::SourceFile,
+    unknown::Unknown,
 };
-use special_object::{comment::Comment, source_file::SourceFile, unknown::Unknown};
 use statement_object::{
+    break_statement::BreakStatement,
+    case_statement::CaseStatement,
     compound_statement::CompoundStatement,
+    continue_statement::ContinueStatement,
+    do_statement::DoStatement,
+    for_statement::ForStatement,
+    goto_statement::GotoStatement,
     if_statement::{IfStatement, else_clause::ElseClause},
+    labeled_statement::LabeledStatement,
+    preproc_if_statement::PreprocIfStatement,
     return_statement::ReturnStatement,
+    switch_statement::SwitchStatement,
+    while_statement::WhileStatement,
 };
 
 // fn write(&self, w: &mut dyn Writer) -> Result<(), WriterError>;
@@ -38,22 +65,53 @@ pub enum LanguageObject {
 
     AssignmentExpression(AssignmentExpression),
     BinaryExpression(BinaryExpression),
+    UnaryExpression(UnaryExpression),
+    UpdateExpression(UpdateExpression),
+    CastExpression(CastExpression),
+    SizeofExpression(SizeofExpression),
+    ConditionalExpression(ConditionalExpression),
+    FieldExpression(FieldExpression),
+    SubscriptExpression(SubscriptExpression),
+    ParenthesizedExpression(ParenthesizedExpression),
+    InitializerList(InitializerList),
+    InitializerPair(InitializerPair),
     CallExpression(CallExpression),
     NumberLiteral(NumberLiteral),
     StringLiteral(StringLiteral),
+    CharLiteral(CharLiteral),
 
+    BreakStatement(BreakStatement),
+    CaseStatement(CaseStatement),
     CompoundStatement(CompoundStatement),
+    ContinueStatement(ContinueStatement),
+    DoStatement(DoStatement),
+    ForStatement(ForStatement),
+    GotoStatement(GotoStatement),
     IfStatement(IfStatement),
+    LabeledStatement(LabeledStatement),
     ReturnStatement(ReturnStatement),
+    SwitchStatement(SwitchStatement),
+    WhileStatement(WhileStatement),
+    PreprocIfStatement(PreprocIfStatement),
 
     Declaration(Declaration),
+    DeclarationList(DeclarationList),
     FunctionDeclaration(FunctionDeclaration),
     FunctionDefinition(FunctionDefinition),
     PreprocInclude(PreprocInclude),
     Reference(Reference),
+    StructDefinition(StructDefinition),
+    UnionDefinition(UnionDefinition),
+    EnumDefinition(EnumDefinition),
+    TypeDefinition(TypeDefinition),
+    PreprocDefine(PreprocDefine),
+    PreprocCall(PreprocCall),
+    PreprocIf(PreprocIf),
 
     ElseClause(ElseClause),
     FunctionParameter(FunctionParameter),
+    FieldDeclaration(FieldDeclaration),
+    Enumerator(Enumerator),
     Unknown(Unknown),
     Comment(Comment),
 }
@@ -66,14 +124,45 @@ impl LanguageObject {
                 assignment_expression.write(w)
             }
             LanguageObject::BinaryExpression(binary_expression) => binary_expression.write(w),
+            LanguageObject::UnaryExpression(unary_expression) => unary_expression.write(w),
+            LanguageObject::UpdateExpression(update_expression) => update_expression.write(w),
+            LanguageObject::CastExpression(cast_expression) => cast_expression.write(w),
+            LanguageObject::SizeofExpression(sizeof_expression) => sizeof_expression.write(w),
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                conditional_expression.write(w)
+            }
+            LanguageObject::FieldExpression(field_expression) => field_expression.write(w),
+            LanguageObject::SubscriptExpression(subscript_expression) => {
+                subscript_expression.write(w)
+            }
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                parenthesized_expression.write(w)
+            }
+            LanguageObject::InitializerList(initializer_list) => initializer_list.write(w),
+            LanguageObject::InitializerPair(initializer_pair) => initializer_pair.write(w),
+            LanguageObject::BreakStatement(break_statement) => break_statement.write(w),
             LanguageObject::CallExpression(call_expression) => call_expression.write(w),
+            LanguageObject::CaseStatement(case_statement) => case_statement.write(w),
             LanguageObject::Comment(comment) => comment.write(w),
+            LanguageObject::ContinueStatement(continue_statement) => continue_statement.write(w),
+            LanguageObject::GotoStatement(goto_statement) => goto_statement.write(w),
+            LanguageObject::LabeledStatement(labeled_statement) => labeled_statement.write(w),
             LanguageObject::Declaration(declaration) => declaration.write(w),
+            LanguageObject::DeclarationList(declaration_list) => declaration_list.write(w),
+            LanguageObject::DoStatement(do_statement) => do_statement.write(w),
             LanguageObject::ElseClause(else_clause) => else_clause.write(w),
+            LanguageObject::EnumDefinition(enum_definition) => enum_definition.write(w),
+            LanguageObject::TypeDefinition(type_definition) => type_definition.write(w),
+            LanguageObject::PreprocDefine(preproc_define) => preproc_define.write(w),
+            LanguageObject::PreprocCall(preproc_call) => preproc_call.write(w),
+            LanguageObject::PreprocIf(preproc_if) => preproc_if.write(w),
+            LanguageObject::Enumerator(enumerator) => enumerator.write(w),
+            LanguageObject::FieldDeclaration(field_declaration) => field_declaration.write(w),
             LanguageObject::FunctionDeclaration(function_declaration) => {
                 function_declaration.write(w)
             }
             LanguageObject::FunctionDefinition(function_definition) => function_definition.write(w),
+            LanguageObject::ForStatement(for_statement) => for_statement.write(w),
             LanguageObject::FunctionParameter(function_parameter) => function_parameter.write(w),
             LanguageObject::IfStatement(if_statement) => if_statement.write(w),
             LanguageObject::NumberLiteral(number_literal) => number_literal.write(w),
@@ -81,7 +170,15 @@ impl LanguageObject {
             LanguageObject::Reference(reference) => reference.write(w),
             LanguageObject::ReturnStatement(return_statement) => return_statement.write(w),
             LanguageObject::StringLiteral(string_literal) => string_literal.write(w),
+            LanguageObject::CharLiteral(char_literal) => char_literal.write(w),
+            LanguageObject::StructDefinition(struct_definition) => struct_definition.write(w),
+            LanguageObject::SwitchStatement(switch_statement) => switch_statement.write(w),
+            LanguageObject::UnionDefinition(union_definition) => union_definition.write(w),
             LanguageObject::CompoundStatement(compound_statement) => compound_statement.write(w),
+            LanguageObject::WhileStatement(while_statement) => while_statement.write(w),
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => {
+                preproc_if_statement.write(w)
+            }
             LanguageObject::Unknown(unknown) => unknown.write(w),
         }
     }
@@ -91,12 +188,41 @@ impl LanguageObject {
             LanguageObject::SourceFile(src_file) => src_file.id,
             LanguageObject::AssignmentExpression(assignment_expression) => assignment_expression.id,
             LanguageObject::BinaryExpression(binary_expression) => binary_expression.id,
+            LanguageObject::UnaryExpression(unary_expression) => unary_expression.id,
+            LanguageObject::UpdateExpression(update_expression) => update_expression.id,
+            LanguageObject::CastExpression(cast_expression) => cast_expression.id,
+            LanguageObject::SizeofExpression(sizeof_expression) => sizeof_expression.id,
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                conditional_expression.id
+            }
+            LanguageObject::FieldExpression(field_expression) => field_expression.id,
+            LanguageObject::SubscriptExpression(subscript_expression) => subscript_expression.id,
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                parenthesized_expression.id
+            }
+            LanguageObject::InitializerList(initializer_list) => initializer_list.id,
+            LanguageObject::InitializerPair(initializer_pair) => initializer_pair.id,
+            LanguageObject::BreakStatement(break_statement) => break_statement.id,
             LanguageObject::CallExpression(call_expression) => call_expression.id,
+            LanguageObject::CaseStatement(case_statement) => case_statement.id,
             LanguageObject::Comment(comment) => comment.id,
+            LanguageObject::ContinueStatement(continue_statement) => continue_statement.id,
+            LanguageObject::GotoStatement(goto_statement) => goto_statement.id,
+            LanguageObject::LabeledStatement(labeled_statement) => labeled_statement.id,
             LanguageObject::Declaration(declaration) => declaration.id,
+            LanguageObject::DeclarationList(declaration_list) => declaration_list.id,
+            LanguageObject::DoStatement(do_statement) => do_statement.id,
             LanguageObject::ElseClause(else_clause) => else_clause.id,
+            LanguageObject::EnumDefinition(enum_definition) => enum_definition.id,
+            LanguageObject::TypeDefinition(type_definition) => type_definition.id,
+            LanguageObject::PreprocDefine(preproc_define) => preproc_define.id,
+            LanguageObject::PreprocCall(preproc_call) => preproc_call.id,
+            LanguageObject::PreprocIf(preproc_if) => preproc_if.id,
+            LanguageObject::Enumerator(enumerator) => enumerator.id,
+            LanguageObject::FieldDeclaration(field_declaration) => field_declaration.id,
             LanguageObject::FunctionDeclaration(function_declaration) => function_declaration.id,
             LanguageObject::FunctionDefinition(function_definition) => function_definition.id,
+            LanguageObject::ForStatement(for_statement) => for_statement.id,
             LanguageObject::FunctionParameter(function_parameter) => function_parameter.id,
             LanguageObject::IfStatement(if_statement) => if_statement.id,
             LanguageObject::NumberLiteral(number_literal) => number_literal.id,
@@ -104,10 +230,332 @@ impl LanguageObject {
             LanguageObject::Reference(reference) => reference.id,
             LanguageObject::ReturnStatement(return_statement) => return_statement.id,
             LanguageObject::StringLiteral(string_literal) => string_literal.id,
+            LanguageObject::CharLiteral(char_literal) => char_literal.id,
+            LanguageObject::StructDefinition(struct_definition) => struct_definition.id,
+            LanguageObject::SwitchStatement(switch_statement) => switch_statement.id,
+            LanguageObject::UnionDefinition(union_definition) => union_definition.id,
             LanguageObject::CompoundStatement(compound_statement) => compound_statement.id,
+            LanguageObject::WhileStatement(while_statement) => while_statement.id,
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => preproc_if_statement.id,
             LanguageObject::Unknown(unknown) => unknown.id,
         }
     }
+
+    /// Where the object was parsed from, if it came from source text
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            LanguageObject::SourceFile(src_file) => src_file.span,
+            LanguageObject::AssignmentExpression(assignment_expression) => {
+                assignment_expression.span
+            }
+            LanguageObject::BinaryExpression(binary_expression) => binary_expression.span,
+            LanguageObject::UnaryExpression(unary_expression) => unary_expression.span,
+            LanguageObject::UpdateExpression(update_expression) => update_expression.span,
+            LanguageObject::CastExpression(cast_expression) => cast_expression.span,
+            LanguageObject::SizeofExpression(sizeof_expression) => sizeof_expression.span,
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                conditional_expression.span
+            }
+            LanguageObject::FieldExpression(field_expression) => field_expression.span,
+            LanguageObject::SubscriptExpression(subscript_expression) => subscript_expression.span,
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                parenthesized_expression.span
+            }
+            LanguageObject::InitializerList(initializer_list) => initializer_list.span,
+            LanguageObject::InitializerPair(initializer_pair) => initializer_pair.span,
+            LanguageObject::BreakStatement(break_statement) => break_statement.span,
+            LanguageObject::CallExpression(call_expression) => call_expression.span,
+            LanguageObject::CaseStatement(case_statement) => case_statement.span,
+            LanguageObject::Comment(comment) => comment.span,
+            LanguageObject::ContinueStatement(continue_statement) => continue_statement.span,
+            LanguageObject::GotoStatement(goto_statement) => goto_statement.span,
+            LanguageObject::LabeledStatement(labeled_statement) => labeled_statement.span,
+            LanguageObject::Declaration(declaration) => declaration.span,
+            LanguageObject::DeclarationList(declaration_list) => declaration_list.span,
+            LanguageObject::DoStatement(do_statement) => do_statement.span,
+            LanguageObject::ElseClause(else_clause) => else_clause.span,
+            LanguageObject::EnumDefinition(enum_definition) => enum_definition.span,
+            LanguageObject::TypeDefinition(type_definition) => type_definition.span,
+            LanguageObject::PreprocDefine(preproc_define) => preproc_define.span,
+            LanguageObject::PreprocCall(preproc_call) => preproc_call.span,
+            LanguageObject::PreprocIf(preproc_if) => preproc_if.span,
+            LanguageObject::Enumerator(enumerator) => enumerator.span,
+            LanguageObject::FieldDeclaration(field_declaration) => field_declaration.span,
+            LanguageObject::FunctionDeclaration(function_declaration) => function_declaration.span,
+            LanguageObject::FunctionDefinition(function_definition) => function_definition.span,
+            LanguageObject::ForStatement(for_statement) => for_statement.span,
+            LanguageObject::FunctionParameter(function_parameter) => function_parameter.span,
+            LanguageObject::IfStatement(if_statement) => if_statement.span,
+            LanguageObject::NumberLiteral(number_literal) => number_literal.span,
+            LanguageObject::PreprocInclude(preproc_include) => preproc_include.span,
+            LanguageObject::Reference(reference) => reference.span,
+            LanguageObject::ReturnStatement(return_statement) => return_statement.span,
+            LanguageObject::StringLiteral(string_literal) => string_literal.span,
+            LanguageObject::CharLiteral(char_literal) => char_literal.span,
+            LanguageObject::StructDefinition(struct_definition) => struct_definition.span,
+            LanguageObject::SwitchStatement(switch_statement) => switch_statement.span,
+            LanguageObject::UnionDefinition(union_definition) => union_definition.span,
+            LanguageObject::CompoundStatement(compound_statement) => compound_statement.span,
+            LanguageObject::WhileStatement(while_statement) => while_statement.span,
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => preproc_if_statement.span,
+            LanguageObject::Unknown(unknown) => unknown.span,
+        }
+    }
+
+    pub fn span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            LanguageObject::SourceFile(src_file) => &mut src_file.span,
+            LanguageObject::AssignmentExpression(assignment_expression) => {
+                &mut assignment_expression.span
+            }
+            LanguageObject::BinaryExpression(binary_expression) => &mut binary_expression.span,
+            LanguageObject::UnaryExpression(unary_expression) => &mut unary_expression.span,
+            LanguageObject::UpdateExpression(update_expression) => &mut update_expression.span,
+            LanguageObject::CastExpression(cast_expression) => &mut cast_expression.span,
+            LanguageObject::SizeofExpression(sizeof_expression) => &mut sizeof_expression.span,
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                &mut conditional_expression.span
+            }
+            LanguageObject::FieldExpression(field_expression) => &mut field_expression.span,
+            LanguageObject::SubscriptExpression(subscript_expression) => {
+                &mut subscript_expression.span
+            }
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                &mut parenthesized_expression.span
+            }
+            LanguageObject::InitializerList(initializer_list) => &mut initializer_list.span,
+            LanguageObject::InitializerPair(initializer_pair) => &mut initializer_pair.span,
+            LanguageObject::BreakStatement(break_statement) => &mut break_statement.span,
+            LanguageObject::CallExpression(call_expression) => &mut call_expression.span,
+            LanguageObject::CaseStatement(case_statement) => &mut case_statement.span,
+            LanguageObject::Comment(comment) => &mut comment.span,
+            LanguageObject::ContinueStatement(continue_statement) => &mut continue_statement.span,
+            LanguageObject::GotoStatement(goto_statement) => &mut goto_statement.span,
+            LanguageObject::LabeledStatement(labeled_statement) => &mut labeled_statement.span,
+            LanguageObject::Declaration(declaration) => &mut declaration.span,
+            LanguageObject::DeclarationList(declaration_list) => &mut declaration_list.span,
+            LanguageObject::DoStatement(do_statement) => &mut do_statement.span,
+            LanguageObject::ElseClause(else_clause) => &mut else_clause.span,
+            LanguageObject::EnumDefinition(enum_definition) => &mut enum_definition.span,
+            LanguageObject::TypeDefinition(type_definition) => &mut type_definition.span,
+            LanguageObject::PreprocDefine(preproc_define) => &mut preproc_define.span,
+            LanguageObject::PreprocCall(preproc_call) => &mut preproc_call.span,
+            LanguageObject::PreprocIf(preproc_if) => &mut preproc_if.span,
+            LanguageObject::Enumerator(enumerator) => &mut enumerator.span,
+            LanguageObject::FieldDeclaration(field_declaration) => &mut field_declaration.span,
+            LanguageObject::FunctionDeclaration(function_declaration) => {
+                &mut function_declaration.span
+            }
+            LanguageObject::FunctionDefinition(function_definition) => {
+                &mut function_definition.span
+            }
+            LanguageObject::ForStatement(for_statement) => &mut for_statement.span,
+            LanguageObject::FunctionParameter(function_parameter) => &mut function_parameter.span,
+            LanguageObject::IfStatement(if_statement) => &mut if_statement.span,
+            LanguageObject::NumberLiteral(number_literal) => &mut number_literal.span,
+            LanguageObject::PreprocInclude(preproc_include) => &mut preproc_include.span,
+            LanguageObject::Reference(reference) => &mut reference.span,
+            LanguageObject::ReturnStatement(return_statement) => &mut return_statement.span,
+            LanguageObject::StringLiteral(string_literal) => &mut string_literal.span,
+            LanguageObject::CharLiteral(char_literal) => &mut char_literal.span,
+            LanguageObject::StructDefinition(struct_definition) => &mut struct_definition.span,
+            LanguageObject::SwitchStatement(switch_statement) => &mut switch_statement.span,
+            LanguageObject::UnionDefinition(union_definition) => &mut union_definition.span,
+            LanguageObject::CompoundStatement(compound_statement) => &mut compound_statement.span,
+            LanguageObject::WhileStatement(while_statement) => &mut while_statement.span,
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => {
+                &mut preproc_if_statement.span
+            }
+            LanguageObject::Unknown(unknown) => &mut unknown.span,
+        }
+    }
+
+    /// Comments attached to the object. Source files and comments themselves have none
+    pub fn comments(&self) -> Option<&Comments> {
+        match self {
+            LanguageObject::SourceFile(_) | LanguageObject::Comment(_) => None,
+            LanguageObject::AssignmentExpression(assignment_expression) => {
+                Some(&assignment_expression.comments)
+            }
+            LanguageObject::BinaryExpression(binary_expression) => {
+                Some(&binary_expression.comments)
+            }
+            LanguageObject::UnaryExpression(unary_expression) => Some(&unary_expression.comments),
+            LanguageObject::UpdateExpression(update_expression) => {
+                Some(&update_expression.comments)
+            }
+            LanguageObject::CastExpression(cast_expression) => Some(&cast_expression.comments),
+            LanguageObject::SizeofExpression(sizeof_expression) => {
+                Some(&sizeof_expression.comments)
+            }
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                Some(&conditional_expression.comments)
+            }
+            LanguageObject::FieldExpression(field_expression) => Some(&field_expression.comments),
+            LanguageObject::SubscriptExpression(subscript_expression) => {
+                Some(&subscript_expression.comments)
+            }
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                Some(&parenthesized_expression.comments)
+            }
+            LanguageObject::InitializerList(initializer_list) => Some(&initializer_list.comments),
+            LanguageObject::InitializerPair(initializer_pair) => Some(&initializer_pair.comments),
+            LanguageObject::BreakStatement(break_statement) => Some(&break_statement.comments),
+            LanguageObject::CallExpression(call_expression) => Some(&call_expression.comments),
+            LanguageObject::CaseStatement(case_statement) => Some(&case_statement.comments),
+            LanguageObject::ContinueStatement(continue_statement) => {
+                Some(&continue_statement.comments)
+            }
+            LanguageObject::GotoStatement(goto_statement) => Some(&goto_statement.comments),
+            LanguageObject::LabeledStatement(labeled_statement) => {
+                Some(&labeled_statement.comments)
+            }
+            LanguageObject::Declaration(declaration) => Some(&declaration.comments),
+            LanguageObject::DeclarationList(declaration_list) => Some(&declaration_list.comments),
+            LanguageObject::DoStatement(do_statement) => Some(&do_statement.comments),
+            LanguageObject::ElseClause(else_clause) => Some(&else_clause.comments),
+            LanguageObject::EnumDefinition(enum_definition) => Some(&enum_definition.comments),
+            LanguageObject::TypeDefinition(type_definition) => Some(&type_definition.comments),
+            LanguageObject::PreprocDefine(preproc_define) => Some(&preproc_define.comments),
+            LanguageObject::PreprocCall(preproc_call) => Some(&preproc_call.comments),
+            LanguageObject::PreprocIf(preproc_if) => Some(&preproc_if.comments),
+            LanguageObject::Enumerator(enumerator) => Some(&enumerator.comments),
+            LanguageObject::FieldDeclaration(field_declaration) => {
+                Some(&field_declaration.comments)
+            }
+            LanguageObject::FunctionDeclaration(function_declaration) => {
+                Some(&function_declaration.comments)
+            }
+            LanguageObject::FunctionDefinition(function_definition) => {
+                Some(&function_definition.comments)
+            }
+            LanguageObject::ForStatement(for_statement) => Some(&for_statement.comments),
+            LanguageObject::FunctionParameter(function_parameter) => {
+                Some(&function_parameter.comments)
+            }
+            LanguageObject::IfStatement(if_statement) => Some(&if_statement.comments),
+            LanguageObject::NumberLiteral(number_literal) => Some(&number_literal.comments),
+            LanguageObject::PreprocInclude(preproc_include) => Some(&preproc_include.comments),
+            LanguageObject::Reference(reference) => Some(&reference.comments),
+            LanguageObject::ReturnStatement(return_statement) => Some(&return_statement.comments),
+            LanguageObject::StringLiteral(string_literal) => Some(&string_literal.comments),
+            LanguageObject::CharLiteral(char_literal) => Some(&char_literal.comments),
+            LanguageObject::StructDefinition(struct_definition) => {
+                Some(&struct_definition.comments)
+            }
+            LanguageObject::SwitchStatement(switch_statement) => Some(&switch_statement.comments),
+            LanguageObject::UnionDefinition(union_definition) => Some(&union_definition.comments),
+            LanguageObject::CompoundStatement(compound_statement) => {
+                Some(&compound_statement.comments)
+            }
+            LanguageObject::WhileStatement(while_statement) => Some(&while_statement.comments),
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => {
+                Some(&preproc_if_statement.comments)
+            }
+            LanguageObject::Unknown(unknown) => Some(&unknown.comments),
+        }
+    }
+
+    pub fn comments_mut(&mut self) -> Option<&mut Comments> {
+        match self {
+            LanguageObject::SourceFile(_) | LanguageObject::Comment(_) => None,
+            LanguageObject::AssignmentExpression(assignment_expression) => {
+                Some(&mut assignment_expression.comments)
+            }
+            LanguageObject::BinaryExpression(binary_expression) => {
+                Some(&mut binary_expression.comments)
+            }
+            LanguageObject::UnaryExpression(unary_expression) => {
+                Some(&mut unary_expression.comments)
+            }
+            LanguageObject::UpdateExpression(update_expression) => {
+                Some(&mut update_expression.comments)
+            }
+            LanguageObject::CastExpression(cast_expression) => Some(&mut cast_expression.comments),
+            LanguageObject::SizeofExpression(sizeof_expression) => {
+                Some(&mut sizeof_expression.comments)
+            }
+            LanguageObject::ConditionalExpression(conditional_expression) => {
+                Some(&mut conditional_expression.comments)
+            }
+            LanguageObject::FieldExpression(field_expression) => {
+                Some(&mut field_expression.comments)
+            }
+            LanguageObject::SubscriptExpression(subscript_expression) => {
+                Some(&mut subscript_expression.comments)
+            }
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+                Some(&mut parenthesized_expression.comments)
+            }
+            LanguageObject::InitializerList(initializer_list) => {
+                Some(&mut initializer_list.comments)
+            }
+            LanguageObject::InitializerPair(initializer_pair) => {
+                Some(&mut initializer_pair.comments)
+            }
+            LanguageObject::BreakStatement(break_statement) => Some(&mut break_statement.comments),
+            LanguageObject::CallExpression(call_expression) => Some(&mut call_expression.comments),
+            LanguageObject::CaseStatement(case_statement) => Some(&mut case_statement.comments),
+            LanguageObject::ContinueStatement(continue_statement) => {
+                Some(&mut continue_statement.comments)
+            }
+            LanguageObject::GotoStatement(goto_statement) => Some(&mut goto_statement.comments),
+            LanguageObject::LabeledStatement(labeled_statement) => {
+                Some(&mut labeled_statement.comments)
+            }
+            LanguageObject::Declaration(declaration) => Some(&mut declaration.comments),
+            LanguageObject::DeclarationList(declaration_list) => {
+                Some(&mut declaration_list.comments)
+            }
+            LanguageObject::DoStatement(do_statement) => Some(&mut do_statement.comments),
+            LanguageObject::ElseClause(else_clause) => Some(&mut else_clause.comments),
+            LanguageObject::EnumDefinition(enum_definition) => Some(&mut enum_definition.comments),
+            LanguageObject::TypeDefinition(type_definition) => Some(&mut type_definition.comments),
+            LanguageObject::PreprocDefine(preproc_define) => Some(&mut preproc_define.comments),
+            LanguageObject::PreprocCall(preproc_call) => Some(&mut preproc_call.comments),
+            LanguageObject::PreprocIf(preproc_if) => Some(&mut preproc_if.comments),
+            LanguageObject::Enumerator(enumerator) => Some(&mut enumerator.comments),
+            LanguageObject::FieldDeclaration(field_declaration) => {
+                Some(&mut field_declaration.comments)
+            }
+            LanguageObject::FunctionDeclaration(function_declaration) => {
+                Some(&mut function_declaration.comments)
+            }
+            LanguageObject::FunctionDefinition(function_definition) => {
+                Some(&mut function_definition.comments)
+            }
+            LanguageObject::ForStatement(for_statement) => Some(&mut for_statement.comments),
+            LanguageObject::FunctionParameter(function_parameter) => {
+                Some(&mut function_parameter.comments)
+            }
+            LanguageObject::IfStatement(if_statement) => Some(&mut if_statement.comments),
+            LanguageObject::NumberLiteral(number_literal) => Some(&mut number_literal.comments),
+            LanguageObject::PreprocInclude(preproc_include) => Some(&mut preproc_include.comments),
+            LanguageObject::Reference(reference) => Some(&mut reference.comments),
+            LanguageObject::ReturnStatement(return_statement) => {
+                Some(&mut return_statement.comments)
+            }
+            LanguageObject::StringLiteral(string_literal) => Some(&mut string_literal.comments),
+            LanguageObject::CharLiteral(char_literal) => Some(&mut char_literal.comments),
+            LanguageObject::StructDefinition(struct_definition) => {
+                Some(&mut struct_definition.comments)
+            }
+            LanguageObject::SwitchStatement(switch_statement) => {
+                Some(&mut switch_statement.comments)
+            }
+            LanguageObject::UnionDefinition(union_definition) => {
+                Some(&mut union_definition.comments)
+            }
+            LanguageObject::CompoundStatement(compound_statement) => {
+                Some(&mut compound_statement.comments)
+            }
+            LanguageObject::WhileStatement(while_statement) => Some(&mut while_statement.comments),
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => {
+                Some(&mut preproc_if_statement.comments)
+            }
+            LanguageObject::Unknown(unknown) => Some(&mut unknown.comments),
+        }
+    }
 }
 
 impl Default for LanguageObject {
@@ -6,9 +6,17 @@ use crate::language::c::language_object::{
 use uuid::Uuid;
 
 pub mod declaration;
+pub mod declaration_list;
+pub mod enum_definition;
 pub mod function_declaration;
 pub mod function_definition;
+pub mod preproc_call;
+pub mod preproc_define;
+pub mod preproc_if;
 pub mod preproc_include;
+pub mod struct_definition;
+pub mod type_definition;
+pub mod union_definition;
 
 #[derive(
     Debug,
@@ -19,10 +27,19 @@ pub mod preproc_include;
 )]
 pub enum DeclarationObject {
     Declaration(declaration::Declaration),
+    DeclarationList(declaration_list::DeclarationList),
     FunctionDeclaration(function_declaration::FunctionDeclaration),
     FunctionDefinition(function_definition::FunctionDefinition),
     PreprocInclude(preproc_include::PreprocInclude),
-    Comment(comment::Comment), // TODO: This doesn't belong here. To avoid putting comments in every enum, I woud try to match them with their corresponding node
+    StructDefinition(struct_definition::StructDefinition),
+    UnionDefinition(union_definition::UnionDefinition),
+    EnumDefinition(enum_definition::EnumDefinition),
+    TypeDefinition(type_definition::TypeDefinition),
+    PreprocDefine(preproc_define::PreprocDefine),
+    PreprocCall(preproc_call::PreprocCall),
+    PreprocIf(preproc_if::PreprocIf),
+    /// A comment that documents no object, like one separated from the next by a blank line
+    Comment(comment::Comment),
     Unknown(Unknown),
 }
 
@@ -33,9 +50,17 @@ impl DeclarationObject {
     ) -> Result<(), crate::language::c::writers::writer_error::WriterError> {
         match self {
             DeclarationObject::Declaration(decl) => decl.write(w),
+            DeclarationObject::DeclarationList(decl) => decl.write(w),
             DeclarationObject::FunctionDeclaration(func_decl) => func_decl.write(w),
             DeclarationObject::FunctionDefinition(func_def) => func_def.write(w),
             DeclarationObject::PreprocInclude(preproc) => preproc.write(w),
+            DeclarationObject::StructDefinition(struct_def) => struct_def.write(w),
+            DeclarationObject::UnionDefinition(union_def) => union_def.write(w),
+            DeclarationObject::EnumDefinition(enum_def) => enum_def.write(w),
+            DeclarationObject::TypeDefinition(type_def) => type_def.write(w),
+            DeclarationObject::PreprocDefine(define) => define.write(w),
+            DeclarationObject::PreprocCall(call) => call.write(w),
+            DeclarationObject::PreprocIf(preproc_if) => preproc_if.write(w),
             DeclarationObject::Unknown(unknown) => unknown.write(w),
             DeclarationObject::Comment(comment) => comment.write(w),
         }
@@ -44,9 +69,17 @@ impl DeclarationObject {
     pub fn id(&self) -> Uuid {
         match self {
             DeclarationObject::Declaration(decl) => decl.id,
+            DeclarationObject::DeclarationList(decl) => decl.id,
             DeclarationObject::FunctionDeclaration(func_decl) => func_decl.id,
             DeclarationObject::FunctionDefinition(func_def) => func_def.id,
             DeclarationObject::PreprocInclude(preproc) => preproc.id,
+            DeclarationObject::StructDefinition(struct_def) => struct_def.id,
+            DeclarationObject::UnionDefinition(union_def) => union_def.id,
+            DeclarationObject::EnumDefinition(enum_def) => enum_def.id,
+            DeclarationObject::TypeDefinition(type_def) => type_def.id,
+            DeclarationObject::PreprocDefine(define) => define.id,
+            DeclarationObject::PreprocCall(call) => call.id,
+            DeclarationObject::PreprocIf(preproc_if) => preproc_if.id,
             DeclarationObject::Unknown(unknown) => unknown.id,
             DeclarationObject::Comment(comment) => comment.id,
         }
@@ -63,6 +96,9 @@ impl From<DeclarationObject> for LanguageObject {
     fn from(value: DeclarationObject) -> Self {
         match value {
             DeclarationObject::Declaration(decl) => LanguageObject::Declaration(decl.clone()),
+            DeclarationObject::DeclarationList(decl) => {
+                LanguageObject::DeclarationList(decl.clone())
+            }
             DeclarationObject::FunctionDeclaration(func_decl) => {
                 LanguageObject::FunctionDeclaration(func_decl.clone())
             }
@@ -72,6 +108,25 @@ impl From<DeclarationObject> for LanguageObject {
             DeclarationObject::PreprocInclude(preproc) => {
                 LanguageObject::PreprocInclude(preproc.clone())
             }
+            DeclarationObject::StructDefinition(struct_def) => {
+                LanguageObject::StructDefinition(struct_def.clone())
+            }
+            DeclarationObject::UnionDefinition(union_def) => {
+                LanguageObject::UnionDefinition(union_def.clone())
+            }
+            DeclarationObject::EnumDefinition(enum_def) => {
+                LanguageObject::EnumDefinition(enum_def.clone())
+            }
+            DeclarationObject::TypeDefinition(type_def) => {
+                LanguageObject::TypeDefinition(type_def.clone())
+            }
+            DeclarationObject::PreprocDefine(define) => {
+                LanguageObject::PreprocDefine(define.clone())
+            }
+            DeclarationObject::PreprocCall(call) => LanguageObject::PreprocCall(call.clone()),
+            DeclarationObject::PreprocIf(preproc_if) => {
+                LanguageObject::PreprocIf(preproc_if.clone())
+            }
             DeclarationObject::Unknown(unknown) => LanguageObject::Unknown(unknown.clone()),
             DeclarationObject::Comment(comment) => LanguageObject::Comment(comment.clone()),
         }
@@ -84,6 +139,7 @@ impl TryFrom<LanguageObject> for DeclarationObject {
     fn try_from(value: LanguageObject) -> Result<Self, Self::Error> {
         match value {
             LanguageObject::Declaration(decl) => Ok(DeclarationObject::Declaration(decl)),
+            LanguageObject::DeclarationList(decl) => Ok(DeclarationObject::DeclarationList(decl)),
             LanguageObject::FunctionDeclaration(func_decl) => {
                 Ok(DeclarationObject::FunctionDeclaration(func_decl))
             }
@@ -93,6 +149,21 @@ impl TryFrom<LanguageObject> for DeclarationObject {
             LanguageObject::PreprocInclude(preproc) => {
                 Ok(DeclarationObject::PreprocInclude(preproc))
             }
+            LanguageObject::StructDefinition(struct_def) => {
+                Ok(DeclarationObject::StructDefinition(struct_def))
+            }
+            LanguageObject::UnionDefinition(union_def) => {
+                Ok(DeclarationObject::UnionDefinition(union_def))
+            }
+            LanguageObject::EnumDefinition(enum_def) => {
+                Ok(DeclarationObject::EnumDefinition(enum_def))
+            }
+            LanguageObject::TypeDefinition(type_def) => {
+                Ok(DeclarationObject::TypeDefinition(type_def))
+            }
+            LanguageObject::PreprocDefine(define) => Ok(DeclarationObject::PreprocDefine(define)),
+            LanguageObject::PreprocCall(call) => Ok(DeclarationObject::PreprocCall(call)),
+            LanguageObject::PreprocIf(preproc_if) => Ok(DeclarationObject::PreprocIf(preproc_if)),
             LanguageObject::Unknown(unknown) => Ok(DeclarationObject::Unknown(unknown)),
             LanguageObject::Comment(comment) => Ok(DeclarationObject::Comment(comment)),
             _ => Err(crate::language::c::language_object::ConversionError(
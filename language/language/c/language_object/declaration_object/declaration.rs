@@ -1,26 +1,46 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     c_type::CType,
     language_object::expression_object::ExpressionObject,
+    specifiers::Specifiers,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
-#[derive(Debug, Clone, PartialEq, lenga_field_inspect_derive::FieldInspect)]
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 /// ```c
 /// int a; // value == None
 /// int a = 5; value == Some(_)
 /// ```
 pub struct Declaration {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub specifiers: Specifiers,
     pub primitive_type: CType,
     pub identifier: String,
     pub value: Option<Box<ExpressionObject>>,
 }
 
+// Where a declaration was parsed from does not make it a different declaration
+impl PartialEq for Declaration {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.specifiers == other.specifiers
+            && self.primitive_type == other.primitive_type
+            && self.identifier == other.identifier
+            && self.value == other.value
+            && self.comments == other.comments
+    }
+}
+
 impl Declaration {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_declaration(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_declaration(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -28,6 +48,9 @@ impl Default for Declaration {
     fn default() -> Self {
         Declaration {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: CType::default(),
             identifier: String::new(),
             value: None,
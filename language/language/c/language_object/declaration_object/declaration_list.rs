@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::declaration_object::declaration::Declaration,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Several declarators sharing one type specifier, each a `Declaration` with its own id and
+/// optional initializer:
+/// ```c
+/// int a = 1, *b, c[3] = {1, 2, 3};
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct DeclarationList {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub declarations: Vec<Declaration>,
+}
+
+impl DeclarationList {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_declaration_list(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl PartialEq for DeclarationList {
+    fn eq(&self, other: &Self) -> bool {
+        self.declarations == other.declarations && self.comments == other.comments
+    }
+}
+
+impl Default for DeclarationList {
+    fn default() -> Self {
+        DeclarationList {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            declarations: vec![],
+        }
+    }
+}
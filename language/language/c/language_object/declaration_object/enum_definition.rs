@@ -0,0 +1,57 @@
+pub mod enumerator;
+
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::declaration_object::enum_definition::enumerator::Enumerator,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+/// ```c
+/// enum Color {
+///     RED,
+///     GREEN = 2,
+/// };
+/// ```
+pub struct EnumDefinition {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub enumerators: Vec<Enumerator>,
+    /// Declared without a body, as in `enum s;`, so its enumerators are defined elsewhere
+    pub is_forward: bool,
+}
+
+impl PartialEq for EnumDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.enumerators == other.enumerators
+            && self.is_forward == other.is_forward
+            && self.comments == other.comments
+    }
+}
+
+impl EnumDefinition {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_enum_definition(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for EnumDefinition {
+    fn default() -> Self {
+        EnumDefinition {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            enumerators: Vec::new(),
+            is_forward: false,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct Enumerator {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub value: Option<Box<ExpressionObject>>,
+}
+
+impl PartialEq for Enumerator {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
+    }
+}
+
+impl Enumerator {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_enumerator(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for Enumerator {
+    fn default() -> Self {
+        Enumerator {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            value: None,
+        }
+    }
+}
@@ -1,20 +1,26 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     c_type::CType,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct FunctionParameter {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub identifier: String,
     pub param_type: CType,
 }
 
 impl PartialEq for FunctionParameter {
     fn eq(&self, other: &Self) -> bool {
-        self.identifier == other.identifier && self.param_type == other.param_type
+        self.identifier == other.identifier
+            && self.param_type == other.param_type
+            && self.comments == other.comments
     }
 }
 
@@ -28,6 +34,8 @@ impl Default for FunctionParameter {
     fn default() -> Self {
         FunctionParameter {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             identifier: String::new(),
             param_type: CType::Void,
         }
@@ -1,13 +1,16 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     c_type::CType,
     language_object::{
         declaration_object::function_declaration::function_parameter::FunctionParameter,
         statement_object::compound_statement::CompoundStatement,
     },
+    specifiers::Specifiers,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 /// ```c
@@ -16,24 +19,37 @@ use crate::language::c::{
 /// ```
 pub struct FunctionDefinition {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub specifiers: Specifiers,
     pub return_type: CType,
     pub identifier: String,
     pub parameter_list: Vec<FunctionParameter>,
+    /// Takes more arguments after the parameters, as in `int printf(const char *fmt, ...)`
+    pub is_variadic: bool,
+    /// Declared as `f(void)`, taking no arguments, rather than `f()`, which leaves them unspecified
+    pub explicit_void: bool,
     pub compound_statement: CompoundStatement,
 }
 
 impl PartialEq for FunctionDefinition {
     fn eq(&self, other: &Self) -> bool {
-        self.return_type == other.return_type
+        self.specifiers == other.specifiers
+            && self.return_type == other.return_type
             && self.identifier == other.identifier
             && self.parameter_list == other.parameter_list
+            && self.is_variadic == other.is_variadic
+            && self.explicit_void == other.explicit_void
             && self.compound_statement == other.compound_statement
+            && self.comments == other.comments
     }
 }
 
 impl FunctionDefinition {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_function_definition(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_function_definition(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -41,9 +57,14 @@ impl Default for FunctionDefinition {
     fn default() -> Self {
         FunctionDefinition {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: CType::default(),
             identifier: String::new(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: CompoundStatement::default(),
         }
     }
@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
+
+/// Any other directive, kept as written:
+/// ```c
+/// #pragma once
+/// #undef MAX
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct PreprocCall {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub directive: String,
+    pub argument: String,
+}
+
+impl PreprocCall {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_preproc_call(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl PartialEq for PreprocCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.directive == other.directive
+            && self.argument == other.argument
+            && self.comments == other.comments
+    }
+}
+
+impl Default for PreprocCall {
+    fn default() -> Self {
+        PreprocCall {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            directive: "#pragma".to_string(),
+            argument: String::new(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// #define MAX 10
+/// #define SQUARE(x) ((x) * (x))
+/// ```
+///
+/// Function-like macros have a parameter list, even an empty one. The replacement list is
+/// kept verbatim.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct PreprocDefine {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub parameters: Option<Vec<String>>,
+    pub value: String,
+}
+
+impl PreprocDefine {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_preproc_define(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl PartialEq for PreprocDefine {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.parameters == other.parameters
+            && self.value == other.value
+            && self.comments == other.comments
+    }
+}
+
+impl Default for PreprocDefine {
+    fn default() -> Self {
+        PreprocDefine {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            parameters: None,
+            value: String::new(),
+        }
+    }
+}
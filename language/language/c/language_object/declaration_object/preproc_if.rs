@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::declaration_object::DeclarationObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Conditional compilation at file scope:
+/// ```c
+/// #ifdef DEBUG
+/// int level = 2;
+/// #elif defined(TRACE)
+/// int level = 1;
+/// #else
+/// int level = 0;
+/// #endif
+/// ```
+///
+/// `directive` is one of `#if`, `#ifdef`, `#ifndef`, `#elif`, `#elifdef`, `#elifndef` or
+/// `#else`, the latter without a condition. Each `#elif`/`#else` branch is the
+/// `alternative` of the previous one, and the last branch closes the block with `#endif`.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct PreprocIf {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub directive: String,
+    pub condition: String,
+    pub body: Vec<DeclarationObject>,
+    pub alternative: Option<Box<PreprocIf>>,
+}
+
+impl PreprocIf {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_preproc_if(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl PartialEq for PreprocIf {
+    fn eq(&self, other: &Self) -> bool {
+        self.directive == other.directive
+            && self.condition == other.condition
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.alternative == other.alternative
+            && self.comments == other.comments
+    }
+}
+
+impl Default for PreprocIf {
+    fn default() -> Self {
+        PreprocIf {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            directive: "#if".to_string(),
+            condition: String::new(),
+            body: vec![],
+            alternative: None,
+        }
+    }
+}
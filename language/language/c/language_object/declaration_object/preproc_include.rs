@@ -1,22 +1,28 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct PreprocInclude {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub content: String,
 }
 
 impl PreprocInclude {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_preproc_include(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_preproc_include(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
 impl PartialEq for PreprocInclude {
     fn eq(&self, other: &Self) -> bool {
-        self.content == other.content
+        self.content == other.content && self.comments == other.comments
     }
 }
 
@@ -24,6 +30,8 @@ impl Default for PreprocInclude {
     fn default() -> Self {
         PreprocInclude {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             content: String::new(),
         }
     }
@@ -0,0 +1,49 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    c_type::CType,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct FieldDeclaration {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub field_type: CType,
+    /// Declared by the same declaration as the field before it, like `y` in `int x, y;`
+    pub shares_declaration: bool,
+}
+
+impl PartialEq for FieldDeclaration {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.field_type == other.field_type
+            && self.shares_declaration == other.shares_declaration
+            && self.comments == other.comments
+    }
+}
+
+impl FieldDeclaration {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_field_declaration(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for FieldDeclaration {
+    fn default() -> Self {
+        FieldDeclaration {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            field_type: CType::default(),
+            shares_declaration: false,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    c_type::CType,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+/// ```c
+/// typedef unsigned long size_type;        // aliased_type == Sized(unsigned long)
+/// typedef int (*callback_t)(int, char);  // aliased_type == Pointer(Fn(..))
+/// ```
+/// Uses of the name are `CType::Named` types holding this definition's id.
+pub struct TypeDefinition {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub aliased_type: CType,
+}
+
+impl PartialEq for TypeDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.aliased_type == other.aliased_type
+            && self.comments == other.comments
+    }
+}
+
+impl TypeDefinition {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_type_definition(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for TypeDefinition {
+    fn default() -> Self {
+        TypeDefinition {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            aliased_type: CType::default(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+/// ```c
+/// union Value {
+///     int i;
+///     float f;
+/// };
+/// ```
+pub struct UnionDefinition {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub identifier: String,
+    pub fields: Vec<FieldDeclaration>,
+    /// Declared without a body, as in `union s;`, so its fields are defined elsewhere
+    pub is_forward: bool,
+}
+
+impl PartialEq for UnionDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.fields == other.fields
+            && self.is_forward == other.is_forward
+            && self.comments == other.comments
+    }
+}
+
+impl UnionDefinition {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_union_definition(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for UnionDefinition {
+    fn default() -> Self {
+        UnionDefinition {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            identifier: String::new(),
+            fields: Vec::new(),
+            is_forward: false,
+        }
+    }
+}
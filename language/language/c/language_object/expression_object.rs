@@ -5,9 +5,20 @@ use crate::language::c::language_object::{
 pub mod assignment_expression;
 pub mod binary_expression;
 pub mod call_expression;
+pub mod cast_expression;
+pub mod char_literal;
+pub mod conditional_expression;
+pub mod field_expression;
+pub mod initializer_list;
+pub mod initializer_pair;
 pub mod number_literal;
+pub mod parenthesized_expression;
 pub mod reference;
+pub mod sizeof_expression;
 pub mod string_literal;
+pub mod subscript_expression;
+pub mod unary_expression;
+pub mod update_expression;
 
 #[derive(
     Debug,
@@ -19,10 +30,21 @@ pub mod string_literal;
 pub enum ExpressionObject {
     AssignmentExpression(assignment_expression::AssignmentExpression),
     BinaryExpression(binary_expression::BinaryExpression),
+    UnaryExpression(unary_expression::UnaryExpression),
+    UpdateExpression(update_expression::UpdateExpression),
+    CastExpression(cast_expression::CastExpression),
+    SizeofExpression(sizeof_expression::SizeofExpression),
+    ConditionalExpression(conditional_expression::ConditionalExpression),
+    FieldExpression(field_expression::FieldExpression),
+    SubscriptExpression(subscript_expression::SubscriptExpression),
+    ParenthesizedExpression(parenthesized_expression::ParenthesizedExpression),
+    InitializerList(initializer_list::InitializerList),
+    InitializerPair(initializer_pair::InitializerPair),
     CallExpression(call_expression::CallExpression),
     NumberLiteral(number_literal::NumberLiteral),
     Reference(reference::Reference),
     StringLiteral(string_literal::StringLiteral),
+    CharLiteral(char_literal::CharLiteral),
     Unknown(Unknown),
 }
 
@@ -34,10 +56,21 @@ impl ExpressionObject {
         match self {
             ExpressionObject::AssignmentExpression(expr) => expr.write(w),
             ExpressionObject::BinaryExpression(expr) => expr.write(w),
+            ExpressionObject::UnaryExpression(expr) => expr.write(w),
+            ExpressionObject::UpdateExpression(expr) => expr.write(w),
+            ExpressionObject::CastExpression(expr) => expr.write(w),
+            ExpressionObject::SizeofExpression(expr) => expr.write(w),
+            ExpressionObject::ConditionalExpression(expr) => expr.write(w),
+            ExpressionObject::FieldExpression(expr) => expr.write(w),
+            ExpressionObject::SubscriptExpression(expr) => expr.write(w),
+            ExpressionObject::ParenthesizedExpression(expr) => expr.write(w),
+            ExpressionObject::InitializerList(expr) => expr.write(w),
+            ExpressionObject::InitializerPair(expr) => expr.write(w),
             ExpressionObject::CallExpression(expr) => expr.write(w),
             ExpressionObject::NumberLiteral(expr) => expr.write(w),
             ExpressionObject::Reference(expr) => expr.write(w),
             ExpressionObject::StringLiteral(expr) => expr.write(w),
+            ExpressionObject::CharLiteral(expr) => expr.write(w),
             ExpressionObject::Unknown(expr) => expr.write(w),
         }
     }
@@ -50,10 +83,39 @@ impl ExpressionObject {
             ExpressionObject::BinaryExpression(expr) => {
                 LanguageObject::BinaryExpression(expr.clone())
             }
+            ExpressionObject::UnaryExpression(expr) => {
+                LanguageObject::UnaryExpression(expr.clone())
+            }
+            ExpressionObject::UpdateExpression(expr) => {
+                LanguageObject::UpdateExpression(expr.clone())
+            }
+            ExpressionObject::CastExpression(expr) => LanguageObject::CastExpression(expr.clone()),
+            ExpressionObject::SizeofExpression(expr) => {
+                LanguageObject::SizeofExpression(expr.clone())
+            }
+            ExpressionObject::ConditionalExpression(expr) => {
+                LanguageObject::ConditionalExpression(expr.clone())
+            }
+            ExpressionObject::FieldExpression(expr) => {
+                LanguageObject::FieldExpression(expr.clone())
+            }
+            ExpressionObject::SubscriptExpression(expr) => {
+                LanguageObject::SubscriptExpression(expr.clone())
+            }
+            ExpressionObject::ParenthesizedExpression(expr) => {
+                LanguageObject::ParenthesizedExpression(expr.clone())
+            }
+            ExpressionObject::InitializerList(expr) => {
+                LanguageObject::InitializerList(expr.clone())
+            }
+            ExpressionObject::InitializerPair(expr) => {
+                LanguageObject::InitializerPair(expr.clone())
+            }
             ExpressionObject::CallExpression(expr) => LanguageObject::CallExpression(expr.clone()),
             ExpressionObject::NumberLiteral(expr) => LanguageObject::NumberLiteral(expr.clone()),
             ExpressionObject::Reference(expr) => LanguageObject::Reference(expr.clone()),
             ExpressionObject::StringLiteral(expr) => LanguageObject::StringLiteral(expr.clone()),
+            ExpressionObject::CharLiteral(expr) => LanguageObject::CharLiteral(expr.clone()),
             ExpressionObject::Unknown(expr) => LanguageObject::Unknown(expr.clone()),
         }
     }
@@ -76,10 +138,27 @@ impl From<ExpressionObject> for LanguageObject {
                 LanguageObject::AssignmentExpression(expr)
             }
             ExpressionObject::BinaryExpression(expr) => LanguageObject::BinaryExpression(expr),
+            ExpressionObject::UnaryExpression(expr) => LanguageObject::UnaryExpression(expr),
+            ExpressionObject::UpdateExpression(expr) => LanguageObject::UpdateExpression(expr),
+            ExpressionObject::CastExpression(expr) => LanguageObject::CastExpression(expr),
+            ExpressionObject::SizeofExpression(expr) => LanguageObject::SizeofExpression(expr),
+            ExpressionObject::ConditionalExpression(expr) => {
+                LanguageObject::ConditionalExpression(expr)
+            }
+            ExpressionObject::FieldExpression(expr) => LanguageObject::FieldExpression(expr),
+            ExpressionObject::SubscriptExpression(expr) => {
+                LanguageObject::SubscriptExpression(expr)
+            }
+            ExpressionObject::ParenthesizedExpression(expr) => {
+                LanguageObject::ParenthesizedExpression(expr)
+            }
+            ExpressionObject::InitializerList(expr) => LanguageObject::InitializerList(expr),
+            ExpressionObject::InitializerPair(expr) => LanguageObject::InitializerPair(expr),
             ExpressionObject::CallExpression(expr) => LanguageObject::CallExpression(expr),
             ExpressionObject::NumberLiteral(expr) => LanguageObject::NumberLiteral(expr),
             ExpressionObject::Reference(expr) => LanguageObject::Reference(expr),
             ExpressionObject::StringLiteral(expr) => LanguageObject::StringLiteral(expr),
+            ExpressionObject::CharLiteral(expr) => LanguageObject::CharLiteral(expr),
             ExpressionObject::Unknown(expr) => LanguageObject::Unknown(expr),
         }
     }
@@ -94,12 +173,43 @@ impl From<Box<ExpressionObject>> for Box<LanguageObject> {
             ExpressionObject::BinaryExpression(expr) => {
                 Box::new(LanguageObject::BinaryExpression(expr))
             }
+            ExpressionObject::UnaryExpression(expr) => {
+                Box::new(LanguageObject::UnaryExpression(expr))
+            }
+            ExpressionObject::UpdateExpression(expr) => {
+                Box::new(LanguageObject::UpdateExpression(expr))
+            }
+            ExpressionObject::CastExpression(expr) => {
+                Box::new(LanguageObject::CastExpression(expr))
+            }
+            ExpressionObject::SizeofExpression(expr) => {
+                Box::new(LanguageObject::SizeofExpression(expr))
+            }
+            ExpressionObject::ConditionalExpression(expr) => {
+                Box::new(LanguageObject::ConditionalExpression(expr))
+            }
+            ExpressionObject::FieldExpression(expr) => {
+                Box::new(LanguageObject::FieldExpression(expr))
+            }
+            ExpressionObject::SubscriptExpression(expr) => {
+                Box::new(LanguageObject::SubscriptExpression(expr))
+            }
+            ExpressionObject::ParenthesizedExpression(expr) => {
+                Box::new(LanguageObject::ParenthesizedExpression(expr))
+            }
+            ExpressionObject::InitializerList(expr) => {
+                Box::new(LanguageObject::InitializerList(expr))
+            }
+            ExpressionObject::InitializerPair(expr) => {
+                Box::new(LanguageObject::InitializerPair(expr))
+            }
             ExpressionObject::CallExpression(expr) => {
                 Box::new(LanguageObject::CallExpression(expr))
             }
             ExpressionObject::NumberLiteral(expr) => Box::new(LanguageObject::NumberLiteral(expr)),
             ExpressionObject::Reference(expr) => Box::new(LanguageObject::Reference(expr)),
             ExpressionObject::StringLiteral(expr) => Box::new(LanguageObject::StringLiteral(expr)),
+            ExpressionObject::CharLiteral(expr) => Box::new(LanguageObject::CharLiteral(expr)),
             ExpressionObject::Unknown(expr) => Box::new(LanguageObject::Unknown(expr)),
         }
     }
@@ -113,10 +223,27 @@ impl TryFrom<LanguageObject> for ExpressionObject {
                 Ok(ExpressionObject::AssignmentExpression(expr))
             }
             LanguageObject::BinaryExpression(expr) => Ok(ExpressionObject::BinaryExpression(expr)),
+            LanguageObject::UnaryExpression(expr) => Ok(ExpressionObject::UnaryExpression(expr)),
+            LanguageObject::UpdateExpression(expr) => Ok(ExpressionObject::UpdateExpression(expr)),
+            LanguageObject::CastExpression(expr) => Ok(ExpressionObject::CastExpression(expr)),
+            LanguageObject::SizeofExpression(expr) => Ok(ExpressionObject::SizeofExpression(expr)),
+            LanguageObject::ConditionalExpression(expr) => {
+                Ok(ExpressionObject::ConditionalExpression(expr))
+            }
+            LanguageObject::FieldExpression(expr) => Ok(ExpressionObject::FieldExpression(expr)),
+            LanguageObject::SubscriptExpression(expr) => {
+                Ok(ExpressionObject::SubscriptExpression(expr))
+            }
+            LanguageObject::ParenthesizedExpression(expr) => {
+                Ok(ExpressionObject::ParenthesizedExpression(expr))
+            }
+            LanguageObject::InitializerList(expr) => Ok(ExpressionObject::InitializerList(expr)),
+            LanguageObject::InitializerPair(expr) => Ok(ExpressionObject::InitializerPair(expr)),
             LanguageObject::CallExpression(expr) => Ok(ExpressionObject::CallExpression(expr)),
             LanguageObject::NumberLiteral(expr) => Ok(ExpressionObject::NumberLiteral(expr)),
             LanguageObject::Reference(expr) => Ok(ExpressionObject::Reference(expr)),
             LanguageObject::StringLiteral(expr) => Ok(ExpressionObject::StringLiteral(expr)),
+            LanguageObject::CharLiteral(expr) => Ok(ExpressionObject::CharLiteral(expr)),
             LanguageObject::Unknown(expr) => Ok(ExpressionObject::Unknown(expr)),
             _ => Err(ConversionError(
                 "Cannot convert LanguageObject to ExpressionObject".into(),
@@ -135,12 +262,43 @@ impl TryFrom<Box<LanguageObject>> for Box<ExpressionObject> {
             LanguageObject::BinaryExpression(expr) => {
                 Ok(ExpressionObject::BinaryExpression(expr).into())
             }
+            LanguageObject::UnaryExpression(expr) => {
+                Ok(ExpressionObject::UnaryExpression(expr).into())
+            }
+            LanguageObject::UpdateExpression(expr) => {
+                Ok(ExpressionObject::UpdateExpression(expr).into())
+            }
+            LanguageObject::CastExpression(expr) => {
+                Ok(ExpressionObject::CastExpression(expr).into())
+            }
+            LanguageObject::SizeofExpression(expr) => {
+                Ok(ExpressionObject::SizeofExpression(expr).into())
+            }
+            LanguageObject::ConditionalExpression(expr) => {
+                Ok(ExpressionObject::ConditionalExpression(expr).into())
+            }
+            LanguageObject::FieldExpression(expr) => {
+                Ok(ExpressionObject::FieldExpression(expr).into())
+            }
+            LanguageObject::SubscriptExpression(expr) => {
+                Ok(ExpressionObject::SubscriptExpression(expr).into())
+            }
+            LanguageObject::ParenthesizedExpression(expr) => {
+                Ok(ExpressionObject::ParenthesizedExpression(expr).into())
+            }
+            LanguageObject::InitializerList(expr) => {
+                Ok(ExpressionObject::InitializerList(expr).into())
+            }
+            LanguageObject::InitializerPair(expr) => {
+                Ok(ExpressionObject::InitializerPair(expr).into())
+            }
             LanguageObject::CallExpression(expr) => {
                 Ok(ExpressionObject::CallExpression(expr).into())
             }
             LanguageObject::NumberLiteral(expr) => Ok(ExpressionObject::NumberLiteral(expr).into()),
             LanguageObject::Reference(expr) => Ok(ExpressionObject::Reference(expr).into()),
             LanguageObject::StringLiteral(expr) => Ok(ExpressionObject::StringLiteral(expr).into()),
+            LanguageObject::CharLiteral(expr) => Ok(ExpressionObject::CharLiteral(expr).into()),
             LanguageObject::Unknown(expr) => Ok(ExpressionObject::Unknown(expr).into()),
             _ => Err(ConversionError(
                 "Cannot convert LanguageObject to ExpressionObject".into(),
@@ -1,24 +1,31 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::expression_object::ExpressionObject,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 /// ```c
 /// a = 5;
+/// p->next = NULL;
 /// ```
 pub struct AssignmentExpression {
     pub id: Uuid,
-    pub id_declaration: Uuid,
-    pub identifier: String,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    /// What is assigned to: a `Reference`, or a field, subscript or dereference expression
+    pub left: Box<ExpressionObject>,
     pub value: Box<ExpressionObject>,
 }
 
 impl AssignmentExpression {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_assignment_expression(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_assignment_expression(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -26,8 +33,9 @@ impl Default for AssignmentExpression {
     fn default() -> Self {
         AssignmentExpression {
             id: Uuid::new_v4(),
-            id_declaration: Uuid::new_v4(),
-            identifier: String::new(),
+            span: None,
+            comments: Comments::default(),
+            left: Box::new(ExpressionObject::default()),
             value: Box::new(ExpressionObject::default()),
         }
     }
@@ -35,7 +43,8 @@ impl Default for AssignmentExpression {
 
 impl PartialEq for AssignmentExpression {
     fn eq(&self, other: &Self) -> bool {
-        self.identifier == other.identifier
+        crate::language::PartialEqAny::eq_dyn(&self.left, &other.left)
             && crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
     }
 }
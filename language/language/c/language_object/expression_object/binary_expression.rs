@@ -1,13 +1,17 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::expression_object::ExpressionObject,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct BinaryExpression {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub left: Box<ExpressionObject>,
     pub operator: String,
     pub right: Box<ExpressionObject>,
@@ -15,7 +19,9 @@ pub struct BinaryExpression {
 
 impl BinaryExpression {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_binary_expression(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_binary_expression(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -23,6 +29,8 @@ impl Default for BinaryExpression {
     fn default() -> Self {
         BinaryExpression {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             left: Box::new(ExpressionObject::default()),
             operator: String::new(),
             right: Box::new(ExpressionObject::default()),
@@ -35,5 +43,6 @@ impl PartialEq for BinaryExpression {
         self.operator == other.operator
             && crate::language::PartialEqAny::eq_dyn(&self.left, &other.left)
             && crate::language::PartialEqAny::eq_dyn(&self.right, &other.right)
+            && self.comments == other.comments
     }
 }
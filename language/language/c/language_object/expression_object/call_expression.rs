@@ -1,9 +1,11 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::expression_object::ExpressionObject,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 /// ```c
@@ -12,6 +14,8 @@ use crate::language::c::{
 /// ```
 pub struct CallExpression {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub id_declaration: Uuid,
     pub identifier: String,
     pub argument_list: Vec<ExpressionObject>,
@@ -21,6 +25,8 @@ impl Default for CallExpression {
     fn default() -> Self {
         CallExpression {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             id_declaration: Uuid::new_v4(),
             identifier: String::new(),
             argument_list: vec![],
@@ -33,11 +39,14 @@ impl PartialEq for CallExpression {
         self.id_declaration == other.id_declaration
             && self.identifier == other.identifier
             && self.argument_list == other.argument_list
+            && self.comments == other.comments
     }
 }
 
 impl CallExpression {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_call_expression(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_call_expression(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
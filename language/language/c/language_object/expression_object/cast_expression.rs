@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    c_type::CType,
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct CastExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub cast_type: CType,
+    pub value: Box<ExpressionObject>,
+}
+
+impl CastExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_cast_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for CastExpression {
+    fn default() -> Self {
+        CastExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            cast_type: CType::default(),
+            value: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for CastExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.cast_type == other.cast_type
+            && crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,69 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    literal::{EncodingPrefix, unescape},
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// 'a'     // value == "a"
+/// L'\n'   // prefix == Wide, value holds a real newline
+/// ```
+/// `value` is the decoded character (or characters, for multi-character constants) and
+/// `spelling` the literal as written, kept to restore its original escapes.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct CharLiteral {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub prefix: EncodingPrefix,
+    pub value: String,
+    pub spelling: String,
+}
+
+impl CharLiteral {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_char_literal(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+
+    /// Decodes a spelling like `L'\n'`, or None if it is not a character literal
+    pub fn from_spelling(spelling: String) -> Option<CharLiteral> {
+        let (prefix, text) = EncodingPrefix::split_spelling(&spelling, '\'')?;
+        Some(CharLiteral {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            prefix,
+            value: unescape(text),
+            spelling,
+        })
+    }
+
+    /// Whether `spelling` still spells `prefix` and `value`, so it can be written as it is
+    pub fn spelling_matches(&self) -> bool {
+        CharLiteral::from_spelling(self.spelling.clone()).is_some_and(|decoded| decoded == *self)
+    }
+}
+
+impl Default for CharLiteral {
+    fn default() -> Self {
+        CharLiteral {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            prefix: EncodingPrefix::default(),
+            value: String::new(),
+            spelling: String::new(),
+        }
+    }
+}
+
+impl PartialEq for CharLiteral {
+    fn eq(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.value == other.value && self.comments == other.comments
+    }
+}
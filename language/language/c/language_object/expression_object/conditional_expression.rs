@@ -0,0 +1,49 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// `condition ? consequence : alternative`
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct ConditionalExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub condition: Box<ExpressionObject>,
+    pub consequence: Box<ExpressionObject>,
+    pub alternative: Box<ExpressionObject>,
+}
+
+impl ConditionalExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_conditional_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for ConditionalExpression {
+    fn default() -> Self {
+        ConditionalExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(ExpressionObject::default()),
+            consequence: Box::new(ExpressionObject::default()),
+            alternative: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for ConditionalExpression {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
+            && crate::language::PartialEqAny::eq_dyn(&self.consequence, &other.consequence)
+            && crate::language::PartialEqAny::eq_dyn(&self.alternative, &other.alternative)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,54 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Member access through a struct or union value (`s.field`) or a pointer to one
+/// (`p->next`). `declaration_id` is the id of the accessed member, or nil when the type of
+/// the argument is not known.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct FieldExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub argument: Box<ExpressionObject>,
+    pub operator: String,
+    pub declaration_id: Uuid,
+    pub identifier: String,
+}
+
+impl FieldExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_field_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for FieldExpression {
+    fn default() -> Self {
+        FieldExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(ExpressionObject::default()),
+            operator: ".".to_string(),
+            declaration_id: Uuid::nil(),
+            identifier: String::new(),
+        }
+    }
+}
+
+impl PartialEq for FieldExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.operator == other.operator
+            && self.declaration_id == other.declaration_id
+            && self.identifier == other.identifier
+            && crate::language::PartialEqAny::eq_dyn(&self.argument, &other.argument)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Brace-enclosed initializer of an array, struct or union: `{1, 2, .z = 3}`.
+/// Designated elements are `InitializerPair`s.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct InitializerList {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub elements: Vec<ExpressionObject>,
+}
+
+impl InitializerList {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_initializer_list(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for InitializerList {
+    fn default() -> Self {
+        InitializerList {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            elements: vec![],
+        }
+    }
+}
+
+impl PartialEq for InitializerList {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.elements, &other.elements)
+            && self.comments == other.comments
+    }
+}
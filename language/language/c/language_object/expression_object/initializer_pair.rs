@@ -0,0 +1,58 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Designated element of an `InitializerList`, naming the member or index it initializes:
+/// ```c
+/// struct Point p = {.x = 1, .pos[2] = 5};
+/// int squares[4] = {[3] = 9};
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct InitializerPair {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub designators: Vec<Designator>,
+    pub value: Box<ExpressionObject>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Designator {
+    /// `.identifier`
+    Field(String),
+    /// `[index]`
+    Subscript(ExpressionObject),
+}
+
+impl InitializerPair {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_initializer_pair(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for InitializerPair {
+    fn default() -> Self {
+        InitializerPair {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            designators: vec![],
+            value: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for InitializerPair {
+    fn eq(&self, other: &Self) -> bool {
+        self.designators == other.designators
+            && crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
+    }
+}
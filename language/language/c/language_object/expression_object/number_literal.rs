@@ -1,16 +1,205 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
 
+/// ```c
+/// 0x1F     // value == Integer(31), radix == Hexadecimal
+/// 31u      // value == Integer(31), suffix == "u"
+/// 1.0e-3f  // value == Float(0.001), suffix == "f"
+/// ```
+/// `spelling` keeps the literal as written, so unchanged numbers are written back byte-exact.
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct NumberLiteral {
     pub id: Uuid,
-    pub value: String,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub value: NumberValue,
+    pub radix: Radix,
+    /// Lowercase suffix as written, like `u`, `ul`, `ll` or `f`
+    pub suffix: String,
+    pub spelling: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberValue {
+    Integer(u128),
+    Float(f64),
+}
+
+impl Default for NumberValue {
+    fn default() -> Self {
+        NumberValue::Integer(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Radix {
+    #[default]
+    Decimal,
+    Hexadecimal,
+    Octal,
+    Binary,
+}
+
+impl Radix {
+    pub fn base(&self) -> u32 {
+        match self {
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+            Radix::Octal => 8,
+            Radix::Binary => 2,
+        }
+    }
+
+    pub fn from_base(base: u32) -> Option<Self> {
+        match base {
+            10 => Some(Radix::Decimal),
+            16 => Some(Radix::Hexadecimal),
+            8 => Some(Radix::Octal),
+            2 => Some(Radix::Binary),
+            _ => None,
+        }
+    }
 }
 
 impl NumberLiteral {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_number_literal(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_number_literal(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+
+    /// Parses a literal like `0x1Fu` or `1.5e3f`, or None if it is not a valid C number.
+    /// Digit separators (`1'000`) are accepted.
+    pub fn from_spelling(spelling: impl Into<String>) -> Option<NumberLiteral> {
+        let spelling = spelling.into();
+        let text = spelling.replace('\'', "").to_ascii_lowercase();
+        let (radix, body) = if let Some(body) = text.strip_prefix("0x") {
+            (Radix::Hexadecimal, body)
+        } else if let Some(body) = text.strip_prefix("0b") {
+            (Radix::Binary, body)
+        } else if text.len() > 1 && text.starts_with('0') {
+            (Radix::Octal, &text[1..])
+        } else {
+            (Radix::Decimal, text.as_str())
+        };
+
+        let (digits, exponent_marker) = match radix {
+            Radix::Hexadecimal => (body.find(|c: char| !c.is_ascii_hexdigit() && c != '.'), 'p'),
+            _ => (body.find(|c: char| !c.is_ascii_digit() && c != '.'), 'e'),
+        };
+        let mut end = digits.unwrap_or(body.len());
+        let mantissa = &body[..end];
+        let mut exponent = None;
+        if body[end..].starts_with(exponent_marker) {
+            let rest = &body[end + 1..];
+            let sign = usize::from(rest.starts_with(['+', '-']));
+            let exponent_len = rest[sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len() - sign);
+            if exponent_len == 0 {
+                return None;
+            }
+            exponent = Some(rest[..sign + exponent_len].parse::<i32>().ok()?);
+            end += 1 + sign + exponent_len;
+        }
+        let suffix = body[end..].to_string();
+        if mantissa.is_empty() || !suffix.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        let is_float = mantissa.contains('.') || exponent.is_some();
+        let (value, radix) = match (is_float, radix) {
+            (true, Radix::Hexadecimal) => (
+                NumberValue::Float(hexadecimal_float(mantissa, exponent?)?),
+                radix,
+            ),
+            // A float with a leading zero, like `0.5` or `01e3`, is still decimal
+            (true, Radix::Decimal | Radix::Octal) => {
+                let decimal = &text[..text.len() - suffix.len()];
+                (NumberValue::Float(decimal.parse().ok()?), Radix::Decimal)
+            }
+            (true, Radix::Binary) => return None,
+            (false, _) => (
+                NumberValue::Integer(u128::from_str_radix(mantissa, radix.base()).ok()?),
+                radix,
+            ),
+        };
+        Some(NumberLiteral {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            value,
+            radix,
+            suffix,
+            spelling,
+        })
+    }
+
+    /// Whether `spelling` still spells `value`, `radix` and `suffix`, so it can be written as it is
+    pub fn spelling_matches(&self) -> bool {
+        NumberLiteral::from_spelling(self.spelling.clone()).is_some_and(|parsed| parsed == *self)
+    }
+
+    /// Spells `value` in `radix` followed by `suffix`, as written for edited literals
+    pub fn canonical_spelling(&self) -> String {
+        let number = match (self.value, self.radix) {
+            (NumberValue::Integer(value), Radix::Decimal) => value.to_string(),
+            (NumberValue::Integer(value), Radix::Hexadecimal) => format!("0x{value:x}"),
+            (NumberValue::Integer(0), Radix::Octal) => "0".to_string(),
+            (NumberValue::Integer(value), Radix::Octal) => format!("0{value:o}"),
+            (NumberValue::Integer(value), Radix::Binary) => format!("0b{value:b}"),
+            (NumberValue::Float(value), Radix::Hexadecimal) if value.is_finite() => {
+                hexadecimal_float_spelling(value)
+            }
+            (NumberValue::Float(value), _) => {
+                let decimal = format!("{value:?}");
+                if decimal.contains(['.', 'e']) {
+                    decimal
+                } else {
+                    format!("{decimal}.0")
+                }
+            }
+        };
+        format!("{number}{}", self.suffix)
+    }
+}
+
+/// Value of a hexadecimal float like `1.8p3`, without its `0x` prefix
+fn hexadecimal_float(mantissa: &str, exponent: i32) -> Option<f64> {
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let mut value = 0f64;
+    for digit in whole.chars().chain(fraction.chars()) {
+        value = value * 16.0 + f64::from(digit.to_digit(16)?);
+    }
+    let fraction_bits = i32::try_from(fraction.len()).ok()?.checked_mul(4)?;
+    Some(value * 2f64.powi(exponent.checked_sub(fraction_bits)?))
+}
+
+fn hexadecimal_float_spelling(value: f64) -> String {
+    if value == 0.0 {
+        return "0x0p+0".to_string();
+    }
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1 << 52) - 1);
+    let (leading, exponent) = if biased_exponent == 0 {
+        (0, -1022)
+    } else {
+        (1, biased_exponent - 1023)
+    };
+    let digits = format!("{fraction:013x}");
+    let digits = digits.trim_end_matches('0');
+    let sign = if exponent < 0 { "" } else { "+" };
+    if digits.is_empty() {
+        format!("0x{leading}p{sign}{exponent}")
+    } else {
+        format!("0x{leading}.{digits}p{sign}{exponent}")
     }
 }
 
@@ -18,7 +207,12 @@ impl Default for NumberLiteral {
     fn default() -> Self {
         NumberLiteral {
             id: Uuid::new_v4(),
-            value: String::new(),
+            span: None,
+            comments: Comments::default(),
+            value: NumberValue::default(),
+            radix: Radix::default(),
+            suffix: String::new(),
+            spelling: "0".to_string(),
         }
     }
 }
@@ -26,5 +220,8 @@ impl Default for NumberLiteral {
 impl PartialEq for NumberLiteral {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
+            && self.radix == other.radix
+            && self.suffix == other.suffix
+            && self.comments == other.comments
     }
 }
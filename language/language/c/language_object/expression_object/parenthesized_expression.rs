@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Explicit grouping, kept as written so operators are printed back with the same
+/// precedence: `(a + b) * c`
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct ParenthesizedExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub value: Box<ExpressionObject>,
+}
+
+impl ParenthesizedExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_parenthesized_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for ParenthesizedExpression {
+    fn default() -> Self {
+        ParenthesizedExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            value: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for ParenthesizedExpression {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
+    }
+}
@@ -1,6 +1,8 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 /// ```c
@@ -8,19 +10,25 @@ use crate::language::c::writers::{Cursor, writer_error::WriterError};
 /// ```
 pub struct Reference {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub declaration_id: Uuid,
     pub identifier: String,
 }
 
 impl PartialEq for Reference {
     fn eq(&self, other: &Self) -> bool {
-        self.declaration_id == other.declaration_id && self.identifier == other.identifier
+        self.declaration_id == other.declaration_id
+            && self.identifier == other.identifier
+            && self.comments == other.comments
     }
 }
 
 impl Reference {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_reference(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_reference(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -28,6 +36,8 @@ impl Default for Reference {
     fn default() -> Self {
         Reference {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             declaration_id: Uuid::new_v4(),
             identifier: String::new(),
         }
@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    c_type::CType,
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// `sizeof` of either a type or an expression, so exactly one of `argument_type` and
+/// `value` is set
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct SizeofExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub argument_type: Option<CType>,
+    pub value: Option<Box<ExpressionObject>>,
+}
+
+impl SizeofExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_sizeof_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for SizeofExpression {
+    fn default() -> Self {
+        SizeofExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            argument_type: None,
+            value: Some(Box::new(ExpressionObject::default())),
+        }
+    }
+}
+
+impl PartialEq for SizeofExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.argument_type == other.argument_type
+            && crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
+    }
+}
@@ -1,16 +1,63 @@
 use uuid::Uuid;
 
-use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    literal::{EncodingPrefix, unescape},
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
 
+/// ```c
+/// L"wide\n"    // prefix == Wide, value holds a real newline
+/// "abc" "def"  // value == "abcdef", segments == ["\"abc\"", "\"def\""]
+/// "\xff"       // value == "\\377", a byte is kept as its octal escape
+/// ```
+/// `value` is the decoded string; `segments` keep each adjacent literal as it was spelled,
+/// so unchanged strings are written back with their original escapes.
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct StringLiteral {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub prefix: EncodingPrefix,
     pub value: String,
+    pub segments: Vec<String>,
 }
 
 impl StringLiteral {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_string_literal(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_string_literal(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+
+    /// Decodes adjacent literal spellings like `L"a\n"` into one string. The prefix is the
+    /// first one that is not empty, as in C. None if a segment is not a string literal.
+    pub fn from_segments(segments: Vec<String>) -> Option<StringLiteral> {
+        let mut prefix = EncodingPrefix::None;
+        let mut value = String::new();
+        for segment in &segments {
+            let (segment_prefix, text) = EncodingPrefix::split_spelling(segment, '"')?;
+            if prefix == EncodingPrefix::None {
+                prefix = segment_prefix;
+            }
+            value.push_str(&unescape(text));
+        }
+        Some(StringLiteral {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            prefix,
+            value,
+            segments,
+        })
+    }
+
+    /// Whether `segments` still spell `prefix` and `value`, so they can be written as they are
+    pub fn segments_match(&self) -> bool {
+        !self.segments.is_empty()
+            && StringLiteral::from_segments(self.segments.clone())
+                .is_some_and(|decoded| decoded == *self)
     }
 }
 
@@ -18,13 +65,17 @@ impl Default for StringLiteral {
     fn default() -> Self {
         StringLiteral {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            prefix: EncodingPrefix::default(),
             value: String::new(),
+            segments: vec![],
         }
     }
 }
 
 impl PartialEq for StringLiteral {
     fn eq(&self, other: &Self) -> bool {
-        self.value == other.value
+        self.prefix == other.prefix && self.value == other.value && self.comments == other.comments
     }
 }
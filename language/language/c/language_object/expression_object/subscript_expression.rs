@@ -0,0 +1,48 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// values[i]
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct SubscriptExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub argument: Box<ExpressionObject>,
+    pub index: Box<ExpressionObject>,
+}
+
+impl SubscriptExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_subscript_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for SubscriptExpression {
+    fn default() -> Self {
+        SubscriptExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(ExpressionObject::default()),
+            index: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for SubscriptExpression {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.argument, &other.argument)
+            && crate::language::PartialEqAny::eq_dyn(&self.index, &other.index)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Prefix operator applied to a single operand: `!x`, `-y`, `~m`, `*p` or `&v`
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct UnaryExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub operator: String,
+    pub argument: Box<ExpressionObject>,
+}
+
+impl UnaryExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_unary_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for UnaryExpression {
+    fn default() -> Self {
+        UnaryExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            operator: String::new(),
+            argument: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for UnaryExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.operator == other.operator
+            && crate::language::PartialEqAny::eq_dyn(&self.argument, &other.argument)
+            && self.comments == other.comments
+    }
+}
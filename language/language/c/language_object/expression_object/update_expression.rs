@@ -0,0 +1,49 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::expression_object::ExpressionObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Increment or decrement, written before (`++i`) or after (`i++`) its operand
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct UpdateExpression {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub operator: String,
+    pub is_prefix: bool,
+    pub argument: Box<ExpressionObject>,
+}
+
+impl UpdateExpression {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_update_expression(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for UpdateExpression {
+    fn default() -> Self {
+        UpdateExpression {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            operator: String::new(),
+            is_prefix: false,
+            argument: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for UpdateExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.operator == other.operator
+            && self.is_prefix == other.is_prefix
+            && crate::language::PartialEqAny::eq_dyn(&self.argument, &other.argument)
+            && self.comments == other.comments
+    }
+}
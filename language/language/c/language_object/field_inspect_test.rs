@@ -57,8 +57,10 @@ mod tests {
         let test = TestStruct::default();
 
         let options = test.get_options("body");
-        // Should return all StatementObject variants: CompoundStatement, IfStatement, ReturnStatement, Unknown
-        assert_eq!(options.len(), 4);
+        // Should return all StatementObject variants: BreakStatement, CaseStatement,
+        // CompoundStatement, ContinueStatement, DoStatement, ForStatement, GotoStatement,
+        // IfStatement, LabeledStatement, ReturnStatement, SwitchStatement, WhileStatement, Unknown
+        assert_eq!(options.len(), 13);
 
         let has_compound_statement = options.iter().any(|opt| {
             matches!(
@@ -84,10 +86,31 @@ mod tests {
                 crate::language::c::language_object::LanguageObject::Unknown(_)
             )
         });
+        let has_while_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::WhileStatement(_)
+            )
+        });
+        let has_do_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::DoStatement(_)
+            )
+        });
+        let has_for_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::ForStatement(_)
+            )
+        });
 
         assert!(has_compound_statement, "Missing CompoundStatement variant");
         assert!(has_if_statement, "Missing IfStatement variant");
         assert!(has_return_statement, "Missing ReturnStatement variant");
+        assert!(has_while_statement, "Missing WhileStatement variant");
+        assert!(has_do_statement, "Missing DoStatement variant");
+        assert!(has_for_statement, "Missing ForStatement variant");
         assert!(has_unknown, "Missing Unknown variant");
     }
 
@@ -97,7 +120,7 @@ mod tests {
 
         let options = test.get_options("expr");
         // Should return all ExpressionObject variants
-        assert_eq!(options.len(), 7);
+        assert_eq!(options.len(), 18);
 
         let has_assignment = options.iter().any(|opt| {
             matches!(
@@ -135,6 +158,12 @@ mod tests {
                 crate::language::c::language_object::LanguageObject::StringLiteral(_)
             )
         });
+        let has_char = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::CharLiteral(_)
+            )
+        });
         let has_unknown = options.iter().any(|opt| {
             matches!(
                 opt,
@@ -142,6 +171,67 @@ mod tests {
             )
         });
 
+        let has_unary = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::UnaryExpression(_)
+            )
+        });
+        let has_update = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::UpdateExpression(_)
+            )
+        });
+        let has_cast = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::CastExpression(_)
+            )
+        });
+        let has_sizeof = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::SizeofExpression(_)
+            )
+        });
+        let has_conditional = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::ConditionalExpression(_)
+            )
+        });
+        let has_field = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::FieldExpression(_)
+            )
+        });
+        let has_subscript = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::SubscriptExpression(_)
+            )
+        });
+        let has_parenthesized = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::ParenthesizedExpression(_)
+            )
+        });
+        let has_initializer_list = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::InitializerList(_)
+            )
+        });
+        let has_initializer_pair = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::InitializerPair(_)
+            )
+        });
+
         assert!(has_assignment, "Missing AssignmentExpression variant");
         assert!(has_binary, "Missing BinaryExpression variant");
         assert!(has_call, "Missing CallExpression variant");
@@ -149,6 +239,17 @@ mod tests {
         assert!(has_reference, "Missing Reference variant");
         assert!(has_string, "Missing StringLiteral variant");
         assert!(has_unknown, "Missing Unknown variant");
+        assert!(has_unary, "Missing UnaryExpression variant");
+        assert!(has_update, "Missing UpdateExpression variant");
+        assert!(has_cast, "Missing CastExpression variant");
+        assert!(has_sizeof, "Missing SizeofExpression variant");
+        assert!(has_conditional, "Missing ConditionalExpression variant");
+        assert!(has_field, "Missing FieldExpression variant");
+        assert!(has_subscript, "Missing SubscriptExpression variant");
+        assert!(has_parenthesized, "Missing ParenthesizedExpression variant");
+        assert!(has_initializer_list, "Missing InitializerList variant");
+        assert!(has_initializer_pair, "Missing InitializerPair variant");
+        assert!(has_char, "Missing CharLiteral variant");
     }
 
     #[test]
@@ -157,7 +258,7 @@ mod tests {
 
         let options = test.get_options("compound");
         // Should return all CompoundStatementObject variants
-        assert_eq!(options.len(), 12);
+        assert_eq!(options.len(), 38);
 
         let has_declaration = options.iter().any(|opt| {
             matches!(
@@ -195,12 +296,129 @@ mod tests {
                 crate::language::c::language_object::LanguageObject::Unknown(_)
             )
         });
+        let has_while_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::WhileStatement(_)
+            )
+        });
+        let has_do_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::DoStatement(_)
+            )
+        });
+        let has_for_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::ForStatement(_)
+            )
+        });
+        let has_switch_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::SwitchStatement(_)
+            )
+        });
+        let has_case_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::CaseStatement(_)
+            )
+        });
+        let has_break_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::BreakStatement(_)
+            )
+        });
+        let has_continue_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::ContinueStatement(_)
+            )
+        });
+        let has_labeled_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::LabeledStatement(_)
+            )
+        });
+        let has_goto_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::GotoStatement(_)
+            )
+        });
+
+        let has_struct_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::StructDefinition(_)
+            )
+        });
+        let has_union_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::UnionDefinition(_)
+            )
+        });
+        let has_enum_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::EnumDefinition(_)
+            )
+        });
+        let has_type_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::TypeDefinition(_)
+            )
+        });
+
+        let has_preproc_define = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocDefine(_)
+            )
+        });
+        let has_preproc_call = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocCall(_)
+            )
+        });
+        let has_preproc_if_statement = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocIfStatement(_)
+            )
+        });
 
         assert!(has_declaration, "Missing Declaration variant");
         assert!(has_compound_statement, "Missing CompoundStatement variant");
         assert!(has_if_statement, "Missing IfStatement variant");
         assert!(has_return_statement, "Missing ReturnStatement variant");
         assert!(has_comment, "Missing Comment variant");
+        assert!(has_while_statement, "Missing WhileStatement variant");
+        assert!(has_do_statement, "Missing DoStatement variant");
+        assert!(has_for_statement, "Missing ForStatement variant");
+        assert!(has_switch_statement, "Missing SwitchStatement variant");
+        assert!(has_case_statement, "Missing CaseStatement variant");
+        assert!(has_break_statement, "Missing BreakStatement variant");
+        assert!(has_continue_statement, "Missing ContinueStatement variant");
+        assert!(has_labeled_statement, "Missing LabeledStatement variant");
+        assert!(has_goto_statement, "Missing GotoStatement variant");
+        assert!(has_struct_definition, "Missing StructDefinition variant");
+        assert!(has_union_definition, "Missing UnionDefinition variant");
+        assert!(has_enum_definition, "Missing EnumDefinition variant");
+        assert!(has_type_definition, "Missing TypeDefinition variant");
+        assert!(has_preproc_define, "Missing PreprocDefine variant");
+        assert!(has_preproc_call, "Missing PreprocCall variant");
+        assert!(
+            has_preproc_if_statement,
+            "Missing PreprocIfStatement variant"
+        );
         assert!(has_unknown, "Missing Unknown variant");
         // Also has all expression variants, but not testing them individually here
     }
@@ -211,7 +429,7 @@ mod tests {
 
         let options = test.get_options("decl");
         // Should return all DeclarationObject variants
-        assert_eq!(options.len(), 6);
+        assert_eq!(options.len(), 14);
 
         let has_declaration = options.iter().any(|opt| {
             matches!(
@@ -250,6 +468,56 @@ mod tests {
             )
         });
 
+        let has_struct_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::StructDefinition(_)
+            )
+        });
+        let has_union_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::UnionDefinition(_)
+            )
+        });
+        let has_enum_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::EnumDefinition(_)
+            )
+        });
+        let has_type_definition = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::TypeDefinition(_)
+            )
+        });
+
+        let has_preproc_define = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocDefine(_)
+            )
+        });
+        let has_preproc_call = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocCall(_)
+            )
+        });
+        let has_preproc_if = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::PreprocIf(_)
+            )
+        });
+        let has_declaration_list = options.iter().any(|opt| {
+            matches!(
+                opt,
+                crate::language::c::language_object::LanguageObject::DeclarationList(_)
+            )
+        });
+
         assert!(has_declaration, "Missing Declaration variant");
         assert!(
             has_function_declaration,
@@ -261,6 +529,14 @@ mod tests {
         );
         assert!(has_preproc_include, "Missing PreprocInclude variant");
         assert!(has_comment, "Missing Comment variant");
+        assert!(has_struct_definition, "Missing StructDefinition variant");
+        assert!(has_union_definition, "Missing UnionDefinition variant");
+        assert!(has_enum_definition, "Missing EnumDefinition variant");
+        assert!(has_type_definition, "Missing TypeDefinition variant");
+        assert!(has_preproc_define, "Missing PreprocDefine variant");
+        assert!(has_preproc_call, "Missing PreprocCall variant");
+        assert!(has_preproc_if, "Missing PreprocIf variant");
+        assert!(has_declaration_list, "Missing DeclarationList variant");
         assert!(has_unknown, "Missing Unknown variant");
     }
 
@@ -278,7 +554,7 @@ mod tests {
 
         let options = test.get_options("vec_statements");
         // Should return all StatementObject variants, unwrapped from Vec
-        assert_eq!(options.len(), 4);
+        assert_eq!(options.len(), 13);
 
         let has_compound_statement = options.iter().any(|opt| {
             matches!(
@@ -317,7 +593,7 @@ mod tests {
 
         let options = test.get_options("opt_expression");
         // Should return all ExpressionObject variants, unwrapped from Option
-        assert_eq!(options.len(), 7);
+        assert_eq!(options.len(), 18);
 
         let has_assignment = options.iter().any(|opt| {
             matches!(
@@ -343,7 +619,7 @@ mod tests {
 
         let options = test.get_options("boxed_declaration");
         // Should return all DeclarationObject variants, unwrapped from Box
-        assert_eq!(options.len(), 6);
+        assert_eq!(options.len(), 14);
 
         let has_declaration = options.iter().any(|opt| {
             matches!(
@@ -371,7 +647,7 @@ mod tests {
 
         let options = test.get_options("nested");
         // Should return all StatementObject variants, unwrapped from Option<Box<Vec<T>>>
-        assert_eq!(options.len(), 4);
+        assert_eq!(options.len(), 13);
 
         let has_compound_statement = options.iter().any(|opt| {
             matches!(
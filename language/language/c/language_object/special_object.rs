@@ -1,3 +1,4 @@
 pub mod comment;
 pub mod source_file;
+pub mod trivia;
 pub mod unknown;
@@ -1,10 +1,12 @@
 use uuid::Uuid;
 
 use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct Comment {
     pub id: Uuid,
+    pub span: Option<Span>,
     pub content: String,
 }
 
@@ -24,7 +26,23 @@ impl Default for Comment {
     fn default() -> Self {
         Comment {
             id: Uuid::new_v4(),
+            span: None,
             content: String::new(),
         }
     }
 }
+
+/// Comments attached to the object they document, so they move and merge along with it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Comments {
+    /// Comments right before the object, like the doc comment of a function
+    pub leading: Vec<Comment>,
+    /// Comments right after the object, on the line it ends
+    pub trailing: Vec<Comment>,
+}
+
+impl Comments {
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty()
+    }
+}
@@ -1,14 +1,19 @@
 use uuid::Uuid;
 
 use crate::language::c::{
-    language_object::declaration_object::DeclarationObject,
+    language_object::{declaration_object::DeclarationObject, special_object::trivia::Trivia},
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct SourceFile {
     pub id: Uuid,
+    pub span: Option<Span>,
     pub code: Vec<DeclarationObject>,
+    /// Layout of the objects of the text the file was parsed from, kept when parsing with
+    /// trivia so they are written back laid out as they were
+    pub trivia: Option<Trivia>,
 }
 
 impl SourceFile {
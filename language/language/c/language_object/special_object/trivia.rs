@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::language::c::{language_object::LanguageObject, writers::text_writer::style::Style};
+
+/// Layout of the text a file was parsed from, kept for each object of the file and of its
+/// blocks so they are written back laid out as they were
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trivia {
+    /// Layout of the objects of the file and of its blocks, by their id
+    pub layouts: HashMap<Uuid, Layout>,
+}
+
+/// How an object of a file or of a block was laid out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Layout {
+    /// Blank lines between the object, leading comments included, and the code before it
+    pub blank_lines_before: usize,
+    /// Where the signature of a function was broken in lines, which other objects don't have
+    pub line_breaks: Option<LineBreaks>,
+    /// Indentation of the line the object starts, when it is not the one of the style
+    pub indent: Option<String>,
+}
+
+/// Where the signature of a function was broken in lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineBreaks {
+    /// The return type is on a line of its own, above the name
+    pub after_type: bool,
+    /// The body starts on the line below the signature
+    pub before_block: bool,
+}
+
+impl Trivia {
+    /// Records the layout of the objects of the file, or of a block `depth` blocks deep, as
+    /// they are in `source`
+    pub fn record(&mut self, objects: &[LanguageObject], source: &str, depth: usize) {
+        // Text is written with the GNU style, so only another indentation is kept
+        let style_indent = " ".repeat(depth * Style::gnu_style().indent_size);
+        for object in objects {
+            let Some(start) = start(object) else {
+                continue;
+            };
+            let before = &source[..start];
+            let whitespace = &before[before.trim_end().len()..];
+            let newlines = whitespace.matches('\n').count();
+            // The first object of a file has no line before it to end
+            let blank_lines_before = if whitespace.len() == before.len() {
+                newlines
+            } else {
+                newlines.saturating_sub(1)
+            };
+            let line = &before[before.rfind('\n').map_or(0, |i| i + 1)..];
+            let indent = (line.trim().is_empty() && line != style_indent).then(|| line.to_string());
+            self.layouts.insert(
+                object.id(),
+                Layout {
+                    blank_lines_before,
+                    line_breaks: line_breaks(object, source),
+                    indent,
+                },
+            );
+        }
+    }
+
+    /// Layout of the object with `id`, when it was recorded
+    pub fn layout(&self, id: &Uuid) -> Option<&Layout> {
+        self.layouts.get(id)
+    }
+
+    /// Layouts of `ours` and `theirs`, merged from `base` object by object. Each object keeps
+    /// the layout of the side that changed it, ours when both did
+    pub fn merge(
+        base: Option<&Trivia>,
+        ours: Option<&Trivia>,
+        theirs: Option<&Trivia>,
+    ) -> Option<Trivia> {
+        if ours.is_none() && theirs.is_none() {
+            return None;
+        }
+        let empty = Trivia::default();
+        let (base, ours, theirs) = (
+            base.unwrap_or(&empty),
+            ours.unwrap_or(&empty),
+            theirs.unwrap_or(&empty),
+        );
+        let mut layouts = theirs.layouts.clone();
+        for (id, layout) in &ours.layouts {
+            if base.layout(id) != Some(layout) || !theirs.layouts.contains_key(id) {
+                layouts.insert(*id, layout.clone());
+            }
+        }
+        Some(Trivia { layouts })
+    }
+}
+
+/// Start in the source of `object` and of the comments before it
+fn start(object: &LanguageObject) -> Option<usize> {
+    let mut start = object.span()?.start.offset;
+    if let Some(comments) = object.comments() {
+        for span in comments.leading.iter().filter_map(|comment| comment.span) {
+            start = start.min(span.start.offset);
+        }
+    }
+    Some(start)
+}
+
+/// Where the signature of a function was broken in lines, read from its text up to its body
+fn line_breaks(object: &LanguageObject, source: &str) -> Option<LineBreaks> {
+    let (identifier, has_body) = match object {
+        LanguageObject::FunctionDefinition(function) => (&function.identifier, true),
+        LanguageObject::FunctionDeclaration(function) => (&function.identifier, false),
+        _ => return None,
+    };
+    let text = source.get(object.span()?.range())?;
+    let signature = if has_body {
+        &text[..text.find('{').unwrap_or(text.len())]
+    } else {
+        text
+    };
+    let name = find_word(signature, identifier)?;
+    Some(LineBreaks {
+        after_type: signature[..name].trim_start().contains('\n'),
+        before_block: has_body && signature[signature.trim_end().len()..].contains('\n'),
+    })
+}
+
+/// Offset of `word` in `text` where it is not part of a longer identifier
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    let is_identifier = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word)
+        .map(|(offset, _)| offset)
+        .find(|&offset| {
+            !text[..offset].ends_with(is_identifier)
+                && !text[offset + word.len()..].starts_with(is_identifier)
+        })
+}
@@ -2,9 +2,19 @@ use crate::language::c::language_object::{
     ConversionError, LanguageObject, special_object::unknown::Unknown,
 };
 
+pub mod break_statement;
+pub mod case_statement;
 pub mod compound_statement;
+pub mod continue_statement;
+pub mod do_statement;
+pub mod for_statement;
+pub mod goto_statement;
 pub mod if_statement;
+pub mod labeled_statement;
+pub mod preproc_if_statement;
 pub mod return_statement;
+pub mod switch_statement;
+pub mod while_statement;
 
 #[derive(
     Debug,
@@ -14,9 +24,18 @@ pub mod return_statement;
     lenga_field_inspect_derive::FieldInspect,
 )]
 pub enum StatementObject {
+    BreakStatement(break_statement::BreakStatement),
+    CaseStatement(case_statement::CaseStatement),
     CompoundStatement(compound_statement::CompoundStatement),
+    ContinueStatement(continue_statement::ContinueStatement),
+    DoStatement(do_statement::DoStatement),
+    ForStatement(for_statement::ForStatement),
+    GotoStatement(goto_statement::GotoStatement),
     IfStatement(if_statement::IfStatement),
+    LabeledStatement(labeled_statement::LabeledStatement),
     ReturnStatement(return_statement::ReturnStatement),
+    SwitchStatement(switch_statement::SwitchStatement),
+    WhileStatement(while_statement::WhileStatement),
     Unknown(Unknown),
 }
 
@@ -26,9 +45,18 @@ impl StatementObject {
         w: &mut dyn crate::language::c::writers::Cursor,
     ) -> Result<(), crate::language::c::writers::writer_error::WriterError> {
         match self {
+            StatementObject::BreakStatement(stmt) => stmt.write(w),
+            StatementObject::CaseStatement(stmt) => stmt.write(w),
             StatementObject::CompoundStatement(stmt) => stmt.write(w),
+            StatementObject::ContinueStatement(stmt) => stmt.write(w),
+            StatementObject::DoStatement(stmt) => stmt.write(w),
+            StatementObject::ForStatement(stmt) => stmt.write(w),
+            StatementObject::GotoStatement(stmt) => stmt.write(w),
             StatementObject::IfStatement(stmt) => stmt.write(w),
+            StatementObject::LabeledStatement(stmt) => stmt.write(w),
             StatementObject::ReturnStatement(stmt) => stmt.write(w),
+            StatementObject::SwitchStatement(stmt) => stmt.write(w),
+            StatementObject::WhileStatement(stmt) => stmt.write(w),
             StatementObject::Unknown(stmt) => stmt.write(w),
         }
     }
@@ -43,9 +71,18 @@ impl Default for StatementObject {
 impl From<StatementObject> for LanguageObject {
     fn from(value: StatementObject) -> Self {
         match value {
+            StatementObject::BreakStatement(stmt) => LanguageObject::BreakStatement(stmt),
+            StatementObject::CaseStatement(stmt) => LanguageObject::CaseStatement(stmt),
             StatementObject::CompoundStatement(stmt) => LanguageObject::CompoundStatement(stmt),
+            StatementObject::ContinueStatement(stmt) => LanguageObject::ContinueStatement(stmt),
+            StatementObject::DoStatement(stmt) => LanguageObject::DoStatement(stmt),
+            StatementObject::ForStatement(stmt) => LanguageObject::ForStatement(stmt),
+            StatementObject::GotoStatement(stmt) => LanguageObject::GotoStatement(stmt),
             StatementObject::IfStatement(stmt) => LanguageObject::IfStatement(stmt),
+            StatementObject::LabeledStatement(stmt) => LanguageObject::LabeledStatement(stmt),
             StatementObject::ReturnStatement(stmt) => LanguageObject::ReturnStatement(stmt),
+            StatementObject::SwitchStatement(stmt) => LanguageObject::SwitchStatement(stmt),
+            StatementObject::WhileStatement(stmt) => LanguageObject::WhileStatement(stmt),
             StatementObject::Unknown(stmt) => LanguageObject::Unknown(stmt),
         }
     }
@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct BreakStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+}
+
+impl PartialEq for BreakStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.comments == other.comments
+    }
+}
+
+impl Default for BreakStatement {
+    fn default() -> Self {
+        BreakStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+        }
+    }
+}
+
+impl BreakStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_break_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::{
+        expression_object::ExpressionObject,
+        statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    },
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// case 0:
+///     state = 1;
+///     break;
+/// ```
+///
+/// A `default:` label is a case without a value.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct CaseStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub value: Option<Box<ExpressionObject>>,
+    pub body: Vec<CompoundStatementObject>,
+}
+
+impl CaseStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_case_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for CaseStatement {
+    fn default() -> Self {
+        CaseStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            value: None,
+            body: vec![],
+        }
+    }
+}
+
+impl PartialEq for CaseStatement {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.comments == other.comments
+    }
+}
@@ -1,18 +1,24 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
 
 pub mod compound_statement_object;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct CompoundStatement {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub code_block: Vec<compound_statement_object::CompoundStatementObject>,
 }
 
 impl CompoundStatement {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_compound_statement(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_compound_statement(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -20,6 +26,8 @@ impl Default for CompoundStatement {
     fn default() -> Self {
         CompoundStatement {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             code_block: vec![],
         }
     }
@@ -28,5 +36,6 @@ impl Default for CompoundStatement {
 impl PartialEq for CompoundStatement {
     fn eq(&self, other: &Self) -> bool {
         crate::language::PartialEqAny::eq_dyn(&self.code_block, &other.code_block)
+            && self.comments == other.comments
     }
 }
@@ -1,13 +1,21 @@
+use uuid::Uuid;
+
 use crate::language::c::language_object::LanguageObject;
-use crate::language::c::language_object::declaration_object::declaration;
+use crate::language::c::language_object::declaration_object::{
+    declaration, declaration_list, enum_definition, preproc_call, preproc_define,
+    struct_definition, type_definition, union_definition,
+};
 use crate::language::c::language_object::expression_object::{
-    assignment_expression, binary_expression, call_expression, number_literal, reference,
-    string_literal,
+    assignment_expression, binary_expression, call_expression, cast_expression, char_literal,
+    conditional_expression, field_expression, number_literal, parenthesized_expression, reference,
+    sizeof_expression, string_literal, subscript_expression, unary_expression, update_expression,
 };
 use crate::language::c::language_object::special_object::comment;
 use crate::language::c::language_object::special_object::unknown::Unknown;
 use crate::language::c::language_object::statement_object::{
-    compound_statement, if_statement, return_statement,
+    break_statement, case_statement, compound_statement, continue_statement, do_statement,
+    for_statement, goto_statement, if_statement, labeled_statement, preproc_if_statement,
+    return_statement, switch_statement, while_statement,
 };
 
 #[derive(
@@ -19,19 +27,46 @@ use crate::language::c::language_object::statement_object::{
 )]
 pub enum CompoundStatementObject {
     Declaration(declaration::Declaration),
+    DeclarationList(declaration_list::DeclarationList),
+    StructDefinition(struct_definition::StructDefinition),
+    UnionDefinition(union_definition::UnionDefinition),
+    EnumDefinition(enum_definition::EnumDefinition),
+    TypeDefinition(type_definition::TypeDefinition),
+    PreprocDefine(preproc_define::PreprocDefine),
+    PreprocCall(preproc_call::PreprocCall),
 
     AssignmentExpression(assignment_expression::AssignmentExpression),
     BinaryExpression(binary_expression::BinaryExpression),
+    UnaryExpression(unary_expression::UnaryExpression),
+    UpdateExpression(update_expression::UpdateExpression),
+    CastExpression(cast_expression::CastExpression),
+    SizeofExpression(sizeof_expression::SizeofExpression),
+    ConditionalExpression(conditional_expression::ConditionalExpression),
+    FieldExpression(field_expression::FieldExpression),
+    SubscriptExpression(subscript_expression::SubscriptExpression),
+    ParenthesizedExpression(parenthesized_expression::ParenthesizedExpression),
     CallExpression(call_expression::CallExpression),
     NumberLiteral(number_literal::NumberLiteral),
     Reference(reference::Reference),
     StringLiteral(string_literal::StringLiteral),
+    CharLiteral(char_literal::CharLiteral),
 
+    BreakStatement(break_statement::BreakStatement),
+    CaseStatement(case_statement::CaseStatement),
     CompoundStatement(compound_statement::CompoundStatement),
+    ContinueStatement(continue_statement::ContinueStatement),
+    DoStatement(do_statement::DoStatement),
+    ForStatement(for_statement::ForStatement),
+    GotoStatement(goto_statement::GotoStatement),
     IfStatement(if_statement::IfStatement),
+    LabeledStatement(labeled_statement::LabeledStatement),
     ReturnStatement(return_statement::ReturnStatement),
+    SwitchStatement(switch_statement::SwitchStatement),
+    WhileStatement(while_statement::WhileStatement),
+    PreprocIfStatement(preproc_if_statement::PreprocIfStatement),
 
-    Comment(comment::Comment), // TODO: This doesn't belong here. To avoid putting comments in every enum, I woud try to match them with their corresponding node
+    /// A comment that documents no object, like one separated from the next by a blank line
+    Comment(comment::Comment),
     Unknown(Unknown),
 }
 
@@ -42,24 +77,98 @@ impl CompoundStatementObject {
     ) -> Result<(), crate::language::c::writers::writer_error::WriterError> {
         match self {
             CompoundStatementObject::Declaration(decl) => decl.write(w),
+            CompoundStatementObject::DeclarationList(decl) => decl.write(w),
+            CompoundStatementObject::StructDefinition(struct_def) => struct_def.write(w),
+            CompoundStatementObject::UnionDefinition(union_def) => union_def.write(w),
+            CompoundStatementObject::EnumDefinition(enum_def) => enum_def.write(w),
+            CompoundStatementObject::TypeDefinition(type_def) => type_def.write(w),
+            CompoundStatementObject::PreprocDefine(define) => define.write(w),
+            CompoundStatementObject::PreprocCall(call) => call.write(w),
 
             CompoundStatementObject::AssignmentExpression(expr) => expr.write(w),
             CompoundStatementObject::BinaryExpression(expr) => expr.write(w),
+            CompoundStatementObject::UnaryExpression(expr) => expr.write(w),
+            CompoundStatementObject::UpdateExpression(expr) => expr.write(w),
+            CompoundStatementObject::CastExpression(expr) => expr.write(w),
+            CompoundStatementObject::SizeofExpression(expr) => expr.write(w),
+            CompoundStatementObject::ConditionalExpression(expr) => expr.write(w),
+            CompoundStatementObject::FieldExpression(expr) => expr.write(w),
+            CompoundStatementObject::SubscriptExpression(expr) => expr.write(w),
+            CompoundStatementObject::ParenthesizedExpression(expr) => expr.write(w),
             CompoundStatementObject::CallExpression(expr) => expr.write(w),
             CompoundStatementObject::NumberLiteral(expr) => expr.write(w),
             CompoundStatementObject::Reference(expr) => expr.write(w),
             CompoundStatementObject::StringLiteral(expr) => expr.write(w),
+            CompoundStatementObject::CharLiteral(expr) => expr.write(w),
 
+            CompoundStatementObject::BreakStatement(break_statement) => break_statement.write(w),
+            CompoundStatementObject::CaseStatement(case_statement) => case_statement.write(w),
             CompoundStatementObject::CompoundStatement(compound_statement) => {
                 compound_statement.write(w)
             }
+            CompoundStatementObject::ContinueStatement(continue_statement) => {
+                continue_statement.write(w)
+            }
+            CompoundStatementObject::GotoStatement(goto_statement) => goto_statement.write(w),
+            CompoundStatementObject::LabeledStatement(labeled_statement) => {
+                labeled_statement.write(w)
+            }
+            CompoundStatementObject::DoStatement(do_statement) => do_statement.write(w),
+            CompoundStatementObject::ForStatement(for_statement) => for_statement.write(w),
             CompoundStatementObject::IfStatement(if_statement) => if_statement.write(w),
             CompoundStatementObject::ReturnStatement(return_statement) => return_statement.write(w),
+            CompoundStatementObject::SwitchStatement(switch_statement) => switch_statement.write(w),
+            CompoundStatementObject::WhileStatement(while_statement) => while_statement.write(w),
+            CompoundStatementObject::PreprocIfStatement(preproc_if_statement) => {
+                preproc_if_statement.write(w)
+            }
 
             CompoundStatementObject::Unknown(unknown) => unknown.write(w),
             CompoundStatementObject::Comment(comment) => comment.write(w),
         }
     }
+    pub fn id(&self) -> Uuid {
+        match self {
+            CompoundStatementObject::Declaration(object) => object.id,
+            CompoundStatementObject::DeclarationList(object) => object.id,
+            CompoundStatementObject::StructDefinition(object) => object.id,
+            CompoundStatementObject::UnionDefinition(object) => object.id,
+            CompoundStatementObject::EnumDefinition(object) => object.id,
+            CompoundStatementObject::TypeDefinition(object) => object.id,
+            CompoundStatementObject::PreprocDefine(object) => object.id,
+            CompoundStatementObject::PreprocCall(object) => object.id,
+            CompoundStatementObject::AssignmentExpression(object) => object.id,
+            CompoundStatementObject::BinaryExpression(object) => object.id,
+            CompoundStatementObject::UnaryExpression(object) => object.id,
+            CompoundStatementObject::UpdateExpression(object) => object.id,
+            CompoundStatementObject::CastExpression(object) => object.id,
+            CompoundStatementObject::SizeofExpression(object) => object.id,
+            CompoundStatementObject::ConditionalExpression(object) => object.id,
+            CompoundStatementObject::FieldExpression(object) => object.id,
+            CompoundStatementObject::SubscriptExpression(object) => object.id,
+            CompoundStatementObject::ParenthesizedExpression(object) => object.id,
+            CompoundStatementObject::CallExpression(object) => object.id,
+            CompoundStatementObject::NumberLiteral(object) => object.id,
+            CompoundStatementObject::Reference(object) => object.id,
+            CompoundStatementObject::StringLiteral(object) => object.id,
+            CompoundStatementObject::CharLiteral(object) => object.id,
+            CompoundStatementObject::BreakStatement(object) => object.id,
+            CompoundStatementObject::CaseStatement(object) => object.id,
+            CompoundStatementObject::CompoundStatement(object) => object.id,
+            CompoundStatementObject::ContinueStatement(object) => object.id,
+            CompoundStatementObject::DoStatement(object) => object.id,
+            CompoundStatementObject::ForStatement(object) => object.id,
+            CompoundStatementObject::GotoStatement(object) => object.id,
+            CompoundStatementObject::IfStatement(object) => object.id,
+            CompoundStatementObject::LabeledStatement(object) => object.id,
+            CompoundStatementObject::ReturnStatement(object) => object.id,
+            CompoundStatementObject::SwitchStatement(object) => object.id,
+            CompoundStatementObject::WhileStatement(object) => object.id,
+            CompoundStatementObject::PreprocIfStatement(object) => object.id,
+            CompoundStatementObject::Comment(object) => object.id,
+            CompoundStatementObject::Unknown(object) => object.id,
+        }
+    }
 }
 
 impl Default for CompoundStatementObject {
@@ -72,6 +181,25 @@ impl From<CompoundStatementObject> for LanguageObject {
     fn from(value: CompoundStatementObject) -> Self {
         match value {
             CompoundStatementObject::Declaration(decl) => LanguageObject::Declaration(decl.clone()),
+            CompoundStatementObject::DeclarationList(decl) => {
+                LanguageObject::DeclarationList(decl.clone())
+            }
+            CompoundStatementObject::StructDefinition(struct_def) => {
+                LanguageObject::StructDefinition(struct_def.clone())
+            }
+            CompoundStatementObject::UnionDefinition(union_def) => {
+                LanguageObject::UnionDefinition(union_def.clone())
+            }
+            CompoundStatementObject::EnumDefinition(enum_def) => {
+                LanguageObject::EnumDefinition(enum_def.clone())
+            }
+            CompoundStatementObject::TypeDefinition(type_def) => {
+                LanguageObject::TypeDefinition(type_def.clone())
+            }
+            CompoundStatementObject::PreprocDefine(define) => {
+                LanguageObject::PreprocDefine(define.clone())
+            }
+            CompoundStatementObject::PreprocCall(call) => LanguageObject::PreprocCall(call.clone()),
 
             CompoundStatementObject::AssignmentExpression(expr) => {
                 LanguageObject::AssignmentExpression(expr.clone())
@@ -79,6 +207,30 @@ impl From<CompoundStatementObject> for LanguageObject {
             CompoundStatementObject::BinaryExpression(expr) => {
                 LanguageObject::BinaryExpression(expr.clone())
             }
+            CompoundStatementObject::UnaryExpression(expr) => {
+                LanguageObject::UnaryExpression(expr.clone())
+            }
+            CompoundStatementObject::UpdateExpression(expr) => {
+                LanguageObject::UpdateExpression(expr.clone())
+            }
+            CompoundStatementObject::CastExpression(expr) => {
+                LanguageObject::CastExpression(expr.clone())
+            }
+            CompoundStatementObject::SizeofExpression(expr) => {
+                LanguageObject::SizeofExpression(expr.clone())
+            }
+            CompoundStatementObject::ConditionalExpression(expr) => {
+                LanguageObject::ConditionalExpression(expr.clone())
+            }
+            CompoundStatementObject::FieldExpression(expr) => {
+                LanguageObject::FieldExpression(expr.clone())
+            }
+            CompoundStatementObject::SubscriptExpression(expr) => {
+                LanguageObject::SubscriptExpression(expr.clone())
+            }
+            CompoundStatementObject::ParenthesizedExpression(expr) => {
+                LanguageObject::ParenthesizedExpression(expr.clone())
+            }
             CompoundStatementObject::CallExpression(expr) => {
                 LanguageObject::CallExpression(expr.clone())
             }
@@ -89,16 +241,47 @@ impl From<CompoundStatementObject> for LanguageObject {
             CompoundStatementObject::StringLiteral(expr) => {
                 LanguageObject::StringLiteral(expr.clone())
             }
+            CompoundStatementObject::CharLiteral(expr) => LanguageObject::CharLiteral(expr.clone()),
 
+            CompoundStatementObject::BreakStatement(break_statement) => {
+                LanguageObject::BreakStatement(break_statement.clone())
+            }
+            CompoundStatementObject::CaseStatement(case_statement) => {
+                LanguageObject::CaseStatement(case_statement.clone())
+            }
             CompoundStatementObject::CompoundStatement(compound_statement) => {
                 LanguageObject::CompoundStatement(compound_statement.clone())
             }
+            CompoundStatementObject::ContinueStatement(continue_statement) => {
+                LanguageObject::ContinueStatement(continue_statement.clone())
+            }
+            CompoundStatementObject::GotoStatement(goto_statement) => {
+                LanguageObject::GotoStatement(goto_statement.clone())
+            }
+            CompoundStatementObject::LabeledStatement(labeled_statement) => {
+                LanguageObject::LabeledStatement(labeled_statement.clone())
+            }
+            CompoundStatementObject::DoStatement(do_statement) => {
+                LanguageObject::DoStatement(do_statement.clone())
+            }
+            CompoundStatementObject::ForStatement(for_statement) => {
+                LanguageObject::ForStatement(for_statement.clone())
+            }
             CompoundStatementObject::IfStatement(if_statement) => {
                 LanguageObject::IfStatement(if_statement.clone())
             }
             CompoundStatementObject::ReturnStatement(return_statement) => {
                 LanguageObject::ReturnStatement(return_statement.clone())
             }
+            CompoundStatementObject::SwitchStatement(switch_statement) => {
+                LanguageObject::SwitchStatement(switch_statement.clone())
+            }
+            CompoundStatementObject::WhileStatement(while_statement) => {
+                LanguageObject::WhileStatement(while_statement.clone())
+            }
+            CompoundStatementObject::PreprocIfStatement(preproc_if_statement) => {
+                LanguageObject::PreprocIfStatement(preproc_if_statement.clone())
+            }
 
             CompoundStatementObject::Unknown(unknown) => LanguageObject::Unknown(unknown.clone()),
             CompoundStatementObject::Comment(comment) => LanguageObject::Comment(comment.clone()),
@@ -112,6 +295,25 @@ impl TryFrom<LanguageObject> for CompoundStatementObject {
     fn try_from(value: LanguageObject) -> Result<Self, Self::Error> {
         match value {
             LanguageObject::Declaration(decl) => Ok(CompoundStatementObject::Declaration(decl)),
+            LanguageObject::DeclarationList(decl) => {
+                Ok(CompoundStatementObject::DeclarationList(decl))
+            }
+            LanguageObject::StructDefinition(struct_def) => {
+                Ok(CompoundStatementObject::StructDefinition(struct_def))
+            }
+            LanguageObject::UnionDefinition(union_def) => {
+                Ok(CompoundStatementObject::UnionDefinition(union_def))
+            }
+            LanguageObject::EnumDefinition(enum_def) => {
+                Ok(CompoundStatementObject::EnumDefinition(enum_def))
+            }
+            LanguageObject::TypeDefinition(type_def) => {
+                Ok(CompoundStatementObject::TypeDefinition(type_def))
+            }
+            LanguageObject::PreprocDefine(define) => {
+                Ok(CompoundStatementObject::PreprocDefine(define))
+            }
+            LanguageObject::PreprocCall(call) => Ok(CompoundStatementObject::PreprocCall(call)),
 
             LanguageObject::AssignmentExpression(assignment_expression) => Ok(
                 CompoundStatementObject::AssignmentExpression(assignment_expression),
@@ -119,6 +321,30 @@ impl TryFrom<LanguageObject> for CompoundStatementObject {
             LanguageObject::BinaryExpression(binary_expression) => {
                 Ok(CompoundStatementObject::BinaryExpression(binary_expression))
             }
+            LanguageObject::UnaryExpression(unary_expression) => {
+                Ok(CompoundStatementObject::UnaryExpression(unary_expression))
+            }
+            LanguageObject::UpdateExpression(update_expression) => {
+                Ok(CompoundStatementObject::UpdateExpression(update_expression))
+            }
+            LanguageObject::CastExpression(cast_expression) => {
+                Ok(CompoundStatementObject::CastExpression(cast_expression))
+            }
+            LanguageObject::SizeofExpression(sizeof_expression) => {
+                Ok(CompoundStatementObject::SizeofExpression(sizeof_expression))
+            }
+            LanguageObject::ConditionalExpression(conditional_expression) => Ok(
+                CompoundStatementObject::ConditionalExpression(conditional_expression),
+            ),
+            LanguageObject::FieldExpression(field_expression) => {
+                Ok(CompoundStatementObject::FieldExpression(field_expression))
+            }
+            LanguageObject::SubscriptExpression(subscript_expression) => Ok(
+                CompoundStatementObject::SubscriptExpression(subscript_expression),
+            ),
+            LanguageObject::ParenthesizedExpression(parenthesized_expression) => Ok(
+                CompoundStatementObject::ParenthesizedExpression(parenthesized_expression),
+            ),
             LanguageObject::CallExpression(call_expression) => {
                 Ok(CompoundStatementObject::CallExpression(call_expression))
             }
@@ -131,16 +357,49 @@ impl TryFrom<LanguageObject> for CompoundStatementObject {
             LanguageObject::StringLiteral(string_literal) => {
                 Ok(CompoundStatementObject::StringLiteral(string_literal))
             }
+            LanguageObject::CharLiteral(char_literal) => {
+                Ok(CompoundStatementObject::CharLiteral(char_literal))
+            }
 
+            LanguageObject::BreakStatement(break_statement) => {
+                Ok(CompoundStatementObject::BreakStatement(break_statement))
+            }
+            LanguageObject::CaseStatement(case_statement) => {
+                Ok(CompoundStatementObject::CaseStatement(case_statement))
+            }
             LanguageObject::CompoundStatement(compound_statement) => Ok(
                 CompoundStatementObject::CompoundStatement(compound_statement),
             ),
+            LanguageObject::ContinueStatement(continue_statement) => Ok(
+                CompoundStatementObject::ContinueStatement(continue_statement),
+            ),
+            LanguageObject::GotoStatement(goto_statement) => {
+                Ok(CompoundStatementObject::GotoStatement(goto_statement))
+            }
+            LanguageObject::LabeledStatement(labeled_statement) => {
+                Ok(CompoundStatementObject::LabeledStatement(labeled_statement))
+            }
+            LanguageObject::DoStatement(do_statement) => {
+                Ok(CompoundStatementObject::DoStatement(do_statement))
+            }
+            LanguageObject::ForStatement(for_statement) => {
+                Ok(CompoundStatementObject::ForStatement(for_statement))
+            }
             LanguageObject::IfStatement(if_statement) => {
                 Ok(CompoundStatementObject::IfStatement(if_statement))
             }
             LanguageObject::ReturnStatement(return_statement) => {
                 Ok(CompoundStatementObject::ReturnStatement(return_statement))
             }
+            LanguageObject::SwitchStatement(switch_statement) => {
+                Ok(CompoundStatementObject::SwitchStatement(switch_statement))
+            }
+            LanguageObject::WhileStatement(while_statement) => {
+                Ok(CompoundStatementObject::WhileStatement(while_statement))
+            }
+            LanguageObject::PreprocIfStatement(preproc_if_statement) => Ok(
+                CompoundStatementObject::PreprocIfStatement(preproc_if_statement),
+            ),
             LanguageObject::Unknown(unknown) => Ok(CompoundStatementObject::Unknown(unknown)),
             LanguageObject::Comment(comment) => Ok(CompoundStatementObject::Comment(comment)),
             _ => Err(crate::language::c::language_object::ConversionError(
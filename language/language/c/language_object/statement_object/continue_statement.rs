@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
+
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct ContinueStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+}
+
+impl PartialEq for ContinueStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.comments == other.comments
+    }
+}
+
+impl Default for ContinueStatement {
+    fn default() -> Self {
+        ContinueStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+        }
+    }
+}
+
+impl ContinueStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_continue_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
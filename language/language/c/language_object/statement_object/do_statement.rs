@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::{
+        expression_object::ExpressionObject,
+        statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    },
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// do {
+///     a = a + 1;
+/// } while (a < 10);
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct DoStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub body: Box<CompoundStatementObject>,
+    pub condition: Box<ExpressionObject>,
+}
+
+impl DoStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_do_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for DoStatement {
+    fn default() -> Self {
+        DoStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            body: Box::new(CompoundStatementObject::default()),
+            condition: Box::new(ExpressionObject::default()),
+        }
+    }
+}
+
+impl PartialEq for DoStatement {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,62 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::{
+        expression_object::ExpressionObject,
+        statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    },
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// for (int i = 0; i < 10; i = i + 1) {
+///     a = a + i;
+/// }
+/// ```
+///
+/// The initializer is either a `Declaration` or an expression, so it is kept as a
+/// `CompoundStatementObject`. Every clause of the header is optional (`for (;;)`).
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct ForStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub initializer: Option<Box<CompoundStatementObject>>,
+    pub condition: Option<Box<ExpressionObject>>,
+    pub update: Option<Box<ExpressionObject>>,
+    pub body: Box<CompoundStatementObject>,
+}
+
+impl ForStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_for_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for ForStatement {
+    fn default() -> Self {
+        ForStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            initializer: None,
+            condition: None,
+            update: None,
+            body: Box::new(CompoundStatementObject::default()),
+        }
+    }
+}
+
+impl PartialEq for ForStatement {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.initializer, &other.initializer)
+            && crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
+            && crate::language::PartialEqAny::eq_dyn(&self.update, &other.update)
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,46 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::writers::{Cursor, writer_error::WriterError};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// goto retry;
+/// ```
+/// `label_id` is the id of the `LabeledStatement` it jumps to.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct GotoStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub label_id: Uuid,
+    pub label: String,
+}
+
+impl PartialEq for GotoStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.label_id == other.label_id
+            && self.label == other.label
+            && self.comments == other.comments
+    }
+}
+
+impl GotoStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_goto_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for GotoStatement {
+    fn default() -> Self {
+        GotoStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            label_id: Uuid::new_v4(),
+            label: String::new(),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use uuid::Uuid;
 
 pub mod else_clause;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::{
         LanguageObject,
@@ -13,6 +14,7 @@ use crate::language::c::{
     },
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub enum ElseStatement {
@@ -58,6 +60,8 @@ impl TryFrom<LanguageObject> for ElseStatement {
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct IfStatement {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub condition: Box<ExpressionObject>,
     pub body: Box<CompoundStatementObject>,
     pub else_statement: Option<ElseStatement>,
@@ -65,7 +69,9 @@ pub struct IfStatement {
 
 impl IfStatement {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_if_statement(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_if_statement(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -73,6 +79,8 @@ impl Default for IfStatement {
     fn default() -> Self {
         IfStatement {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             condition: Box::new(ExpressionObject::default()),
             body: Box::new(CompoundStatementObject::default()),
             else_statement: None,
@@ -85,6 +93,7 @@ impl PartialEq for IfStatement {
         crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
             && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
             && self.else_statement == other.else_statement
+            && self.comments == other.comments
     }
 }
 
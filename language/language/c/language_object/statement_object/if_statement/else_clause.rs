@@ -1,19 +1,25 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct ElseClause {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub body: Box<CompoundStatementObject>,
 }
 
 impl ElseClause {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_else_clause(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_else_clause(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
 
@@ -21,6 +27,7 @@ impl PartialEq for ElseClause {
     fn eq(&self, other: &Self) -> bool {
         // crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
         crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.comments == other.comments
     }
 }
 
@@ -28,6 +35,8 @@ impl Default for ElseClause {
     fn default() -> Self {
         ElseClause {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             body: Box::new(CompoundStatementObject::default()),
         }
     }
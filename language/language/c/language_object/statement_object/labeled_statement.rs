@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// retry:
+///     a = a + 1;
+/// ```
+/// `id` is also the label's symbol id, which every `goto retry` refers to.
+/// `statement` is None for a label followed by an empty statement (`retry: ;`).
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct LabeledStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub label: String,
+    pub statement: Option<Box<CompoundStatementObject>>,
+}
+
+impl LabeledStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_labeled_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for LabeledStatement {
+    fn default() -> Self {
+        LabeledStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            label: String::new(),
+            statement: None,
+        }
+    }
+}
+
+impl PartialEq for LabeledStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && crate::language::PartialEqAny::eq_dyn(&self.statement, &other.statement)
+            && self.comments == other.comments
+    }
+}
@@ -0,0 +1,58 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// Conditional compilation inside a function body, laid out like a file scope
+/// [`PreprocIf`](crate::language::c::language_object::declaration_object::preproc_if::PreprocIf):
+/// ```c
+/// #ifdef DEBUG
+///     log(value);
+/// #endif
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct PreprocIfStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub directive: String,
+    pub condition: String,
+    pub body: Vec<CompoundStatementObject>,
+    pub alternative: Option<Box<PreprocIfStatement>>,
+}
+
+impl PreprocIfStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_preproc_if_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl PartialEq for PreprocIfStatement {
+    fn eq(&self, other: &Self) -> bool {
+        self.directive == other.directive
+            && self.condition == other.condition
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.alternative == other.alternative
+            && self.comments == other.comments
+    }
+}
+
+impl Default for PreprocIfStatement {
+    fn default() -> Self {
+        PreprocIfStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            directive: "#if".to_string(),
+            condition: String::new(),
+            body: vec![],
+            alternative: None,
+        }
+    }
+}
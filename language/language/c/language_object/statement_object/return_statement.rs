@@ -1,19 +1,24 @@
 use uuid::Uuid;
 
+use crate::language::c::language_object::special_object::comment::Comments;
 use crate::language::c::{
     language_object::expression_object::ExpressionObject,
     writers::{Cursor, writer_error::WriterError},
 };
+use crate::language::diagnostic::Span;
 
 #[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
 pub struct ReturnStatement {
     pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
     pub value: Option<ExpressionObject>,
 }
 
 impl PartialEq for ReturnStatement {
     fn eq(&self, other: &Self) -> bool {
         crate::language::PartialEqAny::eq_dyn(&self.value, &other.value)
+            && self.comments == other.comments
     }
 }
 
@@ -21,6 +26,8 @@ impl Default for ReturnStatement {
     fn default() -> Self {
         ReturnStatement {
             id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
             value: None,
         }
     }
@@ -28,6 +35,8 @@ impl Default for ReturnStatement {
 
 impl ReturnStatement {
     pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
-        w.write_return_statement(self)
+        w.write_leading_comments(&self.comments)?;
+        w.write_return_statement(self)?;
+        w.write_trailing_comments(&self.comments)
     }
 }
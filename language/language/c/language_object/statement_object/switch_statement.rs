@@ -0,0 +1,60 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::{
+        expression_object::ExpressionObject,
+        statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    },
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// switch (state) {
+///     case 0:
+///         state = 1;
+///         break;
+///     default:
+///         state = 0;
+/// }
+/// ```
+///
+/// The `case` and `default` labels live as [`CaseStatement`](super::case_statement::CaseStatement)
+/// objects inside the body.
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct SwitchStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub condition: Box<ExpressionObject>,
+    pub body: Box<CompoundStatementObject>,
+}
+
+impl SwitchStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_switch_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for SwitchStatement {
+    fn default() -> Self {
+        SwitchStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(ExpressionObject::default()),
+            body: Box::new(CompoundStatementObject::default()),
+        }
+    }
+}
+
+impl PartialEq for SwitchStatement {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.comments == other.comments
+    }
+}
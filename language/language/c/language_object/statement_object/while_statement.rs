@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use crate::language::c::language_object::special_object::comment::Comments;
+use crate::language::c::{
+    language_object::{
+        expression_object::ExpressionObject,
+        statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
+    },
+    writers::{Cursor, writer_error::WriterError},
+};
+use crate::language::diagnostic::Span;
+
+/// ```c
+/// while (a < 10) {
+///     a = a + 1;
+/// }
+/// ```
+#[derive(Debug, Clone, lenga_field_inspect_derive::FieldInspect)]
+pub struct WhileStatement {
+    pub id: Uuid,
+    pub span: Option<Span>,
+    pub comments: Comments,
+    pub condition: Box<ExpressionObject>,
+    pub body: Box<CompoundStatementObject>,
+}
+
+impl WhileStatement {
+    pub fn write(&self, w: &mut dyn Cursor) -> Result<(), WriterError> {
+        w.write_leading_comments(&self.comments)?;
+        w.write_while_statement(self)?;
+        w.write_trailing_comments(&self.comments)
+    }
+}
+
+impl Default for WhileStatement {
+    fn default() -> Self {
+        WhileStatement {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(ExpressionObject::default()),
+            body: Box::new(CompoundStatementObject::default()),
+        }
+    }
+}
+
+impl PartialEq for WhileStatement {
+    fn eq(&self, other: &Self) -> bool {
+        crate::language::PartialEqAny::eq_dyn(&self.condition, &other.condition)
+            && crate::language::PartialEqAny::eq_dyn(&self.body, &other.body)
+            && self.comments == other.comments
+    }
+}
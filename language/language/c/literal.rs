@@ -0,0 +1,169 @@
+use std::fmt;
+
+/// Encoding prefix of a character or string literal, like `L` in `L"wide"`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EncodingPrefix {
+    #[default]
+    None,
+    /// `L`
+    Wide,
+    /// `u8`
+    Utf8,
+    /// `u`
+    Utf16,
+    /// `U`
+    Utf32,
+}
+
+impl EncodingPrefix {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncodingPrefix::None => "",
+            EncodingPrefix::Wide => "L",
+            EncodingPrefix::Utf8 => "u8",
+            EncodingPrefix::Utf16 => "u",
+            EncodingPrefix::Utf32 => "U",
+        }
+    }
+
+    /// Prefix spelled as `prefix`, or None if it is not one
+    pub fn parse(prefix: &str) -> Option<Self> {
+        match prefix {
+            "" => Some(EncodingPrefix::None),
+            "L" => Some(EncodingPrefix::Wide),
+            "u8" => Some(EncodingPrefix::Utf8),
+            "u" => Some(EncodingPrefix::Utf16),
+            "U" => Some(EncodingPrefix::Utf32),
+            _ => None,
+        }
+    }
+
+    /// Splits a literal spelling like `L"a\n"` into its prefix and the text between the `quote`s
+    pub fn split_spelling(spelling: &str, quote: char) -> Option<(Self, &str)> {
+        let (prefix, rest) = spelling.split_at(spelling.find(quote)?);
+        let text = rest.strip_prefix(quote)?.strip_suffix(quote)?;
+        Some((EncodingPrefix::parse(prefix)?, text))
+    }
+}
+
+impl fmt::Display for EncodingPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Decodes the escape sequences of the text inside a character or string literal.
+/// Line continuations are dropped and unknown escapes keep the escaped character.
+/// Bytes above 0x7F, which are not characters on their own, are kept as three-digit octal
+/// escapes, so `"\xff"` is not read as `"ÿ"`.
+pub fn unescape(text: &str) -> String {
+    let mut value = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        let Some(escaped) = chars.next() else {
+            value.push('\\');
+            break;
+        };
+        match escaped {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            'a' => value.push('\u{7}'),
+            'b' => value.push('\u{8}'),
+            'f' => value.push('\u{c}'),
+            'v' => value.push('\u{b}'),
+            'e' => value.push('\u{1b}'),
+            '\n' => {}
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            '0'..='7' => {
+                let mut code = escaped.to_digit(8).unwrap();
+                for _ in 0..2 {
+                    match chars.next_if(|c| c.is_digit(8)) {
+                        Some(digit) => code = code * 8 + digit.to_digit(8).unwrap(),
+                        None => break,
+                    }
+                }
+                push_code(&mut value, code);
+            }
+            'x' | 'u' | 'U' => {
+                let max_digits = match escaped {
+                    'u' => 4,
+                    'U' => 8,
+                    _ => usize::MAX,
+                };
+                let mut code: u32 = 0;
+                let mut digits = 0;
+                while digits < max_digits
+                    && let Some(digit) = chars.next_if(|c| c.is_ascii_hexdigit())
+                {
+                    code = code.saturating_mul(16) + digit.to_digit(16).unwrap();
+                    digits += 1;
+                }
+                if digits == 0 {
+                    value.push(escaped);
+                } else if escaped == 'x' {
+                    push_code(&mut value, code);
+                } else {
+                    value.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+            }
+            other => value.push(other),
+        }
+    }
+    value
+}
+
+/// Pushes the character of a numeric escape, or its octal escape if it is a byte above 0x7F
+fn push_code(value: &mut String, code: u32) {
+    if (0x80..=0xFF).contains(&code) {
+        value.push_str(&format!("\\{code:03o}"));
+    } else {
+        value.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+    }
+}
+
+/// Whether `text` starts with the octal escape of a byte above 0x7F, like `\377`
+fn starts_with_byte_escape(text: &str) -> bool {
+    let Some(digits) = text.strip_prefix('\\').and_then(|rest| rest.get(..3)) else {
+        return false;
+    };
+    digits.bytes().all(|b| (b'0'..=b'7').contains(&b))
+        && (0o200..=0o377).contains(&u32::from_str_radix(digits, 8).unwrap())
+}
+
+/// Escapes `value` so it can be written between `quote`s in a character or string literal.
+/// The octal escapes `unescape` keeps for bytes above 0x7F are written as they are.
+pub fn escape(value: &str, quote: char) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if starts_with_byte_escape(&value[i..]) => {
+                text.push_str(&value[i..i + 4]);
+                chars.nth(2);
+            }
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\t' => text.push_str("\\t"),
+            '\r' => text.push_str("\\r"),
+            '\u{7}' => text.push_str("\\a"),
+            '\u{8}' => text.push_str("\\b"),
+            '\u{c}' => text.push_str("\\f"),
+            '\u{b}' => text.push_str("\\v"),
+            c if c == quote => {
+                text.push('\\');
+                text.push(c);
+            }
+            // Always three digits, so a following digit is not read as part of the escape
+            c if c.is_ascii_control() => text.push_str(&format!("\\{:03o}", c as u32)),
+            c => text.push(c),
+        }
+    }
+    text
+}
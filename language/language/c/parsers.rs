@@ -1,3 +1,5 @@
+pub mod comments;
 pub mod context;
+pub mod ids;
 pub mod nodes;
 pub mod text;
@@ -0,0 +1,274 @@
+use tree_sitter::Node;
+
+use crate::language::{
+    c::{
+        TreeSitterNodeExt,
+        language_object::special_object::comment::{Comment, Comments},
+        parsers::ids::ObjectIds,
+    },
+    diagnostic::Span,
+};
+
+/// Nodes holding a list of objects, where a comment that documents nothing is kept as a
+/// `Comment` object of its own
+const LIST_KINDS: [&str; 8] = [
+    "translation_unit",
+    "compound_statement",
+    "case_statement",
+    "preproc_if",
+    "preproc_ifdef",
+    "preproc_elif",
+    "preproc_elifdef",
+    "preproc_else",
+];
+
+/// Tokens that close the code before them, so a comment after them still trails that code
+const CLOSING_TOKENS: [&str; 5] = [";", ",", ")", "]", "}"];
+
+enum Placement {
+    /// Before the code starting at this byte
+    Leading(usize),
+    /// After the code ending at one of these bytes: the last token before the comment and the
+    /// code before the closing tokens that end with it, like `x` and `;` in `return x;`
+    Trailing(Vec<usize>),
+    /// Taken by the innermost object around it, for comments between tokens of an object
+    Inside,
+    /// Kept as a `Comment` object in the list it is in
+    Standalone,
+}
+
+enum Side {
+    Leading,
+    Trailing,
+}
+
+struct PendingComment {
+    comment: Comment,
+    start: usize,
+    end: usize,
+    placement: Placement,
+}
+
+/// Comments of a file waiting for the object they document to be parsed. An object takes
+/// the comments around its node when it is parsed, before the objects inside it, so a
+/// comment goes to the outermost object it is next to.
+#[derive(Default)]
+pub struct CommentAttacher {
+    pending: Vec<PendingComment>,
+}
+
+impl CommentAttacher {
+    /// Finds every comment under `root` and where it goes, giving them ids from `ids`
+    pub fn new(root: Node<'_>, source_code: &str, ids: &ObjectIds) -> Self {
+        let mut leaves = Vec::new();
+        collect_leaves(root, &mut leaves);
+        let pending = leaves
+            .iter()
+            .enumerate()
+            .filter(|(_, leaf)| leaf.kind() == "comment")
+            .map(|(index, comment)| PendingComment {
+                comment: Comment {
+                    id: ids.object_id(*comment, "comment", source_code),
+                    span: Some(Span::from_node(*comment)),
+                    content: comment.content(source_code),
+                },
+                start: comment.start_byte(),
+                end: comment.end_byte(),
+                placement: placement(&leaves, index, source_code),
+            })
+            .collect();
+        CommentAttacher { pending }
+    }
+
+    /// Whether `node` is a comment that documents no object, parsed as an object of its own
+    pub fn is_standalone(&self, node: Node<'_>) -> bool {
+        self.pending.iter().any(|pending| {
+            pending.start == node.start_byte() && matches!(pending.placement, Placement::Standalone)
+        })
+    }
+
+    /// Takes the comments right before and right after the code of `node`
+    pub fn take_around(&mut self, node: Node<'_>) -> Comments {
+        let (start, end) = (node.start_byte(), node.end_byte());
+        self.take(|pending| match &pending.placement {
+            Placement::Leading(code_start) if *code_start == start => Some(Side::Leading),
+            Placement::Trailing(code_ends) if code_ends.contains(&end) => Some(Side::Trailing),
+            _ => None,
+        })
+    }
+
+    /// Takes the comments inside `node` that no object in it took, on the side they were
+    /// written on
+    pub fn take_inside(&mut self, node: Node<'_>) -> Comments {
+        let (start, end) = (node.start_byte(), node.end_byte());
+        self.take(|pending| {
+            if pending.start < start || pending.end > end {
+                return None;
+            }
+            match pending.placement {
+                Placement::Standalone => None,
+                Placement::Trailing(_) => Some(Side::Trailing),
+                Placement::Leading(_) | Placement::Inside => Some(Side::Leading),
+            }
+        })
+    }
+
+    /// Takes the comments that no object took, in the order they were written
+    pub fn take_remaining(&mut self) -> Vec<Comment> {
+        let comments = self.take(|pending| match pending.placement {
+            Placement::Standalone => None,
+            _ => Some(Side::Leading),
+        });
+        comments.leading
+    }
+
+    fn take(&mut self, mut side_of: impl FnMut(&PendingComment) -> Option<Side>) -> Comments {
+        let mut comments = Comments::default();
+        let mut kept = Vec::new();
+        for pending in std::mem::take(&mut self.pending) {
+            match side_of(&pending) {
+                Some(Side::Leading) => comments.leading.push(pending.comment),
+                Some(Side::Trailing) => comments.trailing.push(pending.comment),
+                None => kept.push(pending),
+            }
+        }
+        self.pending = kept;
+        comments
+    }
+}
+
+/// Tokens and comments under `node`, in the order they were written
+fn collect_leaves<'t>(node: Node<'t>, leaves: &mut Vec<Node<'t>>) {
+    if node.child_count() == 0 {
+        // Tokens the grammar inserted to recover from an error were not written
+        if node.start_byte() < node.end_byte() {
+            leaves.push(node);
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_leaves(child, leaves);
+    }
+}
+
+/// A comment on the line of the code before it trails that code, unless more code follows
+/// it on the same line. Otherwise it leads the code right after it, when no blank line
+/// separates them.
+fn placement(leaves: &[Node<'_>], index: usize, source_code: &str) -> Placement {
+    let comment = leaves[index];
+    let is_code = |leaf: &&Node<'_>| leaf.kind() != "comment";
+    let previous = leaves[..index].iter().rev().find(is_code);
+    let next_index = leaves[index + 1..]
+        .iter()
+        .position(|leaf| is_code(&leaf))
+        .map(|position| index + 1 + position);
+    let next = next_index.map(|next_index| leaves[next_index]);
+
+    let previous_on_line = previous
+        .filter(|previous| !source_code[previous.end_byte()..comment.start_byte()].contains('\n'));
+    let next_on_line =
+        next.filter(|next| !source_code[comment.end_byte()..next.start_byte()].contains('\n'));
+
+    if let Some(next) = next_on_line
+        && starts_code(&next)
+        && !previous_on_line.is_some_and(is_word)
+    {
+        return Placement::Leading(next.start_byte());
+    }
+    if let Some(previous) = previous_on_line
+        && let Some(next) = next
+        && is_header_end(previous, comment)
+        && starts_code(&next)
+    {
+        return Placement::Leading(next.start_byte());
+    }
+    if let Some(previous) = previous_on_line
+        && ends_code(previous)
+    {
+        // A comment after the condition of a conditional block is the first thing in it
+        if is_directive_token(previous, comment) {
+            return Placement::Standalone;
+        }
+        return Placement::Trailing(code_ends(leaves, index));
+    }
+    if let (Some(next), Some(next_index)) = (next, next_index)
+        && starts_code(&next)
+        && leaves[index..=next_index].windows(2).all(|pair| {
+            source_code[pair[0].end_byte()..pair[1].start_byte()]
+                .matches('\n')
+                .count()
+                <= 1
+        })
+    {
+        return Placement::Leading(next.start_byte());
+    }
+    match comment.parent() {
+        Some(parent) if LIST_KINDS.contains(&parent.kind()) => Placement::Standalone,
+        _ => Placement::Inside,
+    }
+}
+
+/// End bytes of the last token before the comment at `index` and, when it closes something,
+/// of the tokens it closes back to the last one that is not a closing token
+fn code_ends(leaves: &[Node<'_>], index: usize) -> Vec<usize> {
+    let mut ends = Vec::new();
+    for leaf in leaves[..index]
+        .iter()
+        .rev()
+        .filter(|leaf| leaf.kind() != "comment")
+    {
+        ends.push(leaf.end_byte());
+        if !CLOSING_TOKENS.contains(&leaf.kind()) {
+            break;
+        }
+    }
+    ends
+}
+
+/// Identifiers, literals, keywords and directives, as opposed to punctuation and operators
+fn is_word(leaf: &Node<'_>) -> bool {
+    leaf.is_named()
+        || leaf.kind().starts_with('#')
+        || leaf
+            .kind()
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn ends_code(leaf: &Node<'_>) -> bool {
+    is_word(leaf)
+        || CLOSING_TOKENS.contains(&leaf.kind())
+        || matches!(leaf.kind(), "\"" | "'" | "++" | "--")
+}
+
+fn starts_code(leaf: &Node<'_>) -> bool {
+    !CLOSING_TOKENS.contains(&leaf.kind()) && leaf.kind() != ":"
+}
+
+/// Whether `token` ends the header of the control statement or function definition `comment`
+/// is in, like the `)` of `if (a)`, so the comment goes with the body after it
+fn is_header_end(token: &Node<'_>, comment: Node<'_>) -> bool {
+    matches!(token.kind(), ")" | "else" | "do")
+        && comment.parent().is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                "if_statement"
+                    | "else_clause"
+                    | "while_statement"
+                    | "for_statement"
+                    | "switch_statement"
+                    | "do_statement"
+                    | "function_definition"
+            )
+        })
+}
+
+/// Whether `token` is part of the directive line of the conditional block `comment` is in
+fn is_directive_token(token: &Node<'_>, comment: Node<'_>) -> bool {
+    comment.parent().is_some_and(|parent| {
+        parent.kind().starts_with("preproc_")
+            && LIST_KINDS.contains(&parent.kind())
+            && token.parent() == Some(parent)
+    })
+}
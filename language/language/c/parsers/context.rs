@@ -1,12 +1,35 @@
+use std::{cell::RefCell, collections::HashMap};
+
 use bimap::{BiHashMap, Overwritten};
 use uuid::Uuid;
 
+use crate::{
+    language::c::{
+        c_type::CType,
+        language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration,
+    },
+    node::UuidFromSeed,
+};
+
 #[derive(Debug)]
 pub struct SymbolAlreadyExists {}
 
 pub struct Context<'a> {
     /// A map of symbol names to their corresponding IDs (<id, name>)
     symbols: BiHashMap<Uuid, String>,
+    /// Struct, union and enum names live in their own namespace (<id, name>)
+    tags: BiHashMap<Uuid, String>,
+    /// Ids of the struct, union and enum names used before their definition, which the
+    /// definition takes when it is found (<name, id>). Only the file context holds them
+    forward_tags: RefCell<HashMap<String, Uuid>>,
+    /// Declared types of variables, parameters and typedef names (<id, type>)
+    types: HashMap<Uuid, CType>,
+    /// Members of each struct and union (<tag id, members>)
+    members: HashMap<Uuid, Vec<FieldDeclaration>>,
+    /// Labels have function scope and their own namespace, apart from variables (<id, name>)
+    labels: BiHashMap<Uuid, String>,
+    /// Names declared by `typedef`, whose aliased types are kept in `types` (<id, name>)
+    type_names: BiHashMap<Uuid, String>,
     parent: Option<&'a Context<'a>>,
 }
 
@@ -20,6 +43,12 @@ impl<'a> Context<'a> {
     pub fn new() -> Self {
         Self {
             symbols: BiHashMap::new(),
+            tags: BiHashMap::new(),
+            forward_tags: RefCell::default(),
+            types: HashMap::new(),
+            members: HashMap::new(),
+            labels: BiHashMap::new(),
+            type_names: BiHashMap::new(),
             parent: None,
         }
     }
@@ -27,6 +56,12 @@ impl<'a> Context<'a> {
     pub fn branch(&'a self) -> Context<'a> {
         Context {
             symbols: BiHashMap::new(),
+            tags: BiHashMap::new(),
+            forward_tags: RefCell::default(),
+            types: HashMap::new(),
+            members: HashMap::new(),
+            labels: BiHashMap::new(),
+            type_names: BiHashMap::new(),
             parent: Some(self),
         }
     }
@@ -40,6 +75,13 @@ impl<'a> Context<'a> {
                 .and_then(|parent| parent.get_symbol_id(identifier, is_fn)))
     }
 
+    /// Id of the symbol `identifier` declared in this scope, not in the ones around it
+    pub fn get_local_symbol_id(&self, identifier: &String, is_fn: bool) -> Option<Uuid> {
+        self.symbols
+            .get_by_right(&variable_or_function_identifier(identifier, is_fn))
+            .cloned()
+    }
+
     pub fn get_symbol_identifier(&self, id: &Uuid) -> Option<String> {
         self.symbols
             .get_by_left(id)
@@ -55,6 +97,17 @@ impl<'a> Context<'a> {
             .unwrap_or_else(|| self.insert_symbol(identifier, is_fn).unwrap())
     }
 
+    /// Id of the symbol `identifier`, inserted with `id` when it is not known yet
+    pub fn get_or_insert_symbol_with_id(
+        &mut self,
+        identifier: &String,
+        is_fn: bool,
+        id: Uuid,
+    ) -> Uuid {
+        self.get_symbol_id(identifier, is_fn)
+            .unwrap_or_else(|| self.insert_symbol_with_id(identifier, id, is_fn).unwrap())
+    }
+
     pub fn insert_symbol(
         &mut self,
         identifier: &String,
@@ -81,11 +134,158 @@ impl<'a> Context<'a> {
             .symbols
             .insert(id, variable_or_function_identifier(identifier, is_fn));
         match res {
+            // Declared again, like `extern int x;` before `int x = 1;`
+            Overwritten::Neither | Overwritten::Pair(_, _) => Ok(id),
+            _ => Err(SymbolAlreadyExists {}),
+        }
+    }
+
+    pub fn get_tag_id(&self, identifier: &String) -> Option<Uuid> {
+        self.tags
+            .get_by_right(identifier)
+            .cloned()
+            .or(self.parent.and_then(|parent| parent.get_tag_id(identifier)))
+    }
+
+    pub fn get_tag_identifier(&self, id: &Uuid) -> Option<String> {
+        self.tags
+            .get_by_left(id)
+            .cloned()
+            .or(self.parent.and_then(|parent| parent.get_tag_identifier(id)))
+    }
+
+    /// Id of the struct, union or enum `identifier` used before it is defined, the same for
+    /// every use, like a type name without a typedef is kept by name
+    pub fn forward_tag_id(&self, identifier: &str) -> Uuid {
+        match self.parent {
+            Some(parent) => parent.forward_tag_id(identifier),
+            None => *self
+                .forward_tags
+                .borrow_mut()
+                .entry(identifier.to_string())
+                .or_insert_with(|| Uuid::from_seed(&format!("tag/{identifier}"))),
+        }
+    }
+
+    /// Takes the id the uses of `identifier` before its definition were given, so the
+    /// definition binds them
+    pub fn take_forward_tag_id(&self, identifier: &str) -> Option<Uuid> {
+        match self.parent {
+            Some(parent) => parent.take_forward_tag_id(identifier),
+            None => self.forward_tags.borrow_mut().remove(identifier),
+        }
+    }
+
+    pub fn insert_tag(&mut self, identifier: &String) -> Result<Uuid, SymbolAlreadyExists> {
+        self.insert_tag_with_id(identifier, Uuid::new_v4())
+    }
+
+    pub fn insert_tag_with_id(
+        &mut self,
+        identifier: &String,
+        id: Uuid,
+    ) -> Result<Uuid, SymbolAlreadyExists> {
+        match self.tags.insert(id, identifier.to_string()) {
             Overwritten::Neither => Ok(id),
             _ => Err(SymbolAlreadyExists {}),
         }
     }
 
+    pub fn get_label_id(&self, identifier: &String) -> Option<Uuid> {
+        self.labels.get_by_right(identifier).cloned().or(self
+            .parent
+            .and_then(|parent| parent.get_label_id(identifier)))
+    }
+
+    pub fn get_label_identifier(&self, id: &Uuid) -> Option<String> {
+        self.labels.get_by_left(id).cloned().or(self
+            .parent
+            .and_then(|parent| parent.get_label_identifier(id)))
+    }
+
+    pub fn insert_label(&mut self, identifier: &String) -> Result<Uuid, SymbolAlreadyExists> {
+        self.insert_label_with_id(identifier, Uuid::new_v4())
+    }
+
+    pub fn insert_label_with_id(
+        &mut self,
+        identifier: &String,
+        id: Uuid,
+    ) -> Result<Uuid, SymbolAlreadyExists> {
+        match self.labels.insert(id, identifier.to_string()) {
+            Overwritten::Neither => Ok(id),
+            _ => Err(SymbolAlreadyExists {}),
+        }
+    }
+
+    pub fn get_type_name_id(&self, identifier: &String) -> Option<Uuid> {
+        self.type_names.get_by_right(identifier).cloned().or(self
+            .parent
+            .and_then(|parent| parent.get_type_name_id(identifier)))
+    }
+
+    pub fn get_type_name_identifier(&self, id: &Uuid) -> Option<String> {
+        self.type_names.get_by_left(id).cloned().or(self
+            .parent
+            .and_then(|parent| parent.get_type_name_identifier(id)))
+    }
+
+    pub fn insert_type_name_with_id(
+        &mut self,
+        identifier: &String,
+        id: Uuid,
+    ) -> Result<Uuid, SymbolAlreadyExists> {
+        match self.type_names.insert(id, identifier.to_string()) {
+            Overwritten::Neither => Ok(id),
+            _ => Err(SymbolAlreadyExists {}),
+        }
+    }
+
+    /// Follows typedef names to the type they stand for, so a `point_t *` is seen as the
+    /// `struct point *` it aliases. Other types are returned as they are.
+    pub fn resolve_type(&self, c_type: &CType) -> CType {
+        match c_type.unqualified() {
+            CType::Named { declaration_id, .. } => match self.get_symbol_type(declaration_id) {
+                Some(aliased) => self.resolve_type(aliased),
+                None => c_type.clone(),
+            },
+            _ => c_type.clone(),
+        }
+    }
+
+    pub fn insert_symbol_type(&mut self, id: Uuid, symbol_type: CType) {
+        self.types.insert(id, symbol_type);
+    }
+
+    pub fn get_symbol_type(&self, id: &Uuid) -> Option<&CType> {
+        self.types
+            .get(id)
+            .or_else(|| self.parent.and_then(|parent| parent.get_symbol_type(id)))
+    }
+
+    pub fn insert_members(&mut self, tag_id: Uuid, members: Vec<FieldDeclaration>) {
+        self.members.insert(tag_id, members);
+    }
+
+    pub fn get_member(&self, tag_id: &Uuid, identifier: &str) -> Option<&FieldDeclaration> {
+        match self.members.get(tag_id) {
+            Some(members) => members
+                .iter()
+                .find(|member| member.identifier == identifier),
+            None => self
+                .parent
+                .and_then(|parent| parent.get_member(tag_id, identifier)),
+        }
+    }
+
+    pub fn get_member_by_id(&self, id: &Uuid) -> Option<&FieldDeclaration> {
+        self.members
+            .values()
+            .flatten()
+            .find(|member| member.id == *id)
+            .or_else(|| self.parent.and_then(|parent| parent.get_member_by_id(id)))
+    }
+
     pub fn overwrite_symbol(&mut self, identifier: &String, is_fn: bool) -> Uuid {
         // TODO is this even necesary?
         let id = Uuid::new_v4();
@@ -93,6 +293,13 @@ impl<'a> Context<'a> {
             .insert(id, variable_or_function_identifier(identifier, is_fn));
         id
     }
+
+    /// Binds the name to the given id even if it is already taken, as redefining a macro does
+    pub fn overwrite_symbol_with_id(&mut self, identifier: &String, id: Uuid, is_fn: bool) -> Uuid {
+        self.symbols
+            .insert(id, variable_or_function_identifier(identifier, is_fn));
+        id
+    }
 }
 
 fn variable_or_function_identifier(identifier: &String, is_fn: bool) -> String {
@@ -0,0 +1,130 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use sha2::{Digest, Sha256};
+use tree_sitter::Node;
+use uuid::Uuid;
+
+use crate::node::UuidFromSeed;
+
+/// How the objects parsed from text get their ids
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IdMode {
+    /// A new random id for every object on every parse
+    #[default]
+    Random,
+    /// Ids derived from the seed of the file, where each object is in the syntax tree and its
+    /// text, so parsing the same text twice gives the same ids
+    Deterministic { seed: u64 },
+}
+
+impl IdMode {
+    /// Deterministic ids for the file `seed` names, like its path, so the same code in two
+    /// files does not get the same ids
+    pub fn deterministic(seed: &str) -> Self {
+        IdMode::Deterministic {
+            seed: content_hash(seed),
+        }
+    }
+
+    /// Id of the file, which stays the same however its text changes
+    pub fn file_id(self) -> Uuid {
+        match self {
+            IdMode::Random => Uuid::new_v4(),
+            IdMode::Deterministic { seed } => Uuid::from_seed(&format!("{seed:016x}#source_file")),
+        }
+    }
+}
+
+/// Ids of the objects of one parse, given as its `IdMode` says
+#[derive(Debug, Default)]
+pub struct ObjectIds {
+    mode: IdMode,
+    /// Structural paths of the nodes of the parsed tree by node id, listed for all the
+    /// children of a node at once so a file does not walk its siblings again for each object
+    paths: RefCell<HashMap<usize, Rc<str>>>,
+}
+
+impl ObjectIds {
+    pub fn new(mode: IdMode) -> Self {
+        ObjectIds {
+            mode,
+            paths: RefCell::default(),
+        }
+    }
+
+    /// Id of an object parsed from `node`. `role` tells apart the objects parsed from the same
+    /// node, like a declaration list and its only declaration
+    pub fn object_id(&self, node: Node<'_>, role: &str, source_code: &str) -> Uuid {
+        match self.mode {
+            IdMode::Random => Uuid::new_v4(),
+            IdMode::Deterministic { seed } => Uuid::from_seed(&format!(
+                "{seed:016x}#{}#{role}#{:016x}",
+                self.structural_path(node, source_code),
+                content_hash(&source_code[node.byte_range()])
+            )),
+        }
+    }
+
+    /// Kinds of `node` and of the nodes above it, like
+    /// `translation_unit/function_definition(main)[0]/compound_statement[0]`. Nodes that
+    /// declare a name are placed by it, so adding or removing a function does not move the
+    /// others. The rest are placed by their index among the siblings of the same kind
+    fn structural_path(&self, node: Node<'_>, source_code: &str) -> Rc<str> {
+        let mut paths = self.paths.borrow_mut();
+        // Parents whose children have no path yet, from the node up
+        let mut parents = Vec::new();
+        let mut current = node;
+        while !paths.contains_key(&current.id()) {
+            let Some(parent) = current.parent() else {
+                paths.insert(current.id(), current.kind().into());
+                break;
+            };
+            parents.push(parent);
+            current = parent;
+        }
+        for parent in parents.into_iter().rev() {
+            let parent_path = Rc::clone(&paths[&parent.id()]);
+            let mut counts = HashMap::new();
+            let mut cursor = parent.walk();
+            for child in parent.children(&mut cursor) {
+                let name = declared_name(child, source_code);
+                let index = counts.entry((child.kind(), name)).or_insert(0);
+                let path = match name {
+                    Some(name) => format!("{parent_path}/{}({name})[{index}]", child.kind()),
+                    None => format!("{parent_path}/{}[{index}]", child.kind()),
+                };
+                *index += 1;
+                paths.insert(child.id(), path.into());
+            }
+        }
+        Rc::clone(&paths[&node.id()])
+    }
+}
+
+/// Name declared by a function, declaration, typedef, struct, field, enumerator or macro
+fn declared_name<'a>(node: Node<'_>, source_code: &'a str) -> Option<&'a str> {
+    let mut declarator = match node.child_by_field_name("name") {
+        Some(name) => name,
+        None => node.child_by_field_name("declarator")?,
+    };
+    // Declarators wrap the name, as in `*values[4]`, `main(void)` or `(*get(int k))(void)`
+    while let Some(inner) = declarator.child_by_field_name("declarator").or_else(|| {
+        (declarator.kind() == "parenthesized_declarator")
+            .then(|| declarator.named_child(0))
+            .flatten()
+    }) {
+        declarator = inner;
+    }
+    matches!(
+        declarator.kind(),
+        "identifier" | "type_identifier" | "field_identifier"
+    )
+    .then(|| &source_code[declarator.byte_range()])
+}
+
+fn content_hash(content: &str) -> u64 {
+    let digest = Sha256::digest(content.as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
@@ -1,37 +1,85 @@
+use std::{cell::RefCell, rc::Rc};
+
 use uuid::Uuid;
 
 use crate::{
     language::c::{
-        c_type::CType,
+        C,
+        c_type::{ArrayType, CType, FnParameter, FnType, Qualifiers, TypeReference},
         language_object::{
             LanguageObject as CLanguageObject,
             declaration_object::{
                 DeclarationObject,
                 declaration::Declaration,
+                declaration_list::DeclarationList,
+                enum_definition::{EnumDefinition, enumerator::Enumerator},
                 function_declaration::{
                     FunctionDeclaration, function_parameter::FunctionParameter,
                 },
                 function_definition::FunctionDefinition,
+                preproc_call::PreprocCall,
+                preproc_define::PreprocDefine,
+                preproc_if::PreprocIf,
                 preproc_include::PreprocInclude,
+                struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+                type_definition::TypeDefinition,
+                union_definition::UnionDefinition,
             },
             expression_object::{
-                ExpressionObject, assignment_expression::AssignmentExpression,
-                binary_expression::BinaryExpression, call_expression::CallExpression,
-                number_literal::NumberLiteral, reference::Reference, string_literal::StringLiteral,
+                ExpressionObject,
+                assignment_expression::AssignmentExpression,
+                binary_expression::BinaryExpression,
+                call_expression::CallExpression,
+                cast_expression::CastExpression,
+                char_literal::CharLiteral,
+                conditional_expression::ConditionalExpression,
+                field_expression::FieldExpression,
+                initializer_list::InitializerList,
+                initializer_pair::{Designator, InitializerPair},
+                number_literal::{NumberLiteral, NumberValue, Radix},
+                parenthesized_expression::ParenthesizedExpression,
+                reference::Reference,
+                sizeof_expression::SizeofExpression,
+                string_literal::StringLiteral,
+                subscript_expression::SubscriptExpression,
+                unary_expression::UnaryExpression,
+                update_expression::UpdateExpression,
+            },
+            special_object::{
+                comment::{Comment, Comments},
+                source_file::SourceFile,
+                trivia::{Layout, LineBreaks, Trivia},
+                unknown::Unknown,
             },
-            special_object::{comment::Comment, source_file::SourceFile, unknown::Unknown},
             statement_object::{
+                break_statement::BreakStatement,
+                case_statement::CaseStatement,
                 compound_statement::{
                     CompoundStatement, compound_statement_object::CompoundStatementObject,
                 },
+                continue_statement::ContinueStatement,
+                do_statement::DoStatement,
+                for_statement::ForStatement,
+                goto_statement::GotoStatement,
                 if_statement::{ElseStatement, IfStatement, else_clause::ElseClause},
+                labeled_statement::LabeledStatement,
+                preproc_if_statement::PreprocIfStatement,
                 return_statement::ReturnStatement,
+                switch_statement::SwitchStatement,
+                while_statement::WhileStatement,
             },
         },
+        literal::EncodingPrefix,
         parsers::context::{Context, SymbolAlreadyExists},
+        specifiers::Specifiers,
         writers::node_writer::node_type::NodeType,
     },
-    node::Node,
+    language::diagnostic::{Position, Span},
+    node::{
+        Node, UuidFromSeed,
+        header::{Header, HeaderError},
+        migration,
+    },
 };
 
 #[derive(Debug)]
@@ -40,6 +88,12 @@ pub enum NodeParserError {
     MissingSymbol(String),
     EmptyVec,
     WrongType(String),
+    /// A node type code this build does not know, like one from a newer version
+    UnknownNodeType(u64),
+    /// The header does not match the files this build reads
+    Header(HeaderError),
+    /// The bytes after the header are not a node tree
+    Corrupted(String),
 }
 
 impl From<SymbolAlreadyExists> for NodeParserError {
@@ -55,11 +109,16 @@ impl From<NodeParserError> for String {
             NodeParserError::MissingSymbol(name) => format!("Missing symbol: {name}"),
             NodeParserError::EmptyVec => "Tried to parse empty vec".to_string(),
             NodeParserError::WrongType(ty) => format!("Tried to parse wrong type: {ty}"),
+            NodeParserError::UnknownNodeType(code) => format!("Unknown node type: {code}"),
+            NodeParserError::Header(err) => err.to_string(),
+            NodeParserError::Corrupted(reason) => format!("Corrupted Lenga nodes: {reason}"),
         }
     }
 }
 pub struct NodeParser<'a> {
     context: Context<'a>,
+    /// Layout of the text the file was parsed from, shared with every branch, when it was kept
+    trivia: Option<Rc<RefCell<Trivia>>>,
 }
 
 impl<'a> Default for NodeParser<'a> {
@@ -72,12 +131,14 @@ impl<'a> NodeParser<'a> {
     pub fn new() -> Self {
         Self {
             context: Context::new(),
+            trivia: None,
         }
     }
 
     fn branch(&'a self) -> Self {
         Self {
             context: self.context.branch(),
+            trivia: self.trivia.clone(),
         }
     }
 
@@ -85,22 +146,47 @@ impl<'a> NodeParser<'a> {
         if nodes.is_empty() {
             Ok(SourceFile {
                 id: Uuid::new_v4(),
+                span: None,
                 code: vec![],
+                trivia: None,
             })
         } else {
-            let mut nodes_loaded = bincode::deserialize::<Vec<Node>>(&nodes).unwrap();
-            assert_eq!(nodes_loaded.len(), 1);
-            let file = nodes_loaded.pop().unwrap();
-            assert_eq!(file.node_type, NodeType::SourceFile.as_u64());
+            let (header, nodes) = Header::read(&nodes).map_err(NodeParserError::Header)?;
+            let version =
+                migration::readable_version(header.as_ref(), &Header::for_language(&C::new()))
+                    .map_err(NodeParserError::Header)?;
+            let mut nodes_loaded = bincode::deserialize::<Vec<Node>>(nodes)
+                .map_err(|err| NodeParserError::Corrupted(err.to_string()))?;
+            // Older files are read as if they had been migrated, leaving them as they are
+            migration::migrate(&mut nodes_loaded, version).map_err(NodeParserError::Header)?;
+            let [file] = <[Node; 1]>::try_from(nodes_loaded).map_err(|nodes| {
+                NodeParserError::Corrupted(format!("{} root nodes instead of 1", nodes.len()))
+            })?;
+            if file.node_type != NodeType::SourceFile.as_u64() {
+                return Err(NodeParserError::Corrupted(format!(
+                    "root node of type {} instead of a source file",
+                    file.node_type
+                ))
+                .into());
+            }
             Ok(self.source_file_from_node(file)?)
         }
     }
 
     fn clanguageobject_from_node(
         &mut self,
-        node: Node,
+        mut node: Node,
     ) -> Result<CLanguageObject, NodeParserError> {
-        Ok(match NodeType::from(node.node_type) {
+        let span = span_from_nodes(node.tags.remove("span"))?;
+        let comments = comments_from_nodes(&mut node)?;
+        if let Some(layout) = layout_from_nodes(&mut node)?
+            && let Some(trivia) = &self.trivia
+        {
+            trivia.borrow_mut().layouts.insert(node.id, layout);
+        }
+        let node_type =
+            NodeType::try_from(node.node_type).map_err(NodeParserError::UnknownNodeType)?;
+        let mut object = match node_type {
             NodeType::SourceFile => CLanguageObject::SourceFile(self.source_file_from_node(node)?),
             NodeType::AssignmentExpression => {
                 CLanguageObject::AssignmentExpression(self.assignment_expression_from_node(node)?)
@@ -115,6 +201,9 @@ impl<'a> NodeParser<'a> {
             NodeType::Declaration => {
                 CLanguageObject::Declaration(self.declaration_from_node(node)?)
             }
+            NodeType::DeclarationList => {
+                CLanguageObject::DeclarationList(self.declaration_list_from_node(node)?)
+            }
             NodeType::ElseClause => CLanguageObject::ElseClause(self.else_clause_from_node(node)?),
             NodeType::FunctionDeclaration => {
                 CLanguageObject::FunctionDeclaration(self.function_declaration_from_node(node)?)
@@ -125,51 +214,171 @@ impl<'a> NodeParser<'a> {
             NodeType::IfStatement => {
                 CLanguageObject::IfStatement(self.if_statement_from_node(node)?)
             }
-            NodeType::NumberLiteral => {
-                CLanguageObject::NumberLiteral(self.number_literal_from_node(node)?)
-            }
+            NodeType::NumberLiteral => self.signed_number_literal_from_node(node)?,
             NodeType::PreprocInclude => {
                 CLanguageObject::PreprocInclude(self.preproc_include_from_node(node)?)
             }
+            NodeType::PreprocDefine => {
+                CLanguageObject::PreprocDefine(self.preproc_define_from_node(node)?)
+            }
+            NodeType::PreprocCall => {
+                CLanguageObject::PreprocCall(self.preproc_call_from_node(node)?)
+            }
+            NodeType::PreprocIf => CLanguageObject::PreprocIf(self.preproc_if_from_node(node)?),
+            NodeType::PreprocIfStatement => {
+                CLanguageObject::PreprocIfStatement(self.preproc_if_statement_from_node(node)?)
+            }
             NodeType::Reference => CLanguageObject::Reference(self.reference_from_node(node)?),
             NodeType::ReturnStatement => {
                 CLanguageObject::ReturnStatement(self.return_statement_from_node(node)?)
             }
+            NodeType::CharLiteral => {
+                CLanguageObject::CharLiteral(self.char_literal_from_node(node)?)
+            }
             NodeType::StringLiteral => {
                 CLanguageObject::StringLiteral(self.string_literal_from_node(node)?)
             }
+            NodeType::StructDefinition => {
+                CLanguageObject::StructDefinition(self.struct_definition_from_node(node)?)
+            }
+            NodeType::UnionDefinition => {
+                CLanguageObject::UnionDefinition(self.union_definition_from_node(node)?)
+            }
+            NodeType::EnumDefinition => {
+                CLanguageObject::EnumDefinition(self.enum_definition_from_node(node)?)
+            }
+            NodeType::TypeDefinition => {
+                CLanguageObject::TypeDefinition(self.type_definition_from_node(node)?)
+            }
+            NodeType::FieldDeclaration => {
+                CLanguageObject::FieldDeclaration(self.field_declaration_from_node(node)?)
+            }
+            NodeType::Enumerator => CLanguageObject::Enumerator(self.enumerator_from_node(node)?),
             NodeType::FunctionParameter => panic!("this does not represent a clanguage object"),
             NodeType::TypeDescription => panic!("this does not represent a clanguage object"),
             NodeType::Symbol => panic!("this does not represent a clanguage object"),
             NodeType::CompoundStatement => {
                 CLanguageObject::CompoundStatement(self.compound_statement_from_node(node)?)
             }
+            NodeType::WhileStatement => {
+                CLanguageObject::WhileStatement(self.while_statement_from_node(node)?)
+            }
+            NodeType::DoStatement => {
+                CLanguageObject::DoStatement(self.do_statement_from_node(node)?)
+            }
+            NodeType::ForStatement => {
+                CLanguageObject::ForStatement(self.for_statement_from_node(node)?)
+            }
+            NodeType::SwitchStatement => {
+                CLanguageObject::SwitchStatement(self.switch_statement_from_node(node)?)
+            }
+            NodeType::CaseStatement => {
+                CLanguageObject::CaseStatement(self.case_statement_from_node(node)?)
+            }
+            NodeType::BreakStatement => {
+                CLanguageObject::BreakStatement(self.break_statement_from_node(node))
+            }
+            NodeType::ContinueStatement => {
+                CLanguageObject::ContinueStatement(self.continue_statement_from_node(node))
+            }
+            NodeType::LabeledStatement => {
+                CLanguageObject::LabeledStatement(self.labeled_statement_from_node(node)?)
+            }
+            NodeType::GotoStatement => {
+                CLanguageObject::GotoStatement(self.goto_statement_from_node(node)?)
+            }
+            NodeType::UnaryExpression => {
+                CLanguageObject::UnaryExpression(self.unary_expression_from_node(node)?)
+            }
+            NodeType::UpdateExpression => {
+                CLanguageObject::UpdateExpression(self.update_expression_from_node(node)?)
+            }
+            NodeType::CastExpression => {
+                CLanguageObject::CastExpression(self.cast_expression_from_node(node)?)
+            }
+            NodeType::SizeofExpression => {
+                CLanguageObject::SizeofExpression(self.sizeof_expression_from_node(node)?)
+            }
+            NodeType::ConditionalExpression => {
+                CLanguageObject::ConditionalExpression(self.conditional_expression_from_node(node)?)
+            }
+            NodeType::FieldExpression => {
+                CLanguageObject::FieldExpression(self.field_expression_from_node(node)?)
+            }
+            NodeType::SubscriptExpression => {
+                CLanguageObject::SubscriptExpression(self.subscript_expression_from_node(node)?)
+            }
+            NodeType::ParenthesizedExpression => CLanguageObject::ParenthesizedExpression(
+                self.parenthesized_expression_from_node(node)?,
+            ),
+            NodeType::InitializerList => {
+                CLanguageObject::InitializerList(self.initializer_list_from_node(node)?)
+            }
+            NodeType::InitializerPair => {
+                CLanguageObject::InitializerPair(self.initializer_pair_from_node(node)?)
+            }
+            NodeType::Designator => panic!("this does not represent a clanguage object"),
             NodeType::Unknown => CLanguageObject::Unknown(self.unknown_from_node(node)),
-        })
+        };
+        *object.span_mut() = span;
+        if let Some(object_comments) = object.comments_mut() {
+            *object_comments = comments;
+        }
+        Ok(object)
     }
 
-    fn source_file_from_node(&self, node: Node) -> Result<SourceFile, NodeParserError> {
+    fn source_file_from_node(&self, mut node: Node) -> Result<SourceFile, NodeParserError> {
         assert_eq!(node.node_type, NodeType::SourceFile.as_u64());
+        let span = span_from_nodes(node.tags.remove("span"))?;
+        // Files written before the layout was kept by object held the text they were parsed
+        // from, which is no longer needed
+        node.tags.remove("source");
+        let trivia: Rc<RefCell<Trivia>> = Rc::default();
 
         let mut branch = self.branch();
+        branch.trivia = Some(Rc::clone(&trivia));
         let mut code: Vec<DeclarationObject> = Vec::new();
         for child in node.children {
             code.push(branch.clanguageobject_from_node(child)?.try_into()?);
         }
-        Ok(SourceFile { id: node.id, code })
+        Ok(SourceFile {
+            id: node.id,
+            span,
+            code,
+            // Only files parsed with their trivia have objects with a layout
+            trivia: Some(trivia.take()).filter(|trivia| !trivia.layouts.is_empty()),
+        })
     }
 
     fn assignment_expression_from_node(
         &mut self,
-        node: Node,
+        mut node: Node,
     ) -> Result<AssignmentExpression, NodeParserError> {
         assert_eq!(node.node_type, NodeType::AssignmentExpression.as_u64());
-        let id_declaration = Uuid::parse_str(&node.content).unwrap();
-        let identifier = self.context.get_symbol_identifier(&id_declaration).unwrap();
+        let left = match node.tags.remove("left").and_then(|mut left| left.pop()) {
+            Some(left) => self.clanguageobject_from_node(left)?.try_into()?,
+            // Files written before assignments to fields and pointers were kept hold the id
+            // of the assigned name as the content
+            None => {
+                let declaration_id = Uuid::parse_str(&node.content)
+                    .map_err(|_| NodeParserError::WrongType(node.content.clone()))?;
+                ExpressionObject::Reference(Reference {
+                    id: Uuid::from_seed(&format!("{}/left", node.id)),
+                    span: None,
+                    comments: Comments::default(),
+                    declaration_id,
+                    identifier: self
+                        .context
+                        .get_symbol_identifier(&declaration_id)
+                        .ok_or(NodeParserError::MissingSymbol(node.content))?,
+                })
+            }
+        };
         Ok(AssignmentExpression {
             id: node.id,
-            id_declaration,
-            identifier,
+            span: None,
+            comments: Comments::default(),
+            left: Box::new(left),
             value: self.unpack_parse(node.children)?.try_into()?,
         })
     }
@@ -192,6 +401,8 @@ impl<'a> NodeParser<'a> {
         let right = node.tags.remove("right").unwrap().pop().unwrap();
         Ok(BinaryExpression {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             left: Box::new(self.clanguageobject_from_node(left)?.try_into()?),
             operator: node.content,
             right: Box::new(self.clanguageobject_from_node(right)?.try_into()?),
@@ -204,13 +415,17 @@ impl<'a> NodeParser<'a> {
         let (id_declaration, identifier) = match Uuid::parse_str(&node.content) {
             Ok(id_declaration) => (
                 id_declaration,
-                self.context.get_symbol_identifier(&id_declaration).unwrap(),
+                self.context
+                    .get_symbol_identifier(&id_declaration)
+                    .ok_or_else(|| NodeParserError::MissingSymbol(node.content.clone()))?,
             ),
             Err(_) => (Uuid::nil(), node.content),
         };
 
         Ok(CallExpression {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             id_declaration,
             identifier,
             argument_list: node
@@ -228,6 +443,7 @@ impl<'a> NodeParser<'a> {
         assert_eq!(node.node_type, NodeType::Comment.as_u64());
         Ok(Comment {
             id: node.id,
+            span: None,
             content: node.content,
         })
     }
@@ -243,19 +459,122 @@ impl<'a> NodeParser<'a> {
             id: self
                 .context
                 .insert_symbol_with_id(&node.content, node.id, false)?,
-            primitive_type: CType::from_str(
-                &node.tags.remove("type").unwrap().pop().unwrap().content,
-            )
-            .unwrap(),
+            span: span_from_nodes(node.tags.remove("span"))?,
+            comments: comments_from_nodes(&mut node)?,
+            specifiers: specifiers_from_nodes(node.tags.remove("specifiers"))?,
+            primitive_type: self
+                .type_from_node(node.tags.remove("type").unwrap().pop().unwrap())?,
             identifier: node.content,
             value: value.map(|c| c.try_into()).transpose()?,
         })
     }
 
+    fn declaration_list_from_node(
+        &mut self,
+        node: Node,
+    ) -> Result<DeclarationList, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::DeclarationList.as_u64());
+        Ok(DeclarationList {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            declarations: node
+                .children
+                .into_iter()
+                .map(|declaration| self.declaration_from_node(declaration))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn type_from_node(&self, mut node: Node) -> Result<CType, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::TypeDescription.as_u64());
+        // Function types are told apart by their tag, since they can have no children
+        if let Some(mut return_type) = node.tags.remove("return_type") {
+            // Files written before parameter names were kept have no names
+            let mut names = node
+                .tags
+                .remove("parameter_names")
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| Some(name.content).filter(|name| !name.is_empty()));
+            return Ok(CType::Fn(FnType {
+                return_type: Box::new(self.type_from_node(return_type.pop().unwrap())?),
+                parameters: node
+                    .children
+                    .into_iter()
+                    .map(|parameter| {
+                        Ok(FnParameter {
+                            param_type: self.type_from_node(parameter)?,
+                            identifier: names.next().flatten(),
+                        })
+                    })
+                    .collect::<Result<_, NodeParserError>>()?,
+                is_variadic: node.tags.remove("variadic").is_some(),
+                explicit_void: node.tags.remove("explicit_void").is_some(),
+            }));
+        }
+        let Some(child) = node.children.pop() else {
+            // Any other bare name is a type declared outside the file, like `size_t`
+            return Ok(CType::from_str(&node.content).unwrap_or(CType::Typedef(node.content)));
+        };
+        match node.content.as_str() {
+            "pointer" => Ok(CType::Pointer(Box::new(self.type_from_node(child)?))),
+            "array" => Ok(CType::Array(ArrayType {
+                element: Box::new(self.type_from_node(child)?),
+                size: node
+                    .tags
+                    .remove("size")
+                    .and_then(|mut size| size.pop())
+                    .map(|size| size.content),
+                qualifiers: qualifiers_from_nodes(node.tags.remove("qualifiers"))?,
+                is_static: node.tags.contains_key("static"),
+            })),
+            "qualified" => Ok(CType::Qualified(
+                qualifiers_from_nodes(node.tags.remove("qualifiers"))?,
+                Box::new(self.type_from_node(child)?),
+            )),
+            "typedef" => {
+                let declaration_id = Uuid::parse_str(&child.content).unwrap();
+                let name = self
+                    .context
+                    .get_type_name_identifier(&declaration_id)
+                    .ok_or(NodeParserError::MissingSymbol(child.content))?;
+                Ok(CType::Named {
+                    declaration_id,
+                    name,
+                })
+            }
+            keyword => {
+                let declaration_id = Uuid::parse_str(&child.content).unwrap();
+                // Tags used before their definition are only known by the name kept with them
+                let identifier = self
+                    .context
+                    .get_tag_identifier(&declaration_id)
+                    .or_else(|| {
+                        node.tags
+                            .remove("identifier")
+                            .and_then(|mut identifier| identifier.pop())
+                            .map(|identifier| identifier.content)
+                    })
+                    .ok_or(NodeParserError::MissingSymbol(child.content))?;
+                CType::from_tag(
+                    keyword,
+                    TypeReference {
+                        declaration_id,
+                        identifier,
+                    },
+                )
+                .ok_or(NodeParserError::WrongType(node.content))
+            }
+        }
+    }
+
     fn else_clause_from_node(&mut self, mut node: Node) -> Result<ElseClause, NodeParserError> {
         assert_eq!(node.node_type, NodeType::ElseClause.as_u64());
         Ok(ElseClause {
             id: node.id,
+            span: span_from_nodes(node.tags.remove("span"))?,
+            comments: comments_from_nodes(&mut node)?,
             body: Box::new(
                 self.clanguageobject_from_node(node.children.pop().unwrap())?
                     .try_into()?,
@@ -263,6 +582,79 @@ impl<'a> NodeParser<'a> {
         })
     }
 
+    /// Id of the struct, union or enum of `node`, registered as its tag unless it is only
+    /// declared, which leaves the tag to its definition
+    fn tag_from_node(&mut self, node: &Node, is_forward: bool) -> Result<Uuid, NodeParserError> {
+        if is_forward {
+            return Ok(node.id);
+        }
+        Ok(self.context.insert_tag_with_id(&node.content, node.id)?)
+    }
+
+    fn enum_definition_from_node(&mut self, node: Node) -> Result<EnumDefinition, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::EnumDefinition.as_u64());
+        let is_forward = node.tags.contains_key("forward");
+        let id = self.tag_from_node(&node, is_forward)?;
+        Ok(EnumDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            identifier: node.content,
+            enumerators: node
+                .children
+                .into_iter()
+                .map(|enumerator| self.enumerator_from_node(enumerator))
+                .collect::<Result<Vec<Enumerator>, NodeParserError>>()?,
+            is_forward,
+        })
+    }
+
+    fn enumerator_from_node(&mut self, mut node: Node) -> Result<Enumerator, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::Enumerator.as_u64());
+        let value = node
+            .children
+            .pop()
+            .map(|value| self.clanguageobject_from_node(value))
+            .transpose()?
+            .map(|value| value.try_into().map(Box::new))
+            .transpose()?;
+        // Enumerators are ordinary identifiers, so references to them resolve like variables
+        Ok(Enumerator {
+            id: self
+                .context
+                .insert_symbol_with_id(&node.content, node.id, false)?,
+            span: span_from_nodes(node.tags.remove("span"))?,
+            comments: comments_from_nodes(&mut node)?,
+            identifier: node.content,
+            value,
+        })
+    }
+
+    fn field_declaration_from_node(
+        &self,
+        mut node: Node,
+    ) -> Result<FieldDeclaration, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::FieldDeclaration.as_u64());
+        Ok(FieldDeclaration {
+            id: node.id,
+            span: span_from_nodes(node.tags.remove("span"))?,
+            comments: comments_from_nodes(&mut node)?,
+            field_type: self.type_from_node(node.tags.remove("type").unwrap().pop().unwrap())?,
+            identifier: node.content,
+            shares_declaration: node.tags.remove("shares_declaration").is_some(),
+        })
+    }
+
+    fn fields_from_nodes(
+        &self,
+        nodes: Vec<Node>,
+    ) -> Result<Vec<FieldDeclaration>, NodeParserError> {
+        nodes
+            .into_iter()
+            .map(|field| self.field_declaration_from_node(field))
+            .collect()
+    }
+
     fn function_declaration_from_node(
         &mut self,
         mut node: Node,
@@ -273,16 +665,11 @@ impl<'a> NodeParser<'a> {
                 .context
                 .insert_symbol_with_id(&node.content, node.id, true)
                 .unwrap_or(node.id),
-            return_type: CType::from_str(
-                &node
-                    .tags
-                    .remove("return_type")
-                    .unwrap()
-                    .pop()
-                    .unwrap()
-                    .content,
-            )
-            .unwrap(),
+            span: None,
+            comments: Comments::default(),
+            specifiers: specifiers_from_nodes(node.tags.remove("specifiers"))?,
+            return_type: self
+                .type_from_node(node.tags.remove("return_type").unwrap().pop().unwrap())?,
             identifier: node.content,
             parameter_list: node
                 .tags
@@ -292,14 +679,16 @@ impl<'a> NodeParser<'a> {
                 .map(|mut param| {
                     Ok(FunctionParameter {
                         id: param.id,
+                        span: span_from_nodes(param.tags.remove("span"))?,
+                        comments: comments_from_nodes(&mut param)?,
                         identifier: param.content,
-                        param_type: CType::from_str(
-                            &param.tags.remove("type").unwrap().pop().unwrap().content,
-                        )
-                        .unwrap(),
+                        param_type: self
+                            .type_from_node(param.tags.remove("type").unwrap().pop().unwrap())?,
                     })
                 })
-                .collect::<Result<Vec<FunctionParameter>, SymbolAlreadyExists>>()?,
+                .collect::<Result<Vec<FunctionParameter>, NodeParserError>>()?,
+            is_variadic: node.tags.remove("variadic").is_some(),
+            explicit_void: node.tags.remove("explicit_void").is_some(),
         })
     }
 
@@ -313,19 +702,18 @@ impl<'a> NodeParser<'a> {
             .insert_symbol_with_id(&node.content, node.id, true)
             .unwrap_or(node.id);
         let mut subcontext = self.branch();
+        // Labels are visible in the whole function body, even before they are defined
+        let mut body = node.children.pop().unwrap();
+        insert_labels(&mut subcontext.context, &body)?;
+        let body_comments = comments_from_nodes(&mut body)?;
 
         Ok(FunctionDefinition {
             id,
-            return_type: CType::from_str(
-                &node
-                    .tags
-                    .remove("return_type")
-                    .unwrap()
-                    .pop()
-                    .unwrap()
-                    .content,
-            )
-            .unwrap(),
+            span: None,
+            comments: Comments::default(),
+            specifiers: specifiers_from_nodes(node.tags.remove("specifiers"))?,
+            return_type: self
+                .type_from_node(node.tags.remove("return_type").unwrap().pop().unwrap())?,
             identifier: node.content,
             parameter_list: node
                 .tags
@@ -333,22 +721,30 @@ impl<'a> NodeParser<'a> {
                 .unwrap()
                 .into_iter()
                 .map(|mut param| {
+                    // Unnamed parameters declare no symbol
+                    let id = if param.content.is_empty() {
+                        param.id
+                    } else {
+                        subcontext
+                            .context
+                            .insert_symbol_with_id(&param.content, param.id, false)?
+                    };
                     Ok(FunctionParameter {
-                        id: subcontext.context.insert_symbol_with_id(
-                            &param.content,
-                            param.id,
-                            false,
-                        )?,
+                        id,
+                        span: span_from_nodes(param.tags.remove("span"))?,
+                        comments: comments_from_nodes(&mut param)?,
                         identifier: param.content,
-                        param_type: CType::from_str(
-                            &param.tags.remove("type").unwrap().pop().unwrap().content,
-                        )
-                        .unwrap(),
+                        param_type: self
+                            .type_from_node(param.tags.remove("type").unwrap().pop().unwrap())?,
                     })
                 })
-                .collect::<Result<Vec<FunctionParameter>, SymbolAlreadyExists>>()?,
-            compound_statement: subcontext
-                .compound_statement_from_node(node.children.pop().unwrap())?,
+                .collect::<Result<Vec<FunctionParameter>, NodeParserError>>()?,
+            is_variadic: node.tags.remove("variadic").is_some(),
+            explicit_void: node.tags.remove("explicit_void").is_some(),
+            compound_statement: CompoundStatement {
+                comments: body_comments,
+                ..subcontext.compound_statement_from_node(body)?
+            },
         })
     }
 
@@ -370,6 +766,8 @@ impl<'a> NodeParser<'a> {
             };
         Ok(IfStatement {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             condition: self
                 .unpack_parse(node.tags.remove("condition").unwrap())?
                 .try_into()?,
@@ -382,31 +780,211 @@ impl<'a> NodeParser<'a> {
         })
     }
 
-    fn number_literal_from_node(&mut self, node: Node) -> Result<NumberLiteral, NodeParserError> {
+    fn number_literal_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<NumberLiteral, NodeParserError> {
         assert_eq!(node.node_type, NodeType::NumberLiteral.as_u64());
+        let mut tag = |name: &str| {
+            node.tags
+                .remove(name)
+                .and_then(|mut tag| tag.pop())
+                .map(|tag| tag.content)
+        };
+        let wrong_type = |content: String| NodeParserError::WrongType(content);
+        let value = match (tag("integer"), tag("float")) {
+            (Some(integer), None) => {
+                NumberValue::Integer(integer.parse().map_err(|_| wrong_type(integer))?)
+            }
+            (None, Some(float)) => {
+                NumberValue::Float(float.parse().map_err(|_| wrong_type(float))?)
+            }
+            (None, None) => {
+                // Nodes written before numbers had their value in tags only hold the spelling
+                let mut number_literal = NumberLiteral::from_spelling(node.content.clone())
+                    .ok_or(NodeParserError::WrongType(node.content))?;
+                number_literal.id = node.id;
+                return Ok(number_literal);
+            }
+            _ => return Err(NodeParserError::WrongType(node.content)),
+        };
+        let radix = match tag("radix") {
+            Some(radix) => radix
+                .parse()
+                .ok()
+                .and_then(Radix::from_base)
+                .ok_or(NodeParserError::WrongType(radix))?,
+            None => Radix::Decimal,
+        };
+        let suffix = tag("suffix").unwrap_or_default();
         Ok(NumberLiteral {
             id: node.id,
-            value: node.content,
+            span: None,
+            comments: Comments::default(),
+            value,
+            radix,
+            suffix,
+            spelling: node.content,
         })
     }
 
+    /// Nodes written before numbers had their value in tags hold `-1` as one number, which is
+    /// a unary expression around the number like the text parser makes
+    fn signed_number_literal_from_node(
+        &mut self,
+        node: Node,
+    ) -> Result<CLanguageObject, NodeParserError> {
+        let has_value = node.tags.contains_key("integer") || node.tags.contains_key("float");
+        let Some(digits) = node.content.strip_prefix(['-', '+']).filter(|_| !has_value) else {
+            return Ok(CLanguageObject::NumberLiteral(
+                self.number_literal_from_node(node)?,
+            ));
+        };
+        let mut number_literal = NumberLiteral::from_spelling(digits.trim_start())
+            .ok_or(NodeParserError::WrongType(node.content.clone()))?;
+        number_literal.id = Uuid::from_seed(&format!("{}/number_literal", node.id));
+        Ok(CLanguageObject::UnaryExpression(UnaryExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            operator: node.content[..1].to_string(),
+            argument: Box::new(ExpressionObject::NumberLiteral(number_literal)),
+        }))
+    }
+
     fn preproc_include_from_node(&mut self, node: Node) -> Result<PreprocInclude, NodeParserError> {
         assert_eq!(node.node_type, NodeType::PreprocInclude.as_u64());
         Ok(PreprocInclude {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             content: node.content,
         })
     }
 
+    fn preproc_define_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<PreprocDefine, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::PreprocDefine.as_u64());
+        let parameters: Option<Vec<String>> = node.tags.remove("parameters").map(|parameters| {
+            parameters
+                .into_iter()
+                .map(|parameter| parameter.content)
+                .collect()
+        });
+        let value = node.tags.remove("value").unwrap().pop().unwrap().content;
+        Ok(PreprocDefine {
+            id: self
+                .context
+                .overwrite_symbol_with_id(&node.content, node.id, parameters.is_some()),
+            span: None,
+            comments: Comments::default(),
+            identifier: node.content,
+            parameters,
+            value,
+        })
+    }
+
+    fn preproc_call_from_node(&mut self, mut node: Node) -> Result<PreprocCall, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::PreprocCall.as_u64());
+        Ok(PreprocCall {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            directive: node.content,
+            argument: node.tags.remove("argument").unwrap().pop().unwrap().content,
+        })
+    }
+
+    fn preproc_if_from_node(&mut self, mut node: Node) -> Result<PreprocIf, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::PreprocIf.as_u64());
+        // Whatever the first branch declares stays visible after the block, the other
+        // branches get their own scope so they can declare the same names again
+        let mut body: Vec<DeclarationObject> = Vec::new();
+        for child in node.children {
+            body.push(self.clanguageobject_from_node(child)?.try_into()?);
+        }
+        let alternative = node
+            .tags
+            .remove("alternative")
+            .map(|mut alternative| {
+                self.branch()
+                    .preproc_if_from_node(alternative.pop().unwrap())
+                    .map(Box::new)
+            })
+            .transpose()?;
+        Ok(PreprocIf {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            directive: node
+                .tags
+                .remove("directive")
+                .unwrap()
+                .pop()
+                .unwrap()
+                .content,
+            condition: node.content,
+            body,
+            alternative,
+        })
+    }
+
+    fn preproc_if_statement_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<PreprocIfStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::PreprocIfStatement.as_u64());
+        let mut body: Vec<CompoundStatementObject> = Vec::new();
+        for child in node.children {
+            body.push(self.clanguageobject_from_node(child)?.try_into()?);
+        }
+        let alternative = node
+            .tags
+            .remove("alternative")
+            .map(|mut alternative| {
+                self.branch()
+                    .preproc_if_statement_from_node(alternative.pop().unwrap())
+                    .map(Box::new)
+            })
+            .transpose()?;
+        Ok(PreprocIfStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            directive: node
+                .tags
+                .remove("directive")
+                .unwrap()
+                .pop()
+                .unwrap()
+                .content,
+            condition: node.content,
+            body,
+            alternative,
+        })
+    }
+
     fn reference_from_node(&mut self, node: Node) -> Result<Reference, NodeParserError> {
         assert_eq!(node.node_type, NodeType::Reference.as_u64());
 
-        let declaration_id = Uuid::parse_str(&node.content).unwrap();
+        let (declaration_id, identifier) = match Uuid::parse_str(&node.content) {
+            Ok(declaration_id) => (
+                declaration_id,
+                self.context
+                    .get_symbol_identifier(&declaration_id)
+                    .ok_or_else(|| NodeParserError::MissingSymbol(node.content.clone()))?,
+            ),
+            Err(_) => (Uuid::nil(), node.content),
+        };
 
         Ok(Reference {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             declaration_id,
-            identifier: self.context.get_symbol_identifier(&declaration_id).unwrap(),
+            identifier,
         })
     }
 
@@ -417,6 +995,8 @@ impl<'a> NodeParser<'a> {
         assert_eq!(node.node_type, NodeType::ReturnStatement.as_u64());
         Ok(ReturnStatement {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             value: if node.children.is_empty() {
                 None
             } else {
@@ -425,11 +1005,48 @@ impl<'a> NodeParser<'a> {
         })
     }
 
-    fn string_literal_from_node(&mut self, node: Node) -> Result<StringLiteral, NodeParserError> {
+    fn char_literal_from_node(&mut self, mut node: Node) -> Result<CharLiteral, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::CharLiteral.as_u64());
+        Ok(CharLiteral {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            prefix: prefix_from_nodes(node.tags.remove("prefix"))?,
+            value: node.content,
+            spelling: node
+                .tags
+                .remove("spelling")
+                .and_then(|mut spelling| spelling.pop())
+                .map(|spelling| spelling.content)
+                .unwrap_or_default(),
+        })
+    }
+
+    fn string_literal_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<StringLiteral, NodeParserError> {
         assert_eq!(node.node_type, NodeType::StringLiteral.as_u64());
+        let prefix = prefix_from_nodes(node.tags.remove("prefix"))?;
+        let Some(segments) = node.tags.remove("segments") else {
+            // Files written before strings were decoded hold the text between the quotes
+            let spelling = format!("{prefix}\"{}\"", node.content);
+            return Ok(StringLiteral {
+                id: node.id,
+                ..StringLiteral::from_segments(vec![spelling])
+                    .ok_or(NodeParserError::WrongType(node.content))?
+            });
+        };
         Ok(StringLiteral {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
+            prefix,
             value: node.content,
+            segments: segments
+                .into_iter()
+                .map(|segment| segment.content)
+                .collect(),
         })
     }
 
@@ -446,14 +1063,587 @@ impl<'a> NodeParser<'a> {
         }
         Ok(CompoundStatement {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             code_block,
         })
     }
 
+    fn while_statement_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<WhileStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::WhileStatement.as_u64());
+        Ok(WhileStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            condition: self
+                .unpack_parse(node.tags.remove("condition").unwrap())?
+                .try_into()?,
+            body: Box::new(
+                self.branch()
+                    .clanguageobject_from_node(node.children.pop().unwrap())?
+                    .try_into()?,
+            ),
+        })
+    }
+
+    fn do_statement_from_node(&mut self, mut node: Node) -> Result<DoStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::DoStatement.as_u64());
+        Ok(DoStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            body: Box::new(
+                self.branch()
+                    .clanguageobject_from_node(node.children.pop().unwrap())?
+                    .try_into()?,
+            ),
+            condition: self
+                .unpack_parse(node.tags.remove("condition").unwrap())?
+                .try_into()?,
+        })
+    }
+
+    fn for_statement_from_node(&mut self, mut node: Node) -> Result<ForStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::ForStatement.as_u64());
+        // Variables declared in the initializer are only visible inside the loop
+        let mut scope = self.branch();
+        let initializer = node
+            .tags
+            .remove("initializer")
+            .map(|initializer| scope.unpack_parse(initializer))
+            .transpose()?
+            .map(|initializer| (*initializer).try_into().map(Box::new))
+            .transpose()?;
+        let condition = node
+            .tags
+            .remove("condition")
+            .map(|condition| scope.unpack_parse(condition))
+            .transpose()?
+            .map(|condition| (*condition).try_into().map(Box::new))
+            .transpose()?;
+        let update = node
+            .tags
+            .remove("update")
+            .map(|update| scope.unpack_parse(update))
+            .transpose()?
+            .map(|update| (*update).try_into().map(Box::new))
+            .transpose()?;
+        let body = scope
+            .branch()
+            .clanguageobject_from_node(node.children.pop().unwrap())?;
+        Ok(ForStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            initializer,
+            condition,
+            update,
+            body: Box::new(body.try_into()?),
+        })
+    }
+
+    fn struct_definition_from_node(
+        &mut self,
+        node: Node,
+    ) -> Result<StructDefinition, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::StructDefinition.as_u64());
+        let is_forward = node.tags.contains_key("forward");
+        let id = self.tag_from_node(&node, is_forward)?;
+        let fields = self.fields_from_nodes(node.children)?;
+        self.context.insert_members(id, fields.clone());
+        Ok(StructDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            fields,
+            identifier: node.content,
+            is_forward,
+        })
+    }
+
+    fn type_definition_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<TypeDefinition, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::TypeDefinition.as_u64());
+        let aliased_type = self.type_from_node(node.tags.remove("type").unwrap().pop().unwrap())?;
+        Ok(TypeDefinition {
+            id: self
+                .context
+                .insert_type_name_with_id(&node.content, node.id)?,
+            span: None,
+            comments: Comments::default(),
+            identifier: node.content,
+            aliased_type,
+        })
+    }
+
+    fn union_definition_from_node(
+        &mut self,
+        node: Node,
+    ) -> Result<UnionDefinition, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::UnionDefinition.as_u64());
+        let is_forward = node.tags.contains_key("forward");
+        let id = self.tag_from_node(&node, is_forward)?;
+        let fields = self.fields_from_nodes(node.children)?;
+        self.context.insert_members(id, fields.clone());
+        Ok(UnionDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            fields,
+            identifier: node.content,
+            is_forward,
+        })
+    }
+
+    fn switch_statement_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<SwitchStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::SwitchStatement.as_u64());
+        Ok(SwitchStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            condition: self
+                .unpack_parse(node.tags.remove("condition").unwrap())?
+                .try_into()?,
+            body: Box::new(
+                self.branch()
+                    .clanguageobject_from_node(node.children.pop().unwrap())?
+                    .try_into()?,
+            ),
+        })
+    }
+
+    fn case_statement_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<CaseStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::CaseStatement.as_u64());
+        let value = node
+            .tags
+            .remove("value")
+            .map(|value| self.branch().unpack_parse(value))
+            .transpose()?
+            .map(|value| (*value).try_into().map(Box::new))
+            .transpose()?;
+
+        // Statements under a label share the scope of the whole switch body
+        let mut body: Vec<CompoundStatementObject> = Vec::new();
+        for child in node.children {
+            body.push(self.clanguageobject_from_node(child)?.try_into()?)
+        }
+        Ok(CaseStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            value,
+            body,
+        })
+    }
+
+    fn labeled_statement_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<LabeledStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::LabeledStatement.as_u64());
+        let id = self
+            .context
+            .get_label_id(&node.content)
+            .ok_or(NodeParserError::MissingSymbol(node.content.clone()))?;
+        let statement = match node.children.pop() {
+            Some(child) => Some(Box::new(self.clanguageobject_from_node(child)?.try_into()?)),
+            None => None,
+        };
+        Ok(LabeledStatement {
+            id,
+            span: None,
+            comments: Comments::default(),
+            label: node.content,
+            statement,
+        })
+    }
+
+    fn goto_statement_from_node(&self, node: Node) -> Result<GotoStatement, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::GotoStatement.as_u64());
+        let label_id = Uuid::parse_str(&node.content)
+            .map_err(|_| NodeParserError::WrongType(node.content.clone()))?;
+        Ok(GotoStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            label_id,
+            label: self
+                .context
+                .get_label_identifier(&label_id)
+                .ok_or(NodeParserError::MissingSymbol(node.content))?,
+        })
+    }
+
+    fn break_statement_from_node(&self, node: Node) -> BreakStatement {
+        assert_eq!(node.node_type, NodeType::BreakStatement.as_u64());
+        BreakStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+        }
+    }
+
+    fn continue_statement_from_node(&self, node: Node) -> ContinueStatement {
+        assert_eq!(node.node_type, NodeType::ContinueStatement.as_u64());
+        ContinueStatement {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+        }
+    }
+
+    fn unary_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<UnaryExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::UnaryExpression.as_u64());
+        let argument = node.children.pop().unwrap();
+        Ok(UnaryExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            operator: node.content,
+            argument: Box::new(self.clanguageobject_from_node(argument)?.try_into()?),
+        })
+    }
+
+    fn update_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<UpdateExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::UpdateExpression.as_u64());
+        let position = node.tags.remove("position").unwrap().pop().unwrap();
+        let is_prefix = match position.content.as_str() {
+            "prefix" => true,
+            "postfix" => false,
+            _ => return Err(NodeParserError::WrongType(position.content)),
+        };
+        let argument = node.children.pop().unwrap();
+        Ok(UpdateExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            operator: node.content,
+            is_prefix,
+            argument: Box::new(self.clanguageobject_from_node(argument)?.try_into()?),
+        })
+    }
+
+    fn cast_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<CastExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::CastExpression.as_u64());
+        let cast_type = node.tags.remove("type").unwrap().pop().unwrap();
+        let value = node.children.pop().unwrap();
+        Ok(CastExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            cast_type: self.type_from_node(cast_type)?,
+            value: Box::new(self.clanguageobject_from_node(value)?.try_into()?),
+        })
+    }
+
+    fn sizeof_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<SizeofExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::SizeofExpression.as_u64());
+        let argument_type = node
+            .tags
+            .remove("type")
+            .and_then(|mut argument_type| argument_type.pop())
+            .map(|argument_type| self.type_from_node(argument_type))
+            .transpose()?;
+        let value = node
+            .children
+            .pop()
+            .map(|value| self.clanguageobject_from_node(value))
+            .transpose()?
+            .map(|value| value.try_into().map(Box::new))
+            .transpose()?;
+        Ok(SizeofExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            argument_type,
+            value,
+        })
+    }
+
+    fn conditional_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<ConditionalExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::ConditionalExpression.as_u64());
+        let condition = node.tags.remove("condition").unwrap().pop().unwrap();
+        let consequence = node.tags.remove("consequence").unwrap().pop().unwrap();
+        let alternative = node.tags.remove("alternative").unwrap().pop().unwrap();
+        Ok(ConditionalExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(self.clanguageobject_from_node(condition)?.try_into()?),
+            consequence: Box::new(self.clanguageobject_from_node(consequence)?.try_into()?),
+            alternative: Box::new(self.clanguageobject_from_node(alternative)?.try_into()?),
+        })
+    }
+
+    fn field_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<FieldExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::FieldExpression.as_u64());
+        let (declaration_id, identifier) = match Uuid::parse_str(&node.content) {
+            Ok(declaration_id) => (
+                declaration_id,
+                self.context
+                    .get_member_by_id(&declaration_id)
+                    .unwrap()
+                    .identifier
+                    .clone(),
+            ),
+            Err(_) => (Uuid::nil(), node.content),
+        };
+        let operator = node.tags.remove("operator").unwrap().pop().unwrap();
+        let argument = node.children.pop().unwrap();
+        Ok(FieldExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(self.clanguageobject_from_node(argument)?.try_into()?),
+            operator: operator.content,
+            declaration_id,
+            identifier,
+        })
+    }
+
+    fn subscript_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<SubscriptExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::SubscriptExpression.as_u64());
+        let index = node.tags.remove("index").unwrap().pop().unwrap();
+        let argument = node.children.pop().unwrap();
+        Ok(SubscriptExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(self.clanguageobject_from_node(argument)?.try_into()?),
+            index: Box::new(self.clanguageobject_from_node(index)?.try_into()?),
+        })
+    }
+
+    fn parenthesized_expression_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<ParenthesizedExpression, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::ParenthesizedExpression.as_u64());
+        let value = node.children.pop().unwrap();
+        Ok(ParenthesizedExpression {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            value: Box::new(self.clanguageobject_from_node(value)?.try_into()?),
+        })
+    }
+
+    fn initializer_list_from_node(
+        &mut self,
+        node: Node,
+    ) -> Result<InitializerList, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::InitializerList.as_u64());
+        Ok(InitializerList {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            elements: node
+                .children
+                .into_iter()
+                .map(|element| Ok(self.clanguageobject_from_node(element)?.try_into()?))
+                .collect::<Result<_, NodeParserError>>()?,
+        })
+    }
+
+    fn initializer_pair_from_node(
+        &mut self,
+        mut node: Node,
+    ) -> Result<InitializerPair, NodeParserError> {
+        assert_eq!(node.node_type, NodeType::InitializerPair.as_u64());
+        let designators = node
+            .tags
+            .remove("designators")
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut designator| {
+                assert_eq!(designator.node_type, NodeType::Designator.as_u64());
+                Ok(match designator.children.pop() {
+                    Some(index) => {
+                        Designator::Subscript(self.clanguageobject_from_node(index)?.try_into()?)
+                    }
+                    None => Designator::Field(designator.content),
+                })
+            })
+            .collect::<Result<_, NodeParserError>>()?;
+        let value = node.children.pop().unwrap();
+        Ok(InitializerPair {
+            id: node.id,
+            span: None,
+            comments: Comments::default(),
+            designators,
+            value: Box::new(self.clanguageobject_from_node(value)?.try_into()?),
+        })
+    }
+
     fn unknown_from_node(&self, node: Node) -> Unknown {
         Unknown {
             id: node.id,
+            span: None,
+            comments: Comments::default(),
             content: node.content,
         }
     }
 }
+
+/// Registers every label defined under `node`, so gotos can refer to labels defined after them
+fn insert_labels(context: &mut Context, node: &Node) -> Result<(), NodeParserError> {
+    if node.node_type == NodeType::LabeledStatement.as_u64() {
+        context.insert_label_with_id(&node.content, node.id)?;
+    }
+    for child in node.children.iter().chain(node.tags.values().flatten()) {
+        insert_labels(context, child)?;
+    }
+    Ok(())
+}
+
+/// Reads the comments attached to an object from its `leading_comments` and
+/// `trailing_comments` tags, which files written before comments were attached don't have
+fn comments_from_nodes(node: &mut Node) -> Result<Comments, NodeParserError> {
+    let mut attached = |tag: &str| {
+        node.tags
+            .remove(tag)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut comment| {
+                Ok(Comment {
+                    id: comment.id,
+                    span: span_from_nodes(comment.tags.remove("span"))?,
+                    content: comment.content,
+                })
+            })
+            .collect::<Result<Vec<_>, NodeParserError>>()
+    };
+    Ok(Comments {
+        leading: attached("leading_comments")?,
+        trailing: attached("trailing_comments")?,
+    })
+}
+
+/// Reads the `blank_lines_before`, `line_breaks` and `indent` tags of an object of a file
+/// parsed with its trivia. Other objects have none of them
+fn layout_from_nodes(node: &mut Node) -> Result<Option<Layout>, NodeParserError> {
+    // Files written before the layout was kept by object told changed objects apart by it
+    node.tags.remove("fingerprint");
+    let Some(blank_lines) = node.tags.remove("blank_lines_before") else {
+        return Ok(None);
+    };
+    let Some(blank_lines) = blank_lines.first() else {
+        return Err(NodeParserError::WrongType("layout".to_string()));
+    };
+    let line_breaks = match node.tags.remove("line_breaks") {
+        Some(breaks) => {
+            let mut line_breaks = LineBreaks::default();
+            for line_break in breaks {
+                match line_break.content.as_str() {
+                    "after_type" => line_breaks.after_type = true,
+                    "before_block" => line_breaks.before_block = true,
+                    _ => return Err(NodeParserError::WrongType(line_break.content)),
+                }
+            }
+            Some(line_breaks)
+        }
+        None => None,
+    };
+    Ok(Some(Layout {
+        blank_lines_before: blank_lines
+            .content
+            .parse()
+            .map_err(|_| NodeParserError::WrongType(blank_lines.content.clone()))?,
+        line_breaks,
+        indent: node
+            .tags
+            .remove("indent")
+            .and_then(|mut indent| indent.pop())
+            .map(|indent| indent.content),
+    }))
+}
+
+/// Reads the `qualifiers` tag of a qualified or array type
+fn qualifiers_from_nodes(nodes: Option<Vec<Node>>) -> Result<Qualifiers, NodeParserError> {
+    let mut qualifiers = Qualifiers::default();
+    for qualifier in nodes.unwrap_or_default() {
+        if !qualifiers.insert(&qualifier.content) {
+            return Err(NodeParserError::WrongType(qualifier.content));
+        }
+    }
+    Ok(qualifiers)
+}
+
+/// Reads the `span` tag of an object parsed from text. Objects built otherwise, and files
+/// written before spans were kept, have none
+fn span_from_nodes(nodes: Option<Vec<Node>>) -> Result<Option<Span>, NodeParserError> {
+    let Some(nodes) = nodes else {
+        return Ok(None);
+    };
+    let [start, end] = nodes.as_slice() else {
+        return Err(NodeParserError::WrongType("span".to_string()));
+    };
+    Ok(Some(Span {
+        start: position_from_node(start)?,
+        end: position_from_node(end)?,
+    }))
+}
+
+fn position_from_node(node: &Node) -> Result<Position, NodeParserError> {
+    let mut parts = node.content.split(':').map(str::parse::<usize>);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(line)), Some(Ok(column)), Some(Ok(offset)), None) => Ok(Position {
+            line,
+            column,
+            offset,
+        }),
+        _ => Err(NodeParserError::WrongType(node.content.clone())),
+    }
+}
+
+/// Reads the `specifiers` tag of a declaration, which plain declarations leave out
+fn specifiers_from_nodes(nodes: Option<Vec<Node>>) -> Result<Specifiers, NodeParserError> {
+    let mut specifiers = Specifiers::default();
+    for specifier in nodes.unwrap_or_default() {
+        if !specifiers.insert(&specifier.content) {
+            return Err(NodeParserError::WrongType(specifier.content));
+        }
+    }
+    Ok(specifiers)
+}
+
+fn prefix_from_nodes(nodes: Option<Vec<Node>>) -> Result<EncodingPrefix, NodeParserError> {
+    match nodes.and_then(|mut nodes| nodes.pop()) {
+        Some(prefix) => {
+            EncodingPrefix::parse(&prefix.content).ok_or(NodeParserError::WrongType(prefix.content))
+        }
+        None => Ok(EncodingPrefix::None),
+    }
+}
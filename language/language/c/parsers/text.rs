@@ -1,37 +1,93 @@
+use std::{cell::RefCell, collections::HashSet, fmt, rc::Rc};
+
 use uuid::Uuid;
 
-use crate::language::c::{
-    c_type::CType,
-    language_object::{
-        ConversionError, LanguageObject as CLanguageObject,
-        declaration_object::{
-            declaration::Declaration,
-            function_declaration::{FunctionDeclaration, function_parameter::FunctionParameter},
-            function_definition::FunctionDefinition,
-            preproc_include::PreprocInclude,
-        },
-        expression_object::{
-            ExpressionObject, assignment_expression::AssignmentExpression,
-            binary_expression::BinaryExpression, call_expression::CallExpression,
-            number_literal::NumberLiteral, reference::Reference, string_literal::StringLiteral,
-        },
-        special_object::comment::Comment,
-        statement_object::{
-            compound_statement::CompoundStatement,
-            if_statement::{ElseStatement, IfStatement, else_clause::ElseClause},
-            return_statement::ReturnStatement,
+use crate::language::{
+    c::{
+        c_type::CType,
+        language_object::{
+            ConversionError, LanguageObject as CLanguageObject,
+            declaration_object::{
+                DeclarationObject,
+                declaration::Declaration,
+                declaration_list::DeclarationList,
+                enum_definition::{EnumDefinition, enumerator::Enumerator},
+                function_declaration::{
+                    FunctionDeclaration, function_parameter::FunctionParameter,
+                },
+                function_definition::FunctionDefinition,
+                preproc_call::PreprocCall,
+                preproc_define::PreprocDefine,
+                preproc_if::PreprocIf,
+                preproc_include::PreprocInclude,
+                struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+                type_definition::TypeDefinition,
+                union_definition::UnionDefinition,
+            },
+            expression_object::{
+                ExpressionObject,
+                assignment_expression::AssignmentExpression,
+                binary_expression::BinaryExpression,
+                call_expression::CallExpression,
+                cast_expression::CastExpression,
+                char_literal::CharLiteral,
+                conditional_expression::ConditionalExpression,
+                field_expression::FieldExpression,
+                initializer_list::InitializerList,
+                initializer_pair::{Designator, InitializerPair},
+                number_literal::NumberLiteral,
+                parenthesized_expression::ParenthesizedExpression,
+                reference::Reference,
+                sizeof_expression::SizeofExpression,
+                string_literal::StringLiteral,
+                subscript_expression::SubscriptExpression,
+                unary_expression::UnaryExpression,
+                update_expression::UpdateExpression,
+            },
+            special_object::{
+                comment::{Comment, Comments},
+                trivia::Trivia,
+                unknown::Unknown,
+            },
+            statement_object::{
+                break_statement::BreakStatement,
+                case_statement::CaseStatement,
+                compound_statement::{
+                    CompoundStatement, compound_statement_object::CompoundStatementObject,
+                },
+                continue_statement::ContinueStatement,
+                do_statement::DoStatement,
+                for_statement::ForStatement,
+                goto_statement::GotoStatement,
+                if_statement::{ElseStatement, IfStatement, else_clause::ElseClause},
+                labeled_statement::LabeledStatement,
+                preproc_if_statement::PreprocIfStatement,
+                return_statement::ReturnStatement,
+                switch_statement::SwitchStatement,
+                while_statement::WhileStatement,
+            },
         },
+        parsers::context::{Context, SymbolAlreadyExists},
+        specifiers::Specifiers,
     },
-    parsers::context::{Context, SymbolAlreadyExists},
+    diagnostic::{Diagnostic, Severity, Span},
 };
 
-use crate::language::c::TreeSitterNodeExt;
+use crate::language::c::{
+    TreeSitterNodeExt,
+    parsers::{
+        comments::CommentAttacher,
+        ids::{IdMode, ObjectIds},
+    },
+};
 
 #[derive(Debug)]
 pub enum TreeSitterParserError {
     SymbolAlreadyExists(SymbolAlreadyExists),
     MissingSymbol(String),
     WrongType(String),
+    /// Syntax the parser has no objects for, which is kept as an `Unknown` object
+    Unsupported(String),
 }
 
 impl From<SymbolAlreadyExists> for TreeSitterParserError {
@@ -40,21 +96,51 @@ impl From<SymbolAlreadyExists> for TreeSitterParserError {
     }
 }
 
+impl TreeSitterParserError {
+    /// Code of the diagnostic reporting this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            TreeSitterParserError::SymbolAlreadyExists(_) => "symbol-already-exists",
+            TreeSitterParserError::MissingSymbol(_) => "missing-symbol",
+            TreeSitterParserError::WrongType(_) => "wrong-type",
+            TreeSitterParserError::Unsupported(_) => UNSUPPORTED_SYNTAX,
+        }
+    }
+}
+
+impl fmt::Display for TreeSitterParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeSitterParserError::SymbolAlreadyExists(_) => f.write_str("Symbol already exists"),
+            TreeSitterParserError::MissingSymbol(name) => write!(f, "Missing symbol: {name}"),
+            TreeSitterParserError::WrongType(ty) => write!(f, "Wrong type: {ty}"),
+            TreeSitterParserError::Unsupported(syntax) => write!(f, "Unsupported syntax: {syntax}"),
+        }
+    }
+}
+
 impl From<TreeSitterParserError> for String {
     fn from(err: TreeSitterParserError) -> Self {
-        match err {
-            TreeSitterParserError::SymbolAlreadyExists(_) => "Symbol already exists".to_string(),
-            TreeSitterParserError::MissingSymbol(name) => {
-                format!("Missing symbol: {name}")
-            }
-            TreeSitterParserError::WrongType(ty) => format!("Wrong type: {ty}"),
-        }
+        err.to_string()
     }
 }
 
+/// Code of the warnings for code kept as `Unknown` because it has a syntax error
+pub const SYNTAX_ERROR: &str = "syntax-error";
+/// Code of the warnings for code kept as `Unknown` because the parser does not support it
+pub const UNSUPPORTED_SYNTAX: &str = "unsupported-syntax";
+
 pub struct TreeSitterParser<'a> {
     objects: Vec<CLanguageObject>,
     context: Context<'a>,
+    /// Shared with every branch, so diagnostics from nested scopes reach the file
+    diagnostics: Rc<RefCell<Vec<Diagnostic>>>,
+    /// Comments of the file, shared with every branch so each is attached to a single object
+    comments: Rc<RefCell<CommentAttacher>>,
+    /// Layout of the file, shared with every branch, when it is kept
+    trivia: Option<Rc<RefCell<Trivia>>>,
+    /// Ids of the objects, shared with every branch so structural paths are computed once
+    ids: Rc<ObjectIds>,
 }
 
 impl<'a> Default for TreeSitterParser<'a> {
@@ -68,23 +154,64 @@ impl<'a> TreeSitterParser<'a> {
         TreeSitterParser {
             objects: Vec::new(),
             context: Context::new(),
+            diagnostics: Rc::default(),
+            comments: Rc::default(),
+            trivia: None,
+            ids: Rc::default(),
         }
     }
+
+    /// Objects of the file starting at `node`, with a warning for every part kept as `Unknown`.
+    /// The layout of the objects of the file and of its blocks is recorded in `trivia`, when
+    /// given, and the objects get ids of `id_mode`. When the file cannot be parsed, the last
+    /// diagnostic is the error that stopped it.
     pub fn parse_with_tree(
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<Vec<CLanguageObject>, TreeSitterParserError> {
-        let this = Self::new();
-        this.file_from_tree_sitter_nodes(node, source_code)
+        trivia: Option<&mut Trivia>,
+        id_mode: IdMode,
+    ) -> Result<(Vec<CLanguageObject>, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let mut this = Self::new();
+        this.ids = Rc::new(ObjectIds::new(id_mode));
+        this.comments.replace(CommentAttacher::new(
+            node.parent().unwrap_or(node),
+            source_code,
+            &this.ids,
+        ));
+        if trivia.is_some() {
+            this.trivia = Some(Rc::default());
+        }
+        let result = this.file_from_tree_sitter_nodes(node, source_code);
+        if let (Some(trivia), Some(recorded)) = (trivia, &this.trivia) {
+            *trivia = recorded.take();
+        }
+        let mut diagnostics = this.diagnostics.take();
+        match result {
+            Ok(objects) => Ok((objects, diagnostics)),
+            Err(err) => {
+                if !has_error(&diagnostics) {
+                    diagnostics.push(error_diagnostic(&err, node.parent().unwrap_or(node)));
+                }
+                Err(diagnostics)
+            }
+        }
     }
 
     fn branch(&'a self) -> Self {
         Self {
             objects: Vec::new(),
             context: self.context.branch(),
+            diagnostics: Rc::clone(&self.diagnostics),
+            comments: Rc::clone(&self.comments),
+            trivia: self.trivia.clone(),
+            ids: Rc::clone(&self.ids),
         }
     }
 
+    fn new_id(&self, node: tree_sitter::Node<'_>, role: &str, source_code: &str) -> Uuid {
+        self.ids.object_id(node, role, source_code)
+    }
+
     fn file_from_tree_sitter_nodes(
         &self,
         node: tree_sitter::Node<'_>,
@@ -93,14 +220,63 @@ impl<'a> TreeSitterParser<'a> {
         let mut current_node = node;
         let mut branch = self.branch();
         loop {
-            let object = branch.object_from_tree_sitter_node(current_node, source_code)?;
-            branch.objects.push(object);
+            if self.is_attached_comment(current_node) {
+                // Taken by the object it documents
+            } else if current_node.kind() != ";" {
+                // Type definitions leave their closing semicolon as a sibling node
+                for mut object in branch.objects_from_tree_sitter_node(current_node, source_code)? {
+                    // Statements and expressions out of a function come from syntax errors the
+                    // grammar recovered from
+                    if DeclarationObject::try_from(object.clone()).is_err() {
+                        let message = format!("{} out of a function", current_node.kind());
+                        let comments = object.comments().cloned().unwrap_or_default();
+                        let mut unknown = branch.unknown_from_tree_sitter_node(
+                            current_node,
+                            source_code,
+                            SYNTAX_ERROR,
+                            message,
+                        );
+                        unknown.comments = comments;
+                        object = CLanguageObject::Unknown(unknown);
+                    }
+                    branch.push_object(object, source_code);
+                }
+            } else if let Some(CLanguageObject::Unknown(unknown)) = branch.objects.last_mut()
+                && let Some(span) = unknown.span
+            {
+                let extended = Span {
+                    start: span.start,
+                    end: Span::from_node(current_node).end,
+                };
+                if let Some(warning) = branch
+                    .diagnostics
+                    .borrow_mut()
+                    .iter_mut()
+                    .rev()
+                    .find(|warning| warning.span == span)
+                {
+                    warning.span = extended;
+                }
+                unknown.span = Some(extended);
+                unknown.content = source_code[extended.range()].to_string();
+            }
             let Some(next_sibling) = current_node.next_sibling() else {
                 break;
             };
             current_node = next_sibling;
         }
-        Ok(branch.objects)
+        // Comments next to code without an object of its own are kept where they were written
+        let mut objects = branch.objects;
+        for comment in self.comments.borrow_mut().take_remaining() {
+            let start = comment.span.map(|span| span.start.offset);
+            let index = objects
+                .iter()
+                .position(|object| object.span().map(|span| span.start.offset) > start)
+                .unwrap_or(objects.len());
+            objects.insert(index, CLanguageObject::Comment(comment));
+        }
+        self.record_layouts(&objects, source_code, 0);
+        Ok(objects)
     }
 
     fn compound_statement_from_tree_sitter_nodes(
@@ -109,19 +285,33 @@ impl<'a> TreeSitterParser<'a> {
         source_code: &str,
     ) -> Result<CompoundStatement, TreeSitterParserError> {
         let mut current_node = node;
-        assert_eq!(current_node.kind(), "{");
-        current_node = current_node.next_sibling().unwrap();
+        current_node.expect_kind("{")?;
+        current_node = current_node.expect_next_sibling()?;
         let mut branch = self.branch();
         loop {
             if current_node.kind() == "}" {
                 break;
             }
-            let object = branch.object_from_tree_sitter_node(current_node, source_code)?;
-            branch.objects.push(object);
-            current_node = current_node.next_sibling().unwrap();
+            if current_node.kind() != ";" && !self.is_attached_comment(current_node) {
+                for object in branch.objects_from_tree_sitter_node(current_node, source_code)? {
+                    branch.push_object(object, source_code);
+                }
+            }
+            current_node = current_node.expect_next_sibling()?;
         }
+        // Statements are indented once for every block and case they are in
+        let depth = std::iter::successors(node.parent(), tree_sitter::Node::parent)
+            .filter(|ancestor| matches!(ancestor.kind(), "compound_statement" | "case_statement"))
+            .count();
+        branch.record_layouts(&branch.objects, source_code, depth);
         Ok(CompoundStatement {
-            id: Uuid::new_v4(),
+            id: self.new_id(
+                node.parent().unwrap_or(node),
+                "compound_statement",
+                source_code,
+            ),
+            span: None,
+            comments: Comments::default(),
             code_block: branch
                 .objects
                 .into_iter()
@@ -130,19 +320,229 @@ impl<'a> TreeSitterParser<'a> {
         })
     }
 
+    /// Object for `node`, or an `Unknown` object holding its text when it has a syntax error
+    /// or uses syntax the parser does not support
     pub fn object_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
+    ) -> Result<CLanguageObject, TreeSitterParserError> {
+        // Taken before the objects inside are parsed, so a comment next to several objects
+        // goes to the outermost one
+        let around = self.comments.borrow_mut().take_around(node);
+        if let Some(unparsable) = find_unparsable(node) {
+            let (code, message) = if unparsable.is_error() {
+                (SYNTAX_ERROR, "syntax error".to_string())
+            } else if unparsable.is_missing() {
+                (SYNTAX_ERROR, format!("missing `{}`", unparsable.kind()))
+            } else {
+                (
+                    UNSUPPORTED_SYNTAX,
+                    format!("unsupported {}", unparsable.kind()),
+                )
+            };
+            let mut unknown = self.unknown_from_tree_sitter_node(node, source_code, code, message);
+            // Comments inside are already part of its text
+            self.comments.borrow_mut().take_inside(node);
+            unknown.comments = around;
+            return Ok(CLanguageObject::Unknown(unknown));
+        }
+        let diagnostic_count = self.diagnostics.borrow().len();
+        match self.supported_object_from_tree_sitter_node(node, source_code) {
+            // Names and types the parser cannot resolve are kept as written too, so one
+            // unresolved name does not stop the whole file
+            Err(
+                err @ (TreeSitterParserError::Unsupported(_)
+                | TreeSitterParserError::MissingSymbol(_)
+                | TreeSitterParserError::WrongType(_)),
+            ) => {
+                // Unknown parts found before giving up are now inside this one
+                self.diagnostics.borrow_mut().truncate(diagnostic_count);
+                let message = match &err {
+                    TreeSitterParserError::Unsupported(syntax) => format!("unsupported {syntax}"),
+                    err => err.to_string(),
+                };
+                let mut unknown =
+                    self.unknown_from_tree_sitter_node(node, source_code, err.code(), message);
+                self.comments.borrow_mut().take_inside(node);
+                unknown.comments = around;
+                Ok(CLanguageObject::Unknown(unknown))
+            }
+            Err(err) => {
+                // The innermost node an error comes from is the one reported
+                let mut diagnostics = self.diagnostics.borrow_mut();
+                if !has_error(&diagnostics) {
+                    diagnostics.push(error_diagnostic(&err, node));
+                }
+                Err(err)
+            }
+            Ok(mut object) => {
+                *object.span_mut() = Some(Span::from_node(node));
+                // Comments between its tokens that no object inside took belong to it too
+                let inside = self.comments.borrow_mut().take_inside(node);
+                if let Some(comments) = object.comments_mut() {
+                    attach_comments(comments, around, inside);
+                }
+                Ok(object)
+            }
+        }
+    }
+
+    /// Objects for `node`. A declaration or typedef whose type defines a struct, union or
+    /// enum, like `struct s { int a; } g;`, is the definition followed by the declaration
+    fn objects_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<Vec<CLanguageObject>, TreeSitterParserError> {
+        let Some(type_node) = node.child_by_field_name("type").filter(|type_node| {
+            matches!(node.kind(), "declaration" | "type_definition")
+                && matches!(
+                    type_node.kind(),
+                    "struct_specifier" | "union_specifier" | "enum_specifier"
+                )
+                && type_node.child_by_field_name("name").is_some()
+                && type_node.child_by_field_name("body").is_some()
+        }) else {
+            return Ok(vec![self.object_from_tree_sitter_node(node, source_code)?]);
+        };
+        let mut definition = self.object_from_tree_sitter_node(type_node, source_code)?;
+        if let CLanguageObject::Unknown(unknown) = &mut definition {
+            // The declaration cannot be written without its definition, so it is kept with it
+            let span = Span::from_node(node);
+            if let Some(warning) = self
+                .diagnostics
+                .borrow_mut()
+                .iter_mut()
+                .rev()
+                .find(|warning| Some(warning.span) == unknown.span)
+            {
+                warning.span = span;
+            }
+            unknown.span = Some(span);
+            unknown.content = node.content(source_code);
+            self.comments.borrow_mut().take_inside(node);
+            return Ok(vec![definition]);
+        }
+        let declaration = self.object_from_tree_sitter_node(node, source_code)?;
+        Ok(vec![definition, declaration])
+    }
+
+    /// Adds `object` to the objects of this branch. An unknown object right after another one
+    /// is merged into it, with the text between them, so a run of unparsable code is kept as
+    /// it was written. Their warnings are merged too.
+    fn push_object(&mut self, object: CLanguageObject, source_code: &str) {
+        if let CLanguageObject::Unknown(unknown) = &object
+            && let Some(CLanguageObject::Unknown(previous)) = self.objects.last_mut()
+            && let (Some(previous_span), Some(span)) = (previous.span, unknown.span)
+        {
+            let merged_span = Span {
+                start: previous_span.start,
+                end: span.end,
+            };
+            previous.span = Some(merged_span);
+            previous.content = source_code[merged_span.range()].to_string();
+            // Comments between the two are now part of the text
+            previous.comments.trailing = unknown.comments.trailing.clone();
+            // Other warnings, like the ones of nested code, may have been reported in between
+            let mut diagnostics = self.diagnostics.borrow_mut();
+            if let Some(index) = diagnostics.iter().rposition(|warning| warning.span == span) {
+                diagnostics.remove(index);
+            }
+            if let Some(warning) = diagnostics
+                .iter_mut()
+                .rev()
+                .find(|warning| warning.span == previous_span)
+            {
+                warning.span = merged_span;
+            }
+            return;
+        }
+        self.objects.push(object);
+    }
+
+    /// Records the layout of the objects of a file or of a block `depth` blocks deep, when it
+    /// is kept
+    fn record_layouts(&self, objects: &[CLanguageObject], source_code: &str, depth: usize) {
+        if let Some(trivia) = &self.trivia {
+            trivia.borrow_mut().record(objects, source_code, depth);
+        }
+    }
+
+    /// Whether `node` is a comment attached to an object rather than an object of its own
+    fn is_attached_comment(&self, node: tree_sitter::Node<'_>) -> bool {
+        node.kind() == "comment" && !self.comments.borrow().is_standalone(node)
+    }
+
+    fn unknown_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+        code: &'static str,
+        message: String,
+    ) -> Unknown {
+        // A root error node spans the blank lines around the code too, which are not part of it
+        let last_child = node.child(node.child_count().saturating_sub(1));
+        let span = match (node.parent(), node.child(0), last_child) {
+            (None, Some(first), Some(last)) => Span {
+                start: Span::from_node(first).start,
+                end: Span::from_node(last).end,
+            },
+            _ => Span::from_node(node),
+        };
+        self.diagnostics.borrow_mut().push(Diagnostic {
+            severity: Severity::Warning,
+            code,
+            message,
+            span,
+        });
+        Unknown {
+            id: self.new_id(node, "unknown", source_code),
+            span: Some(span),
+            comments: Comments::default(),
+            content: source_code[span.range()].to_string(),
+        }
+    }
+
+    fn supported_object_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
     ) -> Result<CLanguageObject, TreeSitterParserError> {
         let result: CLanguageObject = match node.kind() {
             "preproc_include" => CLanguageObject::PreprocInclude(
                 self.preproc_include_from_tree_sitter_node(node, source_code)?,
             ),
+            "preproc_def" | "preproc_function_def" => CLanguageObject::PreprocDefine(
+                self.preproc_define_from_tree_sitter_node(node, source_code)?,
+            ),
+            "preproc_call" => CLanguageObject::PreprocCall(
+                self.preproc_call_from_tree_sitter_node(node, source_code)?,
+            ),
+            "preproc_if" | "preproc_ifdef" if is_in_function_body(node) => {
+                CLanguageObject::PreprocIfStatement(
+                    self.preproc_if_statement_from_tree_sitter_node(node, source_code)?,
+                )
+            }
+            "preproc_if" | "preproc_ifdef" => CLanguageObject::PreprocIf(
+                self.preproc_if_from_tree_sitter_node(node, source_code)?,
+            ),
             "function_definition" => CLanguageObject::FunctionDefinition(
                 self.function_definition_from_tree_sitter_node(node, source_code)?,
             ),
             "declaration" => self.declaration_from_tree_sitter_node(node, source_code)?,
+            "struct_specifier" => CLanguageObject::StructDefinition(
+                self.struct_definition_from_tree_sitter_node(node, source_code)?,
+            ),
+            "union_specifier" => CLanguageObject::UnionDefinition(
+                self.union_definition_from_tree_sitter_node(node, source_code)?,
+            ),
+            "enum_specifier" => CLanguageObject::EnumDefinition(
+                self.enum_definition_from_tree_sitter_node(node, source_code)?,
+            ),
+            "type_definition" => CLanguageObject::TypeDefinition(
+                self.type_definition_from_tree_sitter_node(node, source_code)?,
+            ),
             "identifier" => {
                 CLanguageObject::Reference(self.reference_from_tree_sitter_node(node, source_code)?)
             }
@@ -150,20 +550,49 @@ impl<'a> TreeSitterParser<'a> {
                 self.return_statement_from_tree_sitter_node(node, source_code)?,
             ),
             "number_literal" => {
-                let value = node.content(source_code);
-                CLanguageObject::NumberLiteral(NumberLiteral {
-                    id: Uuid::new_v4(),
-                    value,
-                })
+                self.signed_number_literal_from_tree_sitter_node(node, source_code)?
             }
-            "string_literal" => CLanguageObject::StringLiteral(
+            "char_literal" => CLanguageObject::CharLiteral(
+                self.char_literal_from_tree_sitter_node(node, source_code)?,
+            ),
+            "string_literal" | "concatenated_string" => CLanguageObject::StringLiteral(
                 self.string_literal_from_tree_sitter_node(node, source_code)?,
             ),
             "call_expression" => CLanguageObject::CallExpression(
                 self.call_expression_from_tree_sitter_node(node, source_code)?,
             ),
             "binary_expression" => CLanguageObject::BinaryExpression(
-                self.binary_expression_from_tree_sitter_node(node, source_code),
+                self.binary_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "unary_expression" | "pointer_expression" => CLanguageObject::UnaryExpression(
+                self.unary_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "update_expression" => CLanguageObject::UpdateExpression(
+                self.update_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "cast_expression" => CLanguageObject::CastExpression(
+                self.cast_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "sizeof_expression" => CLanguageObject::SizeofExpression(
+                self.sizeof_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "conditional_expression" => CLanguageObject::ConditionalExpression(
+                self.conditional_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "field_expression" => CLanguageObject::FieldExpression(
+                self.field_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "subscript_expression" => CLanguageObject::SubscriptExpression(
+                self.subscript_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "parenthesized_expression" => CLanguageObject::ParenthesizedExpression(
+                self.parenthesized_expression_from_tree_sitter_node(node, source_code)?,
+            ),
+            "initializer_list" => CLanguageObject::InitializerList(
+                self.initializer_list_from_tree_sitter_node(node, source_code)?,
+            ),
+            "initializer_pair" => CLanguageObject::InitializerPair(
+                self.initializer_pair_from_tree_sitter_node(node, source_code)?,
             ),
             "expression_statement" => {
                 self.expression_statement_from_tree_sitter_node(node, source_code)?
@@ -175,274 +604,1158 @@ impl<'a> TreeSitterParser<'a> {
             "if_statement" => CLanguageObject::IfStatement(
                 self.if_statement_from_tree_sitter_node(node, source_code)?,
             ),
+            "while_statement" => CLanguageObject::WhileStatement(
+                self.while_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "do_statement" => CLanguageObject::DoStatement(
+                self.do_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "for_statement" => CLanguageObject::ForStatement(
+                self.for_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "switch_statement" => CLanguageObject::SwitchStatement(
+                self.switch_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "case_statement" => CLanguageObject::CaseStatement(
+                self.case_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "break_statement" => CLanguageObject::BreakStatement(BreakStatement {
+                id: self.new_id(node, "break_statement", source_code),
+                span: None,
+                comments: Comments::default(),
+            }),
+            "continue_statement" => CLanguageObject::ContinueStatement(ContinueStatement {
+                id: self.new_id(node, "continue_statement", source_code),
+                span: None,
+                comments: Comments::default(),
+            }),
+            "labeled_statement" => CLanguageObject::LabeledStatement(
+                self.labeled_statement_from_tree_sitter_node(node, source_code)?,
+            ),
+            "goto_statement" => CLanguageObject::GotoStatement(
+                self.goto_statement_from_tree_sitter_node(node, source_code)?,
+            ),
             "assignment_expression" => CLanguageObject::AssignmentExpression(
-                self.assignment_expression_from_tree_sitter_node(node, source_code),
+                self.assignment_expression_from_tree_sitter_node(node, source_code)?,
             ),
             "compound_statement" => CLanguageObject::CompoundStatement(
                 self.branch().compound_statement_from_tree_sitter_nodes(
-                    node.child(0).unwrap(),
+                    node.expect_code_child(0)?,
                     source_code,
                 )?,
             ),
-            "{" | "}" | "(" | ")" | "," => {
-                // Ignore delimiters
-                panic!("Delimiter node should not be processed: {}", node.kind());
-            }
-            other => panic!("Unknown node type: {other}"),
+            other => return Err(TreeSitterParserError::Unsupported(other.to_string())),
         };
         Ok(result)
     }
 
+    /// A declaration with several declarators, like `int a = 1, *b;`, becomes a
+    /// `DeclarationList` holding one `Declaration` per declarator
     fn declaration_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
     ) -> Result<CLanguageObject, TreeSitterParserError> {
-        let type_node = node.child(0).unwrap();
-        assert_eq!(type_node.kind(), "primitive_type");
-        let primitive_type = CType::from_tree_sitter_node(type_node, source_code);
-
-        let declarator_node = node.child(1).unwrap();
-        match declarator_node.kind() {
-            "init_declarator" => {
-                let (identifier, value) = {
-                    let node = declarator_node;
-                    let identifier_node = node.child(0).unwrap();
-                    assert_eq!(identifier_node.kind(), "identifier");
-                    let identifier = identifier_node.content(source_code).to_string();
-                    assert_eq!(node.child(1).unwrap().kind(), "=");
-
-                    let value_node = node.child(2).unwrap();
-                    let value = self
-                        .branch()
-                        .object_from_tree_sitter_node(value_node, source_code)
-                        .unwrap();
-                    (identifier, value)
-                };
-                Ok(CLanguageObject::Declaration(Declaration {
-                    id: self.context.insert_symbol(&identifier, false)?,
-                    primitive_type,
-                    identifier,
-                    value: Some(Box::new(value.try_into()?)),
-                }))
+        let specifiers = Specifiers::from_declaration_node(node, source_code)?;
+        let base_type = CType::from_declaration_node(node, source_code, &self.context)?;
+
+        let mut cursor = node.walk();
+        let declarator_nodes = node
+            .children_by_field_name("declarator", &mut cursor)
+            .collect::<Vec<_>>();
+        if let [declarator_node] = declarator_nodes.as_slice() {
+            return self.declarator_from_tree_sitter_node(
+                specifiers,
+                base_type,
+                *declarator_node,
+                source_code,
+            );
+        }
+        let mut declarations = Vec::new();
+        for declarator_node in declarator_nodes {
+            match self.declarator_from_tree_sitter_node(
+                specifiers,
+                base_type.clone(),
+                declarator_node,
+                source_code,
+            )? {
+                CLanguageObject::Declaration(declaration) => declarations.push(declaration),
+                _ => {
+                    return Err(TreeSitterParserError::WrongType(
+                        declarator_node.content(source_code),
+                    ));
+                }
             }
+        }
+        Ok(CLanguageObject::DeclarationList(DeclarationList {
+            id: self.new_id(node, "declaration_list", source_code),
+            span: None,
+            comments: Comments::default(),
+            declarations,
+        }))
+    }
+
+    fn declarator_from_tree_sitter_node(
+        &mut self,
+        specifiers: Specifiers,
+        base_type: CType,
+        declarator_node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<CLanguageObject, TreeSitterParserError> {
+        // Declarations sharing a type each span their own declarator, like `b = 2` in `int a, b = 2;`
+        let span = Some(Span::from_node(declarator_node));
+        let comments = self.comments.borrow_mut().take_around(declarator_node);
+        let (declarator_node, value_node) = match declarator_node.kind() {
+            "init_declarator" => (
+                declarator_node.expect_field("declarator")?,
+                declarator_node.child_by_field_name("value"),
+            ),
+            _ => (declarator_node, None),
+        };
+        let (primitive_type, name_node) =
+            base_type.apply_declarator(declarator_node, source_code, &self.context)?;
+        let name_node = name_node.ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!("{} without a name", declarator_node.kind()))
+        })?;
+        match name_node.kind() {
             "identifier" => {
-                let identifier = declarator_node.content(source_code);
+                let identifier = name_node.content(source_code);
+                let value = value_node
+                    .map(|value_node| {
+                        self.branch()
+                            .object_from_tree_sitter_node(value_node, source_code)
+                    })
+                    .transpose()?
+                    .map(|value| value.try_into().map(Box::new))
+                    .transpose()?;
+                // Declared again at file scope with the same type, like `extern int x;` before
+                // `int x = 1;`, it is the same variable
+                let at_file_scope =
+                    !std::iter::successors(name_node.parent(), |node| node.parent())
+                        .any(|node| node.kind() == "compound_statement");
+                let redeclared = at_file_scope
+                    .then(|| self.context.get_local_symbol_id(&identifier, false))
+                    .flatten();
+                let id = match redeclared {
+                    Some(id) if self.context.get_symbol_type(&id) == Some(&primitive_type) => id,
+                    Some(_) => {
+                        return Err(TreeSitterParserError::WrongType(format!(
+                            "{identifier} declared again with another type"
+                        )));
+                    }
+                    None => self.context.insert_symbol_with_id(
+                        &identifier,
+                        self.new_id(name_node, "declaration", source_code),
+                        false,
+                    )?,
+                };
+                self.context.insert_symbol_type(id, primitive_type.clone());
                 Ok(CLanguageObject::Declaration(Declaration {
-                    id: self.context.insert_symbol(&identifier, false)?,
+                    id,
+                    span,
+                    comments,
+                    specifiers,
                     primitive_type,
                     identifier,
-                    value: None,
+                    value,
                 }))
             }
-            "function_declarator" => Ok(CLanguageObject::FunctionDeclaration(
-                self.function_declaration_from_tree_sitter_node(
+            "function_declarator" => {
+                let mut function_declaration = self.function_declaration_from_tree_sitter_node(
+                    specifiers,
                     primitive_type,
-                    declarator_node,
+                    name_node,
                     source_code,
-                )?,
-            )),
-            other => {
-                panic!("Unexpected declarator type: {other}");
+                )?;
+                function_declaration.comments = comments;
+                Ok(CLanguageObject::FunctionDeclaration(function_declaration))
             }
+            other => Err(TreeSitterParserError::Unsupported(other.to_string())),
         }
     }
 
-    fn preproc_include_from_tree_sitter_node(
+    /// Registers the struct, union or enum `identifier` defined at `name_node`. Uses of it
+    /// before the definition already have an id, which it takes
+    fn insert_tag_definition(
         &mut self,
-        node: tree_sitter::Node<'_>,
+        identifier: &String,
+        name_node: tree_sitter::Node<'_>,
+        role: &str,
         source_code: &str,
-    ) -> Result<PreprocInclude, TreeSitterParserError> {
-        assert_eq!(node.child(0).unwrap().kind(), "#include");
-        let lib = node.child(1).unwrap();
-        assert_eq!(lib.kind(), "system_lib_string");
-        Ok(PreprocInclude {
-            id: Uuid::new_v4(),
-            content: lib.content(source_code),
-        })
+    ) -> Result<Uuid, TreeSitterParserError> {
+        let id = match self.context.take_forward_tag_id(identifier) {
+            Some(id) => id,
+            None => self.new_id(name_node, role, source_code),
+        };
+        Ok(self.context.insert_tag_with_id(identifier, id)?)
     }
 
-    fn string_literal_from_tree_sitter_node(
+    fn struct_definition_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<StringLiteral, TreeSitterParserError> {
-        let value = node.content(source_code);
-        if let Some(value) = value.strip_prefix('"') {
-            return Ok(StringLiteral {
-                id: Uuid::new_v4(),
-                value: value.strip_suffix('"').unwrap().to_string(),
+    ) -> Result<StructDefinition, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("struct")?;
+        let name_node = node
+            .child_by_field_name("name")
+            .ok_or_else(|| TreeSitterParserError::Unsupported("anonymous struct".to_string()))?;
+        let identifier = name_node.content(source_code);
+        let Some(body) = node.child_by_field_name("body") else {
+            // The definition, found before or after, is the one registering the tag
+            return Ok(StructDefinition {
+                id: self.new_id(name_node, "struct_declaration", source_code),
+                span: None,
+                comments: Comments::default(),
+                identifier,
+                fields: Vec::new(),
+                is_forward: true,
             });
-        }
-        panic!("unexpected delimitators for string literal: {value}");
+        };
+        let id =
+            self.insert_tag_definition(&identifier, name_node, "struct_definition", source_code)?;
+        let fields = self.field_list_from_tree_sitter_node(body, source_code)?;
+        self.context.insert_members(id, fields.clone());
+        Ok(StructDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            identifier,
+            fields,
+            is_forward: false,
+        })
     }
 
-    fn return_statement_from_tree_sitter_node(
+    fn union_definition_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<ReturnStatement, TreeSitterParserError> {
-        assert_eq!(node.child(0).unwrap().kind(), "return");
-        let child = node.child(1).unwrap();
-        let value: Option<ExpressionObject> = match child.kind() {
-            ";" => None,
-            _ => Some(
-                self.object_from_tree_sitter_node(child, source_code)
-                    .unwrap()
-                    .try_into()?,
-            ),
+    ) -> Result<UnionDefinition, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("union")?;
+        let name_node = node
+            .child_by_field_name("name")
+            .ok_or_else(|| TreeSitterParserError::Unsupported("anonymous union".to_string()))?;
+        let identifier = name_node.content(source_code);
+        let Some(body) = node.child_by_field_name("body") else {
+            // The definition, found before or after, is the one registering the tag
+            return Ok(UnionDefinition {
+                id: self.new_id(name_node, "union_declaration", source_code),
+                span: None,
+                comments: Comments::default(),
+                identifier,
+                fields: Vec::new(),
+                is_forward: true,
+            });
         };
-        Ok(ReturnStatement {
-            id: Uuid::new_v4(),
-            value,
+        let id =
+            self.insert_tag_definition(&identifier, name_node, "union_definition", source_code)?;
+        let fields = self.field_list_from_tree_sitter_node(body, source_code)?;
+        self.context.insert_members(id, fields.clone());
+        Ok(UnionDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            identifier,
+            fields,
+            is_forward: false,
         })
     }
 
-    fn reference_from_tree_sitter_node(
+    fn field_list_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<Reference, TreeSitterParserError> {
-        assert_eq!(node.kind(), "identifier");
-        let identifier = node.content(source_code).to_string();
-        let declaration_id = self
-            .context
-            .get_symbol_id(&identifier, false)
-            .ok_or(TreeSitterParserError::MissingSymbol(identifier.to_string()))?;
-        Ok(Reference {
-            id: Uuid::new_v4(),
-            declaration_id,
-            identifier,
-        })
+    ) -> Result<Vec<FieldDeclaration>, TreeSitterParserError> {
+        node.expect_kind("field_declaration_list")?;
+        let mut fields = Vec::new();
+        let mut cursor = node.walk();
+        for field_node in node
+            .named_children(&mut cursor)
+            .filter(|child| !child.is_extra())
+        {
+            field_node.expect_kind("field_declaration")?;
+            // Comments around a field declaring several fields go to the first and the last one
+            let mut field_comments = self.comments.borrow_mut().take_around(field_node);
+            let first_field = fields.len();
+            let base_type = CType::from_declaration_node(field_node, source_code, &self.context)?;
+            let mut declarator_cursor = field_node.walk();
+            for declarator in
+                field_node.children_by_field_name("declarator", &mut declarator_cursor)
+            {
+                let (field_type, name_node) =
+                    base_type
+                        .clone()
+                        .apply_declarator(declarator, source_code, &self.context)?;
+                let name_node = name_node
+                    .ok_or_else(|| {
+                        TreeSitterParserError::Unsupported(format!(
+                            "{} without a name",
+                            declarator.kind()
+                        ))
+                    })?
+                    .expect_kind("field_identifier")?;
+                fields.push(FieldDeclaration {
+                    id: self.new_id(name_node, "field_declaration", source_code),
+                    span: Some(Span::from_node(declarator)),
+                    comments: self.comments.borrow_mut().take_around(declarator),
+                    identifier: name_node.content(source_code),
+                    field_type,
+                    shares_declaration: fields.len() > first_field,
+                });
+            }
+            let inside = self.comments.borrow_mut().take_inside(field_node);
+            field_comments.leading.extend(inside.leading);
+            field_comments.trailing.splice(0..0, inside.trailing);
+            if let Some(first) = fields.get_mut(first_field) {
+                first.comments.leading.splice(0..0, field_comments.leading);
+            }
+            if let Some(last) = fields.last_mut() {
+                last.comments.trailing.extend(field_comments.trailing);
+            }
+        }
+        Ok(fields)
     }
 
-    pub fn number_literal_from_tree_sitter_node(
+    fn enum_definition_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> NumberLiteral {
-        let value = node.content(source_code);
-        NumberLiteral {
-            id: Uuid::new_v4(),
-            value,
+    ) -> Result<EnumDefinition, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("enum")?;
+        let name_node = node
+            .child_by_field_name("name")
+            .ok_or_else(|| TreeSitterParserError::Unsupported("anonymous enum".to_string()))?;
+        let identifier = name_node.content(source_code);
+        let Some(body) = node.child_by_field_name("body") else {
+            // The definition, found before or after, is the one registering the tag
+            return Ok(EnumDefinition {
+                id: self.new_id(name_node, "enum_declaration", source_code),
+                span: None,
+                comments: Comments::default(),
+                identifier,
+                enumerators: Vec::new(),
+                is_forward: true,
+            });
+        };
+        body.expect_kind("enumerator_list")?;
+        let id =
+            self.insert_tag_definition(&identifier, name_node, "enum_definition", source_code)?;
+
+        let mut enumerators = Vec::new();
+        let mut cursor = body.walk();
+        for enumerator_node in body
+            .named_children(&mut cursor)
+            .filter(|child| !child.is_extra())
+        {
+            enumerator_node.expect_kind("enumerator")?;
+            let comments = self.comments.borrow_mut().take_around(enumerator_node);
+            let value = enumerator_node
+                .child_by_field_name("value")
+                .map(|value| {
+                    self.branch()
+                        .object_from_tree_sitter_node(value, source_code)
+                })
+                .transpose()?
+                .map(|value| value.try_into().map(Box::new))
+                .transpose()?;
+            let name_node = enumerator_node.expect_field("name")?;
+            let identifier = name_node.content(source_code);
+            // Enumerators are ordinary identifiers, so references to them resolve like variables
+            enumerators.push(Enumerator {
+                id: self.context.insert_symbol_with_id(
+                    &identifier,
+                    self.new_id(name_node, "enumerator", source_code),
+                    false,
+                )?,
+                span: Some(Span::from_node(enumerator_node)),
+                comments,
+                identifier,
+                value,
+            });
         }
+        Ok(EnumDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            identifier,
+            enumerators,
+            is_forward: false,
+        })
     }
 
-    fn if_statement_from_tree_sitter_node(
+    fn type_definition_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<IfStatement, TreeSitterParserError> {
-        assert_eq!(node.child(0).unwrap().kind(), "if");
-        let parenthesized_expression = node.child(1).unwrap();
-        assert_eq!(parenthesized_expression.kind(), "parenthesized_expression");
-        let body = node.child(2).unwrap();
-
-        assert_eq!(parenthesized_expression.child(0).unwrap().kind(), "(");
-        let condition = self
-            .branch()
-            .object_from_tree_sitter_node(parenthesized_expression.child(1).unwrap(), source_code)
-            .unwrap();
-        assert_eq!(parenthesized_expression.child(2).unwrap().kind(), ")");
-        let body_object = self.object_from_tree_sitter_node(body, source_code)?;
-
-        let else_clause = if let Some(else_node) = node.child(3) {
-            assert_eq!(else_node.kind(), "else_clause");
-            Some(self.else_clause_from_tree_sitter_node(else_node, source_code)?)
-        } else {
-            None
+    ) -> Result<TypeDefinition, TreeSitterParserError> {
+        let base_type = CType::from_declaration_node(node, source_code, &self.context)?;
+        let mut cursor = node.walk();
+        let [declarator_node] = node
+            .children_by_field_name("declarator", &mut cursor)
+            .collect::<Vec<_>>()[..]
+        else {
+            return Err(TreeSitterParserError::WrongType(node.content(source_code)));
         };
+        let (aliased_type, name_node) =
+            base_type.apply_declarator(declarator_node, source_code, &self.context)?;
+        // tree-sitter reads common typedef names like `size_t` as primitive types
+        let name_node = name_node
+            .filter(|name_node| matches!(name_node.kind(), "type_identifier" | "primitive_type"))
+            .ok_or_else(|| TreeSitterParserError::WrongType(node.content(source_code)))?;
+        let identifier = name_node.content(source_code);
 
-        Ok(IfStatement {
-            id: Uuid::new_v4(),
-            condition: Box::new(condition.try_into()?),
-            body: Box::new(body_object.try_into()?),
-            else_statement: else_clause,
+        let id = self.context.insert_type_name_with_id(
+            &identifier,
+            self.new_id(name_node, "type_definition", source_code),
+        )?;
+        self.context.insert_symbol_type(id, aliased_type.clone());
+        Ok(TypeDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            identifier,
+            aliased_type,
         })
     }
 
-    fn function_parameter_from_tree_sitter_node(
+    fn preproc_include_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<FunctionParameter, TreeSitterParserError> {
-        let type_node = node.child(0).unwrap();
-        assert_eq!(type_node.kind(), "primitive_type");
-        let param_type = CType::from_tree_sitter_node(type_node, source_code);
-
-        let name_node = node.child(1).unwrap();
-        assert_eq!(name_node.kind(), "identifier");
-        let identifier = name_node.content(source_code).to_string();
-
-        Ok(FunctionParameter {
-            id: self.context.insert_symbol(&identifier, false)?,
-            identifier,
-            param_type,
+    ) -> Result<PreprocInclude, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("#include")?;
+        let lib = node.expect_code_child(1)?;
+        if lib.kind() != "system_lib_string" {
+            return Err(TreeSitterParserError::Unsupported(lib.kind().to_string()));
+        }
+        Ok(PreprocInclude {
+            id: self.new_id(node, "preproc_include", source_code),
+            span: None,
+            comments: Comments::default(),
+            content: lib.content(source_code),
         })
     }
 
-    pub fn function_declaration_from_tree_sitter_node(
+    fn preproc_define_from_tree_sitter_node(
         &mut self,
-        return_type: CType,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<FunctionDeclaration, TreeSitterParserError> {
-        let (identifier, parameter_list, id, _) =
-            self.parse_function_parameter_list(node, source_code)?;
-        Ok(FunctionDeclaration {
+    ) -> Result<PreprocDefine, TreeSitterParserError> {
+        let name_node = node.expect_field("name")?;
+        let identifier = name_node.content(source_code);
+        let parameters = node.child_by_field_name("parameters").map(|parameters| {
+            let mut cursor = parameters.walk();
+            parameters
+                .children(&mut cursor)
+                .filter(|parameter| matches!(parameter.kind(), "identifier" | "..."))
+                .map(|parameter| parameter.content(source_code))
+                .collect::<Vec<_>>()
+        });
+        let value = node
+            .child_by_field_name("value")
+            .map(|value| value.content(source_code).trim().to_string())
+            .unwrap_or_default();
+        // Uses of the macro link to its latest definition, like references to a variable
+        let id = self.context.overwrite_symbol_with_id(
+            &identifier,
+            self.new_id(name_node, "preproc_define", source_code),
+            parameters.is_some(),
+        );
+        Ok(PreprocDefine {
             id,
-            return_type,
+            span: None,
+            comments: Comments::default(),
             identifier,
-            parameter_list,
+            parameters,
+            value,
         })
     }
 
-    pub fn function_definition_from_tree_sitter_node(
+    fn preproc_call_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<FunctionDefinition, TreeSitterParserError> {
-        let return_type = CType::from_tree_sitter_node(node.child(0).unwrap(), source_code);
-        let (identifier, parameter_list, id, subcontext) =
-            self.parse_function_parameter_list(node.child(1).unwrap(), source_code)?;
-        let code_block_node = node.child(2).unwrap();
-        assert_eq!(code_block_node.kind(), "compound_statement");
-        let code_block = subcontext.compound_statement_from_tree_sitter_nodes(
-            code_block_node.child(0).unwrap(),
-            source_code,
-        )?;
+    ) -> Result<PreprocCall, TreeSitterParserError> {
+        Ok(PreprocCall {
+            id: self.new_id(node, "preproc_call", source_code),
+            span: None,
+            comments: Comments::default(),
+            directive: node.expect_field("directive")?.content(source_code),
+            argument: node
+                .child_by_field_name("argument")
+                .map(|argument| argument.content(source_code).trim().to_string())
+                .unwrap_or_default(),
+        })
+    }
 
-        Ok(FunctionDefinition {
-            id,
-            return_type,
-            identifier,
-            parameter_list,
-            compound_statement: code_block,
+    /// Splits a conditional block, or one of its `#elif`/`#else` branches, into its directive,
+    /// its condition, the nodes in its body and the node of the next branch
+    fn preproc_branch_from_tree_sitter_node<'t>(
+        node: tree_sitter::Node<'t>,
+        source_code: &str,
+    ) -> Result<
+        (
+            String,
+            String,
+            Vec<tree_sitter::Node<'t>>,
+            Option<tree_sitter::Node<'t>>,
+        ),
+        TreeSitterParserError,
+    > {
+        let directive = node.expect_code_child(0)?.kind().to_string();
+        let condition = node
+            .child_by_field_name("condition")
+            .or(node.child_by_field_name("name"))
+            .map(|condition| condition.content(source_code))
+            .unwrap_or_default();
+        let mut cursor = node.walk();
+        // The directives themselves and the line break after a condition are unnamed tokens
+        let body = node
+            .children(&mut cursor)
+            .enumerate()
+            .filter(|(i, child)| child.is_named() && node.field_name_for_child(*i as u32).is_none())
+            .map(|(_, child)| child)
+            .collect();
+        Ok((
+            directive,
+            condition,
+            body,
+            node.child_by_field_name("alternative"),
+        ))
+    }
+
+    fn preproc_if_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<PreprocIf, TreeSitterParserError> {
+        let (directive, condition, body_nodes, alternative_node) =
+            Self::preproc_branch_from_tree_sitter_node(node, source_code)?;
+        // Whatever the first branch declares stays visible after the block, the other
+        // branches get their own scope so they can declare the same names again
+        let mut body: Vec<DeclarationObject> = Vec::new();
+        for child in body_nodes {
+            if self.is_attached_comment(child) {
+                continue;
+            }
+            for object in self.objects_from_tree_sitter_node(child, source_code)? {
+                body.push(object.try_into()?);
+            }
+        }
+        let alternative = alternative_node
+            .map(|alternative| {
+                self.branch()
+                    .preproc_if_from_tree_sitter_node(alternative, source_code)
+                    .map(Box::new)
+            })
+            .transpose()?;
+        Ok(PreprocIf {
+            id: self.new_id(node, "preproc_if", source_code),
+            span: None,
+            comments: Comments::default(),
+            directive,
+            condition,
+            body,
+            alternative,
         })
     }
 
-    pub fn else_clause_from_tree_sitter_node(
+    fn preproc_if_statement_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<ElseStatement, TreeSitterParserError> {
-        assert_eq!(node.child(0).unwrap().kind(), "else");
-        let compound_statement = node.child(1).unwrap();
-        // match compound_statement.kind() {
-        //     "compound_statement" => {
-        //         let code_block = self.compound_statement_from_tree_sitter_nodes(
-        //             compound_statement.child(0).unwrap(),
-        //             source_code,
-        //         )?;
-        //         let body = CompoundStatementObject::CompoundStatement(code_block);
+    ) -> Result<PreprocIfStatement, TreeSitterParserError> {
+        let (directive, condition, body_nodes, alternative_node) =
+            Self::preproc_branch_from_tree_sitter_node(node, source_code)?;
+        let mut body: Vec<CompoundStatementObject> = Vec::new();
+        for child in body_nodes {
+            if self.is_attached_comment(child) {
+                continue;
+            }
+            for object in self.objects_from_tree_sitter_node(child, source_code)? {
+                body.push(object.try_into()?);
+            }
+        }
+        let alternative = alternative_node
+            .map(|alternative| {
+                self.branch()
+                    .preproc_if_statement_from_tree_sitter_node(alternative, source_code)
+                    .map(Box::new)
+            })
+            .transpose()?;
+        Ok(PreprocIfStatement {
+            id: self.new_id(node, "preproc_if_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            directive,
+            condition,
+            body,
+            alternative,
+        })
+    }
 
-        //         Ok(ElseStatement::ElseClause(Box::new(ElseClause {
-        //             id: Uuid::new_v4(),
-        //             body: Box::new(body), // TODO support other types of statements
-        //         })))
-        //     }
-        //     "if_statement" => {
-        //         let if_statement =
+    fn char_literal_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<CharLiteral, TreeSitterParserError> {
+        let spelling = node.content(source_code);
+        let mut char_literal = CharLiteral::from_spelling(spelling.clone())
+            .ok_or(TreeSitterParserError::WrongType(spelling))?;
+        char_literal.id = self.new_id(node, "char_literal", source_code);
+        Ok(char_literal)
+    }
+
+    /// A single literal, or adjacent ones (`"abc" "def"`) kept as segments of one string
+    fn string_literal_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<StringLiteral, TreeSitterParserError> {
+        let segments = match node.kind() {
+            "concatenated_string" => {
+                let mut cursor = node.walk();
+                let mut segments = vec![];
+                for segment in node
+                    .named_children(&mut cursor)
+                    .filter(|child| !child.is_extra())
+                {
+                    if segment.kind() != "string_literal" {
+                        // Macros like `PRId64` between the literals are not supported yet
+                        return Err(TreeSitterParserError::WrongType(
+                            segment.content(source_code),
+                        ));
+                    }
+                    segments.push(segment.content(source_code));
+                }
+                segments
+            }
+            _ => vec![node.content(source_code)],
+        };
+        let mut string_literal = StringLiteral::from_segments(segments)
+            .ok_or_else(|| TreeSitterParserError::WrongType(node.content(source_code)))?;
+        string_literal.id = self.new_id(node, "string_literal", source_code);
+        Ok(string_literal)
+    }
+
+    fn return_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ReturnStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("return")?;
+        let child = node.expect_code_child(1)?;
+        let value: Option<ExpressionObject> = match child.kind() {
+            ";" => None,
+            _ => Some(
+                self.object_from_tree_sitter_node(child, source_code)?
+                    .try_into()?,
+            ),
+        };
+        Ok(ReturnStatement {
+            id: self.new_id(node, "return_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            value,
+        })
+    }
+
+    fn reference_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<Reference, TreeSitterParserError> {
+        node.expect_kind("identifier")?;
+        let identifier = node.content(source_code).to_string();
+        let declaration_id = self
+            .context
+            .get_symbol_id(&identifier, false)
+            .ok_or(TreeSitterParserError::MissingSymbol(identifier.to_string()))?;
+        Ok(Reference {
+            id: self.new_id(node, "reference", source_code),
+            span: None,
+            comments: Comments::default(),
+            declaration_id,
+            identifier,
+        })
+    }
+
+    fn labeled_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<LabeledStatement, TreeSitterParserError> {
+        let label = node.expect_field("label")?.content(source_code);
+        let id = self
+            .context
+            .get_label_id(&label)
+            .ok_or(TreeSitterParserError::MissingSymbol(label.clone()))?;
+        let statement_node = node.expect_last_child()?;
+        // `label: ;` labels an empty statement
+        let statement = if statement_node.kind() == "expression_statement"
+            && statement_node.expect_code_child(0)?.kind() == ";"
+        {
+            None
+        } else {
+            Some(Box::new(
+                self.object_from_tree_sitter_node(statement_node, source_code)?
+                    .try_into()?,
+            ))
+        };
+        Ok(LabeledStatement {
+            id,
+            span: None,
+            comments: Comments::default(),
+            label,
+            statement,
+        })
+    }
+
+    fn goto_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<GotoStatement, TreeSitterParserError> {
+        let label = node.expect_field("label")?.content(source_code);
+        let label_id = self
+            .context
+            .get_label_id(&label)
+            .ok_or(TreeSitterParserError::MissingSymbol(label.clone()))?;
+        Ok(GotoStatement {
+            id: self.new_id(node, "goto_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            label_id,
+            label,
+        })
+    }
+
+    pub fn number_literal_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<NumberLiteral, TreeSitterParserError> {
+        let spelling = node.content(source_code);
+        let mut number_literal = NumberLiteral::from_spelling(spelling.clone())
+            .ok_or(TreeSitterParserError::WrongType(spelling))?;
+        number_literal.id = self.new_id(node, "number_literal", source_code);
+        Ok(number_literal)
+    }
+
+    /// tree-sitter keeps the sign of `-1` or `+1` in the number, but C has no signed literals,
+    /// so the sign is parsed as a unary expression around the number
+    fn signed_number_literal_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<CLanguageObject, TreeSitterParserError> {
+        let spelling = node.content(source_code);
+        let Some(digits) = spelling.strip_prefix(['-', '+']) else {
+            return Ok(CLanguageObject::NumberLiteral(
+                self.number_literal_from_tree_sitter_node(node, source_code)?,
+            ));
+        };
+        let mut number_literal = NumberLiteral::from_spelling(digits.trim_start())
+            .ok_or(TreeSitterParserError::WrongType(spelling.clone()))?;
+        number_literal.id = self.new_id(node, "number_literal", source_code);
+        Ok(CLanguageObject::UnaryExpression(UnaryExpression {
+            id: self.new_id(node, "unary_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            operator: spelling[..1].to_string(),
+            argument: Box::new(ExpressionObject::NumberLiteral(number_literal)),
+        }))
+    }
+
+    fn if_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<IfStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("if")?;
+        let parenthesized_expression = node.expect_code_child(1)?;
+        parenthesized_expression.expect_kind("parenthesized_expression")?;
+        let body = node.expect_code_child(2)?;
+
+        parenthesized_expression
+            .expect_code_child(0)?
+            .expect_kind("(")?;
+        let condition = self.branch().object_from_tree_sitter_node(
+            parenthesized_expression.expect_code_child(1)?,
+            source_code,
+        )?;
+        parenthesized_expression
+            .expect_code_child(2)?
+            .expect_kind(")")?;
+        let body_object = self.object_from_tree_sitter_node(body, source_code)?;
+
+        let else_clause = if let Some(else_node) = node.code_child(3) {
+            else_node.expect_kind("else_clause")?;
+            Some(self.else_clause_from_tree_sitter_node(else_node, source_code)?)
+        } else {
+            None
+        };
+
+        Ok(IfStatement {
+            id: self.new_id(node, "if_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(condition.try_into()?),
+            body: Box::new(body_object.try_into()?),
+            else_statement: else_clause,
+        })
+    }
+
+    fn parenthesized_condition_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ExpressionObject, TreeSitterParserError> {
+        node.expect_kind("parenthesized_expression")?;
+        node.expect_code_child(0)?.expect_kind("(")?;
+        let condition = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_code_child(1)?, source_code)?;
+        node.expect_code_child(2)?.expect_kind(")")?;
+        Ok(condition.try_into()?)
+    }
+
+    fn while_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<WhileStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("while")?;
+        let condition = self.parenthesized_condition_from_tree_sitter_node(
+            node.expect_code_child(1)?,
+            source_code,
+        )?;
+        let body = self.object_from_tree_sitter_node(node.expect_code_child(2)?, source_code)?;
+
+        Ok(WhileStatement {
+            id: self.new_id(node, "while_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(condition),
+            body: Box::new(body.try_into()?),
+        })
+    }
+
+    fn do_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<DoStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("do")?;
+        let body = self.object_from_tree_sitter_node(node.expect_code_child(1)?, source_code)?;
+        node.expect_code_child(2)?.expect_kind("while")?;
+        let condition = self.parenthesized_condition_from_tree_sitter_node(
+            node.expect_code_child(3)?,
+            source_code,
+        )?;
+
+        Ok(DoStatement {
+            id: self.new_id(node, "do_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            body: Box::new(body.try_into()?),
+            condition: Box::new(condition),
+        })
+    }
+
+    fn for_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ForStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("for")?;
+        // Variables declared in the initializer are only visible inside the loop
+        let mut scope = self.branch();
+        let initializer = node
+            .child_by_field_name("initializer")
+            .map(|initializer| scope.object_from_tree_sitter_node(initializer, source_code))
+            .transpose()?
+            .map(|initializer| initializer.try_into().map(Box::new))
+            .transpose()?;
+        let condition = node
+            .child_by_field_name("condition")
+            .map(|condition| {
+                scope
+                    .branch()
+                    .object_from_tree_sitter_node(condition, source_code)
+            })
+            .transpose()?
+            .map(|condition| condition.try_into().map(Box::new))
+            .transpose()?;
+        let update = node
+            .child_by_field_name("update")
+            .map(|update| {
+                scope
+                    .branch()
+                    .object_from_tree_sitter_node(update, source_code)
+            })
+            .transpose()?
+            .map(|update| update.try_into().map(Box::new))
+            .transpose()?;
+        let body = scope.object_from_tree_sitter_node(node.expect_field("body")?, source_code)?;
+
+        Ok(ForStatement {
+            id: self.new_id(node, "for_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            initializer,
+            condition,
+            update,
+            body: Box::new(body.try_into()?),
+        })
+    }
+
+    fn switch_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<SwitchStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("switch")?;
+        let condition = self.parenthesized_condition_from_tree_sitter_node(
+            node.expect_code_child(1)?,
+            source_code,
+        )?;
+        let body = self.object_from_tree_sitter_node(node.expect_code_child(2)?, source_code)?;
+
+        Ok(SwitchStatement {
+            id: self.new_id(node, "switch_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            condition: Box::new(condition),
+            body: Box::new(body.try_into()?),
+        })
+    }
+
+    fn case_statement_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<CaseStatement, TreeSitterParserError> {
+        let mut current_node = node.expect_code_child(0)?;
+        let value = match current_node.kind() {
+            "case" => {
+                let value_node = current_node.expect_next_code_sibling()?;
+                current_node = value_node.expect_next_code_sibling()?;
+                Some(Box::new(
+                    self.branch()
+                        .object_from_tree_sitter_node(value_node, source_code)?
+                        .try_into()?,
+                ))
+            }
+            "default" => {
+                current_node = current_node.expect_next_code_sibling()?;
+                None
+            }
+            other => return Err(TreeSitterParserError::Unsupported(other.to_string())),
+        };
+        current_node.expect_kind(":")?;
+
+        // Statements under a label share the scope of the whole switch body
+        let mut body = Vec::new();
+        while let Some(next_sibling) = current_node.next_sibling() {
+            current_node = next_sibling;
+            if current_node.kind() == ";" || self.is_attached_comment(current_node) {
+                continue;
+            }
+            body.push(
+                self.object_from_tree_sitter_node(current_node, source_code)?
+                    .try_into()?,
+            );
+        }
+
+        Ok(CaseStatement {
+            id: self.new_id(node, "case_statement", source_code),
+            span: None,
+            comments: Comments::default(),
+            value,
+            body,
+        })
+    }
+
+    fn function_parameter_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<FunctionParameter, TreeSitterParserError> {
+        let comments = self.comments.borrow_mut().take_around(node);
+        let (param_type, name_node) = CType::from_parameter_node(node, source_code, &self.context)?;
+
+        // Parameters of a prototype may be unnamed, like in `int f(int, char *)`
+        let Some(name_node) = name_node else {
+            return Ok(FunctionParameter {
+                id: self.new_id(node, "function_parameter", source_code),
+                span: Some(Span::from_node(node)),
+                comments,
+                identifier: String::new(),
+                param_type,
+            });
+        };
+        name_node.expect_kind("identifier")?;
+        let identifier = name_node.content(source_code).to_string();
+
+        let id = self.context.insert_symbol_with_id(
+            &identifier,
+            self.new_id(name_node, "function_parameter", source_code),
+            false,
+        )?;
+        self.context.insert_symbol_type(id, param_type.clone());
+        Ok(FunctionParameter {
+            id,
+            span: Some(Span::from_node(node)),
+            comments,
+            identifier,
+            param_type,
+        })
+    }
+
+    pub fn function_declaration_from_tree_sitter_node(
+        &mut self,
+        specifiers: Specifiers,
+        return_type: CType,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<FunctionDeclaration, TreeSitterParserError> {
+        let (identifier, parameters, id, _) =
+            self.parse_function_parameter_list(node, source_code)?;
+        Ok(FunctionDeclaration {
+            id,
+            span: None,
+            comments: Comments::default(),
+            specifiers,
+            return_type,
+            identifier,
+            parameter_list: parameters.parameter_list,
+            is_variadic: parameters.is_variadic,
+            explicit_void: parameters.explicit_void,
+        })
+    }
+
+    pub fn function_definition_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<FunctionDefinition, TreeSitterParserError> {
+        let specifiers = Specifiers::from_declaration_node(node, source_code)?;
+        let base_type = CType::from_declaration_node(node, source_code, &self.context)?;
+        let (return_type, declarator_node) = base_type.apply_declarator(
+            node.expect_field("declarator")?,
+            source_code,
+            &self.context,
+        )?;
+        let (identifier, parameters, id, mut subcontext) = self.parse_function_parameter_list(
+            declarator_node.ok_or_else(|| {
+                TreeSitterParserError::Unsupported("function definition without a name".to_string())
+            })?,
+            source_code,
+        )?;
+        let code_block_node = node.expect_field("body")?;
+        code_block_node.expect_kind("compound_statement")?;
+        // Comments after the parameters, like `int f(void) // c`, lead the body
+        let comments = subcontext
+            .comments
+            .borrow_mut()
+            .take_around(code_block_node);
+        // Labels are visible in the whole function body, even before they are defined
+        insert_labels(
+            &mut subcontext.context,
+            code_block_node,
+            source_code,
+            &subcontext.ids,
+        )?;
+        let mut code_block = subcontext.compound_statement_from_tree_sitter_nodes(
+            code_block_node.expect_code_child(0)?,
+            source_code,
+        )?;
+        subcontext.unknown_gotos_to_lost_labels(&mut code_block.code_block, source_code);
+        code_block.comments = comments;
+
+        Ok(FunctionDefinition {
+            id,
+            span: None,
+            comments: Comments::default(),
+            specifiers,
+            return_type,
+            identifier,
+            parameter_list: parameters.parameter_list,
+            is_variadic: parameters.is_variadic,
+            explicit_void: parameters.explicit_void,
+            compound_statement: code_block,
+        })
+    }
+
+    /// Turns the gotos in `statements` to a label kept inside an unknown part, like a label
+    /// closing a block, into unknown objects too, as the label is not written with the nodes
+    fn unknown_gotos_to_lost_labels(
+        &self,
+        statements: &mut [CompoundStatementObject],
+        source_code: &str,
+    ) {
+        let mut labels = HashSet::new();
+        for statement in statements.iter_mut() {
+            for_each_statement(statement, &mut |statement| {
+                if let CompoundStatementObject::LabeledStatement(labeled_statement) = statement {
+                    labels.insert(labeled_statement.id);
+                }
+            });
+        }
+        for statement in statements.iter_mut() {
+            for_each_statement(statement, &mut |statement| {
+                let CompoundStatementObject::GotoStatement(goto_statement) = statement else {
+                    return;
+                };
+                if labels.contains(&goto_statement.label_id) {
+                    return;
+                }
+                let err = TreeSitterParserError::MissingSymbol(goto_statement.label.clone());
+                if let Some(span) = goto_statement.span {
+                    self.diagnostics.borrow_mut().push(Diagnostic {
+                        severity: Severity::Warning,
+                        code: err.code(),
+                        message: err.to_string(),
+                        span,
+                    });
+                }
+                let content = match goto_statement.span {
+                    Some(span) => source_code[span.range()].to_string(),
+                    None => format!("goto {};", goto_statement.label),
+                };
+                *statement = CompoundStatementObject::Unknown(Unknown {
+                    id: goto_statement.id,
+                    span: goto_statement.span,
+                    comments: std::mem::take(&mut goto_statement.comments),
+                    content,
+                });
+            });
+        }
+    }
+
+    pub fn else_clause_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ElseStatement, TreeSitterParserError> {
+        node.expect_code_child(0)?.expect_kind("else")?;
+        let comments = self.comments.borrow_mut().take_around(node);
+        let compound_statement = node.expect_code_child(1)?;
+        // match compound_statement.kind() {
+        //     "compound_statement" => {
+        //         let code_block = self.compound_statement_from_tree_sitter_nodes(
+        //             compound_statement.expect_code_child(0)?,
+        //             source_code,
+        //         )?;
+        //         let body = CompoundStatementObject::CompoundStatement(code_block);
+
+        //         Ok(ElseStatement::ElseClause(Box::new(ElseClause {
+        //             id: Uuid::new_v4(),
+        //             body: Box::new(body), // TODO support other types of statements
+        //         })))
+        //     }
+        //     "if_statement" => {
+        //         let if_statement =
         //             self.if_statement_from_tree_sitter_node(compound_statement, source_code)?;
 
         //         Ok(ElseStatement::ElseIf(Box::new(IfStatement {
@@ -462,11 +1775,14 @@ impl<'a> TreeSitterParser<'a> {
             .branch()
             .object_from_tree_sitter_node(compound_statement, source_code)?;
         match object {
-            CLanguageObject::IfStatement(if_statement) => {
+            CLanguageObject::IfStatement(mut if_statement) => {
+                attach_comments(&mut if_statement.comments, comments, Comments::default());
                 Ok(ElseStatement::ElseIf(Box::new(if_statement)))
             }
             other => Ok(ElseStatement::ElseClause(Box::new(ElseClause {
-                id: Uuid::new_v4(),
+                id: self.new_id(node, "else_clause", source_code),
+                span: Some(Span::from_node(node)),
+                comments,
                 body: Box::new(other.try_into()?),
             }))),
         }
@@ -479,7 +1795,8 @@ impl<'a> TreeSitterParser<'a> {
     ) -> Comment {
         let content = node.content(source_code);
         Comment {
-            id: Uuid::new_v4(),
+            id: self.new_id(node, "comment", source_code),
+            span: None,
             content,
         }
     }
@@ -489,8 +1806,8 @@ impl<'a> TreeSitterParser<'a> {
         node: tree_sitter::Node<'_>,
         source_code: &str,
     ) -> Result<CallExpression, TreeSitterParserError> {
-        let identifier_node = node.child(0).unwrap();
-        assert_eq!(identifier_node.kind(), "identifier");
+        let identifier_node = node.expect_code_child(0)?;
+        identifier_node.expect_kind("identifier")?;
         let identifier = identifier_node.content(source_code).to_string();
         let id_declaration = self
             .context
@@ -498,29 +1815,31 @@ impl<'a> TreeSitterParser<'a> {
             .unwrap_or(Uuid::nil()); // TODO this symbols should be registered from imported libraries
 
         // TODO check if this works and replace on function_declaration.rs
-        let argument_list_node = node.child(1).unwrap();
-        assert_eq!(argument_list_node.kind(), "argument_list");
+        let argument_list_node = node.expect_code_child(1)?;
+        argument_list_node.expect_kind("argument_list")?;
 
         let mut argument_list = Vec::new();
-        assert_eq!(argument_list_node.child(0).unwrap().kind(), "(");
-        let mut argument = argument_list_node.child(1).unwrap();
+        argument_list_node.expect_code_child(0)?.expect_kind("(")?;
+        let mut argument = argument_list_node.expect_code_child(1)?;
         if argument.kind() != ")" {
             loop {
                 argument_list.push(
                     self.branch()
                         .object_from_tree_sitter_node(argument, source_code)?,
                 );
-                let delimitator = argument.next_sibling().unwrap();
+                let delimitator = argument.expect_next_code_sibling()?;
                 if delimitator.kind() == ")" {
                     break;
                 }
-                assert_eq!(delimitator.kind(), ",");
-                argument = delimitator.next_sibling().unwrap();
+                delimitator.expect_kind(",")?;
+                argument = delimitator.expect_next_code_sibling()?;
             }
         }
 
         Ok(CallExpression {
-            id: Uuid::new_v4(),
+            id: self.new_id(node, "call_expression", source_code),
+            span: None,
+            comments: Comments::default(),
             id_declaration,
             identifier,
             argument_list: argument_list
@@ -534,42 +1853,351 @@ impl<'a> TreeSitterParser<'a> {
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> BinaryExpression {
+    ) -> Result<BinaryExpression, TreeSitterParserError> {
         let left = self
             .branch()
-            .object_from_tree_sitter_node(node.child(0).unwrap(), source_code)
-            .unwrap();
-        let operator = node.child(1).unwrap().content(source_code);
+            .object_from_tree_sitter_node(node.expect_code_child(0)?, source_code)?;
+        let operator = node.expect_code_child(1)?.content(source_code);
         let right = self
             .branch()
-            .object_from_tree_sitter_node(node.child(2).unwrap(), source_code)
-            .unwrap();
-        BinaryExpression {
-            id: Uuid::new_v4(),
-            left: Box::new(left.try_into().unwrap()),
+            .object_from_tree_sitter_node(node.expect_code_child(2)?, source_code)?;
+        Ok(BinaryExpression {
+            id: self.new_id(node, "binary_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            left: Box::new(left.try_into()?),
             operator,
-            right: Box::new(right.try_into().unwrap()),
-        }
+            right: Box::new(right.try_into()?),
+        })
     }
 
-    fn assignment_expression_from_tree_sitter_node(
+    /// Handles both `unary_expression` and `pointer_expression` nodes, as dereferencing and
+    /// taking an address are plain prefix operators once parsed.
+    fn unary_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<UnaryExpression, TreeSitterParserError> {
+        let operator = node.expect_field("operator")?.content(source_code);
+        let argument = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("argument")?, source_code)?;
+        Ok(UnaryExpression {
+            id: self.new_id(node, "unary_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            operator,
+            argument: Box::new(argument.try_into()?),
+        })
+    }
+
+    fn update_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<UpdateExpression, TreeSitterParserError> {
+        let operator = node.expect_field("operator")?.content(source_code);
+        let is_prefix = matches!(node.expect_code_child(0)?.kind(), "++" | "--");
+        let argument = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("argument")?, source_code)?;
+        Ok(UpdateExpression {
+            id: self.new_id(node, "update_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            operator,
+            is_prefix,
+            argument: Box::new(argument.try_into()?),
+        })
+    }
+
+    fn cast_expression_from_tree_sitter_node(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> AssignmentExpression {
-        let identifier = node.child(0).unwrap().content(source_code);
-        assert_eq!(node.child(1).unwrap().kind(), "=");
+    ) -> Result<CastExpression, TreeSitterParserError> {
+        let (cast_type, _) =
+            CType::from_parameter_node(node.expect_field("type")?, source_code, &self.context)?;
         let value = self
             .branch()
-            .object_from_tree_sitter_node(node.child(2).unwrap(), source_code)
-            .unwrap();
-        let id_declaration = self.context.get_or_insert_symbol(&identifier, false);
-        AssignmentExpression {
-            id: Uuid::new_v4(),
-            id_declaration,
+            .object_from_tree_sitter_node(node.expect_field("value")?, source_code)?;
+        Ok(CastExpression {
+            id: self.new_id(node, "cast_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            cast_type,
+            value: Box::new(value.try_into()?),
+        })
+    }
+
+    fn sizeof_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<SizeofExpression, TreeSitterParserError> {
+        let argument_type = node
+            .child_by_field_name("type")
+            .map(|type_node| CType::from_parameter_node(type_node, source_code, &self.context))
+            .transpose()?
+            .map(|(argument_type, _)| argument_type);
+        let value = match node.child_by_field_name("value") {
+            Some(mut value_node) => {
+                // The writer always parenthesizes the operand, so the parentheses are dropped
+                if value_node.kind() == "parenthesized_expression" {
+                    value_node = value_node.expect_named_child(0)?;
+                }
+                let value = self
+                    .branch()
+                    .object_from_tree_sitter_node(value_node, source_code)?;
+                Some(Box::new(value.try_into()?))
+            }
+            None => None,
+        };
+        Ok(SizeofExpression {
+            id: self.new_id(node, "sizeof_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            argument_type,
+            value,
+        })
+    }
+
+    fn conditional_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ConditionalExpression, TreeSitterParserError> {
+        let field = |name: &str| -> Result<Box<ExpressionObject>, TreeSitterParserError> {
+            let object = self
+                .branch()
+                .object_from_tree_sitter_node(node.expect_field(name)?, source_code)?;
+            Ok(Box::new(object.try_into()?))
+        };
+        Ok(ConditionalExpression {
+            id: self.new_id(node, "conditional_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            condition: field("condition")?,
+            consequence: field("consequence")?,
+            alternative: field("alternative")?,
+        })
+    }
+
+    fn field_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<FieldExpression, TreeSitterParserError> {
+        let argument: ExpressionObject = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("argument")?, source_code)?
+            .try_into()?;
+        let operator = node.expect_field("operator")?.content(source_code);
+        let identifier = node.expect_field("field")?.content(source_code);
+
+        // The member can only be told apart when the struct or union behind the argument is known
+        let record_type = self
+            .expression_type(&argument)
+            .map(|argument_type| self.context.resolve_type(&argument_type))
+            .and_then(|argument_type| match operator.as_str() {
+                "->" => argument_type
+                    .pointee()
+                    .map(|pointee| self.context.resolve_type(pointee)),
+                _ => Some(argument_type),
+            });
+        let declaration_id = match record_type.as_ref().map(CType::unqualified) {
+            Some(CType::Struct(record) | CType::Union(record)) => self
+                .context
+                .get_member(&record.declaration_id, &identifier)
+                .map(|member| member.id)
+                .ok_or(TreeSitterParserError::MissingSymbol(identifier.clone()))?,
+            _ => Uuid::nil(),
+        };
+        Ok(FieldExpression {
+            id: self.new_id(node, "field_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(argument),
+            operator,
+            declaration_id,
             identifier,
-            value: Box::new(value.try_into().unwrap()),
+        })
+    }
+
+    fn subscript_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<SubscriptExpression, TreeSitterParserError> {
+        let argument = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("argument")?, source_code)?;
+        let index = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("index")?, source_code)?;
+        Ok(SubscriptExpression {
+            id: self.new_id(node, "subscript_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            argument: Box::new(argument.try_into()?),
+            index: Box::new(index.try_into()?),
+        })
+    }
+
+    fn parenthesized_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<ParenthesizedExpression, TreeSitterParserError> {
+        Ok(ParenthesizedExpression {
+            id: self.new_id(node, "parenthesized_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            value: Box::new(self.parenthesized_condition_from_tree_sitter_node(node, source_code)?),
+        })
+    }
+
+    fn initializer_list_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<InitializerList, TreeSitterParserError> {
+        let mut elements = Vec::new();
+        let mut cursor = node.walk();
+        for element in node
+            .named_children(&mut cursor)
+            .filter(|child| child.kind() != "comment")
+        {
+            elements.push(
+                self.branch()
+                    .object_from_tree_sitter_node(element, source_code)?
+                    .try_into()?,
+            );
+        }
+        Ok(InitializerList {
+            id: self.new_id(node, "initializer_list", source_code),
+            span: None,
+            comments: Comments::default(),
+            elements,
+        })
+    }
+
+    fn initializer_pair_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<InitializerPair, TreeSitterParserError> {
+        let mut designators = Vec::new();
+        let mut cursor = node.walk();
+        for designator in node.children_by_field_name("designator", &mut cursor) {
+            let inner = designator.expect_named_child(0)?;
+            designators.push(match designator.kind() {
+                "field_designator" => Designator::Field(inner.content(source_code)),
+                "subscript_designator" => Designator::Subscript(
+                    self.branch()
+                        .object_from_tree_sitter_node(inner, source_code)?
+                        .try_into()?,
+                ),
+                other => return Err(TreeSitterParserError::WrongType(other.to_string())),
+            });
+        }
+        let value = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_field("value")?, source_code)?;
+        Ok(InitializerPair {
+            id: self.new_id(node, "initializer_pair", source_code),
+            span: None,
+            comments: Comments::default(),
+            designators,
+            value: Box::new(value.try_into()?),
+        })
+    }
+
+    /// Type of an expression as far as the declarations seen so far tell, used to find the
+    /// struct or union whose member is accessed.
+    fn expression_type(&self, expression: &ExpressionObject) -> Option<CType> {
+        match expression {
+            ExpressionObject::Reference(reference) => self
+                .context
+                .get_symbol_type(&reference.declaration_id)
+                .cloned(),
+            ExpressionObject::FieldExpression(field_expression) => self
+                .context
+                .get_member_by_id(&field_expression.declaration_id)
+                .map(|member| member.field_type.clone()),
+            ExpressionObject::SubscriptExpression(subscript_expression) => self
+                .context
+                .resolve_type(&self.expression_type(&subscript_expression.argument)?)
+                .pointee()
+                .cloned(),
+            ExpressionObject::UnaryExpression(unary_expression)
+                if unary_expression.operator == "*" =>
+            {
+                self.context
+                    .resolve_type(&self.expression_type(&unary_expression.argument)?)
+                    .pointee()
+                    .cloned()
+            }
+            ExpressionObject::UnaryExpression(unary_expression)
+                if unary_expression.operator == "&" =>
+            {
+                Some(CType::Pointer(Box::new(
+                    self.expression_type(&unary_expression.argument)?,
+                )))
+            }
+            ExpressionObject::CastExpression(cast_expression) => {
+                Some(cast_expression.cast_type.clone())
+            }
+            ExpressionObject::ParenthesizedExpression(parenthesized_expression) => {
+                self.expression_type(&parenthesized_expression.value)
+            }
+            _ => None,
+        }
+    }
+
+    fn assignment_expression_from_tree_sitter_node(
+        &mut self,
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<AssignmentExpression, TreeSitterParserError> {
+        let left_node = node.expect_code_child(0)?;
+        let operator = node.expect_code_child(1)?;
+        if operator.kind() != "=" {
+            return Err(TreeSitterParserError::Unsupported(format!(
+                "`{}` assignment",
+                operator.kind()
+            )));
         }
+        let left = match left_node.kind() {
+            // A name declared outside the file, like `errno`, is kept by name, as calls are
+            "identifier" => {
+                let identifier = left_node.content(source_code);
+                let declaration_id = self
+                    .context
+                    .get_symbol_id(&identifier, false)
+                    .unwrap_or(Uuid::nil());
+                CLanguageObject::Reference(Reference {
+                    id: self.new_id(left_node, "reference", source_code),
+                    span: None,
+                    comments: Comments::default(),
+                    declaration_id,
+                    identifier,
+                })
+            }
+            _ => self
+                .branch()
+                .object_from_tree_sitter_node(left_node, source_code)?,
+        };
+        let value = self
+            .branch()
+            .object_from_tree_sitter_node(node.expect_code_child(2)?, source_code)?;
+        Ok(AssignmentExpression {
+            id: self.new_id(node, "assignment_expression", source_code),
+            span: None,
+            comments: Comments::default(),
+            left: Box::new(left.try_into()?),
+            value: Box::new(value.try_into()?),
+        })
     }
 
     fn expression_statement_from_tree_sitter_node(
@@ -577,33 +2205,68 @@ impl<'a> TreeSitterParser<'a> {
         node: tree_sitter::Node<'_>,
         source_code: &str,
     ) -> Result<CLanguageObject, TreeSitterParserError> {
-        let child_node = node.child(0).unwrap();
-        self.branch()
-            .object_from_tree_sitter_node(child_node, source_code)
+        let child_node = node.expect_code_child(0)?;
+        if child_node.kind() == ";" {
+            return Err(TreeSitterParserError::Unsupported(
+                "empty statement".to_string(),
+            ));
+        }
+        match self
+            .branch()
+            .object_from_tree_sitter_node(child_node, source_code)?
+        {
+            // Keep the whole statement, so the unknown text brings its own semicolon
+            CLanguageObject::Unknown(_) => {
+                let warning = self.diagnostics.borrow_mut().pop();
+                let (code, message) = warning
+                    .map(|warning| (warning.code, warning.message))
+                    .unwrap_or((UNSUPPORTED_SYNTAX, String::new()));
+                Ok(CLanguageObject::Unknown(
+                    self.unknown_from_tree_sitter_node(node, source_code, code, message),
+                ))
+            }
+            object => Ok(object),
+        }
     }
 
     fn parse_function_parameter_list(
         &mut self,
         node: tree_sitter::Node<'_>,
         source_code: &str,
-    ) -> Result<(String, Vec<FunctionParameter>, Uuid, TreeSitterParser), TreeSitterParserError>
-    {
-        let identifier_node = node.child(0).unwrap();
-        assert_eq!(identifier_node.kind(), "identifier");
+    ) -> Result<(String, ParameterList, Uuid, TreeSitterParser<'_>), TreeSitterParserError> {
+        let identifier_node = node.expect_code_child(0)?;
+        identifier_node.expect_kind("identifier")?;
         let identifier = identifier_node.content(source_code).to_string();
-        let id = self.context.get_or_insert_symbol(&identifier, true);
+        let parameters_node = node.expect_code_child(1)?;
+        parameters_node.expect_kind("parameter_list")?;
+        // Old style definitions, like `int f(a) int a; { ... }`, only name their parameters
+        // here. They fail before the function is declared, so calls to it are kept by name
+        let mut cursor = parameters_node.walk();
+        if parameters_node
+            .named_children(&mut cursor)
+            .any(|parameter| parameter.kind() == "identifier")
+        {
+            return Err(TreeSitterParserError::Unsupported(
+                "K&R style parameter list".to_string(),
+            ));
+        }
+        let id = self.context.get_or_insert_symbol_with_id(
+            &identifier,
+            true,
+            self.new_id(identifier_node, "function", source_code),
+        );
         let mut subcontext = self.branch();
 
-        let parameters_node = node.child(1).unwrap();
-        assert_eq!(parameters_node.kind(), "parameter_list");
-        let mut parameter_list: Vec<FunctionParameter> = Vec::new();
-        if let Some(mut parameter) = parameters_node.child(0) {
+        let mut parameters = ParameterList::default();
+        if let Some(mut parameter) = parameters_node.code_child(0) {
             loop {
-                if parameter.kind() == "parameter_declaration" {
-                    parameter_list.push(
+                match parameter.kind() {
+                    "parameter_declaration" => parameters.parameter_list.push(
                         subcontext
                             .function_parameter_from_tree_sitter_node(parameter, source_code)?,
-                    );
+                    ),
+                    "variadic_parameter" => parameters.is_variadic = true,
+                    _ => {}
                 }
                 if let Some(next_param) = parameter.next_sibling() {
                     parameter = next_param;
@@ -612,6 +2275,228 @@ impl<'a> TreeSitterParser<'a> {
                 }
             }
         }
-        Ok((identifier, parameter_list, id, subcontext))
+        // A lone unnamed `void` is not a parameter, it declares that there are none
+        if let [
+            FunctionParameter {
+                identifier,
+                param_type: CType::Void,
+                ..
+            },
+        ] = parameters.parameter_list.as_slice()
+            && identifier.is_empty()
+        {
+            parameters.parameter_list.clear();
+            parameters.explicit_void = true;
+        }
+        Ok((identifier, parameters, id, subcontext))
+    }
+}
+
+/// Parameters of a function declarator, with how the list itself is written
+#[derive(Default)]
+struct ParameterList {
+    parameter_list: Vec<FunctionParameter>,
+    is_variadic: bool,
+    explicit_void: bool,
+}
+
+/// Registers every label defined under `node`, so gotos can refer to labels defined after them
+fn insert_labels(
+    context: &mut Context,
+    node: tree_sitter::Node<'_>,
+    source_code: &str,
+    ids: &ObjectIds,
+) -> Result<(), TreeSitterParserError> {
+    if node.kind() == "labeled_statement" {
+        let label_node = node.expect_field("label")?;
+        let label = label_node.content(source_code);
+        context.insert_label_with_id(&label, ids.object_id(label_node, "label", source_code))?;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        insert_labels(context, child, source_code, ids)?;
+    }
+    Ok(())
+}
+
+/// Calls `visit` on `statement`, then on the statements nested in what it left
+fn for_each_statement(
+    statement: &mut CompoundStatementObject,
+    visit: &mut impl FnMut(&mut CompoundStatementObject),
+) {
+    visit(statement);
+    match statement {
+        CompoundStatementObject::CompoundStatement(compound_statement) => {
+            for statement in &mut compound_statement.code_block {
+                for_each_statement(statement, visit);
+            }
+        }
+        CompoundStatementObject::IfStatement(if_statement) => {
+            let mut if_statement = if_statement;
+            loop {
+                for_each_statement(&mut if_statement.body, visit);
+                match &mut if_statement.else_statement {
+                    Some(ElseStatement::ElseIf(else_if)) => if_statement = else_if,
+                    Some(ElseStatement::ElseClause(else_clause)) => {
+                        for_each_statement(&mut else_clause.body, visit);
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        }
+        CompoundStatementObject::WhileStatement(WhileStatement { body, .. })
+        | CompoundStatementObject::DoStatement(DoStatement { body, .. })
+        | CompoundStatementObject::ForStatement(ForStatement { body, .. })
+        | CompoundStatementObject::SwitchStatement(SwitchStatement { body, .. }) => {
+            for_each_statement(body, visit);
+        }
+        CompoundStatementObject::CaseStatement(case_statement) => {
+            for statement in &mut case_statement.body {
+                for_each_statement(statement, visit);
+            }
+        }
+        CompoundStatementObject::LabeledStatement(LabeledStatement {
+            statement: Some(statement),
+            ..
+        }) => for_each_statement(statement, visit),
+        CompoundStatementObject::PreprocIfStatement(preproc_if_statement) => {
+            let mut branch = Some(preproc_if_statement);
+            while let Some(preproc_if_statement) = branch {
+                for statement in &mut preproc_if_statement.body {
+                    for_each_statement(statement, visit);
+                }
+                branch = preproc_if_statement.alternative.as_deref_mut();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Conditional blocks hold statements inside a function and declarations everywhere else
+fn is_in_function_body(node: tree_sitter::Node<'_>) -> bool {
+    let mut ancestor = node.parent();
+    while let Some(node) = ancestor {
+        if node.kind() == "compound_statement" {
+            return true;
+        }
+        ancestor = node.parent();
+    }
+    false
+}
+
+/// Adds the comments taken around an object and inside it to the ones it already has, in the
+/// order they were written
+fn attach_comments(comments: &mut Comments, around: Comments, inside: Comments) {
+    comments.leading.splice(0..0, around.leading);
+    comments.leading.extend(inside.leading);
+    comments.trailing.extend(inside.trailing);
+    comments.trailing.extend(around.trailing);
+}
+
+fn has_error(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Severity::Error)
+}
+
+fn error_diagnostic(err: &TreeSitterParserError, node: tree_sitter::Node<'_>) -> Diagnostic {
+    Diagnostic {
+        severity: Severity::Error,
+        code: err.code(),
+        message: err.to_string(),
+        span: Span::from_node(node),
+    }
+}
+
+/// Shape the parser expects of a node. Nodes shaped otherwise come from syntax the parser has
+/// no objects for, so they fail as `Unsupported` and are kept as `Unknown` objects
+pub trait ExpectShape: Sized {
+    /// This node, if it is of `kind`
+    fn expect_kind(self, kind: &str) -> Result<Self, TreeSitterParserError>;
+    /// Child at `index` among the children that are not comments
+    fn expect_code_child(&self, index: usize) -> Result<Self, TreeSitterParserError>;
+    /// Child at `index` among the named children
+    fn expect_named_child(&self, index: usize) -> Result<Self, TreeSitterParserError>;
+    /// Last child, comments included
+    fn expect_last_child(&self) -> Result<Self, TreeSitterParserError>;
+    /// Child in the field `name`
+    fn expect_field(&self, name: &str) -> Result<Self, TreeSitterParserError>;
+    /// Next sibling, comments included
+    fn expect_next_sibling(&self) -> Result<Self, TreeSitterParserError>;
+    /// Next sibling that is not a comment
+    fn expect_next_code_sibling(&self) -> Result<Self, TreeSitterParserError>;
+}
+
+impl ExpectShape for tree_sitter::Node<'_> {
+    fn expect_kind(self, kind: &str) -> Result<Self, TreeSitterParserError> {
+        if self.kind() == kind {
+            return Ok(self);
+        }
+        Err(TreeSitterParserError::Unsupported(match self.parent() {
+            Some(parent) => format!("{} in {}", self.kind(), parent.kind()),
+            None => self.kind().to_string(),
+        }))
+    }
+
+    fn expect_code_child(&self, index: usize) -> Result<Self, TreeSitterParserError> {
+        self.code_child(index).ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!("{} without child {index}", self.kind()))
+        })
+    }
+
+    fn expect_named_child(&self, index: usize) -> Result<Self, TreeSitterParserError> {
+        self.named_child(index).ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!(
+                "{} without named child {index}",
+                self.kind()
+            ))
+        })
+    }
+
+    fn expect_last_child(&self) -> Result<Self, TreeSitterParserError> {
+        self.child(self.child_count().saturating_sub(1))
+            .ok_or_else(|| TreeSitterParserError::Unsupported(format!("empty {}", self.kind())))
+    }
+
+    fn expect_field(&self, name: &str) -> Result<Self, TreeSitterParserError> {
+        self.child_by_field_name(name).ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!("{} without a {name}", self.kind()))
+        })
+    }
+
+    fn expect_next_sibling(&self) -> Result<Self, TreeSitterParserError> {
+        self.next_sibling().ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!("{} without a next sibling", self.kind()))
+        })
+    }
+
+    fn expect_next_code_sibling(&self) -> Result<Self, TreeSitterParserError> {
+        self.next_code_sibling().ok_or_else(|| {
+            TreeSitterParserError::Unsupported(format!("{} without a next sibling", self.kind()))
+        })
+    }
+}
+
+/// Kinds without objects, which would otherwise be dropped from the declarations holding them
+const UNSUPPORTED_KINDS: [&str; 4] = [
+    "attribute_specifier",
+    "attribute_declaration",
+    "ms_declspec_modifier",
+    "bitfield_clause",
+];
+
+/// First syntax error or unsupported node in `node`. The statements of a block are checked
+/// one by one as they are parsed, so only its braces are checked here and an error in one
+/// statement does not hide the rest of the block.
+fn find_unparsable(node: tree_sitter::Node<'_>) -> Option<tree_sitter::Node<'_>> {
+    if node.is_error() || node.is_missing() || UNSUPPORTED_KINDS.contains(&node.kind()) {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let mut children = node.children(&mut cursor);
+    if node.kind() == "compound_statement" {
+        return children.find(|brace| brace.is_missing());
     }
+    children.find_map(find_unparsable)
 }
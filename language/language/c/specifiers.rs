@@ -0,0 +1,76 @@
+use crate::language::c::TreeSitterNodeExt;
+use crate::language::c::parsers::text::TreeSitterParserError;
+use std::fmt;
+
+/// Storage-class and function specifiers of a declaration, like `static inline`.
+/// Qualifiers such as `const` are part of the declared type instead.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Specifiers {
+    pub is_extern: bool,
+    pub is_static: bool,
+    pub is_auto: bool,
+    pub is_register: bool,
+    pub is_thread_local: bool,
+    pub is_inline: bool,
+}
+
+impl Specifiers {
+    pub fn is_empty(&self) -> bool {
+        self.keywords().is_empty()
+    }
+
+    /// Sets the specifier named by `keyword`, returning false if it is not one.
+    /// Compiler spellings like `__thread` and `__inline__` count as the standard ones.
+    pub fn insert(&mut self, keyword: &str) -> bool {
+        match keyword {
+            "extern" => self.is_extern = true,
+            "static" => self.is_static = true,
+            "auto" => self.is_auto = true,
+            "register" => self.is_register = true,
+            "thread_local" | "_Thread_local" | "__thread" => self.is_thread_local = true,
+            "inline" | "__inline" | "__inline__" => self.is_inline = true,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Keywords in canonical order: storage class, then `thread_local`, then `inline`
+    pub fn keywords(&self) -> Vec<&'static str> {
+        [
+            (self.is_extern, "extern"),
+            (self.is_static, "static"),
+            (self.is_auto, "auto"),
+            (self.is_register, "register"),
+            (self.is_thread_local, "thread_local"),
+            (self.is_inline, "inline"),
+        ]
+        .into_iter()
+        .filter_map(|(set, keyword)| set.then_some(keyword))
+        .collect()
+    }
+
+    /// Specifiers written in a declaration or function definition node
+    pub fn from_declaration_node(
+        node: tree_sitter::Node<'_>,
+        source_code: &str,
+    ) -> Result<Self, TreeSitterParserError> {
+        let mut specifiers = Specifiers::default();
+        let mut cursor = node.walk();
+        for specifier in node
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "storage_class_specifier")
+        {
+            let keyword = specifier.content(source_code);
+            if !specifiers.insert(&keyword) {
+                return Err(TreeSitterParserError::WrongType(keyword));
+            }
+        }
+        Ok(specifiers)
+    }
+}
+
+impl fmt::Display for Specifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.keywords().join(" "))
+    }
+}
@@ -5,19 +5,49 @@ use writer_error::WriterError;
 
 use crate::language::c::language_object::{
     declaration_object::{
-        declaration::Declaration, function_declaration::FunctionDeclaration,
-        function_definition::FunctionDefinition, preproc_include::PreprocInclude,
+        declaration::Declaration,
+        declaration_list::DeclarationList,
+        enum_definition::{EnumDefinition, enumerator::Enumerator},
+        function_declaration::FunctionDeclaration,
+        function_definition::FunctionDefinition,
+        preproc_call::PreprocCall,
+        preproc_define::PreprocDefine,
+        preproc_if::PreprocIf,
+        preproc_include::PreprocInclude,
+        struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+        type_definition::TypeDefinition,
+        union_definition::UnionDefinition,
     },
     expression_object::{
         assignment_expression::AssignmentExpression, binary_expression::BinaryExpression,
-        call_expression::CallExpression, number_literal::NumberLiteral, reference::Reference,
-        string_literal::StringLiteral,
+        call_expression::CallExpression, cast_expression::CastExpression,
+        char_literal::CharLiteral, conditional_expression::ConditionalExpression,
+        field_expression::FieldExpression, initializer_list::InitializerList,
+        initializer_pair::InitializerPair, number_literal::NumberLiteral,
+        parenthesized_expression::ParenthesizedExpression, reference::Reference,
+        sizeof_expression::SizeofExpression, string_literal::StringLiteral,
+        subscript_expression::SubscriptExpression, unary_expression::UnaryExpression,
+        update_expression::UpdateExpression,
+    },
+    special_object::{
+        comment::{Comment, Comments},
+        source_file::SourceFile,
+        unknown::Unknown,
     },
-    special_object::{comment::Comment, source_file::SourceFile, unknown::Unknown},
     statement_object::{
+        break_statement::BreakStatement,
+        case_statement::CaseStatement,
         compound_statement::CompoundStatement,
+        continue_statement::ContinueStatement,
+        do_statement::DoStatement,
+        for_statement::ForStatement,
+        goto_statement::GotoStatement,
         if_statement::{IfStatement, else_clause::ElseClause},
+        labeled_statement::LabeledStatement,
+        preproc_if_statement::PreprocIfStatement,
         return_statement::ReturnStatement,
+        switch_statement::SwitchStatement,
+        while_statement::WhileStatement,
     },
 };
 
@@ -40,17 +70,71 @@ pub trait Cursor {
         binary_expression: &BinaryExpression,
     ) -> Result<(), WriterError>;
 
+    fn write_break_statement(
+        &mut self,
+        break_statement: &BreakStatement,
+    ) -> Result<(), WriterError>;
+
     fn write_call_expression(
         &mut self,
         call_expression: &CallExpression,
     ) -> Result<(), WriterError>;
 
+    fn write_case_statement(&mut self, case_statement: &CaseStatement) -> Result<(), WriterError>;
+
+    fn write_cast_expression(
+        &mut self,
+        cast_expression: &CastExpression,
+    ) -> Result<(), WriterError>;
+
     fn write_comment(&mut self, comment: &Comment) -> Result<(), WriterError>;
 
+    /// Comments attached before an object, written right before it
+    fn write_leading_comments(&mut self, comments: &Comments) -> Result<(), WriterError>;
+
+    /// Comments attached after an object, written right after it
+    fn write_trailing_comments(&mut self, comments: &Comments) -> Result<(), WriterError>;
+
+    fn write_conditional_expression(
+        &mut self,
+        conditional_expression: &ConditionalExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_continue_statement(
+        &mut self,
+        continue_statement: &ContinueStatement,
+    ) -> Result<(), WriterError>;
+
     fn write_declaration(&mut self, declaration: &Declaration) -> Result<(), WriterError>;
 
+    fn write_declaration_list(
+        &mut self,
+        declaration_list: &DeclarationList,
+    ) -> Result<(), WriterError>;
+
+    fn write_do_statement(&mut self, do_statement: &DoStatement) -> Result<(), WriterError>;
+
     fn write_else_clause(&mut self, else_clause: &ElseClause) -> Result<(), WriterError>;
 
+    fn write_enum_definition(
+        &mut self,
+        enum_definition: &EnumDefinition,
+    ) -> Result<(), WriterError>;
+
+    fn write_enumerator(&mut self, enumerator: &Enumerator) -> Result<(), WriterError>;
+
+    fn write_field_declaration(
+        &mut self,
+        field_declaration: &FieldDeclaration,
+    ) -> Result<(), WriterError>;
+
+    fn write_field_expression(
+        &mut self,
+        field_expression: &FieldExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_for_statement(&mut self, for_statement: &ForStatement) -> Result<(), WriterError>;
+
     fn write_function_declaration(
         &mut self,
         function_declaration: &FunctionDeclaration,
@@ -61,10 +145,43 @@ pub trait Cursor {
         function_definition: &FunctionDefinition,
     ) -> Result<(), WriterError>;
 
+    fn write_goto_statement(&mut self, goto_statement: &GotoStatement) -> Result<(), WriterError>;
+
     fn write_if_statement(&mut self, if_statement: &IfStatement) -> Result<(), WriterError>;
 
+    fn write_initializer_list(
+        &mut self,
+        initializer_list: &InitializerList,
+    ) -> Result<(), WriterError>;
+
+    fn write_initializer_pair(
+        &mut self,
+        initializer_pair: &InitializerPair,
+    ) -> Result<(), WriterError>;
+
+    fn write_labeled_statement(
+        &mut self,
+        labeled_statement: &LabeledStatement,
+    ) -> Result<(), WriterError>;
+
     fn write_number_literal(&mut self, number_literal: &NumberLiteral) -> Result<(), WriterError>;
 
+    fn write_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ParenthesizedExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_preproc_call(&mut self, preproc_call: &PreprocCall) -> Result<(), WriterError>;
+
+    fn write_preproc_define(&mut self, preproc_define: &PreprocDefine) -> Result<(), WriterError>;
+
+    fn write_preproc_if(&mut self, preproc_if: &PreprocIf) -> Result<(), WriterError>;
+
+    fn write_preproc_if_statement(
+        &mut self,
+        preproc_if_statement: &PreprocIfStatement,
+    ) -> Result<(), WriterError>;
+
     fn write_preproc_include(
         &mut self,
         preproc_include: &PreprocInclude,
@@ -77,10 +194,57 @@ pub trait Cursor {
         return_statement: &ReturnStatement,
     ) -> Result<(), WriterError>;
 
+    fn write_sizeof_expression(
+        &mut self,
+        sizeof_expression: &SizeofExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_char_literal(&mut self, char_literal: &CharLiteral) -> Result<(), WriterError>;
+
     fn write_string_literal(&mut self, string_literal: &StringLiteral) -> Result<(), WriterError>;
 
+    fn write_struct_definition(
+        &mut self,
+        struct_definition: &StructDefinition,
+    ) -> Result<(), WriterError>;
+
+    fn write_subscript_expression(
+        &mut self,
+        subscript_expression: &SubscriptExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_switch_statement(
+        &mut self,
+        switch_statement: &SwitchStatement,
+    ) -> Result<(), WriterError>;
+
     fn write_compound_statement(
         &mut self,
         compound_statement: &CompoundStatement,
     ) -> Result<(), WriterError>;
+
+    fn write_type_definition(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<(), WriterError>;
+
+    fn write_unary_expression(
+        &mut self,
+        unary_expression: &UnaryExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_union_definition(
+        &mut self,
+        union_definition: &UnionDefinition,
+    ) -> Result<(), WriterError>;
+
+    fn write_update_expression(
+        &mut self,
+        update_expression: &UpdateExpression,
+    ) -> Result<(), WriterError>;
+
+    fn write_while_statement(
+        &mut self,
+        while_statement: &WhileStatement,
+    ) -> Result<(), WriterError>;
 }
@@ -1,5 +1,5 @@
 pub mod node_type;
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 use uuid::Uuid;
 
@@ -7,28 +7,70 @@ use crate::language::c::language_object::{
     LanguageObject as CLanguageObject,
     declaration_object::{
         declaration::Declaration,
+        declaration_list::DeclarationList,
+        enum_definition::{EnumDefinition, enumerator::Enumerator},
         function_declaration::{FunctionDeclaration, function_parameter::FunctionParameter},
         function_definition::FunctionDefinition,
+        preproc_call::PreprocCall,
+        preproc_define::PreprocDefine,
+        preproc_if::PreprocIf,
         preproc_include::PreprocInclude,
+        struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+        type_definition::TypeDefinition,
+        union_definition::UnionDefinition,
     },
     expression_object::{
-        ExpressionObject, assignment_expression::AssignmentExpression,
-        binary_expression::BinaryExpression, call_expression::CallExpression,
-        number_literal::NumberLiteral, reference::Reference, string_literal::StringLiteral,
+        ExpressionObject,
+        assignment_expression::AssignmentExpression,
+        binary_expression::BinaryExpression,
+        call_expression::CallExpression,
+        cast_expression::CastExpression,
+        char_literal::CharLiteral,
+        conditional_expression::ConditionalExpression,
+        field_expression::FieldExpression,
+        initializer_list::InitializerList,
+        initializer_pair::{Designator, InitializerPair},
+        number_literal::{NumberLiteral, NumberValue},
+        parenthesized_expression::ParenthesizedExpression,
+        reference::Reference,
+        sizeof_expression::SizeofExpression,
+        string_literal::StringLiteral,
+        subscript_expression::SubscriptExpression,
+        unary_expression::UnaryExpression,
+        update_expression::UpdateExpression,
+    },
+    special_object::{
+        comment::{Comment, Comments},
+        source_file::SourceFile,
+        trivia::{Layout, Trivia},
+        unknown::Unknown,
     },
-    special_object::{comment::Comment, source_file::SourceFile, unknown::Unknown},
     statement_object::{
+        break_statement::BreakStatement,
+        case_statement::CaseStatement,
         compound_statement::CompoundStatement,
+        continue_statement::ContinueStatement,
+        do_statement::DoStatement,
+        for_statement::ForStatement,
+        goto_statement::GotoStatement,
         if_statement::{ElseStatement, IfStatement, else_clause::ElseClause},
+        labeled_statement::LabeledStatement,
+        preproc_if_statement::PreprocIfStatement,
         return_statement::ReturnStatement,
+        switch_statement::SwitchStatement,
+        while_statement::WhileStatement,
     },
 };
 
 use crate::language::c::{
+    c_type::CType,
+    literal::{EncodingPrefix, escape},
     parsers::context::Context,
+    specifiers::Specifiers,
     writers::{Cursor, node_writer::node_type::NodeType},
 };
-use crate::node::{Node, ToNode, ToTags};
+use crate::language::{c::C, diagnostic::Span};
+use crate::node::{Node, ToNode, ToTags, header::Header, seed_nil_ids};
 
 use super::{Writer, writer_error::WriterError};
 
@@ -40,6 +82,8 @@ pub struct NodeWriter<'a> {
 pub struct NodeCursor<'a> {
     pub nodes: Vec<Node>,
     pub context: Context<'a>,
+    /// Layout of the text the file was parsed from, when it was kept
+    trivia: Option<Rc<Trivia>>,
 }
 
 impl<'a> NodeCursor<'a> {
@@ -47,19 +91,21 @@ impl<'a> NodeCursor<'a> {
         NodeCursor {
             nodes: Vec::new(),
             context: Context::new(),
+            trivia: None,
         }
     }
 
-    fn branch(&self) -> NodeCursor {
+    fn branch(&self) -> NodeCursor<'_> {
         NodeCursor {
             nodes: vec![],
             context: self.context.branch(),
+            trivia: self.trivia.clone(),
         }
     }
 
     fn to_node(&self, value: &CLanguageObject) -> Result<Vec<Node>, WriterError> {
         let mut sub_writer = self.branch();
-        value.write(&mut sub_writer)?;
+        sub_writer.write_object(value)?;
         let children = sub_writer.nodes;
         Ok(children)
     }
@@ -73,10 +119,381 @@ impl<'a> NodeCursor<'a> {
 
     fn save_objects(&mut self, code: &Vec<CLanguageObject>) -> Result<(), WriterError> {
         for object in code {
-            object.write(self)?;
+            self.write_object(object)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the object and tags its node with the span it was parsed from, the comments
+    /// attached to it and its layout
+    fn write_object(&mut self, object: &CLanguageObject) -> Result<(), WriterError> {
+        let index = self.nodes.len();
+        object.write(self)?;
+        if let Some(node) = self.nodes.get_mut(index)
+            && let Ok(node_type) = NodeType::try_from(node.node_type)
+        {
+            insert_span(&mut node.tags, object.span(), node_type);
+            if let Some(comments) = object.comments() {
+                insert_comments(&mut node.tags, comments);
+            }
+            if let Some(layout) = self
+                .trivia
+                .as_ref()
+                .and_then(|trivia| trivia.layout(&object.id()))
+            {
+                insert_layout(&mut node.tags, layout, node_type);
+            }
         }
         Ok(())
     }
+
+    fn fields_to_nodes(&self, fields: &[FieldDeclaration]) -> Result<Vec<Node>, WriterError> {
+        let mut sub_writer = self.branch();
+        for field in fields {
+            sub_writer.write_field_declaration(field)?;
+        }
+        Ok(sub_writer.nodes)
+    }
+}
+
+/// Type nodes are not objects, so their ids are seeded from the object they describe once the
+/// file is written. Struct, union, enum and typedef name types keep the id of their definition in a `Reference`
+/// child, so renaming the definition carries through to every use. Pointer, array and qualified types
+/// hold the type they are built from as a nested `TypeDescription` child, and function types
+/// hold their parameter types as children and their return type, parameter names and
+/// `variadic`/`explicit_void` markers in tags.
+fn type_to_node(c_type: &CType) -> Node {
+    match c_type {
+        CType::Struct(reference) | CType::Union(reference) | CType::Enum(reference) => Node {
+            id: Uuid::nil(),
+            node_type: NodeType::TypeDescription.as_u64(),
+            content: c_type.tag_keyword().unwrap().to_string(),
+            // Tags can be used before their definition, so the name is kept with the id
+            tags: vec![(
+                "identifier",
+                reference.identifier.as_str(),
+                NodeType::TypeDescription.as_u64(),
+            )]
+            .to_tags(),
+            children: vec![
+                reference
+                    .declaration_id
+                    .to_str_node(NodeType::Reference.as_u64()),
+            ],
+        },
+        CType::Named { declaration_id, .. } => Node {
+            id: Uuid::nil(),
+            node_type: NodeType::TypeDescription.as_u64(),
+            content: "typedef".to_string(),
+            tags: HashMap::new(),
+            children: vec![declaration_id.to_str_node(NodeType::Reference.as_u64())],
+        },
+        CType::Pointer(pointee) => Node {
+            id: Uuid::nil(),
+            node_type: NodeType::TypeDescription.as_u64(),
+            content: "pointer".to_string(),
+            tags: HashMap::new(),
+            children: vec![type_to_node(pointee)],
+        },
+        CType::Array(array) => {
+            let mut tags = HashMap::new();
+            if let Some(size) = &array.size {
+                tags.insert(
+                    "size".to_string(),
+                    vec![size.clone().to_str_node(NodeType::NumberLiteral.as_u64())],
+                );
+            }
+            if !array.qualifiers.is_empty() {
+                tags.insert(
+                    "qualifiers".to_string(),
+                    array
+                        .qualifiers
+                        .keywords()
+                        .into_iter()
+                        .map(|keyword| keyword.to_str_node(NodeType::TypeDescription.as_u64()))
+                        .collect(),
+                );
+            }
+            if array.is_static {
+                tags.insert(
+                    "static".to_string(),
+                    vec!["static".to_str_node(NodeType::TypeDescription.as_u64())],
+                );
+            }
+            Node {
+                id: Uuid::nil(),
+                node_type: NodeType::TypeDescription.as_u64(),
+                content: "array".to_string(),
+                tags,
+                children: vec![type_to_node(&array.element)],
+            }
+        }
+        CType::Qualified(qualifiers, base) => Node {
+            id: Uuid::nil(),
+            node_type: NodeType::TypeDescription.as_u64(),
+            content: "qualified".to_string(),
+            tags: vec![(
+                "qualifiers",
+                qualifiers
+                    .keywords()
+                    .into_iter()
+                    .map(|keyword| keyword.to_str_node(NodeType::TypeDescription.as_u64()))
+                    .collect(),
+            )]
+            .to_tags(),
+            children: vec![type_to_node(base)],
+        },
+        CType::Fn(fn_type) => {
+            let mut tags =
+                vec![("return_type", vec![type_to_node(&fn_type.return_type)])].to_tags();
+            // Unnamed parameters keep an empty name, so the names line up with the children
+            if fn_type
+                .parameters
+                .iter()
+                .any(|parameter| parameter.identifier.is_some())
+            {
+                tags.insert(
+                    "parameter_names".to_string(),
+                    fn_type
+                        .parameters
+                        .iter()
+                        .map(|parameter| {
+                            parameter
+                                .identifier
+                                .clone()
+                                .unwrap_or_default()
+                                .to_str_node(NodeType::TypeDescription.as_u64())
+                        })
+                        .collect(),
+                );
+            }
+            insert_parameter_markers(
+                &mut tags,
+                fn_type.is_variadic,
+                fn_type.explicit_void,
+                NodeType::TypeDescription,
+            );
+            Node {
+                id: Uuid::nil(),
+                node_type: NodeType::TypeDescription.as_u64(),
+                content: "function".to_string(),
+                tags,
+                children: fn_type
+                    .parameters
+                    .iter()
+                    .map(|parameter| type_to_node(&parameter.param_type))
+                    .collect(),
+            }
+        }
+        _ => c_type
+            .to_string()
+            .to_str_node(NodeType::TypeDescription.as_u64()),
+    }
+}
+
+/// Adds a `specifiers` tag with one keyword node each, leaving plain declarations without it
+fn insert_specifiers(
+    tags: &mut HashMap<String, Vec<Node>>,
+    specifiers: &Specifiers,
+    node_type: NodeType,
+) {
+    if !specifiers.is_empty() {
+        tags.insert(
+            "specifiers".to_string(),
+            specifiers
+                .keywords()
+                .into_iter()
+                .map(|keyword| keyword.to_str_node(node_type.as_u64()))
+                .collect(),
+        );
+    }
+}
+
+/// Adds `variadic` and `explicit_void` tags to functions whose parameter list ends in `...` or is `(void)`
+fn insert_parameter_markers(
+    tags: &mut HashMap<String, Vec<Node>>,
+    is_variadic: bool,
+    explicit_void: bool,
+    node_type: NodeType,
+) {
+    if is_variadic {
+        tags.insert(
+            "variadic".to_string(),
+            vec!["...".to_str_node(node_type.as_u64())],
+        );
+    }
+    if explicit_void {
+        tags.insert(
+            "explicit_void".to_string(),
+            vec!["void".to_str_node(node_type.as_u64())],
+        );
+    }
+}
+
+/// A `forward` tag for structs, unions and enums declared without a body, as in `struct s;`
+fn forward_marker(is_forward: bool, node_type: NodeType) -> HashMap<String, Vec<Node>> {
+    let mut tags = HashMap::new();
+    if is_forward {
+        tags.insert(
+            "forward".to_string(),
+            vec![";".to_str_node(node_type.as_u64())],
+        );
+    }
+    tags
+}
+
+/// Adds a `span` tag with `line:column:offset` start and end nodes to objects parsed from text.
+/// Objects without a span, like the ones built through the RPC, have no tag
+fn insert_span(tags: &mut HashMap<String, Vec<Node>>, span: Option<Span>, node_type: NodeType) {
+    if let Some(span) = span {
+        tags.insert(
+            "span".to_string(),
+            [span.start, span.end]
+                .into_iter()
+                .map(|position| {
+                    format!("{}:{}:{}", position.line, position.column, position.offset)
+                        .to_str_node(node_type.as_u64())
+                })
+                .collect(),
+        );
+    }
+}
+
+/// Adds `leading_comments` and `trailing_comments` tags with the comments attached to an object
+fn insert_comments(tags: &mut HashMap<String, Vec<Node>>, comments: &Comments) {
+    for (tag, attached) in [
+        ("leading_comments", &comments.leading),
+        ("trailing_comments", &comments.trailing),
+    ] {
+        if !attached.is_empty() {
+            tags.insert(
+                tag.to_string(),
+                attached.iter().map(comment_to_node).collect(),
+            );
+        }
+    }
+}
+
+/// Adds the layout of an object of a file parsed with its trivia: a `blank_lines_before` tag,
+/// a `line_breaks` tag naming where a function signature was broken, and an `indent` tag when
+/// it was not indented as the style
+fn insert_layout(tags: &mut HashMap<String, Vec<Node>>, layout: &Layout, node_type: NodeType) {
+    tags.insert(
+        "blank_lines_before".to_string(),
+        vec![
+            layout
+                .blank_lines_before
+                .to_string()
+                .to_str_node(node_type.as_u64()),
+        ],
+    );
+    if let Some(line_breaks) = layout.line_breaks {
+        let breaks = [
+            ("after_type", line_breaks.after_type),
+            ("before_block", line_breaks.before_block),
+        ];
+        tags.insert(
+            "line_breaks".to_string(),
+            breaks
+                .into_iter()
+                .filter(|(_, broken)| *broken)
+                .map(|(name, _)| name.to_str_node(node_type.as_u64()))
+                .collect(),
+        );
+    }
+    if let Some(indent) = &layout.indent {
+        tags.insert(
+            "indent".to_string(),
+            vec![indent.as_str().to_str_node(node_type.as_u64())],
+        );
+    }
+}
+
+fn comment_to_node(comment: &Comment) -> Node {
+    let mut tags = HashMap::new();
+    insert_span(&mut tags, comment.span, NodeType::Comment);
+    Node {
+        id: comment.id,
+        node_type: NodeType::Comment.as_u64(),
+        content: comment.content.clone(),
+        tags,
+        children: vec![],
+    }
+}
+
+/// Tags of a number literal node, with its value, radix and suffix. Its content is the spelling
+pub fn number_literal_tags(number_literal: &NumberLiteral) -> HashMap<String, Vec<Node>> {
+    let value = match number_literal.value {
+        NumberValue::Integer(value) => ("integer", value.to_string()),
+        NumberValue::Float(value) => ("float", format!("{value:?}")),
+    };
+    let mut tags = vec![
+        (
+            value.0,
+            vec![value.1.to_str_node(NodeType::NumberLiteral.as_u64())],
+        ),
+        (
+            "radix",
+            vec![
+                number_literal
+                    .radix
+                    .base()
+                    .to_string()
+                    .to_str_node(NodeType::NumberLiteral.as_u64()),
+            ],
+        ),
+    ];
+    if !number_literal.suffix.is_empty() {
+        tags.push((
+            "suffix",
+            vec![
+                number_literal
+                    .suffix
+                    .as_str()
+                    .to_str_node(NodeType::NumberLiteral.as_u64()),
+            ],
+        ));
+    }
+    tags.to_tags()
+}
+
+/// Tags of a string literal node, with its prefix and segments. Its content is the value
+pub fn string_literal_tags(string_literal: &StringLiteral) -> HashMap<String, Vec<Node>> {
+    let mut tags = HashMap::new();
+    insert_prefix(&mut tags, string_literal.prefix, NodeType::StringLiteral);
+    // Strings always have their segments, so nodes without them are from files written
+    // before the value was decoded
+    let segments = if string_literal.segments.is_empty() {
+        vec![format!(
+            "{}\"{}\"",
+            string_literal.prefix,
+            escape(&string_literal.value, '"')
+        )]
+    } else {
+        string_literal.segments.clone()
+    };
+    tags.insert(
+        "segments".to_string(),
+        segments
+            .into_iter()
+            .map(|segment| segment.to_str_node(NodeType::StringLiteral.as_u64()))
+            .collect(),
+    );
+    tags
+}
+
+/// Adds a `prefix` tag to character and string literal nodes that have an encoding prefix
+fn insert_prefix(
+    tags: &mut HashMap<String, Vec<Node>>,
+    prefix: EncodingPrefix,
+    node_type: NodeType,
+) {
+    if prefix != EncodingPrefix::None {
+        tags.insert(
+            "prefix".to_string(),
+            vec![prefix.as_str().to_str_node(node_type.as_u64())],
+        );
+    }
 }
 
 impl<'a> NodeWriter<'a> {
@@ -91,6 +508,7 @@ impl<'a> NodeWriter<'a> {
 impl<'a> Writer for NodeWriter<'a> {
     fn write_file(&mut self, src_file: &SourceFile) -> Result<(), WriterError> {
         self.cursor.write_source_file(src_file)?;
+        Header::for_language(&C::new()).write(&mut self.writer)?;
         bincode::serialize_into(&mut self.writer, &self.cursor.nodes).unwrap();
         Ok(())
     }
@@ -98,15 +516,22 @@ impl<'a> Writer for NodeWriter<'a> {
 
 impl<'a> Cursor for NodeCursor<'a> {
     fn write_source_file(&mut self, src_file: &SourceFile) -> Result<(), WriterError> {
-        self.nodes.push(Node {
-            id: Uuid::new_v4(),
+        self.trivia = src_file.trivia.clone().map(Rc::new);
+        let mut file = Node {
+            id: src_file.id,
             node_type: NodeType::SourceFile.as_u64(),
             content: "".to_string(), // TODO maybe the path?
-            tags: HashMap::new(),
+            tags: {
+                let mut tags = HashMap::new();
+                insert_span(&mut tags, src_file.span, NodeType::SourceFile);
+                tags
+            },
             children: self
                 .branch()
                 .to_nodes(&src_file.code.iter().map(|o| o.clone().into()).collect())?,
-        });
+        };
+        seed_nil_ids(&mut file);
+        self.nodes.push(file);
         Ok(())
     }
 
@@ -125,11 +550,12 @@ impl<'a> Cursor for NodeCursor<'a> {
         &mut self,
         assignment_expression: &AssignmentExpression,
     ) -> Result<(), WriterError> {
+        let left = self.to_node(&assignment_expression.left.as_language_object())?;
         self.nodes.push(Node {
             id: assignment_expression.id,
             node_type: NodeType::AssignmentExpression.as_u64(),
-            content: assignment_expression.id_declaration.to_string(),
-            tags: HashMap::new(),
+            content: "=".to_string(),
+            tags: vec![("left", left)].to_tags(),
             children: self.to_node(&assignment_expression.value.as_language_object())?,
         });
         Ok(())
@@ -142,7 +568,7 @@ impl<'a> Cursor for NodeCursor<'a> {
         let left = self.to_node(&binary_expression.left.as_language_object())?;
         let right = self.to_node(&binary_expression.right.as_language_object())?;
         self.nodes.push(Node {
-            id: Uuid::new_v4(),
+            id: binary_expression.id,
             node_type: NodeType::BinaryExpression.as_u64(),
             content: binary_expression.operator.clone(),
             tags: vec![("left", left), ("right", right)].to_tags(),
@@ -151,6 +577,20 @@ impl<'a> Cursor for NodeCursor<'a> {
         Ok(())
     }
 
+    fn write_break_statement(
+        &mut self,
+        break_statement: &BreakStatement,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: break_statement.id,
+            node_type: NodeType::BreakStatement.as_u64(),
+            content: "".to_string(),
+            tags: HashMap::new(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
     fn write_call_expression(
         &mut self,
         call_expression: &CallExpression,
@@ -171,9 +611,47 @@ impl<'a> Cursor for NodeCursor<'a> {
         Ok(())
     }
 
+    fn write_case_statement(&mut self, case_statement: &CaseStatement) -> Result<(), WriterError> {
+        let mut tags = HashMap::new();
+        if let Some(value) = &case_statement.value {
+            tags.insert(
+                "value".to_string(),
+                self.to_node(&value.as_language_object())?,
+            );
+        }
+        self.nodes.push(Node {
+            id: case_statement.id,
+            node_type: NodeType::CaseStatement.as_u64(),
+            content: "".to_string(),
+            tags,
+            children: self.to_nodes(
+                &case_statement
+                    .body
+                    .iter()
+                    .map(|o| o.clone().into())
+                    .collect(),
+            )?,
+        });
+        Ok(())
+    }
+
+    fn write_cast_expression(
+        &mut self,
+        cast_expression: &CastExpression,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: cast_expression.id,
+            node_type: NodeType::CastExpression.as_u64(),
+            content: "".to_string(),
+            tags: vec![("type", vec![type_to_node(&cast_expression.cast_type)])].to_tags(),
+            children: self.to_node(&cast_expression.value.as_language_object())?,
+        });
+        Ok(())
+    }
+
     fn write_comment(&mut self, comment: &Comment) -> Result<(), WriterError> {
         self.nodes.push(Node {
-            id: Uuid::new_v4(),
+            id: comment.id,
             node_type: NodeType::Comment.as_u64(),
             content: comment.content.clone(),
             tags: HashMap::new(),
@@ -182,21 +660,62 @@ impl<'a> Cursor for NodeCursor<'a> {
         Ok(())
     }
 
+    // Attached comments are tags of the object node, added once it is written
+    fn write_leading_comments(&mut self, _: &Comments) -> Result<(), WriterError> {
+        Ok(())
+    }
+
+    fn write_trailing_comments(&mut self, _: &Comments) -> Result<(), WriterError> {
+        Ok(())
+    }
+
+    fn write_conditional_expression(
+        &mut self,
+        conditional_expression: &ConditionalExpression,
+    ) -> Result<(), WriterError> {
+        let condition = self.to_node(&conditional_expression.condition.as_language_object())?;
+        let consequence = self.to_node(&conditional_expression.consequence.as_language_object())?;
+        let alternative = self.to_node(&conditional_expression.alternative.as_language_object())?;
+        self.nodes.push(Node {
+            id: conditional_expression.id,
+            node_type: NodeType::ConditionalExpression.as_u64(),
+            content: "".to_string(),
+            tags: vec![
+                ("condition", condition),
+                ("consequence", consequence),
+                ("alternative", alternative),
+            ]
+            .to_tags(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_continue_statement(
+        &mut self,
+        continue_statement: &ContinueStatement,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: continue_statement.id,
+            node_type: NodeType::ContinueStatement.as_u64(),
+            content: "".to_string(),
+            tags: HashMap::new(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
     fn write_declaration(&mut self, declaration: &Declaration) -> Result<(), WriterError> {
+        let mut tags = vec![("type", vec![type_to_node(&declaration.primitive_type)])].to_tags();
+        insert_specifiers(&mut tags, &declaration.specifiers, NodeType::Declaration);
+        // Declarations of a list are written directly, so they carry their own span and comments
+        insert_span(&mut tags, declaration.span, NodeType::Declaration);
+        insert_comments(&mut tags, &declaration.comments);
         self.nodes.push(Node {
             id: declaration.id,
             node_type: NodeType::Declaration.as_u64(),
             content: declaration.identifier.clone(),
-            tags: vec![(
-                "type",
-                vec![
-                    declaration
-                        .primitive_type
-                        .as_str()
-                        .to_str_node(NodeType::TypeDescription.as_u64()),
-                ],
-            )]
-            .to_tags(),
+            tags,
             children: if let Some(value) = &declaration.value {
                 self.to_node(&value.as_language_object())?
             } else {
@@ -206,17 +725,167 @@ impl<'a> Cursor for NodeCursor<'a> {
         Ok(())
     }
 
+    fn write_declaration_list(
+        &mut self,
+        declaration_list: &DeclarationList,
+    ) -> Result<(), WriterError> {
+        let mut branch = self.branch();
+        for declaration in &declaration_list.declarations {
+            branch.write_declaration(declaration)?;
+        }
+        let children = branch.nodes;
+        self.nodes.push(Node {
+            id: declaration_list.id,
+            node_type: NodeType::DeclarationList.as_u64(),
+            content: "".to_string(),
+            tags: HashMap::new(),
+            children,
+        });
+        Ok(())
+    }
+
+    fn write_do_statement(&mut self, do_statement: &DoStatement) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: do_statement.id,
+            node_type: NodeType::DoStatement.as_u64(),
+            content: "".to_string(),
+            tags: vec![(
+                "condition",
+                self.to_node(&do_statement.condition.as_language_object())?,
+            )]
+            .to_tags(),
+            children: self.to_node(&(*do_statement.body).clone().into())?,
+        });
+        Ok(())
+    }
+
     fn write_else_clause(&mut self, else_clause: &ElseClause) -> Result<(), WriterError> {
         self.nodes.push(Node {
-            id: Uuid::new_v4(),
+            id: else_clause.id,
             node_type: NodeType::ElseClause.as_u64(),
             content: "".to_string(),
             tags: HashMap::new(),
-            children: {
-                let mut branch = self.branch();
-                else_clause.body.write(&mut branch)?;
-                branch.nodes
+            children: self.to_node(&(*else_clause.body).clone().into())?,
+        });
+        Ok(())
+    }
+
+    fn write_enum_definition(
+        &mut self,
+        enum_definition: &EnumDefinition,
+    ) -> Result<(), WriterError> {
+        let mut branch = self.branch();
+        for enumerator in &enum_definition.enumerators {
+            branch.write_enumerator(enumerator)?;
+        }
+        self.nodes.push(Node {
+            id: enum_definition.id,
+            node_type: NodeType::EnumDefinition.as_u64(),
+            content: enum_definition.identifier.clone(),
+            tags: forward_marker(enum_definition.is_forward, NodeType::EnumDefinition),
+            children: branch.nodes,
+        });
+        Ok(())
+    }
+
+    fn write_enumerator(&mut self, enumerator: &Enumerator) -> Result<(), WriterError> {
+        let mut tags = HashMap::new();
+        insert_span(&mut tags, enumerator.span, NodeType::Enumerator);
+        insert_comments(&mut tags, &enumerator.comments);
+        self.nodes.push(Node {
+            id: enumerator.id,
+            node_type: NodeType::Enumerator.as_u64(),
+            content: enumerator.identifier.clone(),
+            tags,
+            children: if let Some(value) = &enumerator.value {
+                self.to_node(&value.as_language_object())?
+            } else {
+                vec![]
+            },
+        });
+        Ok(())
+    }
+
+    fn write_field_declaration(
+        &mut self,
+        field_declaration: &FieldDeclaration,
+    ) -> Result<(), WriterError> {
+        let mut tags = vec![("type", vec![type_to_node(&field_declaration.field_type)])].to_tags();
+        insert_span(
+            &mut tags,
+            field_declaration.span,
+            NodeType::FieldDeclaration,
+        );
+        insert_comments(&mut tags, &field_declaration.comments);
+        if field_declaration.shares_declaration {
+            tags.insert(
+                "shares_declaration".to_string(),
+                vec![",".to_str_node(NodeType::FieldDeclaration.as_u64())],
+            );
+        }
+        self.nodes.push(Node {
+            id: field_declaration.id,
+            node_type: NodeType::FieldDeclaration.as_u64(),
+            content: field_declaration.identifier.clone(),
+            tags,
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_field_expression(
+        &mut self,
+        field_expression: &FieldExpression,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: field_expression.id,
+            node_type: NodeType::FieldExpression.as_u64(),
+            content: if field_expression.declaration_id != Uuid::nil() {
+                field_expression.declaration_id.to_string()
+            } else {
+                field_expression.identifier.clone()
             },
+            tags: vec![(
+                "operator",
+                vec![
+                    field_expression
+                        .operator
+                        .as_str()
+                        .to_str_node(NodeType::FieldExpression.as_u64()),
+                ],
+            )]
+            .to_tags(),
+            children: self.to_node(&field_expression.argument.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_for_statement(&mut self, for_statement: &ForStatement) -> Result<(), WriterError> {
+        let mut tags = HashMap::new();
+        if let Some(initializer) = &for_statement.initializer {
+            tags.insert(
+                "initializer".to_string(),
+                self.to_node(&(**initializer).clone().into())?,
+            );
+        }
+        if let Some(condition) = &for_statement.condition {
+            tags.insert(
+                "condition".to_string(),
+                self.to_node(&condition.as_language_object())?,
+            );
+        }
+        if let Some(update) = &for_statement.update {
+            tags.insert(
+                "update".to_string(),
+                self.to_node(&update.as_language_object())?,
+            );
+        }
+        self.nodes.push(Node {
+            id: for_statement.id,
+            node_type: NodeType::ForStatement.as_u64(),
+            content: "".to_string(),
+            tags,
+            children: self.to_node(&(*for_statement.body).clone().into())?,
         });
         Ok(())
     }
@@ -231,28 +900,45 @@ impl<'a> Cursor for NodeCursor<'a> {
             .map(
                 |FunctionParameter {
                      id,
+                     span,
+                     comments,
                      identifier,
                      param_type,
                  }| {
+                    let mut tags = vec![("type", vec![type_to_node(param_type)])].to_tags();
+                    insert_span(&mut tags, *span, NodeType::FunctionParameter);
+                    insert_comments(&mut tags, comments);
                     Node {
                         id: *id,
                         node_type: NodeType::FunctionParameter.as_u64(),
                         content: identifier.clone(),
-                        tags: vec![(
-                            "type",
-                            vec![
-                                param_type
-                                    .as_str()
-                                    .to_str_node(NodeType::TypeDescription.as_u64()),
-                            ],
-                        )]
-                        .to_tags(),
+                        tags,
                         children: vec![],
                     }
                 },
             )
             .collect::<Vec<Node>>();
 
+        let mut tags = vec![
+            (
+                "return_type",
+                vec![type_to_node(&function_declaration.return_type)],
+            ),
+            ("args", args_mapped),
+        ]
+        .to_tags();
+        insert_specifiers(
+            &mut tags,
+            &function_declaration.specifiers,
+            NodeType::FunctionDeclaration,
+        );
+        insert_parameter_markers(
+            &mut tags,
+            function_declaration.is_variadic,
+            function_declaration.explicit_void,
+            NodeType::FunctionDeclaration,
+        );
+
         self.nodes.push(Node {
             node_type: NodeType::FunctionDeclaration.as_u64(),
             id: self
@@ -263,19 +949,7 @@ impl<'a> Cursor for NodeCursor<'a> {
                     true,
                 )
                 .unwrap_or(function_declaration.id),
-            tags: vec![
-                (
-                    "return_type",
-                    vec![
-                        function_declaration
-                            .return_type
-                            .as_str()
-                            .to_str_node(NodeType::TypeDescription.as_u64()),
-                    ],
-                ),
-                ("args", args_mapped),
-            ]
-            .to_tags(),
+            tags,
             children: vec![],
             content: function_declaration.identifier.clone(),
         });
@@ -294,22 +968,19 @@ impl<'a> Cursor for NodeCursor<'a> {
             .map(
                 |FunctionParameter {
                      id,
+                     span,
+                     comments,
                      identifier,
                      param_type,
                  }| {
+                    let mut tags = vec![("type", vec![type_to_node(param_type)])].to_tags();
+                    insert_span(&mut tags, *span, NodeType::FunctionParameter);
+                    insert_comments(&mut tags, comments);
                     Node {
                         id: *id,
                         node_type: NodeType::FunctionParameter.as_u64(),
                         content: identifier.clone(),
-                        tags: vec![(
-                            "type",
-                            vec![
-                                param_type
-                                    .as_str()
-                                    .to_str_node(NodeType::TypeDescription.as_u64()),
-                            ],
-                        )]
-                        .to_tags(),
+                        tags,
                         children: vec![],
                     }
                 },
@@ -317,30 +988,55 @@ impl<'a> Cursor for NodeCursor<'a> {
             .collect::<Vec<Node>>();
 
         subcontext.write_compound_statement(&function_definition.compound_statement)?;
-        let children = subcontext.nodes;
+        let mut children = subcontext.nodes;
+        if let Some(body) = children.first_mut() {
+            insert_comments(
+                &mut body.tags,
+                &function_definition.compound_statement.comments,
+            );
+        }
+
+        let mut tags = vec![
+            (
+                "return_type",
+                vec![type_to_node(&function_definition.return_type)],
+            ),
+            ("args", args_mapped),
+        ]
+        .to_tags();
+        insert_specifiers(
+            &mut tags,
+            &function_definition.specifiers,
+            NodeType::FunctionDefinition,
+        );
+        insert_parameter_markers(
+            &mut tags,
+            function_definition.is_variadic,
+            function_definition.explicit_void,
+            NodeType::FunctionDefinition,
+        );
 
         self.nodes.push(Node {
             node_type: NodeType::FunctionDefinition.as_u64(),
             id: function_definition.id,
-            tags: vec![
-                (
-                    "return_type",
-                    vec![
-                        function_definition
-                            .return_type
-                            .as_str()
-                            .to_str_node(NodeType::TypeDescription.as_u64()),
-                    ],
-                ),
-                ("args", args_mapped),
-            ]
-            .to_tags(),
+            tags,
             children,
             content: function_definition.identifier.clone(),
         });
         Ok(())
     }
 
+    fn write_goto_statement(&mut self, goto_statement: &GotoStatement) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: goto_statement.id,
+            node_type: NodeType::GotoStatement.as_u64(),
+            content: goto_statement.label_id.to_string(),
+            tags: HashMap::new(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
     fn write_if_statement(&mut self, if_statement: &IfStatement) -> Result<(), WriterError> {
         let mut tags = vec![(
             "condition",
@@ -365,26 +1061,227 @@ impl<'a> Cursor for NodeCursor<'a> {
             }
         };
         self.nodes.push(Node {
-            id: Uuid::new_v4(),
+            id: if_statement.id,
             node_type: NodeType::IfStatement.as_u64(),
             content: "".to_string(),
             tags,
-            children: {
-                let mut branch = self.branch();
-                if_statement.body.write(&mut branch)?;
-                branch.nodes
-            },
+            children: self.to_node(&(*if_statement.body).clone().into())?,
+        });
+        Ok(())
+    }
+
+    fn write_initializer_list(
+        &mut self,
+        initializer_list: &InitializerList,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: initializer_list.id,
+            node_type: NodeType::InitializerList.as_u64(),
+            content: "".to_string(),
+            tags: HashMap::new(),
+            children: self.to_nodes(&ExpressionObject::as_language_objects(
+                &initializer_list.elements,
+            ))?,
+        });
+        Ok(())
+    }
+
+    /// Field designators keep the member name as content, subscript designators hold their
+    /// index expression as the only child
+    fn write_initializer_pair(
+        &mut self,
+        initializer_pair: &InitializerPair,
+    ) -> Result<(), WriterError> {
+        let mut designators = Vec::new();
+        for designator in &initializer_pair.designators {
+            designators.push(match designator {
+                Designator::Field(field) => Node {
+                    id: Uuid::nil(),
+                    node_type: NodeType::Designator.as_u64(),
+                    content: field.clone(),
+                    tags: HashMap::new(),
+                    children: vec![],
+                },
+                Designator::Subscript(index) => Node {
+                    id: Uuid::nil(),
+                    node_type: NodeType::Designator.as_u64(),
+                    content: "".to_string(),
+                    tags: HashMap::new(),
+                    children: self.to_node(&index.as_language_object())?,
+                },
+            });
+        }
+        self.nodes.push(Node {
+            id: initializer_pair.id,
+            node_type: NodeType::InitializerPair.as_u64(),
+            content: "".to_string(),
+            tags: vec![("designators", designators)].to_tags(),
+            children: self.to_node(&initializer_pair.value.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_labeled_statement(
+        &mut self,
+        labeled_statement: &LabeledStatement,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: labeled_statement.id,
+            node_type: NodeType::LabeledStatement.as_u64(),
+            content: labeled_statement.label.clone(),
+            tags: HashMap::new(),
+            children: self.to_nodes(
+                &labeled_statement
+                    .statement
+                    .iter()
+                    .map(|statement| (**statement).clone().into())
+                    .collect(),
+            )?,
         });
         Ok(())
     }
 
     fn write_number_literal(&mut self, number_literal: &NumberLiteral) -> Result<(), WriterError> {
-        self.nodes.push(
-            number_literal
-                .value
-                .to_string()
-                .to_str_node(NodeType::NumberLiteral.as_u64()),
-        );
+        self.nodes.push(Node {
+            id: number_literal.id,
+            node_type: NodeType::NumberLiteral.as_u64(),
+            content: number_literal.spelling.clone(),
+            tags: number_literal_tags(number_literal),
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ParenthesizedExpression,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: parenthesized_expression.id,
+            node_type: NodeType::ParenthesizedExpression.as_u64(),
+            content: "".to_string(),
+            tags: HashMap::new(),
+            children: self.to_node(&parenthesized_expression.value.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_preproc_call(&mut self, preproc_call: &PreprocCall) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: preproc_call.id,
+            node_type: NodeType::PreprocCall.as_u64(),
+            content: preproc_call.directive.clone(),
+            tags: vec![(
+                "argument",
+                vec![
+                    preproc_call
+                        .argument
+                        .as_str()
+                        .to_str_node(NodeType::PreprocCall.as_u64()),
+                ],
+            )]
+            .to_tags(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_preproc_define(&mut self, preproc_define: &PreprocDefine) -> Result<(), WriterError> {
+        let mut tags = vec![(
+            "value",
+            vec![
+                preproc_define
+                    .value
+                    .as_str()
+                    .to_str_node(NodeType::PreprocDefine.as_u64()),
+            ],
+        )]
+        .to_tags();
+        // Object-like macros have no parameters tag at all, function-like ones may have an
+        // empty one
+        if let Some(parameters) = &preproc_define.parameters {
+            tags.insert(
+                "parameters".to_string(),
+                parameters
+                    .iter()
+                    .map(|parameter| {
+                        parameter
+                            .as_str()
+                            .to_str_node(NodeType::PreprocDefine.as_u64())
+                    })
+                    .collect(),
+            );
+        }
+        self.nodes.push(Node {
+            id: preproc_define.id,
+            node_type: NodeType::PreprocDefine.as_u64(),
+            content: preproc_define.identifier.clone(),
+            tags,
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_preproc_if(&mut self, preproc_if: &PreprocIf) -> Result<(), WriterError> {
+        let mut tags = vec![(
+            "directive",
+            vec![
+                preproc_if
+                    .directive
+                    .as_str()
+                    .to_str_node(NodeType::PreprocIf.as_u64()),
+            ],
+        )]
+        .to_tags();
+        if let Some(alternative) = &preproc_if.alternative {
+            tags.insert(
+                "alternative".to_string(),
+                self.to_node(&CLanguageObject::PreprocIf(*alternative.clone()))?,
+            );
+        }
+        self.nodes.push(Node {
+            id: preproc_if.id,
+            node_type: NodeType::PreprocIf.as_u64(),
+            content: preproc_if.condition.clone(),
+            tags,
+            children: self.to_nodes(&preproc_if.body.iter().map(|o| o.clone().into()).collect())?,
+        });
+        Ok(())
+    }
+
+    fn write_preproc_if_statement(
+        &mut self,
+        preproc_if_statement: &PreprocIfStatement,
+    ) -> Result<(), WriterError> {
+        let mut tags = vec![(
+            "directive",
+            vec![
+                preproc_if_statement
+                    .directive
+                    .as_str()
+                    .to_str_node(NodeType::PreprocIfStatement.as_u64()),
+            ],
+        )]
+        .to_tags();
+        if let Some(alternative) = &preproc_if_statement.alternative {
+            tags.insert(
+                "alternative".to_string(),
+                self.to_node(&CLanguageObject::PreprocIfStatement(*alternative.clone()))?,
+            );
+        }
+        self.nodes.push(Node {
+            id: preproc_if_statement.id,
+            node_type: NodeType::PreprocIfStatement.as_u64(),
+            content: preproc_if_statement.condition.clone(),
+            tags,
+            children: self.to_nodes(
+                &preproc_if_statement
+                    .body
+                    .iter()
+                    .map(|o| o.clone().into())
+                    .collect(),
+            )?,
+        });
         Ok(())
     }
 
@@ -394,7 +1291,7 @@ impl<'a> Cursor for NodeCursor<'a> {
     ) -> Result<(), WriterError> {
         self.nodes.push(Node {
             node_type: NodeType::PreprocInclude.as_u64(),
-            id: Uuid::new_v4(),
+            id: preproc_include.id,
             tags: HashMap::new(),
             content: preproc_include.content.clone(),
             children: vec![],
@@ -405,7 +1302,12 @@ impl<'a> Cursor for NodeCursor<'a> {
     fn write_reference(&mut self, reference: &Reference) -> Result<(), WriterError> {
         let value = Node {
             id: reference.id,
-            content: reference.declaration_id.to_string(),
+            // Names declared outside the file have no declaration to refer to
+            content: if reference.declaration_id != Uuid::nil() {
+                reference.declaration_id.to_string()
+            } else {
+                reference.identifier.clone()
+            },
             node_type: NodeType::Reference.as_u64(),
             tags: HashMap::new(),
             children: vec![],
@@ -420,7 +1322,7 @@ impl<'a> Cursor for NodeCursor<'a> {
     ) -> Result<(), WriterError> {
         self.nodes.push(Node {
             node_type: NodeType::ReturnStatement.as_u64(),
-            id: Uuid::new_v4(),
+            id: return_statement.id,
             tags: HashMap::new(),
             content: "".to_string(),
             children: match &return_statement.value {
@@ -431,13 +1333,105 @@ impl<'a> Cursor for NodeCursor<'a> {
         Ok(())
     }
 
+    fn write_sizeof_expression(
+        &mut self,
+        sizeof_expression: &SizeofExpression,
+    ) -> Result<(), WriterError> {
+        let mut tags = HashMap::new();
+        if let Some(argument_type) = &sizeof_expression.argument_type {
+            tags.insert("type".to_string(), vec![type_to_node(argument_type)]);
+        }
+        let children = match &sizeof_expression.value {
+            Some(value) => self.to_node(&value.as_language_object())?,
+            None => vec![],
+        };
+        self.nodes.push(Node {
+            id: sizeof_expression.id,
+            node_type: NodeType::SizeofExpression.as_u64(),
+            content: "".to_string(),
+            tags,
+            children,
+        });
+        Ok(())
+    }
+
+    fn write_char_literal(&mut self, char_literal: &CharLiteral) -> Result<(), WriterError> {
+        let mut tags = vec![(
+            "spelling",
+            vec![
+                char_literal
+                    .spelling
+                    .as_str()
+                    .to_str_node(NodeType::CharLiteral.as_u64()),
+            ],
+        )]
+        .to_tags();
+        insert_prefix(&mut tags, char_literal.prefix, NodeType::CharLiteral);
+        self.nodes.push(Node {
+            id: char_literal.id,
+            node_type: NodeType::CharLiteral.as_u64(),
+            content: char_literal.value.clone(),
+            tags,
+            children: vec![],
+        });
+        Ok(())
+    }
+
     fn write_string_literal(&mut self, string_literal: &StringLiteral) -> Result<(), WriterError> {
-        self.nodes.push(
-            string_literal
-                .value
-                .to_string()
-                .to_str_node(NodeType::StringLiteral.as_u64()),
-        );
+        self.nodes.push(Node {
+            id: string_literal.id,
+            node_type: NodeType::StringLiteral.as_u64(),
+            content: string_literal.value.clone(),
+            tags: string_literal_tags(string_literal),
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_struct_definition(
+        &mut self,
+        struct_definition: &StructDefinition,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: struct_definition.id,
+            node_type: NodeType::StructDefinition.as_u64(),
+            content: struct_definition.identifier.clone(),
+            tags: forward_marker(struct_definition.is_forward, NodeType::StructDefinition),
+            children: self.fields_to_nodes(&struct_definition.fields)?,
+        });
+        Ok(())
+    }
+
+    fn write_subscript_expression(
+        &mut self,
+        subscript_expression: &SubscriptExpression,
+    ) -> Result<(), WriterError> {
+        let index = self.to_node(&subscript_expression.index.as_language_object())?;
+        self.nodes.push(Node {
+            id: subscript_expression.id,
+            node_type: NodeType::SubscriptExpression.as_u64(),
+            content: "".to_string(),
+            tags: vec![("index", index)].to_tags(),
+            children: self.to_node(&subscript_expression.argument.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_switch_statement(
+        &mut self,
+        switch_statement: &SwitchStatement,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: switch_statement.id,
+            node_type: NodeType::SwitchStatement.as_u64(),
+            content: "".to_string(),
+            tags: vec![(
+                "condition",
+                self.to_node(&switch_statement.condition.as_language_object())?,
+            )]
+            .to_tags(),
+            children: self.to_node(&(*switch_statement.body).clone().into())?,
+        });
         Ok(())
     }
 
@@ -446,7 +1440,7 @@ impl<'a> Cursor for NodeCursor<'a> {
         compound_statement: &CompoundStatement,
     ) -> Result<(), WriterError> {
         self.nodes.push(Node {
-            id: Uuid::new_v4(),
+            id: compound_statement.id,
             node_type: NodeType::CompoundStatement.as_u64(),
             content: "".to_string(),
             tags: HashMap::new(),
@@ -460,4 +1454,87 @@ impl<'a> Cursor for NodeCursor<'a> {
         });
         Ok(())
     }
+
+    fn write_type_definition(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: type_definition.id,
+            node_type: NodeType::TypeDefinition.as_u64(),
+            content: type_definition.identifier.clone(),
+            tags: vec![("type", vec![type_to_node(&type_definition.aliased_type)])].to_tags(),
+            children: vec![],
+        });
+        Ok(())
+    }
+
+    fn write_unary_expression(
+        &mut self,
+        unary_expression: &UnaryExpression,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: unary_expression.id,
+            node_type: NodeType::UnaryExpression.as_u64(),
+            content: unary_expression.operator.clone(),
+            tags: HashMap::new(),
+            children: self.to_node(&unary_expression.argument.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_union_definition(
+        &mut self,
+        union_definition: &UnionDefinition,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: union_definition.id,
+            node_type: NodeType::UnionDefinition.as_u64(),
+            content: union_definition.identifier.clone(),
+            tags: forward_marker(union_definition.is_forward, NodeType::UnionDefinition),
+            children: self.fields_to_nodes(&union_definition.fields)?,
+        });
+        Ok(())
+    }
+
+    fn write_update_expression(
+        &mut self,
+        update_expression: &UpdateExpression,
+    ) -> Result<(), WriterError> {
+        let position = if update_expression.is_prefix {
+            "prefix"
+        } else {
+            "postfix"
+        };
+        self.nodes.push(Node {
+            id: update_expression.id,
+            node_type: NodeType::UpdateExpression.as_u64(),
+            content: update_expression.operator.clone(),
+            tags: vec![(
+                "position",
+                vec![position.to_str_node(NodeType::UpdateExpression.as_u64())],
+            )]
+            .to_tags(),
+            children: self.to_node(&update_expression.argument.as_language_object())?,
+        });
+        Ok(())
+    }
+
+    fn write_while_statement(
+        &mut self,
+        while_statement: &WhileStatement,
+    ) -> Result<(), WriterError> {
+        self.nodes.push(Node {
+            id: while_statement.id,
+            node_type: NodeType::WhileStatement.as_u64(),
+            content: "".to_string(),
+            tags: vec![(
+                "condition",
+                self.to_node(&while_statement.condition.as_language_object())?,
+            )]
+            .to_tags(),
+            children: self.to_node(&(*while_statement.body).clone().into())?,
+        });
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use crate::node::header;
+
+#[derive(Debug, Clone, Copy)]
 pub enum NodeType {
     SourceFile,
     AssignmentExpression,
@@ -19,10 +21,55 @@ pub enum NodeType {
     TypeDescription,
     Symbol,
     CompoundStatement,
+    WhileStatement,
+    DoStatement,
+    ForStatement,
+    SwitchStatement,
+    CaseStatement,
+    BreakStatement,
+    ContinueStatement,
+    StructDefinition,
+    UnionDefinition,
+    EnumDefinition,
+    FieldDeclaration,
+    Enumerator,
+    UnaryExpression,
+    UpdateExpression,
+    CastExpression,
+    SizeofExpression,
+    ConditionalExpression,
+    FieldExpression,
+    SubscriptExpression,
+    ParenthesizedExpression,
+    PreprocDefine,
+    PreprocCall,
+    PreprocIf,
+    PreprocIfStatement,
+    DeclarationList,
+    InitializerList,
+    InitializerPair,
+    Designator,
+    CharLiteral,
+    LabeledStatement,
+    GotoStatement,
+    TypeDefinition,
     Unknown,
 }
 
 impl NodeType {
+    /// Hash of the code of every node type, which changes when one is added, removed or
+    /// renumbered
+    pub fn schema_hash() -> u64 {
+        let schema = (0..=NodeType::Unknown.as_u64())
+            .filter_map(|code| {
+                let node_type = NodeType::try_from(code).ok()?;
+                Some(format!("{node_type:?}={code}"))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        header::schema_hash(&schema)
+    }
+
     pub fn as_u64(&self) -> u64 {
         match self {
             NodeType::SourceFile => 0,
@@ -44,6 +91,38 @@ impl NodeType {
             NodeType::TypeDescription => 17,
             NodeType::Symbol => 18,
             NodeType::CompoundStatement => 19,
+            NodeType::WhileStatement => 20,
+            NodeType::DoStatement => 21,
+            NodeType::ForStatement => 22,
+            NodeType::SwitchStatement => 23,
+            NodeType::CaseStatement => 24,
+            NodeType::BreakStatement => 25,
+            NodeType::ContinueStatement => 26,
+            NodeType::StructDefinition => 27,
+            NodeType::UnionDefinition => 28,
+            NodeType::EnumDefinition => 29,
+            NodeType::FieldDeclaration => 30,
+            NodeType::Enumerator => 31,
+            NodeType::UnaryExpression => 32,
+            NodeType::UpdateExpression => 33,
+            NodeType::CastExpression => 34,
+            NodeType::SizeofExpression => 35,
+            NodeType::ConditionalExpression => 36,
+            NodeType::FieldExpression => 37,
+            NodeType::SubscriptExpression => 38,
+            NodeType::ParenthesizedExpression => 39,
+            NodeType::PreprocDefine => 40,
+            NodeType::PreprocCall => 41,
+            NodeType::PreprocIf => 42,
+            NodeType::PreprocIfStatement => 43,
+            NodeType::DeclarationList => 44,
+            NodeType::InitializerList => 45,
+            NodeType::InitializerPair => 46,
+            NodeType::Designator => 47,
+            NodeType::CharLiteral => 48,
+            NodeType::LabeledStatement => 49,
+            NodeType::GotoStatement => 50,
+            NodeType::TypeDefinition => 51,
             NodeType::Unknown => 404,
         }
     }
@@ -71,14 +150,49 @@ impl From<NodeType> for u64 {
             NodeType::TypeDescription => 17,
             NodeType::Symbol => 18,
             NodeType::CompoundStatement => 19,
+            NodeType::WhileStatement => 20,
+            NodeType::DoStatement => 21,
+            NodeType::ForStatement => 22,
+            NodeType::SwitchStatement => 23,
+            NodeType::CaseStatement => 24,
+            NodeType::BreakStatement => 25,
+            NodeType::ContinueStatement => 26,
+            NodeType::StructDefinition => 27,
+            NodeType::UnionDefinition => 28,
+            NodeType::EnumDefinition => 29,
+            NodeType::FieldDeclaration => 30,
+            NodeType::Enumerator => 31,
+            NodeType::UnaryExpression => 32,
+            NodeType::UpdateExpression => 33,
+            NodeType::CastExpression => 34,
+            NodeType::SizeofExpression => 35,
+            NodeType::ConditionalExpression => 36,
+            NodeType::FieldExpression => 37,
+            NodeType::SubscriptExpression => 38,
+            NodeType::ParenthesizedExpression => 39,
+            NodeType::PreprocDefine => 40,
+            NodeType::PreprocCall => 41,
+            NodeType::PreprocIf => 42,
+            NodeType::PreprocIfStatement => 43,
+            NodeType::DeclarationList => 44,
+            NodeType::InitializerList => 45,
+            NodeType::InitializerPair => 46,
+            NodeType::Designator => 47,
+            NodeType::CharLiteral => 48,
+            NodeType::LabeledStatement => 49,
+            NodeType::GotoStatement => 50,
+            NodeType::TypeDefinition => 51,
             NodeType::Unknown => 404,
         }
     }
 }
 
-impl From<u64> for NodeType {
-    fn from(value: u64) -> Self {
-        match value {
+impl TryFrom<u64> for NodeType {
+    /// The code, which no node type has
+    type Error = u64;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        Ok(match value {
             0 => NodeType::SourceFile,
             1 => NodeType::AssignmentExpression,
             2 => NodeType::BinaryExpression,
@@ -98,8 +212,40 @@ impl From<u64> for NodeType {
             17 => NodeType::TypeDescription,
             18 => NodeType::Symbol,
             19 => NodeType::CompoundStatement,
+            20 => NodeType::WhileStatement,
+            21 => NodeType::DoStatement,
+            22 => NodeType::ForStatement,
+            23 => NodeType::SwitchStatement,
+            24 => NodeType::CaseStatement,
+            25 => NodeType::BreakStatement,
+            26 => NodeType::ContinueStatement,
+            27 => NodeType::StructDefinition,
+            28 => NodeType::UnionDefinition,
+            29 => NodeType::EnumDefinition,
+            30 => NodeType::FieldDeclaration,
+            31 => NodeType::Enumerator,
+            32 => NodeType::UnaryExpression,
+            33 => NodeType::UpdateExpression,
+            34 => NodeType::CastExpression,
+            35 => NodeType::SizeofExpression,
+            36 => NodeType::ConditionalExpression,
+            37 => NodeType::FieldExpression,
+            38 => NodeType::SubscriptExpression,
+            39 => NodeType::ParenthesizedExpression,
+            40 => NodeType::PreprocDefine,
+            41 => NodeType::PreprocCall,
+            42 => NodeType::PreprocIf,
+            43 => NodeType::PreprocIfStatement,
+            44 => NodeType::DeclarationList,
+            45 => NodeType::InitializerList,
+            46 => NodeType::InitializerPair,
+            47 => NodeType::Designator,
+            48 => NodeType::CharLiteral,
+            49 => NodeType::LabeledStatement,
+            50 => NodeType::GotoStatement,
+            51 => NodeType::TypeDefinition,
             404 => NodeType::Unknown,
-            _ => panic!("NodeType not supported"),
-        }
+            _ => return Err(value),
+        })
     }
 }
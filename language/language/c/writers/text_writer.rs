@@ -1,25 +1,69 @@
 pub mod style;
 
+use uuid::Uuid;
+
 use super::{Writer, writer_error::WriterError};
+use crate::language::c::c_type::CType;
 use crate::language::c::language_object::{
     declaration_object::{
+        DeclarationObject,
         declaration::Declaration,
+        declaration_list::DeclarationList,
+        enum_definition::{EnumDefinition, enumerator::Enumerator},
         function_declaration::{FunctionDeclaration, function_parameter::FunctionParameter},
         function_definition::FunctionDefinition,
+        preproc_call::PreprocCall,
+        preproc_define::PreprocDefine,
+        preproc_if::PreprocIf,
         preproc_include::PreprocInclude,
+        struct_definition::{StructDefinition, field_declaration::FieldDeclaration},
+        type_definition::TypeDefinition,
+        union_definition::UnionDefinition,
     },
     expression_object::{
-        assignment_expression::AssignmentExpression, binary_expression::BinaryExpression,
-        call_expression::CallExpression, number_literal::NumberLiteral, reference::Reference,
+        ExpressionObject,
+        assignment_expression::AssignmentExpression,
+        binary_expression::BinaryExpression,
+        call_expression::CallExpression,
+        cast_expression::CastExpression,
+        char_literal::CharLiteral,
+        conditional_expression::ConditionalExpression,
+        field_expression::FieldExpression,
+        initializer_list::InitializerList,
+        initializer_pair::{Designator, InitializerPair},
+        number_literal::NumberLiteral,
+        parenthesized_expression::ParenthesizedExpression,
+        reference::Reference,
+        sizeof_expression::SizeofExpression,
         string_literal::StringLiteral,
+        subscript_expression::SubscriptExpression,
+        unary_expression::UnaryExpression,
+        update_expression::UpdateExpression,
+    },
+    special_object::{
+        comment::{Comment, Comments},
+        source_file::SourceFile,
+        trivia::{LineBreaks, Trivia},
+        unknown::Unknown,
     },
-    special_object::{comment::Comment, source_file::SourceFile, unknown::Unknown},
     statement_object::{
+        break_statement::BreakStatement,
+        case_statement::CaseStatement,
         compound_statement::CompoundStatement,
-        if_statement::{IfStatement, else_clause::ElseClause},
+        continue_statement::ContinueStatement,
+        do_statement::DoStatement,
+        for_statement::ForStatement,
+        goto_statement::GotoStatement,
+        if_statement::{ElseStatement, IfStatement, else_clause::ElseClause},
+        labeled_statement::LabeledStatement,
+        preproc_if_statement::PreprocIfStatement,
         return_statement::ReturnStatement,
+        switch_statement::SwitchStatement,
+        while_statement::WhileStatement,
     },
 };
+use crate::language::c::literal::escape;
+use crate::language::c::specifiers::Specifiers;
 use crate::language::c::writers::Cursor;
 
 pub struct TextWriter<'a> {
@@ -29,6 +73,27 @@ pub struct TextWriter<'a> {
     style: style::Style,
     new_line: bool,
     semicolon_on_next: bool,
+    /// Nothing was written yet of the statement being written
+    statement_start: bool,
+    /// Trailing comments of the objects just written, left for after the punctuation that
+    /// closes them, like the `;` of a statement
+    pending_comments: Vec<String>,
+    /// The newline of the last finished line, held back so the trailing comments of the
+    /// object that finished it still go on that line
+    newline_pending: bool,
+    /// Layout of the text the file was parsed from, when it was kept
+    trivia: Option<Trivia>,
+    /// Indentation of the object being written and the level it starts at, when it kept its own
+    /// rather than the one of the style
+    kept_indent: Option<(usize, String)>,
+    /// Indentation kept by the object holding the one being written, which the body of a
+    /// directive or a label is indented from
+    enclosing_indent: Option<(usize, String)>,
+    /// Where the signature of the function being written was broken, when it kept its layout
+    line_breaks: Option<LineBreaks>,
+    /// Blank lines after the pending newline, held back with it so the layout of the next
+    /// object can replace them
+    blank_lines_pending: usize,
 }
 
 impl<'a> TextWriter<'a> {
@@ -40,6 +105,14 @@ impl<'a> TextWriter<'a> {
             new_line: true,
             style,
             semicolon_on_next: true,
+            statement_start: false,
+            pending_comments: Vec::new(),
+            newline_pending: false,
+            trivia: None,
+            kept_indent: None,
+            enclosing_indent: None,
+            line_breaks: None,
+            blank_lines_pending: 0,
         }
     }
 
@@ -52,10 +125,22 @@ impl<'a> TextWriter<'a> {
     }
 
     fn pad(&mut self) -> Result<(), WriterError> {
-        self.write(&" ".repeat(self.indent_level * self.style.indent_size))?;
+        self.write(&self.padding())?;
         Ok(())
     }
 
+    /// Indentation of the current level, which the levels in an object that kept its own
+    /// indentation start from
+    fn padding(&self) -> String {
+        match &self.kept_indent {
+            Some((level, indent)) if self.indent_level >= *level => format!(
+                "{indent}{}",
+                " ".repeat((self.indent_level - level) * self.style.indent_size)
+            ),
+            _ => " ".repeat(self.indent_level * self.style.indent_size),
+        }
+    }
+
     pub fn open_block(&mut self, delimitator: Delimitator) -> Result<(), WriterError> {
         self.write(delimitator.open())?;
         self.delimitator_stack.push(delimitator);
@@ -74,11 +159,34 @@ impl<'a> TextWriter<'a> {
     }
 
     fn finish_line(&mut self, text: &str) -> Result<(), WriterError> {
-        self.write(&format!("{text}\n"))?;
+        // A blank line is held back with the newline before it
+        if text.is_empty() && self.newline_pending {
+            self.blank_lines_pending += 1;
+            self.statement_start = false;
+            return Ok(());
+        }
+        self.write_raw(text)?;
+        self.newline_pending = true;
         self.new_line = true;
         Ok(())
     }
 
+    /// Writes the trailing comments left pending, at the end of the last finished line if its
+    /// newline is still pending. A line comment ends its line, so what follows it goes on the
+    /// next one
+    fn write_pending_comments(&mut self) -> Result<(), WriterError> {
+        let comments = std::mem::take(&mut self.pending_comments);
+        for (i, comment) in comments.iter().enumerate() {
+            self.writer.write_all(format!(" {comment}").as_bytes())?;
+            let ends_line = self.newline_pending && i + 1 == comments.len();
+            if is_line_comment(comment) && !ends_line {
+                let padding = self.padding();
+                self.writer.write_all(format!("\n{padding}").as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
     fn writeln(&mut self, text: &str) -> Result<(), WriterError> {
         self.pad()?;
         self.finish_line(text)?;
@@ -90,7 +198,7 @@ impl<'a> TextWriter<'a> {
         C: FnMut(&mut Self) -> Result<(), WriterError>,
     {
         self.pad()?;
-        self.semicolon_on_next = true;
+        self.start_statement();
         content(self)?;
         if self.semicolon_on_next {
             self.finish_line(";")?;
@@ -102,8 +210,24 @@ impl<'a> TextWriter<'a> {
     }
 
     fn write(&mut self, text: &str) -> Result<(), WriterError> {
+        self.write_pending_comments()?;
+        self.write_raw(text)
+    }
+
+    /// Writes `text` without the pending comments, which stay pending when it's the end of a
+    /// statement, like its `;`
+    fn write_raw(&mut self, text: &str) -> Result<(), WriterError> {
+        if self.newline_pending {
+            self.write_pending_comments()?;
+            self.writer.write_all(b"\n")?;
+            self.writer
+                .write_all("\n".repeat(self.blank_lines_pending).as_bytes())?;
+            self.newline_pending = false;
+            self.blank_lines_pending = 0;
+        }
         self.writer.write_all(text.as_bytes())?;
         self.new_line = false;
+        self.statement_start = false;
         Ok(())
     }
 
@@ -119,10 +243,13 @@ impl<'a> TextWriter<'a> {
         }
         let mut arg_count_in_line = 0;
         for (i, arg) in args.enumerate() {
+            let mut line_ended = false;
             if i > 0 && i < len {
-                self.write(",")?;
+                line_ended = self.write_separator(",")?;
             }
-            if arg_count_in_line >= self.style.fn_call_max_arg_per_line {
+            if line_ended {
+                arg_count_in_line = 0;
+            } else if arg_count_in_line >= self.style.fn_call_max_arg_per_line {
                 self.finish_line("")?;
                 self.pad()?;
                 arg_count_in_line = 0;
@@ -140,36 +267,322 @@ impl<'a> TextWriter<'a> {
         Ok(())
     }
 
+    /// Writes `separator` after the object before it, and after the comments trailing that
+    /// object unless one is a line comment. Returns whether a line comment ended the line, so
+    /// no space is needed after it
+    fn write_separator(&mut self, separator: &str) -> Result<bool, WriterError> {
+        let line_ended = self
+            .pending_comments
+            .iter()
+            .any(|comment| is_line_comment(comment));
+        if line_ended {
+            self.write_raw(separator)?;
+            self.write_pending_comments()?;
+        } else {
+            self.write(separator)?;
+        }
+        Ok(line_ended)
+    }
+
+    /// Starts a statement once its line is padded, leaving its semicolon for after it
+    fn start_statement(&mut self) {
+        self.semicolon_on_next = true;
+        self.statement_start = true;
+    }
+
     fn skip_semicolon(&mut self) {
         self.semicolon_on_next = false;
     }
+
+    /// Body of a struct or union, closed with its own semicolon so it reads the same at file
+    /// level and inside a block
+    fn write_field_list(&mut self, fields: &[FieldDeclaration]) -> Result<(), WriterError> {
+        self.open_block(Delimitator::CurlyBrace)?;
+        self.finish_line("")?;
+        let mut fields = fields.iter().peekable();
+        while let Some(first) = fields.next() {
+            // Fields declared together and still sharing the type specifier stay grouped, as
+            // in a declaration list
+            let mut grouped = vec![];
+            let mut previous = first;
+            while let Some(field) = fields.next_if(|field| {
+                field.shares_declaration
+                    && field.field_type.specifier() == previous.field_type.specifier()
+            }) {
+                grouped.push(field);
+                previous = field;
+            }
+            self.write_statement(|w: &mut Self| {
+                first.write(w)?;
+                for field in &grouped {
+                    if !w.write_separator(",")? {
+                        w.write(" ")?;
+                    }
+                    w.write_leading_comments(&field.comments)?;
+                    w.write(&field.field_type.declarator(&field.identifier))?;
+                    w.write_trailing_comments(&field.comments)?;
+                }
+                Ok(())
+            })?;
+        }
+        self.close_block()?;
+        self.write(";")?;
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    /// Writes the declaration of a struct, union or enum without its body, as in `struct s;`
+    fn write_forward_declaration(
+        &mut self,
+        keyword: &str,
+        identifier: &str,
+    ) -> Result<(), WriterError> {
+        self.write(&format!("{keyword} {identifier};"))?;
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_file_level_object(&mut self, object: &DeclarationObject) -> Result<(), WriterError> {
+        object.write(self)?;
+        // Global variables and typedefs are the only file level objects that don't close themselves
+        if matches!(
+            object,
+            DeclarationObject::Declaration(_)
+                | DeclarationObject::DeclarationList(_)
+                | DeclarationObject::TypeDefinition(_)
+        ) {
+            self.finish_line(";")
+        } else {
+            self.finish_line("")
+        }
+    }
+
+    /// Writes the object with `id` with `write`, after the blank lines that were before it and
+    /// with the indentation and line breaks it had, when it kept its layout
+    fn write_with_layout<F>(&mut self, id: Uuid, write: F) -> Result<(), WriterError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), WriterError>,
+    {
+        let Some(layout) = self
+            .trivia
+            .as_ref()
+            .and_then(|trivia| trivia.layout(&id))
+            .cloned()
+        else {
+            return write(self);
+        };
+        self.write_blank_lines(layout.blank_lines_before)?;
+        // An object that did not start its line, or was indented as the style, is indented
+        // from the object around it
+        let kept_indent = match layout.indent {
+            Some(indent) => self.kept_indent.replace((self.indent_level, indent)),
+            None => self.kept_indent.clone(),
+        };
+        let enclosing_indent = std::mem::replace(&mut self.enclosing_indent, kept_indent.clone());
+        let line_breaks = std::mem::replace(&mut self.line_breaks, layout.line_breaks);
+        let result = write(self);
+        self.kept_indent = kept_indent;
+        self.enclosing_indent = enclosing_indent;
+        self.line_breaks = line_breaks;
+        result
+    }
+
+    /// Writes the body of a directive or of a label with `write`. Directives and labels are
+    /// often outdented, so their body is indented from the code around them instead
+    fn write_outdented_body<F>(&mut self, write: F) -> Result<(), WriterError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), WriterError>,
+    {
+        let kept_indent = std::mem::replace(&mut self.kept_indent, self.enclosing_indent.clone());
+        let result = write(self);
+        self.kept_indent = kept_indent;
+        result
+    }
+
+    /// Whether the return type of the function being written goes on a line of its own
+    fn type_above(&self) -> bool {
+        self.line_breaks
+            .map_or(self.style.function_type_always_above, |line_breaks| {
+                line_breaks.after_type
+            })
+    }
+
+    /// Whether the body of the function being written starts on the line below its signature
+    fn block_below(&self) -> bool {
+        self.line_breaks
+            .map_or(self.style.block_always_below, |line_breaks| {
+                line_breaks.before_block
+            })
+    }
+
+    /// Leaves `count` blank lines before the next line, instead of the ones the style left
+    fn write_blank_lines(&mut self, count: usize) -> Result<(), WriterError> {
+        if self.newline_pending {
+            self.blank_lines_pending = count;
+        } else {
+            self.writer.write_all("\n".repeat(count).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Opening line of a conditional block or of one of its branches
+    fn write_preproc_condition(
+        &mut self,
+        directive: &str,
+        condition: &str,
+    ) -> Result<(), WriterError> {
+        if condition.is_empty() {
+            self.finish_line(directive)
+        } else {
+            self.finish_line(&format!("{directive} {condition}"))
+        }
+    }
+
+    fn write_specifiers(&mut self, specifiers: &Specifiers) -> Result<(), WriterError> {
+        if specifiers.is_empty() {
+            return Ok(());
+        }
+        self.write(&format!("{specifiers} "))
+    }
+
+    fn write_parameter_list(
+        &mut self,
+        parameter_list: &[FunctionParameter],
+        is_variadic: bool,
+        explicit_void: bool,
+    ) -> Result<(), WriterError> {
+        let no_comments = Comments::default();
+        let mut parameters: Vec<(String, &Comments)> = parameter_list
+            .iter()
+            .map(|parameter| {
+                (
+                    parameter.param_type.declaration(&parameter.identifier),
+                    &parameter.comments,
+                )
+            })
+            .collect();
+        if is_variadic {
+            parameters.push(("...".to_string(), &no_comments));
+        }
+        if explicit_void && parameters.is_empty() {
+            parameters.push(("void".to_string(), &no_comments));
+        }
+        self.write_paren_block(parameters.iter().map(|(parameter, comments)| {
+            move |w: &mut Self| {
+                w.write_leading_comments(comments)?;
+                w.write(parameter)?;
+                w.write_trailing_comments(comments)
+            }
+        }))
+    }
+
+    /// Writes the return type, name and parameters of a function. Functions returning
+    /// pointers to functions or arrays have their parameters inside the return type, as in
+    /// `int (*get(int k))(void)`
+    fn write_function_signature(
+        &mut self,
+        return_type: &CType,
+        identifier: &str,
+        parameter_list: &[FunctionParameter],
+        is_variadic: bool,
+        explicit_void: bool,
+    ) -> Result<(), WriterError> {
+        const PARAMETERS: &str = "\0";
+        let declarator = format!("{identifier}{PARAMETERS}");
+        let declaration = return_type.declaration(&declarator);
+        let declaration = if self.type_above() {
+            match declaration.strip_prefix(&return_type.to_string()) {
+                Some(declarator) => format!("{return_type}\n{}", declarator.trim_start()),
+                None => format!(
+                    "{}\n{}",
+                    return_type.specifier(),
+                    return_type.declarator(&declarator)
+                ),
+            }
+        } else {
+            declaration
+        };
+        let (before, after) = declaration
+            .split_once(PARAMETERS)
+            .unwrap_or((&declaration, ""));
+        self.write(before)?;
+        self.write_parameter_list(parameter_list, is_variadic, explicit_void)?;
+        if !after.is_empty() {
+            self.write(after)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an if statement with the else ifs and the else chained to it, leaving the end of
+    /// its line to the statement holding it
+    fn write_if_chain(&mut self, if_statement: &IfStatement) -> Result<(), WriterError> {
+        self.write("if ")?;
+
+        self.open_block(Delimitator::Paren)?;
+        if_statement.condition.write(self)?;
+        self.close_block()?;
+
+        self.write(" ")?;
+
+        if_statement.body.write(self)?;
+        let Some(else_statement) = &if_statement.else_statement else {
+            return Ok(());
+        };
+        // A single statement body is terminated before the `else`
+        if self.semicolon_on_next {
+            self.write(";")?;
+        }
+        match else_statement {
+            ElseStatement::ElseIf(else_if) => {
+                self.write(" else ")?;
+                self.semicolon_on_next = true;
+                self.write_leading_comments(&else_if.comments)?;
+                self.write_if_chain(else_if)?;
+                self.write_trailing_comments(&else_if.comments)
+            }
+            ElseStatement::ElseClause(else_clause) => else_clause.write(self),
+        }
+    }
 }
 
 impl Writer for TextWriter<'_> {
     fn write_file(&mut self, src_file: &SourceFile) -> Result<(), WriterError> {
         self.write_source_file(src_file)?;
+        // Ends the last line, after the comments trailing its object
+        self.write("")?;
         Ok(())
     }
 }
 
 impl Cursor for TextWriter<'_> {
     fn write_source_file(&mut self, src_file: &SourceFile) -> Result<(), WriterError> {
+        self.trivia = src_file.trivia.clone();
         for object in &src_file.code {
-            object.write(self)?;
-            self.finish_line("")?;
+            self.write_with_layout(object.id(), |w: &mut Self| {
+                w.write_file_level_object(object)
+            })?;
+        }
+        if self.trivia.is_some() {
+            // A file with trivia ends with its last line, without the blank lines of the style
+            self.blank_lines_pending = 0;
         }
         Ok(())
     }
 
     fn write_unknown(&mut self, unknown: &Unknown) -> Result<(), WriterError> {
-        self.writeln(&unknown.content)
+        // Unknown statements are written as they were found, with their own semicolon
+        if self.statement_start {
+            self.skip_semicolon();
+        }
+        self.write(&unknown.content)
     }
 
     fn write_assignment_expression(
         &mut self,
         assignment_expression: &AssignmentExpression,
     ) -> Result<(), WriterError> {
-        self.write(&format!("{} = ", assignment_expression.identifier,))?;
+        assignment_expression.left.write(self)?;
+        self.write(" = ")?;
         assignment_expression.value.write(self)?;
         Ok(())
     }
@@ -184,6 +597,10 @@ impl Cursor for TextWriter<'_> {
         Ok(())
     }
 
+    fn write_break_statement(&mut self, _: &BreakStatement) -> Result<(), WriterError> {
+        self.write("break")
+    }
+
     fn write_call_expression(
         &mut self,
         call_expression: &CallExpression,
@@ -197,16 +614,98 @@ impl Cursor for TextWriter<'_> {
         Ok(())
     }
 
+    fn write_case_statement(&mut self, case_statement: &CaseStatement) -> Result<(), WriterError> {
+        match &case_statement.value {
+            Some(value) => {
+                self.write("case ")?;
+                value.write(self)?;
+                self.write(":")?;
+            }
+            None => self.write("default:")?,
+        }
+
+        // The statements of a case are not wrapped in a block, so every one of them but the
+        // last is terminated here and the last one is left for the enclosing statement
+        self.indent();
+        for object in &case_statement.body {
+            self.finish_line("")?;
+            self.pad()?;
+            self.start_statement();
+            object.write(self)?;
+            if self.semicolon_on_next {
+                self.write(";")?;
+            }
+        }
+        self.dedent();
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_cast_expression(
+        &mut self,
+        cast_expression: &CastExpression,
+    ) -> Result<(), WriterError> {
+        self.write(&format!("({})", cast_expression.cast_type))?;
+        cast_expression.value.write(self)
+    }
+
     fn write_comment(&mut self, comment: &Comment) -> Result<(), WriterError> {
-        self.write(&comment.content)
+        self.write(&comment.content)?;
+        // A comment in a block is not a statement
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_leading_comments(&mut self, comments: &Comments) -> Result<(), WriterError> {
+        // The object still starts the statement it is in
+        let statement_start = self.statement_start;
+        // Comments before a statement are on their own line, like the doc of a function
+        let own_line = statement_start || self.new_line;
+        for comment in &comments.leading {
+            self.write(&comment.content)?;
+            if is_line_comment(&comment.content) || own_line {
+                self.finish_line("")?;
+                self.pad()?;
+            } else {
+                self.write(" ")?;
+            }
+        }
+        self.statement_start = statement_start;
+        Ok(())
+    }
+
+    fn write_trailing_comments(&mut self, comments: &Comments) -> Result<(), WriterError> {
+        self.pending_comments.extend(
+            comments
+                .trailing
+                .iter()
+                .map(|comment| comment.content.clone()),
+        );
+        Ok(())
+    }
+
+    fn write_conditional_expression(
+        &mut self,
+        conditional_expression: &ConditionalExpression,
+    ) -> Result<(), WriterError> {
+        conditional_expression.condition.write(self)?;
+        self.write(" ? ")?;
+        conditional_expression.consequence.write(self)?;
+        self.write(" : ")?;
+        conditional_expression.alternative.write(self)
+    }
+
+    fn write_continue_statement(&mut self, _: &ContinueStatement) -> Result<(), WriterError> {
+        self.write("continue")
     }
 
     fn write_declaration(&mut self, declaration: &Declaration) -> Result<(), WriterError> {
-        self.write(&format!(
-            "{} {}",
-            declaration.primitive_type.as_str(),
-            declaration.identifier,
-        ))?;
+        self.write_specifiers(&declaration.specifiers)?;
+        self.write(
+            &declaration
+                .primitive_type
+                .declaration(&declaration.identifier),
+        )?;
         if let Some(value) = &declaration.value {
             self.write(" = ")?;
             value.write(self)?;
@@ -214,35 +713,160 @@ impl Cursor for TextWriter<'_> {
         Ok(())
     }
 
+    fn write_declaration_list(
+        &mut self,
+        declaration_list: &DeclarationList,
+    ) -> Result<(), WriterError> {
+        let mut previous: Option<&Declaration> = None;
+        for declaration in &declaration_list.declarations {
+            match previous {
+                // Declarators sharing the specifiers and the type specifier stay grouped
+                Some(previous)
+                    if previous.specifiers == declaration.specifiers
+                        && previous.primitive_type.specifier()
+                            == declaration.primitive_type.specifier() =>
+                {
+                    if !self.write_separator(",")? {
+                        self.write(" ")?;
+                    }
+                    self.write_leading_comments(&declaration.comments)?;
+                    self.write(
+                        &declaration
+                            .primitive_type
+                            .declarator(&declaration.identifier),
+                    )?;
+                    if let Some(value) = &declaration.value {
+                        self.write(" = ")?;
+                        value.write(self)?;
+                    }
+                    self.write_trailing_comments(&declaration.comments)?;
+                }
+                Some(_) => {
+                    if !self.write_separator(";")? {
+                        self.write(" ")?;
+                    }
+                    declaration.write(self)?;
+                }
+                None => declaration.write(self)?,
+            }
+            previous = Some(declaration);
+        }
+        Ok(())
+    }
+
+    fn write_do_statement(&mut self, do_statement: &DoStatement) -> Result<(), WriterError> {
+        self.write("do ")?;
+        do_statement.body.write(self)?;
+        // A single statement body still needs its own semicolon before the `while`
+        if self.semicolon_on_next {
+            self.write(";")?;
+        }
+        self.write(" while ")?;
+
+        self.open_block(Delimitator::Paren)?;
+        do_statement.condition.write(self)?;
+        self.close_block()?;
+
+        self.semicolon_on_next = true;
+        Ok(())
+    }
+
     fn write_else_clause(&mut self, else_clause: &ElseClause) -> Result<(), WriterError> {
         self.write(" else")?;
         self.write(" ")?;
+        // The body needs its own semicolon even after a block
+        self.semicolon_on_next = true;
         else_clause.body.write(self)?;
         Ok(())
     }
 
-    fn write_function_declaration(
+    fn write_enum_definition(
         &mut self,
-        function_declaration: &FunctionDeclaration,
+        enum_definition: &EnumDefinition,
     ) -> Result<(), WriterError> {
-        self.write(function_declaration.return_type.as_str())?;
+        if enum_definition.is_forward {
+            return self.write_forward_declaration("enum", &enum_definition.identifier);
+        }
+        self.write(&format!("enum {} ", enum_definition.identifier))?;
+        self.open_block(Delimitator::CurlyBrace)?;
+        self.finish_line("")?;
+        let len = enum_definition.enumerators.len();
+        for (i, enumerator) in enum_definition.enumerators.iter().enumerate() {
+            self.pad()?;
+            enumerator.write(self)?;
+            self.finish_line(if i + 1 < len { "," } else { "" })?;
+        }
+        self.close_block()?;
+        self.write(";")?;
+        self.skip_semicolon();
+        Ok(())
+    }
 
-        if self.style.function_type_always_above {
-            self.write(&format!("\n{}", function_declaration.identifier))?;
-        } else {
-            self.write(&format!(" {}", function_declaration.identifier))?;
+    fn write_enumerator(&mut self, enumerator: &Enumerator) -> Result<(), WriterError> {
+        self.write(&enumerator.identifier)?;
+        if let Some(value) = &enumerator.value {
+            self.write(" = ")?;
+            value.write(self)?;
         }
+        Ok(())
+    }
 
-        let argf = function_declaration.parameter_list.iter().map(
-            |FunctionParameter {
-                 id: _,
-                 identifier,
-                 param_type,
-             }| {
-                move |w: &mut Self| w.write(&format!("{} {}", param_type.as_str(), identifier))
-            },
-        );
-        self.write_paren_block(argf)?;
+    fn write_field_declaration(
+        &mut self,
+        field_declaration: &FieldDeclaration,
+    ) -> Result<(), WriterError> {
+        self.write(
+            &field_declaration
+                .field_type
+                .declaration(&field_declaration.identifier),
+        )
+    }
+
+    fn write_field_expression(
+        &mut self,
+        field_expression: &FieldExpression,
+    ) -> Result<(), WriterError> {
+        field_expression.argument.write(self)?;
+        self.write(&field_expression.operator)?;
+        self.write(&field_expression.identifier)
+    }
+
+    fn write_for_statement(&mut self, for_statement: &ForStatement) -> Result<(), WriterError> {
+        self.write("for ")?;
+
+        self.open_block(Delimitator::Paren)?;
+        if let Some(initializer) = &for_statement.initializer {
+            initializer.write(self)?;
+        }
+        self.write(";")?;
+        if let Some(condition) = &for_statement.condition {
+            self.write(" ")?;
+            condition.write(self)?;
+        }
+        self.write(";")?;
+        if let Some(update) = &for_statement.update {
+            self.write(" ")?;
+            update.write(self)?;
+        }
+        self.close_block()?;
+
+        self.write(" ")?;
+        for_statement.body.write(self)?;
+        Ok(())
+    }
+
+    fn write_function_declaration(
+        &mut self,
+        function_declaration: &FunctionDeclaration,
+    ) -> Result<(), WriterError> {
+        self.write_specifiers(&function_declaration.specifiers)?;
+        self.write_function_signature(
+            &function_declaration.return_type,
+            &function_declaration.identifier,
+            &function_declaration.parameter_list,
+            function_declaration.is_variadic,
+            function_declaration.explicit_void,
+        )?;
         self.finish_line(";")?;
         Ok(())
     }
@@ -251,54 +875,183 @@ impl Cursor for TextWriter<'_> {
         &mut self,
         function_definition: &FunctionDefinition,
     ) -> Result<(), WriterError> {
-        self.write(function_definition.return_type.as_str())?;
-
-        if self.style.function_type_always_above {
-            self.write(&format!("\n{}", function_definition.identifier))?;
-        } else {
-            self.write(&format!(" {}", function_definition.identifier))?;
+        self.write_specifiers(&function_definition.specifiers)?;
+        self.write_function_signature(
+            &function_definition.return_type,
+            &function_definition.identifier,
+            &function_definition.parameter_list,
+            function_definition.is_variadic,
+            function_definition.explicit_void,
+        )?;
+        // Comments leading the body stay after the parameters, as they were written
+        let body = &function_definition.compound_statement;
+        let mut line_ended = false;
+        for comment in &body.comments.leading {
+            if !line_ended {
+                self.write(" ")?;
+            }
+            self.write(&comment.content)?;
+            line_ended = is_line_comment(&comment.content);
+            if line_ended {
+                self.finish_line("")?;
+            }
         }
-
-        let argf = function_definition.parameter_list.iter().map(
-            |FunctionParameter {
-                 id: _,
-                 identifier,
-                 param_type,
-             }| {
-                move |w: &mut Self| w.write(&format!("{} {}", param_type.as_str(), identifier))
-            },
-        );
-        self.write_paren_block(argf)?;
-        if self.style.block_always_below {
+        if line_ended {
+            // The body starts on the next line already
+        } else if self.block_below() {
             self.finish_line("")?;
         } else {
             self.write(" ")?;
         }
 
-        self.write_compound_statement(&function_definition.compound_statement)?;
+        self.write_compound_statement(body)?;
         self.finish_line("")?;
         Ok(())
     }
 
+    fn write_goto_statement(&mut self, goto_statement: &GotoStatement) -> Result<(), WriterError> {
+        self.write(&format!("goto {}", goto_statement.label))
+    }
+
     fn write_if_statement(&mut self, if_statement: &IfStatement) -> Result<(), WriterError> {
-        self.write("if ")?;
+        self.write_if_chain(if_statement)?;
+        // Like the body of a loop, a single statement body leaves its semicolon to the
+        // enclosing statement, which ends the line after it
+        if !self.semicolon_on_next {
+            self.finish_line("")?;
+        }
+        Ok(())
+    }
 
-        self.open_block(Delimitator::Paren)?;
-        if_statement.condition.write(self)?;
-        self.close_block()?;
+    fn write_initializer_list(
+        &mut self,
+        initializer_list: &InitializerList,
+    ) -> Result<(), WriterError> {
+        self.open_block(Delimitator::CurlyBrace)?;
+        for (i, element) in initializer_list.elements.iter().enumerate() {
+            if i > 0 {
+                self.write(", ")?;
+            }
+            element.write(self)?;
+        }
+        self.close_block()
+    }
 
-        self.write(" ")?;
+    fn write_initializer_pair(
+        &mut self,
+        initializer_pair: &InitializerPair,
+    ) -> Result<(), WriterError> {
+        for designator in &initializer_pair.designators {
+            match designator {
+                Designator::Field(field) => self.write(&format!(".{field}"))?,
+                Designator::Subscript(index) => {
+                    self.write("[")?;
+                    index.write(self)?;
+                    self.write("]")?;
+                }
+            }
+        }
+        self.write(" = ")?;
+        initializer_pair.value.write(self)
+    }
 
-        if_statement.body.write(self)?;
-        if let Some(else_statement) = &if_statement.else_statement {
-            else_statement.write(self)?;
+    fn write_labeled_statement(
+        &mut self,
+        labeled_statement: &LabeledStatement,
+    ) -> Result<(), WriterError> {
+        self.write(&format!("{}:", labeled_statement.label))?;
+        // The labeled statement goes on its own line and, like the statements of a case, its
+        // semicolon is left for the enclosing statement. Without one, that gives `label:;`
+        if let Some(statement) = &labeled_statement.statement {
+            self.finish_line("")?;
+            self.write_outdented_body(|w: &mut Self| {
+                w.pad()?;
+                w.start_statement();
+                statement.write(w)
+            })?;
         }
-        self.finish_line("")?;
         Ok(())
     }
 
     fn write_number_literal(&mut self, number_literal: &NumberLiteral) -> Result<(), WriterError> {
-        self.write(&number_literal.value.to_string())?;
+        if number_literal.spelling_matches() {
+            self.write(&number_literal.spelling)?;
+        } else {
+            self.write(&number_literal.canonical_spelling())?;
+        }
+        Ok(())
+    }
+
+    fn write_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &ParenthesizedExpression,
+    ) -> Result<(), WriterError> {
+        self.open_block(Delimitator::Paren)?;
+        parenthesized_expression.value.write(self)?;
+        self.close_block()
+    }
+
+    fn write_preproc_call(&mut self, preproc_call: &PreprocCall) -> Result<(), WriterError> {
+        if preproc_call.argument.is_empty() {
+            self.write(&preproc_call.directive)?;
+        } else {
+            self.write(&format!(
+                "{} {}",
+                preproc_call.directive, preproc_call.argument
+            ))?;
+        }
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_preproc_define(&mut self, preproc_define: &PreprocDefine) -> Result<(), WriterError> {
+        self.write(&format!("#define {}", preproc_define.identifier))?;
+        if let Some(parameters) = &preproc_define.parameters {
+            self.write(&format!("({})", parameters.join(", ")))?;
+        }
+        if !preproc_define.value.is_empty() {
+            self.write(&format!(" {}", preproc_define.value))?;
+        }
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_preproc_if(&mut self, preproc_if: &PreprocIf) -> Result<(), WriterError> {
+        self.write_preproc_condition(&preproc_if.directive, &preproc_if.condition)?;
+        self.write_outdented_body(|w: &mut Self| {
+            for object in &preproc_if.body {
+                w.write_file_level_object(object)?;
+            }
+            Ok(())
+        })?;
+        match &preproc_if.alternative {
+            Some(alternative) => alternative.write(self)?,
+            None => self.write("#endif")?,
+        }
+        self.skip_semicolon();
+        Ok(())
+    }
+
+    fn write_preproc_if_statement(
+        &mut self,
+        preproc_if_statement: &PreprocIfStatement,
+    ) -> Result<(), WriterError> {
+        self.write_preproc_condition(
+            &preproc_if_statement.directive,
+            &preproc_if_statement.condition,
+        )?;
+        self.write_outdented_body(|w: &mut Self| {
+            for object in &preproc_if_statement.body {
+                w.write_statement(|w: &mut Self| object.write(w))?;
+            }
+            Ok(())
+        })?;
+        self.pad()?;
+        match &preproc_if_statement.alternative {
+            Some(alternative) => alternative.write(self)?,
+            None => self.write("#endif")?,
+        }
+        self.skip_semicolon();
         Ok(())
     }
 
@@ -327,8 +1080,80 @@ impl Cursor for TextWriter<'_> {
         Ok(())
     }
 
+    fn write_sizeof_expression(
+        &mut self,
+        sizeof_expression: &SizeofExpression,
+    ) -> Result<(), WriterError> {
+        self.write("sizeof")?;
+        self.open_block(Delimitator::Paren)?;
+        if let Some(argument_type) = &sizeof_expression.argument_type {
+            self.write(&argument_type.to_string())?;
+        }
+        if let Some(value) = &sizeof_expression.value {
+            value.write(self)?;
+        }
+        self.close_block()
+    }
+
+    fn write_char_literal(&mut self, char_literal: &CharLiteral) -> Result<(), WriterError> {
+        if char_literal.spelling_matches() {
+            self.write(&char_literal.spelling)?;
+        } else {
+            self.write(&format!(
+                "{}'{}'",
+                char_literal.prefix,
+                escape(&char_literal.value, '\'')
+            ))?;
+        }
+        Ok(())
+    }
+
     fn write_string_literal(&mut self, string_literal: &StringLiteral) -> Result<(), WriterError> {
-        self.write(&format!("\"{}\"", string_literal.value))?;
+        if string_literal.segments_match() {
+            self.write(&string_literal.segments.join(" "))?;
+        } else {
+            self.write(&format!(
+                "{}\"{}\"",
+                string_literal.prefix,
+                escape(&string_literal.value, '"')
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn write_struct_definition(
+        &mut self,
+        struct_definition: &StructDefinition,
+    ) -> Result<(), WriterError> {
+        if struct_definition.is_forward {
+            return self.write_forward_declaration("struct", &struct_definition.identifier);
+        }
+        self.write(&format!("struct {} ", struct_definition.identifier))?;
+        self.write_field_list(&struct_definition.fields)
+    }
+
+    fn write_subscript_expression(
+        &mut self,
+        subscript_expression: &SubscriptExpression,
+    ) -> Result<(), WriterError> {
+        subscript_expression.argument.write(self)?;
+        self.write("[")?;
+        subscript_expression.index.write(self)?;
+        self.write("]")
+    }
+
+    fn write_switch_statement(
+        &mut self,
+        switch_statement: &SwitchStatement,
+    ) -> Result<(), WriterError> {
+        self.write("switch ")?;
+
+        self.open_block(Delimitator::Paren)?;
+        switch_statement.condition.write(self)?;
+        self.close_block()?;
+
+        self.write(" ")?;
+        switch_statement.body.write(self)?;
         Ok(())
     }
 
@@ -339,15 +1164,82 @@ impl Cursor for TextWriter<'_> {
         self.open_block(Delimitator::CurlyBrace)?;
         self.finish_line("")?;
         for object in &compound_statement.code_block {
-            self.write_statement(|w: &mut Self| {
-                object.write(w)?;
-                Ok(())
+            self.write_with_layout(object.id(), |w: &mut Self| {
+                w.write_statement(|w: &mut Self| {
+                    object.write(w)?;
+                    Ok(())
+                })
             })?;
         }
         self.close_block()?;
         self.skip_semicolon();
         Ok(())
     }
+
+    fn write_type_definition(
+        &mut self,
+        type_definition: &TypeDefinition,
+    ) -> Result<(), WriterError> {
+        self.write(&format!(
+            "typedef {}",
+            type_definition
+                .aliased_type
+                .declaration(&type_definition.identifier)
+        ))
+    }
+
+    fn write_unary_expression(
+        &mut self,
+        unary_expression: &UnaryExpression,
+    ) -> Result<(), WriterError> {
+        self.write(&unary_expression.operator)?;
+        // `- -x` written without its space would be `--x`, a decrement
+        if unary_expression.operator.chars().last().is_some()
+            && unary_expression.operator.chars().last() == first_char(&unary_expression.argument)
+        {
+            self.write(" ")?;
+        }
+        unary_expression.argument.write(self)
+    }
+
+    fn write_union_definition(
+        &mut self,
+        union_definition: &UnionDefinition,
+    ) -> Result<(), WriterError> {
+        if union_definition.is_forward {
+            return self.write_forward_declaration("union", &union_definition.identifier);
+        }
+        self.write(&format!("union {} ", union_definition.identifier))?;
+        self.write_field_list(&union_definition.fields)
+    }
+
+    fn write_update_expression(
+        &mut self,
+        update_expression: &UpdateExpression,
+    ) -> Result<(), WriterError> {
+        if update_expression.is_prefix {
+            self.write(&update_expression.operator)?;
+            update_expression.argument.write(self)
+        } else {
+            update_expression.argument.write(self)?;
+            self.write(&update_expression.operator)
+        }
+    }
+
+    fn write_while_statement(
+        &mut self,
+        while_statement: &WhileStatement,
+    ) -> Result<(), WriterError> {
+        self.write("while ")?;
+
+        self.open_block(Delimitator::Paren)?;
+        while_statement.condition.write(self)?;
+        self.close_block()?;
+
+        self.write(" ")?;
+        while_statement.body.write(self)?;
+        Ok(())
+    }
 }
 
 pub enum Delimitator {
@@ -373,3 +1265,28 @@ impl Delimitator {
         }
     }
 }
+
+/// First character `expression` is written with, which an operator right before it could
+/// merge with
+fn first_char(expression: &ExpressionObject) -> Option<char> {
+    match expression {
+        ExpressionObject::UnaryExpression(unary) => unary.operator.chars().next(),
+        ExpressionObject::UpdateExpression(update) if update.is_prefix => {
+            update.operator.chars().next()
+        }
+        ExpressionObject::UpdateExpression(update) => first_char(&update.argument),
+        ExpressionObject::BinaryExpression(binary) => first_char(&binary.left),
+        ExpressionObject::AssignmentExpression(assignment) => first_char(&assignment.left),
+        ExpressionObject::ConditionalExpression(conditional) => first_char(&conditional.condition),
+        ExpressionObject::FieldExpression(field) => first_char(&field.argument),
+        ExpressionObject::SubscriptExpression(subscript) => first_char(&subscript.argument),
+        ExpressionObject::NumberLiteral(number) => number.spelling.chars().next(),
+        ExpressionObject::Unknown(unknown) => unknown.content.chars().next(),
+        // The others start with a name, a quote or a parenthesis
+        _ => None,
+    }
+}
+
+fn is_line_comment(comment: &str) -> bool {
+    comment.starts_with("//")
+}
@@ -0,0 +1,80 @@
+use std::{fmt, ops::Range};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The source could not be parsed
+    Error,
+    /// The source was parsed, but part of it was kept as unknown text
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// Zero-based line and column of a point in the source, with its byte offset.
+/// Columns count bytes, as tree-sitter does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn from_node(node: tree_sitter::Node<'_>) -> Self {
+        Span {
+            start: Position {
+                line: node.start_position().row,
+                column: node.start_position().column,
+                offset: node.start_byte(),
+            },
+            end: Position {
+                line: node.end_position().row,
+                column: node.end_position().column,
+                offset: node.end_byte(),
+            },
+        }
+    }
+
+    /// Byte range of the source covered by the span
+    pub fn range(&self) -> Range<usize> {
+        self.start.offset..self.end.offset
+    }
+}
+
+/// A problem found in the source, like `3:5: error[missing-symbol]: Missing symbol: foo`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable kebab-case name of the kind of problem, like `missing-symbol`
+    pub code: &'static str,
+    pub message: String,
+    pub span: Span,
+}
+
+impl fmt::Display for Diagnostic {
+    /// Lines and columns are printed one-based, as editors show them
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}[{}]: {}",
+            self.span.start.line + 1,
+            self.span.start.column + 1,
+            self.severity,
+            self.code,
+            self.message
+        )
+    }
+}
@@ -1,25 +1,64 @@
-use std::{collections::HashMap, fmt::Error};
+pub mod encoding;
+pub mod header;
+pub mod matching;
+pub mod migration;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Error,
+};
 
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::language::c::writers::node_writer::node_type::NodeType;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Node {
     pub id: Uuid,
     pub node_type: u64,
     pub content: String,
+    /// Written sorted by name, so the same nodes are always written the same way
+    #[serde(serialize_with = "serialize_sorted")]
     pub tags: HashMap<String, Vec<Node>>,
     pub children: Vec<Node>,
 }
 
+fn serialize_sorted<S: Serializer>(
+    tags: &HashMap<String, Vec<Node>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    tags.iter()
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
 pub fn serialize(nodes: &Vec<Node>) -> Result<Vec<u8>, Error> {
     let bin = bincode::serialize(nodes).unwrap();
     Ok(bin)
 }
 
+/// Gives every node under `node` with a nil id one derived from the id of the node above it and
+/// where it is under that node, so the same tree always gets the same ids
+pub fn seed_nil_ids(node: &mut Node) {
+    let parent_id = node.id;
+    for (name, tag_nodes) in node.tags.iter_mut() {
+        for (index, tag_node) in tag_nodes.iter_mut().enumerate() {
+            if tag_node.id.is_nil() {
+                tag_node.id = Uuid::from_seed(&format!("{parent_id}/{name}/{index}"));
+            }
+            seed_nil_ids(tag_node);
+        }
+    }
+    for (index, child) in node.children.iter_mut().enumerate() {
+        if child.id.is_nil() {
+            child.id = Uuid::from_seed(&format!("{parent_id}/{index}"));
+        }
+        seed_nil_ids(child);
+    }
+}
+
 pub trait UuidFromSeed {
     fn from_seed(seed: &str) -> Uuid;
 }
@@ -61,7 +100,8 @@ pub trait ToNode {
     /// ```
     fn to_symbol_node(self, id: Uuid) -> Node;
 
-    /// Converts the current value into a Node whose id is the string representation of the value.
+    /// Converts the current value into a Node with a nil id, for values that are not objects.
+    /// Their ids are seeded from the nodes above them by `seed_nil_ids`.
     fn to_str_node(self, node_type: u64) -> Node;
 }
 
@@ -80,7 +120,7 @@ impl ToNode for String {
     fn to_str_node(self, node_type: u64) -> Node {
         Node {
             node_type,
-            id: Uuid::new_v4(),
+            id: Uuid::nil(),
             content: self,
             tags: HashMap::new(),
             children: vec![],
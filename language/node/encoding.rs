@@ -0,0 +1,209 @@
+pub mod sexpr;
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::node::{Node, header::Header};
+
+/// Ways a `.lenga` file can be written. Bincode is the compact one, JSON and S-expressions
+/// can be read and reviewed as text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Bincode,
+    Json,
+    SExpression,
+}
+
+impl Encoding {
+    /// Extension of the files written with this encoding, after the one of their language
+    pub fn extension(self) -> &'static str {
+        match self {
+            Encoding::Bincode => "lenga",
+            Encoding::Json => "lenga.json",
+            Encoding::SExpression => "lenga.sexp",
+        }
+    }
+
+    /// Encoding of a `.lenga` file from its content, for when its name is unknown. Bincode
+    /// files start with the magic bytes, or with the length of their nodes when older
+    pub fn detect(bytes: &[u8]) -> Encoding {
+        match bytes.trim_ascii_start().first() {
+            Some(b'{') => Encoding::Json,
+            Some(b'(') => Encoding::SExpression,
+            _ => Encoding::Bincode,
+        }
+    }
+
+    /// Encoding of the `.lenga` file at `path`, like `main.c.lenga.json`, and the path of the
+    /// text file it holds, like `main.c`
+    pub fn split_path(path: &Path) -> Option<(PathBuf, Encoding)> {
+        let file_name = path.file_name()?.to_str()?;
+        [Encoding::Json, Encoding::SExpression, Encoding::Bincode]
+            .into_iter()
+            .find_map(|encoding| {
+                let stem = file_name.strip_suffix(encoding.extension())?;
+                let stem = stem.strip_suffix('.').filter(|stem| !stem.is_empty())?;
+                Some((path.with_file_name(stem), encoding))
+            })
+    }
+}
+
+/// Header and nodes of a `.lenga` file, whatever encoding it was written with
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct LengaFile {
+    /// Files written before the header have none
+    pub header: Option<Header>,
+    pub nodes: Vec<Node>,
+}
+
+impl LengaFile {
+    pub fn decode(bytes: &[u8], encoding: Encoding) -> Result<Self, String> {
+        match encoding {
+            Encoding::Bincode => Self::from_bincode(bytes),
+            Encoding::Json => Self::from_json(text(bytes)?),
+            Encoding::SExpression => Self::from_sexpr(text(bytes)?),
+        }
+    }
+
+    pub fn encode(&self, encoding: Encoding) -> Vec<u8> {
+        match encoding {
+            Encoding::Bincode => self.to_bincode(),
+            Encoding::Json => self.to_json().into_bytes(),
+            Encoding::SExpression => self.to_sexpr().into_bytes(),
+        }
+    }
+
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, String> {
+        let (header, nodes) = Header::read(bytes)?;
+        let nodes =
+            bincode::deserialize(nodes).map_err(|err| format!("Corrupted Lenga nodes: {err}"))?;
+        Ok(LengaFile { header, nodes })
+    }
+
+    /// Bytes of the file as the language writes it, the header first when it has one
+    pub fn to_bincode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if let Some(header) = &self.header {
+            header
+                .write(&mut bytes)
+                .expect("writing to a Vec cannot fail");
+        }
+        bincode::serialize_into(&mut bytes, &self.nodes).expect("nodes are always serializable");
+        bytes
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        serde_json::from_str(text).map_err(|err| format!("Malformed Lenga JSON: {err}"))
+    }
+
+    /// Pretty JSON of the file, with ids as strings and the tags of every node sorted
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::to_string_pretty(self).expect("nodes are always serializable");
+        json.push('\n');
+        json
+    }
+
+    pub fn from_sexpr(text: &str) -> Result<Self, String> {
+        sexpr::read(text)
+    }
+
+    /// S-expression of the file, a node per line indented by depth and tags sorted
+    pub fn to_sexpr(&self) -> String {
+        sexpr::write(self)
+    }
+}
+
+/// Converts a `.lenga` file between encodings, keeping every node and the header as they were
+pub fn convert(bytes: &[u8], from: Encoding, to: Encoding) -> Result<Vec<u8>, String> {
+    Ok(LengaFile::decode(bytes, from)?.encode(to))
+}
+
+fn text(bytes: &[u8]) -> Result<&str, String> {
+    std::str::from_utf8(bytes).map_err(|err| format!("Lenga text encoding is not UTF-8: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::{Language, c::C};
+
+    use super::*;
+
+    fn nodes_of(code: &str) -> Vec<u8> {
+        let c_language = C::new();
+        let src_file = c_language.parse_text(code).unwrap();
+        c_language.write_to_nodes(src_file).unwrap()
+    }
+
+    #[test]
+    fn test_text_encodings_round_trip() {
+        let code = r#"// Prints "hello"
+#define GREETING "hello\n"
+
+struct Point
+{
+    int x; // across
+    int y;
+};
+
+int main()
+{
+    printf("%s\t\"%d\"\n", GREETING, 'a');
+    return 0;
+}
+"#;
+        let bytes = nodes_of(code);
+        let file = LengaFile::from_bincode(&bytes).unwrap();
+        assert!(file.header.is_some());
+
+        for encoding in [Encoding::Json, Encoding::SExpression] {
+            let text = file.encode(encoding);
+            let decoded = LengaFile::decode(&text, encoding).unwrap();
+            assert_eq!(decoded, file);
+            // Tags are written sorted, so the same nodes give the same bytes
+            assert_eq!(decoded.encode(encoding), text);
+            assert_eq!(convert(&text, encoding, Encoding::Bincode).unwrap(), bytes);
+        }
+
+        // Files written before the header have none in their text encodings either
+        let (_, legacy) = Header::read(&bytes).unwrap();
+        let json = convert(legacy, Encoding::Bincode, Encoding::Json).unwrap();
+        assert!(String::from_utf8_lossy(&json).contains("\"header\": null"));
+        let sexpr = convert(&json, Encoding::Json, Encoding::SExpression).unwrap();
+        assert_eq!(
+            convert(&sexpr, Encoding::SExpression, Encoding::Bincode).unwrap(),
+            legacy
+        );
+    }
+
+    #[test]
+    fn test_split_path() {
+        let split = |path: &str| Encoding::split_path(Path::new(path));
+        assert_eq!(
+            split("src/main.c.lenga"),
+            Some((PathBuf::from("src/main.c"), Encoding::Bincode))
+        );
+        assert_eq!(
+            split("main.c.lenga.json"),
+            Some((PathBuf::from("main.c"), Encoding::Json))
+        );
+        assert_eq!(
+            split("main.c.lenga.sexp"),
+            Some((PathBuf::from("main.c"), Encoding::SExpression))
+        );
+        assert_eq!(split("main.c"), None);
+        assert_eq!(split(".lenga"), None);
+        assert_eq!(split("main.c.json"), None);
+    }
+
+    #[test]
+    fn test_detect_encoding() {
+        let bytes = nodes_of("int x = 1;\n");
+        let (_, legacy) = Header::read(&bytes).unwrap();
+        for encoding in [Encoding::Bincode, Encoding::Json, Encoding::SExpression] {
+            let encoded = convert(&bytes, Encoding::Bincode, encoding).unwrap();
+            assert_eq!(Encoding::detect(&encoded), encoding);
+        }
+        assert_eq!(Encoding::detect(legacy), Encoding::Bincode);
+    }
+}
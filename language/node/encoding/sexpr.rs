@@ -0,0 +1,366 @@
+//! S-expression form of `.lenga` files:
+//!
+//! ```text
+//! (lenga
+//!   (header 1 "C" 14827277976614862739)
+//!   (node 0 73311b97-a1ed-411f-9202-d485631a277e ""
+//!     (children
+//!       (node 5 beb6a22d-a54f-4125-b7b2-8717f94b6f98 "x"
+//!         (tag "type"
+//!           (node 17 f384d3ad-0d47-43f4-a7c3-774f4144df46 "int"))))))
+//! ```
+//!
+//! A node is its type, its id and its content, then its tags sorted by name and its children.
+//! Files written before the header have no `header` expression.
+
+use std::{collections::HashMap, fmt::Write};
+
+use uuid::Uuid;
+
+use crate::node::{Node, encoding::LengaFile, header::Header};
+
+const INDENT: &str = "  ";
+
+pub fn write(file: &LengaFile) -> String {
+    let mut out = String::from("(lenga");
+    if let Some(header) = &file.header {
+        write!(
+            out,
+            "\n{INDENT}(header {} {} {})",
+            header.format_version,
+            quote(&header.language),
+            header.schema_hash
+        )
+        .unwrap();
+    }
+    for node in &file.nodes {
+        write_node(&mut out, node, 1);
+    }
+    out.push_str(")\n");
+    out
+}
+
+fn write_node(out: &mut String, node: &Node, depth: usize) {
+    let indent = INDENT.repeat(depth);
+    write!(
+        out,
+        "\n{indent}(node {} {} {}",
+        node.node_type,
+        node.id,
+        quote(&node.content)
+    )
+    .unwrap();
+    let mut tags: Vec<_> = node.tags.iter().collect();
+    tags.sort_by_key(|(name, _)| *name);
+    for (name, tag_nodes) in tags {
+        write!(out, "\n{indent}{INDENT}(tag {}", quote(name)).unwrap();
+        for tag_node in tag_nodes {
+            write_node(out, tag_node, depth + 2);
+        }
+        out.push(')');
+    }
+    if !node.children.is_empty() {
+        write!(out, "\n{indent}{INDENT}(children").unwrap();
+        for child in &node.children {
+            write_node(out, child, depth + 2);
+        }
+        out.push(')');
+    }
+    out.push(')');
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{{{:x}}}", c as u32).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+pub fn read(text: &str) -> Result<LengaFile, String> {
+    let mut reader = Reader { text, pos: 0 };
+    let expr = reader.expr()?;
+    reader.skip_whitespace();
+    if reader.pos < text.len() {
+        return Err(reader.error("text after the end of the file"));
+    }
+
+    let mut items = expect_list(expr, "lenga")?.into_iter().peekable();
+    let header = match items.peek() {
+        Some(Expr::List(list, _)) if is_head(list, "header") => {
+            let Some(Expr::List(list, offset)) = items.next() else {
+                unreachable!()
+            };
+            Some(header_from(list, offset)?)
+        }
+        _ => None,
+    };
+    let nodes = items.map(node_from).collect::<Result<_, _>>()?;
+    Ok(LengaFile { header, nodes })
+}
+
+#[derive(Debug)]
+enum Expr {
+    /// A number, an id or the name of an expression, with the byte it starts at
+    Atom(String, usize),
+    Str(String, usize),
+    List(Vec<Expr>, usize),
+}
+
+impl Expr {
+    fn offset(&self) -> usize {
+        match self {
+            Expr::Atom(_, offset) | Expr::Str(_, offset) | Expr::List(_, offset) => *offset,
+        }
+    }
+}
+
+fn error_at(offset: usize, message: &str) -> String {
+    format!("Malformed Lenga S-expression at byte {offset}: {message}")
+}
+
+fn is_head(list: &[Expr], name: &str) -> bool {
+    matches!(list.first(), Some(Expr::Atom(atom, _)) if atom == name)
+}
+
+/// Items after the head of a list starting with `name`
+fn expect_list(expr: Expr, name: &str) -> Result<Vec<Expr>, String> {
+    match expr {
+        Expr::List(list, _) if is_head(&list, name) => Ok(list.into_iter().skip(1).collect()),
+        expr => Err(error_at(expr.offset(), &format!("expected ({name} ...)"))),
+    }
+}
+
+fn atom<T: std::str::FromStr>(expr: Option<Expr>, end: usize, what: &str) -> Result<T, String> {
+    match expr {
+        Some(Expr::Atom(atom, offset)) => atom
+            .parse()
+            .map_err(|_| error_at(offset, &format!("expected {what}, found {atom}"))),
+        Some(expr) => Err(error_at(expr.offset(), &format!("expected {what}"))),
+        None => Err(error_at(end, &format!("expected {what}"))),
+    }
+}
+
+fn string(expr: Option<Expr>, end: usize, what: &str) -> Result<String, String> {
+    match expr {
+        Some(Expr::Str(text, _)) => Ok(text),
+        Some(expr) => Err(error_at(expr.offset(), &format!("expected {what}"))),
+        None => Err(error_at(end, &format!("expected {what}"))),
+    }
+}
+
+fn header_from(list: Vec<Expr>, offset: usize) -> Result<Header, String> {
+    let mut items = list.into_iter().skip(1);
+    let header = Header {
+        format_version: atom(items.next(), offset, "a format version")?,
+        language: string(items.next(), offset, "a language name")?,
+        schema_hash: atom(items.next(), offset, "a schema hash")?,
+    };
+    match items.next() {
+        Some(extra) => Err(error_at(extra.offset(), "unexpected item in header")),
+        None => Ok(header),
+    }
+}
+
+fn node_from(expr: Expr) -> Result<Node, String> {
+    let offset = expr.offset();
+    let mut items = expect_list(expr, "node")?.into_iter();
+    let node_type = atom(items.next(), offset, "a node type")?;
+    let id = atom::<Uuid>(items.next(), offset, "a node id")?;
+    let content = string(items.next(), offset, "the node content")?;
+    let mut tags = HashMap::new();
+    let mut children = Vec::new();
+    for item in items {
+        match item {
+            Expr::List(list, offset) if is_head(&list, "tag") => {
+                let mut tag_items = list.into_iter().skip(1);
+                let name = string(tag_items.next(), offset, "a tag name")?;
+                let tag_nodes = tag_items.map(node_from).collect::<Result<_, _>>()?;
+                if tags.insert(name.clone(), tag_nodes).is_some() {
+                    return Err(error_at(offset, &format!("tag {name:?} appears twice")));
+                }
+            }
+            Expr::List(list, offset) if is_head(&list, "children") => {
+                if !children.is_empty() {
+                    return Err(error_at(offset, "children appear twice"));
+                }
+                children = list
+                    .into_iter()
+                    .skip(1)
+                    .map(node_from)
+                    .collect::<Result<_, _>>()?;
+            }
+            item => {
+                return Err(error_at(
+                    item.offset(),
+                    "expected (tag ...) or (children ...)",
+                ));
+            }
+        }
+    }
+    Ok(Node {
+        id,
+        node_type,
+        content,
+        tags,
+        children,
+    })
+}
+
+struct Reader<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn error(&self, message: &str) -> String {
+        error_at(self.pos, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let mut list = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    match self.peek() {
+                        Some(')') => {
+                            self.pos += 1;
+                            return Ok(Expr::List(list, start));
+                        }
+                        None => return Err(error_at(start, "unclosed parenthesis")),
+                        Some(_) => list.push(self.expr()?),
+                    }
+                }
+            }
+            Some('"') => {
+                self.pos += 1;
+                Ok(Expr::Str(self.string_body(start)?, start))
+            }
+            Some(')') => Err(self.error("unexpected closing parenthesis")),
+            Some(_) => {
+                let len = self.text[start..]
+                    .find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | '"'))
+                    .unwrap_or(self.text.len() - start);
+                self.pos += len;
+                Ok(Expr::Atom(self.text[start..self.pos].to_string(), start))
+            }
+            None => Err(self.error("expected an expression")),
+        }
+    }
+
+    /// Unescaped text of a string, after its opening quote
+    fn string_body(&mut self, start: usize) -> Result<String, String> {
+        let mut text = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => {
+                    let escape = self.pos - 1;
+                    match self.next() {
+                        Some('"') => text.push('"'),
+                        Some('\\') => text.push('\\'),
+                        Some('n') => text.push('\n'),
+                        Some('r') => text.push('\r'),
+                        Some('t') => text.push('\t'),
+                        Some('u') => text.push(self.unicode_escape(escape)?),
+                        _ => return Err(error_at(escape, "unknown escape")),
+                    }
+                }
+                Some(c) => text.push(c),
+                None => return Err(error_at(start, "unclosed string")),
+            }
+        }
+    }
+
+    /// Character of a `\u{...}` escape, after its `u`
+    fn unicode_escape(&mut self, escape: usize) -> Result<char, String> {
+        let rest = &self.text[self.pos..];
+        let code = rest
+            .strip_prefix('{')
+            .and_then(|rest| rest.split_once('}'))
+            .map(|(code, _)| code)
+            .ok_or_else(|| error_at(escape, "unknown escape"))?;
+        self.pos += code.len() + 2;
+        u32::from_str_radix(code, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| error_at(escape, "invalid unicode escape"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sexpr_strings_and_errors() {
+        let file = LengaFile {
+            header: None,
+            nodes: vec![Node {
+                id: Uuid::nil(),
+                node_type: 7,
+                content: "say \"hi\"\\\n\t\u{7}é".to_string(),
+                tags: HashMap::new(),
+                children: vec![],
+            }],
+        };
+        let text = write(&file);
+        assert_eq!(
+            text,
+            "(lenga\n  (node 7 00000000-0000-0000-0000-000000000000 \"say \\\"hi\\\"\\\\\\n\\t\\u{7}é\"))\n"
+        );
+        assert_eq!(read(&text).unwrap(), file);
+
+        let error = |text: &str| read(text).unwrap_err();
+        assert_eq!(
+            error("(lenga (node 7"),
+            "Malformed Lenga S-expression at byte 7: unclosed parenthesis"
+        );
+        assert_eq!(
+            error("(lenga (node x 00000000-0000-0000-0000-000000000000 \"\"))"),
+            "Malformed Lenga S-expression at byte 13: expected a node type, found x"
+        );
+        assert_eq!(
+            error("(lenga (node 1 00000000-0000-0000-0000-000000000000 \"\" (kids)))"),
+            "Malformed Lenga S-expression at byte 55: expected (tag ...) or (children ...)"
+        );
+        assert_eq!(
+            error("(nodes)"),
+            "Malformed Lenga S-expression at byte 0: expected (lenga ...)"
+        );
+        assert_eq!(
+            error("(lenga) )"),
+            "Malformed Lenga S-expression at byte 8: text after the end of the file"
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use std::{fmt, io::Write};
+
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::language::Language;
+
+/// Bytes every `.lenga` file starts with. Files written before the header start with the
+/// length of their node list instead, which never matches
+pub const MAGIC: &[u8; 6] = b"LENGA\0";
+
+/// Version of the `.lenga` format, bumped whenever nodes are written differently. Files
+/// written before the header are version 0, with numbers and strings as they were spelled
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Most bytes a header takes after the magic bytes, far more than any language name needs
+const MAX_HEADER_LEN: u64 = 1024;
+
+/// What a `.lenga` file holds, written after the magic bytes and before its nodes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub format_version: u32,
+    /// Name of the language of the file, as given by `Language::name`
+    pub language: String,
+    /// Hash of the node types of the language, which tells apart builds that write the
+    /// same format version differently
+    pub schema_hash: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HeaderError {
+    /// The header is cut short or its bytes are not a header
+    Corrupted(String),
+    UnsupportedVersion {
+        found: u32,
+        supported: u32,
+    },
+    WrongLanguage {
+        found: String,
+        expected: String,
+    },
+    SchemaMismatch {
+        found: u64,
+        expected: u64,
+    },
+    /// No migration upgrades nodes from this format version to the next one
+    MissingMigration {
+        from: u32,
+    },
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderError::Corrupted(reason) => write!(f, "Corrupted Lenga header: {reason}"),
+            HeaderError::UnsupportedVersion { found, supported } if found > supported => write!(
+                f,
+                "Lenga format version {found} is newer than the supported version {supported}, update Lenga to read this file"
+            ),
+            HeaderError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "Lenga format version {found} is older than the supported version {supported}, migrate this file to read it"
+            ),
+            HeaderError::WrongLanguage { found, expected } => {
+                write!(f, "Lenga file holds {found} code, not {expected}")
+            }
+            HeaderError::SchemaMismatch { found, expected } => write!(
+                f,
+                "Lenga file was written with node schema {found:016x}, this build reads {expected:016x}"
+            ),
+            HeaderError::MissingMigration { from } => {
+                write!(f, "No migration from Lenga format version {from}")
+            }
+        }
+    }
+}
+
+impl From<HeaderError> for String {
+    fn from(err: HeaderError) -> Self {
+        err.to_string()
+    }
+}
+
+impl Header {
+    /// Header of the files this build writes for `language`
+    pub fn for_language<L: Language>(language: &L) -> Self {
+        Header {
+            format_version: FORMAT_VERSION,
+            language: language.name(),
+            schema_hash: language.schema_hash(),
+        }
+    }
+
+    /// Writes the magic bytes and the header
+    pub fn write(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        bincode::serialize_into(writer, self).map_err(std::io::Error::other)
+    }
+
+    /// Splits a `.lenga` file into its header and the bytes of its nodes. Files written
+    /// before the header have none, and all their bytes are nodes
+    pub fn read(bytes: &[u8]) -> Result<(Option<Header>, &[u8]), HeaderError> {
+        let Some(rest) = bytes.strip_prefix(MAGIC) else {
+            return Ok((None, bytes));
+        };
+        let mut cursor = std::io::Cursor::new(rest);
+        // The options of `bincode::serialize_into`, bounded so corrupted lengths don't
+        // allocate a language name as long as they say
+        let header: Header = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit(MAX_HEADER_LEN)
+            .deserialize_from(&mut cursor)
+            .map_err(|err| HeaderError::Corrupted(err.to_string()))?;
+        let header_len = cursor.position() as usize;
+        Ok((Some(header), &rest[header_len..]))
+    }
+
+    /// Checks that a file with this header can be read as one with the `expected` header
+    pub fn check(&self, expected: &Header) -> Result<(), HeaderError> {
+        if self.language != expected.language {
+            return Err(HeaderError::WrongLanguage {
+                found: self.language.clone(),
+                expected: expected.language.clone(),
+            });
+        }
+        if self.format_version != expected.format_version {
+            return Err(HeaderError::UnsupportedVersion {
+                found: self.format_version,
+                supported: expected.format_version,
+            });
+        }
+        if self.schema_hash != expected.schema_hash {
+            return Err(HeaderError::SchemaMismatch {
+                found: self.schema_hash,
+                expected: expected.schema_hash,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Stable hash of the description of a node schema, the same on every build and platform
+pub fn schema_hash(schema: &str) -> u64 {
+    let digest = Sha256::digest(schema.as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
@@ -0,0 +1,568 @@
+//! Matching of two versions of the same nodes, after the GumTree algorithm of Falleri et al.
+//! Identical subtrees are matched top-down, from the tallest, then the nodes holding them are
+//! matched bottom-up by how many of their descendants were matched together, and the children
+//! of matched nodes left unmatched are paired in order.
+
+use std::{
+    collections::{HashMap, HashSet, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+};
+
+use uuid::Uuid;
+
+use crate::node::{Node, migration::for_each_node};
+
+/// Subtrees shorter than this are only matched through the nodes holding them, since small
+/// ones like a literal or a type repeat all over a file
+const MIN_HEIGHT: usize = 2;
+/// Share of their descendants two nodes must have matched together to be matched themselves
+const MIN_DICE: f64 = 0.5;
+/// Tags telling where a node is and how it is laid out, which change with the code around it
+/// and are left out of the matching
+const LAYOUT_TAGS: [&str; 6] = [
+    "span",
+    "blank_lines_before",
+    "line_breaks",
+    "indent",
+    "fingerprint",
+    "source",
+];
+
+/// Ids of the nodes in `new` matched to a node in `old`, with the id of that node. References
+/// to ids outside the nodes, like symbols from a header, are matched the same way
+pub fn match_ids(old: &[Node], new: &[Node]) -> HashMap<Uuid, Uuid> {
+    let old_tree = Tree::new(old);
+    let new_tree = Tree::new(new);
+    let mut matcher = Matcher::new(&old_tree, &new_tree);
+    matcher.top_down();
+    matcher.bottom_up();
+
+    let mut ids = HashMap::new();
+    for (old_index, new_index) in matcher.pairs() {
+        let (old_entry, new_entry) = (&old_tree.entries[old_index], &new_tree.entries[new_index]);
+        ids.insert(new_entry.id, old_entry.id);
+        if let (Some(old_reference), Some(new_reference)) =
+            (old_entry.reference, new_entry.reference)
+            && !old_tree.ids.contains_key(&old_reference)
+            && !new_tree.ids.contains_key(&new_reference)
+        {
+            ids.entry(new_reference).or_insert(old_reference);
+        }
+    }
+    ids
+}
+
+/// Gives the nodes in `new` the ids of the nodes in `old` they are matched to, and updates the
+/// references to them, so the new version of the nodes keeps the history of the old one. Nodes
+/// left unmatched get a new id if theirs is taken in `old`. Returns how many nodes kept an id
+pub fn keep_matched_ids(old: &[Node], new: &mut [Node]) -> usize {
+    let mut ids = match_ids(old, new);
+    let mut old_ids = HashSet::new();
+    collect_ids(old, &mut old_ids);
+    for_each_node(new, &mut |node| {
+        if !ids.contains_key(&node.id) && old_ids.contains(&node.id) {
+            ids.insert(node.id, Uuid::new_v4());
+        }
+    });
+
+    let mut kept = 0;
+    for_each_node(new, &mut |node| {
+        if let Some(id) = ids.get(&node.id) {
+            node.id = *id;
+            if old_ids.contains(id) {
+                kept += 1;
+            }
+        }
+        if let Some(id) = reference(&node.content).and_then(|reference| ids.get(&reference)) {
+            node.content = id.to_string();
+        }
+    });
+    kept
+}
+
+fn collect_ids(nodes: &[Node], ids: &mut HashSet<Uuid>) {
+    for node in nodes {
+        ids.insert(node.id);
+        for tag_nodes in node.tags.values() {
+            collect_ids(tag_nodes, ids);
+        }
+        collect_ids(&node.children, ids);
+    }
+}
+
+/// Id a node references through its content, like a variable its declaration
+fn reference(content: &str) -> Option<Uuid> {
+    if content.len() != 36 {
+        return None;
+    }
+    Uuid::try_parse(content).ok()
+}
+
+/// A node of a [`Tree`]
+struct Entry {
+    id: Uuid,
+    node_type: u64,
+    /// Tag the node is in, empty for children
+    role: String,
+    content: String,
+    reference: Option<Uuid>,
+    /// Node type, role and content, with references replaced by what they reference
+    label: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    height: usize,
+    /// Number of nodes in the subtree of the node, the node included
+    size: usize,
+    /// Hash of the labels of the subtree, equal for identical subtrees
+    hash: u64,
+}
+
+/// Nodes in pre-order, under a root standing for the file, so the subtree of a node is the
+/// entries right after it
+struct Tree {
+    entries: Vec<Entry>,
+    ids: HashMap<Uuid, usize>,
+}
+
+impl Tree {
+    fn new(nodes: &[Node]) -> Tree {
+        let mut tree = Tree {
+            entries: vec![Entry {
+                id: Uuid::nil(),
+                node_type: u64::MAX,
+                role: String::new(),
+                content: String::new(),
+                reference: None,
+                label: String::new(),
+                parent: None,
+                children: vec![],
+                height: 1,
+                size: 1,
+                hash: 0,
+            }],
+            ids: HashMap::new(),
+        };
+        for node in nodes {
+            let child = tree.push(node, "", 0);
+            tree.entries[0].children.push(child);
+        }
+        tree.entries[0].size = tree.entries.len();
+        tree.entries[0].height = 1 + tree.max_child_height(0);
+
+        for index in 1..tree.entries.len() {
+            let entry = &tree.entries[index];
+            let target = entry
+                .reference
+                .and_then(|reference| tree.ids.get(&reference))
+                .map(|target| &tree.entries[*target]);
+            let content = match (entry.reference, target) {
+                (Some(_), Some(target)) => format!("->{}:{}", target.node_type, target.content),
+                (Some(_), None) => "->?".to_string(),
+                (None, _) => entry.content.clone(),
+            };
+            tree.entries[index].label = format!("{}:{}:{content}", entry.node_type, entry.role);
+        }
+        for index in (0..tree.entries.len()).rev() {
+            let mut hasher = DefaultHasher::new();
+            tree.entries[index].label.hash(&mut hasher);
+            for child in &tree.entries[index].children {
+                tree.entries[*child].hash.hash(&mut hasher);
+            }
+            tree.entries[index].hash = hasher.finish();
+        }
+        tree
+    }
+
+    fn push(&mut self, node: &Node, role: &str, parent: usize) -> usize {
+        let index = self.entries.len();
+        self.ids.entry(node.id).or_insert(index);
+        self.entries.push(Entry {
+            id: node.id,
+            node_type: node.node_type,
+            role: role.to_string(),
+            content: node.content.clone(),
+            reference: reference(&node.content),
+            label: String::new(),
+            parent: Some(parent),
+            children: vec![],
+            height: 1,
+            size: 1,
+            hash: 0,
+        });
+
+        let mut tags: Vec<_> = node
+            .tags
+            .iter()
+            .filter(|(name, _)| !LAYOUT_TAGS.contains(&name.as_str()))
+            .collect();
+        tags.sort_by_key(|(name, _)| *name);
+        for (name, tag_nodes) in tags {
+            for tag_node in tag_nodes {
+                let child = self.push(tag_node, name, index);
+                self.entries[index].children.push(child);
+            }
+        }
+        for child_node in &node.children {
+            let child = self.push(child_node, "", index);
+            self.entries[index].children.push(child);
+        }
+        self.entries[index].size = self.entries.len() - index;
+        self.entries[index].height = 1 + self.max_child_height(index);
+        index
+    }
+
+    fn max_child_height(&self, index: usize) -> usize {
+        self.entries[index]
+            .children
+            .iter()
+            .map(|child| self.entries[*child].height)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn is_descendant(&self, descendant: usize, ancestor: usize) -> bool {
+        ancestor < descendant && descendant < ancestor + self.entries[ancestor].size
+    }
+
+    fn same_kind(&self, index: usize, other: &Tree, other_index: usize) -> bool {
+        let (entry, other_entry) = (&self.entries[index], &other.entries[other_index]);
+        entry.node_type == other_entry.node_type && entry.role == other_entry.role
+    }
+}
+
+struct Matcher<'a> {
+    old: &'a Tree,
+    new: &'a Tree,
+    /// Node of `new` matched to each node of `old`
+    old_to_new: Vec<Option<usize>>,
+    new_to_old: Vec<Option<usize>>,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(old: &'a Tree, new: &'a Tree) -> Self {
+        Matcher {
+            old,
+            new,
+            old_to_new: vec![None; old.entries.len()],
+            new_to_old: vec![None; new.entries.len()],
+        }
+    }
+
+    /// Matched nodes, the roots standing for the files left out
+    fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.old_to_new
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(old, new)| new.map(|new| (old, new)))
+    }
+
+    fn add(&mut self, old: usize, new: usize) {
+        self.old_to_new[old] = Some(new);
+        self.new_to_old[new] = Some(old);
+    }
+
+    /// Matches two identical subtrees node by node
+    fn add_subtree(&mut self, old: usize, new: usize) {
+        for offset in 0..self.old.entries[old].size {
+            self.add(old + offset, new + offset);
+        }
+    }
+
+    /// Matches the tallest identical subtrees first. Subtrees with several identical copies
+    /// are matched last, preferring the copies whose parents are most alike
+    fn top_down(&mut self) {
+        let mut old_hashes: HashMap<u64, usize> = HashMap::new();
+        for entry in &self.old.entries {
+            *old_hashes.entry(entry.hash).or_default() += 1;
+        }
+        let mut new_hashes: HashMap<u64, usize> = HashMap::new();
+        for entry in &self.new.entries {
+            *new_hashes.entry(entry.hash).or_default() += 1;
+        }
+
+        let mut old_queue = self.old.entries[0].children.clone();
+        let mut new_queue = self.new.entries[0].children.clone();
+        let mut ambiguous = Vec::new();
+        loop {
+            let old_height = max_height(self.old, &old_queue);
+            let new_height = max_height(self.new, &new_queue);
+            if old_height.min(new_height) < MIN_HEIGHT {
+                break;
+            }
+            if old_height != new_height {
+                if old_height > new_height {
+                    open(self.old, &mut old_queue, old_height);
+                } else {
+                    open(self.new, &mut new_queue, new_height);
+                }
+                continue;
+            }
+
+            let old_tallest = pop_height(self.old, &mut old_queue, old_height);
+            let new_tallest = pop_height(self.new, &mut new_queue, new_height);
+            let mut paired_old = HashSet::new();
+            let mut paired_new = HashSet::new();
+            for &old in &old_tallest {
+                for &new in &new_tallest {
+                    let hash = self.old.entries[old].hash;
+                    if hash != self.new.entries[new].hash {
+                        continue;
+                    }
+                    if old_hashes[&hash] > 1 || new_hashes[&hash] > 1 {
+                        ambiguous.push((old, new));
+                    } else {
+                        self.add_subtree(old, new);
+                    }
+                    paired_old.insert(old);
+                    paired_new.insert(new);
+                }
+            }
+            for old in old_tallest
+                .into_iter()
+                .filter(|old| !paired_old.contains(old))
+            {
+                old_queue.extend(&self.old.entries[old].children);
+            }
+            for new in new_tallest
+                .into_iter()
+                .filter(|new| !paired_new.contains(new))
+            {
+                new_queue.extend(&self.new.entries[new].children);
+            }
+        }
+
+        let parent_dice = |matcher: &Self, (old, new): (usize, usize)| match (
+            matcher.old.entries[old].parent,
+            matcher.new.entries[new].parent,
+        ) {
+            (Some(old_parent), Some(new_parent)) => matcher.dice(old_parent, new_parent),
+            _ => 0.0,
+        };
+        let mut ambiguous: Vec<_> = ambiguous
+            .into_iter()
+            .map(|pair| (parent_dice(self, pair), pair))
+            .collect();
+        ambiguous.sort_by(|(dice, _), (other, _)| other.total_cmp(dice));
+        for (_, (old, new)) in ambiguous {
+            if self.old_to_new[old].is_none() && self.new_to_old[new].is_none() {
+                self.add_subtree(old, new);
+            }
+        }
+    }
+
+    /// Matches the nodes left, children first, to the node of the same kind holding most of the
+    /// nodes their descendants were matched to, then pairs the children of each match
+    fn bottom_up(&mut self) {
+        for old in (1..self.old.entries.len()).rev() {
+            if self.old_to_new[old].is_some() || self.old.entries[old].children.is_empty() {
+                continue;
+            }
+            let mut candidates = HashSet::new();
+            for descendant in old + 1..old + self.old.entries[old].size {
+                let Some(matched) = self.old_to_new[descendant] else {
+                    continue;
+                };
+                let mut ancestor = self.new.entries[matched].parent;
+                while let Some(new) = ancestor {
+                    if self.new_to_old[new].is_none() && self.old.same_kind(old, self.new, new) {
+                        candidates.insert(new);
+                    }
+                    ancestor = self.new.entries[new].parent;
+                }
+            }
+            let best = candidates
+                .into_iter()
+                .map(|new| (self.dice(old, new), new))
+                .max_by(|(dice, new), (other, other_new)| {
+                    dice.total_cmp(other).then(other_new.cmp(new))
+                });
+            if let Some((dice, new)) = best
+                && dice > MIN_DICE
+            {
+                self.add(old, new);
+                self.recover(old, new);
+            }
+        }
+        self.add(0, 0);
+        self.recover(0, 0);
+    }
+
+    /// Pairs the unmatched children of two matched nodes in order, identical subtrees first,
+    /// then nodes with the same label, then nodes of the same kind
+    fn recover(&mut self, old: usize, new: usize) {
+        let (old_tree, new_tree) = (self.old, self.new);
+        let identical =
+            |old: usize, new: usize| old_tree.entries[old].hash == new_tree.entries[new].hash;
+        for (old_child, new_child) in self.unmatched_children_lcs(old, new, identical) {
+            self.add_subtree(old_child, new_child);
+        }
+        let same_label =
+            |old: usize, new: usize| old_tree.entries[old].label == new_tree.entries[new].label;
+        let same_kind = |old: usize, new: usize| old_tree.same_kind(old, new_tree, new);
+        for alike in [&same_label as &dyn Fn(usize, usize) -> bool, &same_kind] {
+            for (old_child, new_child) in self.unmatched_children_lcs(old, new, alike) {
+                self.add(old_child, new_child);
+                self.recover(old_child, new_child);
+            }
+        }
+    }
+
+    /// Longest common subsequence of the unmatched children of `old` and `new`
+    fn unmatched_children_lcs(
+        &self,
+        old: usize,
+        new: usize,
+        alike: impl Fn(usize, usize) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let old_children: Vec<usize> = self.old.entries[old]
+            .children
+            .iter()
+            .copied()
+            .filter(|child| self.old_to_new[*child].is_none())
+            .collect();
+        let new_children: Vec<usize> = self.new.entries[new]
+            .children
+            .iter()
+            .copied()
+            .filter(|child| self.new_to_old[*child].is_none())
+            .collect();
+        lcs(&old_children, &new_children, alike)
+    }
+
+    /// Share of the descendants of `old` and `new` matched to one another
+    fn dice(&self, old: usize, new: usize) -> f64 {
+        let old_descendants = self.old.entries[old].size - 1;
+        let new_descendants = self.new.entries[new].size - 1;
+        if old_descendants + new_descendants == 0 {
+            return 0.0;
+        }
+        let common = (old + 1..old + 1 + old_descendants)
+            .filter(|descendant| {
+                self.old_to_new[*descendant]
+                    .is_some_and(|matched| self.new.is_descendant(matched, new))
+            })
+            .count();
+        2.0 * common as f64 / (old_descendants + new_descendants) as f64
+    }
+}
+
+fn max_height(tree: &Tree, queue: &[usize]) -> usize {
+    queue
+        .iter()
+        .map(|index| tree.entries[*index].height)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Removes the nodes of `height` from `queue`
+fn pop_height(tree: &Tree, queue: &mut Vec<usize>, height: usize) -> Vec<usize> {
+    let (tallest, rest) = queue
+        .iter()
+        .partition(|index| tree.entries[**index].height == height);
+    *queue = rest;
+    tallest
+}
+
+/// Replaces the nodes of `height` in `queue` with their children
+fn open(tree: &Tree, queue: &mut Vec<usize>, height: usize) {
+    for index in pop_height(tree, queue, height) {
+        queue.extend(&tree.entries[index].children);
+    }
+}
+
+fn lcs(old: &[usize], new: &[usize], alike: impl Fn(usize, usize) -> bool) -> Vec<(usize, usize)> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if alike(old[i], new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut pairs = Vec::new();
+    while i < old.len() && j < new.len() {
+        if alike(old[i], new[j]) {
+            pairs.push((old[i], new[j]));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(node_type: u64, content: &str, children: Vec<Node>) -> Node {
+        Node {
+            id: Uuid::new_v4(),
+            node_type,
+            content: content.to_string(),
+            tags: HashMap::new(),
+            children,
+        }
+    }
+
+    fn function(name: &str, statements: &[&str]) -> Node {
+        let body = statements
+            .iter()
+            .map(|statement| node(3, statement, vec![node(4, statement, vec![])]))
+            .collect();
+        node(2, name, vec![node(5, "", body)])
+    }
+
+    #[test]
+    fn test_keep_matched_ids() {
+        // A call to `second`, at the position it is written at
+        let call = |second: &Node, span: &str| {
+            let mut call = node(6, &second.id.to_string(), vec![]);
+            call.tags
+                .insert("span".to_string(), vec![node(7, span, vec![])]);
+            call
+        };
+
+        let (first, second) = (
+            function("first", &["a", "b"]),
+            function("second", &["c", "d", "e"]),
+        );
+        let old_call = call(&second, "10:4:120");
+        let old = vec![node(1, "", vec![first, second, old_call])];
+
+        // Functions swapped, a statement edited and the call written further down
+        let (first, second) = (
+            function("first", &["a", "b"]),
+            function("second", &["c", "x", "e"]),
+        );
+        let new_call = call(&second, "14:4:160");
+        let mut new = vec![node(1, "", vec![second, first, new_call])];
+
+        let kept = keep_matched_ids(&old, &mut new);
+        let (old_file, new_file) = (&old[0], &new[0]);
+        assert_eq!(new_file.id, old_file.id);
+        assert_eq!(new_file.children[1], old_file.children[0]);
+        let (old_second, new_second) = (&old_file.children[1], &new_file.children[0]);
+        assert_eq!(new_second.id, old_second.id);
+        let (old_body, new_body) = (&old_second.children[0], &new_second.children[0]);
+        assert_eq!(new_body.id, old_body.id);
+        assert_eq!(new_body.children[0], old_body.children[0]);
+        assert_eq!(new_body.children[2], old_body.children[2]);
+        // The edited statement is the one in its place, with a new content
+        assert_eq!(new_body.children[1].id, old_body.children[1].id);
+        assert_eq!(new_body.children[1].content, "x");
+        // The call keeps its id and references the second function by its old id
+        let (old_call, new_call) = (&old_file.children[2], &new_file.children[2]);
+        assert_eq!(new_call.id, old_call.id);
+        assert_eq!(new_call.content, old_second.id.to_string());
+        // Every node but the spans, which are left out of the matching
+        assert_eq!(kept, 16);
+    }
+}
@@ -0,0 +1,475 @@
+use std::fmt;
+
+use uuid::Uuid;
+
+use crate::{
+    language::{
+        Language,
+        c::{
+            language_object::expression_object::{
+                number_literal::NumberLiteral, string_literal::StringLiteral,
+            },
+            writers::node_writer::{node_type::NodeType, number_literal_tags, string_literal_tags},
+        },
+    },
+    node::{
+        Node, UuidFromSeed,
+        header::{FORMAT_VERSION, Header, HeaderError},
+        seed_nil_ids,
+    },
+};
+
+/// Upgrades the nodes of a `.lenga` file from one format version to the next
+pub struct Migration {
+    /// Version the nodes are upgraded from, to the one right after it
+    pub from: u32,
+    /// What the migration changes, as reported to whoever migrates a file
+    pub description: &'static str,
+    /// Upgrades the nodes of a file, returning how many of them it changed
+    pub apply: fn(&mut [Node]) -> usize,
+}
+
+/// Migrations from every older format version to the current one
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    description: "Split numbers into their value, radix and suffix, and decode strings",
+    apply: decode_literals,
+}];
+
+/// Files written before the header kept numbers as spelled, sign included, and strings as
+/// the escaped text between their quotes. Numbers get their value, radix and suffix, with a
+/// sign split into a unary expression, and strings their decoded value and segments. The nodes
+/// added for them get ids seeded from the id of their literal
+fn decode_literals(nodes: &mut [Node]) -> usize {
+    let changed = decode_literals_in(nodes);
+    nodes.iter_mut().for_each(seed_nil_ids);
+    changed
+}
+
+/// Decodes the literals in `nodes` without going into their tags, whose nodes have the type of
+/// the literal too
+fn decode_literals_in(nodes: &mut [Node]) -> usize {
+    let mut changed = 0;
+    for node in nodes {
+        if node.node_type == NodeType::NumberLiteral.as_u64() {
+            if !node.tags.contains_key("integer") && !node.tags.contains_key("float") {
+                changed += decode_number(node) as usize;
+            }
+            continue;
+        }
+        if node.node_type == NodeType::StringLiteral.as_u64() {
+            if !node.tags.contains_key("segments") {
+                changed += decode_string(node) as usize;
+            }
+            continue;
+        }
+        for tag_nodes in node.tags.values_mut() {
+            changed += decode_literals_in(tag_nodes);
+        }
+        changed += decode_literals_in(&mut node.children);
+    }
+    changed
+}
+
+fn decode_number(node: &mut Node) -> bool {
+    let sign = node.content.strip_prefix(['-', '+']).map(|digits| {
+        (
+            node.content[..1].to_string(),
+            digits.trim_start().to_string(),
+        )
+    });
+    let spelling = sign
+        .as_ref()
+        .map_or(node.content.as_str(), |(_, digits)| digits.as_str());
+    let Some(number_literal) = NumberLiteral::from_spelling(spelling) else {
+        return false;
+    };
+    let tags = number_literal_tags(&number_literal);
+    match sign {
+        Some((operator, digits)) => {
+            let number = Node {
+                id: Uuid::from_seed(&format!("{}/number_literal", node.id)),
+                node_type: NodeType::NumberLiteral.as_u64(),
+                content: digits,
+                tags,
+                children: vec![],
+            };
+            node.node_type = NodeType::UnaryExpression.as_u64();
+            node.content = operator;
+            node.children = vec![number];
+        }
+        None => node.tags.extend(tags),
+    }
+    true
+}
+
+fn decode_string(node: &mut Node) -> bool {
+    let Some(string_literal) = StringLiteral::from_segments(vec![format!("\"{}\"", node.content)])
+    else {
+        return false;
+    };
+    node.tags.extend(string_literal_tags(&string_literal));
+    node.content = string_literal.value;
+    true
+}
+
+/// A migration applied to the nodes of a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedMigration {
+    pub from: u32,
+    pub description: &'static str,
+    pub changed_nodes: usize,
+}
+
+/// What migrating the nodes of a file changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationReport {
+    pub from: u32,
+    pub to: u32,
+    pub applied: Vec<AppliedMigration>,
+}
+
+impl MigrationReport {
+    /// Whether the nodes were already written with the format they were migrated to
+    pub fn is_up_to_date(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_up_to_date() {
+            return write!(f, "up to date with format version {}", self.to);
+        }
+        write!(
+            f,
+            "migrated from format version {} to {}",
+            self.from, self.to
+        )?;
+        for applied in &self.applied {
+            write!(
+                f,
+                "\n  {} -> {}: {} ({} nodes changed)",
+                applied.from,
+                applied.from + 1,
+                applied.description,
+                applied.changed_nodes
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Format version of a file with `header`, once checked that its nodes can be migrated to
+/// those of a file with the `expected` header. Files written before the header are version 0
+pub fn readable_version(header: Option<&Header>, expected: &Header) -> Result<u32, HeaderError> {
+    let Some(header) = header else {
+        return Ok(0);
+    };
+    if header.format_version >= expected.format_version {
+        header.check(expected)?;
+    } else if header.language != expected.language {
+        return Err(HeaderError::WrongLanguage {
+            found: header.language.clone(),
+            expected: expected.language.clone(),
+        });
+    }
+    Ok(header.format_version)
+}
+
+/// Upgrades `nodes` written with format version `from` to the current one, a version at a time
+pub fn migrate(nodes: &mut [Node], from: u32) -> Result<MigrationReport, HeaderError> {
+    migrate_with(MIGRATIONS, nodes, from, FORMAT_VERSION)
+}
+
+/// Upgrades `nodes` written with format version `from` to version `to` with `migrations`
+pub fn migrate_with(
+    migrations: &[Migration],
+    nodes: &mut [Node],
+    from: u32,
+    to: u32,
+) -> Result<MigrationReport, HeaderError> {
+    if from > to {
+        return Err(HeaderError::UnsupportedVersion {
+            found: from,
+            supported: to,
+        });
+    }
+    let mut applied = Vec::new();
+    for version in from..to {
+        let migration = migrations
+            .iter()
+            .find(|migration| migration.from == version)
+            .ok_or(HeaderError::MissingMigration { from: version })?;
+        applied.push(AppliedMigration {
+            from: version,
+            description: migration.description,
+            changed_nodes: (migration.apply)(nodes),
+        });
+    }
+    Ok(MigrationReport { from, to, applied })
+}
+
+/// Rewrites a `.lenga` file for `language` with the current format version, returning the
+/// new bytes and what changed. Files already up to date are returned as they were
+pub fn migrate_file<L: Language>(
+    language: &L,
+    bytes: &[u8],
+) -> Result<(Vec<u8>, MigrationReport), String> {
+    let expected = Header::for_language(language);
+    let (header, body) = Header::read(bytes)?;
+    let version = readable_version(header.as_ref(), &expected)?;
+    let mut nodes = bincode::deserialize::<Vec<Node>>(body)
+        .map_err(|err| format!("Corrupted Lenga nodes: {err}"))?;
+    let report = migrate(&mut nodes, version)?;
+    if report.is_up_to_date() {
+        return Ok((bytes.to_vec(), report));
+    }
+
+    let mut migrated = Vec::new();
+    expected
+        .write(&mut migrated)
+        .map_err(|err| err.to_string())?;
+    bincode::serialize_into(&mut migrated, &nodes).map_err(|err| err.to_string())?;
+    Ok((migrated, report))
+}
+
+/// Calls `f` on every node in `nodes`, the ones in their tags and children included
+pub fn for_each_node(nodes: &mut [Node], f: &mut impl FnMut(&mut Node)) {
+    for node in nodes {
+        f(node);
+        for tag_nodes in node.tags.values_mut() {
+            for_each_node(tag_nodes, f);
+        }
+        for_each_node(&mut node.children, f);
+    }
+}
+
+/// Changes the node type of the nodes whose type is the first of a pair of `codes` to the
+/// second one, returning how many nodes changed
+pub fn remap_node_types(nodes: &mut [Node], codes: &[(u64, u64)]) -> usize {
+    let mut changed = 0;
+    for_each_node(nodes, &mut |node| {
+        if let Some((_, new)) = codes.iter().find(|(old, _)| *old == node.node_type) {
+            node.node_type = *new;
+            changed += 1;
+        }
+    });
+    changed
+}
+
+/// Renames the tag `from` of every node to `to`, returning how many nodes changed
+pub fn rename_tag(nodes: &mut [Node], from: &str, to: &str) -> usize {
+    let mut changed = 0;
+    for_each_node(nodes, &mut |node| {
+        if let Some(tag_nodes) = node.tags.remove(from) {
+            node.tags.insert(to.to_string(), tag_nodes);
+            changed += 1;
+        }
+    });
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::language::c::C;
+
+    fn node(node_type: u64, tags: Vec<(&str, Vec<Node>)>, children: Vec<Node>) -> Node {
+        Node {
+            id: Uuid::new_v4(),
+            node_type,
+            content: String::new(),
+            tags: tags
+                .into_iter()
+                .map(|(name, nodes)| (name.to_string(), nodes))
+                .collect(),
+            children,
+        }
+    }
+
+    fn renumber_leaves(nodes: &mut [Node]) -> usize {
+        remap_node_types(nodes, &[(10, 20)])
+    }
+
+    fn rename_name_tag(nodes: &mut [Node]) -> usize {
+        rename_tag(nodes, "name", "identifier")
+    }
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration {
+            from: 1,
+            description: "Rename the name tag",
+            apply: rename_name_tag,
+        },
+        Migration {
+            from: 0,
+            description: "Renumber leaves",
+            apply: renumber_leaves,
+        },
+    ];
+
+    #[test]
+    fn test_migrate_step_by_step() {
+        let mut nodes = vec![node(
+            1,
+            vec![("name", vec![node(10, vec![], vec![])])],
+            vec![node(
+                2,
+                vec![("name", vec![node(3, vec![], vec![])])],
+                vec![node(10, vec![], vec![])],
+            )],
+        )];
+
+        let report = migrate_with(TEST_MIGRATIONS, &mut nodes, 0, 2).unwrap();
+        assert_eq!(
+            report.applied,
+            vec![
+                AppliedMigration {
+                    from: 0,
+                    description: "Renumber leaves",
+                    changed_nodes: 2,
+                },
+                AppliedMigration {
+                    from: 1,
+                    description: "Rename the name tag",
+                    changed_nodes: 2,
+                },
+            ]
+        );
+        assert_eq!(nodes[0].tags["identifier"][0].node_type, 20);
+        assert_eq!(nodes[0].children[0].children[0].node_type, 20);
+        assert_eq!(nodes[0].children[0].tags["identifier"][0].node_type, 3);
+        assert!(!nodes[0].tags.contains_key("name"));
+
+        // Nodes already at a version only go through the migrations after it
+        let report = migrate_with(TEST_MIGRATIONS, &mut nodes, 1, 2).unwrap();
+        assert_eq!(report.applied.len(), 1);
+        assert_eq!(report.applied[0].changed_nodes, 0);
+        assert!(
+            migrate_with(TEST_MIGRATIONS, &mut nodes, 2, 2)
+                .unwrap()
+                .is_up_to_date()
+        );
+
+        assert_eq!(
+            migrate_with(TEST_MIGRATIONS, &mut nodes, 0, 3),
+            Err(HeaderError::MissingMigration { from: 2 })
+        );
+        assert_eq!(
+            migrate_with(TEST_MIGRATIONS, &mut nodes, 3, 2),
+            Err(HeaderError::UnsupportedVersion {
+                found: 3,
+                supported: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_readable_version() {
+        let expected = Header {
+            format_version: 2,
+            language: "C".to_string(),
+            schema_hash: 1,
+        };
+        assert_eq!(readable_version(None, &expected), Ok(0));
+        assert_eq!(readable_version(Some(&expected), &expected), Ok(2));
+
+        // Older files were written with older schemas, which their migrations account for
+        let older = Header {
+            format_version: 1,
+            schema_hash: 7,
+            ..expected.clone()
+        };
+        assert_eq!(readable_version(Some(&older), &expected), Ok(1));
+        let older_rust = Header {
+            language: "Rust".to_string(),
+            ..older
+        };
+        assert!(matches!(
+            readable_version(Some(&older_rust), &expected),
+            Err(HeaderError::WrongLanguage { .. })
+        ));
+        let newer = Header {
+            format_version: 3,
+            ..expected.clone()
+        };
+        assert!(matches!(
+            readable_version(Some(&newer), &expected),
+            Err(HeaderError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_migrate_file_written_before_the_header() {
+        // Written by the first release, with numbers as spelled and strings still escaped
+        let bytes = include_bytes!("../language/c/fixtures/baseline.c.lenga");
+        let c_language = C::new();
+        let (migrated, report) = migrate_file(&c_language, bytes).unwrap();
+        assert_eq!(report.from, 0);
+        // 0x1F, -1, -2, 5 and 0, and the format string
+        assert_eq!(report.applied[0].changed_nodes, 6);
+
+        let (header, body) = Header::read(&migrated).unwrap();
+        assert_eq!(header, Some(Header::for_language(&c_language)));
+        let mut nodes = bincode::deserialize::<Vec<Node>>(body).unwrap();
+        // The nodes added for literals get ids too
+        let mut nil_ids = 0;
+        for_each_node(&mut nodes, &mut |node| nil_ids += node.id.is_nil() as usize);
+        assert_eq!(nil_ids, 0);
+        let mut numbers = vec![];
+        let mut strings = vec![];
+        let mut unary_operators = vec![];
+        // The nodes in the tags of a literal have its type too, so literals are told by their tags
+        for_each_node(&mut nodes, &mut |node| {
+            if let Some(radix) = node.tags.get("radix") {
+                numbers.push((
+                    node.content.clone(),
+                    node.tags["integer"][0].content.clone(),
+                    radix[0].content.clone(),
+                ));
+            } else if let Some(segments) = node.tags.get("segments") {
+                strings.push((node.content.clone(), segments[0].content.clone()));
+            } else if node.node_type == NodeType::UnaryExpression.as_u64() {
+                assert!(!node.id.is_nil());
+                unary_operators.push(node.content.clone());
+            }
+        });
+        let number = |spelling: &str, integer: &str, radix: &str| {
+            (spelling.to_string(), integer.to_string(), radix.to_string())
+        };
+        assert_eq!(
+            numbers,
+            vec![
+                number("0x1F", "31", "16"),
+                number("1", "1", "10"),
+                number("2", "2", "10"),
+                number("5", "5", "10"),
+                number("0", "0", "10"),
+            ]
+        );
+        assert_eq!(strings, vec![("hi\n".to_string(), r#""hi\n""#.to_string())]);
+        assert_eq!(unary_operators, vec!["-", "-"]);
+
+        // The migrated file reads like the same code parsed from text
+        let from_text = c_language
+            .parse_text(include_str!("../language/c/fixtures/baseline.c"))
+            .unwrap();
+        let from_text = c_language
+            .parse_nodes(c_language.write_to_nodes(from_text).unwrap())
+            .unwrap();
+        assert_eq!(
+            c_language
+                .write_to_text(c_language.parse_nodes(migrated.clone()).unwrap())
+                .unwrap(),
+            c_language.write_to_text(from_text).unwrap()
+        );
+
+        // Migrating a second time leaves the file as it is
+        let (again, report) = migrate_file(&c_language, &migrated).unwrap();
+        assert!(report.is_up_to_date());
+        assert_eq!(again, migrated);
+    }
+}
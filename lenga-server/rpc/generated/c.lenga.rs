@@ -4,7 +4,7 @@
 pub struct LanguageObject {
     #[prost(
         oneof = "language_object::LanguageObject",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 404, 16, 17"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 404, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48"
     )]
     pub language_object: ::core::option::Option<language_object::LanguageObject>,
 }
@@ -49,12 +49,77 @@ pub mod language_object {
         FunctionParameter(super::FunctionParameter),
         #[prost(message, tag = "17")]
         ElseClause(super::ElseClause),
+        #[prost(message, tag = "18")]
+        WhileStatement(super::WhileStatement),
+        #[prost(message, tag = "19")]
+        DoStatement(super::DoStatement),
+        #[prost(message, tag = "20")]
+        ForStatement(super::ForStatement),
+        #[prost(message, tag = "21")]
+        SwitchStatement(super::SwitchStatement),
+        #[prost(message, tag = "22")]
+        CaseStatement(super::CaseStatement),
+        #[prost(message, tag = "23")]
+        BreakStatement(super::BreakStatement),
+        #[prost(message, tag = "24")]
+        ContinueStatement(super::ContinueStatement),
+        #[prost(message, tag = "25")]
+        StructDefinition(super::StructDefinition),
+        #[prost(message, tag = "26")]
+        UnionDefinition(super::UnionDefinition),
+        #[prost(message, tag = "27")]
+        EnumDefinition(super::EnumDefinition),
+        #[prost(message, tag = "28")]
+        FieldDeclaration(super::FieldDeclaration),
+        #[prost(message, tag = "29")]
+        Enumerator(super::Enumerator),
+        #[prost(message, tag = "30")]
+        UnaryExpression(super::UnaryExpression),
+        #[prost(message, tag = "31")]
+        UpdateExpression(super::UpdateExpression),
+        #[prost(message, tag = "32")]
+        CastExpression(super::CastExpression),
+        #[prost(message, tag = "33")]
+        SizeofExpression(super::SizeofExpression),
+        #[prost(message, tag = "34")]
+        ConditionalExpression(super::ConditionalExpression),
+        #[prost(message, tag = "35")]
+        FieldExpression(super::FieldExpression),
+        #[prost(message, tag = "36")]
+        SubscriptExpression(super::SubscriptExpression),
+        #[prost(message, tag = "37")]
+        ParenthesizedExpression(super::ParenthesizedExpression),
+        #[prost(message, tag = "38")]
+        PreprocDefine(super::PreprocDefine),
+        #[prost(message, tag = "39")]
+        PreprocCall(super::PreprocCall),
+        #[prost(message, tag = "40")]
+        PreprocIf(super::PreprocIf),
+        #[prost(message, tag = "41")]
+        PreprocIfStatement(super::PreprocIfStatement),
+        #[prost(message, tag = "42")]
+        DeclarationList(super::DeclarationList),
+        #[prost(message, tag = "43")]
+        InitializerList(super::InitializerList),
+        #[prost(message, tag = "44")]
+        InitializerPair(super::InitializerPair),
+        #[prost(message, tag = "45")]
+        CharLiteral(super::CharLiteral),
+        #[prost(message, tag = "46")]
+        LabeledStatement(super::LabeledStatement),
+        #[prost(message, tag = "47")]
+        GotoStatement(super::GotoStatement),
+        #[prost(message, tag = "48")]
+        TypeDefinition(super::TypeDefinition),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeclarationObject {
-    #[prost(oneof = "declaration_object::DeclarationObject", tags = "1, 2, 3, 4, 5, 6")]
+    #[prost(
+        oneof = "declaration_object::DeclarationObject",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14"
+    )]
     pub declaration_object: ::core::option::Option<
         declaration_object::DeclarationObject,
     >,
@@ -76,12 +141,31 @@ pub mod declaration_object {
         Comment(super::Comment),
         #[prost(message, tag = "6")]
         Unknown(super::Unknown),
+        #[prost(message, tag = "7")]
+        StructDefinition(super::StructDefinition),
+        #[prost(message, tag = "8")]
+        UnionDefinition(super::UnionDefinition),
+        #[prost(message, tag = "9")]
+        EnumDefinition(super::EnumDefinition),
+        #[prost(message, tag = "10")]
+        PreprocDefine(super::PreprocDefine),
+        #[prost(message, tag = "11")]
+        PreprocCall(super::PreprocCall),
+        #[prost(message, tag = "12")]
+        PreprocIf(super::PreprocIf),
+        #[prost(message, tag = "13")]
+        DeclarationList(super::DeclarationList),
+        #[prost(message, tag = "14")]
+        TypeDefinition(super::TypeDefinition),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ExpressionObject {
-    #[prost(oneof = "expression_object::ExpressionObject", tags = "1, 2, 3, 4, 5, 6, 7")]
+    #[prost(
+        oneof = "expression_object::ExpressionObject",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18"
+    )]
     pub expression_object: ::core::option::Option<expression_object::ExpressionObject>,
 }
 /// Nested message and enum types in `ExpressionObject`.
@@ -103,12 +187,37 @@ pub mod expression_object {
         StringLiteral(super::StringLiteral),
         #[prost(message, tag = "7")]
         Unknown(super::Unknown),
+        #[prost(message, tag = "8")]
+        UnaryExpression(::prost::alloc::boxed::Box<super::UnaryExpression>),
+        #[prost(message, tag = "9")]
+        UpdateExpression(::prost::alloc::boxed::Box<super::UpdateExpression>),
+        #[prost(message, tag = "10")]
+        CastExpression(::prost::alloc::boxed::Box<super::CastExpression>),
+        #[prost(message, tag = "11")]
+        SizeofExpression(::prost::alloc::boxed::Box<super::SizeofExpression>),
+        #[prost(message, tag = "12")]
+        ConditionalExpression(::prost::alloc::boxed::Box<super::ConditionalExpression>),
+        #[prost(message, tag = "13")]
+        FieldExpression(::prost::alloc::boxed::Box<super::FieldExpression>),
+        #[prost(message, tag = "14")]
+        SubscriptExpression(::prost::alloc::boxed::Box<super::SubscriptExpression>),
+        #[prost(message, tag = "15")]
+        ParenthesizedExpression(::prost::alloc::boxed::Box<super::ParenthesizedExpression>),
+        #[prost(message, tag = "16")]
+        InitializerList(::prost::alloc::boxed::Box<super::InitializerList>),
+        #[prost(message, tag = "17")]
+        InitializerPair(::prost::alloc::boxed::Box<super::InitializerPair>),
+        #[prost(message, tag = "18")]
+        CharLiteral(super::CharLiteral),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StatementObject {
-    #[prost(oneof = "statement_object::StatementObject", tags = "1, 2, 3, 4")]
+    #[prost(
+        oneof = "statement_object::StatementObject",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13"
+    )]
     pub statement_object: ::core::option::Option<statement_object::StatementObject>,
 }
 /// Nested message and enum types in `StatementObject`.
@@ -124,6 +233,24 @@ pub mod statement_object {
         ReturnStatement(super::ReturnStatement),
         #[prost(message, tag = "4")]
         Unknown(super::Unknown),
+        #[prost(message, tag = "5")]
+        WhileStatement(super::WhileStatement),
+        #[prost(message, tag = "6")]
+        DoStatement(super::DoStatement),
+        #[prost(message, tag = "7")]
+        ForStatement(super::ForStatement),
+        #[prost(message, tag = "8")]
+        SwitchStatement(super::SwitchStatement),
+        #[prost(message, tag = "9")]
+        CaseStatement(super::CaseStatement),
+        #[prost(message, tag = "10")]
+        BreakStatement(super::BreakStatement),
+        #[prost(message, tag = "11")]
+        ContinueStatement(super::ContinueStatement),
+        #[prost(message, tag = "12")]
+        LabeledStatement(super::LabeledStatement),
+        #[prost(message, tag = "13")]
+        GotoStatement(super::GotoStatement),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -131,7 +258,7 @@ pub mod statement_object {
 pub struct CompoundStatementObject {
     #[prost(
         oneof = "compound_statement_object::CompoundStatementObject",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38"
     )]
     pub compound_statement_object: ::core::option::Option<
         compound_statement_object::CompoundStatementObject,
@@ -166,6 +293,58 @@ pub mod compound_statement_object {
         Comment(super::Comment),
         #[prost(message, tag = "12")]
         Unknown(super::Unknown),
+        #[prost(message, tag = "13")]
+        WhileStatement(::prost::alloc::boxed::Box<super::WhileStatement>),
+        #[prost(message, tag = "14")]
+        DoStatement(::prost::alloc::boxed::Box<super::DoStatement>),
+        #[prost(message, tag = "15")]
+        ForStatement(::prost::alloc::boxed::Box<super::ForStatement>),
+        #[prost(message, tag = "16")]
+        SwitchStatement(::prost::alloc::boxed::Box<super::SwitchStatement>),
+        #[prost(message, tag = "17")]
+        CaseStatement(super::CaseStatement),
+        #[prost(message, tag = "18")]
+        BreakStatement(super::BreakStatement),
+        #[prost(message, tag = "19")]
+        ContinueStatement(super::ContinueStatement),
+        #[prost(message, tag = "20")]
+        StructDefinition(super::StructDefinition),
+        #[prost(message, tag = "21")]
+        UnionDefinition(super::UnionDefinition),
+        #[prost(message, tag = "22")]
+        EnumDefinition(super::EnumDefinition),
+        #[prost(message, tag = "23")]
+        UnaryExpression(super::UnaryExpression),
+        #[prost(message, tag = "24")]
+        UpdateExpression(super::UpdateExpression),
+        #[prost(message, tag = "25")]
+        CastExpression(super::CastExpression),
+        #[prost(message, tag = "26")]
+        SizeofExpression(super::SizeofExpression),
+        #[prost(message, tag = "27")]
+        ConditionalExpression(super::ConditionalExpression),
+        #[prost(message, tag = "28")]
+        FieldExpression(super::FieldExpression),
+        #[prost(message, tag = "29")]
+        SubscriptExpression(super::SubscriptExpression),
+        #[prost(message, tag = "30")]
+        ParenthesizedExpression(super::ParenthesizedExpression),
+        #[prost(message, tag = "31")]
+        PreprocDefine(super::PreprocDefine),
+        #[prost(message, tag = "32")]
+        PreprocCall(super::PreprocCall),
+        #[prost(message, tag = "33")]
+        PreprocIfStatement(super::PreprocIfStatement),
+        #[prost(message, tag = "34")]
+        DeclarationList(super::DeclarationList),
+        #[prost(message, tag = "35")]
+        CharLiteral(super::CharLiteral),
+        #[prost(message, tag = "36")]
+        LabeledStatement(::prost::alloc::boxed::Box<super::LabeledStatement>),
+        #[prost(message, tag = "37")]
+        GotoStatement(super::GotoStatement),
+        #[prost(message, tag = "38")]
+        TypeDefinition(super::TypeDefinition),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -175,6 +354,8 @@ pub struct SourceFile {
     pub id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
     pub code: ::prost::alloc::vec::Vec<DeclarationObject>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -183,18 +364,41 @@ pub struct Unknown {
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub content: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArrayType {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub element: ::core::option::Option<::prost::alloc::boxed::Box<CType>>,
+    /// As written, absent for arrays declared without a size
+    #[prost(string, optional, tag = "3")]
+    pub size: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "4")]
+    pub is_static: bool,
+    #[prost(bool, tag = "5")]
+    pub is_const: bool,
+    #[prost(bool, tag = "6")]
+    pub is_volatile: bool,
+    #[prost(bool, tag = "7")]
+    pub is_restrict: bool,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AssignmentExpression {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub id_declaration: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub identifier: ::prost::alloc::string::String,
     #[prost(message, optional, boxed, tag = "4")]
     pub value: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(message, optional, boxed, tag = "7")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -207,6 +411,55 @@ pub struct BinaryExpression {
     pub operator: ::prost::alloc::string::String,
     #[prost(message, optional, boxed, tag = "4")]
     pub right: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BreakStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "3")]
+    pub comments: ::core::option::Option<Comments>,
+}
+/// Struct, union and enum types, and typedef names declared in the source, reference their
+/// definition by id. Primitive also holds spellings with modifiers, like "unsigned long"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CType {
+    #[prost(oneof = "c_type::Kind", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10")]
+    pub kind: ::core::option::Option<c_type::Kind>,
+}
+/// Nested message and enum types in `CType`.
+pub mod c_type {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(string, tag = "1")]
+        Primitive(::prost::alloc::string::String),
+        #[prost(message, tag = "2")]
+        StructType(super::TypeReference),
+        #[prost(message, tag = "3")]
+        UnionType(super::TypeReference),
+        #[prost(message, tag = "4")]
+        EnumType(super::TypeReference),
+        #[prost(string, tag = "5")]
+        TypedefName(::prost::alloc::string::String),
+        #[prost(message, tag = "6")]
+        Pointer(::prost::alloc::boxed::Box<super::CType>),
+        #[prost(message, tag = "7")]
+        Array(::prost::alloc::boxed::Box<super::ArrayType>),
+        #[prost(message, tag = "8")]
+        Qualified(::prost::alloc::boxed::Box<super::QualifiedType>),
+        #[prost(message, tag = "9")]
+        Function(::prost::alloc::boxed::Box<super::FnType>),
+        #[prost(message, tag = "10")]
+        Named(super::TypeReference),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -219,6 +472,56 @@ pub struct CallExpression {
     pub identifier: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub argument_list: ::prost::alloc::vec::Vec<ExpressionObject>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CaseStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Absent for the default label
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<ExpressionObject>,
+    #[prost(message, repeated, tag = "3")]
+    pub body: ::prost::alloc::vec::Vec<CompoundStatementObject>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CastExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub cast_type: ::core::option::Option<CType>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub value: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CharLiteral {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub prefix: ::prost::alloc::string::String,
+    /// The literal as written, like '\n'
+    #[prost(string, tag = "4")]
+    pub spelling: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -227,6 +530,17 @@ pub struct Comment {
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub content: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+}
+/// Comments attached before and after an object
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Comments {
+    #[prost(message, repeated, tag = "1")]
+    pub leading: ::prost::alloc::vec::Vec<Comment>,
+    #[prost(message, repeated, tag = "2")]
+    pub trailing: ::prost::alloc::vec::Vec<Comment>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -235,19 +549,99 @@ pub struct CompoundStatement {
     pub id: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "2")]
     pub code_block: ::prost::alloc::vec::Vec<CompoundStatementObject>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConditionalExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub condition: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub consequence: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub alternative: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContinueStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "3")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Declaration {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// Assuming CType is serialized as string
-    #[prost(string, tag = "2")]
-    pub primitive_type: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub primitive_type: ::core::option::Option<CType>,
     #[prost(string, tag = "3")]
     pub identifier: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "4")]
     pub value: ::core::option::Option<ExpressionObject>,
+    #[prost(string, repeated, tag = "5")]
+    pub specifiers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeclarationList {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub declarations: ::prost::alloc::vec::Vec<Declaration>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Designator {
+    #[prost(oneof = "designator::Kind", tags = "1, 2")]
+    pub kind: ::core::option::Option<designator::Kind>,
+}
+/// Nested message and enum types in `Designator`.
+pub mod designator {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(string, tag = "1")]
+        Field(::prost::alloc::string::String),
+        #[prost(message, tag = "2")]
+        Index(::prost::alloc::boxed::Box<super::ExpressionObject>),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DoStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub body: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "3")]
+    pub condition: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -258,34 +652,159 @@ pub struct ElseClause {
     pub body: ::core::option::Option<
         ::prost::alloc::boxed::Box<CompoundStatementObject>,
     >,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FunctionDeclaration {
+pub struct ForStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Declaration or expression
+    #[prost(message, optional, boxed, tag = "2")]
+    pub initializer: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "3")]
+    pub condition: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, tag = "4")]
+    pub update: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, boxed, tag = "5")]
+    pub body: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnumDefinition {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub enumerators: ::prost::alloc::vec::Vec<Enumerator>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(bool, tag = "6")]
+    pub is_forward: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Enumerator {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub value: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldDeclaration {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// CType serialized as string
     #[prost(string, tag = "2")]
-    pub return_type: ::prost::alloc::string::String,
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub field_type: ::core::option::Option<CType>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(bool, tag = "6")]
+    pub shares_declaration: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FieldExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub argument: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(string, tag = "3")]
+    pub operator: ::prost::alloc::string::String,
+    /// Id of the accessed struct or union member, nil when the type is unknown
+    #[prost(string, tag = "4")]
+    pub declaration_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FnType {
+    #[prost(message, optional, boxed, tag = "1")]
+    pub return_type: ::core::option::Option<::prost::alloc::boxed::Box<CType>>,
+    #[prost(message, repeated, tag = "2")]
+    pub parameters: ::prost::alloc::vec::Vec<CType>,
+    /// One per parameter, empty for unnamed ones. Absent when none is named
+    #[prost(string, repeated, tag = "3")]
+    pub parameter_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "4")]
+    pub is_variadic: bool,
+    #[prost(bool, tag = "5")]
+    pub explicit_void: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FunctionDeclaration {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub return_type: ::core::option::Option<CType>,
     #[prost(string, tag = "3")]
     pub identifier: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub parameter_list: ::prost::alloc::vec::Vec<FunctionParameter>,
+    #[prost(string, repeated, tag = "5")]
+    pub specifiers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "6")]
+    pub is_variadic: bool,
+    #[prost(bool, tag = "7")]
+    pub explicit_void: bool,
+    #[prost(message, optional, tag = "8")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "9")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FunctionDefinition {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
-    /// CType serialized as string
-    #[prost(string, tag = "2")]
-    pub return_type: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub return_type: ::core::option::Option<CType>,
     #[prost(string, tag = "3")]
     pub identifier: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub parameter_list: ::prost::alloc::vec::Vec<FunctionParameter>,
     #[prost(message, optional, tag = "5")]
     pub compound_statement: ::core::option::Option<CompoundStatement>,
+    #[prost(string, repeated, tag = "6")]
+    pub specifiers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bool, tag = "7")]
+    pub is_variadic: bool,
+    #[prost(bool, tag = "8")]
+    pub explicit_void: bool,
+    #[prost(message, optional, tag = "9")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "10")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -294,9 +813,27 @@ pub struct FunctionParameter {
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub identifier: ::prost::alloc::string::String,
-    /// CType serialized as string
+    #[prost(message, optional, tag = "3")]
+    pub param_type: ::core::option::Option<CType>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GotoStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Id of the labeled statement it jumps to
+    #[prost(string, tag = "2")]
+    pub label_id: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
-    pub param_type: ::prost::alloc::string::String,
+    pub label: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -309,6 +846,10 @@ pub struct IfStatement {
     pub body: ::core::option::Option<
         ::prost::alloc::boxed::Box<CompoundStatementObject>,
     >,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
     #[prost(oneof = "if_statement::ElseStatement", tags = "4, 5")]
     pub else_statement: ::core::option::Option<if_statement::ElseStatement>,
 }
@@ -325,11 +866,162 @@ pub mod if_statement {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializerList {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub elements: ::prost::alloc::vec::Vec<ExpressionObject>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InitializerPair {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub designators: ::prost::alloc::vec::Vec<Designator>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub value: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+/// The id of a labeled statement is also the symbol id of its label
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LabeledStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+    /// Absent for an empty statement
+    #[prost(message, optional, boxed, tag = "3")]
+    pub statement: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+/// Value holds the literal as written, like 0x1Fu. The parsed number is absent when the
+/// literal is only known by its spelling
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NumberLiteral {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub value: ::prost::alloc::string::String,
+    /// 2, 8, 10 or 16
+    #[prost(uint32, tag = "5")]
+    pub radix: u32,
+    /// Lowercase, like "u", "ul" or "f"
+    #[prost(string, tag = "6")]
+    pub suffix: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "7")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "8")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(oneof = "number_literal::Number", tags = "3, 4")]
+    pub number: ::core::option::Option<number_literal::Number>,
+}
+/// Nested message and enum types in `NumberLiteral`.
+pub mod number_literal {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Number {
+        #[prost(uint64, tag = "3")]
+        Integer(u64),
+        #[prost(double, tag = "4")]
+        Float(f64),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParenthesizedExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub value: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreprocCall {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub directive: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub argument: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreprocDefine {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub function_like: bool,
+    #[prost(string, repeated, tag = "4")]
+    pub parameters: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreprocIf {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub directive: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub condition: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub body: ::prost::alloc::vec::Vec<DeclarationObject>,
+    #[prost(message, optional, boxed, tag = "5")]
+    pub alternative: ::core::option::Option<::prost::alloc::boxed::Box<PreprocIf>>,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreprocIfStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub directive: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub condition: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub body: ::prost::alloc::vec::Vec<CompoundStatementObject>,
+    #[prost(message, optional, boxed, tag = "5")]
+    pub alternative: ::core::option::Option<
+        ::prost::alloc::boxed::Box<PreprocIfStatement>,
+    >,
+    #[prost(message, optional, tag = "6")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "7")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -338,6 +1030,22 @@ pub struct PreprocInclude {
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub content: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QualifiedType {
+    #[prost(bool, tag = "1")]
+    pub is_const: bool,
+    #[prost(bool, tag = "2")]
+    pub is_volatile: bool,
+    #[prost(bool, tag = "3")]
+    pub is_restrict: bool,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub base: ::core::option::Option<::prost::alloc::boxed::Box<CType>>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -348,6 +1056,10 @@ pub struct Reference {
     pub declaration_id: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -356,14 +1068,191 @@ pub struct ReturnStatement {
     pub id: ::prost::alloc::string::String,
     #[prost(message, optional, tag = "2")]
     pub value: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, tag = "3")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "4")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SizeofExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Set when the operand is a type
+    #[prost(message, optional, tag = "2")]
+    pub argument_type: ::core::option::Option<CType>,
+    /// Set when the operand is an expression
+    #[prost(message, optional, boxed, tag = "3")]
+    pub value: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+/// Where an object was parsed from. Lines and byte columns are zero-based
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Span {
+    #[prost(uint32, tag = "1")]
+    pub start_line: u32,
+    #[prost(uint32, tag = "2")]
+    pub start_column: u32,
+    #[prost(uint32, tag = "3")]
+    pub end_line: u32,
+    #[prost(uint32, tag = "4")]
+    pub end_column: u32,
+    #[prost(uint64, tag = "5")]
+    pub start_offset: u64,
+    #[prost(uint64, tag = "6")]
+    pub end_offset: u64,
+}
+/// Value is the decoded string; segments keep each adjacent literal as written, like L"a\n"
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StringLiteral {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub value: ::prost::alloc::string::String,
+    /// Encoding prefix: "", "L", "u8", "u" or "U"
+    #[prost(string, tag = "3")]
+    pub prefix: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "4")]
+    pub segments: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StructDefinition {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldDeclaration>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(bool, tag = "6")]
+    pub is_forward: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscriptExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub argument: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub index: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwitchStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub condition: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub body: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TypeDefinition {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub aliased_type: ::core::option::Option<CType>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TypeReference {
+    #[prost(string, tag = "1")]
+    pub declaration_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnaryExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub argument: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnionDefinition {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub identifier: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub fields: ::prost::alloc::vec::Vec<FieldDeclaration>,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
+    #[prost(bool, tag = "6")]
+    pub is_forward: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateExpression {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_prefix: bool,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub argument: ::core::option::Option<::prost::alloc::boxed::Box<ExpressionObject>>,
+    #[prost(message, optional, tag = "5")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "6")]
+    pub comments: ::core::option::Option<Comments>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WhileStatement {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub condition: ::core::option::Option<ExpressionObject>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub body: ::core::option::Option<
+        ::prost::alloc::boxed::Box<CompoundStatementObject>,
+    >,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+    #[prost(message, optional, tag = "5")]
+    pub comments: ::core::option::Option<Comments>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -438,6 +1327,56 @@ pub struct InsertOptions {
     #[prost(message, repeated, tag = "1")]
     pub options: ::prost::alloc::vec::Vec<LanguageObject>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckTextRequest {
+    #[prost(string, tag = "1")]
+    pub content: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Diagnostics {
+    #[prost(message, repeated, tag = "1")]
+    pub diagnostics: ::prost::alloc::vec::Vec<Diagnostic>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Diagnostic {
+    #[prost(enumeration = "Severity", tag = "1")]
+    pub severity: i32,
+    #[prost(string, tag = "2")]
+    pub code: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub span: ::core::option::Option<Span>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Severity {
+    Error = 0,
+    Warning = 1,
+}
+impl Severity {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Severity::Error => "ERROR",
+            Severity::Warning => "WARNING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ERROR" => Some(Self::Error),
+            "WARNING" => Some(Self::Warning),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod c_lenga_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -642,6 +1581,25 @@ pub mod c_lenga_client {
                 .insert(GrpcMethod::new("c.lenga.CLenga", "AvailableInserts"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn check_text(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckTextRequest>,
+        ) -> std::result::Result<tonic::Response<super::Diagnostics>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/c.lenga.CLenga/CheckText");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("c.lenga.CLenga", "CheckText"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -675,6 +1633,10 @@ pub mod c_lenga_server {
             &self,
             request: tonic::Request<super::AvailableInsertsRequest>,
         ) -> std::result::Result<tonic::Response<super::InsertOptions>, tonic::Status>;
+        async fn check_text(
+            &self,
+            request: tonic::Request<super::CheckTextRequest>,
+        ) -> std::result::Result<tonic::Response<super::Diagnostics>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct CLengaServer<T: CLenga> {
@@ -1021,6 +1983,52 @@ pub mod c_lenga_server {
                     };
                     Box::pin(fut)
                 }
+                "/c.lenga.CLenga/CheckText" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckTextSvc<T: CLenga>(pub Arc<T>);
+                    impl<
+                        T: CLenga,
+                    > tonic::server::UnaryService<super::CheckTextRequest>
+                    for CheckTextSvc<T> {
+                        type Response = super::Diagnostics;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckTextRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CLenga>::check_text(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CheckTextSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
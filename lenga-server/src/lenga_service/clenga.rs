@@ -27,10 +27,13 @@ use proto::{
 };
 
 use crate::lenga_service::clenga::{
-    node_parser::{c_language_object_to_proto, source_file_to_proto},
+    node_parser::{c_language_object_to_proto, diagnostic_to_proto, source_file_to_proto},
     node_searcher::find_node,
     nodes_replacer::replace_source_file,
-    proto::{AvailableInsertsRequest, EditResponse, InsertOptions, SaveRequest},
+    proto::{
+        AvailableInsertsRequest, CheckTextRequest, Diagnostics, EditResponse, InsertOptions,
+        SaveRequest,
+    },
     proto_parser::proto_to_c_language_object,
 };
 
@@ -146,6 +149,23 @@ impl CLenga for CLengaService {
         }
     }
 
+    async fn check_text(
+        &self,
+        request: Request<CheckTextRequest>,
+    ) -> Result<Response<Diagnostics>, Status> {
+        let req = request.into_inner();
+
+        let c = C::new();
+        let diagnostics = match c.parse_text_with_diagnostics(&req.content) {
+            Ok((_, diagnostics)) => diagnostics,
+            Err(diagnostics) => diagnostics,
+        };
+
+        Ok(Response::new(Diagnostics {
+            diagnostics: diagnostics.into_iter().map(diagnostic_to_proto).collect(),
+        }))
+    }
+
     async fn save(&self, request: Request<SaveRequest>) -> Result<Response<Void>, Status> {
         let req = request.into_inner();
 
@@ -6,6 +6,7 @@ use std::{
 use lenga::language::{
     Language,
     c::{self, C},
+    diagnostic::{Diagnostic, Severity, Span},
 };
 
 use crate::lenga_service::clenga::proto;
@@ -51,6 +52,92 @@ pub fn c_language_object_to_proto(
                 )),
             }
         }
+        c::language_object::LanguageObject::UnaryExpression(unary_expression) => {
+            let unary_expression_msg = unary_expression_to_proto(unary_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::UnaryExpression(
+                    unary_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::UpdateExpression(update_expression) => {
+            let update_expression_msg = update_expression_to_proto(update_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::UpdateExpression(
+                    update_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::CastExpression(cast_expression) => {
+            let cast_expression_msg = cast_expression_to_proto(cast_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::CastExpression(
+                    cast_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::SizeofExpression(sizeof_expression) => {
+            let sizeof_expression_msg = sizeof_expression_to_proto(sizeof_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::SizeofExpression(
+                    sizeof_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::ConditionalExpression(conditional_expression) => {
+            let conditional_expression_msg =
+                conditional_expression_to_proto(conditional_expression);
+            proto::LanguageObject {
+                language_object: Some(
+                    proto::language_object::LanguageObject::ConditionalExpression(
+                        conditional_expression_msg,
+                    ),
+                ),
+            }
+        }
+        c::language_object::LanguageObject::FieldExpression(field_expression) => {
+            let field_expression_msg = field_expression_to_proto(field_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::FieldExpression(
+                    field_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::SubscriptExpression(subscript_expression) => {
+            let subscript_expression_msg = subscript_expression_to_proto(subscript_expression);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::SubscriptExpression(
+                    subscript_expression_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::ParenthesizedExpression(parenthesized_expression) => {
+            let parenthesized_expression_msg =
+                parenthesized_expression_to_proto(parenthesized_expression);
+            proto::LanguageObject {
+                language_object: Some(
+                    proto::language_object::LanguageObject::ParenthesizedExpression(
+                        parenthesized_expression_msg,
+                    ),
+                ),
+            }
+        }
+        c::language_object::LanguageObject::InitializerList(initializer_list) => {
+            let initializer_list_msg = initializer_list_to_proto(initializer_list);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::InitializerList(
+                    initializer_list_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::InitializerPair(initializer_pair) => {
+            let initializer_pair_msg = initializer_pair_to_proto(initializer_pair);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::InitializerPair(
+                    initializer_pair_msg,
+                )),
+            }
+        }
         c::language_object::LanguageObject::CallExpression(call_expression) => {
             let call_expression_msg = call_expression_to_proto(call_expression);
             proto::LanguageObject {
@@ -73,6 +160,14 @@ pub fn c_language_object_to_proto(
                 )),
             }
         }
+        c::language_object::LanguageObject::DeclarationList(declaration_list) => {
+            let declaration_list_msg = declaration_list_to_proto(declaration_list);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::DeclarationList(
+                    declaration_list_msg,
+                )),
+            }
+        }
         c::language_object::LanguageObject::ElseClause(else_clause) => {
             let else_clause_msg = else_clause_to_proto(else_clause);
             proto::LanguageObject {
@@ -121,6 +216,38 @@ pub fn c_language_object_to_proto(
                 )),
             }
         }
+        c::language_object::LanguageObject::PreprocDefine(preproc_define) => {
+            let preproc_define_msg = preproc_define_to_proto(preproc_define);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::PreprocDefine(
+                    preproc_define_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::PreprocCall(preproc_call) => {
+            let preproc_call_msg = preproc_call_to_proto(preproc_call);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::PreprocCall(
+                    preproc_call_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::PreprocIf(preproc_if) => {
+            let preproc_if_msg = preproc_if_to_proto(preproc_if);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::PreprocIf(
+                    preproc_if_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::PreprocIfStatement(preproc_if_statement) => {
+            let preproc_if_statement_msg = preproc_if_statement_to_proto(preproc_if_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::PreprocIfStatement(
+                    preproc_if_statement_msg,
+                )),
+            }
+        }
         c::language_object::LanguageObject::PreprocInclude(preproc_include) => {
             let preproc_include_msg = preproc_include_to_proto(preproc_include);
             proto::LanguageObject {
@@ -153,6 +280,14 @@ pub fn c_language_object_to_proto(
                 )),
             }
         }
+        c::language_object::LanguageObject::CharLiteral(char_literal) => {
+            let char_literal_msg = char_literal_to_proto(char_literal);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::CharLiteral(
+                    char_literal_msg,
+                )),
+            }
+        }
         c::language_object::LanguageObject::CompoundStatement(compound_statement) => {
             let compound_statement_msg = compound_statement_to_proto(compound_statement);
             proto::LanguageObject {
@@ -161,6 +296,126 @@ pub fn c_language_object_to_proto(
                 )),
             }
         }
+        c::language_object::LanguageObject::WhileStatement(while_statement) => {
+            let while_statement_msg = while_statement_to_proto(while_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::WhileStatement(
+                    while_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::DoStatement(do_statement) => {
+            let do_statement_msg = do_statement_to_proto(do_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::DoStatement(
+                    do_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::ForStatement(for_statement) => {
+            let for_statement_msg = for_statement_to_proto(for_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::ForStatement(
+                    for_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::SwitchStatement(switch_statement) => {
+            let switch_statement_msg = switch_statement_to_proto(switch_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::SwitchStatement(
+                    switch_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::CaseStatement(case_statement) => {
+            let case_statement_msg = case_statement_to_proto(case_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::CaseStatement(
+                    case_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::BreakStatement(break_statement) => {
+            let break_statement_msg = break_statement_to_proto(break_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::BreakStatement(
+                    break_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::ContinueStatement(continue_statement) => {
+            let continue_statement_msg = continue_statement_to_proto(continue_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::ContinueStatement(
+                    continue_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::GotoStatement(goto_statement) => {
+            let goto_statement_msg = goto_statement_to_proto(goto_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::GotoStatement(
+                    goto_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::LabeledStatement(labeled_statement) => {
+            let labeled_statement_msg = labeled_statement_to_proto(labeled_statement);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::LabeledStatement(
+                    labeled_statement_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::StructDefinition(struct_definition) => {
+            let struct_definition_msg = struct_definition_to_proto(struct_definition);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::StructDefinition(
+                    struct_definition_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::UnionDefinition(union_definition) => {
+            let union_definition_msg = union_definition_to_proto(union_definition);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::UnionDefinition(
+                    union_definition_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::EnumDefinition(enum_definition) => {
+            let enum_definition_msg = enum_definition_to_proto(enum_definition);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::EnumDefinition(
+                    enum_definition_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::TypeDefinition(type_definition) => {
+            let type_definition_msg = type_definition_to_proto(type_definition);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::TypeDefinition(
+                    type_definition_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::FieldDeclaration(field_declaration) => {
+            let field_declaration_msg = field_declaration_to_proto(field_declaration);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::FieldDeclaration(
+                    field_declaration_msg,
+                )),
+            }
+        }
+        c::language_object::LanguageObject::Enumerator(enumerator) => {
+            let enumerator_msg = enumerator_to_proto(enumerator);
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::Enumerator(
+                    enumerator_msg,
+                )),
+            }
+        }
         c::language_object::LanguageObject::Unknown(unknown) => {
             let unknown_msg = unknown_to_proto(unknown);
             proto::LanguageObject {
@@ -179,6 +434,7 @@ pub fn source_file_to_proto(
 
     proto::SourceFile {
         id: src_file.id.to_string(),
+        span: src_file.span.map(span_to_proto),
         code,
     }
 }
@@ -196,6 +452,15 @@ fn c_declaration_object_to_proto(
                 ),
             }
         }
+        c::language_object::declaration_object::DeclarationObject::DeclarationList(
+            declaration_list,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(
+                proto::declaration_object::DeclarationObject::DeclarationList(
+                    declaration_list_to_proto(declaration_list),
+                ),
+            ),
+        },
         c::language_object::declaration_object::DeclarationObject::FunctionDeclaration(
             function_declaration,
         ) => proto::DeclarationObject {
@@ -223,6 +488,65 @@ fn c_declaration_object_to_proto(
                 ),
             ),
         },
+        c::language_object::declaration_object::DeclarationObject::PreprocDefine(
+            preproc_define,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(proto::declaration_object::DeclarationObject::PreprocDefine(
+                preproc_define_to_proto(preproc_define),
+            )),
+        },
+        c::language_object::declaration_object::DeclarationObject::PreprocCall(preproc_call) => {
+            proto::DeclarationObject {
+                declaration_object: Some(
+                    proto::declaration_object::DeclarationObject::PreprocCall(
+                        preproc_call_to_proto(preproc_call),
+                    ),
+                ),
+            }
+        }
+        c::language_object::declaration_object::DeclarationObject::PreprocIf(preproc_if) => {
+            proto::DeclarationObject {
+                declaration_object: Some(proto::declaration_object::DeclarationObject::PreprocIf(
+                    preproc_if_to_proto(preproc_if),
+                )),
+            }
+        }
+        c::language_object::declaration_object::DeclarationObject::StructDefinition(
+            struct_definition,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(
+                proto::declaration_object::DeclarationObject::StructDefinition(
+                    struct_definition_to_proto(struct_definition),
+                ),
+            ),
+        },
+        c::language_object::declaration_object::DeclarationObject::UnionDefinition(
+            union_definition,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(
+                proto::declaration_object::DeclarationObject::UnionDefinition(
+                    union_definition_to_proto(union_definition),
+                ),
+            ),
+        },
+        c::language_object::declaration_object::DeclarationObject::EnumDefinition(
+            enum_definition,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(
+                proto::declaration_object::DeclarationObject::EnumDefinition(
+                    enum_definition_to_proto(enum_definition),
+                ),
+            ),
+        },
+        c::language_object::declaration_object::DeclarationObject::TypeDefinition(
+            type_definition,
+        ) => proto::DeclarationObject {
+            declaration_object: Some(
+                proto::declaration_object::DeclarationObject::TypeDefinition(
+                    type_definition_to_proto(type_definition),
+                ),
+            ),
+        },
         c::language_object::declaration_object::DeclarationObject::Comment(comment) => {
             proto::DeclarationObject {
                 declaration_object: Some(proto::declaration_object::DeclarationObject::Comment(
@@ -243,14 +567,18 @@ fn c_declaration_object_to_proto(
 fn assignment_expression_to_proto(
     assignment_expression: c::language_object::expression_object::assignment_expression::AssignmentExpression,
 ) -> proto::AssignmentExpression {
+    let left_proto = Some(Box::new(c_expression_object_to_proto(
+        *assignment_expression.left,
+    )));
     let value_proto = Some(Box::new(c_expression_object_to_proto(
         *assignment_expression.value,
     )));
 
     proto::AssignmentExpression {
         id: assignment_expression.id.to_string(),
-        id_declaration: assignment_expression.id_declaration.to_string(),
-        identifier: assignment_expression.identifier,
+        span: assignment_expression.span.map(span_to_proto),
+        comments: comments_to_proto(assignment_expression.comments),
+        left: left_proto,
         value: value_proto,
     }
 }
@@ -277,6 +605,86 @@ fn c_expression_object_to_proto(
                 )),
             ),
         },
+        c::language_object::expression_object::ExpressionObject::UnaryExpression(
+            unary_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::UnaryExpression(
+                Box::new(unary_expression_to_proto(unary_expression)),
+            )),
+        },
+        c::language_object::expression_object::ExpressionObject::UpdateExpression(
+            update_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::UpdateExpression(Box::new(
+                    update_expression_to_proto(update_expression),
+                )),
+            ),
+        },
+        c::language_object::expression_object::ExpressionObject::CastExpression(
+            cast_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::CastExpression(
+                Box::new(cast_expression_to_proto(cast_expression)),
+            )),
+        },
+        c::language_object::expression_object::ExpressionObject::SizeofExpression(
+            sizeof_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::SizeofExpression(Box::new(
+                    sizeof_expression_to_proto(sizeof_expression),
+                )),
+            ),
+        },
+        c::language_object::expression_object::ExpressionObject::ConditionalExpression(
+            conditional_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::ConditionalExpression(Box::new(
+                    conditional_expression_to_proto(conditional_expression),
+                )),
+            ),
+        },
+        c::language_object::expression_object::ExpressionObject::FieldExpression(
+            field_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::FieldExpression(
+                Box::new(field_expression_to_proto(field_expression)),
+            )),
+        },
+        c::language_object::expression_object::ExpressionObject::SubscriptExpression(
+            subscript_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::SubscriptExpression(Box::new(
+                    subscript_expression_to_proto(subscript_expression),
+                )),
+            ),
+        },
+        c::language_object::expression_object::ExpressionObject::ParenthesizedExpression(
+            parenthesized_expression,
+        ) => proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::ParenthesizedExpression(Box::new(
+                    parenthesized_expression_to_proto(parenthesized_expression),
+                )),
+            ),
+        },
+        c::language_object::expression_object::ExpressionObject::InitializerList(
+            initializer_list,
+        ) => proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::InitializerList(
+                Box::new(initializer_list_to_proto(initializer_list)),
+            )),
+        },
+        c::language_object::expression_object::ExpressionObject::InitializerPair(
+            initializer_pair,
+        ) => proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::InitializerPair(
+                Box::new(initializer_pair_to_proto(initializer_pair)),
+            )),
+        },
         c::language_object::expression_object::ExpressionObject::CallExpression(
             call_expression,
         ) => proto::ExpressionObject {
@@ -305,6 +713,13 @@ fn c_expression_object_to_proto(
                 )),
             }
         }
+        c::language_object::expression_object::ExpressionObject::CharLiteral(char_literal) => {
+            proto::ExpressionObject {
+                expression_object: Some(proto::expression_object::ExpressionObject::CharLiteral(
+                    char_literal_to_proto(char_literal),
+                )),
+            }
+        }
         c::language_object::expression_object::ExpressionObject::Unknown(unknown) => {
             proto::ExpressionObject {
                 expression_object: Some(proto::expression_object::ExpressionObject::Unknown(
@@ -328,12 +743,178 @@ fn binary_expression_to_proto(
 
     proto::BinaryExpression {
         id: binary_expression.id.to_string(),
+        span: binary_expression.span.map(span_to_proto),
+        comments: comments_to_proto(binary_expression.comments),
         left: left_proto,
         operator: binary_expression.operator,
         right: right_proto,
     }
 }
 
+fn unary_expression_to_proto(
+    unary_expression: c::language_object::expression_object::unary_expression::UnaryExpression,
+) -> proto::UnaryExpression {
+    proto::UnaryExpression {
+        id: unary_expression.id.to_string(),
+        span: unary_expression.span.map(span_to_proto),
+        comments: comments_to_proto(unary_expression.comments),
+        operator: unary_expression.operator,
+        argument: Some(Box::new(c_expression_object_to_proto(
+            *unary_expression.argument,
+        ))),
+    }
+}
+
+fn update_expression_to_proto(
+    update_expression: c::language_object::expression_object::update_expression::UpdateExpression,
+) -> proto::UpdateExpression {
+    proto::UpdateExpression {
+        id: update_expression.id.to_string(),
+        span: update_expression.span.map(span_to_proto),
+        comments: comments_to_proto(update_expression.comments),
+        operator: update_expression.operator,
+        is_prefix: update_expression.is_prefix,
+        argument: Some(Box::new(c_expression_object_to_proto(
+            *update_expression.argument,
+        ))),
+    }
+}
+
+fn cast_expression_to_proto(
+    cast_expression: c::language_object::expression_object::cast_expression::CastExpression,
+) -> proto::CastExpression {
+    proto::CastExpression {
+        id: cast_expression.id.to_string(),
+        span: cast_expression.span.map(span_to_proto),
+        comments: comments_to_proto(cast_expression.comments),
+        cast_type: Some(c_type_to_proto(cast_expression.cast_type)),
+        value: Some(Box::new(c_expression_object_to_proto(
+            *cast_expression.value,
+        ))),
+    }
+}
+
+fn sizeof_expression_to_proto(
+    sizeof_expression: c::language_object::expression_object::sizeof_expression::SizeofExpression,
+) -> proto::SizeofExpression {
+    proto::SizeofExpression {
+        id: sizeof_expression.id.to_string(),
+        span: sizeof_expression.span.map(span_to_proto),
+        comments: comments_to_proto(sizeof_expression.comments),
+        argument_type: sizeof_expression.argument_type.map(c_type_to_proto),
+        value: sizeof_expression
+            .value
+            .map(|value| Box::new(c_expression_object_to_proto(*value))),
+    }
+}
+
+fn conditional_expression_to_proto(
+    conditional_expression: c::language_object::expression_object::conditional_expression::ConditionalExpression,
+) -> proto::ConditionalExpression {
+    proto::ConditionalExpression {
+        id: conditional_expression.id.to_string(),
+        span: conditional_expression.span.map(span_to_proto),
+        comments: comments_to_proto(conditional_expression.comments),
+        condition: Some(Box::new(c_expression_object_to_proto(
+            *conditional_expression.condition,
+        ))),
+        consequence: Some(Box::new(c_expression_object_to_proto(
+            *conditional_expression.consequence,
+        ))),
+        alternative: Some(Box::new(c_expression_object_to_proto(
+            *conditional_expression.alternative,
+        ))),
+    }
+}
+
+fn field_expression_to_proto(
+    field_expression: c::language_object::expression_object::field_expression::FieldExpression,
+) -> proto::FieldExpression {
+    proto::FieldExpression {
+        id: field_expression.id.to_string(),
+        span: field_expression.span.map(span_to_proto),
+        comments: comments_to_proto(field_expression.comments),
+        argument: Some(Box::new(c_expression_object_to_proto(
+            *field_expression.argument,
+        ))),
+        operator: field_expression.operator,
+        declaration_id: field_expression.declaration_id.to_string(),
+        identifier: field_expression.identifier,
+    }
+}
+
+fn subscript_expression_to_proto(
+    subscript_expression: c::language_object::expression_object::subscript_expression::SubscriptExpression,
+) -> proto::SubscriptExpression {
+    proto::SubscriptExpression {
+        id: subscript_expression.id.to_string(),
+        span: subscript_expression.span.map(span_to_proto),
+        comments: comments_to_proto(subscript_expression.comments),
+        argument: Some(Box::new(c_expression_object_to_proto(
+            *subscript_expression.argument,
+        ))),
+        index: Some(Box::new(c_expression_object_to_proto(
+            *subscript_expression.index,
+        ))),
+    }
+}
+
+fn parenthesized_expression_to_proto(
+    parenthesized_expression: c::language_object::expression_object::parenthesized_expression::ParenthesizedExpression,
+) -> proto::ParenthesizedExpression {
+    proto::ParenthesizedExpression {
+        id: parenthesized_expression.id.to_string(),
+        span: parenthesized_expression.span.map(span_to_proto),
+        comments: comments_to_proto(parenthesized_expression.comments),
+        value: Some(Box::new(c_expression_object_to_proto(
+            *parenthesized_expression.value,
+        ))),
+    }
+}
+
+fn initializer_list_to_proto(
+    initializer_list: c::language_object::expression_object::initializer_list::InitializerList,
+) -> proto::InitializerList {
+    proto::InitializerList {
+        id: initializer_list.id.to_string(),
+        span: initializer_list.span.map(span_to_proto),
+        comments: comments_to_proto(initializer_list.comments),
+        elements: initializer_list
+            .elements
+            .into_iter()
+            .map(c_expression_object_to_proto)
+            .collect(),
+    }
+}
+
+fn initializer_pair_to_proto(
+    initializer_pair: c::language_object::expression_object::initializer_pair::InitializerPair,
+) -> proto::InitializerPair {
+    let designators = initializer_pair
+        .designators
+        .into_iter()
+        .map(|designator| proto::Designator {
+            kind: Some(match designator {
+                c::language_object::expression_object::initializer_pair::Designator::Field(
+                    field,
+                ) => proto::designator::Kind::Field(field),
+                c::language_object::expression_object::initializer_pair::Designator::Subscript(
+                    index,
+                ) => proto::designator::Kind::Index(Box::new(c_expression_object_to_proto(index))),
+            }),
+        })
+        .collect();
+    proto::InitializerPair {
+        id: initializer_pair.id.to_string(),
+        span: initializer_pair.span.map(span_to_proto),
+        comments: comments_to_proto(initializer_pair.comments),
+        designators,
+        value: Some(Box::new(c_expression_object_to_proto(
+            *initializer_pair.value,
+        ))),
+    }
+}
+
 fn call_expression_to_proto(
     call_expression: c::language_object::expression_object::call_expression::CallExpression,
 ) -> proto::CallExpression {
@@ -344,6 +925,8 @@ fn call_expression_to_proto(
 
     proto::CallExpression {
         id: call_expression.id.to_string(),
+        span: call_expression.span.map(span_to_proto),
+        comments: comments_to_proto(call_expression.comments),
         id_declaration: call_expression.id_declaration.to_string(),
         identifier: call_expression.identifier,
         argument_list,
@@ -355,6 +938,7 @@ fn comment_to_proto(
 ) -> proto::Comment {
     proto::Comment {
         id: comment.id.to_string(),
+        span: comment.span.map(span_to_proto),
         content: comment.content,
     }
 }
@@ -368,9 +952,27 @@ fn declaration_to_proto(
 
     proto::Declaration {
         id: declaration.id.to_string(),
-        primitive_type: declaration.primitive_type.as_str().to_string(),
+        span: declaration.span.map(span_to_proto),
+        comments: comments_to_proto(declaration.comments),
+        primitive_type: Some(c_type_to_proto(declaration.primitive_type)),
         identifier: declaration.identifier,
         value,
+        specifiers: specifiers_to_proto(declaration.specifiers),
+    }
+}
+
+fn declaration_list_to_proto(
+    declaration_list: c::language_object::declaration_object::declaration_list::DeclarationList,
+) -> proto::DeclarationList {
+    proto::DeclarationList {
+        id: declaration_list.id.to_string(),
+        span: declaration_list.span.map(span_to_proto),
+        comments: comments_to_proto(declaration_list.comments),
+        declarations: declaration_list
+            .declarations
+            .into_iter()
+            .map(declaration_to_proto)
+            .collect(),
     }
 }
 
@@ -381,6 +983,8 @@ fn else_clause_to_proto(
 
     proto::ElseClause {
         id: else_clause.id.to_string(),
+        span: else_clause.span.map(span_to_proto),
+        comments: comments_to_proto(else_clause.comments),
         body: Some(Box::new(compound_statement)),
     }
 }
@@ -395,9 +999,14 @@ fn function_declaration_to_proto(
 
     proto::FunctionDeclaration {
         id: function_declaration.id.to_string(),
-        return_type: function_declaration.return_type.as_str().to_string(),
+        span: function_declaration.span.map(span_to_proto),
+        comments: comments_to_proto(function_declaration.comments),
+        return_type: Some(c_type_to_proto(function_declaration.return_type)),
         identifier: function_declaration.identifier,
         parameter_list,
+        specifiers: specifiers_to_proto(function_declaration.specifiers),
+        is_variadic: function_declaration.is_variadic,
+        explicit_void: function_declaration.explicit_void,
     }
 }
 
@@ -413,20 +1022,35 @@ fn function_definition_to_proto(
 
     proto::FunctionDefinition {
         id: function_definition.id.to_string(),
-        return_type: function_definition.return_type.as_str().to_string(),
+        span: function_definition.span.map(span_to_proto),
+        comments: comments_to_proto(function_definition.comments),
+        return_type: Some(c_type_to_proto(function_definition.return_type)),
         identifier: function_definition.identifier,
         parameter_list,
         compound_statement: Some(compound_statement),
+        specifiers: specifiers_to_proto(function_definition.specifiers),
+        is_variadic: function_definition.is_variadic,
+        explicit_void: function_definition.explicit_void,
     }
 }
 
+fn specifiers_to_proto(specifiers: c::specifiers::Specifiers) -> Vec<String> {
+    specifiers
+        .keywords()
+        .into_iter()
+        .map(|keyword| keyword.to_string())
+        .collect()
+}
+
 fn function_parameter_to_proto(
     function_parameter: c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter,
 ) -> proto::FunctionParameter {
     proto::FunctionParameter {
         id: function_parameter.id.to_string(),
+        span: function_parameter.span.map(span_to_proto),
+        comments: comments_to_proto(function_parameter.comments),
         identifier: function_parameter.identifier,
-        param_type: function_parameter.param_type.as_str().to_string(),
+        param_type: Some(c_type_to_proto(function_parameter.param_type)),
     }
 }
 
@@ -452,6 +1076,8 @@ fn if_statement_to_proto(
 
     proto::IfStatement {
         id: if_statement.id.to_string(),
+        span: if_statement.span.map(span_to_proto),
+        comments: comments_to_proto(if_statement.comments),
         condition: Some(condition),
         body: Some(Box::new(body)),
         else_statement,
@@ -463,7 +1089,90 @@ fn number_literal_to_proto(
 ) -> proto::NumberLiteral {
     proto::NumberLiteral {
         id: number_literal.id.to_string(),
-        value: number_literal.value,
+        span: number_literal.span.map(span_to_proto),
+        comments: comments_to_proto(number_literal.comments),
+        value: number_literal.spelling,
+        // Integers too large for the message are sent by their spelling only
+        number: match number_literal.value {
+            c::language_object::expression_object::number_literal::NumberValue::Integer(value) => {
+                u64::try_from(value)
+                    .ok()
+                    .map(proto::number_literal::Number::Integer)
+            }
+            c::language_object::expression_object::number_literal::NumberValue::Float(value) => {
+                Some(proto::number_literal::Number::Float(value))
+            }
+        },
+        radix: number_literal.radix.base(),
+        suffix: number_literal.suffix,
+    }
+}
+
+fn preproc_call_to_proto(
+    preproc_call: c::language_object::declaration_object::preproc_call::PreprocCall,
+) -> proto::PreprocCall {
+    proto::PreprocCall {
+        id: preproc_call.id.to_string(),
+        span: preproc_call.span.map(span_to_proto),
+        comments: comments_to_proto(preproc_call.comments),
+        directive: preproc_call.directive,
+        argument: preproc_call.argument,
+    }
+}
+
+fn preproc_define_to_proto(
+    preproc_define: c::language_object::declaration_object::preproc_define::PreprocDefine,
+) -> proto::PreprocDefine {
+    proto::PreprocDefine {
+        id: preproc_define.id.to_string(),
+        span: preproc_define.span.map(span_to_proto),
+        comments: comments_to_proto(preproc_define.comments),
+        identifier: preproc_define.identifier,
+        function_like: preproc_define.parameters.is_some(),
+        parameters: preproc_define.parameters.unwrap_or_default(),
+        value: preproc_define.value,
+    }
+}
+
+fn preproc_if_to_proto(
+    preproc_if: c::language_object::declaration_object::preproc_if::PreprocIf,
+) -> proto::PreprocIf {
+    let mut body: Vec<proto::DeclarationObject> = Vec::new();
+    for object in preproc_if.body {
+        body.push(c_declaration_object_to_proto(object));
+    }
+
+    proto::PreprocIf {
+        id: preproc_if.id.to_string(),
+        span: preproc_if.span.map(span_to_proto),
+        comments: comments_to_proto(preproc_if.comments),
+        directive: preproc_if.directive,
+        condition: preproc_if.condition,
+        body,
+        alternative: preproc_if
+            .alternative
+            .map(|alternative| Box::new(preproc_if_to_proto(*alternative))),
+    }
+}
+
+fn preproc_if_statement_to_proto(
+    preproc_if: c::language_object::statement_object::preproc_if_statement::PreprocIfStatement,
+) -> proto::PreprocIfStatement {
+    let mut body: Vec<proto::CompoundStatementObject> = Vec::new();
+    for statement in preproc_if.body {
+        body.push(c_compound_statement_object_to_proto(statement));
+    }
+
+    proto::PreprocIfStatement {
+        id: preproc_if.id.to_string(),
+        span: preproc_if.span.map(span_to_proto),
+        comments: comments_to_proto(preproc_if.comments),
+        directive: preproc_if.directive,
+        condition: preproc_if.condition,
+        body,
+        alternative: preproc_if
+            .alternative
+            .map(|alternative| Box::new(preproc_if_statement_to_proto(*alternative))),
     }
 }
 
@@ -472,6 +1181,8 @@ fn preproc_include_to_proto(
 ) -> proto::PreprocInclude {
     proto::PreprocInclude {
         id: preproc_include.id.to_string(),
+        span: preproc_include.span.map(span_to_proto),
+        comments: comments_to_proto(preproc_include.comments),
         content: preproc_include.content,
     }
 }
@@ -481,6 +1192,8 @@ fn reference_to_proto(
 ) -> proto::Reference {
     proto::Reference {
         id: reference.id.to_string(),
+        span: reference.span.map(span_to_proto),
+        comments: comments_to_proto(reference.comments),
         declaration_id: reference.declaration_id.to_string(),
         identifier: reference.identifier,
     }
@@ -493,6 +1206,8 @@ fn return_statement_to_proto(
 
     proto::ReturnStatement {
         id: return_statement.id.to_string(),
+        span: return_statement.span.map(span_to_proto),
+        comments: comments_to_proto(return_statement.comments),
         value,
     }
 }
@@ -502,7 +1217,24 @@ fn string_literal_to_proto(
 ) -> proto::StringLiteral {
     proto::StringLiteral {
         id: string_literal.id.to_string(),
+        span: string_literal.span.map(span_to_proto),
+        comments: comments_to_proto(string_literal.comments),
         value: string_literal.value,
+        prefix: string_literal.prefix.to_string(),
+        segments: string_literal.segments,
+    }
+}
+
+fn char_literal_to_proto(
+    char_literal: c::language_object::expression_object::char_literal::CharLiteral,
+) -> proto::CharLiteral {
+    proto::CharLiteral {
+        id: char_literal.id.to_string(),
+        span: char_literal.span.map(span_to_proto),
+        comments: comments_to_proto(char_literal.comments),
+        value: char_literal.value,
+        prefix: char_literal.prefix.to_string(),
+        spelling: char_literal.spelling,
     }
 }
 
@@ -516,6 +1248,8 @@ fn compound_statement_to_proto(
 
     proto::CompoundStatement {
         id: compound_statement.id.to_string(),
+        span: compound_statement.span.map(span_to_proto),
+        comments: comments_to_proto(compound_statement.comments),
         code_block,
     }
 }
@@ -531,6 +1265,13 @@ fn c_compound_statement_object_to_proto(
                             )),
                         }
             }
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::DeclarationList(declaration_list) => {
+                        proto::CompoundStatementObject {
+                            compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::DeclarationList(
+                                declaration_list_to_proto(declaration_list),
+                            )),
+                        }
+            }
         c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::IfStatement(if_statement) => {
                 let if_statement_msg = if_statement_to_proto(if_statement);
                 proto::CompoundStatementObject {
@@ -571,6 +1312,94 @@ fn c_compound_statement_object_to_proto(
                 )),
             }
         },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UnaryExpression(unary_expression) => {
+            let unary_expression_msg = unary_expression_to_proto(unary_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::UnaryExpression(
+                    unary_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UpdateExpression(update_expression) => {
+            let update_expression_msg = update_expression_to_proto(update_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::UpdateExpression(
+                    update_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CastExpression(cast_expression) => {
+            let cast_expression_msg = cast_expression_to_proto(cast_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::CastExpression(
+                    cast_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SizeofExpression(sizeof_expression) => {
+            let sizeof_expression_msg = sizeof_expression_to_proto(sizeof_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::SizeofExpression(
+                    sizeof_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ConditionalExpression(conditional_expression) => {
+            let conditional_expression_msg = conditional_expression_to_proto(conditional_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::ConditionalExpression(
+                    conditional_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::FieldExpression(field_expression) => {
+            let field_expression_msg = field_expression_to_proto(field_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::FieldExpression(
+                    field_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SubscriptExpression(subscript_expression) => {
+            let subscript_expression_msg = subscript_expression_to_proto(subscript_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::SubscriptExpression(
+                    subscript_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ParenthesizedExpression(parenthesized_expression) => {
+            let parenthesized_expression_msg = parenthesized_expression_to_proto(parenthesized_expression);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+                    parenthesized_expression_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocDefine(preproc_define) => {
+            let preproc_define_msg = preproc_define_to_proto(preproc_define);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::PreprocDefine(
+                    preproc_define_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocCall(preproc_call) => {
+            let preproc_call_msg = preproc_call_to_proto(preproc_call);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::PreprocCall(
+                    preproc_call_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocIfStatement(preproc_if_statement) => {
+            let preproc_if_statement_msg = preproc_if_statement_to_proto(preproc_if_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::PreprocIfStatement(
+                    preproc_if_statement_msg,
+                )),
+            }
+        },
         c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CallExpression(call_expression) => {
             let call_expression_msg = call_expression_to_proto(call_expression);
             proto::CompoundStatementObject {
@@ -603,6 +1432,14 @@ fn c_compound_statement_object_to_proto(
                 )),
             }
         },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CharLiteral(char_literal) => {
+            let char_literal_msg = char_literal_to_proto(char_literal);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::CharLiteral(
+                    char_literal_msg,
+                )),
+            }
+        },
         c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CompoundStatement(compound_statement) => {
             let compound_statement_msg = compound_statement_to_proto(compound_statement);
             proto::CompoundStatementObject {
@@ -619,18 +1456,467 @@ fn c_compound_statement_object_to_proto(
                 )),
             }
         },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::WhileStatement(while_statement) => {
+            let while_statement_msg = while_statement_to_proto(while_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::WhileStatement(
+                    Box::new(while_statement_msg),
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::DoStatement(do_statement) => {
+            let do_statement_msg = do_statement_to_proto(do_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::DoStatement(
+                    Box::new(do_statement_msg),
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ForStatement(for_statement) => {
+            let for_statement_msg = for_statement_to_proto(for_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::ForStatement(
+                    Box::new(for_statement_msg),
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SwitchStatement(switch_statement) => {
+            let switch_statement_msg = switch_statement_to_proto(switch_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::SwitchStatement(
+                    Box::new(switch_statement_msg),
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CaseStatement(case_statement) => {
+            let case_statement_msg = case_statement_to_proto(case_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::CaseStatement(
+                    case_statement_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::BreakStatement(break_statement) => {
+            let break_statement_msg = break_statement_to_proto(break_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::BreakStatement(
+                    break_statement_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ContinueStatement(continue_statement) => {
+            let continue_statement_msg = continue_statement_to_proto(continue_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::ContinueStatement(
+                    continue_statement_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::GotoStatement(goto_statement) => {
+            let goto_statement_msg = goto_statement_to_proto(goto_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::GotoStatement(
+                    goto_statement_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::LabeledStatement(labeled_statement) => {
+            let labeled_statement_msg = labeled_statement_to_proto(labeled_statement);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::LabeledStatement(
+                    Box::new(labeled_statement_msg),
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::StructDefinition(struct_definition) => {
+            let struct_definition_msg = struct_definition_to_proto(struct_definition);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::StructDefinition(
+                    struct_definition_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UnionDefinition(union_definition) => {
+            let union_definition_msg = union_definition_to_proto(union_definition);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::UnionDefinition(
+                    union_definition_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::EnumDefinition(enum_definition) => {
+            let enum_definition_msg = enum_definition_to_proto(enum_definition);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::EnumDefinition(
+                    enum_definition_msg,
+                )),
+            }
+        },
+        c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::TypeDefinition(type_definition) => {
+            let type_definition_msg = type_definition_to_proto(type_definition);
+            proto::CompoundStatementObject {
+                compound_statement_object: Some(proto::compound_statement_object::CompoundStatementObject::TypeDefinition(
+                    type_definition_msg,
+                )),
+            }
+        },
+    }
+}
+
+fn while_statement_to_proto(
+    while_statement: c::language_object::statement_object::while_statement::WhileStatement,
+) -> proto::WhileStatement {
+    let condition = c_expression_object_to_proto(*while_statement.condition);
+    let body = c_compound_statement_object_to_proto(*while_statement.body);
+
+    proto::WhileStatement {
+        id: while_statement.id.to_string(),
+        span: while_statement.span.map(span_to_proto),
+        comments: comments_to_proto(while_statement.comments),
+        condition: Some(condition),
+        body: Some(Box::new(body)),
+    }
+}
+
+fn do_statement_to_proto(
+    do_statement: c::language_object::statement_object::do_statement::DoStatement,
+) -> proto::DoStatement {
+    let body = c_compound_statement_object_to_proto(*do_statement.body);
+    let condition = c_expression_object_to_proto(*do_statement.condition);
+
+    proto::DoStatement {
+        id: do_statement.id.to_string(),
+        span: do_statement.span.map(span_to_proto),
+        comments: comments_to_proto(do_statement.comments),
+        body: Some(Box::new(body)),
+        condition: Some(condition),
     }
 }
 
+fn for_statement_to_proto(
+    for_statement: c::language_object::statement_object::for_statement::ForStatement,
+) -> proto::ForStatement {
+    let initializer = for_statement
+        .initializer
+        .map(|initializer| Box::new(c_compound_statement_object_to_proto(*initializer)));
+    let condition = for_statement
+        .condition
+        .map(|condition| c_expression_object_to_proto(*condition));
+    let update = for_statement
+        .update
+        .map(|update| c_expression_object_to_proto(*update));
+    let body = c_compound_statement_object_to_proto(*for_statement.body);
+
+    proto::ForStatement {
+        id: for_statement.id.to_string(),
+        span: for_statement.span.map(span_to_proto),
+        comments: comments_to_proto(for_statement.comments),
+        initializer,
+        condition,
+        update,
+        body: Some(Box::new(body)),
+    }
+}
+
+fn switch_statement_to_proto(
+    switch_statement: c::language_object::statement_object::switch_statement::SwitchStatement,
+) -> proto::SwitchStatement {
+    let condition = c_expression_object_to_proto(*switch_statement.condition);
+    let body = c_compound_statement_object_to_proto(*switch_statement.body);
+
+    proto::SwitchStatement {
+        id: switch_statement.id.to_string(),
+        span: switch_statement.span.map(span_to_proto),
+        comments: comments_to_proto(switch_statement.comments),
+        condition: Some(condition),
+        body: Some(Box::new(body)),
+    }
+}
+
+fn case_statement_to_proto(
+    case_statement: c::language_object::statement_object::case_statement::CaseStatement,
+) -> proto::CaseStatement {
+    let value = case_statement
+        .value
+        .map(|value| c_expression_object_to_proto(*value));
+    let body = case_statement
+        .body
+        .into_iter()
+        .map(c_compound_statement_object_to_proto)
+        .collect();
+
+    proto::CaseStatement {
+        id: case_statement.id.to_string(),
+        span: case_statement.span.map(span_to_proto),
+        comments: comments_to_proto(case_statement.comments),
+        value,
+        body,
+    }
+}
+
+fn break_statement_to_proto(
+    break_statement: c::language_object::statement_object::break_statement::BreakStatement,
+) -> proto::BreakStatement {
+    proto::BreakStatement {
+        id: break_statement.id.to_string(),
+        span: break_statement.span.map(span_to_proto),
+        comments: comments_to_proto(break_statement.comments),
+    }
+}
+
+fn labeled_statement_to_proto(
+    labeled_statement: c::language_object::statement_object::labeled_statement::LabeledStatement,
+) -> proto::LabeledStatement {
+    let statement = labeled_statement
+        .statement
+        .map(|statement| Box::new(c_compound_statement_object_to_proto(*statement)));
+
+    proto::LabeledStatement {
+        id: labeled_statement.id.to_string(),
+        span: labeled_statement.span.map(span_to_proto),
+        comments: comments_to_proto(labeled_statement.comments),
+        label: labeled_statement.label,
+        statement,
+    }
+}
+
+fn goto_statement_to_proto(
+    goto_statement: c::language_object::statement_object::goto_statement::GotoStatement,
+) -> proto::GotoStatement {
+    proto::GotoStatement {
+        id: goto_statement.id.to_string(),
+        span: goto_statement.span.map(span_to_proto),
+        comments: comments_to_proto(goto_statement.comments),
+        label_id: goto_statement.label_id.to_string(),
+        label: goto_statement.label,
+    }
+}
+
+fn continue_statement_to_proto(
+    continue_statement: c::language_object::statement_object::continue_statement::ContinueStatement,
+) -> proto::ContinueStatement {
+    proto::ContinueStatement {
+        id: continue_statement.id.to_string(),
+        span: continue_statement.span.map(span_to_proto),
+        comments: comments_to_proto(continue_statement.comments),
+    }
+}
+
+fn struct_definition_to_proto(
+    struct_definition: c::language_object::declaration_object::struct_definition::StructDefinition,
+) -> proto::StructDefinition {
+    proto::StructDefinition {
+        id: struct_definition.id.to_string(),
+        span: struct_definition.span.map(span_to_proto),
+        comments: comments_to_proto(struct_definition.comments),
+        identifier: struct_definition.identifier,
+        fields: struct_definition
+            .fields
+            .into_iter()
+            .map(field_declaration_to_proto)
+            .collect(),
+        is_forward: struct_definition.is_forward,
+    }
+}
+
+fn union_definition_to_proto(
+    union_definition: c::language_object::declaration_object::union_definition::UnionDefinition,
+) -> proto::UnionDefinition {
+    proto::UnionDefinition {
+        id: union_definition.id.to_string(),
+        span: union_definition.span.map(span_to_proto),
+        comments: comments_to_proto(union_definition.comments),
+        identifier: union_definition.identifier,
+        fields: union_definition
+            .fields
+            .into_iter()
+            .map(field_declaration_to_proto)
+            .collect(),
+        is_forward: union_definition.is_forward,
+    }
+}
+
+fn field_declaration_to_proto(
+    field_declaration: c::language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration,
+) -> proto::FieldDeclaration {
+    proto::FieldDeclaration {
+        id: field_declaration.id.to_string(),
+        span: field_declaration.span.map(span_to_proto),
+        comments: comments_to_proto(field_declaration.comments),
+        identifier: field_declaration.identifier,
+        field_type: Some(c_type_to_proto(field_declaration.field_type)),
+        shares_declaration: field_declaration.shares_declaration,
+    }
+}
+
+fn enum_definition_to_proto(
+    enum_definition: c::language_object::declaration_object::enum_definition::EnumDefinition,
+) -> proto::EnumDefinition {
+    proto::EnumDefinition {
+        id: enum_definition.id.to_string(),
+        span: enum_definition.span.map(span_to_proto),
+        comments: comments_to_proto(enum_definition.comments),
+        identifier: enum_definition.identifier,
+        enumerators: enum_definition
+            .enumerators
+            .into_iter()
+            .map(enumerator_to_proto)
+            .collect(),
+        is_forward: enum_definition.is_forward,
+    }
+}
+
+fn type_definition_to_proto(
+    type_definition: c::language_object::declaration_object::type_definition::TypeDefinition,
+) -> proto::TypeDefinition {
+    proto::TypeDefinition {
+        id: type_definition.id.to_string(),
+        span: type_definition.span.map(span_to_proto),
+        comments: comments_to_proto(type_definition.comments),
+        identifier: type_definition.identifier,
+        aliased_type: Some(c_type_to_proto(type_definition.aliased_type)),
+    }
+}
+
+fn enumerator_to_proto(
+    enumerator: c::language_object::declaration_object::enum_definition::enumerator::Enumerator,
+) -> proto::Enumerator {
+    proto::Enumerator {
+        id: enumerator.id.to_string(),
+        span: enumerator.span.map(span_to_proto),
+        comments: comments_to_proto(enumerator.comments),
+        identifier: enumerator.identifier,
+        value: enumerator
+            .value
+            .map(|value| c_expression_object_to_proto(*value)),
+    }
+}
+
+fn c_type_to_proto(c_type: c::c_type::CType) -> proto::CType {
+    let type_reference_to_proto = |reference: c::c_type::TypeReference| proto::TypeReference {
+        declaration_id: reference.declaration_id.to_string(),
+        identifier: reference.identifier,
+    };
+    let kind = match c_type {
+        c::c_type::CType::Struct(reference) => {
+            proto::c_type::Kind::StructType(type_reference_to_proto(reference))
+        }
+        c::c_type::CType::Union(reference) => {
+            proto::c_type::Kind::UnionType(type_reference_to_proto(reference))
+        }
+        c::c_type::CType::Enum(reference) => {
+            proto::c_type::Kind::EnumType(type_reference_to_proto(reference))
+        }
+        c::c_type::CType::Typedef(name) => proto::c_type::Kind::TypedefName(name),
+        c::c_type::CType::Named {
+            declaration_id,
+            name,
+        } => proto::c_type::Kind::Named(proto::TypeReference {
+            declaration_id: declaration_id.to_string(),
+            identifier: name,
+        }),
+        c::c_type::CType::Pointer(pointee) => {
+            proto::c_type::Kind::Pointer(Box::new(c_type_to_proto(*pointee)))
+        }
+        c::c_type::CType::Array(array) => proto::c_type::Kind::Array(Box::new(proto::ArrayType {
+            element: Some(Box::new(c_type_to_proto(*array.element))),
+            size: array.size,
+            is_static: array.is_static,
+            is_const: array.qualifiers.is_const,
+            is_volatile: array.qualifiers.is_volatile,
+            is_restrict: array.qualifiers.is_restrict,
+        })),
+        c::c_type::CType::Qualified(qualifiers, base) => {
+            proto::c_type::Kind::Qualified(Box::new(proto::QualifiedType {
+                is_const: qualifiers.is_const,
+                is_volatile: qualifiers.is_volatile,
+                is_restrict: qualifiers.is_restrict,
+                base: Some(Box::new(c_type_to_proto(*base))),
+            }))
+        }
+        c::c_type::CType::Fn(fn_type) => {
+            let parameter_names = if fn_type
+                .parameters
+                .iter()
+                .any(|parameter| parameter.identifier.is_some())
+            {
+                fn_type
+                    .parameters
+                    .iter()
+                    .map(|parameter| parameter.identifier.clone().unwrap_or_default())
+                    .collect()
+            } else {
+                vec![]
+            };
+            proto::c_type::Kind::Function(Box::new(proto::FnType {
+                return_type: Some(Box::new(c_type_to_proto(*fn_type.return_type))),
+                parameters: fn_type
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| c_type_to_proto(parameter.param_type))
+                    .collect(),
+                parameter_names,
+                is_variadic: fn_type.is_variadic,
+                explicit_void: fn_type.explicit_void,
+            }))
+        }
+        primitive => proto::c_type::Kind::Primitive(primitive.to_string()),
+    };
+    proto::CType { kind: Some(kind) }
+}
+
 fn unknown_to_proto(
     unknown: c::language_object::special_object::unknown::Unknown,
 ) -> proto::Unknown {
     proto::Unknown {
         id: unknown.id.to_string(),
+        span: unknown.span.map(span_to_proto),
+        comments: comments_to_proto(unknown.comments),
         content: unknown.content,
     }
 }
 
+/// Objects without attached comments leave the field unset
+fn comments_to_proto(
+    comments: c::language_object::special_object::comment::Comments,
+) -> Option<proto::Comments> {
+    if comments.is_empty() {
+        return None;
+    }
+    Some(proto::Comments {
+        leading: comments.leading.into_iter().map(comment_to_proto).collect(),
+        trailing: comments
+            .trailing
+            .into_iter()
+            .map(comment_to_proto)
+            .collect(),
+    })
+}
+
+fn span_to_proto(span: Span) -> proto::Span {
+    proto::Span {
+        start_line: span.start.line as u32,
+        start_column: span.start.column as u32,
+        end_line: span.end.line as u32,
+        end_column: span.end.column as u32,
+        start_offset: span.start.offset as u64,
+        end_offset: span.end.offset as u64,
+    }
+}
+
+pub fn diagnostic_to_proto(diagnostic: Diagnostic) -> proto::Diagnostic {
+    let severity = match diagnostic.severity {
+        Severity::Error => proto::Severity::Error,
+        Severity::Warning => proto::Severity::Warning,
+    };
+    proto::Diagnostic {
+        severity: severity as i32,
+        code: diagnostic.code.to_string(),
+        message: diagnostic.message,
+        span: Some(span_to_proto(diagnostic.span)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -639,7 +1925,12 @@ mod tests {
     #[test]
     fn test_01_source_file_with_empty_code() {
         let id = Uuid::new_v4();
-        let src = c::language_object::special_object::source_file::SourceFile { id, code: vec![] };
+        let src = c::language_object::special_object::source_file::SourceFile {
+            id,
+            span: None,
+            code: vec![],
+            trivia: None,
+        };
         let proto_src = source_file_to_proto(src);
 
         assert_eq!(proto_src.id, id.to_string());
@@ -652,13 +1943,16 @@ mod tests {
         let content = "this is a comment";
         let comment = c::language_object::special_object::comment::Comment {
             id: comment_id,
+            span: None,
             content: content.to_string(),
         };
 
         let id = Uuid::new_v4();
         let src = c::language_object::special_object::source_file::SourceFile {
             id,
+            span: None,
             code: vec![c::language_object::declaration_object::DeclarationObject::Comment(comment)],
+            trivia: None,
         };
         let proto_src = source_file_to_proto(src);
 
@@ -683,21 +1977,33 @@ mod tests {
         let assignment =
             c::language_object::expression_object::assignment_expression::AssignmentExpression {
                 id,
-                id_declaration,
-                identifier: identifier.to_string(),
+                span: None, comments: c::language_object::special_object::comment::Comments::default(),
+                left: Box::new(c::language_object::expression_object::ExpressionObject::Reference(
+                    c::language_object::expression_object::reference::Reference {
+                        declaration_id: id_declaration,
+                        identifier: identifier.to_string(),
+                        ..Default::default()
+                    },
+                )),
                 value: Box::new(
                     c::language_object::expression_object::ExpressionObject::NumberLiteral(
-                        c::language_object::expression_object::number_literal::NumberLiteral {
-                            id: number_id,
-                            value: value.to_string(),
-                        },
+                        c::language_object::expression_object::number_literal::NumberLiteral { id: number_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(value.to_string()).unwrap() },
                     ),
                 ),
             };
         let proto_assignment = assignment_expression_to_proto(assignment);
 
         assert_eq!(proto_assignment.id, id.to_string());
-        assert_eq!(proto_assignment.identifier, identifier);
+        match proto_assignment.left.as_deref() {
+            Some(proto::ExpressionObject {
+                expression_object:
+                    Some(proto::expression_object::ExpressionObject::Reference(reference)),
+            }) => {
+                assert_eq!(reference.declaration_id, id_declaration.to_string());
+                assert_eq!(reference.identifier, identifier);
+            }
+            _ => panic!("expected Reference"),
+        }
 
         if let Some(inner) = &proto_assignment.value {
             match &inner.expression_object {
@@ -724,21 +2030,16 @@ mod tests {
         let operator = "+";
         let bin = c::language_object::expression_object::binary_expression::BinaryExpression {
             id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             left: Box::new(
                 c::language_object::expression_object::ExpressionObject::NumberLiteral(
-                    c::language_object::expression_object::number_literal::NumberLiteral {
-                        id: left_id,
-                        value: left_value.to_string(),
-                    },
+                    c::language_object::expression_object::number_literal::NumberLiteral { id: left_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(left_value.to_string()).unwrap() },
                 ),
             ),
             operator: operator.to_string(),
             right: Box::new(
                 c::language_object::expression_object::ExpressionObject::NumberLiteral(
-                    c::language_object::expression_object::number_literal::NumberLiteral {
-                        id: right_id,
-                        value: right_value.to_string(),
-                    },
+                    c::language_object::expression_object::number_literal::NumberLiteral { id: right_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(right_value.to_string()).unwrap() },
                 ),
             ),
         };
@@ -780,13 +2081,12 @@ mod tests {
         let param_id = Uuid::new_v4();
         let param_value = "42".to_string();
         let param = c::language_object::expression_object::ExpressionObject::NumberLiteral(
-            c::language_object::expression_object::number_literal::NumberLiteral {
-                id: param_id,
-                value: param_value.clone(),
-            },
+            c::language_object::expression_object::number_literal::NumberLiteral { id: param_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(param_value.clone()).unwrap() },
         );
         let call = c::language_object::expression_object::call_expression::CallExpression {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
             id_declaration: decl_id,
             identifier: call_identifier.to_string(),
             argument_list: vec![param],
@@ -812,6 +2112,7 @@ mod tests {
         let content = "test";
         let comment = c::language_object::special_object::comment::Comment {
             id,
+            span: None,
             content: content.to_string(),
         };
         let proto_comment = comment_to_proto(comment);
@@ -826,7 +2127,10 @@ mod tests {
         let number_value = "42";
         let number_literal = c::language_object::expression_object::number_literal::NumberLiteral {
             id: number_id,
-            value: number_value.to_string(),
+            ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(
+                number_value.to_string(),
+            )
+            .unwrap()
         };
 
         let id = Uuid::new_v4();
@@ -834,6 +2138,9 @@ mod tests {
         let identifier = "test";
         let declaration = c::language_object::declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: identifier.to_string(),
             value: Some(Box::new(
@@ -845,7 +2152,12 @@ mod tests {
         let proto_declaration = declaration_to_proto(declaration);
 
         assert_eq!(proto_declaration.id, id.to_string());
-        assert_eq!(proto_declaration.primitive_type, primitive_type.as_str());
+        assert_eq!(
+            proto_declaration.primitive_type,
+            Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(primitive_type.to_string()))
+            })
+        );
         assert_eq!(proto_declaration.identifier, identifier);
 
         if let Some(inner) = &proto_declaration.value {
@@ -867,6 +2179,8 @@ mod tests {
         let compound_statement =
             c::language_object::statement_object::compound_statement::CompoundStatement {
                 id: comp_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
                 code_block: vec![],
             };
 
@@ -874,6 +2188,7 @@ mod tests {
         let else_clause =
             c::language_object::statement_object::if_statement::else_clause::ElseClause {
                 id,
+                span: None, comments: c::language_object::special_object::comment::Comments::default(),
                 body: Box::new(
                     c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CompoundStatement(
                         compound_statement,
@@ -899,6 +2214,7 @@ mod tests {
         let param_id = Uuid::new_v4();
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: "p".into(),
             param_type: c::c_type::CType::Float,
         };
@@ -909,21 +2225,38 @@ mod tests {
         let decl =
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: return_type.clone(),
                 identifier: identifier.to_string(),
                 parameter_list: vec![param],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let proto_decl = function_declaration_to_proto(decl);
         assert_eq!(proto_decl.id, id.to_string());
-        assert_eq!(proto_decl.return_type, return_type.as_str());
+        assert_eq!(
+            proto_decl.return_type,
+            Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(return_type.to_string()))
+            })
+        );
         assert_eq!(proto_decl.identifier, identifier);
         assert_eq!(proto_decl.parameter_list.len(), 1);
 
         let p = &proto_decl.parameter_list[0];
         assert_eq!(p.id, param_id.to_string());
         assert_eq!(p.identifier, "p");
-        assert_eq!(p.param_type, c::c_type::CType::Float.as_str());
+        assert_eq!(
+            p.param_type,
+            Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(
+                    c::c_type::CType::Float.to_string()
+                ))
+            })
+        );
     }
 
     #[test]
@@ -931,6 +2264,7 @@ mod tests {
         let param_id = Uuid::new_v4();
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: "p".into(),
             param_type: c::c_type::CType::Float,
         };
@@ -939,6 +2273,8 @@ mod tests {
         let compound_statement =
             c::language_object::statement_object::compound_statement::CompoundStatement {
                 id: comp_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
                 code_block: vec![],
             };
 
@@ -947,15 +2283,25 @@ mod tests {
         let identifier = "my_def";
         let def = c::language_object::declaration_object::function_definition::FunctionDefinition {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier.to_string(),
             parameter_list: vec![param],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement,
         };
 
         let proto_def = function_definition_to_proto(def);
         assert_eq!(proto_def.id, id.to_string());
-        assert_eq!(proto_def.return_type, return_type.as_str());
+        assert_eq!(
+            proto_def.return_type,
+            Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(return_type.to_string()))
+            })
+        );
         assert_eq!(proto_def.identifier, identifier);
         assert_eq!(proto_def.parameter_list.len(), 1);
 
@@ -976,6 +2322,7 @@ mod tests {
         let param_type = c::c_type::CType::Char;
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: identifier.to_string(),
             param_type: param_type.clone(),
         };
@@ -983,7 +2330,12 @@ mod tests {
         let proto_param = function_parameter_to_proto(param);
         assert_eq!(proto_param.id, id.to_string());
         assert_eq!(proto_param.identifier, identifier);
-        assert_eq!(proto_param.param_type, param_type.as_str());
+        assert_eq!(
+            proto_param.param_type,
+            Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(param_type.to_string()))
+            })
+        );
     }
 
     #[test]
@@ -991,10 +2343,7 @@ mod tests {
         let cond_id = Uuid::new_v4();
         let cond_value = "1";
         let cond = c::language_object::expression_object::ExpressionObject::NumberLiteral(
-            c::language_object::expression_object::number_literal::NumberLiteral {
-                id: cond_id,
-                value: cond_value.to_string(),
-            },
+            c::language_object::expression_object::number_literal::NumberLiteral { id: cond_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(cond_value.to_string()).unwrap() },
         );
 
         let comp_id = Uuid::new_v4();
@@ -1003,11 +2352,14 @@ mod tests {
             c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CompoundStatement(
                 c::language_object::statement_object::compound_statement::CompoundStatement {
                     id: comp_id,
+                    span: None, comments: c::language_object::special_object::comment::Comments::default(),
                     code_block: vec![],
                 },
             );
         let if_stmt = c::language_object::statement_object::if_statement::IfStatement {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
             condition: Box::new(cond),
             body: Box::new(compound_statement),
             else_statement: None,
@@ -1049,7 +2401,10 @@ mod tests {
         let value = "314";
         let num = c::language_object::expression_object::number_literal::NumberLiteral {
             id,
-            value: value.to_string(),
+            ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(
+                value.to_string(),
+            )
+            .unwrap()
         };
         let proto_num = number_literal_to_proto(num);
         assert_eq!(proto_num.id, id.to_string());
@@ -1062,6 +2417,8 @@ mod tests {
         let content = "#include <stdio.h>";
         let pre = c::language_object::declaration_object::preproc_include::PreprocInclude {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
             content: content.to_string(),
         };
         let proto_pre = preproc_include_to_proto(pre);
@@ -1076,6 +2433,8 @@ mod tests {
         let decl_id = Uuid::new_v4();
         let reference = c::language_object::expression_object::reference::Reference {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
             identifier: identifier.to_string(),
             declaration_id: decl_id,
         };
@@ -1093,12 +2452,10 @@ mod tests {
         let value = "42";
         let ret = c::language_object::statement_object::return_statement::ReturnStatement {
             id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             value: Some(
                 c::language_object::expression_object::ExpressionObject::NumberLiteral(
-                    c::language_object::expression_object::number_literal::NumberLiteral {
-                        id: value_id,
-                        value: value.to_string(),
-                    },
+                    c::language_object::expression_object::number_literal::NumberLiteral { id: value_id, ..c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(value.to_string()).unwrap() },
                 ),
             ),
         };
@@ -1126,7 +2483,11 @@ mod tests {
         let val = "hello world";
         let s = c::language_object::expression_object::string_literal::StringLiteral {
             id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            prefix: c::literal::EncodingPrefix::None,
             value: val.to_string(),
+            segments: vec![],
         };
         let proto_s = string_literal_to_proto(s);
         assert_eq!(proto_s.id, id.to_string());
@@ -1138,6 +2499,7 @@ mod tests {
         let comment_id = Uuid::new_v4();
         let comment = c::language_object::special_object::comment::Comment {
             id: comment_id,
+            span: None,
             content: "ok".to_string(),
         };
 
@@ -1145,6 +2507,7 @@ mod tests {
         let compound =
             c::language_object::statement_object::compound_statement::CompoundStatement {
                 id: comp_id,
+                span: None, comments: c::language_object::special_object::comment::Comments::default(),
                 code_block: vec![c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::Comment(comment)],
             };
 
@@ -1164,4 +2527,29 @@ mod tests {
             _ => panic!("expected Some(Comment)"),
         }
     }
+
+    #[test]
+    fn test_diagnostic_to_proto() {
+        let (_, diagnostics) = C::new()
+            .parse_text_with_diagnostics("int main() {\n    x += 1;\n}\n")
+            .unwrap();
+        let proto_diagnostics: Vec<proto::Diagnostic> =
+            diagnostics.into_iter().map(diagnostic_to_proto).collect();
+
+        assert_eq!(proto_diagnostics.len(), 1);
+        let diagnostic = &proto_diagnostics[0];
+        assert_eq!(diagnostic.severity(), proto::Severity::Warning);
+        assert_eq!(diagnostic.code, "unsupported-syntax");
+        assert_eq!(
+            diagnostic.span,
+            Some(proto::Span {
+                start_line: 1,
+                start_column: 4,
+                end_line: 1,
+                end_column: 11,
+                start_offset: 17,
+                end_offset: 24,
+            })
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use lenga::language::c::language_object::{
-    self, LanguageObject, declaration_object::DeclarationObject,
-    expression_object::ExpressionObject,
+    self, LanguageObject,
+    declaration_object::DeclarationObject,
+    expression_object::{ExpressionObject, initializer_pair::Designator},
     statement_object::compound_statement::compound_statement_object::CompoundStatementObject,
 };
 use uuid::Uuid;
@@ -38,6 +39,7 @@ fn search_declaration_object(decl_obj: &DeclarationObject, id: Uuid) -> Option<L
             }
             None
         }
+        DeclarationObject::DeclarationList(decl_list) => search_declaration_list(decl_list, id),
         DeclarationObject::FunctionDeclaration(func_decl) => {
             if func_decl.id == id {
                 return Some(LanguageObject::FunctionDeclaration(func_decl.clone()));
@@ -56,6 +58,23 @@ fn search_declaration_object(decl_obj: &DeclarationObject, id: Uuid) -> Option<L
             }
             None
         }
+        DeclarationObject::PreprocDefine(define) => {
+            if define.id == id {
+                return Some(LanguageObject::PreprocDefine(define.clone()));
+            }
+            None
+        }
+        DeclarationObject::PreprocCall(call) => {
+            if call.id == id {
+                return Some(LanguageObject::PreprocCall(call.clone()));
+            }
+            None
+        }
+        DeclarationObject::PreprocIf(preproc_if) => search_preproc_if(preproc_if, id),
+        DeclarationObject::StructDefinition(struct_def) => search_struct_definition(struct_def, id),
+        DeclarationObject::UnionDefinition(union_def) => search_union_definition(union_def, id),
+        DeclarationObject::EnumDefinition(enum_def) => search_enum_definition(enum_def, id),
+        DeclarationObject::TypeDefinition(type_def) => search_type_definition(type_def, id),
         DeclarationObject::Comment(comment) => {
             if comment.id == id {
                 return Some(LanguageObject::Comment(comment.clone()));
@@ -88,6 +107,90 @@ fn search_expression_object(expr_obj: &ExpressionObject, id: Uuid) -> Option<Lan
             }
             search_expression_object(&expr.right, id)
         }
+        ExpressionObject::UnaryExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::UnaryExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        ExpressionObject::UpdateExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::UpdateExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        ExpressionObject::CastExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::CastExpression(expr.clone()));
+            }
+            search_expression_object(&expr.value, id)
+        }
+        ExpressionObject::SizeofExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::SizeofExpression(expr.clone()));
+            }
+            if let Some(value) = &expr.value {
+                return search_expression_object(value, id);
+            }
+            None
+        }
+        ExpressionObject::FieldExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::FieldExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        ExpressionObject::SubscriptExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::SubscriptExpression(expr.clone()));
+            }
+            if let Some(found) = search_expression_object(&expr.argument, id) {
+                return Some(found);
+            }
+            search_expression_object(&expr.index, id)
+        }
+        ExpressionObject::ParenthesizedExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::ParenthesizedExpression(expr.clone()));
+            }
+            search_expression_object(&expr.value, id)
+        }
+        ExpressionObject::InitializerList(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::InitializerList(expr.clone()));
+            }
+            for element in &expr.elements {
+                if let Some(found) = search_expression_object(element, id) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        ExpressionObject::InitializerPair(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::InitializerPair(expr.clone()));
+            }
+            for designator in &expr.designators {
+                if let Designator::Subscript(index) = designator
+                    && let Some(found) = search_expression_object(index, id)
+                {
+                    return Some(found);
+                }
+            }
+            search_expression_object(&expr.value, id)
+        }
+        ExpressionObject::ConditionalExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::ConditionalExpression(expr.clone()));
+            }
+            if let Some(found) = search_expression_object(&expr.condition, id) {
+                return Some(found);
+            }
+            if let Some(found) = search_expression_object(&expr.consequence, id) {
+                return Some(found);
+            }
+            search_expression_object(&expr.alternative, id)
+        }
         ExpressionObject::CallExpression(expr) => {
             if expr.id == id {
                 return Some(LanguageObject::CallExpression(expr.clone()));
@@ -117,6 +220,12 @@ fn search_expression_object(expr_obj: &ExpressionObject, id: Uuid) -> Option<Lan
             }
             None
         }
+        ExpressionObject::CharLiteral(lit) => {
+            if lit.id == id {
+                return Some(LanguageObject::CharLiteral(lit.clone()));
+            }
+            None
+        }
         ExpressionObject::Unknown(unknown) => {
             if unknown.id == id {
                 return Some(LanguageObject::Unknown(unknown.clone()));
@@ -155,6 +264,32 @@ fn search_compound_statement_object(
             }
             None
         }
+        CompoundStatementObject::DeclarationList(decl_list) => {
+            search_declaration_list(decl_list, id)
+        }
+        CompoundStatementObject::StructDefinition(struct_def) => {
+            search_struct_definition(struct_def, id)
+        }
+        CompoundStatementObject::UnionDefinition(union_def) => {
+            search_union_definition(union_def, id)
+        }
+        CompoundStatementObject::EnumDefinition(enum_def) => search_enum_definition(enum_def, id),
+        CompoundStatementObject::TypeDefinition(type_def) => search_type_definition(type_def, id),
+        CompoundStatementObject::PreprocDefine(define) => {
+            if define.id == id {
+                return Some(LanguageObject::PreprocDefine(define.clone()));
+            }
+            None
+        }
+        CompoundStatementObject::PreprocCall(call) => {
+            if call.id == id {
+                return Some(LanguageObject::PreprocCall(call.clone()));
+            }
+            None
+        }
+        CompoundStatementObject::PreprocIfStatement(preproc_if) => {
+            search_preproc_if_statement(preproc_if, id)
+        }
         CompoundStatementObject::AssignmentExpression(expr) => {
             if expr.id == id {
                 return Some(LanguageObject::AssignmentExpression(expr.clone()));
@@ -170,6 +305,66 @@ fn search_compound_statement_object(
             }
             search_expression_object(&expr.right, id)
         }
+        CompoundStatementObject::UnaryExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::UnaryExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        CompoundStatementObject::UpdateExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::UpdateExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        CompoundStatementObject::CastExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::CastExpression(expr.clone()));
+            }
+            search_expression_object(&expr.value, id)
+        }
+        CompoundStatementObject::SizeofExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::SizeofExpression(expr.clone()));
+            }
+            if let Some(value) = &expr.value {
+                return search_expression_object(value, id);
+            }
+            None
+        }
+        CompoundStatementObject::FieldExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::FieldExpression(expr.clone()));
+            }
+            search_expression_object(&expr.argument, id)
+        }
+        CompoundStatementObject::SubscriptExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::SubscriptExpression(expr.clone()));
+            }
+            if let Some(found) = search_expression_object(&expr.argument, id) {
+                return Some(found);
+            }
+            search_expression_object(&expr.index, id)
+        }
+        CompoundStatementObject::ParenthesizedExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::ParenthesizedExpression(expr.clone()));
+            }
+            search_expression_object(&expr.value, id)
+        }
+        CompoundStatementObject::ConditionalExpression(expr) => {
+            if expr.id == id {
+                return Some(LanguageObject::ConditionalExpression(expr.clone()));
+            }
+            if let Some(found) = search_expression_object(&expr.condition, id) {
+                return Some(found);
+            }
+            if let Some(found) = search_expression_object(&expr.consequence, id) {
+                return Some(found);
+            }
+            search_expression_object(&expr.alternative, id)
+        }
         CompoundStatementObject::CallExpression(expr) => {
             if expr.id == id {
                 return Some(LanguageObject::CallExpression(expr.clone()));
@@ -199,9 +394,39 @@ fn search_compound_statement_object(
             }
             None
         }
+        CompoundStatementObject::CharLiteral(lit) => {
+            if lit.id == id {
+                return Some(LanguageObject::CharLiteral(lit.clone()));
+            }
+            None
+        }
         CompoundStatementObject::CompoundStatement(stmt) => search_compound_statement(stmt, id),
         CompoundStatementObject::IfStatement(stmt) => search_if_statement(stmt, id),
         CompoundStatementObject::ReturnStatement(stmt) => search_return_statement(stmt, id),
+        CompoundStatementObject::WhileStatement(stmt) => search_while_statement(stmt, id),
+        CompoundStatementObject::DoStatement(stmt) => search_do_statement(stmt, id),
+        CompoundStatementObject::ForStatement(stmt) => search_for_statement(stmt, id),
+        CompoundStatementObject::SwitchStatement(stmt) => search_switch_statement(stmt, id),
+        CompoundStatementObject::CaseStatement(stmt) => search_case_statement(stmt, id),
+        CompoundStatementObject::BreakStatement(stmt) => {
+            if stmt.id == id {
+                return Some(LanguageObject::BreakStatement(stmt.clone()));
+            }
+            None
+        }
+        CompoundStatementObject::ContinueStatement(stmt) => {
+            if stmt.id == id {
+                return Some(LanguageObject::ContinueStatement(stmt.clone()));
+            }
+            None
+        }
+        CompoundStatementObject::LabeledStatement(stmt) => search_labeled_statement(stmt, id),
+        CompoundStatementObject::GotoStatement(stmt) => {
+            if stmt.id == id {
+                return Some(LanguageObject::GotoStatement(stmt.clone()));
+            }
+            None
+        }
         CompoundStatementObject::Comment(comment) => {
             if comment.id == id {
                 return Some(LanguageObject::Comment(comment.clone()));
@@ -273,3 +498,215 @@ fn search_return_statement(
         Some(value) => search_expression_object(value, id),
     }
 }
+
+fn search_while_statement(
+    stmt: &language_object::statement_object::while_statement::WhileStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::WhileStatement(stmt.clone()));
+    }
+    if let Some(found) = search_expression_object(&stmt.condition, id) {
+        return Some(found);
+    }
+    search_compound_statement_object(&stmt.body, id)
+}
+
+fn search_do_statement(
+    stmt: &language_object::statement_object::do_statement::DoStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::DoStatement(stmt.clone()));
+    }
+    if let Some(found) = search_compound_statement_object(&stmt.body, id) {
+        return Some(found);
+    }
+    search_expression_object(&stmt.condition, id)
+}
+
+fn search_for_statement(
+    stmt: &language_object::statement_object::for_statement::ForStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::ForStatement(stmt.clone()));
+    }
+    if let Some(initializer) = &stmt.initializer
+        && let Some(found) = search_compound_statement_object(initializer, id)
+    {
+        return Some(found);
+    }
+    if let Some(condition) = &stmt.condition
+        && let Some(found) = search_expression_object(condition, id)
+    {
+        return Some(found);
+    }
+    if let Some(update) = &stmt.update
+        && let Some(found) = search_expression_object(update, id)
+    {
+        return Some(found);
+    }
+    search_compound_statement_object(&stmt.body, id)
+}
+
+fn search_switch_statement(
+    stmt: &language_object::statement_object::switch_statement::SwitchStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::SwitchStatement(stmt.clone()));
+    }
+    if let Some(found) = search_expression_object(&stmt.condition, id) {
+        return Some(found);
+    }
+    search_compound_statement_object(&stmt.body, id)
+}
+
+fn search_case_statement(
+    stmt: &language_object::statement_object::case_statement::CaseStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::CaseStatement(stmt.clone()));
+    }
+    if let Some(value) = &stmt.value
+        && let Some(found) = search_expression_object(value, id)
+    {
+        return Some(found);
+    }
+    for child in &stmt.body {
+        if let Some(found) = search_compound_statement_object(child, id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn search_labeled_statement(
+    stmt: &language_object::statement_object::labeled_statement::LabeledStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if stmt.id == id {
+        return Some(LanguageObject::LabeledStatement(stmt.clone()));
+    }
+    stmt.statement
+        .as_ref()
+        .and_then(|statement| search_compound_statement_object(statement, id))
+}
+
+fn search_declaration_list(
+    declaration_list: &language_object::declaration_object::declaration_list::DeclarationList,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if declaration_list.id == id {
+        return Some(LanguageObject::DeclarationList(declaration_list.clone()));
+    }
+    for declaration in &declaration_list.declarations {
+        if declaration.id == id {
+            return Some(LanguageObject::Declaration(declaration.clone()));
+        }
+        if let Some(value) = &declaration.value
+            && let Some(found) = search_expression_object(value, id)
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn search_preproc_if(
+    preproc_if: &language_object::declaration_object::preproc_if::PreprocIf,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if preproc_if.id == id {
+        return Some(LanguageObject::PreprocIf(preproc_if.clone()));
+    }
+    for child in &preproc_if.body {
+        if let Some(found) = search_declaration_object(child, id) {
+            return Some(found);
+        }
+    }
+    if let Some(alternative) = &preproc_if.alternative {
+        return search_preproc_if(alternative, id);
+    }
+    None
+}
+
+fn search_preproc_if_statement(
+    preproc_if: &language_object::statement_object::preproc_if_statement::PreprocIfStatement,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if preproc_if.id == id {
+        return Some(LanguageObject::PreprocIfStatement(preproc_if.clone()));
+    }
+    for child in &preproc_if.body {
+        if let Some(found) = search_compound_statement_object(child, id) {
+            return Some(found);
+        }
+    }
+    if let Some(alternative) = &preproc_if.alternative {
+        return search_preproc_if_statement(alternative, id);
+    }
+    None
+}
+
+fn search_struct_definition(
+    def: &language_object::declaration_object::struct_definition::StructDefinition,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if def.id == id {
+        return Some(LanguageObject::StructDefinition(def.clone()));
+    }
+    search_field_declarations(&def.fields, id)
+}
+
+fn search_union_definition(
+    def: &language_object::declaration_object::union_definition::UnionDefinition,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if def.id == id {
+        return Some(LanguageObject::UnionDefinition(def.clone()));
+    }
+    search_field_declarations(&def.fields, id)
+}
+
+fn search_field_declarations(
+    fields: &[language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration],
+    id: Uuid,
+) -> Option<LanguageObject> {
+    fields
+        .iter()
+        .find(|field| field.id == id)
+        .map(|field| LanguageObject::FieldDeclaration(field.clone()))
+}
+
+fn search_type_definition(
+    def: &language_object::declaration_object::type_definition::TypeDefinition,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if def.id == id {
+        return Some(LanguageObject::TypeDefinition(def.clone()));
+    }
+    None
+}
+
+fn search_enum_definition(
+    def: &language_object::declaration_object::enum_definition::EnumDefinition,
+    id: Uuid,
+) -> Option<LanguageObject> {
+    if def.id == id {
+        return Some(LanguageObject::EnumDefinition(def.clone()));
+    }
+    for enumerator in &def.enumerators {
+        if enumerator.id == id {
+            return Some(LanguageObject::Enumerator(enumerator.clone()));
+        }
+        if let Some(value) = &enumerator.value
+            && let Some(found) = search_expression_object(value, id)
+        {
+            return Some(found);
+        }
+    }
+    None
+}
@@ -12,25 +12,60 @@ pub fn replace_object(
 
         LanguageObject::AssignmentExpression(obj) => replace_assignment_expression(obj, new_object),
         LanguageObject::BinaryExpression(obj) => replace_binary_expression(obj, new_object),
+        LanguageObject::UnaryExpression(obj) => replace_unary_expression(obj, new_object),
+        LanguageObject::UpdateExpression(obj) => replace_update_expression(obj, new_object),
+        LanguageObject::CastExpression(obj) => replace_cast_expression(obj, new_object),
+        LanguageObject::SizeofExpression(obj) => replace_sizeof_expression(obj, new_object),
+        LanguageObject::ConditionalExpression(obj) => {
+            replace_conditional_expression(obj, new_object)
+        }
+        LanguageObject::FieldExpression(obj) => replace_field_expression(obj, new_object),
+        LanguageObject::SubscriptExpression(obj) => replace_subscript_expression(obj, new_object),
+        LanguageObject::ParenthesizedExpression(obj) => {
+            replace_parenthesized_expression(obj, new_object)
+        }
+        LanguageObject::InitializerList(obj) => replace_initializer_list(obj, new_object),
+        LanguageObject::InitializerPair(obj) => replace_initializer_pair(obj, new_object),
         LanguageObject::CallExpression(obj) => replace_call_expression(obj, new_object),
         LanguageObject::NumberLiteral(obj) => replace_number_literal(obj, new_object),
         LanguageObject::Reference(obj) => replace_reference(obj, new_object),
         LanguageObject::StringLiteral(obj) => replace_string_literal(obj, new_object),
+        LanguageObject::CharLiteral(obj) => replace_char_literal(obj, new_object),
 
         LanguageObject::CompoundStatement(obj) => replace_compound_statement(obj, new_object),
         LanguageObject::IfStatement(obj) => replace_if_statement(obj, new_object),
         LanguageObject::ReturnStatement(obj) => replace_return_statement(obj, new_object),
+        LanguageObject::WhileStatement(obj) => replace_while_statement(obj, new_object),
+        LanguageObject::DoStatement(obj) => replace_do_statement(obj, new_object),
+        LanguageObject::ForStatement(obj) => replace_for_statement(obj, new_object),
+        LanguageObject::SwitchStatement(obj) => replace_switch_statement(obj, new_object),
+        LanguageObject::CaseStatement(obj) => replace_case_statement(obj, new_object),
+        LanguageObject::BreakStatement(obj) => replace_break_statement(obj, new_object),
+        LanguageObject::ContinueStatement(obj) => replace_continue_statement(obj, new_object),
+        LanguageObject::LabeledStatement(obj) => replace_labeled_statement(obj, new_object),
+        LanguageObject::GotoStatement(obj) => replace_goto_statement(obj, new_object),
 
         LanguageObject::Declaration(obj) => replace_declaration(obj, new_object),
+        LanguageObject::DeclarationList(obj) => replace_declaration_list(obj, new_object),
         LanguageObject::FunctionDeclaration(obj) => replace_function_declaration(obj, new_object),
         LanguageObject::FunctionDefinition(obj) => replace_function_definition(obj, new_object),
         LanguageObject::PreprocInclude(obj) => replace_preproc_include(obj, new_object),
+        LanguageObject::PreprocDefine(obj) => replace_preproc_define(obj, new_object),
+        LanguageObject::PreprocCall(obj) => replace_preproc_call(obj, new_object),
+        LanguageObject::PreprocIf(obj) => replace_preproc_if(obj, new_object),
+        LanguageObject::PreprocIfStatement(obj) => replace_preproc_if_statement(obj, new_object),
+        LanguageObject::StructDefinition(obj) => replace_struct_definition(obj, new_object),
+        LanguageObject::UnionDefinition(obj) => replace_union_definition(obj, new_object),
+        LanguageObject::EnumDefinition(obj) => replace_enum_definition(obj, new_object),
+        LanguageObject::TypeDefinition(obj) => replace_type_definition(obj, new_object),
 
         LanguageObject::Comment(obj) => replace_comment(obj, new_object),
         LanguageObject::Unknown(obj) => replace_unknown(obj, new_object),
 
         LanguageObject::FunctionParameter(obj) => replace_function_parameter(obj, new_object),
         LanguageObject::ElseClause(obj) => replace_else_clause(obj, new_object),
+        LanguageObject::FieldDeclaration(obj) => replace_field_declaration(obj, new_object),
+        LanguageObject::Enumerator(obj) => replace_enumerator(obj, new_object),
     }
 }
 
@@ -45,6 +80,36 @@ fn replace_expression_object(
         language_object::expression_object::ExpressionObject::BinaryExpression(obj) => {
             replace_binary_expression(obj, new_object)
         }
+        language_object::expression_object::ExpressionObject::UnaryExpression(obj) => {
+            replace_unary_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::UpdateExpression(obj) => {
+            replace_update_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::CastExpression(obj) => {
+            replace_cast_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::SizeofExpression(obj) => {
+            replace_sizeof_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::ConditionalExpression(obj) => {
+            replace_conditional_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::FieldExpression(obj) => {
+            replace_field_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::SubscriptExpression(obj) => {
+            replace_subscript_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::ParenthesizedExpression(obj) => {
+            replace_parenthesized_expression(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::InitializerList(obj) => {
+            replace_initializer_list(obj, new_object)
+        }
+        language_object::expression_object::ExpressionObject::InitializerPair(obj) => {
+            replace_initializer_pair(obj, new_object)
+        }
         language_object::expression_object::ExpressionObject::CallExpression(obj) => {
             replace_call_expression(obj, new_object)
         }
@@ -57,6 +122,9 @@ fn replace_expression_object(
         language_object::expression_object::ExpressionObject::StringLiteral(obj) => {
             replace_string_literal(obj, new_object)
         }
+        language_object::expression_object::ExpressionObject::CharLiteral(obj) => {
+            replace_char_literal(obj, new_object)
+        }
         language_object::expression_object::ExpressionObject::Unknown(obj) => {
             replace_unknown(obj, new_object)
         }
@@ -69,11 +137,19 @@ fn replace_declaration_object(
 ) -> Option<LanguageObject> {
     match object {
         DeclarationObject::Declaration(obj) => replace_declaration(obj, new_object),
+        DeclarationObject::DeclarationList(obj) => replace_declaration_list(obj, new_object),
         DeclarationObject::FunctionDeclaration(obj) => {
             replace_function_declaration(obj, new_object)
         }
         DeclarationObject::FunctionDefinition(obj) => replace_function_definition(obj, new_object),
         DeclarationObject::PreprocInclude(obj) => replace_preproc_include(obj, new_object),
+        DeclarationObject::PreprocDefine(obj) => replace_preproc_define(obj, new_object),
+        DeclarationObject::PreprocCall(obj) => replace_preproc_call(obj, new_object),
+        DeclarationObject::PreprocIf(obj) => replace_preproc_if(obj, new_object),
+        DeclarationObject::StructDefinition(obj) => replace_struct_definition(obj, new_object),
+        DeclarationObject::UnionDefinition(obj) => replace_union_definition(obj, new_object),
+        DeclarationObject::EnumDefinition(obj) => replace_enum_definition(obj, new_object),
+        DeclarationObject::TypeDefinition(obj) => replace_type_definition(obj, new_object),
         DeclarationObject::Comment(obj) => replace_comment(obj, new_object),
         DeclarationObject::Unknown(obj) => replace_unknown(obj, new_object),
     }
@@ -90,18 +166,62 @@ fn replace_compound_statement_object(
         CompoundStatementObject::BinaryExpression(obj) => {
             replace_binary_expression(obj, new_object)
         }
+        CompoundStatementObject::UnaryExpression(obj) => replace_unary_expression(obj, new_object),
+        CompoundStatementObject::UpdateExpression(obj) => {
+            replace_update_expression(obj, new_object)
+        }
+        CompoundStatementObject::CastExpression(obj) => replace_cast_expression(obj, new_object),
+        CompoundStatementObject::SizeofExpression(obj) => {
+            replace_sizeof_expression(obj, new_object)
+        }
+        CompoundStatementObject::ConditionalExpression(obj) => {
+            replace_conditional_expression(obj, new_object)
+        }
+        CompoundStatementObject::FieldExpression(obj) => replace_field_expression(obj, new_object),
+        CompoundStatementObject::SubscriptExpression(obj) => {
+            replace_subscript_expression(obj, new_object)
+        }
+        CompoundStatementObject::ParenthesizedExpression(obj) => {
+            replace_parenthesized_expression(obj, new_object)
+        }
         CompoundStatementObject::CallExpression(obj) => replace_call_expression(obj, new_object),
         CompoundStatementObject::NumberLiteral(obj) => replace_number_literal(obj, new_object),
         CompoundStatementObject::Reference(obj) => replace_reference(obj, new_object),
         CompoundStatementObject::StringLiteral(obj) => replace_string_literal(obj, new_object),
+        CompoundStatementObject::CharLiteral(obj) => replace_char_literal(obj, new_object),
 
         CompoundStatementObject::CompoundStatement(obj) => {
             replace_compound_statement(obj, new_object)
         }
         CompoundStatementObject::IfStatement(obj) => replace_if_statement(obj, new_object),
         CompoundStatementObject::ReturnStatement(obj) => replace_return_statement(obj, new_object),
+        CompoundStatementObject::WhileStatement(obj) => replace_while_statement(obj, new_object),
+        CompoundStatementObject::DoStatement(obj) => replace_do_statement(obj, new_object),
+        CompoundStatementObject::ForStatement(obj) => replace_for_statement(obj, new_object),
+        CompoundStatementObject::SwitchStatement(obj) => replace_switch_statement(obj, new_object),
+        CompoundStatementObject::CaseStatement(obj) => replace_case_statement(obj, new_object),
+        CompoundStatementObject::BreakStatement(obj) => replace_break_statement(obj, new_object),
+        CompoundStatementObject::ContinueStatement(obj) => {
+            replace_continue_statement(obj, new_object)
+        }
+        CompoundStatementObject::LabeledStatement(obj) => {
+            replace_labeled_statement(obj, new_object)
+        }
+        CompoundStatementObject::GotoStatement(obj) => replace_goto_statement(obj, new_object),
 
         CompoundStatementObject::Declaration(obj) => replace_declaration(obj, new_object),
+        CompoundStatementObject::DeclarationList(obj) => replace_declaration_list(obj, new_object),
+        CompoundStatementObject::StructDefinition(obj) => {
+            replace_struct_definition(obj, new_object)
+        }
+        CompoundStatementObject::UnionDefinition(obj) => replace_union_definition(obj, new_object),
+        CompoundStatementObject::EnumDefinition(obj) => replace_enum_definition(obj, new_object),
+        CompoundStatementObject::TypeDefinition(obj) => replace_type_definition(obj, new_object),
+        CompoundStatementObject::PreprocDefine(obj) => replace_preproc_define(obj, new_object),
+        CompoundStatementObject::PreprocCall(obj) => replace_preproc_call(obj, new_object),
+        CompoundStatementObject::PreprocIfStatement(obj) => {
+            replace_preproc_if_statement(obj, new_object)
+        }
 
         CompoundStatementObject::Comment(obj) => replace_comment(obj, new_object),
         CompoundStatementObject::Unknown(obj) => replace_unknown(obj, new_object),
@@ -113,7 +233,12 @@ pub fn replace_source_file(
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
     if file.id == new_object.id() {
-        if let LanguageObject::SourceFile(new_file) = new_object {
+        if let LanguageObject::SourceFile(mut new_file) = new_object {
+            // Files from the RPC carry no trivia, so the objects they leave unchanged keep
+            // their layout
+            if new_file.trivia.is_none() {
+                new_file.trivia = file.trivia.take();
+            }
             return Some(LanguageObject::SourceFile(std::mem::replace(
                 file, new_file,
             )));
@@ -165,6 +290,208 @@ fn replace_binary_expression(
     None
 }
 
+fn replace_unary_expression(
+    expr: &mut language_object::expression_object::unary_expression::UnaryExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::UnaryExpression(new_expr) = new_object {
+            return Some(LanguageObject::UnaryExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.argument, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_update_expression(
+    expr: &mut language_object::expression_object::update_expression::UpdateExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::UpdateExpression(new_expr) = new_object {
+            return Some(LanguageObject::UpdateExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.argument, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_cast_expression(
+    expr: &mut language_object::expression_object::cast_expression::CastExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::CastExpression(new_expr) = new_object {
+            return Some(LanguageObject::CastExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.value, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_sizeof_expression(
+    expr: &mut language_object::expression_object::sizeof_expression::SizeofExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::SizeofExpression(new_expr) = new_object {
+            return Some(LanguageObject::SizeofExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(value) = &mut expr.value
+            && let Some(found) = replace_expression_object(value, new_object)
+        {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_conditional_expression(
+    expr: &mut language_object::expression_object::conditional_expression::ConditionalExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::ConditionalExpression(new_expr) = new_object {
+            return Some(LanguageObject::ConditionalExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.condition, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_expression_object(&mut expr.consequence, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_expression_object(&mut expr.alternative, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_field_expression(
+    expr: &mut language_object::expression_object::field_expression::FieldExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::FieldExpression(new_expr) = new_object {
+            return Some(LanguageObject::FieldExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.argument, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_subscript_expression(
+    expr: &mut language_object::expression_object::subscript_expression::SubscriptExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::SubscriptExpression(new_expr) = new_object {
+            return Some(LanguageObject::SubscriptExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.argument, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_expression_object(&mut expr.index, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_parenthesized_expression(
+    expr: &mut language_object::expression_object::parenthesized_expression::ParenthesizedExpression,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if expr.id == new_object.id() {
+        if let LanguageObject::ParenthesizedExpression(new_expr) = new_object {
+            return Some(LanguageObject::ParenthesizedExpression(std::mem::replace(
+                expr, new_expr,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut expr.value, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_initializer_list(
+    list: &mut language_object::expression_object::initializer_list::InitializerList,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if list.id == new_object.id() {
+        if let LanguageObject::InitializerList(new_list) = new_object {
+            return Some(LanguageObject::InitializerList(std::mem::replace(
+                list, new_list,
+            )));
+        }
+    } else {
+        for element in &mut list.elements {
+            if let Some(found) = replace_expression_object(element, new_object.clone()) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn replace_initializer_pair(
+    pair: &mut language_object::expression_object::initializer_pair::InitializerPair,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if pair.id == new_object.id() {
+        if let LanguageObject::InitializerPair(new_pair) = new_object {
+            return Some(LanguageObject::InitializerPair(std::mem::replace(
+                pair, new_pair,
+            )));
+        }
+    } else {
+        for designator in &mut pair.designators {
+            if let language_object::expression_object::initializer_pair::Designator::Subscript(
+                index,
+            ) = designator
+                && let Some(found) = replace_expression_object(index, new_object.clone())
+            {
+                return Some(found);
+            }
+        }
+        if let Some(found) = replace_expression_object(&mut pair.value, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
 fn replace_call_expression(
     call: &mut language_object::expression_object::call_expression::CallExpression,
     new_object: LanguageObject,
@@ -189,13 +516,13 @@ fn replace_comment(
     comment: &mut language_object::special_object::comment::Comment,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if comment.id == new_object.id() {
-        if let LanguageObject::Comment(new_comment) = new_object {
-            return Some(LanguageObject::Comment(std::mem::replace(
-                comment,
-                new_comment,
-            )));
-        }
+    if comment.id == new_object.id()
+        && let LanguageObject::Comment(new_comment) = new_object
+    {
+        return Some(LanguageObject::Comment(std::mem::replace(
+            comment,
+            new_comment,
+        )));
     }
     None
 }
@@ -210,9 +537,30 @@ fn replace_declaration(
                 decl, new_decl,
             )));
         }
-    } else if let Some(val) = &mut decl.value {
-        if let Some(found) = replace_expression_object(val, new_object) {
-            return Some(found);
+    } else if let Some(val) = &mut decl.value
+        && let Some(found) = replace_expression_object(val, new_object)
+    {
+        return Some(found);
+    }
+    None
+}
+
+fn replace_declaration_list(
+    decl_list: &mut language_object::declaration_object::declaration_list::DeclarationList,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if decl_list.id == new_object.id() {
+        if let LanguageObject::DeclarationList(new_decl_list) = new_object {
+            return Some(LanguageObject::DeclarationList(std::mem::replace(
+                decl_list,
+                new_decl_list,
+            )));
+        }
+    } else {
+        for decl in &mut decl_list.declarations {
+            if let Some(found) = replace_declaration(decl, new_object.clone()) {
+                return Some(found);
+            }
         }
     }
     None
@@ -283,12 +631,12 @@ fn replace_function_parameter(
     param: &mut language_object::declaration_object::function_declaration::function_parameter::FunctionParameter,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if param.id == new_object.id() {
-        if let LanguageObject::FunctionParameter(new_param) = new_object {
-            return Some(LanguageObject::FunctionParameter(std::mem::replace(
-                param, new_param,
-            )));
-        }
+    if param.id == new_object.id()
+        && let LanguageObject::FunctionParameter(new_param) = new_object
+    {
+        return Some(LanguageObject::FunctionParameter(std::mem::replace(
+            param, new_param,
+        )));
     }
     None
 }
@@ -336,12 +684,12 @@ fn replace_number_literal(
     lit: &mut language_object::expression_object::number_literal::NumberLiteral,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if lit.id == new_object.id() {
-        if let LanguageObject::NumberLiteral(new_lit) = new_object {
-            return Some(LanguageObject::NumberLiteral(std::mem::replace(
-                lit, new_lit,
-            )));
-        }
+    if lit.id == new_object.id()
+        && let LanguageObject::NumberLiteral(new_lit) = new_object
+    {
+        return Some(LanguageObject::NumberLiteral(std::mem::replace(
+            lit, new_lit,
+        )));
     }
     None
 }
@@ -350,12 +698,88 @@ fn replace_preproc_include(
     inc: &mut language_object::declaration_object::preproc_include::PreprocInclude,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if inc.id == new_object.id() {
-        if let LanguageObject::PreprocInclude(new_inc) = new_object {
-            return Some(LanguageObject::PreprocInclude(std::mem::replace(
-                inc, new_inc,
+    if inc.id == new_object.id()
+        && let LanguageObject::PreprocInclude(new_inc) = new_object
+    {
+        return Some(LanguageObject::PreprocInclude(std::mem::replace(
+            inc, new_inc,
+        )));
+    }
+    None
+}
+
+fn replace_preproc_define(
+    define: &mut language_object::declaration_object::preproc_define::PreprocDefine,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if define.id == new_object.id()
+        && let LanguageObject::PreprocDefine(new_define) = new_object
+    {
+        return Some(LanguageObject::PreprocDefine(std::mem::replace(
+            define, new_define,
+        )));
+    }
+    None
+}
+
+fn replace_preproc_call(
+    call: &mut language_object::declaration_object::preproc_call::PreprocCall,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if call.id == new_object.id()
+        && let LanguageObject::PreprocCall(new_call) = new_object
+    {
+        return Some(LanguageObject::PreprocCall(std::mem::replace(
+            call, new_call,
+        )));
+    }
+    None
+}
+
+fn replace_preproc_if(
+    preproc_if: &mut language_object::declaration_object::preproc_if::PreprocIf,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if preproc_if.id == new_object.id() {
+        if let LanguageObject::PreprocIf(new_preproc_if) = new_object {
+            return Some(LanguageObject::PreprocIf(std::mem::replace(
+                preproc_if,
+                new_preproc_if,
             )));
         }
+    } else {
+        for child in &mut preproc_if.body {
+            if let Some(found) = replace_declaration_object(child, new_object.clone()) {
+                return Some(found);
+            }
+        }
+        if let Some(alternative) = &mut preproc_if.alternative {
+            return replace_preproc_if(alternative, new_object);
+        }
+    }
+    None
+}
+
+fn replace_preproc_if_statement(
+    preproc_if: &mut language_object::statement_object::preproc_if_statement::PreprocIfStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if preproc_if.id == new_object.id() {
+        if let LanguageObject::PreprocIfStatement(new_preproc_if) = new_object {
+            return Some(LanguageObject::PreprocIfStatement(std::mem::replace(
+                preproc_if,
+                new_preproc_if,
+            )));
+        }
+    } else {
+        for child in &mut preproc_if.body {
+            if let Some(found) = replace_compound_statement_object(child, new_object.clone()) {
+                return Some(found);
+            }
+        }
+        if let Some(alternative) = &mut preproc_if.alternative {
+            return replace_preproc_if_statement(alternative, new_object);
+        }
     }
     None
 }
@@ -364,10 +788,10 @@ fn replace_reference(
     r: &mut language_object::expression_object::reference::Reference,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if r.id == new_object.id() {
-        if let LanguageObject::Reference(new_r) = new_object {
-            return Some(LanguageObject::Reference(std::mem::replace(r, new_r)));
-        }
+    if r.id == new_object.id()
+        && let LanguageObject::Reference(new_r) = new_object
+    {
+        return Some(LanguageObject::Reference(std::mem::replace(r, new_r)));
     }
     None
 }
@@ -395,12 +819,24 @@ fn replace_string_literal(
     lit: &mut language_object::expression_object::string_literal::StringLiteral,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if lit.id == new_object.id() {
-        if let LanguageObject::StringLiteral(new_lit) = new_object {
-            return Some(LanguageObject::StringLiteral(std::mem::replace(
-                lit, new_lit,
-            )));
-        }
+    if lit.id == new_object.id()
+        && let LanguageObject::StringLiteral(new_lit) = new_object
+    {
+        return Some(LanguageObject::StringLiteral(std::mem::replace(
+            lit, new_lit,
+        )));
+    }
+    None
+}
+
+fn replace_char_literal(
+    lit: &mut language_object::expression_object::char_literal::CharLiteral,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if lit.id == new_object.id()
+        && let LanguageObject::CharLiteral(new_lit) = new_object
+    {
+        return Some(LanguageObject::CharLiteral(std::mem::replace(lit, new_lit)));
     }
     None
 }
@@ -426,17 +862,303 @@ fn replace_compound_statement(
     None
 }
 
+fn replace_while_statement(
+    stmt: &mut language_object::statement_object::while_statement::WhileStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::WhileStatement(new_stmt) = new_object {
+            return Some(LanguageObject::WhileStatement(std::mem::replace(
+                stmt, new_stmt,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut stmt.condition, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_compound_statement_object(&mut stmt.body, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_do_statement(
+    stmt: &mut language_object::statement_object::do_statement::DoStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::DoStatement(new_stmt) = new_object {
+            return Some(LanguageObject::DoStatement(std::mem::replace(
+                stmt, new_stmt,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_compound_statement_object(&mut stmt.body, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_expression_object(&mut stmt.condition, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_for_statement(
+    stmt: &mut language_object::statement_object::for_statement::ForStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::ForStatement(new_stmt) = new_object {
+            return Some(LanguageObject::ForStatement(std::mem::replace(
+                stmt, new_stmt,
+            )));
+        }
+    } else {
+        if let Some(initializer) = &mut stmt.initializer
+            && let Some(found) = replace_compound_statement_object(initializer, new_object.clone())
+        {
+            return Some(found);
+        }
+        if let Some(condition) = &mut stmt.condition
+            && let Some(found) = replace_expression_object(condition, new_object.clone())
+        {
+            return Some(found);
+        }
+        if let Some(update) = &mut stmt.update
+            && let Some(found) = replace_expression_object(update, new_object.clone())
+        {
+            return Some(found);
+        }
+        if let Some(found) = replace_compound_statement_object(&mut stmt.body, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
 fn replace_unknown(
     unknown: &mut language_object::special_object::unknown::Unknown,
     new_object: LanguageObject,
 ) -> Option<LanguageObject> {
-    if unknown.id == new_object.id() {
-        if let LanguageObject::Unknown(new_unknown) = new_object {
-            return Some(LanguageObject::Unknown(std::mem::replace(
-                unknown,
-                new_unknown,
+    if unknown.id == new_object.id()
+        && let LanguageObject::Unknown(new_unknown) = new_object
+    {
+        return Some(LanguageObject::Unknown(std::mem::replace(
+            unknown,
+            new_unknown,
+        )));
+    }
+    None
+}
+
+fn replace_switch_statement(
+    stmt: &mut language_object::statement_object::switch_statement::SwitchStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::SwitchStatement(new_stmt) = new_object {
+            return Some(LanguageObject::SwitchStatement(std::mem::replace(
+                stmt, new_stmt,
+            )));
+        }
+    } else {
+        if let Some(found) = replace_expression_object(&mut stmt.condition, new_object.clone()) {
+            return Some(found);
+        }
+        if let Some(found) = replace_compound_statement_object(&mut stmt.body, new_object) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn replace_case_statement(
+    stmt: &mut language_object::statement_object::case_statement::CaseStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::CaseStatement(new_stmt) = new_object {
+            return Some(LanguageObject::CaseStatement(std::mem::replace(
+                stmt, new_stmt,
             )));
         }
+    } else {
+        if let Some(value) = &mut stmt.value
+            && let Some(found) = replace_expression_object(value, new_object.clone())
+        {
+            return Some(found);
+        }
+        for child in &mut stmt.body {
+            if let Some(found) = replace_compound_statement_object(child, new_object.clone()) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn replace_break_statement(
+    stmt: &mut language_object::statement_object::break_statement::BreakStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id()
+        && let LanguageObject::BreakStatement(new_stmt) = new_object
+    {
+        return Some(LanguageObject::BreakStatement(std::mem::replace(
+            stmt, new_stmt,
+        )));
+    }
+    None
+}
+
+fn replace_continue_statement(
+    stmt: &mut language_object::statement_object::continue_statement::ContinueStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id()
+        && let LanguageObject::ContinueStatement(new_stmt) = new_object
+    {
+        return Some(LanguageObject::ContinueStatement(std::mem::replace(
+            stmt, new_stmt,
+        )));
+    }
+    None
+}
+
+fn replace_labeled_statement(
+    stmt: &mut language_object::statement_object::labeled_statement::LabeledStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id() {
+        if let LanguageObject::LabeledStatement(new_stmt) = new_object {
+            return Some(LanguageObject::LabeledStatement(std::mem::replace(
+                stmt, new_stmt,
+            )));
+        }
+    } else if let Some(statement) = &mut stmt.statement {
+        return replace_compound_statement_object(statement, new_object);
+    }
+    None
+}
+
+fn replace_goto_statement(
+    stmt: &mut language_object::statement_object::goto_statement::GotoStatement,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if stmt.id == new_object.id()
+        && let LanguageObject::GotoStatement(new_stmt) = new_object
+    {
+        return Some(LanguageObject::GotoStatement(std::mem::replace(
+            stmt, new_stmt,
+        )));
+    }
+    None
+}
+
+fn replace_struct_definition(
+    def: &mut language_object::declaration_object::struct_definition::StructDefinition,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if def.id == new_object.id() {
+        if let LanguageObject::StructDefinition(new_def) = new_object {
+            return Some(LanguageObject::StructDefinition(std::mem::replace(
+                def, new_def,
+            )));
+        }
+    } else {
+        for field in &mut def.fields {
+            if let Some(found) = replace_field_declaration(field, new_object.clone()) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn replace_union_definition(
+    def: &mut language_object::declaration_object::union_definition::UnionDefinition,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if def.id == new_object.id() {
+        if let LanguageObject::UnionDefinition(new_def) = new_object {
+            return Some(LanguageObject::UnionDefinition(std::mem::replace(
+                def, new_def,
+            )));
+        }
+    } else {
+        for field in &mut def.fields {
+            if let Some(found) = replace_field_declaration(field, new_object.clone()) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn replace_field_declaration(
+    field: &mut language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if field.id == new_object.id()
+        && let LanguageObject::FieldDeclaration(new_field) = new_object
+    {
+        return Some(LanguageObject::FieldDeclaration(std::mem::replace(
+            field, new_field,
+        )));
+    }
+    None
+}
+
+fn replace_type_definition(
+    def: &mut language_object::declaration_object::type_definition::TypeDefinition,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if def.id == new_object.id()
+        && let LanguageObject::TypeDefinition(new_def) = new_object
+    {
+        return Some(LanguageObject::TypeDefinition(std::mem::replace(
+            def, new_def,
+        )));
+    }
+    None
+}
+
+fn replace_enum_definition(
+    def: &mut language_object::declaration_object::enum_definition::EnumDefinition,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if def.id == new_object.id() {
+        if let LanguageObject::EnumDefinition(new_def) = new_object {
+            return Some(LanguageObject::EnumDefinition(std::mem::replace(
+                def, new_def,
+            )));
+        }
+    } else {
+        for enumerator in &mut def.enumerators {
+            if let Some(found) = replace_enumerator(enumerator, new_object.clone()) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
+fn replace_enumerator(
+    enumerator: &mut language_object::declaration_object::enum_definition::enumerator::Enumerator,
+    new_object: LanguageObject,
+) -> Option<LanguageObject> {
+    if enumerator.id == new_object.id() {
+        if let LanguageObject::Enumerator(new_enumerator) = new_object {
+            return Some(LanguageObject::Enumerator(std::mem::replace(
+                enumerator,
+                new_enumerator,
+            )));
+        }
+    } else if let Some(value) = &mut enumerator.value
+        && let Some(found) = replace_expression_object(value, new_object)
+    {
+        return Some(found);
     }
     None
 }
@@ -456,6 +1178,9 @@ mod tests {
         let decl_identifier = "a";
         let decl = c::language_object::declaration_object::declaration::Declaration {
             id: decl_id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             primitive_type,
             identifier: decl_identifier.to_string(),
             value: None,
@@ -466,6 +1191,7 @@ mod tests {
         let param_identifier = "b";
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: param_identifier.to_string(),
             param_type,
         };
@@ -476,26 +1202,35 @@ mod tests {
         let func_decl =
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id: func_decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: func_decl_return_type,
                 identifier: func_decl_identifier.to_string(),
                 parameter_list: vec![param],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let src_file_id = Uuid::new_v4();
         let src_file = c::language_object::special_object::source_file::SourceFile {
             id: src_file_id,
+            span: None,
             code: vec![
                 c::language_object::declaration_object::DeclarationObject::Declaration(decl),
                 c::language_object::declaration_object::DeclarationObject::FunctionDeclaration(
                     func_decl,
                 ),
             ],
+            trivia: None,
         };
 
         let replace = c::language_object::LanguageObject::SourceFile(
             c::language_object::special_object::source_file::SourceFile {
                 id: src_file_id,
+                span: None,
                 code: vec![],
+                trivia: None,
             },
         );
 
@@ -518,6 +1253,9 @@ mod tests {
         let decl_identifier = "a";
         let decl = c::language_object::declaration_object::declaration::Declaration {
             id: decl_id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             primitive_type,
             identifier: decl_identifier.to_string(),
             value: None,
@@ -528,6 +1266,7 @@ mod tests {
         let param_identifier = "b";
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: param_identifier.to_string(),
             param_type,
         };
@@ -538,28 +1277,40 @@ mod tests {
         let func_decl =
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id: func_decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: func_decl_return_type.clone(),
                 identifier: func_decl_identifier.to_string(),
                 parameter_list: vec![param],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let src_file_id = Uuid::new_v4();
         let src_file = c::language_object::special_object::source_file::SourceFile {
             id: src_file_id,
+            span: None,
             code: vec![
                 c::language_object::declaration_object::DeclarationObject::Declaration(decl),
                 c::language_object::declaration_object::DeclarationObject::FunctionDeclaration(
                     func_decl,
                 ),
             ],
+            trivia: None,
         };
 
         let replace = c::language_object::LanguageObject::FunctionDeclaration(
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id: func_decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: func_decl_return_type,
                 identifier: func_decl_identifier.to_string(),
                 parameter_list: vec![],
+                is_variadic: false,
+                explicit_void: false,
             },
         );
 
@@ -587,6 +1338,9 @@ mod tests {
         let decl_identifier = "a";
         let decl = c::language_object::declaration_object::declaration::Declaration {
             id: decl_id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             primitive_type,
             identifier: decl_identifier.to_string(),
             value: None,
@@ -597,6 +1351,7 @@ mod tests {
         let param_identifier = "b";
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: param_identifier.to_string(),
             param_type,
         };
@@ -607,26 +1362,36 @@ mod tests {
         let func_decl =
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id: func_decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: func_decl_return_type.clone(),
                 identifier: func_decl_identifier.to_string(),
                 parameter_list: vec![param],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let src_file_id = Uuid::new_v4();
         let src_file = c::language_object::special_object::source_file::SourceFile {
             id: src_file_id,
+            span: None,
             code: vec![
                 language_object::declaration_object::DeclarationObject::Declaration(decl),
                 language_object::declaration_object::DeclarationObject::FunctionDeclaration(
                     func_decl,
                 ),
             ],
+            trivia: None,
         };
 
         let new_type = c::c_type::CType::Char;
         let replace = c::language_object::LanguageObject::Declaration(
             c::language_object::declaration_object::declaration::Declaration {
                 id: decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 primitive_type: new_type.clone(),
                 identifier: decl_identifier.to_string(),
                 value: None,
@@ -657,6 +1422,9 @@ mod tests {
         let decl_identifier = "a";
         let decl = c::language_object::declaration_object::declaration::Declaration {
             id: decl_id,
+            span: None,
+            comments: c::language_object::special_object::comment::Comments::default(),
+            specifiers: c::specifiers::Specifiers::default(),
             primitive_type,
             identifier: decl_identifier.to_string(),
             value: None,
@@ -667,6 +1435,7 @@ mod tests {
         let param_identifier = "b";
         let param = c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id: param_id,
+            span: None, comments: c::language_object::special_object::comment::Comments::default(),
             identifier: param_identifier.to_string(),
             param_type,
         };
@@ -677,25 +1446,33 @@ mod tests {
         let func_decl =
             c::language_object::declaration_object::function_declaration::FunctionDeclaration {
                 id: func_decl_id,
+                span: None,
+                comments: c::language_object::special_object::comment::Comments::default(),
+                specifiers: c::specifiers::Specifiers::default(),
                 return_type: func_decl_return_type.clone(),
                 identifier: func_decl_identifier.to_string(),
                 parameter_list: vec![param],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let src_file_id = Uuid::new_v4();
         let src_file = c::language_object::special_object::source_file::SourceFile {
             id: src_file_id,
+            span: None,
             code: vec![
                 c::language_object::declaration_object::DeclarationObject::Declaration(decl),
                 c::language_object::declaration_object::DeclarationObject::FunctionDeclaration(
                     func_decl,
                 ),
             ],
+            trivia: None,
         };
 
         let replace = c::language_object::LanguageObject::Comment(
             c::language_object::special_object::comment::Comment {
                 id: Uuid::new_v4(),
+                span: None,
                 content: "test".to_string(),
             },
         );
@@ -1,4 +1,7 @@
-use lenga::language::c::{self};
+use lenga::language::{
+    c::{self},
+    diagnostic::{Position, Span},
+};
 use uuid::Uuid;
 
 use crate::lenga_service::clenga::proto;
@@ -22,6 +25,57 @@ pub fn proto_to_c_language_object(
             let binary_expression_c_object = binary_expression_to_c_object(binary_expression)?;
             c::language_object::LanguageObject::BinaryExpression(binary_expression_c_object)
         }
+        Some(proto::language_object::LanguageObject::UnaryExpression(unary_expression)) => {
+            let unary_expression_c_object = unary_expression_to_c_object(unary_expression)?;
+            c::language_object::LanguageObject::UnaryExpression(unary_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::UpdateExpression(update_expression)) => {
+            let update_expression_c_object = update_expression_to_c_object(update_expression)?;
+            c::language_object::LanguageObject::UpdateExpression(update_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::CastExpression(cast_expression)) => {
+            let cast_expression_c_object = cast_expression_to_c_object(cast_expression)?;
+            c::language_object::LanguageObject::CastExpression(cast_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::SizeofExpression(sizeof_expression)) => {
+            let sizeof_expression_c_object = sizeof_expression_to_c_object(sizeof_expression)?;
+            c::language_object::LanguageObject::SizeofExpression(sizeof_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::ConditionalExpression(
+            conditional_expression,
+        )) => {
+            let conditional_expression_c_object =
+                conditional_expression_to_c_object(conditional_expression)?;
+            c::language_object::LanguageObject::ConditionalExpression(
+                conditional_expression_c_object,
+            )
+        }
+        Some(proto::language_object::LanguageObject::FieldExpression(field_expression)) => {
+            let field_expression_c_object = field_expression_to_c_object(field_expression)?;
+            c::language_object::LanguageObject::FieldExpression(field_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::SubscriptExpression(subscript_expression)) => {
+            let subscript_expression_c_object =
+                subscript_expression_to_c_object(subscript_expression)?;
+            c::language_object::LanguageObject::SubscriptExpression(subscript_expression_c_object)
+        }
+        Some(proto::language_object::LanguageObject::ParenthesizedExpression(
+            parenthesized_expression,
+        )) => {
+            let parenthesized_expression_c_object =
+                parenthesized_expression_to_c_object(parenthesized_expression)?;
+            c::language_object::LanguageObject::ParenthesizedExpression(
+                parenthesized_expression_c_object,
+            )
+        }
+        Some(proto::language_object::LanguageObject::InitializerList(initializer_list)) => {
+            let initializer_list_c_object = initializer_list_to_c_object(initializer_list)?;
+            c::language_object::LanguageObject::InitializerList(initializer_list_c_object)
+        }
+        Some(proto::language_object::LanguageObject::InitializerPair(initializer_pair)) => {
+            let initializer_pair_c_object = initializer_pair_to_c_object(initializer_pair)?;
+            c::language_object::LanguageObject::InitializerPair(initializer_pair_c_object)
+        }
         Some(proto::language_object::LanguageObject::CallExpression(call_expression)) => {
             let call_expression_c_object = call_expression_to_c_object(call_expression)?;
             c::language_object::LanguageObject::CallExpression(call_expression_c_object)
@@ -34,6 +88,10 @@ pub fn proto_to_c_language_object(
             let declaration_c_object = declaration_to_c_object(declaration)?;
             c::language_object::LanguageObject::Declaration(declaration_c_object)
         }
+        Some(proto::language_object::LanguageObject::DeclarationList(declaration_list)) => {
+            let declaration_list_c_object = declaration_list_to_c_object(declaration_list)?;
+            c::language_object::LanguageObject::DeclarationList(declaration_list_c_object)
+        }
         Some(proto::language_object::LanguageObject::ElseClause(else_clause)) => {
             let else_clause_c_object = else_clause_to_c_object(else_clause)?;
             c::language_object::LanguageObject::ElseClause(else_clause_c_object)
@@ -60,6 +118,23 @@ pub fn proto_to_c_language_object(
             let number_literal_c_object = number_literal_to_c_object(number_literal)?;
             c::language_object::LanguageObject::NumberLiteral(number_literal_c_object)
         }
+        Some(proto::language_object::LanguageObject::PreprocDefine(preproc_define)) => {
+            let preproc_define_c_object = preproc_define_to_c_object(preproc_define)?;
+            c::language_object::LanguageObject::PreprocDefine(preproc_define_c_object)
+        }
+        Some(proto::language_object::LanguageObject::PreprocCall(preproc_call)) => {
+            let preproc_call_c_object = preproc_call_to_c_object(preproc_call)?;
+            c::language_object::LanguageObject::PreprocCall(preproc_call_c_object)
+        }
+        Some(proto::language_object::LanguageObject::PreprocIf(preproc_if)) => {
+            let preproc_if_c_object = preproc_if_to_c_object(preproc_if)?;
+            c::language_object::LanguageObject::PreprocIf(preproc_if_c_object)
+        }
+        Some(proto::language_object::LanguageObject::PreprocIfStatement(preproc_if_statement)) => {
+            let preproc_if_statement_c_object =
+                preproc_if_statement_to_c_object(preproc_if_statement)?;
+            c::language_object::LanguageObject::PreprocIfStatement(preproc_if_statement_c_object)
+        }
         Some(proto::language_object::LanguageObject::PreprocInclude(preproc_include)) => {
             let preproc_include_c_object = preproc_include_to_c_object(preproc_include)?;
             c::language_object::LanguageObject::PreprocInclude(preproc_include_c_object)
@@ -76,10 +151,74 @@ pub fn proto_to_c_language_object(
             let string_literal_c_object = string_literal_to_c_object(string_literal)?;
             c::language_object::LanguageObject::StringLiteral(string_literal_c_object)
         }
+        Some(proto::language_object::LanguageObject::CharLiteral(char_literal)) => {
+            let char_literal_c_object = char_literal_to_c_object(char_literal)?;
+            c::language_object::LanguageObject::CharLiteral(char_literal_c_object)
+        }
         Some(proto::language_object::LanguageObject::CompoundStatement(compound_statement)) => {
             let compound_statement_c_object = compound_statement_to_c_object(compound_statement)?;
             c::language_object::LanguageObject::CompoundStatement(compound_statement_c_object)
         }
+        Some(proto::language_object::LanguageObject::WhileStatement(while_statement)) => {
+            let while_statement_c_object = while_statement_to_c_object(while_statement)?;
+            c::language_object::LanguageObject::WhileStatement(while_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::DoStatement(do_statement)) => {
+            let do_statement_c_object = do_statement_to_c_object(do_statement)?;
+            c::language_object::LanguageObject::DoStatement(do_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::ForStatement(for_statement)) => {
+            let for_statement_c_object = for_statement_to_c_object(for_statement)?;
+            c::language_object::LanguageObject::ForStatement(for_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::SwitchStatement(switch_statement)) => {
+            let switch_statement_c_object = switch_statement_to_c_object(switch_statement)?;
+            c::language_object::LanguageObject::SwitchStatement(switch_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::CaseStatement(case_statement)) => {
+            let case_statement_c_object = case_statement_to_c_object(case_statement)?;
+            c::language_object::LanguageObject::CaseStatement(case_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::BreakStatement(break_statement)) => {
+            let break_statement_c_object = break_statement_to_c_object(break_statement)?;
+            c::language_object::LanguageObject::BreakStatement(break_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::ContinueStatement(continue_statement)) => {
+            let continue_statement_c_object = continue_statement_to_c_object(continue_statement)?;
+            c::language_object::LanguageObject::ContinueStatement(continue_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::GotoStatement(goto_statement)) => {
+            let goto_statement_c_object = goto_statement_to_c_object(goto_statement)?;
+            c::language_object::LanguageObject::GotoStatement(goto_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::LabeledStatement(labeled_statement)) => {
+            let labeled_statement_c_object = labeled_statement_to_c_object(labeled_statement)?;
+            c::language_object::LanguageObject::LabeledStatement(labeled_statement_c_object)
+        }
+        Some(proto::language_object::LanguageObject::StructDefinition(struct_definition)) => {
+            let struct_definition_c_object = struct_definition_to_c_object(struct_definition)?;
+            c::language_object::LanguageObject::StructDefinition(struct_definition_c_object)
+        }
+        Some(proto::language_object::LanguageObject::UnionDefinition(union_definition)) => {
+            let union_definition_c_object = union_definition_to_c_object(union_definition)?;
+            c::language_object::LanguageObject::UnionDefinition(union_definition_c_object)
+        }
+        Some(proto::language_object::LanguageObject::EnumDefinition(enum_definition)) => {
+            let enum_definition_c_object = enum_definition_to_c_object(enum_definition)?;
+            c::language_object::LanguageObject::EnumDefinition(enum_definition_c_object)
+        }
+        Some(proto::language_object::LanguageObject::TypeDefinition(type_definition)) => {
+            let type_definition_c_object = type_definition_to_c_object(type_definition)?;
+            c::language_object::LanguageObject::TypeDefinition(type_definition_c_object)
+        }
+        Some(proto::language_object::LanguageObject::FieldDeclaration(field_declaration)) => {
+            let field_declaration_c_object = field_declaration_to_c_object(field_declaration)?;
+            c::language_object::LanguageObject::FieldDeclaration(field_declaration_c_object)
+        }
+        Some(proto::language_object::LanguageObject::Enumerator(enumerator)) => {
+            let enumerator_c_object = enumerator_to_c_object(enumerator)?;
+            c::language_object::LanguageObject::Enumerator(enumerator_c_object)
+        }
         Some(proto::language_object::LanguageObject::Unknown(unknown)) => {
             let unknown_c_object = unknown_to_c_object(unknown)?;
             c::language_object::LanguageObject::Unknown(unknown_c_object)
@@ -109,6 +248,73 @@ pub fn proto_to_c_expression_object(
                 binary_expression_c_object,
             )
         }
+        proto::expression_object::ExpressionObject::UnaryExpression(unary_expression) => {
+            let unary_expression_c_object = unary_expression_to_c_object(*unary_expression)?;
+            c::language_object::expression_object::ExpressionObject::UnaryExpression(
+                unary_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::UpdateExpression(update_expression) => {
+            let update_expression_c_object = update_expression_to_c_object(*update_expression)?;
+            c::language_object::expression_object::ExpressionObject::UpdateExpression(
+                update_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::CastExpression(cast_expression) => {
+            let cast_expression_c_object = cast_expression_to_c_object(*cast_expression)?;
+            c::language_object::expression_object::ExpressionObject::CastExpression(
+                cast_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::SizeofExpression(sizeof_expression) => {
+            let sizeof_expression_c_object = sizeof_expression_to_c_object(*sizeof_expression)?;
+            c::language_object::expression_object::ExpressionObject::SizeofExpression(
+                sizeof_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::ConditionalExpression(
+            conditional_expression,
+        ) => {
+            let conditional_expression_c_object =
+                conditional_expression_to_c_object(*conditional_expression)?;
+            c::language_object::expression_object::ExpressionObject::ConditionalExpression(
+                conditional_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::FieldExpression(field_expression) => {
+            let field_expression_c_object = field_expression_to_c_object(*field_expression)?;
+            c::language_object::expression_object::ExpressionObject::FieldExpression(
+                field_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::SubscriptExpression(subscript_expression) => {
+            let subscript_expression_c_object =
+                subscript_expression_to_c_object(*subscript_expression)?;
+            c::language_object::expression_object::ExpressionObject::SubscriptExpression(
+                subscript_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::ParenthesizedExpression(
+            parenthesized_expression,
+        ) => {
+            let parenthesized_expression_c_object =
+                parenthesized_expression_to_c_object(*parenthesized_expression)?;
+            c::language_object::expression_object::ExpressionObject::ParenthesizedExpression(
+                parenthesized_expression_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::InitializerList(initializer_list) => {
+            let initializer_list_c_object = initializer_list_to_c_object(*initializer_list)?;
+            c::language_object::expression_object::ExpressionObject::InitializerList(
+                initializer_list_c_object,
+            )
+        }
+        proto::expression_object::ExpressionObject::InitializerPair(initializer_pair) => {
+            let initializer_pair_c_object = initializer_pair_to_c_object(*initializer_pair)?;
+            c::language_object::expression_object::ExpressionObject::InitializerPair(
+                initializer_pair_c_object,
+            )
+        }
         proto::expression_object::ExpressionObject::CallExpression(call_expression) => {
             let call_expression_c_object = call_expression_to_c_object(call_expression)?;
             c::language_object::expression_object::ExpressionObject::CallExpression(
@@ -131,6 +337,12 @@ pub fn proto_to_c_expression_object(
                 string_literal_c_object,
             )
         }
+        proto::expression_object::ExpressionObject::CharLiteral(char_literal) => {
+            let char_literal_c_object = char_literal_to_c_object(char_literal)?;
+            c::language_object::expression_object::ExpressionObject::CharLiteral(
+                char_literal_c_object,
+            )
+        }
         proto::expression_object::ExpressionObject::Unknown(unknown) => {
             let unknown_c_object = unknown_to_c_object(unknown)?;
             c::language_object::expression_object::ExpressionObject::Unknown(unknown_c_object)
@@ -150,7 +362,14 @@ fn source_file_to_c_object(
         code.push(proto_to_c_declaration_object(msg)?);
     }
 
-    Ok(c::language_object::special_object::source_file::SourceFile { id, code })
+    Ok(
+        c::language_object::special_object::source_file::SourceFile {
+            id,
+            span: src_file.span.map(span_from_proto),
+            code,
+            trivia: None,
+        },
+    )
 }
 
 fn proto_to_c_declaration_object(
@@ -170,6 +389,13 @@ fn proto_to_c_declaration_object(
                     declaration_c_object,
                 )
             }
+            proto::declaration_object::DeclarationObject::DeclarationList(declaration_list) => {
+                let declaration_list_c_object = declaration_list_to_c_object(declaration_list)?;
+
+                c::language_object::declaration_object::DeclarationObject::DeclarationList(
+                    declaration_list_c_object,
+                )
+            }
             proto::declaration_object::DeclarationObject::FunctionDeclaration(
                 function_declaration,
             ) => {
@@ -197,6 +423,55 @@ fn proto_to_c_declaration_object(
                     preproc_include_c_object,
                 )
             }
+            proto::declaration_object::DeclarationObject::PreprocDefine(preproc_define) => {
+                let preproc_define_c_object = preproc_define_to_c_object(preproc_define)?;
+
+                c::language_object::declaration_object::DeclarationObject::PreprocDefine(
+                    preproc_define_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::PreprocCall(preproc_call) => {
+                let preproc_call_c_object = preproc_call_to_c_object(preproc_call)?;
+
+                c::language_object::declaration_object::DeclarationObject::PreprocCall(
+                    preproc_call_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::PreprocIf(preproc_if) => {
+                let preproc_if_c_object = preproc_if_to_c_object(preproc_if)?;
+
+                c::language_object::declaration_object::DeclarationObject::PreprocIf(
+                    preproc_if_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::StructDefinition(struct_definition) => {
+                let struct_definition_c_object = struct_definition_to_c_object(struct_definition)?;
+
+                c::language_object::declaration_object::DeclarationObject::StructDefinition(
+                    struct_definition_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::UnionDefinition(union_definition) => {
+                let union_definition_c_object = union_definition_to_c_object(union_definition)?;
+
+                c::language_object::declaration_object::DeclarationObject::UnionDefinition(
+                    union_definition_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::EnumDefinition(enum_definition) => {
+                let enum_definition_c_object = enum_definition_to_c_object(enum_definition)?;
+
+                c::language_object::declaration_object::DeclarationObject::EnumDefinition(
+                    enum_definition_c_object,
+                )
+            }
+            proto::declaration_object::DeclarationObject::TypeDefinition(type_definition) => {
+                let type_definition_c_object = type_definition_to_c_object(type_definition)?;
+
+                c::language_object::declaration_object::DeclarationObject::TypeDefinition(
+                    type_definition_c_object,
+                )
+            }
             proto::declaration_object::DeclarationObject::Unknown(unknown) => {
                 let unknown_c_object = unknown_to_c_object(unknown)?;
 
@@ -215,8 +490,11 @@ fn assignment_expression_to_c_object(
     let id = Uuid::parse_str(&assignment_expression.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let id_declaration = Uuid::parse_str(&assignment_expression.id_declaration)
-        .map_err(|_| "assignment expression with unparsable id_declaration attribute")?;
+    let proto_left = assignment_expression
+        .left
+        .ok_or("assignment expression with inexistent left attribute".to_string())?;
+
+    let left_c_object = Box::new(proto_to_c_expression_object(*proto_left)?);
 
     let proto_value = assignment_expression
         .value
@@ -227,8 +505,9 @@ fn assignment_expression_to_c_object(
     Ok(
         c::language_object::expression_object::assignment_expression::AssignmentExpression {
             id,
-            id_declaration,
-            identifier: assignment_expression.identifier,
+            span: assignment_expression.span.map(span_from_proto),
+            comments: comments_from_proto(assignment_expression.comments)?,
+            left: left_c_object,
             value: value_c_object,
         },
     )
@@ -253,6 +532,8 @@ fn binary_expression_to_c_object(
     Ok(
         c::language_object::expression_object::binary_expression::BinaryExpression {
             id,
+            span: binary_expression.span.map(span_from_proto),
+            comments: comments_from_proto(binary_expression.comments)?,
             left: left_c_object,
             operator: binary_expression.operator,
             right: right_c_object,
@@ -260,6 +541,288 @@ fn binary_expression_to_c_object(
     )
 }
 
+fn unary_expression_to_c_object(
+    unary_expression: proto::UnaryExpression,
+) -> Result<c::language_object::expression_object::unary_expression::UnaryExpression, String> {
+    let id = Uuid::parse_str(&unary_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let proto_argument = unary_expression
+        .argument
+        .ok_or("unary expression with inexistent argument attribute".to_string())?;
+    let argument_c_object = Box::new(proto_to_c_expression_object(*proto_argument)?);
+
+    Ok(
+        c::language_object::expression_object::unary_expression::UnaryExpression {
+            id,
+            span: unary_expression.span.map(span_from_proto),
+            comments: comments_from_proto(unary_expression.comments)?,
+            operator: unary_expression.operator,
+            argument: argument_c_object,
+        },
+    )
+}
+
+fn update_expression_to_c_object(
+    update_expression: proto::UpdateExpression,
+) -> Result<c::language_object::expression_object::update_expression::UpdateExpression, String> {
+    let id = Uuid::parse_str(&update_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let proto_argument = update_expression
+        .argument
+        .ok_or("update expression with inexistent argument attribute".to_string())?;
+    let argument_c_object = Box::new(proto_to_c_expression_object(*proto_argument)?);
+
+    Ok(
+        c::language_object::expression_object::update_expression::UpdateExpression {
+            id,
+            span: update_expression.span.map(span_from_proto),
+            comments: comments_from_proto(update_expression.comments)?,
+            operator: update_expression.operator,
+            is_prefix: update_expression.is_prefix,
+            argument: argument_c_object,
+        },
+    )
+}
+
+fn cast_expression_to_c_object(
+    cast_expression: proto::CastExpression,
+) -> Result<c::language_object::expression_object::cast_expression::CastExpression, String> {
+    let id = Uuid::parse_str(&cast_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let cast_type = c_type_to_c_object(
+        cast_expression
+            .cast_type
+            .ok_or("cast expression without cast_type attribute")?,
+    )?;
+
+    let proto_value = cast_expression
+        .value
+        .ok_or("cast expression with inexistent value attribute".to_string())?;
+    let value_c_object = Box::new(proto_to_c_expression_object(*proto_value)?);
+
+    Ok(
+        c::language_object::expression_object::cast_expression::CastExpression {
+            id,
+            span: cast_expression.span.map(span_from_proto),
+            comments: comments_from_proto(cast_expression.comments)?,
+            cast_type,
+            value: value_c_object,
+        },
+    )
+}
+
+fn sizeof_expression_to_c_object(
+    sizeof_expression: proto::SizeofExpression,
+) -> Result<c::language_object::expression_object::sizeof_expression::SizeofExpression, String> {
+    let id = Uuid::parse_str(&sizeof_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let argument_type = sizeof_expression
+        .argument_type
+        .map(c_type_to_c_object)
+        .transpose()?;
+
+    let value = sizeof_expression
+        .value
+        .map(|v| proto_to_c_expression_object(*v).map(Box::new))
+        .transpose()?;
+
+    if argument_type.is_some() == value.is_some() {
+        return Err("sizeof expression needs exactly one of argument_type and value".to_string());
+    }
+
+    Ok(
+        c::language_object::expression_object::sizeof_expression::SizeofExpression {
+            id,
+            span: sizeof_expression.span.map(span_from_proto),
+            comments: comments_from_proto(sizeof_expression.comments)?,
+            argument_type,
+            value,
+        },
+    )
+}
+
+fn conditional_expression_to_c_object(
+    conditional_expression: proto::ConditionalExpression,
+) -> Result<
+    c::language_object::expression_object::conditional_expression::ConditionalExpression,
+    String,
+> {
+    let id = Uuid::parse_str(&conditional_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let proto_condition = conditional_expression
+        .condition
+        .ok_or("conditional expression with inexistent condition attribute".to_string())?;
+    let condition_c_object = Box::new(proto_to_c_expression_object(*proto_condition)?);
+
+    let proto_consequence = conditional_expression
+        .consequence
+        .ok_or("conditional expression with inexistent consequence attribute".to_string())?;
+    let consequence_c_object = Box::new(proto_to_c_expression_object(*proto_consequence)?);
+
+    let proto_alternative = conditional_expression
+        .alternative
+        .ok_or("conditional expression with inexistent alternative attribute".to_string())?;
+    let alternative_c_object = Box::new(proto_to_c_expression_object(*proto_alternative)?);
+
+    Ok(
+        c::language_object::expression_object::conditional_expression::ConditionalExpression {
+            id,
+            span: conditional_expression.span.map(span_from_proto),
+            comments: comments_from_proto(conditional_expression.comments)?,
+            condition: condition_c_object,
+            consequence: consequence_c_object,
+            alternative: alternative_c_object,
+        },
+    )
+}
+
+fn field_expression_to_c_object(
+    field_expression: proto::FieldExpression,
+) -> Result<c::language_object::expression_object::field_expression::FieldExpression, String> {
+    let id = Uuid::parse_str(&field_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+    let declaration_id = Uuid::parse_str(&field_expression.declaration_id)
+        .map_err(|_| "declaration id could not be parsed".to_string())?;
+
+    let proto_argument = field_expression
+        .argument
+        .ok_or("field expression with inexistent argument attribute".to_string())?;
+    let argument_c_object = Box::new(proto_to_c_expression_object(*proto_argument)?);
+
+    Ok(
+        c::language_object::expression_object::field_expression::FieldExpression {
+            id,
+            span: field_expression.span.map(span_from_proto),
+            comments: comments_from_proto(field_expression.comments)?,
+            argument: argument_c_object,
+            operator: field_expression.operator,
+            declaration_id,
+            identifier: field_expression.identifier,
+        },
+    )
+}
+
+fn subscript_expression_to_c_object(
+    subscript_expression: proto::SubscriptExpression,
+) -> Result<c::language_object::expression_object::subscript_expression::SubscriptExpression, String>
+{
+    let id = Uuid::parse_str(&subscript_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let proto_argument = subscript_expression
+        .argument
+        .ok_or("subscript expression with inexistent argument attribute".to_string())?;
+    let argument_c_object = Box::new(proto_to_c_expression_object(*proto_argument)?);
+
+    let proto_index = subscript_expression
+        .index
+        .ok_or("subscript expression with inexistent index attribute".to_string())?;
+    let index_c_object = Box::new(proto_to_c_expression_object(*proto_index)?);
+
+    Ok(
+        c::language_object::expression_object::subscript_expression::SubscriptExpression {
+            id,
+            span: subscript_expression.span.map(span_from_proto),
+            comments: comments_from_proto(subscript_expression.comments)?,
+            argument: argument_c_object,
+            index: index_c_object,
+        },
+    )
+}
+
+fn parenthesized_expression_to_c_object(
+    parenthesized_expression: proto::ParenthesizedExpression,
+) -> Result<
+    c::language_object::expression_object::parenthesized_expression::ParenthesizedExpression,
+    String,
+> {
+    let id = Uuid::parse_str(&parenthesized_expression.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let proto_value = parenthesized_expression
+        .value
+        .ok_or("parenthesized expression with inexistent value attribute".to_string())?;
+    let value_c_object = Box::new(proto_to_c_expression_object(*proto_value)?);
+
+    Ok(
+        c::language_object::expression_object::parenthesized_expression::ParenthesizedExpression {
+            id,
+            span: parenthesized_expression.span.map(span_from_proto),
+            comments: comments_from_proto(parenthesized_expression.comments)?,
+            value: value_c_object,
+        },
+    )
+}
+
+fn initializer_list_to_c_object(
+    initializer_list: proto::InitializerList,
+) -> Result<c::language_object::expression_object::initializer_list::InitializerList, String> {
+    let id = Uuid::parse_str(&initializer_list.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let elements = initializer_list
+        .elements
+        .into_iter()
+        .map(proto_to_c_expression_object)
+        .collect::<Result<_, _>>()?;
+
+    Ok(
+        c::language_object::expression_object::initializer_list::InitializerList {
+            id,
+            span: initializer_list.span.map(span_from_proto),
+            comments: comments_from_proto(initializer_list.comments)?,
+            elements,
+        },
+    )
+}
+
+fn initializer_pair_to_c_object(
+    initializer_pair: proto::InitializerPair,
+) -> Result<c::language_object::expression_object::initializer_pair::InitializerPair, String> {
+    let id = Uuid::parse_str(&initializer_pair.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let designators =
+        initializer_pair
+            .designators
+            .into_iter()
+            .map(|designator| {
+                match designator.kind.ok_or("designator without kind".to_string())? {
+                proto::designator::Kind::Field(field) => Ok(
+                    c::language_object::expression_object::initializer_pair::Designator::Field(
+                        field,
+                    ),
+                ),
+                proto::designator::Kind::Index(index) => Ok(
+                    c::language_object::expression_object::initializer_pair::Designator::Subscript(
+                        proto_to_c_expression_object(*index)?,
+                    ),
+                ),
+            }
+            })
+            .collect::<Result<_, String>>()?;
+
+    let proto_value = initializer_pair
+        .value
+        .ok_or("initializer pair with inexistent value attribute".to_string())?;
+    let value_c_object = Box::new(proto_to_c_expression_object(*proto_value)?);
+
+    Ok(
+        c::language_object::expression_object::initializer_pair::InitializerPair {
+            id,
+            span: initializer_pair.span.map(span_from_proto),
+            comments: comments_from_proto(initializer_pair.comments)?,
+            designators,
+            value: value_c_object,
+        },
+    )
+}
+
 fn call_expression_to_c_object(
     call_expression: proto::CallExpression,
 ) -> Result<c::language_object::expression_object::call_expression::CallExpression, String> {
@@ -278,6 +841,8 @@ fn call_expression_to_c_object(
     Ok(
         c::language_object::expression_object::call_expression::CallExpression {
             id,
+            span: call_expression.span.map(span_from_proto),
+            comments: comments_from_proto(call_expression.comments)?,
             id_declaration,
             identifier: call_expression.identifier,
             argument_list,
@@ -293,6 +858,7 @@ fn comment_to_c_object(
 
     Ok(c::language_object::special_object::comment::Comment {
         id,
+        span: comment.span.map(span_from_proto),
         content: comment.content,
     })
 }
@@ -303,8 +869,11 @@ fn declaration_to_c_object(
     let id = Uuid::parse_str(&declaration.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let primitive_type = c::c_type::CType::from_str(&declaration.primitive_type)
-        .ok_or("declaration with unknown primitive_type attribute")?;
+    let primitive_type = c_type_to_c_object(
+        declaration
+            .primitive_type
+            .ok_or("declaration without primitive_type attribute")?,
+    )?;
 
     let value = declaration
         .value
@@ -314,6 +883,9 @@ fn declaration_to_c_object(
     Ok(
         c::language_object::declaration_object::declaration::Declaration {
             id,
+            span: declaration.span.map(span_from_proto),
+            comments: comments_from_proto(declaration.comments)?,
+            specifiers: specifiers_to_c_object(declaration.specifiers)?,
             primitive_type,
             identifier: declaration.identifier,
             value,
@@ -321,6 +893,28 @@ fn declaration_to_c_object(
     )
 }
 
+fn declaration_list_to_c_object(
+    declaration_list: proto::DeclarationList,
+) -> Result<c::language_object::declaration_object::declaration_list::DeclarationList, String> {
+    let id = Uuid::parse_str(&declaration_list.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let declarations = declaration_list
+        .declarations
+        .into_iter()
+        .map(declaration_to_c_object)
+        .collect::<Result<_, _>>()?;
+
+    Ok(
+        c::language_object::declaration_object::declaration_list::DeclarationList {
+            id,
+            span: declaration_list.span.map(span_from_proto),
+            comments: comments_from_proto(declaration_list.comments)?,
+            declarations,
+        },
+    )
+}
+
 fn else_clause_to_c_object(
     else_clause: proto::ElseClause,
 ) -> Result<c::language_object::statement_object::if_statement::else_clause::ElseClause, String> {
@@ -335,6 +929,8 @@ fn else_clause_to_c_object(
     Ok(
         c::language_object::statement_object::if_statement::else_clause::ElseClause {
             id,
+            span: else_clause.span.map(span_from_proto),
+            comments: comments_from_proto(else_clause.comments)?,
             body: Box::new(compound_statement),
         },
     )
@@ -349,6 +945,8 @@ fn unknown_to_c_object(
 
     Ok(c::language_object::special_object::unknown::Unknown {
         id,
+        span: unknown.span.map(span_from_proto),
+        comments: comments_from_proto(unknown.comments)?,
         content: unknown.content,
     })
 }
@@ -360,8 +958,11 @@ fn function_declaration_to_c_object(
     let id = Uuid::parse_str(&function_declaration.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let return_type = c::c_type::CType::from_str(&function_declaration.return_type)
-        .ok_or("function declaration with unknown return_type attribute")?;
+    let return_type = c_type_to_c_object(
+        function_declaration
+            .return_type
+            .ok_or("function declaration without return_type attribute")?,
+    )?;
 
     let mut parameter_list: Vec<
         c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter,
@@ -373,9 +974,14 @@ fn function_declaration_to_c_object(
     Ok(
         c::language_object::declaration_object::function_declaration::FunctionDeclaration {
             id,
+            span: function_declaration.span.map(span_from_proto),
+            comments: comments_from_proto(function_declaration.comments)?,
+            specifiers: specifiers_to_c_object(function_declaration.specifiers)?,
             return_type,
             identifier: function_declaration.identifier,
             parameter_list,
+            is_variadic: function_declaration.is_variadic,
+            explicit_void: function_declaration.explicit_void,
         },
     )
 }
@@ -387,8 +993,11 @@ fn function_definition_to_c_object(
     let id = Uuid::parse_str(&function_definition.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let return_type = c::c_type::CType::from_str(&function_definition.return_type)
-        .ok_or("function definition with unknown return_type attribute")?;
+    let return_type = c_type_to_c_object(
+        function_definition
+            .return_type
+            .ok_or("function definition without return_type attribute")?,
+    )?;
 
     let mut parameter_list: Vec<
         c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter,
@@ -406,26 +1015,45 @@ fn function_definition_to_c_object(
     Ok(
         c::language_object::declaration_object::function_definition::FunctionDefinition {
             id,
+            span: function_definition.span.map(span_from_proto),
+            comments: comments_from_proto(function_definition.comments)?,
+            specifiers: specifiers_to_c_object(function_definition.specifiers)?,
             return_type,
             identifier: function_definition.identifier,
             parameter_list,
+            is_variadic: function_definition.is_variadic,
+            explicit_void: function_definition.explicit_void,
             compound_statement,
         },
     )
 }
 
+fn specifiers_to_c_object(specifiers: Vec<String>) -> Result<c::specifiers::Specifiers, String> {
+    let mut c_specifiers = c::specifiers::Specifiers::default();
+    for specifier in specifiers {
+        if !c_specifiers.insert(&specifier) {
+            return Err(format!("unknown specifier {specifier}"));
+        }
+    }
+    Ok(c_specifiers)
+}
+
 fn function_parameter_to_c_object(
     function_parameter: proto::FunctionParameter,
 ) -> Result<c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter, String>{
     let id = Uuid::parse_str(&function_parameter.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let param_type = c::c_type::CType::from_str(&function_parameter.param_type)
-        .ok_or("function parameter with unknown param_type attribute")?;
+    let param_type = c_type_to_c_object(
+        function_parameter
+            .param_type
+            .ok_or("function parameter without param_type attribute")?,
+    )?;
 
     Ok(
         c::language_object::declaration_object::function_declaration::function_parameter::FunctionParameter {
             id,
+            span: function_parameter.span.map(span_from_proto), comments: comments_from_proto(function_parameter.comments)?,
             identifier: function_parameter.identifier,
             param_type,
         },
@@ -467,6 +1095,8 @@ fn if_statement_to_c_object(
     Ok(
         c::language_object::statement_object::if_statement::IfStatement {
             id,
+            span: if_statement.span.map(span_from_proto),
+            comments: comments_from_proto(if_statement.comments)?,
             condition: Box::new(condition),
             body: Box::new(body),
             else_statement,
@@ -480,41 +1110,173 @@ fn number_literal_to_c_object(
     let id = Uuid::parse_str(&number_literal.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
+    let value = match number_literal.number {
+        Some(proto::number_literal::Number::Integer(value)) => {
+            c::language_object::expression_object::number_literal::NumberValue::Integer(
+                value.into(),
+            )
+        }
+        Some(proto::number_literal::Number::Float(value)) => {
+            c::language_object::expression_object::number_literal::NumberValue::Float(value)
+        }
+        None => {
+            let parsed = c::language_object::expression_object::number_literal::NumberLiteral::from_spelling(number_literal.value)
+                .ok_or_else(|| "number literal could not be parsed".to_string())?;
+            return Ok(
+                c::language_object::expression_object::number_literal::NumberLiteral {
+                    id,
+                    ..parsed
+                },
+            );
+        }
+    };
     Ok(
         c::language_object::expression_object::number_literal::NumberLiteral {
             id,
-            value: number_literal.value,
+            span: number_literal.span.map(span_from_proto),
+            comments: comments_from_proto(number_literal.comments)?,
+            value,
+            radix: c::language_object::expression_object::number_literal::Radix::from_base(
+                number_literal.radix,
+            )
+            .ok_or_else(|| format!("unknown radix {}", number_literal.radix))?,
+            suffix: number_literal.suffix,
+            spelling: number_literal.value,
         },
     )
 }
 
-fn preproc_include_to_c_object(
-    preproc_include: proto::PreprocInclude,
-) -> Result<c::language_object::declaration_object::preproc_include::PreprocInclude, String> {
-    let id = Uuid::parse_str(&preproc_include.id)
+fn preproc_call_to_c_object(
+    preproc_call: proto::PreprocCall,
+) -> Result<c::language_object::declaration_object::preproc_call::PreprocCall, String> {
+    let id = Uuid::parse_str(&preproc_call.id)
         .map_err(|_| "object id could not be parsed".to_string())?;
 
     Ok(
-        c::language_object::declaration_object::preproc_include::PreprocInclude {
+        c::language_object::declaration_object::preproc_call::PreprocCall {
             id,
-            content: preproc_include.content,
+            span: preproc_call.span.map(span_from_proto),
+            comments: comments_from_proto(preproc_call.comments)?,
+            directive: preproc_call.directive,
+            argument: preproc_call.argument,
         },
     )
 }
 
-fn reference_to_c_object(
-    reference: proto::Reference,
-) -> Result<c::language_object::expression_object::reference::Reference, String> {
-    let id =
-        Uuid::parse_str(&reference.id).map_err(|_| "object id could not be parsed".to_string())?;
+fn preproc_define_to_c_object(
+    preproc_define: proto::PreprocDefine,
+) -> Result<c::language_object::declaration_object::preproc_define::PreprocDefine, String> {
+    let id = Uuid::parse_str(&preproc_define.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
 
-    let declaration_id = Uuid::parse_str(&reference.declaration_id)
-        .map_err(|_| "reference with unparsable declaration_id attribute".to_string())?;
+    let parameters = if preproc_define.function_like {
+        Some(preproc_define.parameters)
+    } else {
+        None
+    };
 
     Ok(
-        c::language_object::expression_object::reference::Reference {
+        c::language_object::declaration_object::preproc_define::PreprocDefine {
             id,
-            declaration_id,
+            span: preproc_define.span.map(span_from_proto),
+            comments: comments_from_proto(preproc_define.comments)?,
+            identifier: preproc_define.identifier,
+            parameters,
+            value: preproc_define.value,
+        },
+    )
+}
+
+fn preproc_if_to_c_object(
+    preproc_if: proto::PreprocIf,
+) -> Result<c::language_object::declaration_object::preproc_if::PreprocIf, String> {
+    let id =
+        Uuid::parse_str(&preproc_if.id).map_err(|_| "object id could not be parsed".to_string())?;
+
+    let mut body: Vec<c::language_object::declaration_object::DeclarationObject> = Vec::new();
+    for msg in preproc_if.body {
+        body.push(proto_to_c_declaration_object(msg)?);
+    }
+
+    let alternative = match preproc_if.alternative {
+        Some(alternative) => Some(Box::new(preproc_if_to_c_object(*alternative)?)),
+        None => None,
+    };
+
+    Ok(
+        c::language_object::declaration_object::preproc_if::PreprocIf {
+            id,
+            span: preproc_if.span.map(span_from_proto),
+            comments: comments_from_proto(preproc_if.comments)?,
+            directive: preproc_if.directive,
+            condition: preproc_if.condition,
+            body,
+            alternative,
+        },
+    )
+}
+
+fn preproc_if_statement_to_c_object(
+    preproc_if: proto::PreprocIfStatement,
+) -> Result<c::language_object::statement_object::preproc_if_statement::PreprocIfStatement, String>
+{
+    let id =
+        Uuid::parse_str(&preproc_if.id).map_err(|_| "object id could not be parsed".to_string())?;
+
+    let mut body: Vec<c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject> = Vec::new();
+    for statement in preproc_if.body {
+        body.push(compound_statement_object_to_c_object(statement)?);
+    }
+
+    let alternative = match preproc_if.alternative {
+        Some(alternative) => Some(Box::new(preproc_if_statement_to_c_object(*alternative)?)),
+        None => None,
+    };
+
+    Ok(
+        c::language_object::statement_object::preproc_if_statement::PreprocIfStatement {
+            id,
+            span: preproc_if.span.map(span_from_proto),
+            comments: comments_from_proto(preproc_if.comments)?,
+            directive: preproc_if.directive,
+            condition: preproc_if.condition,
+            body,
+            alternative,
+        },
+    )
+}
+
+fn preproc_include_to_c_object(
+    preproc_include: proto::PreprocInclude,
+) -> Result<c::language_object::declaration_object::preproc_include::PreprocInclude, String> {
+    let id = Uuid::parse_str(&preproc_include.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    Ok(
+        c::language_object::declaration_object::preproc_include::PreprocInclude {
+            id,
+            span: preproc_include.span.map(span_from_proto),
+            comments: comments_from_proto(preproc_include.comments)?,
+            content: preproc_include.content,
+        },
+    )
+}
+
+fn reference_to_c_object(
+    reference: proto::Reference,
+) -> Result<c::language_object::expression_object::reference::Reference, String> {
+    let id =
+        Uuid::parse_str(&reference.id).map_err(|_| "object id could not be parsed".to_string())?;
+
+    let declaration_id = Uuid::parse_str(&reference.declaration_id)
+        .map_err(|_| "reference with unparsable declaration_id attribute".to_string())?;
+
+    Ok(
+        c::language_object::expression_object::reference::Reference {
+            id,
+            span: reference.span.map(span_from_proto),
+            comments: comments_from_proto(reference.comments)?,
+            declaration_id,
             identifier: reference.identifier,
         },
     )
@@ -530,7 +1292,14 @@ fn return_statement_to_c_object(
         None => None,
     };
 
-    Ok(c::language_object::statement_object::return_statement::ReturnStatement { id, value })
+    Ok(
+        c::language_object::statement_object::return_statement::ReturnStatement {
+            id,
+            span: return_statement.span.map(span_from_proto),
+            comments: comments_from_proto(return_statement.comments)?,
+            value,
+        },
+    )
 }
 
 fn expression_object_to_c_language_object(
@@ -552,6 +1321,62 @@ fn expression_object_to_c_language_object(
                 binary_expression_to_c_object(*binary_expression)?,
             ),
         ),
+        proto::expression_object::ExpressionObject::UnaryExpression(unary_expression) => Ok(
+            c::language_object::expression_object::ExpressionObject::UnaryExpression(
+                unary_expression_to_c_object(*unary_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::UpdateExpression(update_expression) => Ok(
+            c::language_object::expression_object::ExpressionObject::UpdateExpression(
+                update_expression_to_c_object(*update_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::CastExpression(cast_expression) => Ok(
+            c::language_object::expression_object::ExpressionObject::CastExpression(
+                cast_expression_to_c_object(*cast_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::SizeofExpression(sizeof_expression) => Ok(
+            c::language_object::expression_object::ExpressionObject::SizeofExpression(
+                sizeof_expression_to_c_object(*sizeof_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::ConditionalExpression(
+            conditional_expression,
+        ) => Ok(
+            c::language_object::expression_object::ExpressionObject::ConditionalExpression(
+                conditional_expression_to_c_object(*conditional_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::FieldExpression(field_expression) => Ok(
+            c::language_object::expression_object::ExpressionObject::FieldExpression(
+                field_expression_to_c_object(*field_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::SubscriptExpression(subscript_expression) => {
+            Ok(
+                c::language_object::expression_object::ExpressionObject::SubscriptExpression(
+                    subscript_expression_to_c_object(*subscript_expression)?,
+                ),
+            )
+        }
+        proto::expression_object::ExpressionObject::ParenthesizedExpression(
+            parenthesized_expression,
+        ) => Ok(
+            c::language_object::expression_object::ExpressionObject::ParenthesizedExpression(
+                parenthesized_expression_to_c_object(*parenthesized_expression)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::InitializerList(initializer_list) => Ok(
+            c::language_object::expression_object::ExpressionObject::InitializerList(
+                initializer_list_to_c_object(*initializer_list)?,
+            ),
+        ),
+        proto::expression_object::ExpressionObject::InitializerPair(initializer_pair) => Ok(
+            c::language_object::expression_object::ExpressionObject::InitializerPair(
+                initializer_pair_to_c_object(*initializer_pair)?,
+            ),
+        ),
         proto::expression_object::ExpressionObject::CallExpression(call_expression) => Ok(
             c::language_object::expression_object::ExpressionObject::CallExpression(
                 call_expression_to_c_object(call_expression)?,
@@ -572,6 +1397,11 @@ fn expression_object_to_c_language_object(
                 string_literal_to_c_object(string_literal)?,
             ),
         ),
+        proto::expression_object::ExpressionObject::CharLiteral(char_literal) => Ok(
+            c::language_object::expression_object::ExpressionObject::CharLiteral(
+                char_literal_to_c_object(char_literal)?,
+            ),
+        ),
         proto::expression_object::ExpressionObject::Unknown(unknown) => Ok(
             c::language_object::expression_object::ExpressionObject::Unknown(unknown_to_c_object(
                 unknown,
@@ -589,11 +1419,38 @@ fn string_literal_to_c_object(
     Ok(
         c::language_object::expression_object::string_literal::StringLiteral {
             id,
+            span: string_literal.span.map(span_from_proto),
+            comments: comments_from_proto(string_literal.comments)?,
+            prefix: encoding_prefix_to_c(&string_literal.prefix)?,
             value: string_literal.value,
+            segments: string_literal.segments,
+        },
+    )
+}
+
+fn char_literal_to_c_object(
+    char_literal: proto::CharLiteral,
+) -> Result<c::language_object::expression_object::char_literal::CharLiteral, String> {
+    let id = Uuid::parse_str(&char_literal.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    Ok(
+        c::language_object::expression_object::char_literal::CharLiteral {
+            id,
+            span: char_literal.span.map(span_from_proto),
+            comments: comments_from_proto(char_literal.comments)?,
+            prefix: encoding_prefix_to_c(&char_literal.prefix)?,
+            value: char_literal.value,
+            spelling: char_literal.spelling,
         },
     )
 }
 
+fn encoding_prefix_to_c(prefix: &str) -> Result<c::literal::EncodingPrefix, String> {
+    c::literal::EncodingPrefix::parse(prefix)
+        .ok_or_else(|| format!("unknown encoding prefix {prefix}"))
+}
+
 fn compound_statement_to_c_object(
     compound_statement: proto::CompoundStatement,
 ) -> Result<c::language_object::statement_object::compound_statement::CompoundStatement, String> {
@@ -608,6 +1465,8 @@ fn compound_statement_to_c_object(
     Ok(
         c::language_object::statement_object::compound_statement::CompoundStatement {
             id,
+            span: compound_statement.span.map(span_from_proto),
+            comments: comments_from_proto(compound_statement.comments)?,
             code_block,
         },
     )
@@ -622,6 +1481,11 @@ fn compound_statement_object_to_c_object(statement: proto::CompoundStatementObje
                     declaration_to_c_object(declaration)?,
                 ),
             ),
+            proto::compound_statement_object::CompoundStatementObject::DeclarationList(declaration_list) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::DeclarationList(
+                    declaration_list_to_c_object(declaration_list)?,
+                ),
+            ),
             proto::compound_statement_object::CompoundStatementObject::AssignmentExpression(assignment_expression) => Ok(
                 c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::AssignmentExpression(
                     assignment_expression_to_c_object(assignment_expression)?,
@@ -632,6 +1496,61 @@ fn compound_statement_object_to_c_object(statement: proto::CompoundStatementObje
                     binary_expression_to_c_object(binary_expression)?,
                 ),
             ),
+            proto::compound_statement_object::CompoundStatementObject::UnaryExpression(unary_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UnaryExpression(
+                    unary_expression_to_c_object(unary_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::UpdateExpression(update_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UpdateExpression(
+                    update_expression_to_c_object(update_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::CastExpression(cast_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CastExpression(
+                    cast_expression_to_c_object(cast_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::SizeofExpression(sizeof_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SizeofExpression(
+                    sizeof_expression_to_c_object(sizeof_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::ConditionalExpression(conditional_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ConditionalExpression(
+                    conditional_expression_to_c_object(conditional_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::FieldExpression(field_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::FieldExpression(
+                    field_expression_to_c_object(field_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::SubscriptExpression(subscript_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SubscriptExpression(
+                    subscript_expression_to_c_object(subscript_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::ParenthesizedExpression(parenthesized_expression) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+                    parenthesized_expression_to_c_object(parenthesized_expression)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::PreprocDefine(preproc_define) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocDefine(
+                    preproc_define_to_c_object(preproc_define)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::PreprocCall(preproc_call) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocCall(
+                    preproc_call_to_c_object(preproc_call)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::PreprocIfStatement(preproc_if_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::PreprocIfStatement(
+                    preproc_if_statement_to_c_object(preproc_if_statement)?,
+                ),
+            ),
             proto::compound_statement_object::CompoundStatementObject::CallExpression(call_expression) => Ok(
                 c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CallExpression(
                     call_expression_to_c_object(call_expression)?,
@@ -652,6 +1571,11 @@ fn compound_statement_object_to_c_object(statement: proto::CompoundStatementObje
                     string_literal_to_c_object(string_literal)?,
                 ),
             ),
+            proto::compound_statement_object::CompoundStatementObject::CharLiteral(char_literal) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CharLiteral(
+                    char_literal_to_c_object(char_literal)?,
+                ),
+            ),
             proto::compound_statement_object::CompoundStatementObject::CompoundStatement(compound_statement) => Ok(
                 c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CompoundStatement(
                     compound_statement_to_c_object(compound_statement)?,
@@ -677,7 +1601,565 @@ fn compound_statement_object_to_c_object(statement: proto::CompoundStatementObje
                     unknown_to_c_object(unknown)?,
                 ),
             ),
+            proto::compound_statement_object::CompoundStatementObject::WhileStatement(while_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::WhileStatement(
+                    while_statement_to_c_object(*while_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::DoStatement(do_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::DoStatement(
+                    do_statement_to_c_object(*do_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::ForStatement(for_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ForStatement(
+                    for_statement_to_c_object(*for_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::SwitchStatement(switch_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::SwitchStatement(
+                    switch_statement_to_c_object(*switch_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::CaseStatement(case_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::CaseStatement(
+                    case_statement_to_c_object(case_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::BreakStatement(break_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::BreakStatement(
+                    break_statement_to_c_object(break_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::ContinueStatement(continue_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::ContinueStatement(
+                    continue_statement_to_c_object(continue_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::GotoStatement(goto_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::GotoStatement(
+                    goto_statement_to_c_object(goto_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::LabeledStatement(labeled_statement) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::LabeledStatement(
+                    labeled_statement_to_c_object(*labeled_statement)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::StructDefinition(struct_definition) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::StructDefinition(
+                    struct_definition_to_c_object(struct_definition)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::UnionDefinition(union_definition) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::UnionDefinition(
+                    union_definition_to_c_object(union_definition)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::EnumDefinition(enum_definition) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::EnumDefinition(
+                    enum_definition_to_c_object(enum_definition)?,
+                ),
+            ),
+            proto::compound_statement_object::CompoundStatementObject::TypeDefinition(type_definition) => Ok(
+                c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::TypeDefinition(
+                    type_definition_to_c_object(type_definition)?,
+                ),
+            ),
+        }
+}
+
+fn while_statement_to_c_object(
+    while_statement: proto::WhileStatement,
+) -> Result<c::language_object::statement_object::while_statement::WhileStatement, String> {
+    let id = Uuid::parse_str(&while_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let condition = expression_object_to_c_language_object(
+        while_statement
+            .condition
+            .ok_or("while statement without condition attribute")?,
+    )?;
+
+    let body = compound_statement_object_to_c_object(
+        *while_statement
+            .body
+            .ok_or("while statement without body attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::statement_object::while_statement::WhileStatement {
+            id,
+            span: while_statement.span.map(span_from_proto),
+            comments: comments_from_proto(while_statement.comments)?,
+            condition: Box::new(condition),
+            body: Box::new(body),
+        },
+    )
+}
+
+fn do_statement_to_c_object(
+    do_statement: proto::DoStatement,
+) -> Result<c::language_object::statement_object::do_statement::DoStatement, String> {
+    let id = Uuid::parse_str(&do_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let body = compound_statement_object_to_c_object(
+        *do_statement
+            .body
+            .ok_or("do statement without body attribute")?,
+    )?;
+
+    let condition = expression_object_to_c_language_object(
+        do_statement
+            .condition
+            .ok_or("do statement without condition attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::statement_object::do_statement::DoStatement {
+            id,
+            span: do_statement.span.map(span_from_proto),
+            comments: comments_from_proto(do_statement.comments)?,
+            body: Box::new(body),
+            condition: Box::new(condition),
+        },
+    )
+}
+
+fn for_statement_to_c_object(
+    for_statement: proto::ForStatement,
+) -> Result<c::language_object::statement_object::for_statement::ForStatement, String> {
+    let id = Uuid::parse_str(&for_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let initializer = match for_statement.initializer {
+        Some(initializer) => Some(Box::new(compound_statement_object_to_c_object(
+            *initializer,
+        )?)),
+        None => None,
+    };
+    let condition = match for_statement.condition {
+        Some(condition) => Some(Box::new(expression_object_to_c_language_object(condition)?)),
+        None => None,
+    };
+    let update = match for_statement.update {
+        Some(update) => Some(Box::new(expression_object_to_c_language_object(update)?)),
+        None => None,
+    };
+
+    let body = compound_statement_object_to_c_object(
+        *for_statement
+            .body
+            .ok_or("for statement without body attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::statement_object::for_statement::ForStatement {
+            id,
+            span: for_statement.span.map(span_from_proto),
+            comments: comments_from_proto(for_statement.comments)?,
+            initializer,
+            condition,
+            update,
+            body: Box::new(body),
+        },
+    )
+}
+
+fn switch_statement_to_c_object(
+    switch_statement: proto::SwitchStatement,
+) -> Result<c::language_object::statement_object::switch_statement::SwitchStatement, String> {
+    let id = Uuid::parse_str(&switch_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let condition = expression_object_to_c_language_object(
+        switch_statement
+            .condition
+            .ok_or("switch statement without condition attribute")?,
+    )?;
+
+    let body = compound_statement_object_to_c_object(
+        *switch_statement
+            .body
+            .ok_or("switch statement without body attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::statement_object::switch_statement::SwitchStatement {
+            id,
+            span: switch_statement.span.map(span_from_proto),
+            comments: comments_from_proto(switch_statement.comments)?,
+            condition: Box::new(condition),
+            body: Box::new(body),
+        },
+    )
+}
+
+fn case_statement_to_c_object(
+    case_statement: proto::CaseStatement,
+) -> Result<c::language_object::statement_object::case_statement::CaseStatement, String> {
+    let id = Uuid::parse_str(&case_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let value = match case_statement.value {
+        Some(value) => Some(Box::new(expression_object_to_c_language_object(value)?)),
+        None => None,
+    };
+
+    let mut body = Vec::new();
+    for object in case_statement.body {
+        body.push(compound_statement_object_to_c_object(object)?);
+    }
+
+    Ok(
+        c::language_object::statement_object::case_statement::CaseStatement {
+            id,
+            span: case_statement.span.map(span_from_proto),
+            comments: comments_from_proto(case_statement.comments)?,
+            value,
+            body,
+        },
+    )
+}
+
+fn break_statement_to_c_object(
+    break_statement: proto::BreakStatement,
+) -> Result<c::language_object::statement_object::break_statement::BreakStatement, String> {
+    let id = Uuid::parse_str(&break_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    Ok(
+        c::language_object::statement_object::break_statement::BreakStatement {
+            id,
+            span: break_statement.span.map(span_from_proto),
+            comments: comments_from_proto(break_statement.comments)?,
+        },
+    )
+}
+
+fn labeled_statement_to_c_object(
+    labeled_statement: proto::LabeledStatement,
+) -> Result<c::language_object::statement_object::labeled_statement::LabeledStatement, String> {
+    let id = Uuid::parse_str(&labeled_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let statement = match labeled_statement.statement {
+        Some(statement) => Some(Box::new(compound_statement_object_to_c_object(*statement)?)),
+        None => None,
+    };
+
+    Ok(
+        c::language_object::statement_object::labeled_statement::LabeledStatement {
+            id,
+            span: labeled_statement.span.map(span_from_proto),
+            comments: comments_from_proto(labeled_statement.comments)?,
+            label: labeled_statement.label,
+            statement,
+        },
+    )
+}
+
+fn goto_statement_to_c_object(
+    goto_statement: proto::GotoStatement,
+) -> Result<c::language_object::statement_object::goto_statement::GotoStatement, String> {
+    let id = Uuid::parse_str(&goto_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let label_id = Uuid::parse_str(&goto_statement.label_id)
+        .map_err(|_| "goto with unparsable label_id attribute".to_string())?;
+
+    Ok(
+        c::language_object::statement_object::goto_statement::GotoStatement {
+            id,
+            span: goto_statement.span.map(span_from_proto),
+            comments: comments_from_proto(goto_statement.comments)?,
+            label_id,
+            label: goto_statement.label,
+        },
+    )
+}
+
+fn continue_statement_to_c_object(
+    continue_statement: proto::ContinueStatement,
+) -> Result<c::language_object::statement_object::continue_statement::ContinueStatement, String> {
+    let id = Uuid::parse_str(&continue_statement.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    Ok(
+        c::language_object::statement_object::continue_statement::ContinueStatement {
+            id,
+            span: continue_statement.span.map(span_from_proto),
+            comments: comments_from_proto(continue_statement.comments)?,
+        },
+    )
+}
+
+fn struct_definition_to_c_object(
+    struct_definition: proto::StructDefinition,
+) -> Result<c::language_object::declaration_object::struct_definition::StructDefinition, String> {
+    let id = Uuid::parse_str(&struct_definition.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let fields = struct_definition
+        .fields
+        .into_iter()
+        .map(field_declaration_to_c_object)
+        .collect::<Result<_, _>>()?;
+
+    Ok(
+        c::language_object::declaration_object::struct_definition::StructDefinition {
+            id,
+            span: struct_definition.span.map(span_from_proto),
+            comments: comments_from_proto(struct_definition.comments)?,
+            identifier: struct_definition.identifier,
+            fields,
+            is_forward: struct_definition.is_forward,
+        },
+    )
+}
+
+fn union_definition_to_c_object(
+    union_definition: proto::UnionDefinition,
+) -> Result<c::language_object::declaration_object::union_definition::UnionDefinition, String> {
+    let id = Uuid::parse_str(&union_definition.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let fields = union_definition
+        .fields
+        .into_iter()
+        .map(field_declaration_to_c_object)
+        .collect::<Result<_, _>>()?;
+
+    Ok(
+        c::language_object::declaration_object::union_definition::UnionDefinition {
+            id,
+            span: union_definition.span.map(span_from_proto),
+            comments: comments_from_proto(union_definition.comments)?,
+            identifier: union_definition.identifier,
+            fields,
+            is_forward: union_definition.is_forward,
+        },
+    )
+}
+
+fn field_declaration_to_c_object(
+    field_declaration: proto::FieldDeclaration,
+) -> Result<
+    c::language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration,
+    String,
+> {
+    let id = Uuid::parse_str(&field_declaration.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let field_type = c_type_to_c_object(
+        field_declaration
+            .field_type
+            .ok_or("field declaration without field_type attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::declaration_object::struct_definition::field_declaration::FieldDeclaration {
+            id,
+            span: field_declaration.span.map(span_from_proto), comments: comments_from_proto(field_declaration.comments)?,
+            identifier: field_declaration.identifier,
+            field_type,
+            shares_declaration: field_declaration.shares_declaration,
+        },
+    )
+}
+
+fn enum_definition_to_c_object(
+    enum_definition: proto::EnumDefinition,
+) -> Result<c::language_object::declaration_object::enum_definition::EnumDefinition, String> {
+    let id = Uuid::parse_str(&enum_definition.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let enumerators = enum_definition
+        .enumerators
+        .into_iter()
+        .map(enumerator_to_c_object)
+        .collect::<Result<_, _>>()?;
+
+    Ok(
+        c::language_object::declaration_object::enum_definition::EnumDefinition {
+            id,
+            span: enum_definition.span.map(span_from_proto),
+            comments: comments_from_proto(enum_definition.comments)?,
+            identifier: enum_definition.identifier,
+            enumerators,
+            is_forward: enum_definition.is_forward,
+        },
+    )
+}
+
+fn type_definition_to_c_object(
+    type_definition: proto::TypeDefinition,
+) -> Result<c::language_object::declaration_object::type_definition::TypeDefinition, String> {
+    let id = Uuid::parse_str(&type_definition.id)
+        .map_err(|_| "object id could not be parsed".to_string())?;
+
+    let aliased_type = c_type_to_c_object(
+        type_definition
+            .aliased_type
+            .ok_or("type definition without aliased_type attribute")?,
+    )?;
+
+    Ok(
+        c::language_object::declaration_object::type_definition::TypeDefinition {
+            id,
+            span: type_definition.span.map(span_from_proto),
+            comments: comments_from_proto(type_definition.comments)?,
+            identifier: type_definition.identifier,
+            aliased_type,
+        },
+    )
+}
+
+fn enumerator_to_c_object(
+    enumerator: proto::Enumerator,
+) -> Result<c::language_object::declaration_object::enum_definition::enumerator::Enumerator, String>
+{
+    let id =
+        Uuid::parse_str(&enumerator.id).map_err(|_| "object id could not be parsed".to_string())?;
+
+    let value = enumerator
+        .value
+        .map(|value| proto_to_c_expression_object(value).map(Box::new))
+        .transpose()?;
+
+    Ok(
+        c::language_object::declaration_object::enum_definition::enumerator::Enumerator {
+            id,
+            span: enumerator.span.map(span_from_proto),
+            comments: comments_from_proto(enumerator.comments)?,
+            identifier: enumerator.identifier,
+            value,
+        },
+    )
+}
+
+fn c_type_to_c_object(c_type: proto::CType) -> Result<c::c_type::CType, String> {
+    let type_reference_to_c_object =
+        |reference: proto::TypeReference| -> Result<c::c_type::TypeReference, String> {
+            let declaration_id = Uuid::parse_str(&reference.declaration_id)
+                .map_err(|_| "type reference with unparsable declaration_id attribute")?;
+            Ok(c::c_type::TypeReference {
+                declaration_id,
+                identifier: reference.identifier,
+            })
+        };
+
+    match c_type.kind.ok_or("empty c type")? {
+        proto::c_type::Kind::Primitive(primitive) => c::c_type::CType::from_str(&primitive)
+            .ok_or(format!("unknown primitive type {primitive}")),
+        proto::c_type::Kind::StructType(reference) => Ok(c::c_type::CType::Struct(
+            type_reference_to_c_object(reference)?,
+        )),
+        proto::c_type::Kind::UnionType(reference) => Ok(c::c_type::CType::Union(
+            type_reference_to_c_object(reference)?,
+        )),
+        proto::c_type::Kind::EnumType(reference) => Ok(c::c_type::CType::Enum(
+            type_reference_to_c_object(reference)?,
+        )),
+        proto::c_type::Kind::TypedefName(name) => Ok(c::c_type::CType::Typedef(name)),
+        proto::c_type::Kind::Named(reference) => {
+            let reference = type_reference_to_c_object(reference)?;
+            Ok(c::c_type::CType::Named {
+                declaration_id: reference.declaration_id,
+                name: reference.identifier,
+            })
+        }
+        proto::c_type::Kind::Pointer(pointee) => Ok(c::c_type::CType::Pointer(Box::new(
+            c_type_to_c_object(*pointee)?,
+        ))),
+        proto::c_type::Kind::Array(array) => Ok(c::c_type::CType::Array(c::c_type::ArrayType {
+            element: Box::new(c_type_to_c_object(
+                *array
+                    .element
+                    .ok_or("array type without element attribute")?,
+            )?),
+            size: array.size,
+            qualifiers: c::c_type::Qualifiers {
+                is_const: array.is_const,
+                is_volatile: array.is_volatile,
+                is_restrict: array.is_restrict,
+            },
+            is_static: array.is_static,
+        })),
+        proto::c_type::Kind::Qualified(qualified) => Ok(c::c_type::CType::Qualified(
+            c::c_type::Qualifiers {
+                is_const: qualified.is_const,
+                is_volatile: qualified.is_volatile,
+                is_restrict: qualified.is_restrict,
+            },
+            Box::new(c_type_to_c_object(
+                *qualified
+                    .base
+                    .ok_or("qualified type without base attribute")?,
+            )?),
+        )),
+        proto::c_type::Kind::Function(fn_type) => {
+            let mut names = fn_type
+                .parameter_names
+                .into_iter()
+                .map(|name| Some(name).filter(|name| !name.is_empty()));
+            Ok(c::c_type::CType::Fn(c::c_type::FnType {
+                return_type: Box::new(c_type_to_c_object(
+                    *fn_type
+                        .return_type
+                        .ok_or("function type without return_type attribute")?,
+                )?),
+                parameters: fn_type
+                    .parameters
+                    .into_iter()
+                    .map(|parameter| {
+                        Ok::<_, String>(c::c_type::FnParameter {
+                            param_type: c_type_to_c_object(parameter)?,
+                            identifier: names.next().flatten(),
+                        })
+                    })
+                    .collect::<Result<_, _>>()?,
+                is_variadic: fn_type.is_variadic,
+                explicit_void: fn_type.explicit_void,
+            }))
         }
+    }
+}
+
+fn comments_from_proto(
+    comments: Option<proto::Comments>,
+) -> Result<c::language_object::special_object::comment::Comments, String> {
+    let Some(comments) = comments else {
+        return Ok(c::language_object::special_object::comment::Comments::default());
+    };
+    Ok(c::language_object::special_object::comment::Comments {
+        leading: comments
+            .leading
+            .into_iter()
+            .map(comment_to_c_object)
+            .collect::<Result<_, _>>()?,
+        trailing: comments
+            .trailing
+            .into_iter()
+            .map(comment_to_c_object)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn span_from_proto(span: proto::Span) -> Span {
+    Span {
+        start: Position {
+            line: span.start_line as usize,
+            column: span.start_column as usize,
+            offset: span.start_offset as usize,
+        },
+        end: Position {
+            line: span.end_line as usize,
+            column: span.end_column as usize,
+            offset: span.end_offset as usize,
+        },
+    }
 }
 
 #[cfg(test)]
@@ -695,6 +2177,7 @@ mod tests {
             declaration_object: Some(proto::declaration_object::DeclarationObject::Comment(
                 proto::Comment {
                     id: comment_id.to_string(),
+                    span: None,
                     content: content.to_string(),
                 },
             )),
@@ -703,6 +2186,7 @@ mod tests {
         let id = Uuid::new_v4();
         let src = proto::SourceFile {
             id: id.to_string(),
+            span: None,
             code: vec![comment],
         };
         let c_src = source_file_to_c_object(src).unwrap();
@@ -726,6 +2210,7 @@ mod tests {
                 proto::NumberLiteral {
                     id: number_id.to_string(),
                     value: number_value.to_string(),
+                    ..Default::default()
                 },
             )),
         };
@@ -735,19 +2220,35 @@ mod tests {
         let identifier = "test";
         let assignment = proto::AssignmentExpression {
             id: id.to_string(),
-            id_declaration: id_declaration.to_string(),
-            identifier: identifier.to_string(),
+            span: None,
+            comments: None,
+            left: Some(Box::new(proto::ExpressionObject {
+                expression_object: Some(proto::expression_object::ExpressionObject::Reference(
+                    proto::Reference {
+                        id: Uuid::new_v4().to_string(),
+                        declaration_id: id_declaration.to_string(),
+                        identifier: identifier.to_string(),
+                        span: None,
+                        comments: None,
+                    },
+                )),
+            })),
             value: Some(Box::new(number)),
         };
         let c_assign = assignment_expression_to_c_object(assignment).unwrap();
 
         assert_eq!(c_assign.id, id);
-        assert_eq!(c_assign.id_declaration, id_declaration);
-        assert_eq!(c_assign.identifier, identifier);
+        match *c_assign.left {
+            c::language_object::expression_object::ExpressionObject::Reference(ref reference) => {
+                assert_eq!(reference.declaration_id, id_declaration);
+                assert_eq!(reference.identifier, identifier);
+            }
+            _ => panic!("expected Reference"),
+        }
         match *c_assign.value {
             c::language_object::expression_object::ExpressionObject::NumberLiteral(ref c_num) => {
                 assert_eq!(c_num.id, number_id);
-                assert_eq!(c_num.value, number_value);
+                assert_eq!(c_num.spelling, number_value);
             }
             _ => panic!("expected NumberLiteral"),
         }
@@ -762,6 +2263,7 @@ mod tests {
                 proto::NumberLiteral {
                     id: left_id.to_string(),
                     value: left_value.to_string(),
+                    ..Default::default()
                 },
             )),
         };
@@ -773,6 +2275,7 @@ mod tests {
                 proto::NumberLiteral {
                     id: right_id.to_string(),
                     value: right_value.to_string(),
+                    ..Default::default()
                 },
             )),
         };
@@ -781,6 +2284,8 @@ mod tests {
         let operator = "+";
         let binary = proto::BinaryExpression {
             id: id.to_string(),
+            span: None,
+            comments: None,
             operator: operator.to_string(),
             left: Some(Box::new(left)),
             right: Some(Box::new(right)),
@@ -793,7 +2298,7 @@ mod tests {
         match *c_bin.left {
             c::language_object::expression_object::ExpressionObject::NumberLiteral(ref c_num) => {
                 assert_eq!(c_num.id, left_id);
-                assert_eq!(c_num.value, left_value);
+                assert_eq!(c_num.spelling, left_value);
             }
             _ => panic!("expected NumberLiteral"),
         }
@@ -801,7 +2306,7 @@ mod tests {
         match *c_bin.right {
             c::language_object::expression_object::ExpressionObject::NumberLiteral(ref c_num) => {
                 assert_eq!(c_num.id, right_id);
-                assert_eq!(c_num.value, right_value);
+                assert_eq!(c_num.spelling, right_value);
             }
             _ => panic!("expected NumberLiteral"),
         }
@@ -816,6 +2321,8 @@ mod tests {
             expression_object: Some(proto::expression_object::ExpressionObject::Reference(
                 proto::Reference {
                     id: arg_id.to_string(),
+                    span: None,
+                    comments: None,
                     declaration_id: declaration_id.to_string(),
                     identifier: arg_identifier.to_string(),
                 },
@@ -827,6 +2334,8 @@ mod tests {
         let identifier = "foo";
         let call = proto::CallExpression {
             id: id.to_string(),
+            span: None,
+            comments: None,
             id_declaration: id_declaration.to_string(),
             identifier: identifier.to_string(),
             argument_list: vec![arg],
@@ -853,6 +2362,7 @@ mod tests {
         let content = "test";
         let proto_comment = proto::Comment {
             id: id.to_string(),
+            span: None,
             content: content.to_string(),
         };
         let c_comment = comment_to_c_object(proto_comment).unwrap();
@@ -868,6 +2378,7 @@ mod tests {
         let number = proto::NumberLiteral {
             id: number_id.to_string(),
             value: number_value.to_string(),
+            ..Default::default()
         };
 
         let id = Uuid::new_v4();
@@ -875,7 +2386,12 @@ mod tests {
         let identifier = "test";
         let decl = proto::Declaration {
             id: id.to_string(),
-            primitive_type: primitive_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            primitive_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(primitive_type.to_string())),
+            }),
             identifier: identifier.to_string(),
             value: Some(proto::ExpressionObject {
                 expression_object: Some(proto::expression_object::ExpressionObject::NumberLiteral(
@@ -892,7 +2408,7 @@ mod tests {
             match inner.as_ref() {
                 c::language_object::expression_object::ExpressionObject::NumberLiteral(number) => {
                     assert_eq!(number.id, number_id);
-                    assert_eq!(number.value, number_value);
+                    assert_eq!(number.spelling, number_value);
                 }
                 _ => panic!("expected NumberLiteral"),
             }
@@ -907,6 +2423,7 @@ mod tests {
         let content = "test";
         let comment = proto::Comment {
             id: comment_id.to_string(),
+            span: None,
             content: content.to_string(),
         };
 
@@ -921,6 +2438,8 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::CompoundStatement(
                     proto::CompoundStatement {
                         id: stmt_id.to_string(),
+                        span: None,
+                        comments: None,
                         code_block: vec![stmt_comment],
                     },
                 ),
@@ -930,6 +2449,8 @@ mod tests {
         let id = Uuid::new_v4();
         let else_clause = proto::ElseClause {
             id: id.to_string(),
+            span: None,
+            comments: None,
             body: Some(Box::new(comp_stmt)),
         };
         let c_else = else_clause_to_c_object(else_clause).unwrap();
@@ -957,7 +2478,11 @@ mod tests {
         let param_identifier = "test";
         let param = proto::FunctionParameter {
             id: param_id.to_string(),
-            param_type: param_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            param_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(param_type.to_string())),
+            }),
             identifier: param_identifier.to_string(),
         };
 
@@ -966,9 +2491,16 @@ mod tests {
         let identifier = "foo";
         let decl = proto::FunctionDeclaration {
             id: id.to_string(),
-            return_type: return_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            return_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(return_type.to_string())),
+            }),
             identifier: identifier.to_string(),
             parameter_list: vec![param],
+            is_variadic: false,
+            explicit_void: false,
         };
         let c_decl = function_declaration_to_c_object(decl).unwrap();
 
@@ -990,6 +2522,7 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::Comment(
                     proto::Comment {
                         id: comment_id.to_string(),
+                        span: None,
                         content: content.to_string(),
                     },
                 ),
@@ -997,6 +2530,8 @@ mod tests {
         };
         let compound = proto::CompoundStatement {
             id: body_id.to_string(),
+            span: None,
+            comments: None,
             code_block: vec![comment],
         };
 
@@ -1005,7 +2540,11 @@ mod tests {
         let param_identifier = "test";
         let param = proto::FunctionParameter {
             id: param_id.to_string(),
-            param_type: param_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            param_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(param_type.to_string())),
+            }),
             identifier: param_identifier.to_string(),
         };
 
@@ -1014,9 +2553,16 @@ mod tests {
         let identifier = "foo";
         let def = proto::FunctionDefinition {
             id: id.to_string(),
-            return_type: return_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            return_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(return_type.to_string())),
+            }),
             identifier: identifier.to_string(),
             parameter_list: vec![param],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: Some(compound),
         };
         let c_def = function_definition_to_c_object(def).unwrap();
@@ -1041,7 +2587,11 @@ mod tests {
         let identifier = "bar";
         let param = proto::FunctionParameter {
             id: id.to_string(),
-            param_type: param_type.as_str().to_string(),
+            span: None,
+            comments: None,
+            param_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(param_type.to_string())),
+            }),
             identifier: identifier.to_string(),
         };
         let c_param = function_parameter_to_c_object(param).unwrap();
@@ -1060,6 +2610,7 @@ mod tests {
                 proto::NumberLiteral {
                     id: cond_id.to_string(),
                     value: cond_val.to_string(),
+                    ..Default::default()
                 },
             )),
         };
@@ -1072,6 +2623,7 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::Comment(
                     proto::Comment {
                         id: then_comment_id.to_string(),
+                        span: None,
                         content: then_comment_content.to_string(),
                     },
                 ),
@@ -1082,6 +2634,8 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::CompoundStatement(
                     proto::CompoundStatement {
                         id: then_id.to_string(),
+                        span: None,
+                        comments: None,
                         code_block: vec![then_comment],
                     },
                 ),
@@ -1096,6 +2650,7 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::Comment(
                     proto::Comment {
                         id: else_comment_id.to_string(),
+                        span: None,
                         content: else_comment_content.to_string(),
                     },
                 ),
@@ -1106,6 +2661,8 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::CompoundStatement(
                     proto::CompoundStatement {
                         id: else_id.to_string(),
+                        span: None,
+                        comments: None,
                         code_block: vec![else_comment],
                     },
                 ),
@@ -1113,12 +2670,16 @@ mod tests {
         };
         let else_clause = proto::ElseClause {
             id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
             body: Some(Box::new(else_compound)),
         };
 
         let id = Uuid::new_v4();
         let if_stmt = proto::IfStatement {
             id: id.to_string(),
+            span: None,
+            comments: None,
             condition: Some(condition),
             body: Some(Box::new(then_stmt)),
             else_statement: Some(proto::if_statement::ElseStatement::ElseClause(Box::new(
@@ -1131,7 +2692,7 @@ mod tests {
         match *c_if.condition {
             c::language_object::expression_object::ExpressionObject::NumberLiteral(ref c_num) => {
                 assert_eq!(c_num.id, cond_id);
-                assert_eq!(c_num.value, cond_val);
+                assert_eq!(c_num.spelling, cond_val);
             }
             _ => panic!("expected NumberLiteral"),
         }
@@ -1176,11 +2737,12 @@ mod tests {
         let num = proto::NumberLiteral {
             id: id.to_string(),
             value: value.to_string(),
+            ..Default::default()
         };
         let c_num = number_literal_to_c_object(num).unwrap();
 
         assert_eq!(c_num.id, id);
-        assert_eq!(c_num.value, value);
+        assert_eq!(c_num.spelling, value);
     }
 
     #[test]
@@ -1189,6 +2751,8 @@ mod tests {
         let directive = "stdio.h";
         let incl = proto::PreprocInclude {
             id: id.to_string(),
+            span: None,
+            comments: None,
             content: directive.to_string(),
         };
         let c_incl = preproc_include_to_c_object(incl).unwrap();
@@ -1204,6 +2768,8 @@ mod tests {
         let identifier = "foo";
         let proto_ref = proto::Reference {
             id: id.to_string(),
+            span: None,
+            comments: None,
             declaration_id: decl_id.to_string(),
             identifier: identifier.to_string(),
         };
@@ -1223,6 +2789,7 @@ mod tests {
                 proto::NumberLiteral {
                     id: num_id.to_string(),
                     value: val.to_string(),
+                    ..Default::default()
                 },
             )),
         };
@@ -1230,6 +2797,8 @@ mod tests {
         let id = Uuid::new_v4();
         let proto_ret = proto::ReturnStatement {
             id: id.to_string(),
+            span: None,
+            comments: None,
             value: Some(ret_expr),
         };
         let c_ret = return_statement_to_c_object(proto_ret).unwrap();
@@ -1238,7 +2807,7 @@ mod tests {
         match c_ret.value.unwrap() {
             c::language_object::expression_object::ExpressionObject::NumberLiteral(ref c_num) => {
                 assert_eq!(c_num.id, num_id);
-                assert_eq!(c_num.value, val);
+                assert_eq!(c_num.spelling, val);
             }
             _ => panic!("expected NumberLiteral"),
         }
@@ -1250,7 +2819,11 @@ mod tests {
         let val = "hello";
         let proto_str = proto::StringLiteral {
             id: id.to_string(),
+            span: None,
+            comments: None,
             value: val.to_string(),
+            prefix: String::new(),
+            segments: vec![],
         };
         let c_str = string_literal_to_c_object(proto_str).unwrap();
 
@@ -1267,6 +2840,7 @@ mod tests {
                 proto::compound_statement_object::CompoundStatementObject::Comment(
                     proto::Comment {
                         id: comment_id.to_string(),
+                        span: None,
                         content: content.to_string(),
                     },
                 ),
@@ -1276,6 +2850,8 @@ mod tests {
         let id = Uuid::new_v4();
         let comp = proto::CompoundStatement {
             id: id.to_string(),
+            span: None,
+            comments: None,
             code_block: vec![comment],
         };
         let c_comp = compound_statement_to_c_object(comp).unwrap();
@@ -1291,4 +2867,784 @@ mod tests {
             _ => panic!("expected Comment"),
         }
     }
+
+    #[test]
+    fn test_18_struct_definition() {
+        let field_id = Uuid::new_v4();
+        let field = proto::FieldDeclaration {
+            id: field_id.to_string(),
+            span: None,
+            comments: None,
+            identifier: "x".to_string(),
+            field_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive("int".to_string())),
+            }),
+            shares_declaration: false,
+        };
+
+        let id = Uuid::new_v4();
+        let def = proto::StructDefinition {
+            id: id.to_string(),
+            span: None,
+            comments: None,
+            identifier: "Point".to_string(),
+            fields: vec![field],
+            is_forward: false,
+        };
+        let c_def = struct_definition_to_c_object(def).unwrap();
+
+        assert_eq!(c_def.id, id);
+        assert_eq!(c_def.identifier, "Point");
+        assert_eq!(c_def.fields[0].id, field_id);
+        assert_eq!(c_def.fields[0].field_type, c::c_type::CType::Int);
+
+        let declaration_id = Uuid::new_v4();
+        let c_type = c_type_to_c_object(proto::CType {
+            kind: Some(proto::c_type::Kind::StructType(proto::TypeReference {
+                declaration_id: declaration_id.to_string(),
+                identifier: "Point".to_string(),
+            })),
+        })
+        .unwrap();
+        assert_eq!(
+            c_type,
+            c::c_type::CType::Struct(c::c_type::TypeReference {
+                declaration_id,
+                identifier: "Point".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_19_derived_types() {
+        let proto_type = proto::CType {
+            kind: Some(proto::c_type::Kind::Array(Box::new(proto::ArrayType {
+                element: Some(Box::new(proto::CType {
+                    kind: Some(proto::c_type::Kind::Pointer(Box::new(proto::CType {
+                        kind: Some(proto::c_type::Kind::Qualified(Box::new(
+                            proto::QualifiedType {
+                                is_const: true,
+                                is_volatile: false,
+                                is_restrict: false,
+                                base: Some(Box::new(proto::CType {
+                                    kind: Some(proto::c_type::Kind::Primitive(
+                                        "unsigned char".to_string(),
+                                    )),
+                                })),
+                            },
+                        ))),
+                    }))),
+                })),
+                size: Some("MAX_NAMES".to_string()),
+                ..Default::default()
+            }))),
+        };
+        let c_type = c_type_to_c_object(proto_type).unwrap();
+
+        assert_eq!(
+            c_type.declaration("names"),
+            "const unsigned char *names[MAX_NAMES]"
+        );
+    }
+
+    #[test]
+    fn test_20_function_pointer_type() {
+        let int_type = proto::CType {
+            kind: Some(proto::c_type::Kind::Primitive("int".to_string())),
+        };
+        let proto_type = proto::CType {
+            kind: Some(proto::c_type::Kind::Pointer(Box::new(proto::CType {
+                kind: Some(proto::c_type::Kind::Function(Box::new(proto::FnType {
+                    return_type: Some(Box::new(int_type.clone())),
+                    parameters: vec![
+                        int_type,
+                        proto::CType {
+                            kind: Some(proto::c_type::Kind::Primitive("char".to_string())),
+                        },
+                    ],
+                    parameter_names: vec!["count".to_string(), String::new()],
+                    is_variadic: true,
+                    explicit_void: false,
+                }))),
+            }))),
+        };
+        let c_type = c_type_to_c_object(proto_type).unwrap();
+
+        assert_eq!(c_type.declaration("cb"), "int (*cb)(int count, char, ...)");
+    }
+
+    #[test]
+    fn test_21_unary_cast_and_sizeof_expressions() {
+        let reference_id = Uuid::new_v4();
+        let reference = proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::Reference(
+                proto::Reference {
+                    id: reference_id.to_string(),
+                    span: None,
+                    comments: None,
+                    declaration_id: Uuid::new_v4().to_string(),
+                    identifier: "value".to_string(),
+                },
+            )),
+        };
+
+        let unary_id = Uuid::new_v4();
+        let unary = proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::UnaryExpression(
+                Box::new(proto::UnaryExpression {
+                    id: unary_id.to_string(),
+                    span: None,
+                    comments: None,
+                    operator: "-".to_string(),
+                    argument: Some(Box::new(reference)),
+                }),
+            )),
+        };
+        let cast = proto::CastExpression {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            cast_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive("double".to_string())),
+            }),
+            value: Some(Box::new(unary)),
+        };
+        let c_cast = cast_expression_to_c_object(cast).unwrap();
+
+        assert_eq!(c_cast.cast_type, c::c_type::CType::Double);
+        match *c_cast.value {
+            c::language_object::expression_object::ExpressionObject::UnaryExpression(
+                ref c_unary,
+            ) => {
+                assert_eq!(c_unary.id, unary_id);
+                assert_eq!(c_unary.operator, "-");
+                assert_eq!(c_unary.argument.as_language_object().id(), reference_id);
+            }
+            _ => panic!("expected UnaryExpression"),
+        }
+
+        let sizeof = proto::SizeofExpression {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            argument_type: None,
+            value: None,
+        };
+        assert!(sizeof_expression_to_c_object(sizeof).is_err());
+    }
+
+    #[test]
+    fn test_22_field_subscript_and_parenthesized_expressions() {
+        let member_id = Uuid::new_v4();
+        let reference = |identifier: &str| proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::Reference(
+                proto::Reference {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    declaration_id: Uuid::new_v4().to_string(),
+                    identifier: identifier.to_string(),
+                },
+            )),
+        };
+
+        let subscript = proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::SubscriptExpression(Box::new(
+                    proto::SubscriptExpression {
+                        id: Uuid::new_v4().to_string(),
+                        span: None,
+                        comments: None,
+                        argument: Some(Box::new(reference("nodes"))),
+                        index: Some(Box::new(reference("i"))),
+                    },
+                )),
+            ),
+        };
+        let parenthesized = proto::ExpressionObject {
+            expression_object: Some(
+                proto::expression_object::ExpressionObject::ParenthesizedExpression(Box::new(
+                    proto::ParenthesizedExpression {
+                        id: Uuid::new_v4().to_string(),
+                        span: None,
+                        comments: None,
+                        value: Some(Box::new(subscript)),
+                    },
+                )),
+            ),
+        };
+        let field = proto::FieldExpression {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            argument: Some(Box::new(parenthesized)),
+            operator: "->".to_string(),
+            declaration_id: member_id.to_string(),
+            identifier: "next".to_string(),
+        };
+        let c_field = field_expression_to_c_object(field).unwrap();
+
+        assert_eq!(c_field.operator, "->");
+        assert_eq!(c_field.declaration_id, member_id);
+        assert_eq!(c_field.identifier, "next");
+        let c::language_object::expression_object::ExpressionObject::ParenthesizedExpression(
+            c_parenthesized,
+        ) = *c_field.argument
+        else {
+            panic!("expected ParenthesizedExpression");
+        };
+        assert!(matches!(
+            *c_parenthesized.value,
+            c::language_object::expression_object::ExpressionObject::SubscriptExpression(_)
+        ));
+
+        let subscript = proto::SubscriptExpression {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            argument: Some(Box::new(reference("nodes"))),
+            index: None,
+        };
+        assert!(subscript_expression_to_c_object(subscript).is_err());
+    }
+
+    #[test]
+    fn test_23_preprocessor_conditional() {
+        let define = proto::DeclarationObject {
+            declaration_object: Some(proto::declaration_object::DeclarationObject::PreprocDefine(
+                proto::PreprocDefine {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    identifier: "SQUARE".to_string(),
+                    function_like: true,
+                    parameters: vec!["x".to_string()],
+                    value: "((x) * (x))".to_string(),
+                },
+            )),
+        };
+        let pragma = proto::DeclarationObject {
+            declaration_object: Some(proto::declaration_object::DeclarationObject::PreprocCall(
+                proto::PreprocCall {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    directive: "#pragma".to_string(),
+                    argument: "once".to_string(),
+                },
+            )),
+        };
+        let preproc_if = proto::PreprocIf {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            directive: "#ifdef".to_string(),
+            condition: "DEBUG".to_string(),
+            body: vec![define],
+            alternative: Some(Box::new(proto::PreprocIf {
+                id: Uuid::new_v4().to_string(),
+                span: None,
+                comments: None,
+                directive: "#else".to_string(),
+                condition: String::new(),
+                body: vec![pragma],
+                alternative: None,
+            })),
+        };
+        let c_preproc_if = preproc_if_to_c_object(preproc_if).unwrap();
+
+        assert_eq!(c_preproc_if.directive, "#ifdef");
+        assert_eq!(c_preproc_if.condition, "DEBUG");
+        let c::language_object::declaration_object::DeclarationObject::PreprocDefine(c_define) =
+            &c_preproc_if.body[0]
+        else {
+            panic!("expected PreprocDefine");
+        };
+        assert_eq!(c_define.parameters, Some(vec!["x".to_string()]));
+        let alternative = c_preproc_if.alternative.expect("expected #else branch");
+        assert_eq!(alternative.directive, "#else");
+        assert!(matches!(
+            alternative.body[0],
+            c::language_object::declaration_object::DeclarationObject::PreprocCall(_)
+        ));
+
+        let define = proto::PreprocDefine {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            identifier: "LIMIT".to_string(),
+            function_like: false,
+            parameters: vec![],
+            value: "10".to_string(),
+        };
+        assert_eq!(preproc_define_to_c_object(define).unwrap().parameters, None);
+    }
+
+    #[test]
+    fn test_24_declaration_list_with_initializer_list() {
+        let number = |value: &str| proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::NumberLiteral(
+                proto::NumberLiteral {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    value: value.to_string(),
+                    number: Some(proto::number_literal::Number::Integer(
+                        value.parse().unwrap(),
+                    )),
+                    radix: 10,
+                    suffix: String::new(),
+                },
+            )),
+        };
+        let int_type = || proto::CType {
+            kind: Some(proto::c_type::Kind::Primitive("int".to_string())),
+        };
+        let designated = proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::InitializerPair(
+                Box::new(proto::InitializerPair {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    designators: vec![proto::Designator {
+                        kind: Some(proto::designator::Kind::Index(Box::new(number("2")))),
+                    }],
+                    value: Some(Box::new(number("5"))),
+                }),
+            )),
+        };
+        let initializer_list = proto::ExpressionObject {
+            expression_object: Some(proto::expression_object::ExpressionObject::InitializerList(
+                Box::new(proto::InitializerList {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    elements: vec![number("1"), designated],
+                }),
+            )),
+        };
+        let declaration_list = proto::DeclarationList {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            declarations: vec![
+                proto::Declaration {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    primitive_type: Some(int_type()),
+                    identifier: "a".to_string(),
+                    value: Some(number("1")),
+                    specifiers: vec![],
+                },
+                proto::Declaration {
+                    id: Uuid::new_v4().to_string(),
+                    span: None,
+                    comments: None,
+                    primitive_type: Some(proto::CType {
+                        kind: Some(proto::c_type::Kind::Array(Box::new(proto::ArrayType {
+                            element: Some(Box::new(int_type())),
+                            size: Some("3".to_string()),
+                            ..Default::default()
+                        }))),
+                    }),
+                    identifier: "c".to_string(),
+                    value: Some(initializer_list),
+                    specifiers: vec![],
+                },
+            ],
+        };
+        let c_declaration_list = declaration_list_to_c_object(declaration_list.clone()).unwrap();
+
+        let [a, c] = c_declaration_list.declarations.as_slice() else {
+            panic!("expected two declarations");
+        };
+        assert_eq!(a.identifier, "a");
+        let Some(c::language_object::expression_object::ExpressionObject::InitializerList(list)) =
+            c.value.as_deref()
+        else {
+            panic!("expected InitializerList");
+        };
+        let c::language_object::expression_object::ExpressionObject::InitializerPair(pair) =
+            &list.elements[1]
+        else {
+            panic!("expected InitializerPair");
+        };
+        assert!(matches!(
+            pair.designators.as_slice(),
+            [c::language_object::expression_object::initializer_pair::Designator::Subscript(_)]
+        ));
+
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::DeclarationList(c_declaration_list)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::DeclarationList(
+                    declaration_list
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn test_25_char_and_string_literals() {
+        let char_literal = proto::CharLiteral {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            value: "\n".to_string(),
+            prefix: "L".to_string(),
+            spelling: r"L'\n'".to_string(),
+        };
+        let c_char = char_literal_to_c_object(char_literal.clone()).unwrap();
+        assert_eq!(c_char.prefix, c::literal::EncodingPrefix::Wide);
+        assert!(c_char.spelling_matches());
+
+        let string_literal = proto::StringLiteral {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            value: "ab\t".to_string(),
+            prefix: "u8".to_string(),
+            segments: vec![r#"u8"a""#.to_string(), r#""b\t""#.to_string()],
+        };
+        let c_string = string_literal_to_c_object(string_literal.clone()).unwrap();
+        assert_eq!(c_string.prefix, c::literal::EncodingPrefix::Utf8);
+        assert!(c_string.segments_match());
+
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::CharLiteral(c_char)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::CharLiteral(
+                    char_literal
+                )),
+            }
+        );
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::StringLiteral(c_string)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::StringLiteral(
+                    string_literal
+                )),
+            }
+        );
+
+        let unknown_prefix = proto::StringLiteral {
+            prefix: "X".to_string(),
+            ..Default::default()
+        };
+        assert!(string_literal_to_c_object(unknown_prefix).is_err());
+    }
+
+    #[test]
+    fn test_26_number_literal() {
+        let hexadecimal = proto::NumberLiteral {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            value: "0x1Fu".to_string(),
+            number: Some(proto::number_literal::Number::Integer(31)),
+            radix: 16,
+            suffix: "u".to_string(),
+        };
+        let c_hexadecimal = number_literal_to_c_object(hexadecimal.clone()).unwrap();
+        assert_eq!(
+            c_hexadecimal.value,
+            c::language_object::expression_object::number_literal::NumberValue::Integer(31)
+        );
+        assert!(c_hexadecimal.spelling_matches());
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::NumberLiteral(c_hexadecimal)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::NumberLiteral(
+                    hexadecimal
+                )),
+            }
+        );
+
+        // Without a parsed number the spelling is parsed instead
+        let spelling_only = proto::NumberLiteral {
+            id: Uuid::new_v4().to_string(),
+            value: "1.5e3f".to_string(),
+            ..Default::default()
+        };
+        let c_float = number_literal_to_c_object(spelling_only).unwrap();
+        assert_eq!(
+            c_float.value,
+            c::language_object::expression_object::number_literal::NumberValue::Float(1500.0)
+        );
+        assert_eq!(c_float.suffix, "f");
+
+        let invalid_radix = proto::NumberLiteral {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            value: "1".to_string(),
+            number: Some(proto::number_literal::Number::Integer(1)),
+            radix: 3,
+            suffix: String::new(),
+        };
+        assert!(number_literal_to_c_object(invalid_radix).is_err());
+    }
+
+    #[test]
+    fn test_27_labeled_and_goto_statements() {
+        let label_id = Uuid::new_v4().to_string();
+        let goto = proto::GotoStatement {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            label_id: label_id.clone(),
+            label: "retry".to_string(),
+        };
+        let labeled = proto::LabeledStatement {
+            id: label_id.clone(),
+            span: None,
+            comments: None,
+            label: "retry".to_string(),
+            statement: Some(Box::new(proto::CompoundStatementObject {
+                compound_statement_object: Some(
+                    proto::compound_statement_object::CompoundStatementObject::GotoStatement(
+                        goto.clone(),
+                    ),
+                ),
+            })),
+        };
+
+        let c_labeled = labeled_statement_to_c_object(labeled.clone()).unwrap();
+        assert_eq!(c_labeled.id.to_string(), label_id);
+        let Some(statement) = c_labeled.statement.as_deref() else {
+            panic!("expected a labeled statement");
+        };
+        let c::language_object::statement_object::compound_statement::compound_statement_object::CompoundStatementObject::GotoStatement(c_goto) = statement else {
+            panic!("expected a goto statement, got {statement:?}");
+        };
+        assert_eq!(c_goto.label_id, c_labeled.id);
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::LabeledStatement(c_labeled)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::LabeledStatement(
+                    labeled
+                )),
+            }
+        );
+
+        let empty = proto::LabeledStatement {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            label: "done".to_string(),
+            statement: None,
+        };
+        assert!(
+            labeled_statement_to_c_object(empty)
+                .unwrap()
+                .statement
+                .is_none()
+        );
+
+        let invalid_label_id = proto::GotoStatement {
+            label_id: "retry".to_string(),
+            ..goto
+        };
+        assert!(goto_statement_to_c_object(invalid_label_id).is_err());
+    }
+
+    #[test]
+    fn test_28_variadic_and_void_parameter_lists() {
+        let int_type = || proto::CType {
+            kind: Some(proto::c_type::Kind::Primitive(
+                c::c_type::CType::Int.to_string(),
+            )),
+        };
+        let variadic = proto::FunctionDeclaration {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            return_type: Some(int_type()),
+            identifier: "sum".to_string(),
+            parameter_list: vec![proto::FunctionParameter {
+                id: Uuid::new_v4().to_string(),
+                span: None,
+                comments: None,
+                identifier: "count".to_string(),
+                param_type: Some(int_type()),
+            }],
+            is_variadic: true,
+            explicit_void: false,
+        };
+
+        let c_variadic = function_declaration_to_c_object(variadic.clone()).unwrap();
+        assert!(c_variadic.is_variadic);
+        assert!(!c_variadic.explicit_void);
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::FunctionDeclaration(c_variadic)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::FunctionDeclaration(
+                    variadic
+                )),
+            }
+        );
+
+        let explicit_void = proto::FunctionDefinition {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            return_type: Some(int_type()),
+            identifier: "main".to_string(),
+            parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: true,
+            compound_statement: Some(proto::CompoundStatement {
+                id: Uuid::new_v4().to_string(),
+                span: None,
+                comments: None,
+                code_block: vec![],
+            }),
+        };
+
+        let c_explicit_void = function_definition_to_c_object(explicit_void.clone()).unwrap();
+        assert!(!c_explicit_void.is_variadic);
+        assert!(c_explicit_void.explicit_void);
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::FunctionDefinition(c_explicit_void)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::FunctionDefinition(
+                    explicit_void
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn test_29_type_definition_and_named_type() {
+        let type_definition = proto::TypeDefinition {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            identifier: "handle_t".to_string(),
+            aliased_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Primitive(
+                    c::c_type::CType::Int.to_string(),
+                )),
+            }),
+        };
+        let declaration = proto::Declaration {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+            specifiers: vec![],
+            primitive_type: Some(proto::CType {
+                kind: Some(proto::c_type::Kind::Named(proto::TypeReference {
+                    declaration_id: type_definition.id.clone(),
+                    identifier: type_definition.identifier.clone(),
+                })),
+            }),
+            identifier: "handle".to_string(),
+            value: None,
+        };
+
+        let c_type_definition = type_definition_to_c_object(type_definition.clone()).unwrap();
+        assert_eq!(c_type_definition.identifier, "handle_t");
+        assert_eq!(c_type_definition.aliased_type, c::c_type::CType::Int);
+
+        let c_declaration = declaration_to_c_object(declaration.clone()).unwrap();
+        assert_eq!(
+            c_declaration.primitive_type,
+            c::c_type::CType::Named {
+                declaration_id: c_type_definition.id,
+                name: "handle_t".to_string(),
+            }
+        );
+
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::TypeDefinition(c_type_definition)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::TypeDefinition(
+                    type_definition
+                )),
+            }
+        );
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::Declaration(c_declaration)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::Declaration(
+                    declaration
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn test_30_source_span() {
+        let span = proto::Span {
+            start_line: 2,
+            start_column: 4,
+            end_line: 2,
+            end_column: 13,
+            start_offset: 30,
+            end_offset: 39,
+        };
+        let return_statement = proto::ReturnStatement {
+            id: Uuid::new_v4().to_string(),
+            span: Some(span.clone()),
+            comments: None,
+            value: None,
+        };
+        let break_statement = proto::BreakStatement {
+            id: Uuid::new_v4().to_string(),
+            span: None,
+            comments: None,
+        };
+
+        let c_return_statement = return_statement_to_c_object(return_statement.clone()).unwrap();
+        let c_span = c_return_statement.span.unwrap();
+        assert_eq!((c_span.start.line, c_span.start.column), (2, 4));
+        assert_eq!((c_span.end.line, c_span.end.column), (2, 13));
+        assert_eq!(c_span.range(), 30..39);
+
+        // Objects created from an editor have no source position
+        let c_break_statement = break_statement_to_c_object(break_statement.clone()).unwrap();
+        assert_eq!(c_break_statement.span, None);
+
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::ReturnStatement(c_return_statement)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::ReturnStatement(
+                    return_statement
+                )),
+            }
+        );
+        assert_eq!(
+            crate::lenga_service::clenga::node_parser::c_language_object_to_proto(
+                c::language_object::LanguageObject::BreakStatement(c_break_statement)
+            ),
+            proto::LanguageObject {
+                language_object: Some(proto::language_object::LanguageObject::BreakStatement(
+                    break_statement
+                )),
+            }
+        );
+    }
 }
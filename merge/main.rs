@@ -7,7 +7,10 @@ use std::{
     path::Path,
 };
 
-use lenga::language::{Language, c::C};
+use lenga::{
+    language::{Language, c::C},
+    node::encoding::{self, Encoding},
+};
 
 use crate::merger::Merger;
 
@@ -36,8 +39,8 @@ fn main() -> io::Result<()> {
     BufReader::new(&mut file_origin)
         .read_to_end(&mut content_origin)
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
-    let src_file_origin = c
-        .parse_nodes(content_origin)
+    let src_file_origin = to_bincode(&content_origin)
+        .and_then(|nodes| c.parse_nodes(nodes))
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
 
     let mut file_ours = File::open(&path_ours)?;
@@ -45,8 +48,8 @@ fn main() -> io::Result<()> {
     BufReader::new(&mut file_ours)
         .read_to_end(&mut content_ours)
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
-    let src_file_ours = c
-        .parse_nodes(content_ours)
+    let src_file_ours = to_bincode(&content_ours)
+        .and_then(|nodes| c.parse_nodes(nodes))
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
 
     let mut file_theirs = File::open(&path_theirs)?;
@@ -54,8 +57,8 @@ fn main() -> io::Result<()> {
     BufReader::new(&mut file_theirs)
         .read_to_end(&mut content_theirs)
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
-    let src_file_theirs = c
-        .parse_nodes(content_theirs)
+    let src_file_theirs = to_bincode(&content_theirs)
+        .and_then(|nodes| c.parse_nodes(nodes))
         .map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
 
     let merger = Merger::new();
@@ -65,8 +68,19 @@ fn main() -> io::Result<()> {
         .map_err(io::Error::other)?;
 
     let merged_data = c.write_to_nodes(merged_file).map_err(io::Error::other)?;
+    // Written back in the encoding of our side, so text encoded files stay reviewable
+    let merged_data = encoding::convert(
+        &merged_data,
+        Encoding::Bincode,
+        Encoding::detect(&content_ours),
+    )
+    .map_err(io::Error::other)?;
 
     fs::write(Path::new(&path_ours), merged_data)?;
 
     Ok(())
 }
+
+fn to_bincode(content: &[u8]) -> Result<Vec<u8>, String> {
+    encoding::convert(content, Encoding::detect(content), Encoding::Bincode)
+}
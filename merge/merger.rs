@@ -1,6 +1,9 @@
-use lenga::language::c::language_object::{
-    declaration_object, expression_object, special_object,
-    statement_object::{self, compound_statement::compound_statement_object},
+use lenga::language::c::{
+    language_object::{
+        declaration_object, expression_object, special_object,
+        statement_object::{self, compound_statement::compound_statement_object},
+    },
+    specifiers::Specifiers,
 };
 
 pub struct Merger {}
@@ -24,7 +27,14 @@ impl Merger {
     ) -> Result<special_object::source_file::SourceFile, String> {
         let mut merge = special_object::source_file::SourceFile {
             id: origin.id,
+            span: None,
             code: vec![],
+            // Every object keeps the layout of the side that changed it
+            trivia: special_object::trivia::Trivia::merge(
+                origin.trivia.as_ref(),
+                ours.trivia.as_ref(),
+                theirs.trivia.as_ref(),
+            ),
         };
 
         for (i, object) in origin.code.iter().enumerate() {
@@ -63,6 +73,15 @@ fn merge_declaration_objects(
             ours_declaration,
             theirs_declaration,
         )?),
+        (
+            declaration_object::DeclarationObject::DeclarationList(origin_declaration_list),
+            declaration_object::DeclarationObject::DeclarationList(ours_declaration_list),
+            declaration_object::DeclarationObject::DeclarationList(theirs_declaration_list),
+        ) => declaration_object::DeclarationObject::DeclarationList(merge_declaration_list(
+            origin_declaration_list,
+            ours_declaration_list,
+            theirs_declaration_list,
+        )?),
         (
             declaration_object::DeclarationObject::FunctionDeclaration(origin_function_declaration),
             declaration_object::DeclarationObject::FunctionDeclaration(ours_function_declaration),
@@ -92,6 +111,69 @@ fn merge_declaration_objects(
             ours_preproc_include,
             theirs_preproc_include,
         )?),
+        (
+            declaration_object::DeclarationObject::StructDefinition(origin_struct),
+            declaration_object::DeclarationObject::StructDefinition(ours_struct),
+            declaration_object::DeclarationObject::StructDefinition(theirs_struct),
+        ) => declaration_object::DeclarationObject::StructDefinition(merge_struct_definition(
+            origin_struct,
+            ours_struct,
+            theirs_struct,
+        )?),
+        (
+            declaration_object::DeclarationObject::UnionDefinition(origin_union),
+            declaration_object::DeclarationObject::UnionDefinition(ours_union),
+            declaration_object::DeclarationObject::UnionDefinition(theirs_union),
+        ) => declaration_object::DeclarationObject::UnionDefinition(merge_union_definition(
+            origin_union,
+            ours_union,
+            theirs_union,
+        )?),
+        (
+            declaration_object::DeclarationObject::EnumDefinition(origin_enum),
+            declaration_object::DeclarationObject::EnumDefinition(ours_enum),
+            declaration_object::DeclarationObject::EnumDefinition(theirs_enum),
+        ) => declaration_object::DeclarationObject::EnumDefinition(merge_enum_definition(
+            origin_enum,
+            ours_enum,
+            theirs_enum,
+        )?),
+        (
+            declaration_object::DeclarationObject::TypeDefinition(origin_typedef),
+            declaration_object::DeclarationObject::TypeDefinition(ours_typedef),
+            declaration_object::DeclarationObject::TypeDefinition(theirs_typedef),
+        ) => declaration_object::DeclarationObject::TypeDefinition(merge_type_definition(
+            origin_typedef,
+            ours_typedef,
+            theirs_typedef,
+        )?),
+        (
+            declaration_object::DeclarationObject::PreprocDefine(origin_preproc_define),
+            declaration_object::DeclarationObject::PreprocDefine(ours_preproc_define),
+            declaration_object::DeclarationObject::PreprocDefine(theirs_preproc_define),
+        ) => declaration_object::DeclarationObject::PreprocDefine(merge_preproc_define(
+            origin_preproc_define,
+            ours_preproc_define,
+            theirs_preproc_define,
+        )?),
+        (
+            declaration_object::DeclarationObject::PreprocCall(origin_preproc_call),
+            declaration_object::DeclarationObject::PreprocCall(ours_preproc_call),
+            declaration_object::DeclarationObject::PreprocCall(theirs_preproc_call),
+        ) => declaration_object::DeclarationObject::PreprocCall(merge_preproc_call(
+            origin_preproc_call,
+            ours_preproc_call,
+            theirs_preproc_call,
+        )?),
+        (
+            declaration_object::DeclarationObject::PreprocIf(origin_preproc_if),
+            declaration_object::DeclarationObject::PreprocIf(ours_preproc_if),
+            declaration_object::DeclarationObject::PreprocIf(theirs_preproc_if),
+        ) => declaration_object::DeclarationObject::PreprocIf(merge_preproc_if(
+            origin_preproc_if,
+            ours_preproc_if,
+            theirs_preproc_if,
+        )?),
         _ => return Err("Objects are of different type".to_string()),
     };
 
@@ -104,10 +186,9 @@ fn expression_object_changes(
 ) -> Result<Option<expression_object::ExpressionObject>, String> {
     let result = match (origin, alt) {
         (
-            expression_object::ExpressionObject::AssignmentExpression(origin_assignment),
-            expression_object::ExpressionObject::AssignmentExpression(alt_assignment),
-        ) => assignment_expression_changes(origin_assignment, alt_assignment)
-            .map(expression_object::ExpressionObject::AssignmentExpression),
+            expression_object::ExpressionObject::AssignmentExpression(_),
+            expression_object::ExpressionObject::AssignmentExpression(_),
+        ) => None,
         (
             expression_object::ExpressionObject::BinaryExpression(origin_binary),
             expression_object::ExpressionObject::BinaryExpression(alt_binary),
@@ -118,6 +199,53 @@ fn expression_object_changes(
             expression_object::ExpressionObject::CallExpression(alt_call),
         ) => call_expression_changes(origin_call, alt_call)
             .map(expression_object::ExpressionObject::CallExpression),
+        (
+            expression_object::ExpressionObject::UnaryExpression(origin_unary),
+            expression_object::ExpressionObject::UnaryExpression(alt_unary),
+        ) => unary_expression_changes(origin_unary, alt_unary)
+            .map(expression_object::ExpressionObject::UnaryExpression),
+        (
+            expression_object::ExpressionObject::UpdateExpression(origin_update),
+            expression_object::ExpressionObject::UpdateExpression(alt_update),
+        ) => update_expression_changes(origin_update, alt_update)
+            .map(expression_object::ExpressionObject::UpdateExpression),
+        (
+            expression_object::ExpressionObject::CastExpression(origin_cast),
+            expression_object::ExpressionObject::CastExpression(alt_cast),
+        ) => cast_expression_changes(origin_cast, alt_cast)
+            .map(expression_object::ExpressionObject::CastExpression),
+        (
+            expression_object::ExpressionObject::SizeofExpression(origin_sizeof),
+            expression_object::ExpressionObject::SizeofExpression(alt_sizeof),
+        ) => sizeof_expression_changes(origin_sizeof, alt_sizeof)
+            .map(expression_object::ExpressionObject::SizeofExpression),
+        // A conditional expression has nothing of its own besides its three operands
+        (
+            expression_object::ExpressionObject::ConditionalExpression(_),
+            expression_object::ExpressionObject::ConditionalExpression(_),
+        ) => None,
+        (
+            expression_object::ExpressionObject::FieldExpression(origin_field),
+            expression_object::ExpressionObject::FieldExpression(alt_field),
+        ) => field_expression_changes(origin_field, alt_field)
+            .map(expression_object::ExpressionObject::FieldExpression),
+        (
+            expression_object::ExpressionObject::SubscriptExpression(_),
+            expression_object::ExpressionObject::SubscriptExpression(_),
+        ) => None,
+        (
+            expression_object::ExpressionObject::ParenthesizedExpression(_),
+            expression_object::ExpressionObject::ParenthesizedExpression(_),
+        ) => None,
+        (
+            expression_object::ExpressionObject::InitializerList(_),
+            expression_object::ExpressionObject::InitializerList(_),
+        ) => None,
+        (
+            expression_object::ExpressionObject::InitializerPair(origin_pair),
+            expression_object::ExpressionObject::InitializerPair(alt_pair),
+        ) => initializer_pair_changes(origin_pair, alt_pair)
+            .map(expression_object::ExpressionObject::InitializerPair),
         (
             expression_object::ExpressionObject::NumberLiteral(origin_number_literal),
             expression_object::ExpressionObject::NumberLiteral(alt_number_literal),
@@ -133,6 +261,11 @@ fn expression_object_changes(
             expression_object::ExpressionObject::StringLiteral(alt_string_literal),
         ) => string_literal_changes(origin_string_literal, alt_string_literal)
             .map(expression_object::ExpressionObject::StringLiteral),
+        (
+            expression_object::ExpressionObject::CharLiteral(origin_char_literal),
+            expression_object::ExpressionObject::CharLiteral(alt_char_literal),
+        ) => char_literal_changes(origin_char_literal, alt_char_literal)
+            .map(expression_object::ExpressionObject::CharLiteral),
         _ => return Err("Objects are of different type".to_string()),
     };
 
@@ -170,6 +303,96 @@ fn merge_expression_objects(
             ours_call,
             theirs_call,
         )?),
+        (
+            expression_object::ExpressionObject::FieldExpression(origin_field),
+            expression_object::ExpressionObject::FieldExpression(ours_field),
+            expression_object::ExpressionObject::FieldExpression(theirs_field),
+        ) => expression_object::ExpressionObject::FieldExpression(merge_field_expression(
+            origin_field,
+            ours_field,
+            theirs_field,
+        )?),
+        (
+            expression_object::ExpressionObject::SubscriptExpression(origin_subscript),
+            expression_object::ExpressionObject::SubscriptExpression(ours_subscript),
+            expression_object::ExpressionObject::SubscriptExpression(theirs_subscript),
+        ) => expression_object::ExpressionObject::SubscriptExpression(merge_subscript_expression(
+            origin_subscript,
+            ours_subscript,
+            theirs_subscript,
+        )?),
+        (
+            expression_object::ExpressionObject::ParenthesizedExpression(origin_parenthesized),
+            expression_object::ExpressionObject::ParenthesizedExpression(ours_parenthesized),
+            expression_object::ExpressionObject::ParenthesizedExpression(theirs_parenthesized),
+        ) => expression_object::ExpressionObject::ParenthesizedExpression(
+            merge_parenthesized_expression(
+                origin_parenthesized,
+                ours_parenthesized,
+                theirs_parenthesized,
+            )?,
+        ),
+        (
+            expression_object::ExpressionObject::InitializerList(origin_list),
+            expression_object::ExpressionObject::InitializerList(ours_list),
+            expression_object::ExpressionObject::InitializerList(theirs_list),
+        ) => expression_object::ExpressionObject::InitializerList(merge_initializer_list(
+            origin_list,
+            ours_list,
+            theirs_list,
+        )?),
+        (
+            expression_object::ExpressionObject::InitializerPair(origin_pair),
+            expression_object::ExpressionObject::InitializerPair(ours_pair),
+            expression_object::ExpressionObject::InitializerPair(theirs_pair),
+        ) => expression_object::ExpressionObject::InitializerPair(merge_initializer_pair(
+            origin_pair,
+            ours_pair,
+            theirs_pair,
+        )?),
+        (
+            expression_object::ExpressionObject::UnaryExpression(origin_unary),
+            expression_object::ExpressionObject::UnaryExpression(ours_unary),
+            expression_object::ExpressionObject::UnaryExpression(theirs_unary),
+        ) => expression_object::ExpressionObject::UnaryExpression(merge_unary_expression(
+            origin_unary,
+            ours_unary,
+            theirs_unary,
+        )?),
+        (
+            expression_object::ExpressionObject::UpdateExpression(origin_update),
+            expression_object::ExpressionObject::UpdateExpression(ours_update),
+            expression_object::ExpressionObject::UpdateExpression(theirs_update),
+        ) => expression_object::ExpressionObject::UpdateExpression(merge_update_expression(
+            origin_update,
+            ours_update,
+            theirs_update,
+        )?),
+        (
+            expression_object::ExpressionObject::CastExpression(origin_cast),
+            expression_object::ExpressionObject::CastExpression(ours_cast),
+            expression_object::ExpressionObject::CastExpression(theirs_cast),
+        ) => expression_object::ExpressionObject::CastExpression(merge_cast_expression(
+            origin_cast,
+            ours_cast,
+            theirs_cast,
+        )?),
+        (
+            expression_object::ExpressionObject::SizeofExpression(origin_sizeof),
+            expression_object::ExpressionObject::SizeofExpression(ours_sizeof),
+            expression_object::ExpressionObject::SizeofExpression(theirs_sizeof),
+        ) => expression_object::ExpressionObject::SizeofExpression(merge_sizeof_expression(
+            origin_sizeof,
+            ours_sizeof,
+            theirs_sizeof,
+        )?),
+        (
+            expression_object::ExpressionObject::ConditionalExpression(origin_conditional),
+            expression_object::ExpressionObject::ConditionalExpression(ours_conditional),
+            expression_object::ExpressionObject::ConditionalExpression(theirs_conditional),
+        ) => expression_object::ExpressionObject::ConditionalExpression(
+            merge_conditional_expression(origin_conditional, ours_conditional, theirs_conditional)?,
+        ),
         (
             expression_object::ExpressionObject::NumberLiteral(origin_number_literal),
             expression_object::ExpressionObject::NumberLiteral(ours_number_literal),
@@ -197,6 +420,15 @@ fn merge_expression_objects(
             ours_string_literal,
             theirs_string_literal,
         )?),
+        (
+            expression_object::ExpressionObject::CharLiteral(origin_char_literal),
+            expression_object::ExpressionObject::CharLiteral(ours_char_literal),
+            expression_object::ExpressionObject::CharLiteral(theirs_char_literal),
+        ) => expression_object::ExpressionObject::CharLiteral(merge_char_literal(
+            origin_char_literal,
+            ours_char_literal,
+            theirs_char_literal,
+        )?),
         _ => return Err("Objects are of different type".to_string()),
     };
 
@@ -218,6 +450,13 @@ fn merge_compound_statement_object(
             ours_declaration,
             theirs_declaration,
         )?),
+        (
+            compound_statement_object::CompoundStatementObject::DeclarationList(origin_list),
+            compound_statement_object::CompoundStatementObject::DeclarationList(ours_list),
+            compound_statement_object::CompoundStatementObject::DeclarationList(theirs_list),
+        ) => compound_statement_object::CompoundStatementObject::DeclarationList(
+            merge_declaration_list(origin_list, ours_list, theirs_list)?,
+        ),
         (
             compound_statement_object::CompoundStatementObject::AssignmentExpression(origin_assign),
             compound_statement_object::CompoundStatementObject::AssignmentExpression(ours_assign),
@@ -239,6 +478,82 @@ fn merge_compound_statement_object(
         ) => compound_statement_object::CompoundStatementObject::CallExpression(
             merge_call_expression(origin_call, ours_call, theirs_call)?,
         ),
+        (
+            compound_statement_object::CompoundStatementObject::FieldExpression(origin_field),
+            compound_statement_object::CompoundStatementObject::FieldExpression(ours_field),
+            compound_statement_object::CompoundStatementObject::FieldExpression(theirs_field),
+        ) => compound_statement_object::CompoundStatementObject::FieldExpression(
+            merge_field_expression(origin_field, ours_field, theirs_field)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::SubscriptExpression(
+                origin_subscript,
+            ),
+            compound_statement_object::CompoundStatementObject::SubscriptExpression(ours_subscript),
+            compound_statement_object::CompoundStatementObject::SubscriptExpression(
+                theirs_subscript,
+            ),
+        ) => compound_statement_object::CompoundStatementObject::SubscriptExpression(
+            merge_subscript_expression(origin_subscript, ours_subscript, theirs_subscript)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+                origin_parenthesized,
+            ),
+            compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+                ours_parenthesized,
+            ),
+            compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+                theirs_parenthesized,
+            ),
+        ) => compound_statement_object::CompoundStatementObject::ParenthesizedExpression(
+            merge_parenthesized_expression(
+                origin_parenthesized,
+                ours_parenthesized,
+                theirs_parenthesized,
+            )?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::UnaryExpression(origin_unary),
+            compound_statement_object::CompoundStatementObject::UnaryExpression(ours_unary),
+            compound_statement_object::CompoundStatementObject::UnaryExpression(theirs_unary),
+        ) => compound_statement_object::CompoundStatementObject::UnaryExpression(
+            merge_unary_expression(origin_unary, ours_unary, theirs_unary)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::UpdateExpression(origin_update),
+            compound_statement_object::CompoundStatementObject::UpdateExpression(ours_update),
+            compound_statement_object::CompoundStatementObject::UpdateExpression(theirs_update),
+        ) => compound_statement_object::CompoundStatementObject::UpdateExpression(
+            merge_update_expression(origin_update, ours_update, theirs_update)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::CastExpression(origin_cast),
+            compound_statement_object::CompoundStatementObject::CastExpression(ours_cast),
+            compound_statement_object::CompoundStatementObject::CastExpression(theirs_cast),
+        ) => compound_statement_object::CompoundStatementObject::CastExpression(
+            merge_cast_expression(origin_cast, ours_cast, theirs_cast)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::SizeofExpression(origin_sizeof),
+            compound_statement_object::CompoundStatementObject::SizeofExpression(ours_sizeof),
+            compound_statement_object::CompoundStatementObject::SizeofExpression(theirs_sizeof),
+        ) => compound_statement_object::CompoundStatementObject::SizeofExpression(
+            merge_sizeof_expression(origin_sizeof, ours_sizeof, theirs_sizeof)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::ConditionalExpression(
+                origin_conditional,
+            ),
+            compound_statement_object::CompoundStatementObject::ConditionalExpression(
+                ours_conditional,
+            ),
+            compound_statement_object::CompoundStatementObject::ConditionalExpression(
+                theirs_conditional,
+            ),
+        ) => compound_statement_object::CompoundStatementObject::ConditionalExpression(
+            merge_conditional_expression(origin_conditional, ours_conditional, theirs_conditional)?,
+        ),
         (
             compound_statement_object::CompoundStatementObject::NumberLiteral(origin_number),
             compound_statement_object::CompoundStatementObject::NumberLiteral(ours_number),
@@ -262,6 +577,15 @@ fn merge_compound_statement_object(
         ) => compound_statement_object::CompoundStatementObject::StringLiteral(
             merge_string_literal(origin_string, ours_string, theirs_string)?,
         ),
+        (
+            compound_statement_object::CompoundStatementObject::CharLiteral(origin_char),
+            compound_statement_object::CompoundStatementObject::CharLiteral(ours_char),
+            compound_statement_object::CompoundStatementObject::CharLiteral(theirs_char),
+        ) => compound_statement_object::CompoundStatementObject::CharLiteral(merge_char_literal(
+            origin_char,
+            ours_char,
+            theirs_char,
+        )?),
         (
             compound_statement_object::CompoundStatementObject::CompoundStatement(origin_comp_stmt),
             compound_statement_object::CompoundStatementObject::CompoundStatement(ours_comp_stmt),
@@ -285,6 +609,109 @@ fn merge_compound_statement_object(
         ) => compound_statement_object::CompoundStatementObject::ReturnStatement(
             merge_return_statement(origin_return, ours_return, theirs_return)?,
         ),
+        (
+            compound_statement_object::CompoundStatementObject::WhileStatement(origin_while),
+            compound_statement_object::CompoundStatementObject::WhileStatement(ours_while),
+            compound_statement_object::CompoundStatementObject::WhileStatement(theirs_while),
+        ) => compound_statement_object::CompoundStatementObject::WhileStatement(
+            merge_while_statement(origin_while, ours_while, theirs_while)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::DoStatement(origin_do),
+            compound_statement_object::CompoundStatementObject::DoStatement(ours_do),
+            compound_statement_object::CompoundStatementObject::DoStatement(theirs_do),
+        ) => compound_statement_object::CompoundStatementObject::DoStatement(merge_do_statement(
+            origin_do, ours_do, theirs_do,
+        )?),
+        (
+            compound_statement_object::CompoundStatementObject::ForStatement(origin_for),
+            compound_statement_object::CompoundStatementObject::ForStatement(ours_for),
+            compound_statement_object::CompoundStatementObject::ForStatement(theirs_for),
+        ) => compound_statement_object::CompoundStatementObject::ForStatement(merge_for_statement(
+            origin_for, ours_for, theirs_for,
+        )?),
+        (
+            compound_statement_object::CompoundStatementObject::SwitchStatement(origin_switch),
+            compound_statement_object::CompoundStatementObject::SwitchStatement(ours_switch),
+            compound_statement_object::CompoundStatementObject::SwitchStatement(theirs_switch),
+        ) => compound_statement_object::CompoundStatementObject::SwitchStatement(
+            merge_switch_statement(origin_switch, ours_switch, theirs_switch)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::CaseStatement(origin_case),
+            compound_statement_object::CompoundStatementObject::CaseStatement(ours_case),
+            compound_statement_object::CompoundStatementObject::CaseStatement(theirs_case),
+        ) => compound_statement_object::CompoundStatementObject::CaseStatement(
+            merge_case_statement(origin_case, ours_case, theirs_case)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::BreakStatement(origin_break),
+            compound_statement_object::CompoundStatementObject::BreakStatement(ours_break),
+            compound_statement_object::CompoundStatementObject::BreakStatement(theirs_break),
+        ) => {
+            let mut m_break = origin_break.clone();
+            m_break.comments = merge_comments(
+                &origin_break.comments,
+                &ours_break.comments,
+                &theirs_break.comments,
+            )?;
+            compound_statement_object::CompoundStatementObject::BreakStatement(m_break)
+        }
+        (
+            compound_statement_object::CompoundStatementObject::ContinueStatement(origin_continue),
+            compound_statement_object::CompoundStatementObject::ContinueStatement(ours_continue),
+            compound_statement_object::CompoundStatementObject::ContinueStatement(theirs_continue),
+        ) => {
+            let mut m_continue = origin_continue.clone();
+            m_continue.comments = merge_comments(
+                &origin_continue.comments,
+                &ours_continue.comments,
+                &theirs_continue.comments,
+            )?;
+            compound_statement_object::CompoundStatementObject::ContinueStatement(m_continue)
+        }
+        (
+            compound_statement_object::CompoundStatementObject::LabeledStatement(origin_labeled),
+            compound_statement_object::CompoundStatementObject::LabeledStatement(ours_labeled),
+            compound_statement_object::CompoundStatementObject::LabeledStatement(theirs_labeled),
+        ) => compound_statement_object::CompoundStatementObject::LabeledStatement(
+            merge_labeled_statement(origin_labeled, ours_labeled, theirs_labeled)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::GotoStatement(origin_goto),
+            compound_statement_object::CompoundStatementObject::GotoStatement(ours_goto),
+            compound_statement_object::CompoundStatementObject::GotoStatement(theirs_goto),
+        ) => compound_statement_object::CompoundStatementObject::GotoStatement(
+            merge_goto_statement(origin_goto, ours_goto, theirs_goto)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::StructDefinition(origin_struct),
+            compound_statement_object::CompoundStatementObject::StructDefinition(ours_struct),
+            compound_statement_object::CompoundStatementObject::StructDefinition(theirs_struct),
+        ) => compound_statement_object::CompoundStatementObject::StructDefinition(
+            merge_struct_definition(origin_struct, ours_struct, theirs_struct)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::UnionDefinition(origin_union),
+            compound_statement_object::CompoundStatementObject::UnionDefinition(ours_union),
+            compound_statement_object::CompoundStatementObject::UnionDefinition(theirs_union),
+        ) => compound_statement_object::CompoundStatementObject::UnionDefinition(
+            merge_union_definition(origin_union, ours_union, theirs_union)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::EnumDefinition(origin_enum),
+            compound_statement_object::CompoundStatementObject::EnumDefinition(ours_enum),
+            compound_statement_object::CompoundStatementObject::EnumDefinition(theirs_enum),
+        ) => compound_statement_object::CompoundStatementObject::EnumDefinition(
+            merge_enum_definition(origin_enum, ours_enum, theirs_enum)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::TypeDefinition(origin_typedef),
+            compound_statement_object::CompoundStatementObject::TypeDefinition(ours_typedef),
+            compound_statement_object::CompoundStatementObject::TypeDefinition(theirs_typedef),
+        ) => compound_statement_object::CompoundStatementObject::TypeDefinition(
+            merge_type_definition(origin_typedef, ours_typedef, theirs_typedef)?,
+        ),
         (
             compound_statement_object::CompoundStatementObject::Comment(origin_comment),
             compound_statement_object::CompoundStatementObject::Comment(ours_comment),
@@ -294,6 +721,29 @@ fn merge_compound_statement_object(
             ours_comment,
             theirs_comment,
         )?),
+        (
+            compound_statement_object::CompoundStatementObject::PreprocDefine(origin_define),
+            compound_statement_object::CompoundStatementObject::PreprocDefine(ours_define),
+            compound_statement_object::CompoundStatementObject::PreprocDefine(theirs_define),
+        ) => compound_statement_object::CompoundStatementObject::PreprocDefine(
+            merge_preproc_define(origin_define, ours_define, theirs_define)?,
+        ),
+        (
+            compound_statement_object::CompoundStatementObject::PreprocCall(origin_call),
+            compound_statement_object::CompoundStatementObject::PreprocCall(ours_call),
+            compound_statement_object::CompoundStatementObject::PreprocCall(theirs_call),
+        ) => compound_statement_object::CompoundStatementObject::PreprocCall(merge_preproc_call(
+            origin_call,
+            ours_call,
+            theirs_call,
+        )?),
+        (
+            compound_statement_object::CompoundStatementObject::PreprocIfStatement(origin_if),
+            compound_statement_object::CompoundStatementObject::PreprocIfStatement(ours_if),
+            compound_statement_object::CompoundStatementObject::PreprocIfStatement(theirs_if),
+        ) => compound_statement_object::CompoundStatementObject::PreprocIfStatement(
+            merge_preproc_if_statement(origin_if, ours_if, theirs_if)?,
+        ),
         _ => return Err("Objects are of different type".to_string()),
     };
 
@@ -329,6 +779,21 @@ fn merge_comment(
     Ok(m_comment)
 }
 
+/// Comments attached to an object merge on their own, so one side rewording the comment of a
+/// function does not conflict with the other changing its code
+fn merge_comments(
+    origin: &special_object::comment::Comments,
+    ours: &special_object::comment::Comments,
+    theirs: &special_object::comment::Comments,
+) -> Result<special_object::comment::Comments, String> {
+    match (ours != origin, theirs != origin) {
+        (false, _) => Ok(theirs.clone()),
+        (true, false) => Ok(ours.clone()),
+        (true, true) if ours == theirs => Ok(ours.clone()),
+        _ => Err("merge conflict in comments".to_string()),
+    }
+}
+
 fn merge_value(
     origin: Option<&expression_object::ExpressionObject>,
     ours: Option<&expression_object::ExpressionObject>,
@@ -376,6 +841,21 @@ fn declaration_changes(
     }
 }
 
+/// Specifiers merge on their own, so one side adding `static` does not conflict with the
+/// other renaming the declaration
+fn merge_specifiers(
+    origin: &Specifiers,
+    ours: &Specifiers,
+    theirs: &Specifiers,
+) -> Result<Specifiers, String> {
+    match (ours != origin, theirs != origin) {
+        (false, _) => Ok(*theirs),
+        (true, false) => Ok(*ours),
+        (true, true) if ours == theirs => Ok(*ours),
+        _ => Err("merge conflict in specifiers".to_string()),
+    }
+}
+
 fn merge_declaration(
     origin: &declaration_object::declaration::Declaration,
     ours: &declaration_object::declaration::Declaration,
@@ -397,11 +877,43 @@ fn merge_declaration(
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
+    m_declaration.specifiers =
+        merge_specifiers(&origin.specifiers, &ours.specifiers, &theirs.specifiers)?;
     m_declaration.value = m_value.map(Box::new);
 
+    m_declaration.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_declaration)
 }
 
+fn merge_declaration_list(
+    origin: &declaration_object::declaration_list::DeclarationList,
+    ours: &declaration_object::declaration_list::DeclarationList,
+    theirs: &declaration_object::declaration_list::DeclarationList,
+) -> Result<declaration_object::declaration_list::DeclarationList, String> {
+    if ours.declarations.len() != origin.declarations.len()
+        || theirs.declarations.len() != origin.declarations.len()
+    {
+        return Err(format!("merge conflict in object {}", origin.id));
+    }
+
+    let mut m_declarations = vec![];
+    for (i, declaration) in origin.declarations.iter().enumerate() {
+        m_declarations.push(merge_declaration(
+            declaration,
+            &ours.declarations[i],
+            &theirs.declarations[i],
+        )?);
+    }
+
+    let mut m_declaration_list = origin.clone();
+    m_declaration_list.declarations = m_declarations;
+    m_declaration_list.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_declaration_list)
+}
+
 fn function_parameter_changes(
     origin: &declaration_object::function_declaration::function_parameter::FunctionParameter,
     alt: &declaration_object::function_declaration::function_parameter::FunctionParameter,
@@ -419,15 +931,20 @@ fn merge_function_parameter(
     theirs: &declaration_object::function_declaration::function_parameter::FunctionParameter,
 ) -> Result<declaration_object::function_declaration::function_parameter::FunctionParameter, String>
 {
-    match (
+    let mut m_function_parameter = match (
         function_parameter_changes(origin, ours),
         function_parameter_changes(origin, theirs),
     ) {
-        (None, None) => Ok(origin.clone()),
-        (None, Some(m_function_parameter)) => Ok(m_function_parameter),
-        (Some(m_function_parameter), None) => Ok(m_function_parameter),
-        _ => Err(format!("merge conflict in object {}", origin.id)),
-    }
+        (None, None) => origin.clone(),
+        (None, Some(m_function_parameter)) => m_function_parameter,
+        (Some(m_function_parameter), None) => m_function_parameter,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_function_parameter.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_function_parameter)
 }
 
 fn function_declaration_changes(
@@ -436,6 +953,8 @@ fn function_declaration_changes(
 ) -> Option<declaration_object::function_declaration::FunctionDeclaration> {
     if alt.return_type != origin.return_type
         || alt.identifier != origin.identifier
+        || alt.is_variadic != origin.is_variadic
+        || alt.explicit_void != origin.explicit_void
         || alt.id != origin.id
     //Because references are dependant of id, the check must be stricter
     {
@@ -469,8 +988,13 @@ fn merge_function_declaration(
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
+    m_function_declaration.specifiers =
+        merge_specifiers(&origin.specifiers, &ours.specifiers, &theirs.specifiers)?;
     m_function_declaration.parameter_list = m_parameter_list;
 
+    m_function_declaration.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_function_declaration)
 }
 
@@ -480,6 +1004,8 @@ fn function_definition_changes(
 ) -> Option<declaration_object::function_definition::FunctionDefinition> {
     if alt.return_type != origin.return_type
         || alt.identifier != origin.identifier
+        || alt.is_variadic != origin.is_variadic
+        || alt.explicit_void != origin.explicit_void
         || alt.id != origin.id
     //Because references are dependant of id, the check must be stricter
     {
@@ -519,9 +1045,14 @@ fn merge_function_definition(
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
+    m_function_definition.specifiers =
+        merge_specifiers(&origin.specifiers, &ours.specifiers, &theirs.specifiers)?;
     m_function_definition.parameter_list = m_parameter_list;
     m_function_definition.compound_statement = m_compound_statement;
 
+    m_function_definition.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_function_definition)
 }
 
@@ -541,7 +1072,7 @@ fn merge_preproc_include(
     ours: &declaration_object::preproc_include::PreprocInclude,
     theirs: &declaration_object::preproc_include::PreprocInclude,
 ) -> Result<declaration_object::preproc_include::PreprocInclude, String> {
-    let m_preproc_include = match (
+    let mut m_preproc_include = match (
         preproc_include_changes(origin, ours),
         preproc_include_changes(origin, theirs),
     ) {
@@ -551,37 +1082,190 @@ fn merge_preproc_include(
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
+    m_preproc_include.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_preproc_include)
 }
 
-fn assignment_expression_changes(
-    origin: &expression_object::assignment_expression::AssignmentExpression,
-    alt: &expression_object::assignment_expression::AssignmentExpression,
-) -> Option<expression_object::assignment_expression::AssignmentExpression> {
-    if alt.id_declaration != origin.id_declaration || alt.identifier != origin.identifier {
+fn preproc_define_changes(
+    origin: &declaration_object::preproc_define::PreprocDefine,
+    alt: &declaration_object::preproc_define::PreprocDefine,
+) -> Option<declaration_object::preproc_define::PreprocDefine> {
+    if alt.identifier != origin.identifier
+        || alt.parameters != origin.parameters
+        || alt.value != origin.value
+    {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_preproc_define(
+    origin: &declaration_object::preproc_define::PreprocDefine,
+    ours: &declaration_object::preproc_define::PreprocDefine,
+    theirs: &declaration_object::preproc_define::PreprocDefine,
+) -> Result<declaration_object::preproc_define::PreprocDefine, String> {
+    let mut m_preproc_define = match (
+        preproc_define_changes(origin, ours),
+        preproc_define_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_preproc_define)) => m_preproc_define,
+        (Some(m_preproc_define), None) => m_preproc_define,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_preproc_define.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_preproc_define)
+}
+
+fn preproc_call_changes(
+    origin: &declaration_object::preproc_call::PreprocCall,
+    alt: &declaration_object::preproc_call::PreprocCall,
+) -> Option<declaration_object::preproc_call::PreprocCall> {
+    if alt.directive != origin.directive || alt.argument != origin.argument {
         Some(alt.clone())
     } else {
         None
     }
 }
 
+fn merge_preproc_call(
+    origin: &declaration_object::preproc_call::PreprocCall,
+    ours: &declaration_object::preproc_call::PreprocCall,
+    theirs: &declaration_object::preproc_call::PreprocCall,
+) -> Result<declaration_object::preproc_call::PreprocCall, String> {
+    let mut m_preproc_call = match (
+        preproc_call_changes(origin, ours),
+        preproc_call_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_preproc_call)) => m_preproc_call,
+        (Some(m_preproc_call), None) => m_preproc_call,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_preproc_call.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_preproc_call)
+}
+
+fn merge_preproc_alternative<T: Clone + PartialEq>(
+    origin: &Option<Box<T>>,
+    ours: &Option<Box<T>>,
+    theirs: &Option<Box<T>>,
+    merge: impl Fn(&T, &T, &T) -> Result<T, String>,
+) -> Result<Option<Box<T>>, String> {
+    match (origin, ours, theirs) {
+        (Some(origin_alternative), Some(ours_alternative), Some(theirs_alternative)) => {
+            Ok(Some(Box::new(merge(
+                origin_alternative,
+                ours_alternative,
+                theirs_alternative,
+            )?)))
+        }
+        _ if ours == origin => Ok(theirs.clone()),
+        _ if theirs == origin => Ok(ours.clone()),
+        _ => Err("merge conflict in preprocessor alternative".to_string()),
+    }
+}
+
+fn merge_preproc_if(
+    origin: &declaration_object::preproc_if::PreprocIf,
+    ours: &declaration_object::preproc_if::PreprocIf,
+    theirs: &declaration_object::preproc_if::PreprocIf,
+) -> Result<declaration_object::preproc_if::PreprocIf, String> {
+    let mut m_body = vec![];
+    for (i, object) in origin.body.iter().enumerate() {
+        m_body.push(merge_declaration_objects(
+            object,
+            &ours.body[i],
+            &theirs.body[i],
+        )?);
+    }
+
+    let m_alternative = merge_preproc_alternative(
+        &origin.alternative,
+        &ours.alternative,
+        &theirs.alternative,
+        merge_preproc_if,
+    )?;
+
+    let mut m_preproc_if = match (
+        origin.directive != ours.directive || origin.condition != ours.condition,
+        origin.directive != theirs.directive || origin.condition != theirs.condition,
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_preproc_if.body = m_body;
+    m_preproc_if.alternative = m_alternative;
+
+    m_preproc_if.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_preproc_if)
+}
+
+fn merge_preproc_if_statement(
+    origin: &statement_object::preproc_if_statement::PreprocIfStatement,
+    ours: &statement_object::preproc_if_statement::PreprocIfStatement,
+    theirs: &statement_object::preproc_if_statement::PreprocIfStatement,
+) -> Result<statement_object::preproc_if_statement::PreprocIfStatement, String> {
+    let mut m_body = vec![];
+    for (i, object) in origin.body.iter().enumerate() {
+        m_body.push(merge_compound_statement_object(
+            object,
+            &ours.body[i],
+            &theirs.body[i],
+        )?);
+    }
+
+    let m_alternative = merge_preproc_alternative(
+        &origin.alternative,
+        &ours.alternative,
+        &theirs.alternative,
+        merge_preproc_if_statement,
+    )?;
+
+    let mut m_preproc_if_statement = match (
+        origin.directive != ours.directive || origin.condition != ours.condition,
+        origin.directive != theirs.directive || origin.condition != theirs.condition,
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_preproc_if_statement.body = m_body;
+    m_preproc_if_statement.alternative = m_alternative;
+
+    m_preproc_if_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_preproc_if_statement)
+}
+
 fn merge_assignment_expression(
     origin: &expression_object::assignment_expression::AssignmentExpression,
     ours: &expression_object::assignment_expression::AssignmentExpression,
     theirs: &expression_object::assignment_expression::AssignmentExpression,
 ) -> Result<expression_object::assignment_expression::AssignmentExpression, String> {
+    let m_left = merge_expression_objects(&origin.left, &ours.left, &theirs.left)?;
     let m_value = merge_expression_objects(&origin.value, &ours.value, &theirs.value)?;
-    let mut m_assignment_expression = match (
-        assignment_expression_changes(origin, ours),
-        assignment_expression_changes(origin, theirs),
-    ) {
-        (None, None) => origin.clone(),
-        (None, Some(m_assignment_expression)) => m_assignment_expression,
-        (Some(m_assignment_expression), None) => m_assignment_expression,
-        _ => return Err(format!("merge conflict in object {}", origin.id)),
-    };
 
+    let mut m_assignment_expression = origin.clone();
+    m_assignment_expression.left = Box::new(m_left);
     m_assignment_expression.value = Box::new(m_value);
+    m_assignment_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_assignment_expression)
 }
 
@@ -615,113 +1299,417 @@ fn merge_binary_expression(
 
     m_binary_expression.left = Box::new(m_left);
     m_binary_expression.right = Box::new(m_right);
+    m_binary_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_binary_expression)
 }
 
-fn call_expression_changes(
-    origin: &expression_object::call_expression::CallExpression,
-    alt: &expression_object::call_expression::CallExpression,
-) -> Option<expression_object::call_expression::CallExpression> {
-    if alt.id_declaration != origin.id_declaration || alt.identifier != origin.identifier {
+fn unary_expression_changes(
+    origin: &expression_object::unary_expression::UnaryExpression,
+    alt: &expression_object::unary_expression::UnaryExpression,
+) -> Option<expression_object::unary_expression::UnaryExpression> {
+    if alt.operator != origin.operator {
         Some(alt.clone())
     } else {
         None
     }
 }
 
-fn merge_call_expression(
-    origin: &expression_object::call_expression::CallExpression,
-    ours: &expression_object::call_expression::CallExpression,
-    theirs: &expression_object::call_expression::CallExpression,
-) -> Result<expression_object::call_expression::CallExpression, String> {
-    let mut m_argument_list = vec![];
-    for (i, arg) in origin.argument_list.iter().enumerate() {
-        m_argument_list.push(merge_expression_objects(
-            arg,
-            &ours.argument_list[i],
-            &theirs.argument_list[i],
-        )?);
-    }
-
-    let mut m_call_expression = match (
-        call_expression_changes(origin, ours),
-        call_expression_changes(origin, theirs),
+fn merge_unary_expression(
+    origin: &expression_object::unary_expression::UnaryExpression,
+    ours: &expression_object::unary_expression::UnaryExpression,
+    theirs: &expression_object::unary_expression::UnaryExpression,
+) -> Result<expression_object::unary_expression::UnaryExpression, String> {
+    let m_argument = merge_expression_objects(&origin.argument, &ours.argument, &theirs.argument)?;
+    let mut m_unary_expression = match (
+        unary_expression_changes(origin, ours),
+        unary_expression_changes(origin, theirs),
     ) {
         (None, None) => origin.clone(),
-        (None, Some(m_call_expression)) => m_call_expression,
-        (Some(m_call_expression), None) => m_call_expression,
+        (None, Some(m_unary_expression)) => m_unary_expression,
+        (Some(m_unary_expression), None) => m_unary_expression,
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
-    m_call_expression.argument_list = m_argument_list;
-    Ok(m_call_expression)
+    m_unary_expression.argument = Box::new(m_argument);
+    m_unary_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_unary_expression)
 }
 
-fn number_literal_changes(
-    origin: &expression_object::number_literal::NumberLiteral,
-    alt: &expression_object::number_literal::NumberLiteral,
-) -> Option<expression_object::number_literal::NumberLiteral> {
-    if alt.value != origin.value {
+fn update_expression_changes(
+    origin: &expression_object::update_expression::UpdateExpression,
+    alt: &expression_object::update_expression::UpdateExpression,
+) -> Option<expression_object::update_expression::UpdateExpression> {
+    if alt.operator != origin.operator || alt.is_prefix != origin.is_prefix {
         Some(alt.clone())
     } else {
         None
     }
 }
 
-fn merge_number_literal(
-    origin: &expression_object::number_literal::NumberLiteral,
-    ours: &expression_object::number_literal::NumberLiteral,
-    theirs: &expression_object::number_literal::NumberLiteral,
-) -> Result<expression_object::number_literal::NumberLiteral, String> {
-    let m_number_literal = match (
-        number_literal_changes(origin, ours),
-        number_literal_changes(origin, theirs),
+fn merge_update_expression(
+    origin: &expression_object::update_expression::UpdateExpression,
+    ours: &expression_object::update_expression::UpdateExpression,
+    theirs: &expression_object::update_expression::UpdateExpression,
+) -> Result<expression_object::update_expression::UpdateExpression, String> {
+    let m_argument = merge_expression_objects(&origin.argument, &ours.argument, &theirs.argument)?;
+    let mut m_update_expression = match (
+        update_expression_changes(origin, ours),
+        update_expression_changes(origin, theirs),
     ) {
         (None, None) => origin.clone(),
-        (None, Some(m_number_literal)) => m_number_literal,
-        (Some(m_number_literal), None) => m_number_literal,
+        (None, Some(m_update_expression)) => m_update_expression,
+        (Some(m_update_expression), None) => m_update_expression,
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
-    Ok(m_number_literal)
+    m_update_expression.argument = Box::new(m_argument);
+    m_update_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_update_expression)
 }
 
-fn reference_changes(
-    origin: &expression_object::reference::Reference,
-    alt: &expression_object::reference::Reference,
-) -> Option<expression_object::reference::Reference> {
-    if alt.declaration_id != origin.declaration_id
-    /*|| alt.identifier != origin.identifier*/
-    {
+fn cast_expression_changes(
+    origin: &expression_object::cast_expression::CastExpression,
+    alt: &expression_object::cast_expression::CastExpression,
+) -> Option<expression_object::cast_expression::CastExpression> {
+    if alt.cast_type != origin.cast_type {
         Some(alt.clone())
     } else {
         None
     }
 }
 
-fn merge_reference(
-    origin: &expression_object::reference::Reference,
-    ours: &expression_object::reference::Reference,
-    theirs: &expression_object::reference::Reference,
-) -> Result<expression_object::reference::Reference, String> {
-    let m_reference = match (
-        reference_changes(origin, ours),
-        reference_changes(origin, theirs),
+fn merge_cast_expression(
+    origin: &expression_object::cast_expression::CastExpression,
+    ours: &expression_object::cast_expression::CastExpression,
+    theirs: &expression_object::cast_expression::CastExpression,
+) -> Result<expression_object::cast_expression::CastExpression, String> {
+    let m_value = merge_expression_objects(&origin.value, &ours.value, &theirs.value)?;
+    let mut m_cast_expression = match (
+        cast_expression_changes(origin, ours),
+        cast_expression_changes(origin, theirs),
     ) {
         (None, None) => origin.clone(),
-        (None, Some(m_reference)) => m_reference,
-        (Some(m_reference), None) => m_reference,
+        (None, Some(m_cast_expression)) => m_cast_expression,
+        (Some(m_cast_expression), None) => m_cast_expression,
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
-    Ok(m_reference)
+    m_cast_expression.value = Box::new(m_value);
+    m_cast_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_cast_expression)
+}
+
+fn sizeof_expression_changes(
+    origin: &expression_object::sizeof_expression::SizeofExpression,
+    alt: &expression_object::sizeof_expression::SizeofExpression,
+) -> Option<expression_object::sizeof_expression::SizeofExpression> {
+    if alt.argument_type != origin.argument_type {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_sizeof_expression(
+    origin: &expression_object::sizeof_expression::SizeofExpression,
+    ours: &expression_object::sizeof_expression::SizeofExpression,
+    theirs: &expression_object::sizeof_expression::SizeofExpression,
+) -> Result<expression_object::sizeof_expression::SizeofExpression, String> {
+    let m_value = merge_value(
+        origin.value.as_deref(),
+        ours.value.as_deref(),
+        theirs.value.as_deref(),
+    )?;
+    let mut m_sizeof_expression = match (
+        sizeof_expression_changes(origin, ours),
+        sizeof_expression_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_sizeof_expression)) => m_sizeof_expression,
+        (Some(m_sizeof_expression), None) => m_sizeof_expression,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_sizeof_expression.value = m_value.map(Box::new);
+    m_sizeof_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_sizeof_expression)
+}
+
+fn merge_conditional_expression(
+    origin: &expression_object::conditional_expression::ConditionalExpression,
+    ours: &expression_object::conditional_expression::ConditionalExpression,
+    theirs: &expression_object::conditional_expression::ConditionalExpression,
+) -> Result<expression_object::conditional_expression::ConditionalExpression, String> {
+    let m_condition =
+        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+    let m_consequence =
+        merge_expression_objects(&origin.consequence, &ours.consequence, &theirs.consequence)?;
+    let m_alternative =
+        merge_expression_objects(&origin.alternative, &ours.alternative, &theirs.alternative)?;
+
+    let mut m_conditional_expression = origin.clone();
+    m_conditional_expression.condition = Box::new(m_condition);
+    m_conditional_expression.consequence = Box::new(m_consequence);
+    m_conditional_expression.alternative = Box::new(m_alternative);
+    m_conditional_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_conditional_expression)
+}
+
+fn field_expression_changes(
+    origin: &expression_object::field_expression::FieldExpression,
+    alt: &expression_object::field_expression::FieldExpression,
+) -> Option<expression_object::field_expression::FieldExpression> {
+    if alt.operator != origin.operator
+        || alt.declaration_id != origin.declaration_id
+        || alt.identifier != origin.identifier
+    {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_field_expression(
+    origin: &expression_object::field_expression::FieldExpression,
+    ours: &expression_object::field_expression::FieldExpression,
+    theirs: &expression_object::field_expression::FieldExpression,
+) -> Result<expression_object::field_expression::FieldExpression, String> {
+    let m_argument = merge_expression_objects(&origin.argument, &ours.argument, &theirs.argument)?;
+    let mut m_field_expression = match (
+        field_expression_changes(origin, ours),
+        field_expression_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_field_expression)) => m_field_expression,
+        (Some(m_field_expression), None) => m_field_expression,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_field_expression.argument = Box::new(m_argument);
+    m_field_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_field_expression)
+}
+
+fn merge_subscript_expression(
+    origin: &expression_object::subscript_expression::SubscriptExpression,
+    ours: &expression_object::subscript_expression::SubscriptExpression,
+    theirs: &expression_object::subscript_expression::SubscriptExpression,
+) -> Result<expression_object::subscript_expression::SubscriptExpression, String> {
+    let m_argument = merge_expression_objects(&origin.argument, &ours.argument, &theirs.argument)?;
+    let m_index = merge_expression_objects(&origin.index, &ours.index, &theirs.index)?;
+
+    let mut m_subscript_expression = origin.clone();
+    m_subscript_expression.argument = Box::new(m_argument);
+    m_subscript_expression.index = Box::new(m_index);
+    m_subscript_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_subscript_expression)
+}
+
+fn merge_parenthesized_expression(
+    origin: &expression_object::parenthesized_expression::ParenthesizedExpression,
+    ours: &expression_object::parenthesized_expression::ParenthesizedExpression,
+    theirs: &expression_object::parenthesized_expression::ParenthesizedExpression,
+) -> Result<expression_object::parenthesized_expression::ParenthesizedExpression, String> {
+    let m_value = merge_expression_objects(&origin.value, &ours.value, &theirs.value)?;
+
+    let mut m_parenthesized_expression = origin.clone();
+    m_parenthesized_expression.value = Box::new(m_value);
+    m_parenthesized_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_parenthesized_expression)
+}
+
+fn merge_initializer_list(
+    origin: &expression_object::initializer_list::InitializerList,
+    ours: &expression_object::initializer_list::InitializerList,
+    theirs: &expression_object::initializer_list::InitializerList,
+) -> Result<expression_object::initializer_list::InitializerList, String> {
+    if ours.elements.len() != origin.elements.len()
+        || theirs.elements.len() != origin.elements.len()
+    {
+        return Err(format!("merge conflict in object {}", origin.id));
+    }
+
+    let mut m_elements = vec![];
+    for (i, element) in origin.elements.iter().enumerate() {
+        m_elements.push(merge_expression_objects(
+            element,
+            &ours.elements[i],
+            &theirs.elements[i],
+        )?);
+    }
+
+    let mut m_initializer_list = origin.clone();
+    m_initializer_list.elements = m_elements;
+    m_initializer_list.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_initializer_list)
+}
+
+fn initializer_pair_changes(
+    origin: &expression_object::initializer_pair::InitializerPair,
+    alt: &expression_object::initializer_pair::InitializerPair,
+) -> Option<expression_object::initializer_pair::InitializerPair> {
+    if alt.designators != origin.designators {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_initializer_pair(
+    origin: &expression_object::initializer_pair::InitializerPair,
+    ours: &expression_object::initializer_pair::InitializerPair,
+    theirs: &expression_object::initializer_pair::InitializerPair,
+) -> Result<expression_object::initializer_pair::InitializerPair, String> {
+    let m_value = merge_expression_objects(&origin.value, &ours.value, &theirs.value)?;
+
+    let mut m_initializer_pair = match (
+        initializer_pair_changes(origin, ours),
+        initializer_pair_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_initializer_pair)) => m_initializer_pair,
+        (Some(m_initializer_pair), None) => m_initializer_pair,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_initializer_pair.value = Box::new(m_value);
+    m_initializer_pair.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_initializer_pair)
+}
+
+fn call_expression_changes(
+    origin: &expression_object::call_expression::CallExpression,
+    alt: &expression_object::call_expression::CallExpression,
+) -> Option<expression_object::call_expression::CallExpression> {
+    if alt.id_declaration != origin.id_declaration || alt.identifier != origin.identifier {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_call_expression(
+    origin: &expression_object::call_expression::CallExpression,
+    ours: &expression_object::call_expression::CallExpression,
+    theirs: &expression_object::call_expression::CallExpression,
+) -> Result<expression_object::call_expression::CallExpression, String> {
+    let mut m_argument_list = vec![];
+    for (i, arg) in origin.argument_list.iter().enumerate() {
+        m_argument_list.push(merge_expression_objects(
+            arg,
+            &ours.argument_list[i],
+            &theirs.argument_list[i],
+        )?);
+    }
+
+    let mut m_call_expression = match (
+        call_expression_changes(origin, ours),
+        call_expression_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_call_expression)) => m_call_expression,
+        (Some(m_call_expression), None) => m_call_expression,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_call_expression.argument_list = m_argument_list;
+    m_call_expression.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_call_expression)
+}
+
+fn number_literal_changes(
+    origin: &expression_object::number_literal::NumberLiteral,
+    alt: &expression_object::number_literal::NumberLiteral,
+) -> Option<expression_object::number_literal::NumberLiteral> {
+    if alt != origin || alt.spelling != origin.spelling {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_number_literal(
+    origin: &expression_object::number_literal::NumberLiteral,
+    ours: &expression_object::number_literal::NumberLiteral,
+    theirs: &expression_object::number_literal::NumberLiteral,
+) -> Result<expression_object::number_literal::NumberLiteral, String> {
+    let mut m_number_literal = match (
+        number_literal_changes(origin, ours),
+        number_literal_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_number_literal)) => m_number_literal,
+        (Some(m_number_literal), None) => m_number_literal,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_number_literal.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_number_literal)
+}
+
+fn reference_changes(
+    origin: &expression_object::reference::Reference,
+    alt: &expression_object::reference::Reference,
+) -> Option<expression_object::reference::Reference> {
+    if alt.declaration_id != origin.declaration_id
+    /*|| alt.identifier != origin.identifier*/
+    {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_reference(
+    origin: &expression_object::reference::Reference,
+    ours: &expression_object::reference::Reference,
+    theirs: &expression_object::reference::Reference,
+) -> Result<expression_object::reference::Reference, String> {
+    let mut m_reference = match (
+        reference_changes(origin, ours),
+        reference_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_reference)) => m_reference,
+        (Some(m_reference), None) => m_reference,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_reference.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_reference)
 }
 
 fn string_literal_changes(
     origin: &expression_object::string_literal::StringLiteral,
     alt: &expression_object::string_literal::StringLiteral,
 ) -> Option<expression_object::string_literal::StringLiteral> {
-    if alt.value != origin.value {
+    if alt.value != origin.value || alt.prefix != origin.prefix {
         Some(alt.clone())
     } else {
         None
@@ -733,7 +1721,7 @@ fn merge_string_literal(
     ours: &expression_object::string_literal::StringLiteral,
     theirs: &expression_object::string_literal::StringLiteral,
 ) -> Result<expression_object::string_literal::StringLiteral, String> {
-    let m_string_literal = match (
+    let mut m_string_literal = match (
         string_literal_changes(origin, ours),
         string_literal_changes(origin, theirs),
     ) {
@@ -743,9 +1731,42 @@ fn merge_string_literal(
         _ => return Err(format!("merge conflict in object {}", origin.id)),
     };
 
+    m_string_literal.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_string_literal)
 }
 
+fn char_literal_changes(
+    origin: &expression_object::char_literal::CharLiteral,
+    alt: &expression_object::char_literal::CharLiteral,
+) -> Option<expression_object::char_literal::CharLiteral> {
+    if alt.value != origin.value || alt.prefix != origin.prefix {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_char_literal(
+    origin: &expression_object::char_literal::CharLiteral,
+    ours: &expression_object::char_literal::CharLiteral,
+    theirs: &expression_object::char_literal::CharLiteral,
+) -> Result<expression_object::char_literal::CharLiteral, String> {
+    let mut m_char_literal = match (
+        char_literal_changes(origin, ours),
+        char_literal_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_char_literal)) => m_char_literal,
+        (Some(m_char_literal), None) => m_char_literal,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_char_literal.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_char_literal)
+}
+
 fn merge_compound_statement(
     origin: &statement_object::compound_statement::CompoundStatement,
     ours: &statement_object::compound_statement::CompoundStatement,
@@ -763,6 +1784,9 @@ fn merge_compound_statement(
     let mut m_compound_statement = origin.clone();
     m_compound_statement.code_block = m_code_block;
 
+    m_compound_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
     Ok(m_compound_statement)
 }
 
@@ -788,181 +1812,624 @@ fn merge_else_clause(
         theirs.body.as_ref(),
     )?;
 
-    let mut m_else_clause = origin.clone();
-    m_else_clause.body = Box::new(m_body);
-    Ok(m_else_clause)
+    let mut m_else_clause = origin.clone();
+    m_else_clause.body = Box::new(m_body);
+    m_else_clause.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_else_clause)
+}
+
+fn else_statement_changes(
+    origin: &statement_object::if_statement::ElseStatement,
+    alt: &statement_object::if_statement::ElseStatement,
+) -> Result<Option<statement_object::if_statement::ElseStatement>, String> {
+    match (origin, alt) {
+        (
+            statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
+            statement_object::if_statement::ElseStatement::ElseClause(alt_else_clause),
+        ) => Ok(else_clause_changes(origin_else_clause, alt_else_clause)
+            .map(|opt| statement_object::if_statement::ElseStatement::ElseClause(Box::new(opt)))),
+        (
+            statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
+            statement_object::if_statement::ElseStatement::ElseIf(alt_else_if),
+        ) => Ok(if_statement_changes(origin_else_if, alt_else_if)
+            .map(|opt| statement_object::if_statement::ElseStatement::ElseIf(Box::new(opt)))),
+        _ => Err("".to_string()),
+    }
+}
+
+fn merge_else_statement(
+    origin: &statement_object::if_statement::ElseStatement,
+    ours: &statement_object::if_statement::ElseStatement,
+    theirs: &statement_object::if_statement::ElseStatement,
+) -> Result<statement_object::if_statement::ElseStatement, String> {
+    let m_else_statement = match (origin, ours, theirs) {
+        (
+            statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
+            statement_object::if_statement::ElseStatement::ElseClause(ours_else_clause),
+            statement_object::if_statement::ElseStatement::ElseClause(theirs_else_clause),
+        ) => statement_object::if_statement::ElseStatement::ElseClause(Box::new(
+            merge_else_clause(origin_else_clause, ours_else_clause, theirs_else_clause)?,
+        )),
+        (
+            statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
+            statement_object::if_statement::ElseStatement::ElseIf(ours_else_if),
+            statement_object::if_statement::ElseStatement::ElseIf(theirs_else_if),
+        ) => statement_object::if_statement::ElseStatement::ElseIf(Box::new(merge_if_statement(
+            origin_else_if,
+            ours_else_if,
+            theirs_else_if,
+        )?)),
+        (
+            statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
+            statement_object::if_statement::ElseStatement::ElseClause(ours_else_clause),
+            statement_object::if_statement::ElseStatement::ElseIf(_),
+        ) if else_clause_changes(origin_else_clause, ours_else_clause).is_none() => theirs.clone(),
+        (
+            statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
+            statement_object::if_statement::ElseStatement::ElseIf(_),
+            statement_object::if_statement::ElseStatement::ElseClause(theirs_else_clause),
+        ) if else_clause_changes(origin_else_clause, theirs_else_clause).is_none() => ours.clone(),
+        (
+            statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
+            statement_object::if_statement::ElseStatement::ElseIf(ours_else_if),
+            statement_object::if_statement::ElseStatement::ElseClause(_),
+        ) if if_statement_changes(origin_else_if, ours_else_if).is_none() => theirs.clone(),
+        (
+            statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
+            statement_object::if_statement::ElseStatement::ElseClause(_),
+            statement_object::if_statement::ElseStatement::ElseIf(theirs_else_if),
+        ) if if_statement_changes(origin_else_if, theirs_else_if).is_none() => ours.clone(),
+        _ => return Err("".to_string()),
+    };
+
+    Ok(m_else_statement)
+}
+
+fn merge_ifs_else_statement(
+    origin: &Option<statement_object::if_statement::ElseStatement>,
+    ours: &Option<statement_object::if_statement::ElseStatement>,
+    theirs: &Option<statement_object::if_statement::ElseStatement>,
+) -> Result<Option<statement_object::if_statement::ElseStatement>, String> {
+    match (origin, ours, theirs) {
+        (Some(origin_statement), Some(ours_statement), Some(theirs_statement)) => {
+            let value = merge_else_statement(origin_statement, ours_statement, theirs_statement)?;
+            Ok(Some(value))
+        }
+        (None, None, None) => Ok(None),
+        (Some(origin_expression), None, Some(theirs_expression)) => {
+            if else_statement_changes(origin_expression, theirs_expression)?.is_none() {
+                Ok(None)
+            } else {
+                Err("merge conflict in declaration value".to_string())
+            }
+        }
+        (Some(origin_expression), Some(ours_expression), None) => {
+            if else_statement_changes(origin_expression, ours_expression)?.is_none() {
+                Ok(None)
+            } else {
+                Err("merge conflict in declaration value".to_string())
+            }
+        }
+        (None, Some(ours_expression), None) => Ok(Some(ours_expression.clone())),
+        (None, None, Some(theirs_expression)) => Ok(Some(theirs_expression.clone())),
+        _ => Err("merge conflict in declaration value".to_string()),
+    }
+}
+
+fn if_statement_changes(
+    origin: &statement_object::if_statement::IfStatement,
+    alt: &statement_object::if_statement::IfStatement,
+) -> Option<statement_object::if_statement::IfStatement> {
+    if alt.id != origin.id {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_if_statement(
+    origin: &statement_object::if_statement::IfStatement,
+    ours: &statement_object::if_statement::IfStatement,
+    theirs: &statement_object::if_statement::IfStatement,
+) -> Result<statement_object::if_statement::IfStatement, String> {
+    let m_condition =
+        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+
+    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+
+    let m_else_statement = merge_ifs_else_statement(
+        &origin.else_statement,
+        &ours.else_statement,
+        &theirs.else_statement,
+    )?;
+
+    let mut m_if_statement = origin.clone();
+    m_if_statement.condition = Box::new(m_condition);
+    m_if_statement.body = Box::new(m_body);
+    m_if_statement.else_statement = m_else_statement;
+
+    m_if_statement.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_if_statement)
+}
+
+fn merge_return_statement(
+    origin: &statement_object::return_statement::ReturnStatement,
+    ours: &statement_object::return_statement::ReturnStatement,
+    theirs: &statement_object::return_statement::ReturnStatement,
+) -> Result<statement_object::return_statement::ReturnStatement, String> {
+    let m_value = merge_value(
+        origin.value.as_ref(),
+        ours.value.as_ref(),
+        theirs.value.as_ref(),
+    )?;
+
+    let mut m_return_statement = origin.clone();
+    m_return_statement.value = m_value;
+    m_return_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_return_statement)
+}
+
+fn merge_while_statement(
+    origin: &statement_object::while_statement::WhileStatement,
+    ours: &statement_object::while_statement::WhileStatement,
+    theirs: &statement_object::while_statement::WhileStatement,
+) -> Result<statement_object::while_statement::WhileStatement, String> {
+    let m_condition =
+        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+
+    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+
+    let mut m_while_statement = origin.clone();
+    m_while_statement.condition = Box::new(m_condition);
+    m_while_statement.body = Box::new(m_body);
+    m_while_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_while_statement)
+}
+
+fn merge_do_statement(
+    origin: &statement_object::do_statement::DoStatement,
+    ours: &statement_object::do_statement::DoStatement,
+    theirs: &statement_object::do_statement::DoStatement,
+) -> Result<statement_object::do_statement::DoStatement, String> {
+    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+
+    let m_condition =
+        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+
+    let mut m_do_statement = origin.clone();
+    m_do_statement.body = Box::new(m_body);
+    m_do_statement.condition = Box::new(m_condition);
+    m_do_statement.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_do_statement)
+}
+
+fn merge_for_initializer(
+    origin: Option<&compound_statement_object::CompoundStatementObject>,
+    ours: Option<&compound_statement_object::CompoundStatementObject>,
+    theirs: Option<&compound_statement_object::CompoundStatementObject>,
+) -> Result<Option<compound_statement_object::CompoundStatementObject>, String> {
+    match (origin, ours, theirs) {
+        (Some(origin_initializer), Some(ours_initializer), Some(theirs_initializer)) => {
+            let value = merge_compound_statement_object(
+                origin_initializer,
+                ours_initializer,
+                theirs_initializer,
+            )?;
+            Ok(Some(value))
+        }
+        (None, None, None) => Ok(None),
+        (Some(origin_initializer), None, Some(theirs_initializer))
+            if origin_initializer == theirs_initializer =>
+        {
+            Ok(None)
+        }
+        (Some(origin_initializer), Some(ours_initializer), None)
+            if origin_initializer == ours_initializer =>
+        {
+            Ok(None)
+        }
+        (None, Some(ours_initializer), None) => Ok(Some(ours_initializer.clone())),
+        (None, None, Some(theirs_initializer)) => Ok(Some(theirs_initializer.clone())),
+        _ => Err("merge conflict in for statement initializer".to_string()),
+    }
+}
+
+fn merge_for_statement(
+    origin: &statement_object::for_statement::ForStatement,
+    ours: &statement_object::for_statement::ForStatement,
+    theirs: &statement_object::for_statement::ForStatement,
+) -> Result<statement_object::for_statement::ForStatement, String> {
+    let m_initializer = merge_for_initializer(
+        origin.initializer.as_deref(),
+        ours.initializer.as_deref(),
+        theirs.initializer.as_deref(),
+    )?;
+
+    let m_condition = merge_value(
+        origin.condition.as_deref(),
+        ours.condition.as_deref(),
+        theirs.condition.as_deref(),
+    )?;
+
+    let m_update = merge_value(
+        origin.update.as_deref(),
+        ours.update.as_deref(),
+        theirs.update.as_deref(),
+    )?;
+
+    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+
+    let mut m_for_statement = origin.clone();
+    m_for_statement.initializer = m_initializer.map(Box::new);
+    m_for_statement.condition = m_condition.map(Box::new);
+    m_for_statement.update = m_update.map(Box::new);
+    m_for_statement.body = Box::new(m_body);
+    m_for_statement.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_for_statement)
+}
+
+fn merge_switch_statement(
+    origin: &statement_object::switch_statement::SwitchStatement,
+    ours: &statement_object::switch_statement::SwitchStatement,
+    theirs: &statement_object::switch_statement::SwitchStatement,
+) -> Result<statement_object::switch_statement::SwitchStatement, String> {
+    let m_condition =
+        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+
+    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+
+    let mut m_switch_statement = origin.clone();
+    m_switch_statement.condition = Box::new(m_condition);
+    m_switch_statement.body = Box::new(m_body);
+    m_switch_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_switch_statement)
+}
+
+fn merge_case_statement(
+    origin: &statement_object::case_statement::CaseStatement,
+    ours: &statement_object::case_statement::CaseStatement,
+    theirs: &statement_object::case_statement::CaseStatement,
+) -> Result<statement_object::case_statement::CaseStatement, String> {
+    let m_value = merge_value(
+        origin.value.as_deref(),
+        ours.value.as_deref(),
+        theirs.value.as_deref(),
+    )?;
+
+    let m_body = merge_by_id(
+        &origin.body,
+        &ours.body,
+        &theirs.body,
+        compound_statement_object::CompoundStatementObject::id,
+        merge_compound_statement_object,
+    )?;
+
+    let mut m_case_statement = origin.clone();
+    m_case_statement.value = m_value.map(Box::new);
+    m_case_statement.body = m_body;
+    m_case_statement.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_case_statement)
+}
+
+fn merge_labeled_statement(
+    origin: &statement_object::labeled_statement::LabeledStatement,
+    ours: &statement_object::labeled_statement::LabeledStatement,
+    theirs: &statement_object::labeled_statement::LabeledStatement,
+) -> Result<statement_object::labeled_statement::LabeledStatement, String> {
+    let m_label = match (ours.label != origin.label, theirs.label != origin.label) {
+        (false, false) => origin.label.clone(),
+        (true, false) => ours.label.clone(),
+        (false, true) => theirs.label.clone(),
+        (true, true) if ours.label == theirs.label => ours.label.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    let m_statement = match (
+        origin.statement.as_deref(),
+        ours.statement.as_deref(),
+        theirs.statement.as_deref(),
+    ) {
+        (Some(origin_statement), Some(ours_statement), Some(theirs_statement)) => Some(
+            merge_compound_statement_object(origin_statement, ours_statement, theirs_statement)?,
+        ),
+        (None, None, None) => None,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    let mut m_labeled_statement = origin.clone();
+    m_labeled_statement.label = m_label;
+    m_labeled_statement.statement = m_statement.map(Box::new);
+    m_labeled_statement.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_labeled_statement)
+}
+
+fn goto_statement_changes(
+    origin: &statement_object::goto_statement::GotoStatement,
+    alt: &statement_object::goto_statement::GotoStatement,
+) -> Option<statement_object::goto_statement::GotoStatement> {
+    if alt.label_id != origin.label_id {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_goto_statement(
+    origin: &statement_object::goto_statement::GotoStatement,
+    ours: &statement_object::goto_statement::GotoStatement,
+    theirs: &statement_object::goto_statement::GotoStatement,
+) -> Result<statement_object::goto_statement::GotoStatement, String> {
+    let mut m_goto_statement = match (
+        goto_statement_changes(origin, ours),
+        goto_statement_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_goto_statement)) => m_goto_statement,
+        (Some(m_goto_statement), None) => m_goto_statement,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_goto_statement.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_goto_statement)
 }
 
-fn else_statement_changes(
-    origin: &statement_object::if_statement::ElseStatement,
-    alt: &statement_object::if_statement::ElseStatement,
-) -> Result<Option<statement_object::if_statement::ElseStatement>, String> {
-    match (origin, alt) {
-        (
-            statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
-            statement_object::if_statement::ElseStatement::ElseClause(alt_else_clause),
-        ) => Ok(else_clause_changes(origin_else_clause, alt_else_clause)
-            .map(|opt| statement_object::if_statement::ElseStatement::ElseClause(Box::new(opt)))),
-        (
-            statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
-            statement_object::if_statement::ElseStatement::ElseIf(alt_else_if),
-        ) => Ok(if_statement_changes(origin_else_if, alt_else_if)
-            .map(|opt| statement_object::if_statement::ElseStatement::ElseIf(Box::new(opt)))),
-        _ => Err("".to_string()),
+/// Merges the items of a list, like the fields of a struct, pairing them by id. Items on the
+/// three sides are merged with `merge_item`, an item removed on one side is removed unless the
+/// other side changed it, and an item added on one side is kept after the item it follows there
+fn merge_by_id<T, I, M>(
+    origin: &[T],
+    ours: &[T],
+    theirs: &[T],
+    id: I,
+    merge_item: M,
+) -> Result<Vec<T>, String>
+where
+    T: Clone + PartialEq,
+    I: Fn(&T) -> uuid::Uuid,
+    M: Fn(&T, &T, &T) -> Result<T, String>,
+{
+    fn find<T>(items: &[T], id: impl Fn(&T) -> uuid::Uuid, item_id: uuid::Uuid) -> Option<&T> {
+        items.iter().find(|item| id(item) == item_id)
     }
-}
 
-fn merge_else_statement(
-    origin: &statement_object::if_statement::ElseStatement,
-    ours: &statement_object::if_statement::ElseStatement,
-    theirs: &statement_object::if_statement::ElseStatement,
-) -> Result<statement_object::if_statement::ElseStatement, String> {
-    let m_else_statement =
-        match (origin, ours, theirs) {
-            (
-                statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
-                statement_object::if_statement::ElseStatement::ElseClause(ours_else_clause),
-                statement_object::if_statement::ElseStatement::ElseClause(theirs_else_clause),
-            ) => statement_object::if_statement::ElseStatement::ElseClause(Box::new(
-                merge_else_clause(origin_else_clause, ours_else_clause, theirs_else_clause)?,
-            )),
-            (
-                statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
-                statement_object::if_statement::ElseStatement::ElseIf(ours_else_if),
-                statement_object::if_statement::ElseStatement::ElseIf(theirs_else_if),
-            ) => statement_object::if_statement::ElseStatement::ElseIf(Box::new(
-                merge_if_statement(origin_else_if, ours_else_if, theirs_else_if)?,
-            )),
-            (
-                statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
-                statement_object::if_statement::ElseStatement::ElseClause(ours_else_clause),
-                statement_object::if_statement::ElseStatement::ElseIf(_),
-            ) => {
-                if else_clause_changes(origin_else_clause, ours_else_clause).is_none() {
-                    theirs.clone()
-                } else {
-                    return Err("".to_string());
-                }
+    let mut merged = vec![];
+    for item in ours {
+        let item_id = id(item);
+        match (find(origin, &id, item_id), find(theirs, &id, item_id)) {
+            (Some(origin_item), Some(theirs_item)) => {
+                merged.push(merge_item(origin_item, item, theirs_item)?);
             }
-            (
-                statement_object::if_statement::ElseStatement::ElseClause(origin_else_clause),
-                statement_object::if_statement::ElseStatement::ElseIf(_),
-                statement_object::if_statement::ElseStatement::ElseClause(theirs_else_clause),
-            ) => {
-                if else_clause_changes(origin_else_clause, theirs_else_clause).is_none() {
-                    ours.clone()
-                } else {
-                    return Err("".to_string());
-                }
-            }
-            (
-                statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
-                statement_object::if_statement::ElseStatement::ElseIf(ours_else_if),
-                statement_object::if_statement::ElseStatement::ElseClause(_),
-            ) => {
-                if if_statement_changes(origin_else_if, ours_else_if).is_none() {
-                    theirs.clone()
-                } else {
-                    return Err("".to_string());
-                }
-            }
-            (
-                statement_object::if_statement::ElseStatement::ElseIf(origin_else_if),
-                statement_object::if_statement::ElseStatement::ElseClause(_),
-                statement_object::if_statement::ElseStatement::ElseIf(theirs_else_if),
-            ) => {
-                if if_statement_changes(origin_else_if, theirs_else_if).is_none() {
-                    ours.clone()
-                } else {
-                    return Err("".to_string());
-                }
+            // Removed in theirs, after ours changed it
+            (Some(origin_item), None) if item != origin_item => {
+                return Err(format!("merge conflict in object {item_id}"));
             }
-            _ => return Err("".to_string()),
-        };
-
-    Ok(m_else_statement)
-}
-
-fn merge_ifs_else_statement(
-    origin: &Option<statement_object::if_statement::ElseStatement>,
-    ours: &Option<statement_object::if_statement::ElseStatement>,
-    theirs: &Option<statement_object::if_statement::ElseStatement>,
-) -> Result<Option<statement_object::if_statement::ElseStatement>, String> {
-    match (origin, ours, theirs) {
-        (Some(origin_statement), Some(ours_statement), Some(theirs_statement)) => {
-            let value = merge_else_statement(origin_statement, ours_statement, theirs_statement)?;
-            Ok(Some(value))
+            (Some(_), None) => {}
+            // Added in ours
+            (None, _) => merged.push(item.clone()),
         }
-        (None, None, None) => Ok(None),
-        (Some(origin_expression), None, Some(theirs_expression)) => {
-            if else_statement_changes(origin_expression, theirs_expression)?.is_none() {
-                Ok(None)
-            } else {
-                Err("merge conflict in declaration value".to_string())
+    }
+    for (index, item) in theirs.iter().enumerate() {
+        let item_id = id(item);
+        match (find(origin, &id, item_id), find(ours, &id, item_id)) {
+            // Removed in ours, after theirs changed it
+            (Some(origin_item), None) if item != origin_item => {
+                return Err(format!("merge conflict in object {item_id}"));
             }
-        }
-        (Some(origin_expression), Some(ours_expression), None) => {
-            if else_statement_changes(origin_expression, ours_expression)?.is_none() {
-                Ok(None)
-            } else {
-                Err("merge conflict in declaration value".to_string())
+            // Added in theirs
+            (None, None) => {
+                let position = theirs[..index]
+                    .iter()
+                    .rev()
+                    .find_map(|previous| {
+                        merged
+                            .iter()
+                            .position(|merged_item| id(merged_item) == id(previous))
+                    })
+                    .map_or(0, |position| position + 1);
+                merged.insert(position, item.clone());
             }
+            _ => {}
         }
-        (None, Some(ours_expression), None) => Ok(Some(ours_expression.clone())),
-        (None, None, Some(theirs_expression)) => Ok(Some(theirs_expression.clone())),
-        _ => Err("merge conflict in declaration value".to_string()),
     }
+
+    Ok(merged)
 }
 
-fn if_statement_changes(
-    origin: &statement_object::if_statement::IfStatement,
-    alt: &statement_object::if_statement::IfStatement,
-) -> Option<statement_object::if_statement::IfStatement> {
-    if alt.id != origin.id {
+fn field_declaration_changes(
+    origin: &declaration_object::struct_definition::field_declaration::FieldDeclaration,
+    alt: &declaration_object::struct_definition::field_declaration::FieldDeclaration,
+) -> Option<declaration_object::struct_definition::field_declaration::FieldDeclaration> {
+    if alt.field_type != origin.field_type
+        || alt.identifier != origin.identifier
+        || alt.shares_declaration != origin.shares_declaration
+    {
         Some(alt.clone())
     } else {
         None
     }
 }
 
-fn merge_if_statement(
-    origin: &statement_object::if_statement::IfStatement,
-    ours: &statement_object::if_statement::IfStatement,
-    theirs: &statement_object::if_statement::IfStatement,
-) -> Result<statement_object::if_statement::IfStatement, String> {
-    let m_condition =
-        merge_expression_objects(&origin.condition, &ours.condition, &theirs.condition)?;
+fn merge_fields(
+    origin: &[declaration_object::struct_definition::field_declaration::FieldDeclaration],
+    ours: &[declaration_object::struct_definition::field_declaration::FieldDeclaration],
+    theirs: &[declaration_object::struct_definition::field_declaration::FieldDeclaration],
+) -> Result<Vec<declaration_object::struct_definition::field_declaration::FieldDeclaration>, String>
+{
+    merge_by_id(
+        origin,
+        ours,
+        theirs,
+        |field| field.id,
+        |field, ours, theirs| {
+            let mut m_field = match (
+                field_declaration_changes(field, ours),
+                field_declaration_changes(field, theirs),
+            ) {
+                (None, None) => field.clone(),
+                (None, Some(m_field)) => m_field,
+                (Some(m_field), None) => m_field,
+                _ => return Err(format!("merge conflict in object {}", field.id)),
+            };
+            m_field.comments = merge_comments(&field.comments, &ours.comments, &theirs.comments)?;
+            Ok(m_field)
+        },
+    )
+}
 
-    let m_body = merge_compound_statement_object(&origin.body, &ours.body, &theirs.body)?;
+fn merge_struct_definition(
+    origin: &declaration_object::struct_definition::StructDefinition,
+    ours: &declaration_object::struct_definition::StructDefinition,
+    theirs: &declaration_object::struct_definition::StructDefinition,
+) -> Result<declaration_object::struct_definition::StructDefinition, String> {
+    let m_fields = merge_fields(&origin.fields, &ours.fields, &theirs.fields)?;
 
-    let m_else_statement = merge_ifs_else_statement(
-        &origin.else_statement,
-        &ours.else_statement,
-        &theirs.else_statement,
-    )?;
+    let mut m_struct_definition = match (
+        (&origin.identifier, origin.is_forward) != (&ours.identifier, ours.is_forward),
+        (&origin.identifier, origin.is_forward) != (&theirs.identifier, theirs.is_forward),
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
 
-    let mut m_if_statement = origin.clone();
-    m_if_statement.condition = Box::new(m_condition);
-    m_if_statement.body = Box::new(m_body);
-    m_if_statement.else_statement = m_else_statement;
+    m_struct_definition.fields = m_fields;
 
-    Ok(m_if_statement)
+    m_struct_definition.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_struct_definition)
 }
 
-fn merge_return_statement(
-    origin: &statement_object::return_statement::ReturnStatement,
-    ours: &statement_object::return_statement::ReturnStatement,
-    theirs: &statement_object::return_statement::ReturnStatement,
-) -> Result<statement_object::return_statement::ReturnStatement, String> {
+fn merge_union_definition(
+    origin: &declaration_object::union_definition::UnionDefinition,
+    ours: &declaration_object::union_definition::UnionDefinition,
+    theirs: &declaration_object::union_definition::UnionDefinition,
+) -> Result<declaration_object::union_definition::UnionDefinition, String> {
+    let m_fields = merge_fields(&origin.fields, &ours.fields, &theirs.fields)?;
+
+    let mut m_union_definition = match (
+        (&origin.identifier, origin.is_forward) != (&ours.identifier, ours.is_forward),
+        (&origin.identifier, origin.is_forward) != (&theirs.identifier, theirs.is_forward),
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_union_definition.fields = m_fields;
+
+    m_union_definition.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_union_definition)
+}
+
+fn merge_enumerator(
+    origin: &declaration_object::enum_definition::enumerator::Enumerator,
+    ours: &declaration_object::enum_definition::enumerator::Enumerator,
+    theirs: &declaration_object::enum_definition::enumerator::Enumerator,
+) -> Result<declaration_object::enum_definition::enumerator::Enumerator, String> {
     let m_value = merge_value(
-        origin.value.as_ref(),
-        ours.value.as_ref(),
-        theirs.value.as_ref(),
+        origin.value.as_deref(),
+        ours.value.as_deref(),
+        theirs.value.as_deref(),
     )?;
 
-    let mut m_return_statement = origin.clone();
-    m_return_statement.value = m_value;
-    Ok(m_return_statement)
+    let mut m_enumerator = match (
+        origin.identifier != ours.identifier,
+        origin.identifier != theirs.identifier,
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_enumerator.value = m_value.map(Box::new);
+
+    m_enumerator.comments = merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_enumerator)
+}
+
+fn merge_enum_definition(
+    origin: &declaration_object::enum_definition::EnumDefinition,
+    ours: &declaration_object::enum_definition::EnumDefinition,
+    theirs: &declaration_object::enum_definition::EnumDefinition,
+) -> Result<declaration_object::enum_definition::EnumDefinition, String> {
+    let m_enumerators = merge_by_id(
+        &origin.enumerators,
+        &ours.enumerators,
+        &theirs.enumerators,
+        |enumerator| enumerator.id,
+        merge_enumerator,
+    )?;
+
+    let mut m_enum_definition = match (
+        (&origin.identifier, origin.is_forward) != (&ours.identifier, ours.is_forward),
+        (&origin.identifier, origin.is_forward) != (&theirs.identifier, theirs.is_forward),
+    ) {
+        (false, false) => origin.clone(),
+        (false, true) => theirs.clone(),
+        (true, false) => ours.clone(),
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_enum_definition.enumerators = m_enumerators;
+
+    m_enum_definition.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_enum_definition)
+}
+
+fn type_definition_changes(
+    origin: &declaration_object::type_definition::TypeDefinition,
+    alt: &declaration_object::type_definition::TypeDefinition,
+) -> Option<declaration_object::type_definition::TypeDefinition> {
+    if alt.aliased_type != origin.aliased_type || alt.identifier != origin.identifier {
+        Some(alt.clone())
+    } else {
+        None
+    }
+}
+
+fn merge_type_definition(
+    origin: &declaration_object::type_definition::TypeDefinition,
+    ours: &declaration_object::type_definition::TypeDefinition,
+    theirs: &declaration_object::type_definition::TypeDefinition,
+) -> Result<declaration_object::type_definition::TypeDefinition, String> {
+    let mut m_type_definition = match (
+        type_definition_changes(origin, ours),
+        type_definition_changes(origin, theirs),
+    ) {
+        (None, None) => origin.clone(),
+        (None, Some(m_type_definition)) => m_type_definition,
+        (Some(m_type_definition), None) => m_type_definition,
+        (Some(ours_change), Some(theirs_change)) if ours_change == theirs_change => ours_change,
+        _ => return Err(format!("merge conflict in object {}", origin.id)),
+    };
+
+    m_type_definition.comments =
+        merge_comments(&origin.comments, &ours.comments, &theirs.comments)?;
+
+    Ok(m_type_definition)
 }
 
 #[cfg(test)]
@@ -971,6 +2438,7 @@ mod tests {
     use lenga::language::c::{
         c_type::CType,
         language_object::statement_object::compound_statement::compound_statement_object,
+        specifiers::Specifiers,
     };
     use uuid::Uuid;
 
@@ -978,7 +2446,9 @@ mod tests {
     fn test_01_merge_empty_files() {
         let origin = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![],
+            trivia: None,
         };
 
         let merger = Merger::new();
@@ -997,17 +2467,20 @@ mod tests {
         let content_comment_origin = "origin".to_string();
         let comment_origin = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_origin.clone(),
         };
 
         let content_comment_ours = "ours".to_string();
         let comment_ours = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_ours.clone(),
         };
 
         let comment_theirs = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_origin,
         };
 
@@ -1023,18 +2496,21 @@ mod tests {
         let content_comment_origin = "origin".to_string();
         let comment_origin = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_origin.clone(),
         };
 
         let content_comment_ours = "ours".to_string();
         let comment_ours = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_ours.clone(),
         };
 
         let content_comment_theirs = "theirs".to_string();
         let comment_theirs = special_object::comment::Comment {
             id,
+            span: None,
             content: content_comment_theirs,
         };
 
@@ -1050,6 +2526,9 @@ mod tests {
         let declaration_identifier_origin = "origin".to_string();
         let declaration_origin = declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: declaration_identifier_origin.clone(),
             value: None,
@@ -1057,6 +2536,9 @@ mod tests {
 
         let declaration_ours = declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: declaration_identifier_origin,
             value: None,
@@ -1065,6 +2547,9 @@ mod tests {
         let declaration_identifier_theirs = "theirs".to_string();
         let declaration_theirs = declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: declaration_identifier_theirs.clone(),
             value: None,
@@ -1086,6 +2571,9 @@ mod tests {
         let declaration_identifier_origin = "origin".to_string();
         let declaration_origin = declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: declaration_identifier_origin.clone(),
             value: None,
@@ -1095,12 +2583,16 @@ mod tests {
         let value = "0".to_string();
         let number_literal = expression_object::number_literal::NumberLiteral {
             id: id_number_literal,
-            value: value.clone(),
+            ..expression_object::number_literal::NumberLiteral::from_spelling(value.clone())
+                .unwrap()
         };
 
         let declaration_identifier_ours = "ours".to_string();
         let declaration_ours = declaration_object::declaration::Declaration {
             id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type.clone(),
             identifier: declaration_identifier_ours.clone(),
             value: Some(Box::new(
@@ -1122,7 +2614,7 @@ mod tests {
             declaration.value.as_deref()
         {
             assert_eq!(number_literal.id, id_number_literal);
-            assert_eq!(number_literal.value, value);
+            assert_eq!(number_literal.spelling, value);
         }
     }
 
@@ -1134,6 +2626,8 @@ mod tests {
         let param_origin =
             declaration_object::function_declaration::function_parameter::FunctionParameter {
                 id: id_param,
+                span: None,
+                comments: special_object::comment::Comments::default(),
                 identifier: identifier_param_origin,
                 param_type: param_type_origin,
             };
@@ -1144,9 +2638,14 @@ mod tests {
         let function_declaration_origin =
             declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
                 return_type: return_type_origin,
                 identifier: identifier_origin,
                 parameter_list: vec![param_origin],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let identifier_param_ours = "param_ours".to_string();
@@ -1154,6 +2653,8 @@ mod tests {
         let param_ours =
             declaration_object::function_declaration::function_parameter::FunctionParameter {
                 id: id_param,
+                span: None,
+                comments: special_object::comment::Comments::default(),
                 identifier: identifier_param_ours.clone(),
                 param_type: param_type_ours.clone(),
             };
@@ -1163,9 +2664,14 @@ mod tests {
         let function_declaration_ours =
             declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
                 return_type: return_type_ours.clone(),
                 identifier: identifier_ours.clone(),
                 parameter_list: vec![param_ours],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let function_declaration = merge_function_declaration(
@@ -1201,6 +2707,8 @@ mod tests {
         let param_origin =
             declaration_object::function_declaration::function_parameter::FunctionParameter {
                 id: id_param,
+                span: None,
+                comments: special_object::comment::Comments::default(),
                 identifier: identifier_param_origin,
                 param_type: param_type_origin,
             };
@@ -1211,9 +2719,14 @@ mod tests {
         let function_declaration_origin =
             declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
                 return_type: return_type_origin.clone(),
                 identifier: identifier_origin.clone(),
                 parameter_list: vec![param_origin],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let identifier_param_ours = "param_ours".to_string();
@@ -1221,6 +2734,8 @@ mod tests {
         let param_ours =
             declaration_object::function_declaration::function_parameter::FunctionParameter {
                 id: id_param,
+                span: None,
+                comments: special_object::comment::Comments::default(),
                 identifier: identifier_param_ours.clone(),
                 param_type: param_type_ours.clone(),
             };
@@ -1230,9 +2745,14 @@ mod tests {
         let function_declaration_ours =
             declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
                 return_type: return_type_ours.clone(),
                 identifier: identifier_ours.clone(),
                 parameter_list: vec![param_ours],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         let identifier_param_theirs = "param_theirs".to_string();
@@ -1240,6 +2760,8 @@ mod tests {
         let param_theirs =
             declaration_object::function_declaration::function_parameter::FunctionParameter {
                 id: id_param,
+                span: None,
+                comments: special_object::comment::Comments::default(),
                 identifier: identifier_param_theirs.clone(),
                 param_type: param_type_theirs.clone(),
             };
@@ -1247,9 +2769,14 @@ mod tests {
         let function_declaration_theirs =
             declaration_object::function_declaration::FunctionDeclaration {
                 id,
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
                 return_type: return_type_origin,
                 identifier: identifier_origin,
                 parameter_list: vec![param_theirs],
+                is_variadic: false,
+                explicit_void: false,
             };
 
         assert!(
@@ -1271,6 +2798,9 @@ mod tests {
         let identifier_one = "a".to_string();
         let mut declaration_one = declaration_object::declaration::Declaration {
             id: id_decl_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_one,
             identifier: identifier_one.clone(),
             value: None,
@@ -1281,6 +2811,9 @@ mod tests {
         let identifier_two = "b".to_string();
         let mut declaration_two = declaration_object::declaration::Declaration {
             id: id_decl_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_two,
             identifier: identifier_two.clone(),
             value: None,
@@ -1289,6 +2822,8 @@ mod tests {
         let id_ref_one = Uuid::new_v4();
         let mut reference_one = expression_object::reference::Reference {
             id: id_ref_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_one,
             identifier: identifier_one.clone(),
         };
@@ -1296,6 +2831,8 @@ mod tests {
         let id_ref_two = Uuid::new_v4();
         let mut reference_two = expression_object::reference::Reference {
             id: id_ref_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_two,
             identifier: identifier_two.clone(),
         };
@@ -1304,6 +2841,8 @@ mod tests {
         let operator = "+".to_string();
         let mut operation = expression_object::binary_expression::BinaryExpression {
             id: id_operation,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             left: Box::new(expression_object::ExpressionObject::Reference(
                 reference_one.clone(),
             )),
@@ -1316,6 +2855,8 @@ mod tests {
         let id_comp_stmt = Uuid::new_v4();
         let comp_stmt = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1334,17 +2875,24 @@ mod tests {
         let identifier_function = "main".to_string();
         let function = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier_function.clone(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt.clone(),
         };
 
         let origin = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function.clone(),
             )],
+            trivia: None,
         };
 
         //Creating ours
@@ -1353,12 +2901,12 @@ mod tests {
         declaration_one.identifier = new_identifier_one.clone();
         reference_one.identifier = new_identifier_one.clone();
 
-        operation.left = Box::new(expression_object::ExpressionObject::Reference(
-            reference_one.clone(),
-        ));
+        *operation.left = expression_object::ExpressionObject::Reference(reference_one.clone());
 
         let comp_stmt_ours = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1374,17 +2922,24 @@ mod tests {
 
         let function_ours = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier_function.clone(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt_ours,
         };
 
         let ours = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function_ours,
             )],
+            trivia: None,
         };
 
         //Creating theirs
@@ -1396,16 +2951,14 @@ mod tests {
         declaration_two.identifier = new_identifier_two.clone();
         reference_two.identifier = new_identifier_two.clone();
 
-        operation.left = Box::new(expression_object::ExpressionObject::Reference(
-            reference_one.clone(),
-        ));
+        *operation.left = expression_object::ExpressionObject::Reference(reference_one.clone());
 
-        operation.right = Box::new(expression_object::ExpressionObject::Reference(
-            reference_two.clone(),
-        ));
+        *operation.right = expression_object::ExpressionObject::Reference(reference_two.clone());
 
         let comp_stmt_theirs = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1421,17 +2974,24 @@ mod tests {
 
         let function_theirs = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type,
             identifier: identifier_function,
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt_theirs,
         };
 
         let theirs = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function_theirs,
             )],
+            trivia: None,
         };
 
         //Testing
@@ -1508,14 +3068,14 @@ mod tests {
         let value_one = "1".to_string();
         let number_one = expression_object::number_literal::NumberLiteral {
             id: id_number_one,
-            value: value_one,
+            ..expression_object::number_literal::NumberLiteral::from_spelling(value_one).unwrap()
         };
 
         let id_number_two = Uuid::new_v4();
         let value_two = "2".to_string();
         let number_two = expression_object::number_literal::NumberLiteral {
             id: id_number_two,
-            value: value_two,
+            ..expression_object::number_literal::NumberLiteral::from_spelling(value_two).unwrap()
         };
 
         let id_decl_one = Uuid::new_v4();
@@ -1523,6 +3083,9 @@ mod tests {
         let mut identifier_one = "a".to_string();
         let declaration_one = declaration_object::declaration::Declaration {
             id: id_decl_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_one.clone(),
             identifier: identifier_one.clone(),
             value: Some(Box::new(
@@ -1535,6 +3098,9 @@ mod tests {
         let mut identifier_two = "b".to_string();
         let declaration_two = declaration_object::declaration::Declaration {
             id: id_decl_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_two.clone(),
             identifier: identifier_two.clone(),
             value: Some(Box::new(
@@ -1545,6 +3111,8 @@ mod tests {
         let id_ref_one = Uuid::new_v4();
         let reference_one = expression_object::reference::Reference {
             id: id_ref_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_one,
             identifier: identifier_one.clone(),
         };
@@ -1552,6 +3120,8 @@ mod tests {
         let id_ref_two = Uuid::new_v4();
         let reference_two = expression_object::reference::Reference {
             id: id_ref_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_two,
             identifier: identifier_two.clone(),
         };
@@ -1560,6 +3130,8 @@ mod tests {
         let operator = "+".to_string();
         let operation = expression_object::binary_expression::BinaryExpression {
             id: id_operation,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             left: Box::new(expression_object::ExpressionObject::Reference(
                 reference_one.clone(),
             )),
@@ -1574,6 +3146,9 @@ mod tests {
         let identifier_three = "c".to_string();
         let declaration_three = declaration_object::declaration::Declaration {
             id: id_decl_three,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_three.clone(),
             identifier: identifier_three.clone(),
             value: Some(Box::new(
@@ -1584,6 +3159,8 @@ mod tests {
         let id_comp_stmt = Uuid::new_v4();
         let comp_stmt = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1602,18 +3179,25 @@ mod tests {
         let identifier_function = "main".to_string();
         let function = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier_function.clone(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt.clone(),
         };
 
         let id = Uuid::new_v4();
         let origin = special_object::source_file::SourceFile {
             id,
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function.clone(),
             )],
+            trivia: None,
         };
 
         // Creating ours
@@ -1621,6 +3205,9 @@ mod tests {
         let new_identifier_one = "A".to_string();
         let declaration_one = declaration_object::declaration::Declaration {
             id: id_decl_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_one.clone(),
             identifier: new_identifier_one.clone(),
             value: Some(Box::new(
@@ -1631,6 +3218,9 @@ mod tests {
         let new_identifier_two = "B".to_string();
         let declaration_two = declaration_object::declaration::Declaration {
             id: id_decl_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_two.clone(),
             identifier: new_identifier_two.clone(),
             value: Some(Box::new(
@@ -1640,12 +3230,16 @@ mod tests {
 
         let reference_one = expression_object::reference::Reference {
             id: id_ref_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_one,
             identifier: identifier_one.clone(),
         };
 
         let reference_two = expression_object::reference::Reference {
             id: id_ref_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_two,
             identifier: identifier_two.clone(),
         };
@@ -1653,6 +3247,8 @@ mod tests {
         let operator = "+".to_string();
         let operation = expression_object::binary_expression::BinaryExpression {
             id: id_operation,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             left: Box::new(expression_object::ExpressionObject::Reference(
                 reference_one.clone(),
             )),
@@ -1665,6 +3261,9 @@ mod tests {
         let new_identifier_three = "C".to_string();
         let declaration_three = declaration_object::declaration::Declaration {
             id: id_decl_three,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_three.clone(),
             identifier: new_identifier_three.clone(),
             value: Some(Box::new(
@@ -1674,6 +3273,8 @@ mod tests {
 
         let comp_stmt = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1690,17 +3291,24 @@ mod tests {
         let identifier_function = "main".to_string();
         let function = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier_function.clone(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt.clone(),
         };
 
         let ours = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function.clone(),
             )],
+            trivia: None,
         };
 
         // Theirs
@@ -1708,6 +3316,9 @@ mod tests {
         identifier_one = "a".to_string();
         let declaration_one = declaration_object::declaration::Declaration {
             id: id_decl_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_one.clone(),
             identifier: identifier_one.clone(),
             value: Some(Box::new(
@@ -1718,6 +3329,9 @@ mod tests {
         identifier_two = "b".to_string();
         let declaration_two = declaration_object::declaration::Declaration {
             id: id_decl_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_two.clone(),
             identifier: identifier_two.clone(),
             value: Some(Box::new(
@@ -1727,12 +3341,16 @@ mod tests {
 
         let reference_one = expression_object::reference::Reference {
             id: id_ref_one,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_one,
             identifier: identifier_one.clone(),
         };
 
         let reference_two = expression_object::reference::Reference {
             id: id_ref_two,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             declaration_id: id_decl_two,
             identifier: identifier_two.clone(),
         };
@@ -1740,6 +3358,8 @@ mod tests {
         let operator = "+".to_string();
         let operation = expression_object::binary_expression::BinaryExpression {
             id: id_operation,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             left: Box::new(expression_object::ExpressionObject::Reference(
                 reference_two.clone(),
             )),
@@ -1752,6 +3372,9 @@ mod tests {
         let identifier_three = "c".to_string();
         let declaration_three = declaration_object::declaration::Declaration {
             id: id_decl_three,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             primitive_type: primitive_type_three.clone(),
             identifier: identifier_three.clone(),
             value: Some(Box::new(
@@ -1761,6 +3384,8 @@ mod tests {
 
         let comp_stmt = statement_object::compound_statement::CompoundStatement {
             id: id_comp_stmt,
+            span: None,
+            comments: special_object::comment::Comments::default(),
             code_block: vec![
                 compound_statement_object::CompoundStatementObject::Declaration(
                     declaration_one.clone(),
@@ -1777,17 +3402,24 @@ mod tests {
         let identifier_function = "main".to_string();
         let function = declaration_object::function_definition::FunctionDefinition {
             id: id_function,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
             return_type: return_type.clone(),
             identifier: identifier_function.clone(),
             parameter_list: vec![],
+            is_variadic: false,
+            explicit_void: false,
             compound_statement: comp_stmt.clone(),
         };
 
         let theirs = special_object::source_file::SourceFile {
             id: Uuid::new_v4(),
+            span: None,
             code: vec![declaration_object::DeclarationObject::FunctionDefinition(
                 function.clone(),
             )],
+            trivia: None,
         };
 
         // Testing
@@ -1878,4 +3510,316 @@ mod tests {
             panic!("expected FunctionDefinition");
         }
     }
+
+    #[test]
+    fn test_10_merge_specifiers_with_rename() {
+        let id = Uuid::new_v4();
+        let declaration_origin = declaration_object::declaration::Declaration {
+            id,
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            specifiers: Specifiers::default(),
+            primitive_type: CType::Int,
+            identifier: "origin".to_string(),
+            value: None,
+        };
+
+        let mut declaration_ours = declaration_origin.clone();
+        declaration_ours.specifiers.is_static = true;
+
+        let mut declaration_theirs = declaration_origin.clone();
+        declaration_theirs.identifier = "theirs".to_string();
+
+        let declaration =
+            merge_declaration(&declaration_origin, &declaration_ours, &declaration_theirs).unwrap();
+
+        assert_eq!(declaration.id, id);
+        assert_eq!(declaration.specifiers.keywords(), ["static"]);
+        assert_eq!(declaration.identifier, "theirs");
+
+        declaration_theirs.specifiers.is_extern = true;
+        let merge = merge_declaration(&declaration_origin, &declaration_ours, &declaration_theirs);
+        assert!(merge.is_err());
+    }
+
+    #[test]
+    fn test_11_merge_declaration_list() {
+        let number = |value: &str| {
+            expression_object::ExpressionObject::NumberLiteral(
+                expression_object::number_literal::NumberLiteral {
+                    id: Uuid::new_v4(),
+                    ..expression_object::number_literal::NumberLiteral::from_spelling(
+                        value.to_string(),
+                    )
+                    .unwrap()
+                },
+            )
+        };
+        let declaration =
+            |identifier: &str, value: &str| declaration_object::declaration::Declaration {
+                id: Uuid::new_v4(),
+                span: None,
+                comments: special_object::comment::Comments::default(),
+                specifiers: Specifiers::default(),
+                primitive_type: CType::Int,
+                identifier: identifier.to_string(),
+                value: Some(Box::new(number(value))),
+            };
+        let list_origin = declaration_object::declaration_list::DeclarationList {
+            id: Uuid::new_v4(),
+            span: None,
+            comments: special_object::comment::Comments::default(),
+            declarations: vec![declaration("a", "1"), declaration("b", "2")],
+        };
+
+        let mut list_ours = list_origin.clone();
+        list_ours.declarations[0].value = Some(Box::new(number("10")));
+
+        let mut list_theirs = list_origin.clone();
+        list_theirs.declarations[1].identifier = "count".to_string();
+
+        let merged = merge_declaration_list(&list_origin, &list_ours, &list_theirs).unwrap();
+
+        assert_eq!(merged.id, list_origin.id);
+        assert_eq!(
+            merged.declarations[0].value,
+            list_ours.declarations[0].value
+        );
+        assert_eq!(merged.declarations[1].identifier, "count");
+
+        list_theirs.declarations.pop();
+        let merge = merge_declaration_list(&list_origin, &list_ours, &list_theirs);
+        assert!(merge.is_err());
+    }
+
+    #[test]
+    fn test_12_merge_type_definition_keeps_uses_linked() {
+        use lenga::language::{Language, c::C};
+
+        let c = C::new();
+        let origin = c
+            .parse_text("typedef unsigned long size_type;\nsize_type count;\n")
+            .unwrap();
+
+        let mut ours = origin.clone();
+        let declaration_object::DeclarationObject::TypeDefinition(type_definition) =
+            &mut ours.code[0]
+        else {
+            panic!("expected a type definition");
+        };
+        type_definition.identifier = "length_t".to_string();
+
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::Declaration(declaration) = &mut theirs.code[1]
+        else {
+            panic!("expected a declaration");
+        };
+        declaration.identifier = "total".to_string();
+
+        let merged = Merger::new().merge(origin, ours, theirs).unwrap();
+
+        // Uses hold the id of the typedef, so they follow its rename
+        let nodes = c.write_to_nodes(merged).unwrap();
+        let text = c.write_to_text(c.parse_nodes(nodes).unwrap()).unwrap();
+        assert!(text.contains("typedef unsigned long length_t;"), "{text}");
+        assert!(text.contains("length_t total;"), "{text}");
+    }
+
+    #[test]
+    fn test_13_merge_comment_and_code_edits_separately() {
+        use lenga::language::{Language, c::C};
+
+        let c = C::new();
+        let origin = c
+            .parse_text(
+                "// Counts the items
+int count(int items)
+{
+    return items; // all of them
+}
+",
+            )
+            .unwrap();
+
+        let mut ours = origin.clone();
+        let declaration_object::DeclarationObject::FunctionDefinition(function) = &mut ours.code[0]
+        else {
+            panic!("expected a function definition");
+        };
+        function.comments.leading[0].content = "// Counts every item".to_string();
+
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::FunctionDefinition(function) =
+            &mut theirs.code[0]
+        else {
+            panic!("expected a function definition");
+        };
+        function.identifier = "total".to_string();
+
+        let merged = Merger::new()
+            .merge(origin.clone(), ours.clone(), theirs)
+            .unwrap();
+        let text = c.write_to_text(merged).unwrap();
+        assert!(
+            text.starts_with(
+                "// Counts every item
+int
+total(int items)"
+            ),
+            "{text}"
+        );
+        assert!(text.contains("return items; // all of them"), "{text}");
+
+        // Both sides rewording the same comment still conflict
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::FunctionDefinition(function) =
+            &mut theirs.code[0]
+        else {
+            panic!("expected a function definition");
+        };
+        function.comments.leading[0].content = "// Counts the elements".to_string();
+        assert!(Merger::new().merge(origin, ours, theirs).is_err());
+    }
+
+    #[test]
+    fn test_14_merge_assignment_to_field() {
+        use lenga::language::{Language, c::C};
+
+        let c = C::new();
+        let origin = c
+            .parse_text(
+                "struct pair
+{
+    int a;
+};
+void set(struct pair *p)
+{
+    p->a = 1;
+}
+",
+            )
+            .unwrap();
+        let mut ours = origin.clone();
+        let declaration_object::DeclarationObject::StructDefinition(pair) = &mut ours.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        pair.fields[0].identifier = "first".to_string();
+
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::FunctionDefinition(function) =
+            &mut theirs.code[1]
+        else {
+            panic!("expected a function definition");
+        };
+        let compound_statement_object::CompoundStatementObject::AssignmentExpression(assignment) =
+            &mut function.compound_statement.code_block[0]
+        else {
+            panic!("expected an assignment");
+        };
+        *assignment.value = expression_object::ExpressionObject::NumberLiteral(
+            expression_object::number_literal::NumberLiteral {
+                id: Uuid::new_v4(),
+                ..expression_object::number_literal::NumberLiteral::from_spelling("2".to_string())
+                    .unwrap()
+            },
+        );
+
+        let merged = Merger::new().merge(origin, ours, theirs).unwrap();
+
+        // The field expression holds the id of the field, so it follows the rename
+        let nodes = c.write_to_nodes(merged).unwrap();
+        let text = c.write_to_text(c.parse_nodes(nodes).unwrap()).unwrap();
+        assert!(text.contains("int first;"), "{text}");
+        assert!(text.contains("p->first = 2;"), "{text}");
+    }
+
+    #[test]
+    fn test_15_merge_layout_per_object() {
+        use lenga::language::{Language, c::C};
+
+        let c = C::with_trivia().with_deterministic_ids("main.c");
+        let origin = c.parse_text("int a = 1;\nint b = 2;\n").unwrap();
+        // Ours only moves b away from a
+        let ours = c.parse_text("int a = 1;\n\n\nint b = 2;\n").unwrap();
+        assert_eq!(ours, origin);
+
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::Declaration(declaration) = &mut theirs.code[0]
+        else {
+            panic!("expected a declaration");
+        };
+        declaration.identifier = "first".to_string();
+
+        let merged = Merger::new().merge(origin, ours, theirs).unwrap();
+        assert_eq!(
+            c.write_to_text(merged).unwrap(),
+            "int first = 1;\n\n\nint b = 2;\n"
+        );
+    }
+
+    #[test]
+    fn test_16_merge_added_and_removed_fields() {
+        use lenga::language::{Language, c::C};
+
+        let c = C::new();
+        let origin = c
+            .parse_text("struct point\n{\n    int x;\n    int y;\n};\n")
+            .unwrap();
+        let declaration_object::DeclarationObject::StructDefinition(point) = &origin.code[0] else {
+            panic!("expected a struct definition");
+        };
+
+        // Ours adds a field after y, theirs removes x
+        let mut ours = origin.clone();
+        let declaration_object::DeclarationObject::StructDefinition(ours_point) = &mut ours.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        let mut z = point.fields[1].clone();
+        z.id = Uuid::new_v4();
+        z.identifier = "z".to_string();
+        ours_point.fields.push(z);
+
+        let mut theirs = origin.clone();
+        let declaration_object::DeclarationObject::StructDefinition(theirs_point) =
+            &mut theirs.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        theirs_point.fields.remove(0);
+
+        let merged = Merger::new()
+            .merge(origin.clone(), ours.clone(), theirs.clone())
+            .unwrap();
+        let declaration_object::DeclarationObject::StructDefinition(merged_point) = &merged.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        let identifiers: Vec<&str> = merged_point
+            .fields
+            .iter()
+            .map(|field| field.identifier.as_str())
+            .collect();
+        assert_eq!(identifiers, ["y", "z"]);
+        // The same from the other side
+        let merged = Merger::new()
+            .merge(origin.clone(), theirs.clone(), ours)
+            .unwrap();
+        let declaration_object::DeclarationObject::StructDefinition(merged_point) = &merged.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        assert_eq!(merged_point.fields.len(), 2);
+
+        // A field removed on one side and changed on the other is a conflict
+        let mut renamed = origin.clone();
+        let declaration_object::DeclarationObject::StructDefinition(renamed_point) =
+            &mut renamed.code[0]
+        else {
+            panic!("expected a struct definition");
+        };
+        renamed_point.fields[0].identifier = "first".to_string();
+        assert!(Merger::new().merge(origin, renamed, theirs).is_err());
+    }
 }
@@ -2,25 +2,112 @@ pub mod transpiler;
 
 use std::{
     env::args,
-    fs::File,
+    fs::{self, File},
     io::{BufReader, Read, Write},
-    path::Path,
+    path::{Path, PathBuf},
+};
+
+use lenga::{
+    language::diagnostic::Diagnostic,
+    node::encoding::{self, Encoding},
 };
 
 use crate::transpiler::Transpiler;
 
-const EXTENSION: &str = "lenga";
+/// Keeps the layout of the text in the nodes, so converting back lays it out the same way
+const KEEP_TRIVIA_FLAG: &str = "--keep-trivia";
+/// Derives ids from the text, so converting unchanged text again gives the same nodes
+const DETERMINISTIC_IDS_FLAG: &str = "--deterministic-ids";
+/// Writes the nodes as pretty JSON, in a `.lenga.json` file
+const JSON_FLAG: &str = "--json";
+/// Writes the nodes as S-expressions, in a `.lenga.sexp` file
+const SEXP_FLAG: &str = "--sexp";
+const FLAGS: [&str; 4] = [
+    KEEP_TRIVIA_FLAG,
+    DETERMINISTIC_IDS_FLAG,
+    JSON_FLAG,
+    SEXP_FLAG,
+];
+/// Upgrades `.lenga` files written with an older format version, in place
+const MIGRATE_COMMAND: &str = "migrate";
+/// Converts a `.lenga` file to another encoding
+const CONVERT_COMMAND: &str = "convert";
+/// Converts the text file of `.lenga` files again, keeping the ids of the code it still has
+const UPDATE_COMMAND: &str = "update";
 
 fn main() {
-    let Some(input_path_str) = args().nth(1) else {
+    let program = args().next().unwrap();
+    let usage = || {
+        println!(
+            "Usage: {program} [{KEEP_TRIVIA_FLAG}] [{DETERMINISTIC_IDS_FLAG}] [{JSON_FLAG} | {SEXP_FLAG}] <input_file>"
+        );
+        println!("       {program} {MIGRATE_COMMAND} <input_file.lenga>...");
+        println!("       {program} {CONVERT_COMMAND} <input_file.lenga> <output_file.lenga>");
+        println!(
+            "       {program} {UPDATE_COMMAND} [{KEEP_TRIVIA_FLAG}] [{DETERMINISTIC_IDS_FLAG}] <input_file.lenga>..."
+        );
+    };
+
+    match args().nth(1).as_deref() {
+        Some(MIGRATE_COMMAND) => {
+            let paths: Vec<String> = args().skip(2).collect();
+            if paths.is_empty() {
+                println!("No input file provided");
+                usage();
+                return;
+            }
+            if !migrate(&paths) {
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(CONVERT_COMMAND) => {
+            let paths: Vec<String> = args().skip(2).collect();
+            let [input_path_str, output_path_str] = &paths[..] else {
+                println!("Expected an input and an output file");
+                usage();
+                return;
+            };
+            if let Err(e) = convert(input_path_str, output_path_str) {
+                eprintln!("Error converting {input_path_str}: {e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(UPDATE_COMMAND) => {
+            let paths: Vec<String> = args()
+                .skip(2)
+                .filter(|arg| !FLAGS.contains(&arg.as_str()))
+                .collect();
+            if paths.is_empty() {
+                println!("No input file provided");
+                usage();
+                return;
+            }
+            if !update(&paths) {
+                std::process::exit(1);
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let encoding = if args().any(|arg| arg == JSON_FLAG) {
+        Encoding::Json
+    } else if args().any(|arg| arg == SEXP_FLAG) {
+        Encoding::SExpression
+    } else {
+        Encoding::Bincode
+    };
+    let Some(input_path_str) = args().skip(1).find(|arg| !FLAGS.contains(&arg.as_str())) else {
         println!("No input file provided");
-        println!("Usage: {} <input_file>", args().next().unwrap());
+        usage();
         return;
     };
 
-    let transpiler = Transpiler::new();
-
     let input_path = Path::new(&input_path_str);
+    let transpiler = transpiler_from_args(input_path);
+
     let mut file = match File::open(input_path) {
         Ok(file) => file,
         Err(e) => {
@@ -28,15 +115,13 @@ fn main() {
             return;
         }
     };
-    let file_extension = input_path.extension().and_then(|s| s.to_str()).unwrap();
 
-    if file_extension == EXTENSION {
+    if let Some((output_path, input_encoding)) = Encoding::split_path(input_path) {
         let mut content = Vec::new();
         BufReader::new(&mut file).read_to_end(&mut content).unwrap();
-        let output_path = input_path.with_extension("");
         let file_extension = output_path.extension().and_then(|s| s.to_str()).unwrap();
-        let output = transpiler
-            .nodes_to_text(content, file_extension)
+        let output = encoding::convert(&content, input_encoding, Encoding::Bincode)
+            .and_then(|nodes| transpiler.nodes_to_text(nodes, file_extension))
             .unwrap_or_else(|e| {
                 eprintln!("Error parsing nodes file {input_path_str}: {e}");
                 std::process::exit(1);
@@ -44,15 +129,113 @@ fn main() {
         let mut output_file = File::create(output_path).unwrap();
         output_file.write_all(output.as_bytes()).unwrap();
     } else {
+        let file_extension = input_path.extension().and_then(|s| s.to_str()).unwrap();
         let mut content = String::new();
         if let Err(e) = file.read_to_string(&mut content) {
             eprintln!("Error reading file {input_path_str}: {e}");
             return;
         }
-        let output = transpiler.text_to_nodes(&content, file_extension).unwrap();
-        let output_path = input_path.with_extension(format!("{file_extension}.{EXTENSION}"));
+        let (output, diagnostics) = transpiler
+            .text_to_nodes_with_diagnostics(&content, file_extension)
+            .unwrap_or_else(|diagnostics| {
+                print_diagnostics(&input_path_str, &diagnostics);
+                std::process::exit(1);
+            });
+        print_diagnostics(&input_path_str, &diagnostics);
+        let output = encoding::convert(&output, Encoding::Bincode, encoding).unwrap();
+        let output_path =
+            input_path.with_extension(format!("{file_extension}.{}", encoding.extension()));
 
         let mut output_file = File::create(output_path).unwrap();
         output_file.write_all(&output).unwrap();
     }
 }
+
+/// Transpiler with the options given as flags for the text file at `text_path`, whose path in
+/// its repository seeds the deterministic ids
+fn transpiler_from_args(text_path: &Path) -> Transpiler {
+    let transpiler = if args().any(|arg| arg == KEEP_TRIVIA_FLAG) {
+        Transpiler::with_trivia()
+    } else {
+        Transpiler::new()
+    };
+    if args().any(|arg| arg == DETERMINISTIC_IDS_FLAG) {
+        transpiler.with_deterministic_ids(&transpiler::id_seed(text_path))
+    } else {
+        transpiler
+    }
+}
+
+/// Path of the text file a `.lenga` file holds, and the encoding it was written with
+fn split_path(path_str: &str) -> Result<(PathBuf, Encoding), String> {
+    Encoding::split_path(Path::new(path_str))
+        .ok_or_else(|| "not a .lenga, .lenga.json or .lenga.sexp file".to_string())
+}
+
+/// Rewrites each `.lenga` file with the current format version, in the encoding it was
+/// written with, and prints what changed. Returns whether every file could be migrated
+fn migrate(paths: &[String]) -> bool {
+    let transpiler = Transpiler::new();
+    let mut migrated_all = true;
+    for path_str in paths {
+        let result = split_path(path_str).and_then(|(text_path, encoding)| {
+            let language_extension = text_path.extension().and_then(|s| s.to_str()).unwrap_or("");
+            let content = fs::read(path_str).map_err(|e| e.to_string())?;
+            let nodes = encoding::convert(&content, encoding, Encoding::Bincode)?;
+            let (output, report) = transpiler.migrate(&nodes, language_extension)?;
+            if !report.is_up_to_date() {
+                let output = encoding::convert(&output, Encoding::Bincode, encoding)?;
+                fs::write(path_str, output).map_err(|e| e.to_string())?;
+            }
+            Ok(report)
+        });
+        match result {
+            Ok(report) => println!("{path_str}: {report}"),
+            Err(e) => {
+                eprintln!("Error migrating {path_str}: {e}");
+                migrated_all = false;
+            }
+        }
+    }
+    migrated_all
+}
+
+/// Rewrites each `.lenga` file from the text file it holds, like `main.c` for `main.c.lenga`,
+/// keeping the ids of the code both still share. Returns whether every file could be updated
+fn update(paths: &[String]) -> bool {
+    let mut updated_all = true;
+    for path_str in paths {
+        let result = split_path(path_str).and_then(|(text_path, encoding)| {
+            let language_extension = text_path.extension().and_then(|s| s.to_str()).unwrap_or("");
+            let text = fs::read_to_string(&text_path)
+                .map_err(|e| format!("{}: {e}", text_path.display()))?;
+            let content = fs::read(path_str).map_err(|e| e.to_string())?;
+            let nodes = encoding::convert(&content, encoding, Encoding::Bincode)?;
+            let output =
+                transpiler_from_args(&text_path).update_nodes(nodes, &text, language_extension)?;
+            let output = encoding::convert(&output, Encoding::Bincode, encoding)?;
+            fs::write(path_str, output).map_err(|e| e.to_string())
+        });
+        if let Err(e) = result {
+            eprintln!("Error updating {path_str}: {e}");
+            updated_all = false;
+        }
+    }
+    updated_all
+}
+
+/// Writes the nodes of a `.lenga` file to another one, in the encoding of its extension
+fn convert(input_path_str: &str, output_path_str: &str) -> Result<(), String> {
+    let (_, from) = split_path(input_path_str)?;
+    let (_, to) = split_path(output_path_str)?;
+    let content = fs::read(input_path_str).map_err(|e| e.to_string())?;
+    let output = encoding::convert(&content, from, to)?;
+    fs::write(output_path_str, output).map_err(|e| e.to_string())
+}
+
+/// Prints each diagnostic as `path:line:column: severity[code]: message`
+fn print_diagnostics(path: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        eprintln!("{path}:{diagnostic}");
+    }
+}
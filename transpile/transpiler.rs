@@ -1,4 +1,13 @@
-use lenga::language::{Language, c::C};
+use std::path::Path;
+
+use lenga::{
+    language::{
+        Language,
+        c::C,
+        diagnostic::{Diagnostic, Severity},
+    },
+    node::migration::{self, MigrationReport},
+};
 
 pub enum SuportedLanguage {
     C(C),
@@ -6,7 +15,10 @@ pub enum SuportedLanguage {
 }
 
 impl SuportedLanguage {
-    pub fn text_to_nodes(&self, content: &str) -> Result<Vec<u8>, String> {
+    pub fn text_to_nodes(
+        &self,
+        content: &str,
+    ) -> Result<(Vec<u8>, Vec<Diagnostic>), Vec<Diagnostic>> {
         match self {
             SuportedLanguage::C(language) => text_to_nodes(language, content),
         }
@@ -23,18 +35,42 @@ impl SuportedLanguage {
             SuportedLanguage::C(language) => nodes_to_text(language, nodes),
         }
     }
+
+    pub fn migrate(&self, nodes: &[u8]) -> Result<(Vec<u8>, MigrationReport), String> {
+        match self {
+            SuportedLanguage::C(language) => migration::migrate_file(language, nodes),
+        }
+    }
+
+    pub fn update_nodes(&self, nodes: Vec<u8>, content: &str) -> Result<Vec<u8>, String> {
+        match self {
+            SuportedLanguage::C(language) => update_nodes(language, nodes, content),
+        }
+    }
 }
 
 fn text_to_text<T: Language>(language: &T, content: &str) -> Result<String, String> {
     let nodes = language.parse_text(content)?;
-    let output = language.write_to_text(nodes).unwrap();
-    Ok(output)
+    language.write_to_text(nodes)
 }
 
-fn text_to_nodes<T: Language>(language: &T, content: &str) -> Result<Vec<u8>, String> {
-    let code_objects = language.parse_text(content)?;
+fn text_to_nodes<T: Language>(
+    language: &T,
+    content: &str,
+) -> Result<(Vec<u8>, Vec<Diagnostic>), Vec<Diagnostic>> {
+    let (code_objects, diagnostics) = language.parse_text_with_diagnostics(content)?;
     let output = language.write_to_nodes(code_objects).unwrap();
-    Ok(output)
+    Ok((output, diagnostics))
+}
+
+fn update_nodes<T: Language>(
+    language: &T,
+    nodes: Vec<u8>,
+    content: &str,
+) -> Result<Vec<u8>, String> {
+    let src_file = language.parse_nodes(nodes)?;
+    let updated = language.update_from_text(src_file, content)?;
+    language.write_to_nodes(updated)
 }
 
 pub fn nodes_to_text<T: Language>(language: &T, nodes: Vec<u8>) -> Result<String, String> {
@@ -43,7 +79,13 @@ pub fn nodes_to_text<T: Language>(language: &T, nodes: Vec<u8>) -> Result<String
     Ok(output)
 }
 
-pub struct Transpiler {}
+pub struct Transpiler {
+    /// Whether text keeps its layout in the nodes, so it is written back laid out as it was
+    keep_trivia: bool,
+    /// Seed of the ids text gets, like the path of the file, so it gets the same ids every
+    /// time it is converted to nodes. Ids are random without one
+    id_seed: Option<String>,
+}
 
 impl Default for Transpiler {
     fn default() -> Self {
@@ -53,44 +95,140 @@ impl Default for Transpiler {
 
 impl Transpiler {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            keep_trivia: false,
+            id_seed: None,
+        }
+    }
+
+    /// Transpiler that keeps the blank lines, function line breaks and indentation of the text
+    /// it converts to nodes, so it is converted back laid out the same way
+    pub fn with_trivia() -> Self {
+        Self {
+            keep_trivia: true,
+            ..Self::new()
+        }
+    }
+
+    /// Same transpiler, but deriving the ids of the objects in text from `seed`, where they
+    /// are and what they say, so converting unchanged text again gives the same nodes. The
+    /// seed tells files apart, like their path
+    pub fn with_deterministic_ids(self, seed: &str) -> Self {
+        Self {
+            id_seed: Some(seed.to_string()),
+            ..self
+        }
     }
 
     fn get_language(&self, file_extension: &str) -> Option<SuportedLanguage> {
         let cext = C::new().file_extension();
+        let c = if self.keep_trivia {
+            C::with_trivia()
+        } else {
+            C::new()
+        };
+        let c = match &self.id_seed {
+            Some(seed) => c.with_deterministic_ids(seed),
+            None => c,
+        };
         let lang: Option<SuportedLanguage> = match file_extension {
-            ext if ext == cext => Some(SuportedLanguage::C(C::new())),
+            ext if ext == cext => Some(SuportedLanguage::C(c)),
             _ => None,
         };
         lang
     }
 
     pub fn text_to_nodes(&self, content: &str, file_extension: &str) -> Result<Vec<u8>, String> {
+        let (nodes, _) = self
+            .text_to_nodes_with_diagnostics(content, file_extension)
+            .map_err(|diagnostics| {
+                diagnostics
+                    .into_iter()
+                    .find(|diagnostic| diagnostic.severity == Severity::Error)
+                    .map(|diagnostic| diagnostic.message)
+                    .unwrap_or_default()
+            })?;
+        Ok(nodes)
+    }
+
+    /// Converts text to nodes like `text_to_nodes`, with the diagnostics of the parsed text
+    pub fn text_to_nodes_with_diagnostics(
+        &self,
+        content: &str,
+        file_extension: &str,
+    ) -> Result<(Vec<u8>, Vec<Diagnostic>), Vec<Diagnostic>> {
         let language = self
             .get_language(file_extension)
             .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))
             .unwrap();
-        let nodes = language.text_to_nodes(content)?;
-        Ok(nodes)
+        language.text_to_nodes(content)
     }
 
     pub fn text_to_text(&self, content: &str, file_extension: &str) -> Result<String, String> {
         let language = self
             .get_language(file_extension)
-            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))
-            .unwrap();
-        let output = language.text_to_text(content).unwrap();
-        Ok(output)
+            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))?;
+        language.text_to_text(content)
     }
 
     pub fn nodes_to_text(&self, nodes: Vec<u8>, file_extension: &str) -> Result<String, String> {
         let language = self
             .get_language(file_extension)
-            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))
-            .unwrap();
-        let output = language.nodes_to_text(nodes).unwrap();
-        Ok(output)
+            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))?;
+        language.nodes_to_text(nodes)
     }
+
+    /// Upgrades nodes written with an older format version to the current one, returning the
+    /// new nodes and what changed
+    pub fn migrate(
+        &self,
+        nodes: &[u8],
+        file_extension: &str,
+    ) -> Result<(Vec<u8>, MigrationReport), String> {
+        let language = self
+            .get_language(file_extension)
+            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))?;
+        language.migrate(nodes)
+    }
+
+    /// Converts `content`, a new version of the text `nodes` were converted from, to nodes that
+    /// keep the ids of the objects it still has
+    pub fn update_nodes(
+        &self,
+        nodes: Vec<u8>,
+        content: &str,
+        file_extension: &str,
+    ) -> Result<Vec<u8>, String> {
+        let language = self
+            .get_language(file_extension)
+            .ok_or_else(|| format!("Language with extension '{file_extension}' not registered"))?;
+        language.update_nodes(nodes, content)
+    }
+}
+
+/// Seed of the deterministic ids of the text file at `text_path`: its path from the root of
+/// the git repository holding it, with `/` separators, or only its file name outside of one.
+/// So the ids are the same whatever directory the file is transpiled from
+pub fn id_seed(text_path: &Path) -> String {
+    let file_name = text_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let Ok(path) = text_path.canonicalize() else {
+        return file_name;
+    };
+    path.ancestors()
+        .skip(1)
+        .find(|dir| dir.join(".git").exists())
+        .and_then(|root| path.strip_prefix(root).ok())
+        .map(|relative| {
+            relative
+                .iter()
+                .map(|component| component.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or(file_name)
 }
 
 #[cfg(test)]
@@ -138,4 +276,494 @@ int main()
             output_code.replace(" ", "").replace("\n", "")
         );
     }
+
+    #[test]
+    fn test_transpile_c_loops_to_nodes_and_back() {
+        let c_code = r#"
+int main()
+{
+    int a = 0;
+    while (a < 10)
+    {
+        a = a + 1;
+    }
+    do
+    {
+        a = a - 1;
+    } while (a > 0);
+    for (int i = 0; i < 10; i = i + 1)
+    {
+        a = a + i;
+    }
+    for (;;)
+    {
+        return a;
+    }
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_switch_to_nodes_and_back() {
+        let c_code = r#"
+int main()
+{
+    int state = 0;
+    while (state < 3)
+    {
+        switch (state)
+        {
+            case 0:
+                state = 1;
+                break;
+            case 1:
+                state = 2;
+                continue;
+            default:
+                state = 3;
+        }
+    }
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_struct_to_nodes_and_back() {
+        let c_code = r#"
+struct Point
+{
+    int x;
+    int y;
+};
+enum Color
+{
+    RED,
+    GREEN = 2
+};
+int main()
+{
+    struct Point p;
+    enum Color c = GREEN;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_pointer_types_to_nodes_and_back() {
+        let c_code = r#"
+struct Node
+{
+    int value;
+    struct Node *next;
+    char name[16];
+};
+int main(int argc, char *argv[])
+{
+    const char *const greeting = "hi";
+    unsigned long long count = 0;
+    volatile int *flags;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_callbacks_to_nodes_and_back() {
+        let c_code = r#"
+struct Table
+{
+    int (*compare)(int, int);
+};
+int run(int (*step)(int), int start)
+{
+    int (*next)(int) = step;
+    return start;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_operators_to_nodes_and_back() {
+        let c_code = r#"
+int step(int value, int limit)
+{
+    int *pointer = &value;
+    value++;
+    --limit;
+    long wide = (long)*pointer;
+    unsigned int size = sizeof(int);
+    return value < limit ? -value : !limit;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_member_access_to_nodes_and_back() {
+        let c_code = r#"
+struct Node
+{
+    int value;
+    struct Node *next;
+};
+int second(struct Node *head, int values[], int i)
+{
+    int total = head->next->value + (*head).value;
+    return (total + values[i]) * 2;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_preprocessor_to_nodes_and_back() {
+        let c_code = r#"
+#ifndef LIMITS_H
+#define LIMITS_H
+#define LIMIT 10
+#define MAX(a, b) ((a) > (b) ? (a) : (b))
+#pragma pack(1)
+int clamp(int value)
+{
+#ifdef DEBUG
+    return value;
+#else
+    return MAX(value, LIMIT);
+#endif
+}
+#endif
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_specifiers_to_nodes_and_back() {
+        let c_code = r#"
+static const int limit = 10;
+extern int counter;
+static inline int
+square(int value)
+{
+    register int result = value * value;
+    return result;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_declaration_lists_to_nodes_and_back() {
+        let c_code = r#"
+struct Point {
+    int x;
+    int y[3];
+};
+int a = 1, *b, c[3] = {1, 2, 3};
+struct Point origin = {.x = 1, .y[2] = 5};
+int
+main()
+{
+    for (int i = 0, j = 10; i < j; i++) {
+    }
+    return a;
+}
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_literals_to_nodes_and_back() {
+        let c_code = r#"
+char c = 'a', nl = '\n', quote = '\'';
+int wide = L'x';
+char *s = L"tab\there" " and \x41" u8"!";
+char *octal = "\101\"q\"";
+        "#;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(
+            c_code.replace(" ", "").replace("\n", ""),
+            output_code.replace(" ", "").replace("\n", "")
+        );
+    }
+
+    #[test]
+    fn test_transpile_c_with_trivia_to_nodes_and_back() {
+        let c_code = "\n#include <stdio.h>\n\n\nint first(int a, int b)\n{\n\tint sum = a + b;\n\n\treturn sum; // keeps its layout\n}\n";
+
+        let transpiler = Transpiler::with_trivia();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let output_code = transpiler
+            .nodes_to_text(nodes, "c")
+            .expect("Failed to convert nodes back to C code");
+
+        assert_eq!(c_code, output_code);
+    }
+
+    #[test]
+    fn test_transpile_c_with_deterministic_ids_twice() {
+        let c_code = r#"
+#include <stdio.h>
+
+int main()
+{
+    printf("hello\n");
+    return 0;
+}
+"#;
+
+        let transpiler = Transpiler::with_trivia().with_deterministic_ids("main.c");
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        assert_eq!(nodes, transpiler.text_to_nodes(c_code, "c").unwrap());
+        assert_ne!(
+            nodes,
+            Transpiler::with_trivia()
+                .text_to_nodes(c_code, "c")
+                .unwrap()
+        );
+        assert_eq!(transpiler.nodes_to_text(nodes, "c").unwrap(), c_code);
+    }
+
+    #[test]
+    fn test_transpile_c_update_nodes_from_edited_text() {
+        let c_code = "int add(int a, int b)\n{\n    return a + b;\n}\n";
+        let edited_code = "int add(int a, int b)\n{\n    return a - b;\n}\n";
+        let transpiler = Transpiler::with_trivia();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+
+        let unchanged = transpiler.update_nodes(nodes.clone(), c_code, "c").unwrap();
+        assert_eq!(unchanged, nodes);
+
+        let updated = transpiler
+            .update_nodes(nodes.clone(), edited_code, "c")
+            .unwrap();
+        assert_ne!(updated, nodes);
+        assert_eq!(
+            transpiler.nodes_to_text(updated.clone(), "c").unwrap(),
+            edited_code
+        );
+        let c_language = C::with_trivia();
+        let (before, after) = (
+            c_language.parse_nodes(nodes).unwrap(),
+            c_language.parse_nodes(updated).unwrap(),
+        );
+        assert_eq!(after.id, before.id);
+        assert_eq!(after.code[0].id(), before.code[0].id());
+    }
+
+    #[test]
+    fn test_transpile_c_migrate_nodes_written_before_the_header() {
+        use lenga::node::header::{FORMAT_VERSION, Header};
+
+        let c_code = "int main()\n{\n    return 0;\n}\n";
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes(c_code, "c")
+            .expect("Failed to convert C code to nodes");
+        let (_, legacy) = Header::read(&nodes).unwrap();
+
+        let (migrated, report) = transpiler
+            .migrate(legacy, "c")
+            .expect("Failed to migrate nodes");
+        assert_eq!((report.from, report.to), (0, FORMAT_VERSION));
+        assert_eq!(
+            Header::read(&migrated).unwrap().0,
+            Header::read(&nodes).unwrap().0
+        );
+        assert_eq!(
+            transpiler.nodes_to_text(migrated.clone(), "c").unwrap(),
+            transpiler.nodes_to_text(nodes, "c").unwrap()
+        );
+
+        let (unchanged, report) = transpiler.migrate(&migrated, "c").unwrap();
+        assert!(report.is_up_to_date());
+        assert_eq!(unchanged, migrated);
+    }
+
+    #[test]
+    fn test_id_seed_is_the_path_in_the_repository() {
+        let root = std::env::temp_dir().join(format!("lenga-id-seed-{}", std::process::id()));
+        let repository = root.join("repository");
+        std::fs::create_dir_all(repository.join(".git")).unwrap();
+        std::fs::create_dir_all(repository.join("src")).unwrap();
+        std::fs::write(repository.join("src/main.c"), "").unwrap();
+        std::fs::write(root.join("loose.c"), "").unwrap();
+
+        assert_eq!(id_seed(&repository.join("src/main.c")), "src/main.c");
+        assert_eq!(
+            id_seed(&repository.join("src/../src/./main.c")),
+            "src/main.c"
+        );
+        assert_eq!(id_seed(&root.join("loose.c")), "loose.c");
+        assert_eq!(id_seed(Path::new("missing/file.c")), "file.c");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_transpile_c_unreadable_nodes_to_text_is_an_error() {
+        use lenga::node::header::Header;
+
+        let transpiler = Transpiler::new();
+        let nodes = transpiler
+            .text_to_nodes("int x = 1;\n", "c")
+            .expect("Failed to convert C code to nodes");
+        let (header, body) = Header::read(&nodes).unwrap();
+        let header = header.unwrap();
+        let with_header = |header: Header| {
+            let mut bytes = Vec::new();
+            header.write(&mut bytes).unwrap();
+            bytes.extend_from_slice(body);
+            bytes
+        };
+
+        let newer = with_header(Header {
+            format_version: 2,
+            ..header.clone()
+        });
+        let other_language = with_header(Header {
+            language: "X".to_string(),
+            ..header
+        });
+        for nodes in [newer, other_language, br#"{"nodes":[]}"#.to_vec()] {
+            assert!(transpiler.nodes_to_text(nodes, "c").is_err());
+        }
+        assert!(transpiler.nodes_to_text(nodes, "x").is_err());
+    }
 }